@@ -214,6 +214,7 @@ impl ArbitraryQueryParameters {
             inner: Ok(nom_sql::CacheInner::Statement(Box::new(stmt))),
             always: false,
             concurrently: false,
+            options: Default::default(),
             unparsed_create_cache_statement: None,
         };
 
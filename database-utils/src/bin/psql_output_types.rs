@@ -2,9 +2,9 @@ use std::collections::HashMap;
 use std::iter::zip;
 
 const PSQL_TYPES: &[&str] = &["smallint", "integer", "bigint"];
-const PSQL_OPS: &[&str] = &["+", "-", "*", "/"];
+const PSQL_OPS: &[&str] = &["+", "-", "*", "/", "%"];
 const READYSET_TYPES: &[&str] = &["SmallInt", "Int", "BigInt"];
-const READYSET_OPS: &[&str] = &["Add", "Subtract", "Multiply", "Divide"];
+const READYSET_OPS: &[&str] = &["Add", "Subtract", "Multiply", "Divide", "StrictModulo"];
 
 async fn test(
     client: &tokio_postgres::Client,
@@ -52,6 +52,12 @@ pub enum BinaryOperator {
     /// `/`
     Divide,
 
+    /// `%`, returning NULL if the divisor is zero (MySQL)
+    Modulo,
+
+    /// `%`, returning an error if the divisor is zero (PostgreSQL)
+    StrictModulo,
+
     /// `?`
     JsonExists,
 
@@ -126,6 +132,7 @@ impl fmt::Display for BinaryOperator {
             Self::JsonSubtractPath => "#-",
             Self::Multiply => "*",
             Self::Divide => "/",
+            Self::Modulo | Self::StrictModulo => "%",
             Self::JsonExists => "?",
             Self::JsonAnyExists => "?|",
             Self::JsonAllExists => "?&",
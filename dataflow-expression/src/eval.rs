@@ -19,6 +19,18 @@ macro_rules! non_null {
 pub(crate) mod builtins;
 mod json;
 
+/// Returns true if `val` is a numeric zero
+fn is_zero(val: &DfValue) -> bool {
+    match val {
+        DfValue::Int(n) => *n == 0,
+        DfValue::UnsignedInt(n) => *n == 0,
+        DfValue::Float(f) => *f == 0.0,
+        DfValue::Double(f) => *f == 0.0,
+        DfValue::Numeric(d) => d.is_zero(),
+        _ => false,
+    }
+}
+
 fn eval_binary_op(op: BinaryOperator, left: &DfValue, right: &DfValue) -> ReadySetResult<DfValue> {
     use BinaryOperator::*;
 
@@ -41,6 +53,17 @@ fn eval_binary_op(op: BinaryOperator, left: &DfValue, right: &DfValue) -> ReadyS
         Subtract => Ok((non_null!(left) - non_null!(right))?),
         Multiply => Ok((non_null!(left) * non_null!(right))?),
         Divide => Ok((non_null!(left) / non_null!(right))?),
+        Modulo | StrictModulo => {
+            let (left, right) = (non_null!(left), non_null!(right));
+            if is_zero(right) {
+                return if op == StrictModulo {
+                    Err(invalid_query_err!("division by zero"))
+                } else {
+                    Ok(DfValue::None)
+                };
+            }
+            Ok((left % right)?)
+        }
         And => Ok((non_null!(left).is_truthy() && non_null!(right).is_truthy()).into()),
         Or => Ok((non_null!(left).is_truthy() || non_null!(right).is_truthy()).into()),
         Equal => Ok((non_null!(left) == non_null!(right)).into()),
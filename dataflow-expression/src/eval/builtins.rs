@@ -1,6 +1,7 @@
 use std::borrow::Borrow;
 use std::cmp::Ordering;
 use std::fmt::{self, Write};
+use std::iter;
 use std::ops::{Add, Div, Mul, Sub};
 use std::str::FromStr;
 
@@ -12,7 +13,7 @@ use chrono_tz::Tz;
 use itertools::Either;
 use mysql_time::MySqlTime;
 use nom_sql::TimestampField;
-use readyset_data::{DfType, DfValue, TimestampTz};
+use readyset_data::{Collation, DfType, DfValue, Interval, TimestampTz};
 use readyset_errors::{internal, invalid_query_err, unsupported, ReadySetError, ReadySetResult};
 use readyset_util::math::integer_rnd;
 use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
@@ -21,7 +22,8 @@ use serde_json::Value as JsonValue;
 use test_strategy::Arbitrary;
 use vec1::Vec1;
 
-use crate::{BuiltinFunction, Expr};
+use crate::regexp::{translate_replacement, RegexFlags, RegexFlavor};
+use crate::{BuiltinFunction, Expr, TrimSide};
 
 const MICROS_IN_SECOND: u32 = 1_000_000;
 const MILLIS_IN_SECOND: u32 = 1_000;
@@ -710,6 +712,220 @@ where
     Ok(res)
 }
 
+/// The maximum length, in characters, of the result of `lpad` or `rpad`.
+///
+/// This is the default value of MySQL's `max_allowed_packet`, which is much smaller than
+/// PostgreSQL's limit, but avoids allocating an arbitrarily large string for a single value.
+const MAX_PADDED_LENGTH: usize = 64 * 1024 * 1024;
+
+/// Returns the string contents and the collation of the given text value
+fn str_and_collation(value: &DfValue) -> ReadySetResult<(&str, Collation)> {
+    Ok((
+        <&str>::try_from(value)?,
+        value.collation().unwrap_or_default(),
+    ))
+}
+
+/// Evaluates the optional flags argument to a regular expression function, returning `None` if it
+/// is NULL
+fn regex_flags<D>(
+    flags: &Option<Expr>,
+    flavor: RegexFlavor,
+    record: &[D],
+) -> ReadySetResult<Option<RegexFlags>>
+where
+    D: Borrow<DfValue>,
+{
+    match flags {
+        Some(flags) => {
+            let flags = flags.eval(record)?;
+            if flags.is_none() {
+                return Ok(None);
+            }
+            RegexFlags::parse(flavor, <&str>::try_from(&flags)?).map(Some)
+        }
+        None => Ok(Some(RegexFlags::new(flavor))),
+    }
+}
+
+/// Returns the byte range of the first occurrence of `needle` in `haystack`, starting the search at
+/// the byte offset `from` and comparing characters according to `collation`
+fn find_str(
+    haystack: &str,
+    needle: &str,
+    from: usize,
+    collation: Collation,
+) -> Option<(usize, usize)> {
+    match collation {
+        Collation::Utf8 => haystack[from..]
+            .find(needle)
+            .map(|start| (from + start, from + start + needle.len())),
+        Collation::Citext => haystack[from..].char_indices().find_map(|(start, _)| {
+            let start = from + start;
+            let mut candidate = haystack[start..].char_indices();
+            let mut len = 0;
+            for needle_char in needle.chars() {
+                let (offset, c) = candidate.next()?;
+                if !c.to_lowercase().eq(needle_char.to_lowercase()) {
+                    return None;
+                }
+                len = offset + c.len_utf8();
+            }
+            Some((start, start + len))
+        }),
+    }
+}
+
+/// Capitalizes the first letter of each word in the given string, and converts the rest of the
+/// letters to lowercase, where words are sequences of alphanumeric characters
+fn initcap(s: &str) -> String {
+    let mut res = String::with_capacity(s.len());
+    let mut in_word = false;
+    for c in s.chars() {
+        if in_word {
+            res.extend(c.to_lowercase());
+        } else {
+            res.extend(c.to_uppercase());
+        }
+        in_word = c.is_alphanumeric();
+    }
+    res
+}
+
+/// Pads `s` to `length` characters by repeating `fill` on its left or right side, or truncates it
+/// to `length` characters if it's already longer than that. Returns `None` if the result should be
+/// NULL.
+///
+/// See [`BuiltinFunction::Pad`] for the meaning of `null_on_invalid`.
+fn pad(
+    s: &str,
+    length: i64,
+    fill: &str,
+    pad_left: bool,
+    null_on_invalid: bool,
+) -> ReadySetResult<Option<String>> {
+    let Ok(length) = usize::try_from(length) else {
+        return Ok((!null_on_invalid).then(String::new));
+    };
+    if length > MAX_PADDED_LENGTH {
+        if null_on_invalid {
+            return Ok(None);
+        }
+        return Err(invalid_query_err!("requested length too large"));
+    }
+
+    let s_len = s.chars().count();
+    if length <= s_len {
+        return Ok(Some(s.chars().take(length).collect()));
+    }
+    if fill.is_empty() {
+        return Ok((!null_on_invalid).then(|| s.to_owned()));
+    }
+
+    let padding = fill.chars().cycle().take(length - s_len);
+    Ok(Some(if pad_left {
+        padding.chain(s.chars()).collect()
+    } else {
+        s.chars().chain(padding).collect()
+    }))
+}
+
+/// Returns the error for a math function whose result is out of the range of its return type
+fn out_of_range(func: &str) -> ReadySetError {
+    invalid_query_err!("value out of range in '{func}'")
+}
+
+/// Converts the approximate result of a math function to the function's return type, returning an
+/// error if it isn't finite
+fn approximate_math_result(val: f64, ty: &DfType, func: &str) -> ReadySetResult<DfValue> {
+    if !val.is_finite() {
+        return Err(out_of_range(func));
+    }
+    if ty.is_numeric() {
+        Decimal::from_f64(val)
+            .map(DfValue::from)
+            .ok_or_else(|| out_of_range(func))
+    } else {
+        Ok(DfValue::Double(val))
+    }
+}
+
+/// Converts the exact result of a math function to the function's return type, which may be an
+/// integer type
+fn exact_math_result(val: Decimal, ty: &DfType, func: &str) -> ReadySetResult<DfValue> {
+    if ty.is_any_int() {
+        val.to_i64()
+            .map(DfValue::Int)
+            .ok_or_else(|| out_of_range(func))
+    } else {
+        Ok(DfValue::from(val))
+    }
+}
+
+/// Returns the logarithm of `f` in the given `base`, using the more accurate specialized functions
+/// for bases 2 and 10
+fn log(f: f64, base: f64) -> f64 {
+    if base == 10.0 {
+        f.log10()
+    } else if base == 2.0 {
+        f.log2()
+    } else {
+        f.ln() / base.ln()
+    }
+}
+
+/// Truncates the integer `n` to `places` decimal places, which only has an effect if `places` is
+/// negative
+fn truncate_int(n: i128, places: i64) -> i128 {
+    if places >= 0 {
+        return n;
+    }
+    match u32::try_from(places.unsigned_abs())
+        .ok()
+        .and_then(|places| 10_i128.checked_pow(places))
+    {
+        Some(factor) => n - n % factor,
+        None => 0,
+    }
+}
+
+/// Truncates the float `f` to `places` decimal places
+fn truncate_float(f: f64, places: i64) -> f64 {
+    let factor = 10_f64.powi(places.unsigned_abs().min(i32::MAX as u64) as i32);
+    if places >= 0 {
+        let shifted = f * factor;
+        if shifted.is_finite() {
+            shifted.trunc() / factor
+        } else {
+            // Too large to have any digits past `places`
+            f
+        }
+    } else if factor.is_finite() {
+        (f / factor).trunc() * factor
+    } else {
+        0.0
+    }
+}
+
+/// Truncates the decimal `d` to `places` decimal places
+fn truncate_decimal(d: Decimal, places: i64) -> Decimal {
+    if places >= 0 {
+        d.round_dp_with_strategy(
+            u32::try_from(places).unwrap_or(u32::MAX),
+            rust_decimal::RoundingStrategy::ToZero,
+        )
+    } else {
+        match u32::try_from(places.unsigned_abs())
+            .ok()
+            .and_then(|places| 10_i128.checked_pow(places))
+            .and_then(Decimal::from_i128)
+        {
+            Some(factor) => (d / factor).trunc() * factor,
+            None => Decimal::ZERO,
+        }
+    }
+}
+
 fn greatest_or_least<F, D>(
     args: &Vec1<Expr>,
     record: &[D],
@@ -1180,6 +1396,300 @@ impl BuiltinFunction {
                     }
                 }
             }
+            BuiltinFunction::Lower(string) => {
+                let string = non_null!(string.eval(record)?);
+                let (s, collation) = str_and_collation(&string)?;
+                Ok(DfValue::from_str_and_collation(
+                    &s.to_lowercase(),
+                    collation,
+                ))
+            }
+            BuiltinFunction::Upper(string) => {
+                let string = non_null!(string.eval(record)?);
+                let (s, collation) = str_and_collation(&string)?;
+                Ok(DfValue::from_str_and_collation(
+                    &s.to_uppercase(),
+                    collation,
+                ))
+            }
+            BuiltinFunction::CharLength(string) => {
+                let string = non_null!(string.eval(record)?);
+                Ok(DfValue::Int(<&str>::try_from(&string)?.chars().count() as _))
+            }
+            BuiltinFunction::OctetLength(string) => {
+                let string = non_null!(string.eval(record)?);
+                Ok(DfValue::Int(<&str>::try_from(&string)?.len() as _))
+            }
+            BuiltinFunction::Trim(string, chars, side) => {
+                let string = non_null!(string.eval(record)?);
+                let (s, collation) = str_and_collation(&string)?;
+                let chars: Vec<char> = match chars {
+                    Some(chars) => <&str>::try_from(&non_null!(chars.eval(record)?))?
+                        .chars()
+                        .collect(),
+                    None => vec![' '],
+                };
+
+                let trimmed = |c: char| chars.contains(&c);
+                let res = match side {
+                    TrimSide::Leading => s.trim_start_matches(trimmed),
+                    TrimSide::Trailing => s.trim_end_matches(trimmed),
+                    TrimSide::Both => s.trim_matches(trimmed),
+                };
+                Ok(DfValue::from_str_and_collation(res, collation))
+            }
+            BuiltinFunction::Replace(string, from, to) => {
+                let string = non_null!(string.eval(record)?);
+                let from = non_null!(from.eval(record)?);
+                let to = non_null!(to.eval(record)?);
+                let (s, collation) = str_and_collation(&string)?;
+                let from = <&str>::try_from(&from)?;
+                let to = <&str>::try_from(&to)?;
+
+                if from.is_empty() {
+                    return Ok(string);
+                }
+
+                let mut res = String::with_capacity(s.len());
+                let mut pos = 0;
+                while let Some((start, end)) = find_str(s, from, pos, collation) {
+                    res.push_str(&s[pos..start]);
+                    res.push_str(to);
+                    pos = end;
+                }
+                res.push_str(&s[pos..]);
+                Ok(DfValue::from_str_and_collation(&res, collation))
+            }
+            BuiltinFunction::Pad {
+                string,
+                length,
+                fill,
+                pad_left,
+                null_on_invalid,
+            } => {
+                let string = non_null!(string.eval(record)?);
+                let length = i64::try_from(non_null!(length.eval(record)?))?;
+                let fill = match fill {
+                    Some(fill) => Some(non_null!(fill.eval(record)?)),
+                    None => None,
+                };
+                let (s, collation) = str_and_collation(&string)?;
+                let fill = match &fill {
+                    Some(fill) => <&str>::try_from(fill)?,
+                    None => " ",
+                };
+
+                Ok(match pad(s, length, fill, *pad_left, *null_on_invalid)? {
+                    Some(res) => DfValue::from_str_and_collation(&res, collation),
+                    None => DfValue::None,
+                })
+            }
+            BuiltinFunction::LeftOrRight {
+                string,
+                length,
+                left,
+                negative_from_end,
+            } => {
+                let string = non_null!(string.eval(record)?);
+                let length = i64::try_from(non_null!(length.eval(record)?))?;
+                let (s, collation) = str_and_collation(&string)?;
+
+                let s_len = s.chars().count();
+                let num_chars = match usize::try_from(length) {
+                    Ok(length) => length,
+                    Err(_) if *negative_from_end => s_len.saturating_sub(
+                        usize::try_from(length.unsigned_abs()).unwrap_or(usize::MAX),
+                    ),
+                    Err(_) => 0,
+                };
+
+                let res = if *left {
+                    s.chars().take(num_chars).collect::<String>()
+                } else {
+                    s.chars()
+                        .skip(s_len.saturating_sub(num_chars))
+                        .collect::<String>()
+                };
+                Ok(DfValue::from_str_and_collation(&res, collation))
+            }
+            BuiltinFunction::Reverse(string) => {
+                let string = non_null!(string.eval(record)?);
+                let (s, collation) = str_and_collation(&string)?;
+                Ok(DfValue::from_str_and_collation(
+                    &s.chars().rev().collect::<String>(),
+                    collation,
+                ))
+            }
+            BuiltinFunction::Locate {
+                substring,
+                string,
+                start,
+            } => {
+                let substring = non_null!(substring.eval(record)?);
+                let string = non_null!(string.eval(record)?);
+                let start = match start {
+                    Some(start) => i64::try_from(non_null!(start.eval(record)?))?,
+                    None => 1,
+                };
+                let substring = <&str>::try_from(&substring)?;
+                let (s, collation) = str_and_collation(&string)?;
+
+                // Convert the 1-based character position to start searching at into a byte offset,
+                // where the position just past the end of the string is still valid
+                let Some(from) = usize::try_from(start).ok().and_then(|start| {
+                    s.char_indices()
+                        .map(|(offset, _)| offset)
+                        .chain(iter::once(s.len()))
+                        .nth(start.checked_sub(1)?)
+                }) else {
+                    return Ok(DfValue::Int(0));
+                };
+
+                if substring.is_empty() {
+                    return Ok(DfValue::Int(start));
+                }
+
+                Ok(DfValue::Int(
+                    match find_str(s, substring, from, collation) {
+                        Some((offset, _)) => s[..offset].chars().count() as i64 + 1,
+                        None => 0,
+                    },
+                ))
+            }
+            BuiltinFunction::Initcap(string) => {
+                let string = non_null!(string.eval(record)?);
+                let (s, collation) = str_and_collation(&string)?;
+                Ok(DfValue::from_str_and_collation(&initcap(s), collation))
+            }
+            BuiltinFunction::Abs(arg) => match non_null!(arg.eval(record)?) {
+                DfValue::Int(n) => n
+                    .checked_abs()
+                    .map(DfValue::Int)
+                    .ok_or_else(|| out_of_range(self.name())),
+                val @ DfValue::UnsignedInt(_) => Ok(val),
+                DfValue::Float(f) => Ok(DfValue::Float(f.abs())),
+                DfValue::Double(f) => Ok(DfValue::Double(f.abs())),
+                DfValue::Numeric(d) => Ok(DfValue::from(d.abs())),
+                val => approximate_math_result(f64::try_from(&val)?.abs(), ty, self.name()),
+            },
+            BuiltinFunction::Ceil(arg) | BuiltinFunction::Floor(arg) => {
+                let ceil = matches!(self, BuiltinFunction::Ceil(_));
+                match non_null!(arg.eval(record)?) {
+                    val @ (DfValue::Int(_) | DfValue::UnsignedInt(_)) => Ok(val),
+                    DfValue::Numeric(d) => {
+                        exact_math_result(if ceil { d.ceil() } else { d.floor() }, ty, self.name())
+                    }
+                    val => {
+                        let f = f64::try_from(&val)?;
+                        approximate_math_result(
+                            if ceil { f.ceil() } else { f.floor() },
+                            ty,
+                            self.name(),
+                        )
+                    }
+                }
+            }
+            BuiltinFunction::Trunc(arg, places) => {
+                let val = non_null!(arg.eval(record)?);
+                let places = match places {
+                    Some(places) => i64::try_from(non_null!(places.eval(record)?))?,
+                    None => 0,
+                };
+
+                match val {
+                    // Truncating toward zero can't make an integer any larger, so these casts
+                    // can't overflow
+                    DfValue::Int(n) => Ok(DfValue::Int(truncate_int(n.into(), places) as _)),
+                    DfValue::UnsignedInt(n) => {
+                        Ok(DfValue::UnsignedInt(truncate_int(n.into(), places) as _))
+                    }
+                    DfValue::Numeric(d) => {
+                        exact_math_result(truncate_decimal(*d, places), ty, self.name())
+                    }
+                    val => approximate_math_result(
+                        truncate_float(f64::try_from(&val)?, places),
+                        ty,
+                        self.name(),
+                    ),
+                }
+            }
+            BuiltinFunction::Sign(arg) => {
+                let sign = match non_null!(arg.eval(record)?) {
+                    DfValue::Int(n) => n.cmp(&0),
+                    DfValue::UnsignedInt(n) => n.cmp(&0),
+                    DfValue::Numeric(d) => (*d).cmp(&Decimal::ZERO),
+                    val => f64::try_from(&val)?
+                        .partial_cmp(&0.0)
+                        .unwrap_or(Ordering::Equal),
+                } as i64;
+
+                Ok(if ty.is_numeric() {
+                    DfValue::from(Decimal::from(sign))
+                } else if ty.is_any_float() {
+                    DfValue::Double(sign as f64)
+                } else {
+                    DfValue::Int(sign)
+                })
+            }
+            BuiltinFunction::Power(base, exponent) => {
+                let base = f64::try_from(&non_null!(base.eval(record)?))?;
+                let exponent = f64::try_from(&non_null!(exponent.eval(record)?))?;
+                approximate_math_result(base.powf(exponent), ty, self.name())
+            }
+            BuiltinFunction::Sqrt {
+                arg,
+                null_on_invalid,
+            } => {
+                let f = f64::try_from(&non_null!(arg.eval(record)?))?;
+                if f < 0.0 {
+                    if *null_on_invalid {
+                        return Ok(DfValue::None);
+                    }
+                    return Err(invalid_query_err!(
+                        "cannot take square root of a negative number"
+                    ));
+                }
+                approximate_math_result(f.sqrt(), ty, self.name())
+            }
+            BuiltinFunction::Exp(arg) => {
+                let f = f64::try_from(&non_null!(arg.eval(record)?))?;
+                approximate_math_result(f.exp(), ty, self.name())
+            }
+            BuiltinFunction::Log {
+                arg,
+                base,
+                null_on_invalid,
+            } => {
+                let f = f64::try_from(&non_null!(arg.eval(record)?))?;
+                let base = match base {
+                    Some(base) => Some(f64::try_from(&non_null!(base.eval(record)?))?),
+                    None => None,
+                };
+
+                let invalid = |msg: &str| {
+                    if *null_on_invalid {
+                        Ok(DfValue::None)
+                    } else {
+                        Err(invalid_query_err!("{msg}"))
+                    }
+                };
+                for val in iter::once(f).chain(base) {
+                    if val == 0.0 {
+                        return invalid("cannot take logarithm of zero");
+                    }
+                    if val < 0.0 {
+                        return invalid("cannot take logarithm of a negative number");
+                    }
+                }
+
+                match base {
+                    // MySQL returns NULL for any base less than or equal to 1
+                    Some(base) if base <= 1.0 && *null_on_invalid => Ok(DfValue::None),
+                    Some(base) if base == 1.0 => invalid("division by zero"),
+                    Some(base) => approximate_math_result(log(f, base), ty, self.name()),
+                    None => approximate_math_result(f.ln(), ty, self.name()),
+                }
+            }
             BuiltinFunction::Greatest { args, compare_as } => {
                 greatest_or_least(args, record, compare_as, ty, |v1, v2| v1 > v2)
             }
@@ -1267,6 +1777,166 @@ impl BuiltinFunction {
                 }
                 .and_then(|value| value.coerce_to(ty, &DfType::Unknown))
             }
+            BuiltinFunction::Interval { value, unit } => {
+                let value = non_null!(value.eval(record)?);
+                Ok(Interval::from_mysql_unit(<&str>::try_from(&value)?, *unit)
+                    .map(DfValue::from)
+                    .unwrap_or(DfValue::None))
+            }
+            BuiltinFunction::TimestampTzAddInterval {
+                timestamp,
+                interval,
+                subtract,
+                time_zone,
+            } => {
+                let DfValue::TimestampTz(timestamp) = non_null!(timestamp.eval(record)?) else {
+                    return Err(invalid_query_err!(
+                        "can't add an interval to a non-timestamp"
+                    ));
+                };
+                let interval = non_null!(interval.eval(record)?);
+                let interval = if *subtract {
+                    interval
+                        .as_interval()?
+                        .checked_neg()
+                        .ok_or_else(|| invalid_query_err!("interval out of range"))?
+                } else {
+                    *interval.as_interval()?
+                };
+                interval
+                    .add_to_timestamp_in(&timestamp, time_zone)
+                    .map(DfValue::TimestampTz)
+                    .ok_or_else(|| invalid_query_err!("timestamp out of range"))
+            }
+            BuiltinFunction::Datediff(arg1, arg2) => {
+                let date1 = NaiveDate::try_from(non_null!(&arg1.eval(record)?))?;
+                let date2 = NaiveDate::try_from(non_null!(&arg2.eval(record)?))?;
+                Ok(DfValue::Int(date1.signed_duration_since(date2).num_days()))
+            }
+            BuiltinFunction::Age(arg1, arg2) => {
+                let end = NaiveDateTime::try_from(non_null!(&arg1.eval(record)?))?;
+                let start = NaiveDateTime::try_from(non_null!(&arg2.eval(record)?))?;
+                Interval::age(end, start)
+                    .map(DfValue::from)
+                    .ok_or_else(|| invalid_query_err!("interval out of range"))
+            }
+            BuiltinFunction::RegexpLike {
+                string,
+                pattern,
+                flags,
+                flavor,
+                cache,
+            } => {
+                let string = non_null!(string.eval(record)?);
+                let pattern = non_null!(pattern.eval(record)?);
+                let Some(flags) = regex_flags(flags, *flavor, record)? else {
+                    return Ok(DfValue::None);
+                };
+
+                let regex = cache.get(<&str>::try_from(&pattern)?, *flavor, flags)?;
+                Ok(regex.is_match(<&str>::try_from(&string)?).into())
+            }
+            BuiltinFunction::RegexpReplace {
+                string,
+                pattern,
+                replacement,
+                position,
+                occurrence,
+                flags,
+                flavor,
+                cache,
+            } => {
+                let string = non_null!(string.eval(record)?);
+                let pattern = non_null!(pattern.eval(record)?);
+                let replacement = non_null!(replacement.eval(record)?);
+                let position = match position {
+                    Some(position) => i64::try_from(non_null!(position.eval(record)?))?,
+                    None => 1,
+                };
+                let occurrence = match occurrence {
+                    Some(occurrence) => i64::try_from(non_null!(occurrence.eval(record)?))?,
+                    None => 0,
+                };
+                let Some(flags) = regex_flags(flags, *flavor, record)? else {
+                    return Ok(DfValue::None);
+                };
+
+                let (s, collation) = str_and_collation(&string)?;
+                let regex = cache.get(<&str>::try_from(&pattern)?, *flavor, flags)?;
+                let replacement = translate_replacement(<&str>::try_from(&replacement)?, *flavor);
+                // MySQL replaces every match by default, whereas PostgreSQL only replaces the
+                // first one unless the `g` flag is passed
+                let occurrence = match flavor {
+                    RegexFlavor::MySql => usize::try_from(occurrence).unwrap_or(0),
+                    _ if flags.global => 0,
+                    _ => 1,
+                };
+                let start = usize::try_from(position)
+                    .ok()
+                    .and_then(|position| {
+                        s.char_indices()
+                            .map(|(i, _)| i)
+                            .chain(iter::once(s.len()))
+                            .nth(position.checked_sub(1)?)
+                    })
+                    .ok_or_else(|| {
+                        invalid_query_err!("Index out of bounds in regular expression search")
+                    })?;
+
+                let (prefix, s) = s.split_at(start);
+                let mut res = String::with_capacity(prefix.len() + s.len());
+                res.push_str(prefix);
+                let mut pos = 0;
+                for (n, caps) in regex.captures_iter(s).enumerate() {
+                    if occurrence != 0 && n + 1 != occurrence {
+                        continue;
+                    }
+                    let Some(m) = caps.get(0) else {
+                        continue;
+                    };
+                    res.push_str(&s[pos..m.start()]);
+                    caps.expand(&replacement, &mut res);
+                    pos = m.end();
+                    if occurrence != 0 {
+                        break;
+                    }
+                }
+                res.push_str(&s[pos..]);
+                Ok(DfValue::from_str_and_collation(&res, collation))
+            }
+            BuiltinFunction::RegexpMatch {
+                string,
+                pattern,
+                flags,
+                cache,
+            } => {
+                let string = non_null!(string.eval(record)?);
+                let pattern = non_null!(pattern.eval(record)?);
+                let Some(flags) = regex_flags(flags, RegexFlavor::Postgres, record)? else {
+                    return Ok(DfValue::None);
+                };
+                if flags.global {
+                    return Err(invalid_query_err!(
+                        "regexp_match() does not support the \"global\" option"
+                    ));
+                }
+
+                let regex = cache.get(<&str>::try_from(&pattern)?, RegexFlavor::Postgres, flags)?;
+                let Some(caps) = regex.captures(<&str>::try_from(&string)?) else {
+                    return Ok(DfValue::None);
+                };
+                // If the pattern has capture groups, the result contains the substrings matching
+                // each of them, otherwise it contains the substring matching the whole pattern
+                let groups = if caps.len() > 1 {
+                    Either::Left(caps.iter().skip(1))
+                } else {
+                    Either::Right(caps.iter())
+                };
+                Ok(groups
+                    .map(|m| m.map_or(DfValue::None, |m| m.as_str().into()))
+                    .collect::<Vec<_>>()
+                    .into())
+            }
         }
     }
 }
@@ -1274,7 +1944,7 @@ impl BuiltinFunction {
 #[cfg(test)]
 mod tests {
     use chrono::NaiveTime;
-    use chrono_tz::{Asia, Atlantic};
+    use chrono_tz::{America, Asia, Atlantic};
     use lazy_static::lazy_static;
     use nom_sql::parse_expr;
     use nom_sql::Dialect::*;
@@ -1286,7 +1956,7 @@ mod tests {
 
     use super::*;
     use crate::eval::tests::{eval_expr, try_eval_expr};
-    use crate::lower::tests::resolve_columns;
+    use crate::lower::tests::{in_time_zone, resolve_columns};
     use crate::utils::strings_to_array_expr;
     use crate::{Dialect, LowerContext};
 
@@ -2043,6 +2713,354 @@ mod tests {
         );
     }
 
+    #[test]
+    fn lower_and_upper() {
+        assert_eq!(eval_expr("lower('AbÇ')", MySQL), "abç".into());
+        assert_eq!(eval_expr("upper('AbÇ')", PostgreSQL), "ABÇ".into());
+        assert_eq!(eval_expr("lcase('AbC')", MySQL), "abc".into());
+        assert_eq!(eval_expr("ucase('AbC')", MySQL), "ABC".into());
+        assert_eq!(eval_expr("lower(123)", MySQL), "123".into());
+        assert_eq!(eval_expr("upper(null)", PostgreSQL), DfValue::None);
+    }
+
+    #[test]
+    fn length() {
+        assert_eq!(eval_expr("length('héllo')", MySQL), 6.into());
+        assert_eq!(eval_expr("length('héllo')", PostgreSQL), 5.into());
+        assert_eq!(eval_expr("char_length('héllo')", MySQL), 5.into());
+        assert_eq!(eval_expr("character_length('héllo')", PostgreSQL), 5.into());
+        assert_eq!(eval_expr("octet_length('héllo')", PostgreSQL), 6.into());
+        assert_eq!(eval_expr("length(null)", MySQL), DfValue::None);
+    }
+
+    #[test]
+    fn trim() {
+        assert_eq!(eval_expr("trim('  a b  ')", MySQL), "a b".into());
+        assert_eq!(eval_expr("ltrim('  a b  ')", MySQL), "a b  ".into());
+        assert_eq!(eval_expr("rtrim('  a b  ')", PostgreSQL), "  a b".into());
+        assert_eq!(eval_expr("trim('\ta ')", PostgreSQL), "\ta".into());
+        assert_eq!(eval_expr("btrim('xyaxy', 'yx')", PostgreSQL), "a".into());
+        assert_eq!(eval_expr("ltrim('xyaxy', 'yx')", PostgreSQL), "axy".into());
+        assert_eq!(eval_expr("rtrim('xyaxy', 'yx')", PostgreSQL), "xya".into());
+        assert_eq!(eval_expr("btrim('xyaxy', null)", PostgreSQL), DfValue::None);
+    }
+
+    #[test]
+    fn replace() {
+        assert_eq!(
+            eval_expr("replace('abcabc', 'b', 'XY')", MySQL),
+            "aXYcaXYc".into()
+        );
+        assert_eq!(
+            eval_expr("replace('abc', '', 'X')", PostgreSQL),
+            "abc".into()
+        );
+        assert_eq!(
+            eval_expr("replace('aaa', 'aa', 'b')", PostgreSQL),
+            "ba".into()
+        );
+        assert_eq!(eval_expr("replace('abc', 'B', 'X')", MySQL), "abc".into());
+        assert_eq!(eval_expr("replace('abc', null, 'X')", MySQL), DfValue::None);
+    }
+
+    #[test]
+    fn replace_citext() {
+        let citext = DfType::Text(Collation::Citext);
+        let expr = Expr::Call {
+            func: Box::new(BuiltinFunction::Replace(
+                Expr::Column {
+                    index: 0,
+                    ty: citext.clone(),
+                },
+                Expr::Literal {
+                    val: "b".into(),
+                    ty: DfType::DEFAULT_TEXT,
+                },
+                Expr::Literal {
+                    val: "x".into(),
+                    ty: DfType::DEFAULT_TEXT,
+                },
+            )),
+            ty: citext,
+        };
+        let res = expr
+            .eval(&[DfValue::from_str_and_collation("aBcb", Collation::Citext)])
+            .unwrap();
+        assert_eq!(
+            res.as_str_and_collation(),
+            Some(("axcx", Collation::Citext))
+        );
+    }
+
+    #[test]
+    fn lpad_and_rpad() {
+        assert_eq!(eval_expr("lpad('hi', 5, 'ab')", MySQL), "abahi".into());
+        assert_eq!(eval_expr("rpad('hi', 5, 'ab')", PostgreSQL), "hiaba".into());
+        assert_eq!(eval_expr("lpad('hello', 2, 'x')", MySQL), "he".into());
+        assert_eq!(eval_expr("rpad('hello', 2, 'x')", PostgreSQL), "he".into());
+        assert_eq!(eval_expr("lpad('hi', 4)", PostgreSQL), "  hi".into());
+        assert_eq!(eval_expr("lpad('hi', 5, '')", MySQL), DfValue::None);
+        assert_eq!(eval_expr("lpad('hi', 5, '')", PostgreSQL), "hi".into());
+        assert_eq!(eval_expr("rpad('hi', -1, 'x')", MySQL), DfValue::None);
+        assert_eq!(eval_expr("rpad('hi', -1, 'x')", PostgreSQL), "".into());
+    }
+
+    #[test]
+    fn left_and_right() {
+        assert_eq!(eval_expr("left('héllo', 2)", MySQL), "hé".into());
+        assert_eq!(eval_expr("right('héllo', 4)", PostgreSQL), "éllo".into());
+        assert_eq!(eval_expr("left('hello', 10)", MySQL), "hello".into());
+        assert_eq!(eval_expr("left('hello', -2)", MySQL), "".into());
+        assert_eq!(eval_expr("right('hello', -2)", MySQL), "".into());
+        assert_eq!(eval_expr("left('hello', -2)", PostgreSQL), "hel".into());
+        assert_eq!(eval_expr("right('hello', -2)", PostgreSQL), "llo".into());
+        assert_eq!(eval_expr("left('hello', -10)", PostgreSQL), "".into());
+    }
+
+    #[test]
+    fn reverse() {
+        assert_eq!(eval_expr("reverse('héllo')", MySQL), "olléh".into());
+        assert_eq!(eval_expr("reverse('')", PostgreSQL), "".into());
+    }
+
+    #[test]
+    fn locate() {
+        assert_eq!(eval_expr("locate('bar', 'foobarbar')", MySQL), 4.into());
+        assert_eq!(eval_expr("locate('bar', 'foobarbar', 5)", MySQL), 7.into());
+        assert_eq!(eval_expr("locate('xbar', 'foobar')", MySQL), 0.into());
+        assert_eq!(eval_expr("locate('bar', 'foobarbar', 0)", MySQL), 0.into());
+        assert_eq!(eval_expr("locate('', 'abc', 4)", MySQL), 4.into());
+        assert_eq!(eval_expr("locate('', 'abc', 5)", MySQL), 0.into());
+        assert_eq!(eval_expr("instr('foobar', 'bar')", MySQL), 4.into());
+        assert_eq!(eval_expr("position('b' in 'aéb')", MySQL), 3.into());
+        assert_eq!(eval_expr("position('b' in 'aéb')", PostgreSQL), 3.into());
+        assert_eq!(eval_expr("locate('A', 'abc')", MySQL), 0.into());
+        assert_eq!(eval_expr("instr('fooBAR', 'bar')", MySQL), 0.into());
+        assert_eq!(eval_expr("position('B' in 'abc')", PostgreSQL), 0.into());
+        assert_eq!(eval_expr("strpos('abc', 'B')", PostgreSQL), 0.into());
+        assert_eq!(eval_expr("strpos('abc', '')", PostgreSQL), 1.into());
+        assert_eq!(eval_expr("strpos('abc', null)", PostgreSQL), DfValue::None);
+    }
+
+    #[test]
+    fn locate_citext() {
+        let expr = Expr::Call {
+            func: Box::new(BuiltinFunction::Locate {
+                substring: Expr::Literal {
+                    val: "B".into(),
+                    ty: DfType::DEFAULT_TEXT,
+                },
+                string: Expr::Column {
+                    index: 0,
+                    ty: DfType::Text(Collation::Citext),
+                },
+                start: None,
+            }),
+            ty: DfType::Int,
+        };
+        let res = expr
+            .eval(&[DfValue::from_str_and_collation("abc", Collation::Citext)])
+            .unwrap();
+        assert_eq!(res, 2.into());
+        let res = expr
+            .eval(&[DfValue::from_str_and_collation("abc", Collation::Utf8)])
+            .unwrap();
+        assert_eq!(res, 0.into());
+    }
+
+    #[test]
+    fn initcap() {
+        assert_eq!(
+            eval_expr("initcap('hi THOMAS o''neil-smith')", PostgreSQL),
+            "Hi Thomas O'Neil-Smith".into()
+        );
+    }
+
+    #[test]
+    fn dialect_specific_string_functions() {
+        let lower = |expr: &str, dialect: nom_sql::Dialect| {
+            Expr::lower(
+                parse_expr(dialect, expr).unwrap(),
+                match dialect {
+                    PostgreSQL => Dialect::DEFAULT_POSTGRESQL,
+                    MySQL => Dialect::DEFAULT_MYSQL,
+                },
+                &numbered_columns(),
+            )
+        };
+
+        lower("lcase('a')", PostgreSQL).unwrap_err();
+        lower("locate('a', 'b')", PostgreSQL).unwrap_err();
+        lower("initcap('a')", MySQL).unwrap_err();
+        lower("strpos('a', 'b')", MySQL).unwrap_err();
+        lower("ltrim('a', 'b')", MySQL).unwrap_err();
+        // MySQL requires the fill string for `lpad` and `rpad`
+        lower("lpad('a', 2)", MySQL).unwrap_err();
+    }
+
+    #[test]
+    fn abs() {
+        assert_eq!(eval_expr("abs(-5)", MySQL), 5.into());
+        assert_eq!(eval_expr("abs(-5)", PostgreSQL), 5.into());
+        assert_eq!(eval_expr("abs(-1.5)", MySQL), DfValue::Double(1.5));
+        assert_eq!(eval_expr("abs(null)", MySQL), DfValue::None);
+
+        let expr = parse_and_lower("abs(c0)", PostgreSQL);
+        assert_eq!(
+            expr.eval::<DfValue>(&[DfValue::from(Decimal::new(-15, 1))])
+                .unwrap(),
+            DfValue::from(Decimal::new(15, 1))
+        );
+        expr.eval::<DfValue>(&[DfValue::Int(i64::MIN)]).unwrap_err();
+    }
+
+    #[test]
+    fn ceil_and_floor() {
+        assert_eq!(eval_expr("ceil(1.5)", MySQL), DfValue::Double(2.0));
+        assert_eq!(
+            eval_expr("ceiling(-1.5)", PostgreSQL),
+            DfValue::Double(-1.0)
+        );
+        assert_eq!(eval_expr("floor(-1.5)", MySQL), DfValue::Double(-2.0));
+        assert_eq!(eval_expr("floor(3)", MySQL), 3.into());
+        assert_eq!(eval_expr("floor(3)", PostgreSQL), DfValue::Double(3.0));
+
+        let expr = Expr::lower(
+            parse_expr(MySQL, "ceil(c0)").unwrap(),
+            Dialect::DEFAULT_MYSQL,
+            &resolve_columns(|_| Ok((0, DfType::Numeric { prec: 5, scale: 2 }))),
+        )
+        .unwrap();
+        assert_eq!(*expr.ty(), DfType::BigInt);
+        assert_eq!(
+            expr.eval::<DfValue>(&[DfValue::from(Decimal::new(-12345, 2))])
+                .unwrap(),
+            DfValue::Int(-123)
+        );
+    }
+
+    #[test]
+    fn trunc() {
+        assert_eq!(eval_expr("truncate(1.999, 1)", MySQL), DfValue::Double(1.9));
+        assert_eq!(
+            eval_expr("truncate(-1.999, 1)", MySQL),
+            DfValue::Double(-1.9)
+        );
+        assert_eq!(eval_expr("truncate(122, -2)", MySQL), 100.into());
+        assert_eq!(eval_expr("truncate(-122, -2)", MySQL), (-100).into());
+        assert_eq!(eval_expr("truncate(122, -100)", MySQL), 0.into());
+        assert_eq!(eval_expr("trunc(-1.7)", PostgreSQL), DfValue::Double(-1.0));
+        assert_eq!(
+            eval_expr("trunc(1.2345, 2)", PostgreSQL),
+            DfValue::from(Decimal::new(123, 2))
+        );
+
+        let expr = parse_and_lower("truncate(c0, -1)", MySQL);
+        assert_eq!(
+            expr.eval::<DfValue>(&[DfValue::from(Decimal::new(12345, 2))])
+                .unwrap(),
+            DfValue::from(Decimal::new(120, 0))
+        );
+    }
+
+    #[test]
+    fn sign() {
+        assert_eq!(eval_expr("sign(-3)", MySQL), (-1).into());
+        assert_eq!(eval_expr("sign(0)", MySQL), 0.into());
+        assert_eq!(eval_expr("sign(2.5)", MySQL), 1.into());
+        assert_eq!(eval_expr("sign(-2.5)", PostgreSQL), DfValue::Double(-1.0));
+    }
+
+    #[test]
+    fn power() {
+        assert_eq!(eval_expr("power(2, 10)", MySQL), DfValue::Double(1024.0));
+        assert_eq!(eval_expr("pow(2, -1)", MySQL), DfValue::Double(0.5));
+        assert_eq!(eval_expr("power(4, 0.5)", PostgreSQL), DfValue::Double(2.0));
+        try_eval_expr("power(10, 1000)", MySQL).unwrap_err();
+        try_eval_expr("power(0, -1)", PostgreSQL).unwrap_err();
+        try_eval_expr("power(-8, 0.5)", PostgreSQL).unwrap_err();
+    }
+
+    #[test]
+    fn sqrt_and_exp() {
+        assert_eq!(eval_expr("sqrt(16)", MySQL), DfValue::Double(4.0));
+        assert_eq!(eval_expr("sqrt(-1)", MySQL), DfValue::None);
+        try_eval_expr("sqrt(-1)", PostgreSQL).unwrap_err();
+        assert_eq!(eval_expr("exp(0)", MySQL), DfValue::Double(1.0));
+        try_eval_expr("exp(1000)", PostgreSQL).unwrap_err();
+    }
+
+    #[test]
+    fn log() {
+        assert_eq!(eval_expr("ln(1)", MySQL), DfValue::Double(0.0));
+        assert_eq!(eval_expr("log(2, 8)", MySQL), DfValue::Double(3.0));
+        assert_eq!(eval_expr("log(1)", MySQL), DfValue::Double(0.0));
+        assert_eq!(eval_expr("log(100)", PostgreSQL), DfValue::Double(2.0));
+        assert_eq!(eval_expr("ln(0)", MySQL), DfValue::None);
+        assert_eq!(eval_expr("log(1, 8)", MySQL), DfValue::None);
+        assert_eq!(eval_expr("log(0.5, 8)", MySQL), DfValue::None);
+        try_eval_expr("ln(0)", PostgreSQL).unwrap_err();
+        try_eval_expr("log(-1)", PostgreSQL).unwrap_err();
+    }
+
+    #[test]
+    fn modulo() {
+        assert_eq!(eval_expr("7 % 3", MySQL), 1.into());
+        assert_eq!(eval_expr("-7 % 3", PostgreSQL), (-1).into());
+        assert_eq!(eval_expr("mod(7, 3)", MySQL), 1.into());
+        // Literals with a decimal point are exact values in MySQL
+        assert_eq!(
+            eval_expr("mod(7.5, 2)", MySQL),
+            DfValue::from(Decimal::new(15, 1))
+        );
+        assert_eq!(
+            eval_expr("2 % 0.75", MySQL),
+            DfValue::from(Decimal::new(50, 2))
+        );
+        assert_eq!(eval_expr("7 % 0", MySQL), DfValue::None);
+        assert_eq!(eval_expr("mod(7.5, 0)", MySQL), DfValue::None);
+        try_eval_expr("7 % 0", PostgreSQL).unwrap_err();
+        try_eval_expr("mod(7, 0)", PostgreSQL).unwrap_err();
+        assert_eq!(eval_expr("null % 0", PostgreSQL), DfValue::None);
+
+        for (expr, dialect, expr_dialect) in [
+            ("c0 % -1", PostgreSQL, Dialect::DEFAULT_POSTGRESQL),
+            ("mod(c0, -1)", MySQL, Dialect::DEFAULT_MYSQL),
+        ] {
+            let expr = Expr::lower(
+                parse_expr(dialect, expr).unwrap(),
+                expr_dialect,
+                &resolve_columns(|_| Ok((0, DfType::BigInt))),
+            )
+            .unwrap();
+            assert_eq!(
+                expr.eval::<DfValue>(&[DfValue::Int(i64::MIN)]).unwrap(),
+                DfValue::Int(0)
+            );
+        }
+    }
+
+    #[test]
+    fn dialect_specific_math_functions() {
+        let lower = |expr: &str, dialect: nom_sql::Dialect| {
+            Expr::lower(
+                parse_expr(dialect, expr).unwrap(),
+                match dialect {
+                    PostgreSQL => Dialect::DEFAULT_POSTGRESQL,
+                    MySQL => Dialect::DEFAULT_MYSQL,
+                },
+                &numbered_columns(),
+            )
+        };
+
+        lower("truncate(1.5, 0)", PostgreSQL).unwrap_err();
+        lower("pow(2, 2)", PostgreSQL).unwrap_err();
+        lower("trunc(1.5)", MySQL).unwrap_err();
+        // MySQL requires the number of decimal places for `truncate`
+        lower("truncate(1.5)", MySQL).unwrap_err();
+        lower("mod(1)", MySQL).unwrap_err();
+    }
+
     #[track_caller]
     fn date_format(time: &str, fmt: &str) -> DfValue {
         lazy_static! {
@@ -3335,4 +4353,346 @@ mod tests {
         test_with_null_string("{null,1,2,3,null,5}", "*,1,2,3,*,5");
         test_with_null_string("{{1,2},{3,4},{null,5}}", "1,2,3,4,*,5");
     }
+
+    #[test]
+    fn date_add_and_date_sub() {
+        let eval = |expr| eval_expr(expr, MySQL).to_string();
+        assert_eq!(
+            eval("date_add(cast('2020-01-31' as date), interval 1 month)"),
+            "2020-02-29"
+        );
+        assert_eq!(
+            eval("date_sub(cast('2020-03-01' as date), interval 1 day)"),
+            "2020-02-29"
+        );
+        assert_eq!(
+            eval("adddate(cast('2020-01-01' as date), 31)"),
+            "2020-02-01"
+        );
+        assert_eq!(
+            eval("interval 1 day + cast('2020-01-01' as date)"),
+            "2020-01-02"
+        );
+        assert_eq!(
+            eval("cast('2020-01-01 12:00:00' as datetime) + interval '1:30' hour_minute"),
+            "2020-01-01 13:30:00"
+        );
+        assert_eq!(
+            eval("date_sub(cast('2020-01-01' as date), interval 90 minute)"),
+            "2019-12-31 22:30:00"
+        );
+        assert_eq!(
+            eval_expr("date_add(null, interval 1 day)", MySQL),
+            DfValue::None
+        );
+    }
+
+    #[test]
+    fn date_add_output_type() {
+        let subsecond_digits = crate::Dialect::DEFAULT_MYSQL.default_subsecond_digits();
+        assert_eq!(
+            *parse_and_lower("date_add(cast(c0 as date), interval 1 day)", MySQL).ty(),
+            DfType::Date
+        );
+        assert_eq!(
+            *parse_and_lower("date_add(cast(c0 as date), interval 1 hour)", MySQL).ty(),
+            DfType::DateTime { subsecond_digits }
+        );
+        assert_eq!(
+            *parse_and_lower("c0 - interval 1 day", MySQL).ty(),
+            DfType::DateTime { subsecond_digits }
+        );
+    }
+
+    #[test]
+    fn datediff() {
+        assert_eq!(
+            eval_expr("datediff('2020-03-01', '2020-02-01')", MySQL),
+            DfValue::Int(29)
+        );
+        assert_eq!(
+            eval_expr("datediff('2020-01-01', '2020-02-01')", MySQL),
+            DfValue::Int(-31)
+        );
+        assert_eq!(
+            eval_expr("datediff(null, '2020-02-01')", MySQL),
+            DfValue::None
+        );
+    }
+
+    #[test]
+    fn postgres_interval_arithmetic() {
+        let eval = |expr| eval_expr(expr, PostgreSQL).to_string();
+        let timestamp = |y, m, d, h| {
+            DfValue::from(
+                NaiveDate::from_ymd_opt(y, m, d)
+                    .unwrap()
+                    .and_hms_opt(h, 0, 0)
+                    .unwrap(),
+            )
+        };
+        assert_eq!(
+            eval_expr(
+                "cast('2020-01-31' as date) + interval '1 month'",
+                PostgreSQL
+            ),
+            timestamp(2020, 2, 29, 0)
+        );
+        assert_eq!(
+            eval_expr(
+                "cast('2020-01-01 00:00:00' as timestamp) - interval '1 day 2 hours'",
+                PostgreSQL
+            ),
+            timestamp(2019, 12, 30, 22)
+        );
+        assert_eq!(
+            eval("interval '1 day' + interval '1 hour'"),
+            "1 day 01:00:00"
+        );
+        assert_eq!(
+            eval(
+                "cast('2020-03-01 12:00:00' as timestamp) - cast('2020-02-01 00:00:00' as timestamp)"
+            ),
+            "29 days 12:00:00"
+        );
+        assert_eq!(
+            eval_expr(
+                "cast('2020-03-01' as date) - cast('2020-02-01' as date)",
+                PostgreSQL
+            ),
+            DfValue::Int(29)
+        );
+        assert_eq!(eval("interval '1 hour' * 3"), "03:00:00");
+        assert_eq!(eval("2 * interval '1 day 1 hour'"), "2 days 02:00:00");
+        assert_eq!(eval("interval '1 month' * 1.5"), "1 mon 15 days");
+        assert_eq!(eval("interval '1 month' / 2"), "15 days");
+        assert_eq!(eval("-interval '1 day 1 hour'"), "-1 days -01:00:00");
+        try_eval_expr("interval '1 day' / 0", PostgreSQL).unwrap_err();
+
+        let lower = |expr| {
+            Expr::lower(
+                parse_expr(PostgreSQL, expr).unwrap(),
+                crate::Dialect::DEFAULT_POSTGRESQL,
+                &numbered_columns(),
+            )
+        };
+        lower("2 / interval '1 day'").unwrap_err();
+        lower("interval '1 day' * interval '1 day'").unwrap_err();
+        lower("interval '1 day' % 2").unwrap_err();
+    }
+
+    #[test]
+    fn postgres_timestamptz_interval_arithmetic_across_dst() {
+        let eval = |expr| {
+            Expr::lower(
+                parse_expr(PostgreSQL, expr).unwrap(),
+                crate::Dialect::DEFAULT_POSTGRESQL,
+                &in_time_zone(America::New_York),
+            )
+            .unwrap()
+            .eval::<DfValue>(&[])
+            .unwrap()
+        };
+
+        // The clocks went forward an hour at 2am on 2024-03-10, so a day is only 23 hours long
+        assert_eq!(
+            eval("cast('2024-03-09 12:00:00-05' as timestamptz) + interval '1 day'"),
+            eval("cast('2024-03-10 12:00:00-04' as timestamptz)")
+        );
+        assert_eq!(
+            eval("interval '1 day' + cast('2024-03-09 12:00:00-05' as timestamptz)"),
+            eval("cast('2024-03-10 12:00:00-04' as timestamptz)")
+        );
+        assert_eq!(
+            eval("cast('2024-03-10 12:00:00-04' as timestamptz) - interval '1 day'"),
+            eval("cast('2024-03-09 12:00:00-05' as timestamptz)")
+        );
+        // ...but the time component of an interval is always added as an absolute duration
+        assert_eq!(
+            eval("cast('2024-03-10 01:30:00-05' as timestamptz) + interval '1 hour'"),
+            eval("cast('2024-03-10 03:30:00-04' as timestamptz)")
+        );
+        // A local time skipped by the transition uses the offset from before it
+        assert_eq!(
+            eval("cast('2024-03-09 02:30:00-05' as timestamptz) + interval '1 day'"),
+            eval("cast('2024-03-10 03:30:00-04' as timestamptz)")
+        );
+        assert_eq!(
+            eval("cast(null as timestamptz) + interval '1 day'"),
+            DfValue::None
+        );
+
+        // Without a known time zone, intervals are added in the timestamp's own offset
+        assert_eq!(
+            eval_expr(
+                "cast('2024-03-09 12:00:00-05' as timestamptz) + interval '1 day'",
+                PostgreSQL
+            ),
+            eval("cast('2024-03-10 13:00:00-04' as timestamptz)")
+        );
+    }
+
+    #[test]
+    fn age() {
+        assert_eq!(
+            eval_expr("age('2001-04-10', '1957-06-13')", PostgreSQL).to_string(),
+            "43 years 9 mons 27 days"
+        );
+        assert_eq!(
+            *parse_and_lower("age(c0, c1)", PostgreSQL).ty(),
+            DfType::Interval
+        );
+    }
+
+    #[test]
+    fn regex_match_operators() {
+        assert_eq!(eval_expr("'abc' REGEXP 'b+'", MySQL), true.into());
+        assert_eq!(eval_expr("'abc' RLIKE '^b'", MySQL), false.into());
+        assert_eq!(eval_expr("'abc' NOT REGEXP '^b'", MySQL), true.into());
+        assert_eq!(eval_expr("'abc' REGEXP null", MySQL), DfValue::None);
+        assert_eq!(eval_expr("'ABC' REGEXP 'b'", MySQL), true.into());
+        assert_eq!(eval_expr("'ABC' NOT RLIKE 'b'", MySQL), false.into());
+        assert_eq!(eval_expr("'abc' ~ 'b+'", PostgreSQL), true.into());
+        assert_eq!(eval_expr("'abc' ~ 'B+'", PostgreSQL), false.into());
+        assert_eq!(eval_expr("'abc' ~* 'B+'", PostgreSQL), true.into());
+        assert_eq!(eval_expr("'abc' !~ 'B+'", PostgreSQL), true.into());
+        assert_eq!(eval_expr("'abc' !~* 'B+'", PostgreSQL), false.into());
+        assert_eq!(eval_expr("null ~ 'a'", PostgreSQL), DfValue::None);
+        assert_eq!(
+            eval_expr("'abc' SIMILAR TO '%(b|d)%'", PostgreSQL),
+            true.into()
+        );
+        assert_eq!(
+            eval_expr("'abc' SIMILAR TO '(b|c)%'", PostgreSQL),
+            false.into()
+        );
+        assert_eq!(
+            eval_expr("'abc' NOT SIMILAR TO 'a_c'", PostgreSQL),
+            false.into()
+        );
+        try_eval_expr("'abc' ~ '('", PostgreSQL).unwrap_err();
+    }
+
+    #[test]
+    fn regex_match_with_columns() {
+        let expr = parse_and_lower("c0 ~ c1", PostgreSQL);
+        let eval =
+            |s: &str, pattern: &str| expr.eval::<DfValue>(&[s.into(), pattern.into()]).unwrap();
+        assert_eq!(eval("abc", "^a"), true.into());
+        assert_eq!(eval("bcd", "^a"), false.into());
+        assert_eq!(eval("bcd", "^b"), true.into());
+        assert_eq!(eval("abc", "^b"), false.into());
+    }
+
+    #[test]
+    fn regexp_like() {
+        assert_eq!(eval_expr("regexp_like('abc', 'B')", MySQL), true.into());
+        assert_eq!(
+            eval_expr("regexp_like('abc', 'B', 'c')", MySQL),
+            false.into()
+        );
+        assert_eq!(
+            eval_expr("regexp_like('abc', 'B', 'ci')", MySQL),
+            true.into()
+        );
+        assert_eq!(
+            eval_expr("regexp_like('abc', 'B', 'ic')", MySQL),
+            false.into()
+        );
+        assert_eq!(
+            eval_expr("regexp_like('abc', 'b', null)", MySQL),
+            DfValue::None
+        );
+        try_eval_expr("regexp_like('abc', 'b', 'z')", MySQL).unwrap_err();
+    }
+
+    #[test]
+    fn regexp_replace() {
+        assert_eq!(
+            eval_expr("regexp_replace('a b c', '[a-z]', 'X')", MySQL),
+            "X X X".into()
+        );
+        assert_eq!(
+            eval_expr("regexp_replace('a b c', '[a-z]', 'X', 1, 2)", MySQL),
+            "a X c".into()
+        );
+        assert_eq!(
+            eval_expr("regexp_replace('a b c', '[a-z]', 'X', 3)", MySQL),
+            "a X X".into()
+        );
+        assert_eq!(
+            eval_expr(
+                "regexp_replace('a b c', '([a-z])', '<$1>', 1, 0, 'c')",
+                MySQL
+            ),
+            "<a> <b> <c>".into()
+        );
+        assert_eq!(
+            eval_expr("regexp_replace('a B c', '[a-z]', 'X')", MySQL),
+            "X X X".into()
+        );
+        assert_eq!(
+            eval_expr("regexp_replace('a B c', '[a-z]', 'X', 1, 0, 'c')", MySQL),
+            "X B X".into()
+        );
+        try_eval_expr("regexp_replace('abc', 'b', 'X', 5)", MySQL).unwrap_err();
+        assert_eq!(
+            eval_expr("regexp_replace('a b c', '[a-z]', 'X')", PostgreSQL),
+            "X b c".into()
+        );
+        assert_eq!(
+            eval_expr("regexp_replace('a b c', '[A-Z]', 'X', 'gi')", PostgreSQL),
+            "X X X".into()
+        );
+        assert_eq!(
+            eval_expr(r"regexp_replace('foobar', 'o(.)', E'[\\1\\&]')", PostgreSQL),
+            "f[oob]ar".into()
+        );
+        assert_eq!(
+            eval_expr("regexp_replace(null, 'a', 'b')", PostgreSQL),
+            DfValue::None
+        );
+    }
+
+    #[test]
+    fn regexp_match() {
+        assert_eq!(
+            eval_expr("regexp_match('foobarbequebaz', 'bar.*que')", PostgreSQL),
+            DfValue::from(vec![DfValue::from("barbeque")])
+        );
+        assert_eq!(
+            eval_expr("regexp_match('foobarbequebaz', '(bar)(beque)')", PostgreSQL),
+            DfValue::from(vec![DfValue::from("bar"), DfValue::from("beque")])
+        );
+        assert_eq!(
+            eval_expr("regexp_match('foobar', 'baz')", PostgreSQL),
+            DfValue::None
+        );
+        try_eval_expr("regexp_match('foobar', 'o', 'g')", PostgreSQL).unwrap_err();
+        assert_eq!(
+            *parse_and_lower("regexp_match(c0, c1)", PostgreSQL).ty(),
+            DfType::Array(Box::new(DfType::DEFAULT_TEXT))
+        );
+    }
+
+    #[test]
+    fn dialect_specific_regex_functions() {
+        let lower = |expr: &str, dialect: nom_sql::Dialect| {
+            Expr::lower(
+                parse_expr(dialect, expr).unwrap(),
+                match dialect {
+                    PostgreSQL => Dialect::DEFAULT_POSTGRESQL,
+                    MySQL => Dialect::DEFAULT_MYSQL,
+                },
+                &numbered_columns(),
+            )
+        };
+
+        lower("regexp_like('a', 'b')", PostgreSQL).unwrap_err();
+        lower("regexp_match('a', 'b')", MySQL).unwrap_err();
+        lower("'a' REGEXP 'b'", PostgreSQL).unwrap_err();
+        lower("'a' SIMILAR TO 'b'", MySQL).unwrap_err();
+        // PostgreSQL's `regexp_replace` doesn't take a position or occurrence
+        lower("regexp_replace('a', 'b', 'c', 1, 1)", PostgreSQL).unwrap_err();
+    }
 }
@@ -6,13 +6,15 @@ pub mod like;
 mod lower;
 mod promotion;
 mod reader_processing;
+pub mod regexp;
 pub mod utils;
 
 use std::fmt::{self, Display, Formatter};
 
+use chrono_tz::Tz;
 pub use eval::builtins::DateTruncPrecision;
 use itertools::Itertools;
-use nom_sql::TimestampField;
+use nom_sql::{IntervalUnit, TimestampField};
 pub use readyset_data::Dialect;
 use readyset_data::{DfType, DfValue};
 use serde::{Deserialize, Serialize};
@@ -22,8 +24,9 @@ pub use crate::binary_operator::*;
 pub use crate::lower::LowerContext;
 pub use crate::reader_processing::{
     PostLookup, PostLookupAggregate, PostLookupAggregateFunction, PostLookupAggregates,
-    PreInsertion, ReaderProcessing,
+    PostLookupLeftJoinFilter, PreInsertion, ReaderProcessing,
 };
+use crate::regexp::{RegexCache, RegexFlavor};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum BuiltinFunction {
@@ -87,6 +90,168 @@ pub enum BuiltinFunction {
     /// [`split_part`](https://www.postgresql.org/docs/current/functions-string.html)
     SplitPart(Expr, Expr, Expr),
 
+    /// `lower`:
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/string-functions.html#function_lower)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-string.html)
+    Lower(Expr),
+
+    /// `upper`:
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/string-functions.html#function_upper)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-string.html)
+    Upper(Expr),
+
+    /// The length of a string in characters (`char_length`, and `length` in PostgreSQL):
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/string-functions.html#function_char-length)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-string.html)
+    CharLength(Expr),
+
+    /// The length of a string in bytes (`octet_length`, and `length` in MySQL):
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/string-functions.html#function_length)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-string.html)
+    OctetLength(Expr),
+
+    /// `trim`, `ltrim`, `rtrim`, and `btrim`:
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/string-functions.html#function_trim)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-string.html)
+    ///
+    /// The optional second argument is the set of characters to remove (only supported by
+    /// PostgreSQL), which defaults to a single space.
+    Trim(Expr, Option<Expr>, TrimSide),
+
+    /// `replace`:
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/string-functions.html#function_replace)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-string.html)
+    Replace(Expr, Expr, Expr),
+
+    /// `lpad` and `rpad`:
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/string-functions.html#function_lpad)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-string.html)
+    Pad {
+        string: Expr,
+        length: Expr,
+        /// The string to pad with, which defaults to a single space (only PostgreSQL allows this
+        /// to be omitted)
+        fill: Option<Expr>,
+        /// `true` for `lpad`, `false` for `rpad`
+        pad_left: bool,
+        /// MySQL returns NULL if the length is negative or if the string has to be padded with an
+        /// empty fill string, whereas PostgreSQL returns an empty or unpadded string
+        null_on_invalid: bool,
+    },
+
+    /// `left` and `right`:
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/string-functions.html#function_left)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-string.html)
+    LeftOrRight {
+        string: Expr,
+        length: Expr,
+        /// `true` for `left`, `false` for `right`
+        left: bool,
+        /// PostgreSQL treats a negative length as the number of characters to drop from the
+        /// other end of the string, whereas MySQL returns an empty string
+        negative_from_end: bool,
+    },
+
+    /// `reverse`:
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/string-functions.html#function_reverse)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-string.html)
+    Reverse(Expr),
+
+    /// The 1-based position of the first occurrence of a substring in a string, or 0 if it does
+    /// not occur (`position`, `locate` and `instr` in MySQL, `position` and `strpos` in
+    /// PostgreSQL):
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/string-functions.html#function_locate)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-string.html)
+    Locate {
+        substring: Expr,
+        string: Expr,
+        /// The position to start searching at, which defaults to 1 (only supported by MySQL)
+        start: Option<Expr>,
+    },
+
+    /// [`initcap`](https://www.postgresql.org/docs/current/functions-string.html)
+    Initcap(Expr),
+
+    /// `abs`:
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/mathematical-functions.html#function_abs)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-math.html)
+    Abs(Expr),
+
+    /// `ceil` and `ceiling`:
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/mathematical-functions.html#function_ceil)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-math.html)
+    Ceil(Expr),
+
+    /// `floor`:
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/mathematical-functions.html#function_floor)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-math.html)
+    Floor(Expr),
+
+    /// `trunc` in PostgreSQL and `truncate` in MySQL:
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/mathematical-functions.html#function_truncate)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-math.html)
+    ///
+    /// The optional second argument is the number of decimal places to keep, which defaults to 0
+    /// (only PostgreSQL allows this to be omitted)
+    Trunc(Expr, Option<Expr>),
+
+    /// `sign`:
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/mathematical-functions.html#function_sign)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-math.html)
+    Sign(Expr),
+
+    /// `power` (and `pow` in MySQL):
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/mathematical-functions.html#function_power)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-math.html)
+    Power(Expr, Expr),
+
+    /// `sqrt`:
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/mathematical-functions.html#function_sqrt)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-math.html)
+    Sqrt {
+        arg: Expr,
+        /// MySQL returns NULL for a negative argument, whereas PostgreSQL returns an error
+        null_on_invalid: bool,
+    },
+
+    /// `exp`:
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/mathematical-functions.html#function_exp)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-math.html)
+    Exp(Expr),
+
+    /// Logarithms (`ln` and `log`):
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/mathematical-functions.html#function_log)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-math.html)
+    Log {
+        arg: Expr,
+        /// The base of the logarithm, or `None` for the natural logarithm. Note that the
+        /// single-argument form of `log` is the natural logarithm in MySQL, but the base-10
+        /// logarithm in PostgreSQL.
+        base: Option<Expr>,
+        /// MySQL returns NULL if the argument is not positive or the base is invalid, whereas
+        /// PostgreSQL returns an error
+        null_on_invalid: bool,
+    },
+
     /// `greatest`:
     ///
     /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/comparison-operators.html#function_greatest)
@@ -117,6 +282,77 @@ pub enum BuiltinFunction {
 
     /// [`date_trunc`](https://www.postgresql.org/docs/current/functions-datetime.html#FUNCTIONS-DATETIME-TRUNC)
     Extract(TimestampField, Expr),
+
+    /// MySQL's [`INTERVAL expr unit`](https://dev.mysql.com/doc/refman/8.0/en/expressions.html#temporal-intervals)
+    /// temporal interval expression.
+    ///
+    /// The value is evaluated as text, and if it can't be interpreted as an interval in the given
+    /// unit the result is NULL.
+    Interval { value: Expr, unit: IntervalUnit },
+
+    /// PostgreSQL's `timestamptz + interval` and `timestamptz - interval` operators, adding the
+    /// months and days of the interval in the local time of `time_zone`.
+    ///
+    /// Only used if the upstream database's time zone is known; otherwise intervals are added to
+    /// timestamps in the timestamp's own UTC offset.
+    TimestampTzAddInterval {
+        timestamp: Expr,
+        interval: Expr,
+        subtract: bool,
+        time_zone: Tz,
+    },
+
+    /// [`datediff`](https://dev.mysql.com/doc/refman/8.0/en/date-and-time-functions.html#function_datediff)
+    Datediff(Expr, Expr),
+
+    /// [`age`](https://www.postgresql.org/docs/current/functions-datetime.html)
+    Age(Expr, Expr),
+
+    /// Whether a string matches a regular expression: `regexp_like` and the `REGEXP` operator in
+    /// MySQL, and the `~`, `~*` and `SIMILAR TO` operators in PostgreSQL:
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/regexp.html#function_regexp-like)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-matching.html)
+    RegexpLike {
+        string: Expr,
+        pattern: Expr,
+        /// The flags (or "match type") to match with
+        flags: Option<Expr>,
+        flavor: RegexFlavor,
+        #[serde(skip)]
+        cache: RegexCache,
+    },
+
+    /// `regexp_replace`:
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/regexp.html#function_regexp-replace)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-matching.html)
+    RegexpReplace {
+        string: Expr,
+        pattern: Expr,
+        replacement: Expr,
+        /// The 1-based character position to start searching at (only supported by MySQL)
+        position: Option<Expr>,
+        /// Which match to replace, or 0 to replace all of them (only supported by MySQL, which
+        /// defaults to replacing all matches, whereas PostgreSQL only replaces the first match
+        /// unless the `g` flag is passed)
+        occurrence: Option<Expr>,
+        /// The flags (or "match type") to match with
+        flags: Option<Expr>,
+        flavor: RegexFlavor,
+        #[serde(skip)]
+        cache: RegexCache,
+    },
+
+    /// [`regexp_match`](https://www.postgresql.org/docs/current/functions-matching.html)
+    RegexpMatch {
+        string: Expr,
+        pattern: Expr,
+        /// The flags to match with
+        flags: Option<Expr>,
+        #[serde(skip)]
+        cache: RegexCache,
+    },
 }
 
 impl BuiltinFunction {
@@ -147,11 +383,48 @@ impl BuiltinFunction {
             Concat { .. } => "concat",
             Substring { .. } => "substring",
             SplitPart { .. } => "split_part",
+            Lower { .. } => "lower",
+            Upper { .. } => "upper",
+            CharLength { .. } => "char_length",
+            OctetLength { .. } => "octet_length",
+            Trim(_, _, TrimSide::Both) => "trim",
+            Trim(_, _, TrimSide::Leading) => "ltrim",
+            Trim(_, _, TrimSide::Trailing) => "rtrim",
+            Replace { .. } => "replace",
+            Pad { pad_left: true, .. } => "lpad",
+            Pad {
+                pad_left: false, ..
+            } => "rpad",
+            LeftOrRight { left: true, .. } => "left",
+            LeftOrRight { left: false, .. } => "right",
+            Reverse { .. } => "reverse",
+            Locate { .. } => "locate",
+            Initcap { .. } => "initcap",
+            Abs { .. } => "abs",
+            Ceil { .. } => "ceil",
+            Floor { .. } => "floor",
+            Trunc { .. } => "trunc",
+            Sign { .. } => "sign",
+            Power { .. } => "power",
+            Sqrt { .. } => "sqrt",
+            Exp { .. } => "exp",
+            Log { base: None, .. } => "ln",
+            Log { base: Some(_), .. } => "log",
             Greatest { .. } => "greatest",
             Least { .. } => "least",
             ArrayToString { .. } => "array_to_string",
             DateTrunc { .. } => "date_trunc",
             Extract { .. } => "extract",
+            Interval { .. } => "interval",
+            TimestampTzAddInterval {
+                subtract: false, ..
+            } => "timestamptz_pl_interval",
+            TimestampTzAddInterval { subtract: true, .. } => "timestamptz_mi_interval",
+            Datediff { .. } => "datediff",
+            Age { .. } => "age",
+            RegexpLike { .. } => "regexp_like",
+            RegexpReplace { .. } => "regexp_replace",
+            RegexpMatch { .. } => "regexp_match",
         }
     }
 }
@@ -237,6 +510,65 @@ impl Display for BuiltinFunction {
                 write!(f, ")")
             }
             SplitPart(string, delimiter, field) => write!(f, "({string}, {delimiter}, {field})"),
+            Lower(arg)
+            | Upper(arg)
+            | CharLength(arg)
+            | OctetLength(arg)
+            | Reverse(arg)
+            | Initcap(arg)
+            | Abs(arg)
+            | Ceil(arg)
+            | Floor(arg)
+            | Sign(arg)
+            | Exp(arg)
+            | Sqrt { arg, .. } => write!(f, "({arg})"),
+            Trim(string, chars, _) => {
+                write!(f, "({string}")?;
+                if let Some(chars) = chars {
+                    write!(f, ", {chars}")?;
+                }
+                write!(f, ")")
+            }
+            Replace(string, from, to) => write!(f, "({string}, {from}, {to})"),
+            Pad {
+                string,
+                length,
+                fill,
+                ..
+            } => {
+                write!(f, "({string}, {length}")?;
+                if let Some(fill) = fill {
+                    write!(f, ", {fill}")?;
+                }
+                write!(f, ")")
+            }
+            LeftOrRight { string, length, .. } => write!(f, "({string}, {length})"),
+            Locate {
+                substring,
+                string,
+                start,
+            } => {
+                write!(f, "({substring}, {string}")?;
+                if let Some(start) = start {
+                    write!(f, ", {start}")?;
+                }
+                write!(f, ")")
+            }
+            Trunc(arg, places) => {
+                write!(f, "({arg}")?;
+                if let Some(places) = places {
+                    write!(f, ", {places}")?;
+                }
+                write!(f, ")")
+            }
+            Power(base, exponent) => write!(f, "({base}, {exponent})"),
+            Log { arg, base, .. } => {
+                write!(f, "(")?;
+                if let Some(base) = base {
+                    write!(f, "{base}, ")?;
+                }
+                write!(f, "{arg})")
+            }
             Greatest { args, .. } | Least { args, .. } => {
                 write!(f, "({})", args.iter().join(", "))
             }
@@ -253,10 +585,61 @@ impl Display for BuiltinFunction {
             Extract(field, expr) => {
                 write!(f, "({} FROM {})", field, expr)
             }
+            Interval { value, unit } => write!(f, "({value} {unit})"),
+            TimestampTzAddInterval {
+                timestamp,
+                interval,
+                ..
+            } => write!(f, "({timestamp}, {interval})"),
+            Datediff(arg1, arg2) | Age(arg1, arg2) => write!(f, "({arg1}, {arg2})"),
+            RegexpLike {
+                string,
+                pattern,
+                flags,
+                ..
+            }
+            | RegexpMatch {
+                string,
+                pattern,
+                flags,
+                ..
+            } => {
+                write!(f, "({string}, {pattern}")?;
+                if let Some(flags) = flags {
+                    write!(f, ", {flags}")?;
+                }
+                write!(f, ")")
+            }
+            RegexpReplace {
+                string,
+                pattern,
+                replacement,
+                position,
+                occurrence,
+                flags,
+                ..
+            } => {
+                write!(f, "({string}, {pattern}, {replacement}")?;
+                for arg in [position, occurrence, flags].into_iter().flatten() {
+                    write!(f, ", {arg}")?;
+                }
+                write!(f, ")")
+            }
         }
     }
 }
 
+/// Which end(s) of a string [`BuiltinFunction::Trim`] removes characters from
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TrimSide {
+    /// `ltrim`
+    Leading,
+    /// `rtrim`
+    Trailing,
+    /// `trim` or `btrim`
+    Both,
+}
+
 /// Argument for [`BuiltinFunction::JsonbSet`] that differentiates between `jsonb_set` and
 /// `jsonb_set_lax` behavior.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
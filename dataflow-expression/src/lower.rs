@@ -1,20 +1,24 @@
 use std::{cmp, iter};
 
+use chrono_tz::Tz;
 use nom_sql::{
-    BinaryOperator as SqlBinaryOperator, Column, DialectDisplay, Expr as AstExpr, FunctionExpr,
-    InValue, Relation, UnaryOperator,
+    BinaryOperator as SqlBinaryOperator, Column, DialectDisplay, Double, Expr as AstExpr,
+    FunctionExpr, InValue, IntervalUnit, Literal, Relation, UnaryOperator,
 };
 use readyset_data::dialect::SqlEngine;
 use readyset_data::{Collation, DfType, DfValue};
 use readyset_errors::{
-    internal, internal_err, invalid_query, invalid_query_err, unsupported, ReadySetError,
-    ReadySetResult,
+    internal, internal_err, invalid_query, invalid_query_err, unsupported, unsupported_err,
+    ReadySetError, ReadySetResult,
 };
 use readyset_util::redacted::Sensitive;
+use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::Decimal;
 use vec1::Vec1;
 
+use crate::regexp::RegexFlavor;
 use crate::{
-    BinaryOperator, BuiltinFunction, CaseWhenBranch, Dialect, Expr, NullValueTreatmentArg,
+    BinaryOperator, BuiltinFunction, CaseWhenBranch, Dialect, Expr, NullValueTreatmentArg, TrimSide,
 };
 
 /// Context supplied to expression lowering to allow resolving references to objects within the
@@ -26,6 +30,12 @@ pub trait LowerContext: Clone {
 
     /// Look up a named custom type in the schema.
     fn resolve_type(&self, ty: Relation) -> Option<DfType>;
+
+    /// The time zone of the upstream database, if known, in which intervals are added to and
+    /// subtracted from `timestamptz` values in PostgreSQL.
+    fn time_zone(&self) -> Option<Tz> {
+        None
+    }
 }
 
 /// Unify the given list of types according to PostgreSQL's [type unification rules][pg-docs]
@@ -126,6 +136,85 @@ fn mysql_least_greatest_compare_as(arg_types: Vec<&DfType>) -> DfType {
     DfType::VarBinary(u16::MAX)
 }
 
+/// Returns the type that the string argument to a string function should be cast to, which is the
+/// argument's own type if it's already a text type
+fn string_arg_type(expr: &Expr) -> DfType {
+    if expr.ty().is_any_text() {
+        expr.ty().clone()
+    } else {
+        DfType::DEFAULT_TEXT
+    }
+}
+
+/// Returns the return type for a string function which may return a string longer than its
+/// argument of the given type, which keeps the collation of that argument
+fn unbounded_text_type(ty: &DfType) -> DfType {
+    match ty {
+        DfType::Text(collation) | DfType::VarChar(_, collation) | DfType::Char(_, collation) => {
+            DfType::Text(*collation)
+        }
+        _ => DfType::DEFAULT_TEXT,
+    }
+}
+
+/// If `op` is a regular expression match operator, returns the [`RegexFlavor`] it matches with,
+/// whether it always matches case-insensitively (rather than according to the flavor's default),
+/// and whether its result is negated
+fn regex_match_op(op: SqlBinaryOperator) -> Option<(RegexFlavor, bool, bool)> {
+    use SqlBinaryOperator::*;
+    match op {
+        Regexp => Some((RegexFlavor::MySql, false, false)),
+        NotRegexp => Some((RegexFlavor::MySql, false, true)),
+        Tilde => Some((RegexFlavor::Postgres, false, false)),
+        TildeAsterisk => Some((RegexFlavor::Postgres, true, false)),
+        ExclamationTilde => Some((RegexFlavor::Postgres, false, true)),
+        ExclamationTildeAsterisk => Some((RegexFlavor::Postgres, true, true)),
+        SimilarTo => Some((RegexFlavor::SimilarTo, false, false)),
+        NotSimilarTo => Some((RegexFlavor::SimilarTo, false, true)),
+        _ => None,
+    }
+}
+
+/// Returns the type that the argument to a math function which preserves exact values (such as
+/// `abs` or `ceil`) should be cast to, which is the argument's own type if it's already numeric
+fn exact_math_arg_type(ty: &DfType) -> DfType {
+    if ty.is_any_exact_number() || ty.is_any_float() || !ty.is_known() {
+        ty.clone()
+    } else {
+        DfType::Double
+    }
+}
+
+/// Returns the type that the arguments to a math function which computes an approximate value
+/// (such as `sqrt` or `power`) should be cast to, which is also the return type of the function.
+///
+/// MySQL always computes these functions with DOUBLE values, whereas PostgreSQL computes them with
+/// NUMERIC values if any of the arguments are NUMERIC
+fn approximate_math_type(arg_types: &[&DfType], dialect: Dialect) -> DfType {
+    match dialect.engine() {
+        SqlEngine::MySQL => DfType::Double,
+        SqlEngine::PostgreSQL => arg_types
+            .iter()
+            .find(|ty| ty.is_numeric())
+            .map(|ty| (*ty).clone())
+            .unwrap_or(DfType::Double),
+    }
+}
+
+/// Returns the type that the arguments to a math function which we can only compute with DOUBLE
+/// values (such as `sqrt` or `power`) should be cast to, which is also the return type of the
+/// function.
+///
+/// PostgreSQL computes these functions with NUMERIC values to a precision that depends on the
+/// arguments, which we can't reproduce with DOUBLE values, so NUMERIC arguments aren't supported
+fn double_math_type(name: &str, arg_types: &[&DfType], dialect: Dialect) -> ReadySetResult<DfType> {
+    let ty = approximate_math_type(arg_types, dialect);
+    if ty.is_numeric() {
+        unsupported!("Function {name} with NUMERIC arguments is not supported");
+    }
+    Ok(ty)
+}
+
 impl BuiltinFunction {
     pub(crate) fn from_name_and_args<A>(
         name: &str,
@@ -366,6 +455,54 @@ impl BuiltinFunction {
                     ty,
                 )
             }
+            "regexp_like" => (
+                Self::RegexpLike {
+                    string: cast(next_arg()?, DfType::DEFAULT_TEXT),
+                    pattern: cast(next_arg()?, DfType::DEFAULT_TEXT),
+                    flags: next_arg().ok().map(|arg| cast(arg, DfType::DEFAULT_TEXT)),
+                    flavor: RegexFlavor::MySql,
+                    cache: Default::default(),
+                },
+                DfType::Bool,
+            ),
+            "regexp_replace" => {
+                let string = next_arg()?;
+                let ty = unbounded_text_type(&string_arg_type(&string));
+                let pattern = cast(next_arg()?, DfType::DEFAULT_TEXT);
+                let replacement = cast(next_arg()?, DfType::DEFAULT_TEXT);
+                let func = match dialect.engine() {
+                    SqlEngine::MySQL => Self::RegexpReplace {
+                        string: cast(string, ty.clone()),
+                        pattern,
+                        replacement,
+                        position: next_arg().ok().map(|arg| cast(arg, DfType::BigInt)),
+                        occurrence: next_arg().ok().map(|arg| cast(arg, DfType::BigInt)),
+                        flags: next_arg().ok().map(|arg| cast(arg, DfType::DEFAULT_TEXT)),
+                        flavor: RegexFlavor::MySql,
+                        cache: Default::default(),
+                    },
+                    SqlEngine::PostgreSQL => Self::RegexpReplace {
+                        string: cast(string, ty.clone()),
+                        pattern,
+                        replacement,
+                        position: None,
+                        occurrence: None,
+                        flags: next_arg().ok().map(|arg| cast(arg, DfType::DEFAULT_TEXT)),
+                        flavor: RegexFlavor::Postgres,
+                        cache: Default::default(),
+                    },
+                };
+                (func, ty)
+            }
+            "regexp_match" => (
+                Self::RegexpMatch {
+                    string: cast(next_arg()?, DfType::DEFAULT_TEXT),
+                    pattern: cast(next_arg()?, DfType::DEFAULT_TEXT),
+                    flags: next_arg().ok().map(|arg| cast(arg, DfType::DEFAULT_TEXT)),
+                    cache: Default::default(),
+                },
+                DfType::Array(Box::new(DfType::DEFAULT_TEXT)),
+            ),
             "split_part" => (
                 Self::SplitPart(
                     cast(next_arg()?, DfType::DEFAULT_TEXT),
@@ -374,6 +511,320 @@ impl BuiltinFunction {
                 ),
                 DfType::DEFAULT_TEXT,
             ),
+            // String functions which only exist in one of the two dialects
+            "lcase" | "ucase" | "locate" | "instr" if dialect.engine() != SqlEngine::MySQL => {
+                unsupported!("Function {name} does not exist")
+            }
+            "btrim" | "strpos" | "initcap" if dialect.engine() != SqlEngine::PostgreSQL => {
+                unsupported!("Function {name} does not exist")
+            }
+            // Math functions which only exist in one of the two dialects
+            "truncate" | "pow" if dialect.engine() != SqlEngine::MySQL => {
+                unsupported!("Function {name} does not exist")
+            }
+            "trunc" if dialect.engine() != SqlEngine::PostgreSQL => {
+                unsupported!("Function {name} does not exist")
+            }
+            // Regular expression functions which only exist in one of the two dialects
+            "regexp_like" if dialect.engine() != SqlEngine::MySQL => {
+                unsupported!("Function {name} does not exist")
+            }
+            "regexp_match" if dialect.engine() != SqlEngine::PostgreSQL => {
+                unsupported!("Function {name} does not exist")
+            }
+            "lower" | "lcase" => {
+                let string = next_arg()?;
+                let ty = string_arg_type(&string);
+                (Self::Lower(cast(string, ty.clone())), ty)
+            }
+            "upper" | "ucase" => {
+                let string = next_arg()?;
+                let ty = string_arg_type(&string);
+                (Self::Upper(cast(string, ty.clone())), ty)
+            }
+            "reverse" => {
+                let string = next_arg()?;
+                let ty = string_arg_type(&string);
+                (Self::Reverse(cast(string, ty.clone())), ty)
+            }
+            "initcap" => {
+                let string = next_arg()?;
+                let ty = string_arg_type(&string);
+                (Self::Initcap(cast(string, ty.clone())), ty)
+            }
+            "char_length" | "character_length" => (
+                Self::CharLength(cast(next_arg()?, DfType::DEFAULT_TEXT)),
+                DfType::Int,
+            ),
+            "octet_length" => (
+                Self::OctetLength(cast(next_arg()?, DfType::DEFAULT_TEXT)),
+                DfType::Int,
+            ),
+            "length" => {
+                let string = cast(next_arg()?, DfType::DEFAULT_TEXT);
+                (
+                    match dialect.engine() {
+                        // MySQL's LENGTH is the length in bytes...
+                        SqlEngine::MySQL => Self::OctetLength(string),
+                        // ...whereas PostgreSQL's is the length in characters
+                        SqlEngine::PostgreSQL => Self::CharLength(string),
+                    },
+                    DfType::Int,
+                )
+            }
+            "trim" | "btrim" | "ltrim" | "rtrim" => {
+                let side = if name.eq_ignore_ascii_case("ltrim") {
+                    TrimSide::Leading
+                } else if name.eq_ignore_ascii_case("rtrim") {
+                    TrimSide::Trailing
+                } else {
+                    TrimSide::Both
+                };
+                let string = next_arg()?;
+                let ty = string_arg_type(&string);
+                let chars = match dialect.engine() {
+                    // MySQL can only trim strings other than spaces with the
+                    // `TRIM(remstr FROM str)` syntax, which we don't support
+                    SqlEngine::MySQL => None,
+                    SqlEngine::PostgreSQL => {
+                        next_arg().ok().map(|arg| cast(arg, DfType::DEFAULT_TEXT))
+                    }
+                };
+                (Self::Trim(cast(string, ty.clone()), chars, side), ty)
+            }
+            "replace" => {
+                let string = next_arg()?;
+                let ty = string_arg_type(&string);
+                (
+                    Self::Replace(
+                        cast(string, ty.clone()),
+                        cast(next_arg()?, DfType::DEFAULT_TEXT),
+                        cast(next_arg()?, DfType::DEFAULT_TEXT),
+                    ),
+                    unbounded_text_type(&ty),
+                )
+            }
+            "lpad" | "rpad" => {
+                let string = next_arg()?;
+                let ty = string_arg_type(&string);
+                let length = cast(next_arg()?, DfType::BigInt);
+                let fill = match dialect.engine() {
+                    SqlEngine::MySQL => Some(next_arg()?),
+                    SqlEngine::PostgreSQL => next_arg().ok(),
+                };
+                (
+                    Self::Pad {
+                        string: cast(string, ty.clone()),
+                        length,
+                        fill: fill.map(|arg| cast(arg, DfType::DEFAULT_TEXT)),
+                        pad_left: name.eq_ignore_ascii_case("lpad"),
+                        null_on_invalid: dialect.engine() == SqlEngine::MySQL,
+                    },
+                    unbounded_text_type(&ty),
+                )
+            }
+            "left" | "right" => {
+                let string = next_arg()?;
+                let ty = string_arg_type(&string);
+                (
+                    Self::LeftOrRight {
+                        string: cast(string, ty.clone()),
+                        length: cast(next_arg()?, DfType::BigInt),
+                        left: name.eq_ignore_ascii_case("left"),
+                        negative_from_end: dialect.engine() == SqlEngine::PostgreSQL,
+                    },
+                    ty,
+                )
+            }
+            "locate" => {
+                let substring = cast(next_arg()?, DfType::DEFAULT_TEXT);
+                let string = next_arg()?;
+                let ty = string_arg_type(&string);
+                (
+                    Self::Locate {
+                        substring,
+                        string: cast(string, ty),
+                        start: next_arg().ok().map(|arg| cast(arg, DfType::BigInt)),
+                    },
+                    DfType::Int,
+                )
+            }
+            "instr" | "strpos" => {
+                let string = next_arg()?;
+                let ty = string_arg_type(&string);
+                (
+                    Self::Locate {
+                        string: cast(string, ty),
+                        substring: cast(next_arg()?, DfType::DEFAULT_TEXT),
+                        start: None,
+                    },
+                    DfType::Int,
+                )
+            }
+            "abs" => {
+                let arg = next_arg()?;
+                let mut ty = exact_math_arg_type(arg.ty());
+                if dialect.engine() == SqlEngine::MySQL {
+                    // MySQL widens the result of `abs` to INT, BIGINT or DOUBLE
+                    ty = match ty {
+                        DfType::BigInt
+                        | DfType::UnsignedBigInt
+                        | DfType::Numeric { .. }
+                        | DfType::Unknown => ty,
+                        _ if ty.is_any_unsigned_int() => DfType::UnsignedInt,
+                        _ if ty.is_any_int() => DfType::Int,
+                        _ => DfType::Double,
+                    };
+                }
+                (Self::Abs(cast(arg, ty.clone())), ty)
+            }
+            "ceil" | "ceiling" | "floor" => {
+                let arg = next_arg()?;
+                let (arg_ty, ty) = match dialect.engine() {
+                    SqlEngine::MySQL => {
+                        let arg_ty = exact_math_arg_type(arg.ty());
+                        // Exact-value arguments give exact-value results, which are BIGINT if
+                        // they fit
+                        let ty = match arg_ty {
+                            _ if arg_ty.is_any_unsigned_int() => DfType::UnsignedBigInt,
+                            _ if arg_ty.is_any_int() => DfType::BigInt,
+                            DfType::Numeric { prec, scale } => {
+                                let digits = prec.saturating_sub(u16::from(scale)) + 1;
+                                if digits <= 18 {
+                                    DfType::BigInt
+                                } else {
+                                    DfType::Numeric {
+                                        prec: digits,
+                                        scale: 0,
+                                    }
+                                }
+                            }
+                            DfType::Unknown => DfType::Unknown,
+                            _ => DfType::Double,
+                        };
+                        (arg_ty, ty)
+                    }
+                    SqlEngine::PostgreSQL => {
+                        let ty = approximate_math_type(&[arg.ty()], dialect);
+                        (ty.clone(), ty)
+                    }
+                };
+                let arg = cast(arg, arg_ty);
+                (
+                    if name.eq_ignore_ascii_case("floor") {
+                        Self::Floor(arg)
+                    } else {
+                        Self::Ceil(arg)
+                    },
+                    ty,
+                )
+            }
+            "truncate" => {
+                let arg = next_arg()?;
+                let arg_ty = exact_math_arg_type(arg.ty());
+                let arg = cast(arg, arg_ty);
+                let places = cast(next_arg()?, DfType::BigInt);
+                let ty = type_for_round(&arg, &places);
+                (Self::Trunc(arg, Some(places)), ty)
+            }
+            "trunc" => {
+                let arg = next_arg()?;
+                match next_arg() {
+                    // PostgreSQL can only truncate to a number of decimal places with NUMERIC
+                    // values
+                    Ok(places) => {
+                        let ty = if arg.ty().is_numeric() {
+                            arg.ty().clone()
+                        } else {
+                            DfType::DEFAULT_NUMERIC
+                        };
+                        (
+                            Self::Trunc(cast(arg, ty.clone()), Some(cast(places, DfType::BigInt))),
+                            ty,
+                        )
+                    }
+                    Err(_) => {
+                        let ty = approximate_math_type(&[arg.ty()], dialect);
+                        (Self::Trunc(cast(arg, ty.clone()), None), ty)
+                    }
+                }
+            }
+            "sign" => {
+                let arg = next_arg()?;
+                let (arg_ty, ty) = match dialect.engine() {
+                    SqlEngine::MySQL => (exact_math_arg_type(arg.ty()), DfType::Int),
+                    SqlEngine::PostgreSQL => {
+                        let ty = approximate_math_type(&[arg.ty()], dialect);
+                        (ty.clone(), ty)
+                    }
+                };
+                (Self::Sign(cast(arg, arg_ty)), ty)
+            }
+            "power" | "pow" => {
+                let base = next_arg()?;
+                let exponent = next_arg()?;
+                let ty = double_math_type(name, &[base.ty(), exponent.ty()], dialect)?;
+                (
+                    Self::Power(cast(base, ty.clone()), cast(exponent, ty.clone())),
+                    ty,
+                )
+            }
+            "sqrt" => {
+                let arg = next_arg()?;
+                let ty = double_math_type(name, &[arg.ty()], dialect)?;
+                (
+                    Self::Sqrt {
+                        arg: cast(arg, ty.clone()),
+                        null_on_invalid: dialect.engine() == SqlEngine::MySQL,
+                    },
+                    ty,
+                )
+            }
+            "exp" => {
+                let arg = next_arg()?;
+                let ty = double_math_type(name, &[arg.ty()], dialect)?;
+                (Self::Exp(cast(arg, ty.clone())), ty)
+            }
+            "ln" | "log" => {
+                let first = next_arg()?;
+                let (base, arg) = match next_arg() {
+                    // PostgreSQL only has a NUMERIC version of `log` with a base
+                    Ok(_)
+                        if name.eq_ignore_ascii_case("log")
+                            && dialect.engine() == SqlEngine::PostgreSQL =>
+                    {
+                        unsupported!("Function {name} with a base is not supported")
+                    }
+                    Ok(arg) if name.eq_ignore_ascii_case("log") => (Some(first), arg),
+                    Ok(_) => return Err(arity_error()),
+                    Err(_) => match dialect.engine() {
+                        // The single-argument form of `log` is the base-10 logarithm in
+                        // PostgreSQL...
+                        SqlEngine::PostgreSQL if name.eq_ignore_ascii_case("log") => (
+                            Some(Expr::Literal {
+                                val: DfValue::from(10),
+                                ty: DfType::BigInt,
+                            }),
+                            first,
+                        ),
+                        // ...but the natural logarithm in MySQL
+                        _ => (None, first),
+                    },
+                };
+                let ty = double_math_type(
+                    name,
+                    &[arg.ty(), base.as_ref().map_or(&DfType::Unknown, |b| b.ty())],
+                    dialect,
+                )?;
+                (
+                    Self::Log {
+                        arg: cast(arg, ty.clone()),
+                        base: base.map(|base| cast(base, ty.clone())),
+                        null_on_invalid: dialect.engine() == SqlEngine::MySQL,
+                    },
+                    ty,
+                )
+            }
             "greatest" | "least" => {
                 // The type inference rules for GREATEST and LEAST are the same, so this block
                 // covers both then dispatches for the actual function construction at the end
@@ -448,6 +899,29 @@ impl BuiltinFunction {
 
                 (Self::DateTrunc(precision, source), ret_type)
             }
+            "datediff" => (
+                Self::Datediff(
+                    try_cast(next_arg()?, DfType::Date),
+                    try_cast(next_arg()?, DfType::Date),
+                ),
+                DfType::BigInt,
+            ),
+            "age" => {
+                let subsecond_digits = dialect.default_subsecond_digits();
+                let end = next_arg()?;
+                // The single-argument form of `age` subtracts from the current date, which we
+                // can't cache
+                let start = next_arg().map_err(|_| {
+                    unsupported_err!("The single-argument form of age() is not supported")
+                })?;
+                (
+                    Self::Age(
+                        cast(end, DfType::Timestamp { subsecond_digits }),
+                        cast(start, DfType::Timestamp { subsecond_digits }),
+                    ),
+                    DfType::Interval,
+                )
+            }
             _ => unsupported!("Function {name} does not exist"),
         };
 
@@ -586,6 +1060,26 @@ fn mysql_numerical_type_cvt(left: &DfType, right: &DfType) -> Option<DfType> {
     }
 }
 
+/// MySQL treats numeric literals containing a decimal point as exact DECIMAL values, but we parse
+/// them as [`Literal::Double`]. Converts such a literal to the equivalent [`Literal::Numeric`],
+/// and returns any other expression unchanged.
+///
+/// <https://dev.mysql.com/doc/refman/8.0/en/precision-math-numbers.html>
+fn mysql_exact_value_literal(expr: AstExpr) -> AstExpr {
+    match expr {
+        AstExpr::Literal(Literal::Double(Double { value, precision })) => {
+            match Decimal::from_f64(value) {
+                Some(mut d) => {
+                    d.rescale(precision.into());
+                    AstExpr::Literal(Literal::Numeric(d.mantissa(), d.scale()))
+                }
+                None => AstExpr::Literal(Literal::Double(Double { value, precision })),
+            }
+        }
+        expr => expr,
+    }
+}
+
 /// <https://dev.mysql.com/doc/refman/8.0/en/type-conversion.html>
 fn mysql_type_conversion(left_ty: &DfType, right_ty: &DfType) -> DfType {
     if left_ty.is_bool() && right_ty.is_bool() {
@@ -629,6 +1123,10 @@ impl BinaryOperator {
             HashSubtract => Ok((Self::JsonSubtractPath, false)),
             Multiply => Ok((Self::Multiply, false)),
             Divide => Ok((Self::Divide, false)),
+            Modulo => match dialect.engine() {
+                SqlEngine::MySQL => Ok((Self::Modulo, false)),
+                SqlEngine::PostgreSQL => Ok((Self::StrictModulo, false)),
+            },
             Like => Ok((Self::Like, false)),
             NotLike => Ok((Self::Like, true)),
             ILike => Ok((Self::ILike, false)),
@@ -665,6 +1163,18 @@ impl BinaryOperator {
             HashArrow2 => Ok((Self::JsonKeyPathExtractText, false)),
             AtArrowRight => Ok((Self::JsonContains, false)),
             AtArrowLeft => Ok((Self::JsonContainedIn, false)),
+            // Regular expression matches are lowered to calls to `BuiltinFunction::RegexpLike` by
+            // `Expr::lower`, so the only way to get here is via ANY, SOME or ALL
+            Regexp
+            | NotRegexp
+            | Tilde
+            | TildeAsterisk
+            | ExclamationTilde
+            | ExclamationTildeAsterisk
+            | SimilarTo
+            | NotSimilarTo => {
+                unsupported!("'{op}' is not supported with ANY, SOME or ALL")
+            }
         }
     }
 
@@ -704,8 +1214,8 @@ impl BinaryOperator {
 
         use BinaryOperator::*;
         match self {
-            Add | Subtract | Multiply | Divide | And | Or | Greater | GreaterOrEqual | Less
-            | LessOrEqual | Is => match dialect.engine() {
+            Add | Subtract | Multiply | Divide | Modulo | StrictModulo | And | Or | Greater
+            | GreaterOrEqual | Less | LessOrEqual | Is => match dialect.engine() {
                 SqlEngine::PostgreSQL => Ok((None, None)),
                 SqlEngine::MySQL => {
                     let ty = mysql_type_conversion(left_type, right_type);
@@ -817,9 +1327,106 @@ impl BinaryOperator {
             | Self::JsonKeyExtractText
             | Self::JsonKeyPathExtractText => Ok(DfType::DEFAULT_TEXT),
 
+            // MySQL computes the remainder in the type that both operands are converted to
+            Self::Modulo => Ok(mysql_type_conversion(left_type, right_type)),
+
             _ => Ok(left_type.clone()),
         }
     }
+
+    /// If this operator is adding or subtracting dates, times, or intervals, or scaling an
+    /// interval by a number, returns its output type along with the types that the lhs and rhs
+    /// need to be coerced to before evaluation.
+    ///
+    /// Returns `None` for any other operator or operand types, in which case the usual rules in
+    /// [`Self::output_type`] and [`Self::argument_type_coercions`] apply.
+    fn temporal_arithmetic_types(
+        &self,
+        dialect: Dialect,
+        left: &Expr,
+        right: &Expr,
+    ) -> Option<(DfType, Option<DfType>, Option<DfType>)> {
+        let (left_type, right_type) = (left.ty(), right.ty());
+        let is_number = |ty: &DfType| ty.is_any_exact_number() || ty.is_any_float();
+
+        match self {
+            Self::Add | Self::Subtract => {}
+            // PostgreSQL can multiply intervals by a number on either side, and divide them by one
+            Self::Multiply | Self::Divide if dialect.engine() == SqlEngine::PostgreSQL => {
+                let scales_interval = match (left_type, right_type) {
+                    (DfType::Interval, ty) => is_number(ty),
+                    (ty, DfType::Interval) => *self == Self::Multiply && is_number(ty),
+                    _ => false,
+                };
+                return scales_interval.then_some((DfType::Interval, None, None));
+            }
+            _ => return None,
+        }
+
+        let subsecond_digits = dialect.default_subsecond_digits();
+
+        // Intervals can be added on either side, but only subtracted from the rhs
+        let (value_type, interval, interval_on_left) = if *right_type == DfType::Interval {
+            (left_type, right, false)
+        } else if *self == Self::Add && *left_type == DfType::Interval {
+            (right_type, left, true)
+        } else {
+            return match dialect.engine() {
+                SqlEngine::PostgreSQL if *self == Self::Subtract => match (left_type, right_type) {
+                    (DfType::Date, DfType::Date) => Some((DfType::Int, None, None)),
+                    (DfType::Time { .. }, DfType::Time { .. }) => {
+                        Some((DfType::Interval, None, None))
+                    }
+                    (
+                        DfType::Date | DfType::Timestamp { .. } | DfType::TimestampTz { .. },
+                        DfType::Date | DfType::Timestamp { .. } | DfType::TimestampTz { .. },
+                    ) => Some((DfType::Interval, None, None)),
+                    _ => None,
+                },
+                _ => None,
+            };
+        };
+
+        let (out, value_coercion) = match (dialect.engine(), value_type) {
+            (SqlEngine::PostgreSQL, DfType::Interval) => (DfType::Interval, None),
+            (SqlEngine::PostgreSQL, DfType::Date) => {
+                let ty = DfType::Timestamp { subsecond_digits };
+                (ty.clone(), Some(ty))
+            }
+            (SqlEngine::PostgreSQL, _) if value_type.is_date_and_time() => {
+                (value_type.clone(), None)
+            }
+            (_, DfType::Time { .. }) => (value_type.clone(), None),
+            (SqlEngine::PostgreSQL, _) | (SqlEngine::MySQL, DfType::Interval) => return None,
+            (SqlEngine::MySQL, DfType::Date) => {
+                // Adding an interval to a date gives a date if the interval only has date parts,
+                // and a datetime otherwise
+                let unit = match interval {
+                    Expr::Call { func, .. } => match **func {
+                        BuiltinFunction::Interval { unit, .. } => Some(unit),
+                        _ => None,
+                    },
+                    _ => None,
+                };
+                if unit.is_some_and(|unit| unit.is_date_unit()) {
+                    (DfType::Date, None)
+                } else {
+                    (DfType::DateTime { subsecond_digits }, None)
+                }
+            }
+            (SqlEngine::MySQL, _) if value_type.is_date_and_time() => (value_type.clone(), None),
+            (SqlEngine::MySQL, _) => {
+                let ty = DfType::DateTime { subsecond_digits };
+                (ty.clone(), Some(ty))
+            }
+        };
+
+        if interval_on_left {
+            Some((out, None, value_coercion))
+        } else {
+            Some((out, value_coercion, None))
+        }
+    }
 }
 
 impl Expr {
@@ -833,6 +1440,8 @@ impl Expr {
     /// - Function calls being resolved to built-in functions, and arities checked
     /// - Desugaring x IN (y, z, ...) to `x = y OR x = z OR ...` and x NOT IN (y, z, ...) to `x != y
     ///   AND x != z AND ...`
+    /// - Desugaring `mod(x, y)` to `x % y`
+    /// - Desugaring `date_add(x, y)` and `date_sub(x, y)` to `x + y` and `x - y`
     /// - Replacing unary negation with `(expr * -1)`
     /// - Replacing unary NOT with `(expr != 1)`
     /// - Inferring the type of each node in the expression AST.
@@ -841,6 +1450,57 @@ impl Expr {
         C: LowerContext,
     {
         match expr {
+            AstExpr::Call(FunctionExpr::Call {
+                name: fname,
+                arguments,
+            }) if fname.eq_ignore_ascii_case("mod") => {
+                let Ok([lhs, rhs]) = <[AstExpr; 2]>::try_from(arguments) else {
+                    return Err(ReadySetError::ArityError(fname.to_string()));
+                };
+                Self::lower(
+                    AstExpr::BinaryOp {
+                        lhs: Box::new(lhs),
+                        op: SqlBinaryOperator::Modulo,
+                        rhs: Box::new(rhs),
+                    },
+                    dialect,
+                    context,
+                )
+            }
+            AstExpr::Call(FunctionExpr::Call {
+                name: fname,
+                arguments,
+            }) if ["date_add", "adddate", "date_sub", "subdate"]
+                .iter()
+                .any(|f| fname.eq_ignore_ascii_case(f)) =>
+            {
+                let Ok([date, interval]) = <[AstExpr; 2]>::try_from(arguments) else {
+                    return Err(ReadySetError::ArityError(fname.to_string()));
+                };
+                let op = if fname.to_lowercase().contains("add") {
+                    SqlBinaryOperator::Add
+                } else {
+                    SqlBinaryOperator::Subtract
+                };
+                // `ADDDATE(expr, days)` and `SUBDATE(expr, days)` take a number of days in place of
+                // an interval
+                let interval = match interval {
+                    interval @ AstExpr::Interval { .. } => interval,
+                    days => AstExpr::Interval {
+                        value: Box::new(days),
+                        unit: IntervalUnit::Day,
+                    },
+                };
+                Self::lower(
+                    AstExpr::BinaryOp {
+                        lhs: Box::new(date),
+                        op,
+                        rhs: Box::new(interval),
+                    },
+                    dialect,
+                    context,
+                )
+            }
             AstExpr::Call(FunctionExpr::Call {
                 name: fname,
                 arguments,
@@ -899,9 +1559,23 @@ impl Expr {
                 let (index, ty) = context.resolve_column(col)?;
                 Ok(Self::Column { index, ty })
             }
+            AstExpr::BinaryOp { lhs, op, rhs } if regex_match_op(op).is_some() => {
+                Self::lower_regex_match(*lhs, op, *rhs, dialect, context)
+            }
             AstExpr::BinaryOp { lhs, op, rhs } => {
-                let mut left = Box::new(Self::lower(*lhs, dialect, context)?);
-                let mut right = Box::new(Self::lower(*rhs, dialect, context)?);
+                // The result type of `%` in MySQL depends on whether its operands are exact
+                // values
+                let (lhs, rhs) =
+                    if op == SqlBinaryOperator::Modulo && dialect.engine() == SqlEngine::MySQL {
+                        (
+                            mysql_exact_value_literal(*lhs),
+                            mysql_exact_value_literal(*rhs),
+                        )
+                    } else {
+                        (*lhs, *rhs)
+                    };
+                let mut left = Box::new(Self::lower(lhs, dialect, context)?);
+                let mut right = Box::new(Self::lower(rhs, dialect, context)?);
                 let (op, negated) =
                     BinaryOperator::from_sql_op(op, dialect, left.ty(), right.ty())?;
 
@@ -912,34 +1586,84 @@ impl Expr {
                     unsupported!("'{op}' operator not implemented yet for MySQL");
                 }
 
-                let out = op.output_type(dialect, left.ty(), right.ty())?;
-                let (left_coerce_target, right_coerce_target) =
-                    op.argument_type_coercions(left.ty(), right.ty(), dialect)?;
+                let (out, left_coerce_target, right_coerce_target) =
+                    match op.temporal_arithmetic_types(dialect, &left, &right) {
+                        Some(types) => types,
+                        None if matches!(
+                            op,
+                            BinaryOperator::Add
+                                | BinaryOperator::Subtract
+                                | BinaryOperator::Multiply
+                                | BinaryOperator::Divide
+                                | BinaryOperator::Modulo
+                        ) && (*left.ty() == DfType::Interval
+                            || *right.ty() == DfType::Interval) =>
+                        {
+                            unsupported!(
+                                "Unsupported arithmetic between {} and {}",
+                                left.ty(),
+                                right.ty()
+                            )
+                        }
+                        None => {
+                            let out = op.output_type(dialect, left.ty(), right.ty())?;
+                            let (left_coerce_target, right_coerce_target) =
+                                op.argument_type_coercions(left.ty(), right.ty(), dialect)?;
+                            (
+                                out.clone(),
+                                left_coerce_target.filter(|ty| *ty != out),
+                                right_coerce_target.filter(|ty| *ty != out),
+                            )
+                        }
+                    };
 
                 if let Some(ty) = left_coerce_target {
-                    if ty != out {
-                        left = Box::new(Self::Cast {
-                            expr: left,
-                            ty,
-                            null_on_failure: false,
-                        });
-                    }
+                    left = Box::new(Self::Cast {
+                        expr: left,
+                        ty,
+                        null_on_failure: false,
+                    });
                 }
                 if let Some(ty) = right_coerce_target {
-                    if ty != out {
-                        right = Box::new(Self::Cast {
-                            expr: right,
-                            ty,
-                            null_on_failure: false,
-                        });
-                    }
+                    right = Box::new(Self::Cast {
+                        expr: right,
+                        ty,
+                        null_on_failure: false,
+                    });
                 }
 
-                let op_node = Self::Op {
-                    op,
-                    left,
-                    right,
-                    ty: out,
+                let op_node = match context.time_zone() {
+                    // PostgreSQL adds the months and days of an interval to a timestamptz in the
+                    // local time of the session's time zone, so it matters whether there was a
+                    // daylight saving time transition in between
+                    Some(time_zone)
+                        if dialect.engine() == SqlEngine::PostgreSQL
+                            && matches!(op, BinaryOperator::Add | BinaryOperator::Subtract)
+                            && matches!(out, DfType::TimestampTz { .. })
+                            && (*left.ty() == DfType::Interval
+                                || *right.ty() == DfType::Interval) =>
+                    {
+                        let (timestamp, interval) = if *left.ty() == DfType::Interval {
+                            (*right, *left)
+                        } else {
+                            (*left, *right)
+                        };
+                        Self::Call {
+                            func: Box::new(BuiltinFunction::TimestampTzAddInterval {
+                                timestamp,
+                                interval,
+                                subtract: op == BinaryOperator::Subtract,
+                                time_zone,
+                            }),
+                            ty: out,
+                        }
+                    }
+                    _ => Self::Op {
+                        op,
+                        left,
+                        right,
+                        ty: out,
+                    },
                 };
                 if negated {
                     Ok(Self::Not {
@@ -1123,8 +1847,26 @@ impl Expr {
                     ty,
                 })
             }
+            AstExpr::Interval { value, unit } => {
+                let value = Self::lower(*value, dialect, context)?;
+                let value = if value.ty().is_any_text() {
+                    value
+                } else {
+                    Self::Cast {
+                        expr: Box::new(value),
+                        ty: DfType::DEFAULT_TEXT,
+                        null_on_failure: false,
+                    }
+                };
+                Ok(Self::Call {
+                    func: Box::new(BuiltinFunction::Interval { value, unit }),
+                    ty: DfType::Interval,
+                })
+            }
             AstExpr::Row { .. } => unsupported!("Row expressions not currently supported"),
-            AstExpr::Exists(_) => unsupported!("EXISTS not currently supported"),
+            AstExpr::Exists(_) => unsupported!(
+                "EXISTS is only supported in the WHERE clause or at the top level of the SELECT list"
+            ),
             AstExpr::Variable(_) => unsupported!("Variables not currently supported"),
             AstExpr::Between { .. } | AstExpr::NestedSelect(_) | AstExpr::In { .. } => {
                 internal!(
@@ -1135,6 +1877,62 @@ impl Expr {
         }
     }
 
+    fn lower_regex_match<C>(
+        lhs: AstExpr,
+        op: SqlBinaryOperator,
+        rhs: AstExpr,
+        dialect: Dialect,
+        context: &C,
+    ) -> ReadySetResult<Expr>
+    where
+        C: LowerContext,
+    {
+        let Some((flavor, case_insensitive, negated)) = regex_match_op(op) else {
+            internal!("'{op}' is not a regular expression match operator")
+        };
+        let engine = match flavor {
+            RegexFlavor::MySql => SqlEngine::MySQL,
+            RegexFlavor::Postgres | RegexFlavor::SimilarTo => SqlEngine::PostgreSQL,
+        };
+        if dialect.engine() != engine {
+            unsupported!("'{op}' not available in {}", dialect.engine())
+        }
+
+        let to_text = |expr: Expr| {
+            if expr.ty().is_any_text() {
+                expr
+            } else {
+                Expr::Cast {
+                    expr: Box::new(expr),
+                    ty: DfType::DEFAULT_TEXT,
+                    null_on_failure: false,
+                }
+            }
+        };
+        let call = Self::Call {
+            func: Box::new(BuiltinFunction::RegexpLike {
+                string: to_text(Self::lower(lhs, dialect, context)?),
+                pattern: to_text(Self::lower(rhs, dialect, context)?),
+                flags: case_insensitive.then(|| Self::Literal {
+                    val: "i".into(),
+                    ty: DfType::DEFAULT_TEXT,
+                }),
+                flavor,
+                cache: Default::default(),
+            }),
+            ty: DfType::Bool,
+        };
+
+        if negated {
+            Ok(Self::Not {
+                expr: Box::new(call),
+                ty: DfType::Bool,
+            })
+        } else {
+            Ok(call)
+        }
+    }
+
     fn lower_op_any_or_all<C>(
         lhs: AstExpr,
         op: SqlBinaryOperator,
@@ -1236,7 +2034,7 @@ impl Expr {
 #[cfg(test)]
 pub(crate) mod tests {
     use nom_sql::{
-        parse_expr, BinaryOperator as AstBinaryOperator, Dialect as ParserDialect, Float, Literal,
+        parse_expr, BinaryOperator as AstBinaryOperator, Dialect as ParserDialect, Float,
     };
     use readyset_data::{Collation, PgEnumMetadata};
 
@@ -1246,6 +2044,7 @@ pub(crate) mod tests {
     pub(crate) struct TestLowerContext<RC, RT> {
         resolve_column: RC,
         resolve_type: RT,
+        time_zone: Option<Tz>,
     }
 
     impl<RC, RT> LowerContext for TestLowerContext<RC, RT>
@@ -1260,12 +2059,25 @@ pub(crate) mod tests {
         fn resolve_type(&self, ty: Relation) -> Option<DfType> {
             (self.resolve_type)(ty)
         }
+
+        fn time_zone(&self) -> Option<Tz> {
+            self.time_zone
+        }
     }
 
     pub(crate) fn no_op_lower_context() -> impl LowerContext {
         TestLowerContext {
             resolve_column: |_| internal!(),
             resolve_type: |_| None,
+            time_zone: None,
+        }
+    }
+
+    pub(crate) fn in_time_zone(time_zone: Tz) -> impl LowerContext {
+        TestLowerContext {
+            resolve_column: |_| internal!(),
+            resolve_type: |_| None,
+            time_zone: Some(time_zone),
         }
     }
 
@@ -1276,6 +2088,7 @@ pub(crate) mod tests {
         TestLowerContext {
             resolve_column,
             resolve_type: |_| None,
+            time_zone: None,
         }
     }
 
@@ -1286,6 +2099,7 @@ pub(crate) mod tests {
         TestLowerContext {
             resolve_column: |_| internal!(),
             resolve_type,
+            time_zone: None,
         }
     }
 
@@ -1302,6 +2116,22 @@ pub(crate) mod tests {
         assert_eq!(result.ty(), &DfType::Unknown);
     }
 
+    #[test]
+    fn postgresql_numeric_approximate_math_unsupported() {
+        for expr in [
+            "sqrt(cast(2 as numeric))",
+            "exp(cast(1.5 as numeric))",
+            "ln(cast(2.5 as numeric))",
+            "power(4, cast(0.5 as numeric))",
+            "log(2, 8)",
+        ] {
+            let input = parse_expr(ParserDialect::PostgreSQL, expr).unwrap();
+            let err = Expr::lower(input, Dialect::DEFAULT_POSTGRESQL, &no_op_lower_context())
+                .unwrap_err();
+            assert!(err.is_unsupported(), "{expr}: {err}");
+        }
+    }
+
     #[test]
     fn simple_column_reference() {
         let input = AstExpr::Column("t.x".into());
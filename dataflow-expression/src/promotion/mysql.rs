@@ -135,6 +135,34 @@ fn build_map() -> HashMap<(DfType, BinaryOperator, DfType), DfType> {
         ),
         DfType::Numeric { prec: 7, scale: 4 },
     );
+    map.insert(
+        (DfType::TinyInt, BinaryOperator::Modulo, DfType::TinyInt),
+        DfType::Int,
+    );
+    map.insert(
+        (
+            DfType::TinyInt,
+            BinaryOperator::Modulo,
+            DfType::UnsignedTinyInt,
+        ),
+        DfType::Int,
+    );
+    map.insert(
+        (
+            DfType::UnsignedTinyInt,
+            BinaryOperator::Modulo,
+            DfType::TinyInt,
+        ),
+        DfType::UnsignedInt,
+    );
+    map.insert(
+        (
+            DfType::UnsignedTinyInt,
+            BinaryOperator::Modulo,
+            DfType::UnsignedTinyInt,
+        ),
+        DfType::UnsignedInt,
+    );
     map.insert(
         (DfType::TinyInt, BinaryOperator::Add, DfType::SmallInt),
         DfType::Int,
@@ -247,6 +275,34 @@ fn build_map() -> HashMap<(DfType, BinaryOperator, DfType), DfType> {
         ),
         DfType::Numeric { prec: 7, scale: 4 },
     );
+    map.insert(
+        (DfType::TinyInt, BinaryOperator::Modulo, DfType::SmallInt),
+        DfType::Int,
+    );
+    map.insert(
+        (
+            DfType::TinyInt,
+            BinaryOperator::Modulo,
+            DfType::UnsignedSmallInt,
+        ),
+        DfType::Int,
+    );
+    map.insert(
+        (
+            DfType::UnsignedTinyInt,
+            BinaryOperator::Modulo,
+            DfType::SmallInt,
+        ),
+        DfType::UnsignedInt,
+    );
+    map.insert(
+        (
+            DfType::UnsignedTinyInt,
+            BinaryOperator::Modulo,
+            DfType::UnsignedSmallInt,
+        ),
+        DfType::UnsignedInt,
+    );
     map.insert(
         (DfType::TinyInt, BinaryOperator::Add, DfType::MediumInt),
         DfType::Int,
@@ -359,6 +415,34 @@ fn build_map() -> HashMap<(DfType, BinaryOperator, DfType), DfType> {
         ),
         DfType::Numeric { prec: 7, scale: 4 },
     );
+    map.insert(
+        (DfType::TinyInt, BinaryOperator::Modulo, DfType::MediumInt),
+        DfType::Int,
+    );
+    map.insert(
+        (
+            DfType::TinyInt,
+            BinaryOperator::Modulo,
+            DfType::UnsignedMediumInt,
+        ),
+        DfType::Int,
+    );
+    map.insert(
+        (
+            DfType::UnsignedTinyInt,
+            BinaryOperator::Modulo,
+            DfType::MediumInt,
+        ),
+        DfType::UnsignedInt,
+    );
+    map.insert(
+        (
+            DfType::UnsignedTinyInt,
+            BinaryOperator::Modulo,
+            DfType::UnsignedMediumInt,
+        ),
+        DfType::UnsignedInt,
+    );
     map.insert(
         (DfType::TinyInt, BinaryOperator::Add, DfType::Int),
         DfType::BigInt,
@@ -455,6 +539,26 @@ fn build_map() -> HashMap<(DfType, BinaryOperator, DfType), DfType> {
         ),
         DfType::Numeric { prec: 7, scale: 4 },
     );
+    map.insert(
+        (DfType::TinyInt, BinaryOperator::Modulo, DfType::Int),
+        DfType::Int,
+    );
+    map.insert(
+        (DfType::TinyInt, BinaryOperator::Modulo, DfType::UnsignedInt),
+        DfType::Int,
+    );
+    map.insert(
+        (DfType::UnsignedTinyInt, BinaryOperator::Modulo, DfType::Int),
+        DfType::UnsignedInt,
+    );
+    map.insert(
+        (
+            DfType::UnsignedTinyInt,
+            BinaryOperator::Modulo,
+            DfType::UnsignedInt,
+        ),
+        DfType::UnsignedInt,
+    );
     map.insert(
         (DfType::TinyInt, BinaryOperator::Add, DfType::BigInt),
         DfType::BigInt,
@@ -559,6 +663,34 @@ fn build_map() -> HashMap<(DfType, BinaryOperator, DfType), DfType> {
         ),
         DfType::Numeric { prec: 7, scale: 4 },
     );
+    map.insert(
+        (DfType::TinyInt, BinaryOperator::Modulo, DfType::BigInt),
+        DfType::BigInt,
+    );
+    map.insert(
+        (
+            DfType::TinyInt,
+            BinaryOperator::Modulo,
+            DfType::UnsignedBigInt,
+        ),
+        DfType::BigInt,
+    );
+    map.insert(
+        (
+            DfType::UnsignedTinyInt,
+            BinaryOperator::Modulo,
+            DfType::BigInt,
+        ),
+        DfType::UnsignedBigInt,
+    );
+    map.insert(
+        (
+            DfType::UnsignedTinyInt,
+            BinaryOperator::Modulo,
+            DfType::UnsignedBigInt,
+        ),
+        DfType::UnsignedBigInt,
+    );
     map.insert(
         (DfType::SmallInt, BinaryOperator::Add, DfType::TinyInt),
         DfType::Int,
@@ -671,6 +803,34 @@ fn build_map() -> HashMap<(DfType, BinaryOperator, DfType), DfType> {
         ),
         DfType::Numeric { prec: 9, scale: 4 },
     );
+    map.insert(
+        (DfType::SmallInt, BinaryOperator::Modulo, DfType::TinyInt),
+        DfType::Int,
+    );
+    map.insert(
+        (
+            DfType::SmallInt,
+            BinaryOperator::Modulo,
+            DfType::UnsignedTinyInt,
+        ),
+        DfType::Int,
+    );
+    map.insert(
+        (
+            DfType::UnsignedSmallInt,
+            BinaryOperator::Modulo,
+            DfType::TinyInt,
+        ),
+        DfType::UnsignedInt,
+    );
+    map.insert(
+        (
+            DfType::UnsignedSmallInt,
+            BinaryOperator::Modulo,
+            DfType::UnsignedTinyInt,
+        ),
+        DfType::UnsignedInt,
+    );
     map.insert(
         (DfType::SmallInt, BinaryOperator::Add, DfType::SmallInt),
         DfType::Int,
@@ -783,6 +943,34 @@ fn build_map() -> HashMap<(DfType, BinaryOperator, DfType), DfType> {
         ),
         DfType::Numeric { prec: 9, scale: 4 },
     );
+    map.insert(
+        (DfType::SmallInt, BinaryOperator::Modulo, DfType::SmallInt),
+        DfType::Int,
+    );
+    map.insert(
+        (
+            DfType::SmallInt,
+            BinaryOperator::Modulo,
+            DfType::UnsignedSmallInt,
+        ),
+        DfType::Int,
+    );
+    map.insert(
+        (
+            DfType::UnsignedSmallInt,
+            BinaryOperator::Modulo,
+            DfType::SmallInt,
+        ),
+        DfType::UnsignedInt,
+    );
+    map.insert(
+        (
+            DfType::UnsignedSmallInt,
+            BinaryOperator::Modulo,
+            DfType::UnsignedSmallInt,
+        ),
+        DfType::UnsignedInt,
+    );
     map.insert(
         (DfType::SmallInt, BinaryOperator::Add, DfType::MediumInt),
         DfType::Int,
@@ -903,6 +1091,34 @@ fn build_map() -> HashMap<(DfType, BinaryOperator, DfType), DfType> {
         ),
         DfType::Numeric { prec: 9, scale: 4 },
     );
+    map.insert(
+        (DfType::SmallInt, BinaryOperator::Modulo, DfType::MediumInt),
+        DfType::Int,
+    );
+    map.insert(
+        (
+            DfType::SmallInt,
+            BinaryOperator::Modulo,
+            DfType::UnsignedMediumInt,
+        ),
+        DfType::Int,
+    );
+    map.insert(
+        (
+            DfType::UnsignedSmallInt,
+            BinaryOperator::Modulo,
+            DfType::MediumInt,
+        ),
+        DfType::UnsignedInt,
+    );
+    map.insert(
+        (
+            DfType::UnsignedSmallInt,
+            BinaryOperator::Modulo,
+            DfType::UnsignedMediumInt,
+        ),
+        DfType::UnsignedInt,
+    );
     map.insert(
         (DfType::SmallInt, BinaryOperator::Add, DfType::Int),
         DfType::BigInt,
@@ -1007,6 +1223,34 @@ fn build_map() -> HashMap<(DfType, BinaryOperator, DfType), DfType> {
         ),
         DfType::Numeric { prec: 9, scale: 4 },
     );
+    map.insert(
+        (DfType::SmallInt, BinaryOperator::Modulo, DfType::Int),
+        DfType::Int,
+    );
+    map.insert(
+        (
+            DfType::SmallInt,
+            BinaryOperator::Modulo,
+            DfType::UnsignedInt,
+        ),
+        DfType::Int,
+    );
+    map.insert(
+        (
+            DfType::UnsignedSmallInt,
+            BinaryOperator::Modulo,
+            DfType::Int,
+        ),
+        DfType::UnsignedInt,
+    );
+    map.insert(
+        (
+            DfType::UnsignedSmallInt,
+            BinaryOperator::Modulo,
+            DfType::UnsignedInt,
+        ),
+        DfType::UnsignedInt,
+    );
     map.insert(
         (DfType::SmallInt, BinaryOperator::Add, DfType::BigInt),
         DfType::BigInt,
@@ -1119,6 +1363,34 @@ fn build_map() -> HashMap<(DfType, BinaryOperator, DfType), DfType> {
         ),
         DfType::Numeric { prec: 9, scale: 4 },
     );
+    map.insert(
+        (DfType::SmallInt, BinaryOperator::Modulo, DfType::BigInt),
+        DfType::BigInt,
+    );
+    map.insert(
+        (
+            DfType::SmallInt,
+            BinaryOperator::Modulo,
+            DfType::UnsignedBigInt,
+        ),
+        DfType::BigInt,
+    );
+    map.insert(
+        (
+            DfType::UnsignedSmallInt,
+            BinaryOperator::Modulo,
+            DfType::BigInt,
+        ),
+        DfType::UnsignedBigInt,
+    );
+    map.insert(
+        (
+            DfType::UnsignedSmallInt,
+            BinaryOperator::Modulo,
+            DfType::UnsignedBigInt,
+        ),
+        DfType::UnsignedBigInt,
+    );
     map.insert(
         (DfType::MediumInt, BinaryOperator::Add, DfType::TinyInt),
         DfType::Int,
@@ -1231,6 +1503,34 @@ fn build_map() -> HashMap<(DfType, BinaryOperator, DfType), DfType> {
         ),
         DfType::Numeric { prec: 12, scale: 4 },
     );
+    map.insert(
+        (DfType::MediumInt, BinaryOperator::Modulo, DfType::TinyInt),
+        DfType::Int,
+    );
+    map.insert(
+        (
+            DfType::MediumInt,
+            BinaryOperator::Modulo,
+            DfType::UnsignedTinyInt,
+        ),
+        DfType::Int,
+    );
+    map.insert(
+        (
+            DfType::UnsignedMediumInt,
+            BinaryOperator::Modulo,
+            DfType::TinyInt,
+        ),
+        DfType::UnsignedInt,
+    );
+    map.insert(
+        (
+            DfType::UnsignedMediumInt,
+            BinaryOperator::Modulo,
+            DfType::UnsignedTinyInt,
+        ),
+        DfType::UnsignedInt,
+    );
     map.insert(
         (DfType::MediumInt, BinaryOperator::Add, DfType::SmallInt),
         DfType::Int,
@@ -1351,6 +1651,34 @@ fn build_map() -> HashMap<(DfType, BinaryOperator, DfType), DfType> {
         ),
         DfType::Numeric { prec: 12, scale: 4 },
     );
+    map.insert(
+        (DfType::MediumInt, BinaryOperator::Modulo, DfType::SmallInt),
+        DfType::Int,
+    );
+    map.insert(
+        (
+            DfType::MediumInt,
+            BinaryOperator::Modulo,
+            DfType::UnsignedSmallInt,
+        ),
+        DfType::Int,
+    );
+    map.insert(
+        (
+            DfType::UnsignedMediumInt,
+            BinaryOperator::Modulo,
+            DfType::SmallInt,
+        ),
+        DfType::UnsignedInt,
+    );
+    map.insert(
+        (
+            DfType::UnsignedMediumInt,
+            BinaryOperator::Modulo,
+            DfType::UnsignedSmallInt,
+        ),
+        DfType::UnsignedInt,
+    );
     map.insert(
         (DfType::MediumInt, BinaryOperator::Add, DfType::MediumInt),
         DfType::Int,
@@ -1471,6 +1799,34 @@ fn build_map() -> HashMap<(DfType, BinaryOperator, DfType), DfType> {
         ),
         DfType::Numeric { prec: 12, scale: 4 },
     );
+    map.insert(
+        (DfType::MediumInt, BinaryOperator::Modulo, DfType::MediumInt),
+        DfType::Int,
+    );
+    map.insert(
+        (
+            DfType::MediumInt,
+            BinaryOperator::Modulo,
+            DfType::UnsignedMediumInt,
+        ),
+        DfType::Int,
+    );
+    map.insert(
+        (
+            DfType::UnsignedMediumInt,
+            BinaryOperator::Modulo,
+            DfType::MediumInt,
+        ),
+        DfType::UnsignedInt,
+    );
+    map.insert(
+        (
+            DfType::UnsignedMediumInt,
+            BinaryOperator::Modulo,
+            DfType::UnsignedMediumInt,
+        ),
+        DfType::UnsignedInt,
+    );
     map.insert(
         (DfType::MediumInt, BinaryOperator::Add, DfType::Int),
         DfType::BigInt,
@@ -1575,6 +1931,34 @@ fn build_map() -> HashMap<(DfType, BinaryOperator, DfType), DfType> {
         ),
         DfType::Numeric { prec: 12, scale: 4 },
     );
+    map.insert(
+        (DfType::MediumInt, BinaryOperator::Modulo, DfType::Int),
+        DfType::Int,
+    );
+    map.insert(
+        (
+            DfType::MediumInt,
+            BinaryOperator::Modulo,
+            DfType::UnsignedInt,
+        ),
+        DfType::Int,
+    );
+    map.insert(
+        (
+            DfType::UnsignedMediumInt,
+            BinaryOperator::Modulo,
+            DfType::Int,
+        ),
+        DfType::UnsignedInt,
+    );
+    map.insert(
+        (
+            DfType::UnsignedMediumInt,
+            BinaryOperator::Modulo,
+            DfType::UnsignedInt,
+        ),
+        DfType::UnsignedInt,
+    );
     map.insert(
         (DfType::MediumInt, BinaryOperator::Add, DfType::BigInt),
         DfType::BigInt,
@@ -1687,6 +2071,34 @@ fn build_map() -> HashMap<(DfType, BinaryOperator, DfType), DfType> {
         ),
         DfType::Numeric { prec: 12, scale: 4 },
     );
+    map.insert(
+        (DfType::MediumInt, BinaryOperator::Modulo, DfType::BigInt),
+        DfType::BigInt,
+    );
+    map.insert(
+        (
+            DfType::MediumInt,
+            BinaryOperator::Modulo,
+            DfType::UnsignedBigInt,
+        ),
+        DfType::BigInt,
+    );
+    map.insert(
+        (
+            DfType::UnsignedMediumInt,
+            BinaryOperator::Modulo,
+            DfType::BigInt,
+        ),
+        DfType::UnsignedBigInt,
+    );
+    map.insert(
+        (
+            DfType::UnsignedMediumInt,
+            BinaryOperator::Modulo,
+            DfType::UnsignedBigInt,
+        ),
+        DfType::UnsignedBigInt,
+    );
     map.insert(
         (DfType::Int, BinaryOperator::Add, DfType::TinyInt),
         DfType::BigInt,
@@ -1783,6 +2195,26 @@ fn build_map() -> HashMap<(DfType, BinaryOperator, DfType), DfType> {
         ),
         DfType::Numeric { prec: 14, scale: 4 },
     );
+    map.insert(
+        (DfType::Int, BinaryOperator::Modulo, DfType::TinyInt),
+        DfType::Int,
+    );
+    map.insert(
+        (DfType::Int, BinaryOperator::Modulo, DfType::UnsignedTinyInt),
+        DfType::Int,
+    );
+    map.insert(
+        (DfType::UnsignedInt, BinaryOperator::Modulo, DfType::TinyInt),
+        DfType::UnsignedInt,
+    );
+    map.insert(
+        (
+            DfType::UnsignedInt,
+            BinaryOperator::Modulo,
+            DfType::UnsignedTinyInt,
+        ),
+        DfType::UnsignedInt,
+    );
     map.insert(
         (DfType::Int, BinaryOperator::Add, DfType::SmallInt),
         DfType::BigInt,
@@ -1887,6 +2319,34 @@ fn build_map() -> HashMap<(DfType, BinaryOperator, DfType), DfType> {
         ),
         DfType::Numeric { prec: 14, scale: 4 },
     );
+    map.insert(
+        (DfType::Int, BinaryOperator::Modulo, DfType::SmallInt),
+        DfType::Int,
+    );
+    map.insert(
+        (
+            DfType::Int,
+            BinaryOperator::Modulo,
+            DfType::UnsignedSmallInt,
+        ),
+        DfType::Int,
+    );
+    map.insert(
+        (
+            DfType::UnsignedInt,
+            BinaryOperator::Modulo,
+            DfType::SmallInt,
+        ),
+        DfType::UnsignedInt,
+    );
+    map.insert(
+        (
+            DfType::UnsignedInt,
+            BinaryOperator::Modulo,
+            DfType::UnsignedSmallInt,
+        ),
+        DfType::UnsignedInt,
+    );
     map.insert(
         (DfType::Int, BinaryOperator::Add, DfType::MediumInt),
         DfType::BigInt,
@@ -1991,6 +2451,34 @@ fn build_map() -> HashMap<(DfType, BinaryOperator, DfType), DfType> {
         ),
         DfType::Numeric { prec: 14, scale: 4 },
     );
+    map.insert(
+        (DfType::Int, BinaryOperator::Modulo, DfType::MediumInt),
+        DfType::Int,
+    );
+    map.insert(
+        (
+            DfType::Int,
+            BinaryOperator::Modulo,
+            DfType::UnsignedMediumInt,
+        ),
+        DfType::Int,
+    );
+    map.insert(
+        (
+            DfType::UnsignedInt,
+            BinaryOperator::Modulo,
+            DfType::MediumInt,
+        ),
+        DfType::UnsignedInt,
+    );
+    map.insert(
+        (
+            DfType::UnsignedInt,
+            BinaryOperator::Modulo,
+            DfType::UnsignedMediumInt,
+        ),
+        DfType::UnsignedInt,
+    );
     map.insert(
         (DfType::Int, BinaryOperator::Add, DfType::Int),
         DfType::BigInt,
@@ -2071,6 +2559,26 @@ fn build_map() -> HashMap<(DfType, BinaryOperator, DfType), DfType> {
         ),
         DfType::Numeric { prec: 14, scale: 4 },
     );
+    map.insert(
+        (DfType::Int, BinaryOperator::Modulo, DfType::Int),
+        DfType::Int,
+    );
+    map.insert(
+        (DfType::Int, BinaryOperator::Modulo, DfType::UnsignedInt),
+        DfType::Int,
+    );
+    map.insert(
+        (DfType::UnsignedInt, BinaryOperator::Modulo, DfType::Int),
+        DfType::UnsignedInt,
+    );
+    map.insert(
+        (
+            DfType::UnsignedInt,
+            BinaryOperator::Modulo,
+            DfType::UnsignedInt,
+        ),
+        DfType::UnsignedInt,
+    );
     map.insert(
         (DfType::Int, BinaryOperator::Add, DfType::BigInt),
         DfType::BigInt,
@@ -2167,6 +2675,26 @@ fn build_map() -> HashMap<(DfType, BinaryOperator, DfType), DfType> {
         ),
         DfType::Numeric { prec: 14, scale: 4 },
     );
+    map.insert(
+        (DfType::Int, BinaryOperator::Modulo, DfType::BigInt),
+        DfType::BigInt,
+    );
+    map.insert(
+        (DfType::Int, BinaryOperator::Modulo, DfType::UnsignedBigInt),
+        DfType::BigInt,
+    );
+    map.insert(
+        (DfType::UnsignedInt, BinaryOperator::Modulo, DfType::BigInt),
+        DfType::UnsignedBigInt,
+    );
+    map.insert(
+        (
+            DfType::UnsignedInt,
+            BinaryOperator::Modulo,
+            DfType::UnsignedBigInt,
+        ),
+        DfType::UnsignedBigInt,
+    );
     map.insert(
         (DfType::BigInt, BinaryOperator::Add, DfType::TinyInt),
         DfType::BigInt,
@@ -2271,6 +2799,34 @@ fn build_map() -> HashMap<(DfType, BinaryOperator, DfType), DfType> {
         ),
         DfType::Numeric { prec: 24, scale: 4 },
     );
+    map.insert(
+        (DfType::BigInt, BinaryOperator::Modulo, DfType::TinyInt),
+        DfType::BigInt,
+    );
+    map.insert(
+        (
+            DfType::BigInt,
+            BinaryOperator::Modulo,
+            DfType::UnsignedTinyInt,
+        ),
+        DfType::BigInt,
+    );
+    map.insert(
+        (
+            DfType::UnsignedBigInt,
+            BinaryOperator::Modulo,
+            DfType::TinyInt,
+        ),
+        DfType::UnsignedBigInt,
+    );
+    map.insert(
+        (
+            DfType::UnsignedBigInt,
+            BinaryOperator::Modulo,
+            DfType::UnsignedTinyInt,
+        ),
+        DfType::UnsignedBigInt,
+    );
     map.insert(
         (DfType::BigInt, BinaryOperator::Add, DfType::SmallInt),
         DfType::BigInt,
@@ -2383,6 +2939,34 @@ fn build_map() -> HashMap<(DfType, BinaryOperator, DfType), DfType> {
         ),
         DfType::Numeric { prec: 24, scale: 4 },
     );
+    map.insert(
+        (DfType::BigInt, BinaryOperator::Modulo, DfType::SmallInt),
+        DfType::BigInt,
+    );
+    map.insert(
+        (
+            DfType::BigInt,
+            BinaryOperator::Modulo,
+            DfType::UnsignedSmallInt,
+        ),
+        DfType::BigInt,
+    );
+    map.insert(
+        (
+            DfType::UnsignedBigInt,
+            BinaryOperator::Modulo,
+            DfType::SmallInt,
+        ),
+        DfType::UnsignedBigInt,
+    );
+    map.insert(
+        (
+            DfType::UnsignedBigInt,
+            BinaryOperator::Modulo,
+            DfType::UnsignedSmallInt,
+        ),
+        DfType::UnsignedBigInt,
+    );
     map.insert(
         (DfType::BigInt, BinaryOperator::Add, DfType::MediumInt),
         DfType::BigInt,
@@ -2495,6 +3079,34 @@ fn build_map() -> HashMap<(DfType, BinaryOperator, DfType), DfType> {
         ),
         DfType::Numeric { prec: 24, scale: 4 },
     );
+    map.insert(
+        (DfType::BigInt, BinaryOperator::Modulo, DfType::MediumInt),
+        DfType::BigInt,
+    );
+    map.insert(
+        (
+            DfType::BigInt,
+            BinaryOperator::Modulo,
+            DfType::UnsignedMediumInt,
+        ),
+        DfType::BigInt,
+    );
+    map.insert(
+        (
+            DfType::UnsignedBigInt,
+            BinaryOperator::Modulo,
+            DfType::MediumInt,
+        ),
+        DfType::UnsignedBigInt,
+    );
+    map.insert(
+        (
+            DfType::UnsignedBigInt,
+            BinaryOperator::Modulo,
+            DfType::UnsignedMediumInt,
+        ),
+        DfType::UnsignedBigInt,
+    );
     map.insert(
         (DfType::BigInt, BinaryOperator::Add, DfType::Int),
         DfType::BigInt,
@@ -2591,6 +3203,26 @@ fn build_map() -> HashMap<(DfType, BinaryOperator, DfType), DfType> {
         ),
         DfType::Numeric { prec: 24, scale: 4 },
     );
+    map.insert(
+        (DfType::BigInt, BinaryOperator::Modulo, DfType::Int),
+        DfType::BigInt,
+    );
+    map.insert(
+        (DfType::BigInt, BinaryOperator::Modulo, DfType::UnsignedInt),
+        DfType::BigInt,
+    );
+    map.insert(
+        (DfType::UnsignedBigInt, BinaryOperator::Modulo, DfType::Int),
+        DfType::UnsignedBigInt,
+    );
+    map.insert(
+        (
+            DfType::UnsignedBigInt,
+            BinaryOperator::Modulo,
+            DfType::UnsignedInt,
+        ),
+        DfType::UnsignedBigInt,
+    );
     map.insert(
         (DfType::BigInt, BinaryOperator::Add, DfType::BigInt),
         DfType::BigInt,
@@ -2695,5 +3327,33 @@ fn build_map() -> HashMap<(DfType, BinaryOperator, DfType), DfType> {
         ),
         DfType::Numeric { prec: 24, scale: 4 },
     );
+    map.insert(
+        (DfType::BigInt, BinaryOperator::Modulo, DfType::BigInt),
+        DfType::BigInt,
+    );
+    map.insert(
+        (
+            DfType::BigInt,
+            BinaryOperator::Modulo,
+            DfType::UnsignedBigInt,
+        ),
+        DfType::BigInt,
+    );
+    map.insert(
+        (
+            DfType::UnsignedBigInt,
+            BinaryOperator::Modulo,
+            DfType::BigInt,
+        ),
+        DfType::UnsignedBigInt,
+    );
+    map.insert(
+        (
+            DfType::UnsignedBigInt,
+            BinaryOperator::Modulo,
+            DfType::UnsignedBigInt,
+        ),
+        DfType::UnsignedBigInt,
+    );
     map
 }
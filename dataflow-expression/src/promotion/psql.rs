@@ -39,6 +39,14 @@ fn build_map() -> HashMap<(DfType, BinaryOperator, DfType), DfType> {
         (DfType::SmallInt, BinaryOperator::Divide, DfType::SmallInt),
         DfType::SmallInt,
     );
+    map.insert(
+        (
+            DfType::SmallInt,
+            BinaryOperator::StrictModulo,
+            DfType::SmallInt,
+        ),
+        DfType::SmallInt,
+    );
     map.insert(
         (DfType::SmallInt, BinaryOperator::Add, DfType::Int),
         DfType::Int,
@@ -55,6 +63,10 @@ fn build_map() -> HashMap<(DfType, BinaryOperator, DfType), DfType> {
         (DfType::SmallInt, BinaryOperator::Divide, DfType::Int),
         DfType::Int,
     );
+    map.insert(
+        (DfType::SmallInt, BinaryOperator::StrictModulo, DfType::Int),
+        DfType::Int,
+    );
     map.insert(
         (DfType::SmallInt, BinaryOperator::Add, DfType::BigInt),
         DfType::BigInt,
@@ -71,6 +83,14 @@ fn build_map() -> HashMap<(DfType, BinaryOperator, DfType), DfType> {
         (DfType::SmallInt, BinaryOperator::Divide, DfType::BigInt),
         DfType::BigInt,
     );
+    map.insert(
+        (
+            DfType::SmallInt,
+            BinaryOperator::StrictModulo,
+            DfType::BigInt,
+        ),
+        DfType::BigInt,
+    );
     map.insert(
         (DfType::Int, BinaryOperator::Add, DfType::SmallInt),
         DfType::Int,
@@ -87,6 +107,10 @@ fn build_map() -> HashMap<(DfType, BinaryOperator, DfType), DfType> {
         (DfType::Int, BinaryOperator::Divide, DfType::SmallInt),
         DfType::Int,
     );
+    map.insert(
+        (DfType::Int, BinaryOperator::StrictModulo, DfType::SmallInt),
+        DfType::Int,
+    );
     map.insert((DfType::Int, BinaryOperator::Add, DfType::Int), DfType::Int);
     map.insert(
         (DfType::Int, BinaryOperator::Subtract, DfType::Int),
@@ -100,6 +124,10 @@ fn build_map() -> HashMap<(DfType, BinaryOperator, DfType), DfType> {
         (DfType::Int, BinaryOperator::Divide, DfType::Int),
         DfType::Int,
     );
+    map.insert(
+        (DfType::Int, BinaryOperator::StrictModulo, DfType::Int),
+        DfType::Int,
+    );
     map.insert(
         (DfType::Int, BinaryOperator::Add, DfType::BigInt),
         DfType::BigInt,
@@ -116,6 +144,10 @@ fn build_map() -> HashMap<(DfType, BinaryOperator, DfType), DfType> {
         (DfType::Int, BinaryOperator::Divide, DfType::BigInt),
         DfType::BigInt,
     );
+    map.insert(
+        (DfType::Int, BinaryOperator::StrictModulo, DfType::BigInt),
+        DfType::BigInt,
+    );
     map.insert(
         (DfType::BigInt, BinaryOperator::Add, DfType::SmallInt),
         DfType::BigInt,
@@ -132,6 +164,14 @@ fn build_map() -> HashMap<(DfType, BinaryOperator, DfType), DfType> {
         (DfType::BigInt, BinaryOperator::Divide, DfType::SmallInt),
         DfType::BigInt,
     );
+    map.insert(
+        (
+            DfType::BigInt,
+            BinaryOperator::StrictModulo,
+            DfType::SmallInt,
+        ),
+        DfType::BigInt,
+    );
     map.insert(
         (DfType::BigInt, BinaryOperator::Add, DfType::Int),
         DfType::BigInt,
@@ -148,6 +188,10 @@ fn build_map() -> HashMap<(DfType, BinaryOperator, DfType), DfType> {
         (DfType::BigInt, BinaryOperator::Divide, DfType::Int),
         DfType::BigInt,
     );
+    map.insert(
+        (DfType::BigInt, BinaryOperator::StrictModulo, DfType::Int),
+        DfType::BigInt,
+    );
     map.insert(
         (DfType::BigInt, BinaryOperator::Add, DfType::BigInt),
         DfType::BigInt,
@@ -164,5 +208,9 @@ fn build_map() -> HashMap<(DfType, BinaryOperator, DfType), DfType> {
         (DfType::BigInt, BinaryOperator::Divide, DfType::BigInt),
         DfType::BigInt,
     );
+    map.insert(
+        (DfType::BigInt, BinaryOperator::StrictModulo, DfType::BigInt),
+        DfType::BigInt,
+    );
     map
 }
@@ -5,14 +5,14 @@ use std::sync::Arc;
 
 use nom_sql::OrderType;
 use partial_map::InsertionOrder;
-use readyset_data::DfValue;
+use readyset_data::{DfType, DfValue};
 use readyset_errors::{internal, ReadySetResult};
 use serde::{Deserialize, Serialize};
 
 /// Representation of an aggregate function
 // TODO(aspen): It would be really nice to deduplicate this somehow with the grouped operator itself
 #[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
-pub enum PostLookupAggregateFunction {
+pub enum PostLookupAggregateFunction<Column = usize> {
     /// Add together all the input numbers
     ///
     /// Note that this encapsulates both `SUM` *and* `COUNT` in base SQL, as re-aggregating counts
@@ -26,12 +26,28 @@ pub enum PostLookupAggregateFunction {
     Max,
     /// Take the minimum input value
     Min,
+    /// Take the average of the input numbers.
+    ///
+    /// Averages can't be re-aggregated from the averages for each key, so the sum and the count of
+    /// the input numbers for each key are carried in the `sum` and `count` columns, and the
+    /// average is recomputed from their totals
+    Avg { sum: Column, count: Column },
+    /// Count the distinct input values, given a column containing a sorted array of the distinct
+    /// values for each key
+    CountDistinct { values: Column },
+    /// Add together the distinct input numbers, given a column containing a sorted array of the
+    /// distinct values for each key
+    SumDistinct { values: Column },
+    /// Take the average of the distinct input numbers, given a column containing a sorted array of
+    /// the distinct values for each key
+    AvgDistinct { values: Column },
 }
 
 impl PostLookupAggregateFunction {
     /// Apply this aggregate function to the two input values
     ///
-    /// This forms a semigroup.
+    /// This forms a semigroup. Aggregate functions which are recomputed from partial state in
+    /// other columns can't be applied to values alone, and return an error.
     pub fn apply(&self, val1: &DfValue, val2: &DfValue) -> ReadySetResult<DfValue> {
         match self {
             PostLookupAggregateFunction::Sum => val1 + val2,
@@ -45,17 +61,48 @@ impl PostLookupAggregateFunction {
             .into()),
             PostLookupAggregateFunction::Max => Ok(cmp::max(val1, val2).clone()),
             PostLookupAggregateFunction::Min => Ok(cmp::min(val1, val2).clone()),
+            PostLookupAggregateFunction::Avg { .. }
+            | PostLookupAggregateFunction::CountDistinct { .. }
+            | PostLookupAggregateFunction::SumDistinct { .. }
+            | PostLookupAggregateFunction::AvgDistinct { .. } => {
+                internal!("{self:?} must be computed from partial state in other columns")
+            }
         }
     }
 }
 
+impl<Column> PostLookupAggregateFunction<Column> {
+    /// Transform all column references in self by applying a function
+    pub fn map_columns<F, C2, E>(self, mut f: F) -> Result<PostLookupAggregateFunction<C2>, E>
+    where
+        F: FnMut(Column) -> Result<C2, E>,
+    {
+        use PostLookupAggregateFunction::*;
+
+        Ok(match self {
+            Sum => Sum,
+            Product => Product,
+            GroupConcat { separator } => GroupConcat { separator },
+            Max => Max,
+            Min => Min,
+            Avg { sum, count } => Avg {
+                sum: f(sum)?,
+                count: f(count)?,
+            },
+            CountDistinct { values } => CountDistinct { values: f(values)? },
+            SumDistinct { values } => SumDistinct { values: f(values)? },
+            AvgDistinct { values } => AvgDistinct { values: f(values)? },
+        })
+    }
+}
+
 /// Representation of a single aggregate function to be performed on a column post-lookup
 #[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
 pub struct PostLookupAggregate<Column = usize> {
     /// The column index in the result set containing the already-aggregated values
     pub column: Column,
     /// The aggregate function to perform
-    pub function: PostLookupAggregateFunction,
+    pub function: PostLookupAggregateFunction<Column>,
 }
 
 impl<Column> PostLookupAggregate<Column> {
@@ -66,11 +113,96 @@ impl<Column> PostLookupAggregate<Column> {
     {
         Ok(PostLookupAggregate {
             column: f(self.column)?,
-            function: self.function,
+            function: self.function.map_columns(f)?,
         })
     }
 }
 
+/// Returns the values in the given array of distinct values
+fn distinct_values(values: &DfValue) -> ReadySetResult<impl Iterator<Item = &DfValue>> {
+    match values {
+        DfValue::Array(arr) => Ok(arr.values()),
+        _ => internal!("Distinct values for post-lookup aggregates must be an array"),
+    }
+}
+
+/// Returns the sum of the given (non-NULL) values, or NULL if there aren't any
+fn sum<'a>(mut values: impl Iterator<Item = &'a DfValue>) -> ReadySetResult<DfValue> {
+    let Some(first) = values.next() else {
+        return Ok(DfValue::None);
+    };
+    values.try_fold(first.clone(), |acc, v| &acc + v)
+}
+
+/// Divide `sum` by `count`, returning a value with the same type as `like` (the average computed
+/// in the dataflow graph), or NULL if `count` is zero
+fn average(sum: &DfValue, count: &DfValue, like: &DfValue) -> ReadySetResult<DfValue> {
+    if sum.is_none() || count.is_none() || *count == DfValue::from(0) {
+        return Ok(DfValue::None);
+    }
+    let ty = match like.infer_dataflow_type() {
+        ty @ DfType::Numeric { .. } => ty,
+        _ => DfType::Double,
+    };
+    &sum.coerce_to(&ty, &sum.infer_dataflow_type())? / count
+}
+
+impl PostLookupAggregate {
+    /// Merge the aggregated values (and any partial state) in `row` into the aggregated values in
+    /// `acc`, where both rows are in the same group
+    pub fn merge(&self, acc: &mut [DfValue], row: &[DfValue]) -> ReadySetResult<()> {
+        match &self.function {
+            PostLookupAggregateFunction::Avg { sum, count } => {
+                acc[*sum] = (&acc[*sum] + &row[*sum])?;
+                acc[*count] = (&acc[*count] + &row[*count])?;
+            }
+            PostLookupAggregateFunction::CountDistinct { values }
+            | PostLookupAggregateFunction::SumDistinct { values }
+            | PostLookupAggregateFunction::AvgDistinct { values } => {
+                let mut merged = distinct_values(&acc[*values])?
+                    .chain(distinct_values(&row[*values])?)
+                    .cloned()
+                    .collect::<Vec<_>>();
+                merged.sort();
+                merged.dedup();
+                acc[*values] = merged.into();
+            }
+            function => acc[self.column] = function.apply(&acc[self.column], &row[self.column])?,
+        }
+        Ok(())
+    }
+
+    /// Recompute the aggregated value in `row` from the partial state merged into it by
+    /// [`merge`](Self::merge), for aggregate functions which can't be merged directly
+    pub fn finalize(&self, row: &mut [DfValue]) -> ReadySetResult<()> {
+        row[self.column] = match &self.function {
+            PostLookupAggregateFunction::Avg { sum, count } => {
+                average(&row[*sum], &row[*count], &row[self.column])?
+            }
+            PostLookupAggregateFunction::CountDistinct { values } => {
+                DfValue::from(distinct_values(&row[*values])?.count() as i64)
+            }
+            PostLookupAggregateFunction::SumDistinct { values } => {
+                let sum = sum(distinct_values(&row[*values])?)?;
+                match row[self.column].infer_dataflow_type() {
+                    DfType::Unknown => sum,
+                    ty => sum.coerce_to(&ty, &sum.infer_dataflow_type())?,
+                }
+            }
+            PostLookupAggregateFunction::AvgDistinct { values } => {
+                let values = distinct_values(&row[*values])?.cloned().collect::<Vec<_>>();
+                average(
+                    &sum(values.iter())?,
+                    &DfValue::from(values.len() as i64),
+                    &row[self.column],
+                )?
+            }
+            _ => return Ok(()),
+        };
+        Ok(())
+    }
+}
+
 /// Representation of a set of multiple aggregate functions to be performed post-lookup
 ///
 /// This is used for range queries, where lookups cover multiple grouped keys
@@ -103,6 +235,42 @@ impl<Column> PostLookupAggregates<Column> {
     }
 }
 
+/// Representation of the filtering to perform post-lookup on the results of a query with a
+/// parameter on the right-hand side of a LEFT JOIN.
+///
+/// Rows for such queries are cached with every row in the left-hand side of the join *also* joined
+/// against NULLs, and the parameter is compared against the columns in `right_columns` after the
+/// lookup. Rows where all of `right_columns` are NULL are then removed if another row in the result
+/// set with the same values for `left_columns` has non-NULL values for `right_columns`.
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct PostLookupLeftJoinFilter<Column = usize> {
+    /// The columns which identify a row in the left-hand side of the join
+    pub left_columns: Vec<Column>,
+    /// The columns in the right-hand side of the join which are compared against parameters
+    pub right_columns: Vec<Column>,
+}
+
+impl<Column> PostLookupLeftJoinFilter<Column> {
+    /// Transform all column references in self by applying a function
+    pub fn map_columns<F, C2, E>(self, mut f: F) -> Result<PostLookupLeftJoinFilter<C2>, E>
+    where
+        F: FnMut(Column) -> Result<C2, E>,
+    {
+        Ok(PostLookupLeftJoinFilter {
+            left_columns: self
+                .left_columns
+                .into_iter()
+                .map(&mut f)
+                .collect::<Result<_, E>>()?,
+            right_columns: self
+                .right_columns
+                .into_iter()
+                .map(&mut f)
+                .collect::<Result<_, E>>()?,
+        })
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Default, Eq, PartialEq)]
 /// Operations to perform on rows before insertion into a reader or after a lookup
 pub struct ReaderProcessing {
@@ -120,6 +288,7 @@ impl ReaderProcessing {
         returned_cols: Option<Vec<usize>>,
         default_row: Option<Vec<DfValue>>,
         aggregates: Option<PostLookupAggregates>,
+        left_join_filter: Option<PostLookupLeftJoinFilter>,
     ) -> ReadySetResult<Self> {
         if let Some(cols) = &returned_cols {
             if cols.iter().enumerate().any(|(i, v)| i != *v) {
@@ -133,6 +302,7 @@ impl ReaderProcessing {
             returned_cols,
             default_row: default_row.map(|r| Arc::new(r.into_boxed_slice())),
             aggregates,
+            left_join_filter,
         };
 
         let pre_processing = PreInsertion {
@@ -179,6 +349,10 @@ pub struct PostLookup {
     /// Note that currently these are only performed on each key individually, not the overall
     /// result set returned by all keys in a multi-key lookup
     pub aggregates: Option<PostLookupAggregates>,
+    /// Filtering to perform on the result set for each key to remove extra NULL-padded rows, for
+    /// queries with parameters on the right-hand side of a LEFT JOIN. This is performed before
+    /// any other post-lookup operations.
+    pub left_join_filter: Option<PostLookupLeftJoinFilter>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default, Eq, PartialEq)]
@@ -227,3 +401,78 @@ impl InsertionOrder<Box<[DfValue]>> for PreInsertion {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn merge_all(aggregate: &PostLookupAggregate, rows: Vec<Vec<DfValue>>) -> Vec<DfValue> {
+        let mut rows = rows.into_iter();
+        let mut acc = rows.next().unwrap();
+        for row in rows {
+            aggregate.merge(&mut acc, &row).unwrap();
+        }
+        aggregate.finalize(&mut acc).unwrap();
+        acc
+    }
+
+    #[test]
+    fn avg_from_partial_state() {
+        let aggregate = PostLookupAggregate {
+            column: 0,
+            function: PostLookupAggregateFunction::Avg { sum: 1, count: 2 },
+        };
+        let res = merge_all(
+            &aggregate,
+            vec![
+                vec![DfValue::Double(1.0), DfValue::from(2), DfValue::from(2)],
+                vec![DfValue::Double(4.0), DfValue::from(4), DfValue::from(1)],
+            ],
+        );
+        assert_eq!(res[0], DfValue::Double(2.0));
+    }
+
+    #[test]
+    fn count_distinct_from_partial_state() {
+        let aggregate = PostLookupAggregate {
+            column: 0,
+            function: PostLookupAggregateFunction::CountDistinct { values: 1 },
+        };
+        let res = merge_all(
+            &aggregate,
+            vec![
+                vec![
+                    DfValue::from(2),
+                    DfValue::from(vec![DfValue::from(1), DfValue::from(2)]),
+                ],
+                vec![
+                    DfValue::from(2),
+                    DfValue::from(vec![DfValue::from(2), DfValue::from(3)]),
+                ],
+            ],
+        );
+        assert_eq!(res[0], DfValue::from(3));
+    }
+
+    #[test]
+    fn sum_distinct_from_partial_state() {
+        let aggregate = PostLookupAggregate {
+            column: 0,
+            function: PostLookupAggregateFunction::SumDistinct { values: 1 },
+        };
+        let res = merge_all(
+            &aggregate,
+            vec![
+                vec![
+                    DfValue::from(3),
+                    DfValue::from(vec![DfValue::from(1), DfValue::from(2)]),
+                ],
+                vec![
+                    DfValue::from(5),
+                    DfValue::from(vec![DfValue::from(2), DfValue::from(3)]),
+                ],
+            ],
+        );
+        assert_eq!(res[0], DfValue::from(6));
+    }
+}
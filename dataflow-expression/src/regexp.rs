@@ -0,0 +1,317 @@
+//! Implementation of SQL regular expression matching and replacement
+//!
+//! This covers MySQL's `REGEXP` (and `RLIKE`) operators and `REGEXP_LIKE` and `REGEXP_REPLACE`
+//! functions, and PostgreSQL's `~`, `~*`, `!~` and `!~*` operators, `SIMILAR TO` operator, and
+//! `regexp_replace` and `regexp_match` functions.
+//!
+//! Patterns are compiled with the [`regex`] crate, which supports the syntax that's common to ICU
+//! (used by MySQL) and POSIX ARE (used by PostgreSQL), but not backreferences or lookaround.
+//! Patterns using those will fail to compile, returning an error.
+
+use std::fmt;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use readyset_errors::{invalid_query_err, ReadySetResult};
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+
+/// The flavor of regular expression that a pattern is written in, which determines how it's
+/// compiled and which flags can be passed alongside it
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RegexFlavor {
+    /// MySQL's `REGEXP` operator and `REGEXP_*` functions
+    MySql,
+    /// PostgreSQL's `~` operators and `regexp_*` functions
+    Postgres,
+    /// PostgreSQL's `SIMILAR TO` operator, which matches the *entire* string against a pattern
+    /// using a mix of `LIKE` and regular expression syntax
+    SimilarTo,
+}
+
+/// Options parsed from the flags (or "match type") argument to a regular expression function
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RegexFlags {
+    case_insensitive: bool,
+    multi_line: bool,
+    dot_matches_new_line: bool,
+    ignore_whitespace: bool,
+    /// Replace all matches rather than just the first (PostgreSQL's `g` flag)
+    pub global: bool,
+}
+
+impl RegexFlags {
+    /// Returns the default flags for the given [`RegexFlavor`]
+    pub fn new(flavor: RegexFlavor) -> Self {
+        Self {
+            // MySQL's default collations are case-insensitive, and its regular expressions match
+            // according to the collation unless the `c` or `i` flags are given
+            case_insensitive: flavor == RegexFlavor::MySql,
+            multi_line: false,
+            // In PostgreSQL `.` matches newlines unless newline-sensitive matching is enabled,
+            // whereas in MySQL it only does so with the `n` flag
+            dot_matches_new_line: flavor != RegexFlavor::MySql,
+            ignore_whitespace: false,
+            global: false,
+        }
+    }
+
+    /// Parse the given flags string on top of the defaults for the given [`RegexFlavor`],
+    /// returning an error if it contains any unsupported flags.
+    pub fn parse(flavor: RegexFlavor, flags: &str) -> ReadySetResult<Self> {
+        let mut res = Self::new(flavor);
+        for flag in flags.chars() {
+            match (flavor, flag) {
+                (_, 'c') => res.case_insensitive = false,
+                (_, 'i') => res.case_insensitive = true,
+                (RegexFlavor::MySql, 'm') => res.multi_line = true,
+                (RegexFlavor::MySql, 'n') => res.dot_matches_new_line = true,
+                // Unix-only line endings are the only line endings we support anyway
+                (RegexFlavor::MySql, 'u') => {}
+                (RegexFlavor::Postgres, 'g') => res.global = true,
+                (RegexFlavor::Postgres, 'm' | 'n') => {
+                    res.multi_line = true;
+                    res.dot_matches_new_line = false;
+                }
+                (RegexFlavor::Postgres, 's') => {
+                    res.multi_line = false;
+                    res.dot_matches_new_line = true;
+                }
+                (RegexFlavor::Postgres, 'x') => res.ignore_whitespace = true,
+                (RegexFlavor::Postgres, 't') => res.ignore_whitespace = false,
+                _ => {
+                    return Err(invalid_query_err!(
+                        "invalid regular expression option: \"{flag}\""
+                    ))
+                }
+            }
+        }
+        Ok(res)
+    }
+}
+
+/// Translate a `SIMILAR TO` pattern into an equivalent regular expression
+///
+/// Within `SIMILAR TO` patterns, `%` and `_` have the same meaning as in `LIKE` patterns, `.`,
+/// `^` and `$` are literal characters, the rest of the regular expression metacharacters have
+/// their usual meaning, and `\` escapes the following character. The pattern must match the
+/// entire string.
+fn similar_to_regex(pattern: &str) -> String {
+    let mut re = String::with_capacity(pattern.len() + 8);
+    re.push_str("^(?:");
+    let mut chars = pattern.chars();
+    let mut in_bracket = false;
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some(escaped) => {
+                    re.push_str(&regex::escape(escaped.encode_utf8(&mut [0; 4])));
+                }
+                None => re.push_str(r"\\"),
+            },
+            '[' if !in_bracket => {
+                in_bracket = true;
+                re.push('[');
+            }
+            '[' => re.push_str(r"\["),
+            ']' if in_bracket => {
+                in_bracket = false;
+                re.push(']');
+            }
+            _ if in_bracket => re.push(c),
+            '%' => re.push_str(".*"),
+            '_' => re.push('.'),
+            '.' | '^' | '$' => {
+                re.push('\\');
+                re.push(c);
+            }
+            _ => re.push(c),
+        }
+    }
+    re.push_str(")$");
+    re
+}
+
+fn compile(pattern: &str, flavor: RegexFlavor, flags: RegexFlags) -> ReadySetResult<Regex> {
+    let pattern = match flavor {
+        RegexFlavor::SimilarTo => similar_to_regex(pattern),
+        RegexFlavor::MySql | RegexFlavor::Postgres => pattern.to_owned(),
+    };
+    RegexBuilder::new(&pattern)
+        .case_insensitive(flags.case_insensitive)
+        .multi_line(flags.multi_line)
+        .dot_matches_new_line(flags.dot_matches_new_line)
+        .ignore_whitespace(flags.ignore_whitespace)
+        .build()
+        .map_err(|e| invalid_query_err!("invalid regular expression: {e}"))
+}
+
+/// Translate the replacement string for `REGEXP_REPLACE` or `regexp_replace` into the syntax used
+/// by [`Regex::replace`].
+///
+/// MySQL refers to capture groups as `$n`, and PostgreSQL as `\n` (with `\&` referring to the
+/// whole match). In both, `\` escapes the following character.
+pub(crate) fn translate_replacement(replacement: &str, flavor: RegexFlavor) -> String {
+    let mut res = String::with_capacity(replacement.len());
+    let mut chars = replacement.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (flavor, c) {
+            (RegexFlavor::MySql, '$') if chars.peek().map_or(false, char::is_ascii_digit) => {
+                res.push_str("${");
+                while let Some(d) = chars.next_if(char::is_ascii_digit) {
+                    res.push(d);
+                }
+                res.push('}');
+            }
+            (_, '\\') => match chars.next() {
+                Some(d) if d.is_ascii_digit() && flavor != RegexFlavor::MySql => {
+                    res.push_str("${");
+                    res.push(d);
+                    res.push('}');
+                }
+                Some('&') if flavor != RegexFlavor::MySql => res.push_str("${0}"),
+                Some('$') => res.push_str("$$"),
+                Some(escaped) => res.push(escaped),
+                None => res.push('\\'),
+            },
+            (_, '$') => res.push_str("$$"),
+            _ => res.push(c),
+        }
+    }
+    res
+}
+
+#[derive(Debug)]
+struct CachedRegex {
+    pattern: String,
+    flags: RegexFlags,
+    regex: Arc<Regex>,
+}
+
+/// A cache for the most recently compiled regular expression of a single expression node.
+///
+/// Compiling a regular expression is much more expensive than matching it, and the pattern passed
+/// to a regular expression operator is almost always a literal, so holding on to the last compiled
+/// pattern means each node only compiles its pattern once. The cache isn't part of the identity of
+/// the expression, so it compares equal to every other cache, and starts out empty when cloned or
+/// deserialized.
+#[derive(Default)]
+pub struct RegexCache(Mutex<Option<CachedRegex>>);
+
+impl RegexCache {
+    /// Returns the compiled form of `pattern`, compiling it if it's not the most recently used
+    /// pattern for this cache.
+    ///
+    /// The regex is shared rather than cloned, since cloning a [`Regex`] allocates a fresh pool of
+    /// matching state, which would be done for every row.
+    pub fn get(
+        &self,
+        pattern: &str,
+        flavor: RegexFlavor,
+        flags: RegexFlags,
+    ) -> ReadySetResult<Arc<Regex>> {
+        let mut cached = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(cached) = cached.as_ref() {
+            if cached.pattern == pattern && cached.flags == flags {
+                return Ok(cached.regex.clone());
+            }
+        }
+
+        let regex = Arc::new(compile(pattern, flavor, flags)?);
+        *cached = Some(CachedRegex {
+            pattern: pattern.to_owned(),
+            flags,
+            regex: regex.clone(),
+        });
+        Ok(regex)
+    }
+}
+
+impl Clone for RegexCache {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl PartialEq for RegexCache {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for RegexCache {}
+
+impl fmt::Debug for RegexCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RegexCache").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(pattern: &str, flavor: RegexFlavor, flags: &str, s: &str) -> bool {
+        let flags = RegexFlags::parse(flavor, flags).unwrap();
+        compile(pattern, flavor, flags).unwrap().is_match(s)
+    }
+
+    #[test]
+    fn similar_to() {
+        let similar = |pattern, s| matches(pattern, RegexFlavor::SimilarTo, "", s);
+        assert!(similar("abc", "abc"));
+        assert!(!similar("a", "abc"));
+        assert!(similar("%(b|d)%", "abc"));
+        assert!(!similar("(b|c)%", "abc"));
+        assert!(similar("a_c", "abc"));
+        assert!(similar("a.c", "a.c"));
+        assert!(!similar("a.c", "abc"));
+        assert!(similar(r"a\%", "a%"));
+        assert!(!similar(r"a\%", "abc"));
+        assert!(similar("[a-c]+", "abc"));
+        assert!(similar("[%_]", "_"));
+    }
+
+    #[test]
+    fn flags() {
+        assert!(matches("^b$", RegexFlavor::MySql, "m", "a\nb"));
+        assert!(!matches("^b$", RegexFlavor::MySql, "", "a\nb"));
+        assert!(!matches("a.b", RegexFlavor::MySql, "", "a\nb"));
+        assert!(matches("a.b", RegexFlavor::MySql, "n", "a\nb"));
+        assert!(matches("a.b", RegexFlavor::Postgres, "", "a\nb"));
+        assert!(!matches("a.b", RegexFlavor::Postgres, "n", "a\nb"));
+        assert!(matches("A", RegexFlavor::Postgres, "ci", "a"));
+        assert!(!matches("A", RegexFlavor::Postgres, "ic", "a"));
+        assert!(matches("B", RegexFlavor::MySql, "", "abc"));
+        assert!(matches("B", RegexFlavor::MySql, "m", "abc"));
+        assert!(!matches("B", RegexFlavor::MySql, "c", "abc"));
+        assert!(!matches("B", RegexFlavor::Postgres, "", "abc"));
+        RegexFlags::parse(RegexFlavor::MySql, "g").unwrap_err();
+        RegexFlags::parse(RegexFlavor::Postgres, "z").unwrap_err();
+    }
+
+    #[test]
+    fn replacement() {
+        assert_eq!(
+            translate_replacement(r"<$1$12\$>", RegexFlavor::MySql),
+            "<${1}${12}$$>"
+        );
+        assert_eq!(
+            translate_replacement(r"<\1\&\\$a>", RegexFlavor::Postgres),
+            r"<${1}${0}\$$a>"
+        );
+    }
+
+    #[test]
+    fn cache_reuses_compiled_pattern() {
+        let cache = RegexCache::default();
+        let flags = RegexFlags::new(RegexFlavor::Postgres);
+        let get = |pattern| cache.get(pattern, RegexFlavor::Postgres, flags);
+
+        let regex = get("a+").unwrap();
+        assert!(regex.is_match("ba"));
+        assert!(Arc::ptr_eq(&get("a+").unwrap(), &regex));
+        assert!(!get("^a+$").unwrap().is_match("ba"));
+        get("(").unwrap_err();
+        assert!(get("a+").unwrap().is_match("aa"));
+    }
+}
@@ -1,8 +1,10 @@
+use std::cell::RefCell;
 use std::env;
 
 use mysql_async::prelude::Queryable;
 use mysql_async::{Conn, Opts, OptsBuilder, Row};
 use nom_sql::parse_sql_type;
+use proptest::prelude::*;
 use readyset_data::{DfType, DfValue};
 
 use self::common::parse_lower_eval;
@@ -151,7 +153,125 @@ async fn example_exprs_eval_same_as_mysql() {
         "concat('one',2,'three')",
         "concat('a','b')",
         "concat('a')",
+        "lower('AbÇ')",
+        "upper('AbÇ')",
+        "lcase('AbC')",
+        "ucase('AbC')",
+        "length('héllo')",
+        "char_length('héllo')",
+        "trim('  a b  ')",
+        "ltrim('  a b  ')",
+        "rtrim('  a b  ')",
+        "replace('abcabc', 'b', 'XY')",
+        "replace('abc', '', 'X')",
+        "lpad('hi', 5, 'ab')",
+        "lpad('hi', 5, '')",
+        "rpad('hello', 2, 'x')",
+        "rpad('hi', -1, 'x')",
+        "left('héllo', 2)",
+        "left('hello', -2)",
+        "right('héllo', 4)",
+        "reverse('héllo')",
+        "locate('bar', 'foobarbar')",
+        "locate('bar', 'foobarbar', 5)",
+        "locate('', 'abc', 4)",
+        "instr('foobar', 'bar')",
+        "position('b' in 'aéb')",
+        "locate('A', 'abc')",
+        "instr('fooBAR', 'bar')",
+        "position('B' in 'aéb')",
+        "lower(null)",
+        "lpad('hi', null, 'x')",
+        "'abc' regexp 'b+'",
+        "'abc' not rlike '^b'",
+        "regexp_like('abc', 'b', 'c')",
+        "regexp_replace('a b c', '[a-z]', 'X')",
+        "regexp_replace('a b c', '[a-z]', 'X', 1, 2)",
+        "regexp_replace('a b c', '([a-z])', '<$1>', 3)",
+        "'ABC' regexp 'b'",
+        "'abc' not regexp 'B'",
+        "regexp_like('aBc', 'b')",
+        "regexp_like('aBc', 'b', 'c')",
+        "regexp_like('aBc', 'b', 'm')",
+        "regexp_replace('a B c', '[a-z]', 'X')",
+        "regexp_replace('a B c', '[a-z]', 'X', 1, 0, 'c')",
+        "abs(-5)",
+        "ceil(5)",
+        "ceiling(-5)",
+        "floor(-5)",
+        "truncate(-122, -2)",
+        "sign(-3)",
+        "sign(0)",
+        "7 % 3",
+        "-7 % 3",
+        "7 % 0",
+        "mod(7, 3)",
+        "mod(7.5, 2)",
+        "7.5 % 0",
+        "power(2, 10)",
+        "pow(2, -1)",
+        "sqrt(16)",
+        "sqrt(-1)",
+        "exp(0)",
+        "ln(1)",
+        "ln(0)",
+        "log(2, 8)",
+        "log(0.5, 8)",
+        "log(1, 8)",
+        "abs(null)",
     ] {
         compare_eval(expr, &mut conn).await;
     }
 }
+
+/// Test the string functions with arbitrary strings and lengths
+#[test]
+fn string_functions_eval_same_as_mysql() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let conn = RefCell::new(rt.block_on(Conn::new(opts())).unwrap());
+
+    proptest!(|(s in "[a-zA-Z0-9 ]{0,12}", sub in "[a-cA ]{0,2}", n in -15i64..15)| {
+        let mut conn = conn.borrow_mut();
+        for expr in [
+            format!("lower('{s}')"),
+            format!("upper('{s}')"),
+            format!("length('{s}')"),
+            format!("char_length('{s}')"),
+            format!("trim('{s}')"),
+            format!("ltrim('{s}')"),
+            format!("rtrim('{s}')"),
+            format!("replace('{s}', '{sub}', 'xy')"),
+            format!("lpad('{s}', {n}, '{sub}')"),
+            format!("rpad('{s}', {n}, '{sub}')"),
+            format!("left('{s}', {n})"),
+            format!("right('{s}', {n})"),
+            format!("reverse('{s}')"),
+            format!("locate('{sub}', '{s}')"),
+            format!("locate('{sub}', '{s}', {n})"),
+            format!("instr('{s}', '{sub}')"),
+            format!("position('{sub}' in '{s}')"),
+        ] {
+            rt.block_on(compare_eval(&expr, &mut conn));
+        }
+    });
+}
+
+/// Test the integer math functions and operators with arbitrary integers
+#[test]
+fn integer_math_eval_same_as_mysql() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let conn = RefCell::new(rt.block_on(Conn::new(opts())).unwrap());
+
+    proptest!(|(a in -1000i64..1000, b in -50i64..50)| {
+        let mut conn = conn.borrow_mut();
+        for expr in [
+            format!("abs({a})"),
+            format!("sign({a})"),
+            format!("{a} % {b}"),
+            format!("mod({a}, {b})"),
+            format!("truncate({a}, {})", b % 4),
+        ] {
+            rt.block_on(compare_eval(&expr, &mut conn));
+        }
+    });
+}
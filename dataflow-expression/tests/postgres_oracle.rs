@@ -103,11 +103,121 @@ fn example_exprs_eval_same_as_postgres() {
         "split_part('a.b.c', '.', 4)",
         "split_part('a.b.c', '.', -1)",
         "split_part('a.b.c', '.', -4)",
+        "lower('AbÇ')",
+        "upper('AbÇ')",
+        "length('héllo')",
+        "char_length('héllo')",
+        "octet_length('héllo')",
+        "trim('  a b  ')",
+        "ltrim('  a b  ')",
+        "rtrim('  a b  ')",
+        "btrim('xyaxy', 'yx')",
+        "ltrim('xyaxy', 'yx')",
+        "replace('abcabc', 'b', 'XY')",
+        "replace('abc', '', 'X')",
+        "lpad('hi', 5, 'ab')",
+        "lpad('hi', 5, '')",
+        "lpad('hi', 4)",
+        "rpad('hello', 2, 'x')",
+        "rpad('hi', -1, 'x')",
+        "left('héllo', 2)",
+        "left('hello', -2)",
+        "right('héllo', 4)",
+        "right('hello', -2)",
+        "reverse('héllo')",
+        "position('b' in 'aéb')",
+        "strpos('abc', '')",
+        "initcap('hi THOMAS o''neil-smith')",
+        "lower(null)",
+        "lpad('hi', null)",
+        "'abc' ~ 'b+'",
+        "'abc' ~* 'B+'",
+        "'abc' !~ 'B+'",
+        "'abc' !~* '^b'",
+        "'abc' similar to '%(b|d)%'",
+        "'abc' not similar to 'a_c'",
+        "regexp_replace('a b c', '[a-z]', 'X')",
+        "regexp_replace('a b c', '[A-Z]', 'X', 'gi')",
+        "regexp_match('foobarbequebaz', '(bar)(beque)')",
+        "regexp_match('foobar', 'baz')",
+        "abs(-5)",
+        "ceil(5)",
+        "ceiling(-5)",
+        "floor(-5)",
+        "trunc(-5)",
+        "sign(-3)",
+        "sign(0)",
+        "7 % 3",
+        "-7 % 3",
+        "mod(7, 3)",
+        "power(2, 10)",
+        "sqrt(16)",
+        "exp(0)",
+        "ln(1)",
+        "log(100)",
+        "abs(null)",
+        "abs(cast(-5.5 as numeric))",
+        "ceil(cast(5.5 as numeric))",
+        "floor(cast(-5.5 as numeric))",
+        "trunc(cast(-5.5 as numeric))",
+        "trunc(cast(5.678 as numeric), 2)",
+        "sign(cast(-2.5 as numeric))",
     ] {
         compare_eval(expr, &mut client);
     }
 }
 
+/// Test the string functions with arbitrary (ASCII, to avoid depending on the locale of the
+/// database) strings and lengths
+#[test]
+fn string_functions() {
+    let client = RefCell::new(config().connect(NoTls).unwrap());
+
+    proptest!(|(s in "[a-zA-Z0-9 ]{0,12}", sub in "[a-cA ]{0,2}", n in -15i64..15)| {
+        let mut client = client.borrow_mut();
+        for expr in [
+            format!("lower('{s}')"),
+            format!("upper('{s}')"),
+            format!("length('{s}')"),
+            format!("octet_length('{s}')"),
+            format!("trim('{s}')"),
+            format!("btrim('{s}', '{sub}')"),
+            format!("ltrim('{s}', '{sub}')"),
+            format!("rtrim('{s}', '{sub}')"),
+            format!("replace('{s}', '{sub}', 'xy')"),
+            format!("lpad('{s}', {n}, '{sub}')"),
+            format!("rpad('{s}', {n})"),
+            format!("left('{s}', {n})"),
+            format!("right('{s}', {n})"),
+            format!("reverse('{s}')"),
+            format!("strpos('{s}', '{sub}')"),
+            format!("position('{sub}' in '{s}')"),
+            format!("initcap('{s}')"),
+        ] {
+            compare_eval(&expr, &mut client);
+        }
+    });
+}
+
+/// Test the integer math functions and operators with arbitrary integers
+#[test]
+fn integer_math() {
+    let client = RefCell::new(config().connect(NoTls).unwrap());
+
+    proptest!(|(a in -1000i64..1000, b in 1i64..50)| {
+        let mut client = client.borrow_mut();
+        for expr in [
+            format!("abs({a})"),
+            format!("sign({a})"),
+            format!("{a} % {b}"),
+            format!("{a} % -{b}"),
+            format!("mod({a}, {b})"),
+        ] {
+            compare_eval(&expr, &mut client);
+        }
+    });
+}
+
 // Normalize the UTC offset to hours and minutes (dropping any seconds).
 // This is currently necessary as `chrono::FixedOffset` does not correctly
 // parse seconds in all circumstances (https://github.com/chronotope/chrono/pull/1083).
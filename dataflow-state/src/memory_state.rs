@@ -261,36 +261,12 @@ impl State for MemoryState {
     /// strongly referenced `state`, then they are removed from the weakly referenced
     /// `weak_indices`.
     fn evict_bytes(&mut self, bytes: usize) -> Option<EvictBytesResult> {
-        let mut rng = rand::thread_rng();
-        let state_index = rng.gen_range(0..self.state.len());
-        let mut bytes_freed = 0u64;
-        let mut keys_evicted = Vec::new();
-
-        while bytes_freed < bytes as u64 {
-            let evicted = self.state[state_index].evict_random(&mut rng);
-
-            if evicted.is_none() {
-                // There are no more keys in this state.
-                break;
-            }
-
-            let (keys, rows) = evicted?;
-            rows.iter()
-                .for_each(|row| bytes_freed += self.handle_evicted_row(row));
-            bytes_freed += base_row_bytes(&keys);
-            keys_evicted.push(keys);
-        }
-
-        if bytes_freed == 0 {
-            return None;
-        }
-
-        self.mem_size = self.mem_size.saturating_sub(bytes_freed);
-        return Some(EvictBytesResult {
-            index: self.state[state_index].index(),
-            keys_evicted,
-            bytes_freed,
-        });
+        let evicted = self.evict_random_keys(bytes)?;
+        Some(EvictBytesResult {
+            index: self.state[evicted.state_index].index(),
+            keys_evicted: evicted.keys.into_iter().map(|(key, _)| key).collect(),
+            bytes_freed: evicted.bytes_freed,
+        })
     }
 
     /// Evicts the given `keys` for the state associated with the target of the given `tag`
@@ -413,7 +389,75 @@ impl State for MemoryState {
     }
 }
 
+/// Keys evicted from a [`MemoryState`] by [`MemoryState::evict_random_keys`], along with the rows
+/// that were materialized for those keys
+pub(crate) struct EvictedKeys {
+    /// The position in `MemoryState::state` of the index that was evicted from
+    pub(crate) state_index: usize,
+    /// The keys that were evicted, and the rows that were removed for each key
+    pub(crate) keys: Vec<(Vec<DfValue>, Rows)>,
+    /// The number of bytes removed from the state
+    pub(crate) bytes_freed: u64,
+}
+
 impl MemoryState {
+    /// Evicts `bytes` by evicting random keys from a randomly chosen strict index, returning the
+    /// rows that were evicted along with each key. The rows are also removed from the weakly
+    /// referenced `weak_indices`.
+    pub(crate) fn evict_random_keys(&mut self, bytes: usize) -> Option<EvictedKeys> {
+        let mut rng = rand::thread_rng();
+        let state_index = rng.gen_range(0..self.state.len());
+        let mut bytes_freed = 0u64;
+        let mut keys = Vec::new();
+
+        while bytes_freed < bytes as u64 {
+            let Some((key, rows)) = self.state[state_index].evict_random(&mut rng) else {
+                // There are no more keys in this state.
+                break;
+            };
+
+            rows.iter()
+                .for_each(|row| bytes_freed += self.handle_evicted_row(row));
+            bytes_freed += base_row_bytes(&key);
+            keys.push((key, rows));
+        }
+
+        if bytes_freed == 0 {
+            return None;
+        }
+
+        self.mem_size = self.mem_size.saturating_sub(bytes_freed);
+        Some(EvictedKeys {
+            state_index,
+            keys,
+            bytes_freed,
+        })
+    }
+
+    /// Returns the index at the given position in `self.state`, along with one of the tags of the
+    /// replay paths that target that index, if any
+    pub(crate) fn index_and_tag(&self, state_index: usize) -> (&Index, Option<Tag>) {
+        let tag = self
+            .by_tag
+            .iter()
+            .find(|(_, i)| **i == state_index)
+            .map(|(tag, _)| *tag);
+        (self.state[state_index].index(), tag)
+    }
+
+    /// Returns true if the given row is materialized in any of the strict indices of this state
+    pub(crate) fn contains_row(&self, row: &[DfValue]) -> bool {
+        self.state.iter().any(|state| {
+            let key = PointKey::from(state.columns().iter().map(|c| row[*c].clone()));
+            state
+                .lookup(&key)
+                .records()
+                .into_iter()
+                .flatten()
+                .any(|r| *r == *row)
+        })
+    }
+
     /// Returns the index in `self.state` of the index keyed on `cols` and with the given
     /// `index_type`, or None if no such index exists.
     fn state_for(&self, cols: &[usize], index_type: IndexType) -> Option<usize> {
@@ -1,7 +1,8 @@
 //! Node state that's persisted to disk
 //!
-//! The [`PersistedState`] struct is an implementation of [`State`] that stores rows (currently only
-//! for base tables) in [RocksDB], an on-disk key-value store. The data is stored in
+//! The [`PersistedState`] struct is an implementation of [`State`] that stores rows (for base
+//! tables, and for partially materialized nodes that spill their state to disk) in [RocksDB], an
+//! on-disk key-value store. The data is stored in
 //! [indices](PersistentState::indices) - each lookup index stores the copies of all the rows in the
 //! database.
 //!
@@ -60,12 +61,31 @@
 //! that replication log of the last record that we have successfully applied. To maintain
 //! atomicity, these offsets are stored inside of rocksdb as part of the persisted
 //! [`PersistentMeta`], and updated as part of every write.
+//!
+//! # Partial materialization
+//!
+//! If the first index added to a [`PersistentState`] is a partial index (one with replay path
+//! tags), the state is *partially* materialized. The set of keys (and, for [`BTreeMap`] indices,
+//! ranges of keys) that are filled in each partial index is tracked in memory, in
+//! [`PartialState`], while the rows themselves are only ever stored on disk. Lookups into holes
+//! return misses, and writes to keys that are holes in every partial index are dropped, exactly
+//! as for partial [`MemoryState`](crate::MemoryState).
+//!
+//! Since each row is only stored once, regardless of how many partial indices it's materialized
+//! in, we maintain the invariant that a row is on disk if and only if its key is filled in at
+//! least one partial index: marking a key as a hole only deletes the rows that aren't covered by
+//! another partial index, and marking a key as filled first deletes any rows already on disk for
+//! that key, since the replay that fills it will contain them again.
+//!
+//! Partial state can always be recomputed from upstream, and is never reopened after a restart,
+//! so writes to it skip the RocksDB WAL entirely.
 mod handle;
 
 use std::borrow::Cow;
 use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
 use std::io::{self, Read};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::mpsc::RecvTimeoutError;
 use std::sync::{mpsc, Arc};
@@ -78,6 +98,7 @@ use clap::ValueEnum;
 use common::{IndexType, Record, Records, SizeOf, Tag};
 pub use handle::PersistentStateHandle;
 use handle::{PersistentStateReadGuard, PersistentStateWriteGuard};
+use partial_map::PartialMap;
 use rand::Rng;
 use readyset_alloc::thread::StdThreadBuildWrapper;
 use readyset_client::debug::info::KeyCount;
@@ -86,20 +107,22 @@ use readyset_client::{KeyComparison, SqlIdentifier};
 use readyset_data::{Bound, BoundedRange, DfValue};
 use readyset_errors::{internal_err, invariant, ReadySetError, ReadySetResult};
 use replication_offset::ReplicationOffset;
+use rocksdb::checkpoint::Checkpoint;
 use rocksdb::{
     self, BlockBasedOptions, ColumnFamilyDescriptor, CompactOptions, IteratorMode, SliceTransform,
     WriteBatch, DB,
 };
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
-use tempfile::{tempdir, TempDir};
+use tempfile::{tempdir, tempdir_in, TempDir};
 use test_strategy::Arbitrary;
 use thiserror::Error;
 use tracing::{debug, error, info, info_span, instrument, trace, warn};
+use vec1::Vec1;
 
 use crate::{
-    EvictKeysResult, EvictRandomResult, LookupResult, PersistencePoint, PointKey, RangeKey,
-    RangeLookupResult, RecordResult, State,
+    EvictBytesResult, EvictKeysResult, EvictRandomResult, LookupResult, PersistencePoint, PointKey,
+    RangeKey, RangeLookupResult, RecordResult, State,
 };
 
 // Incremented on each PersistentState initialization so that IndexSeq
@@ -431,6 +454,133 @@ pub struct PersistentState {
     snapshot_mode: SnapshotMode,
     compaction_threads: Vec<CompactionThreadHandle>,
     wal_flush_thread_handle: Option<(mpsc::Sender<()>, JoinHandle<()>)>,
+    /// If this state is partially materialized, the set of keys that are filled in each of its
+    /// partial indices. See [the module documentation](self#partial-materialization)
+    partial: Option<PartialState>,
+}
+
+/// Tracks the keys that are filled in each partial index of a partially materialized
+/// [`PersistentState`]
+#[derive(Debug, Default)]
+struct PartialState {
+    /// Map from the tag of each replay path that targets this state to the position in `indices`
+    /// of the index it fills
+    by_tag: HashMap<Tag, usize>,
+    /// The partial indices of this state
+    indices: Vec<PartialIndex>,
+    /// The number of bytes used to track filled keys
+    mem_size: u64,
+}
+
+#[derive(Debug)]
+struct PartialIndex {
+    index: Index,
+    /// The keys and ranges of keys which are filled in this index
+    filled: PartialMap<Vec<DfValue>, ()>,
+}
+
+impl PartialState {
+    fn add_index(&mut self, index: Index, tags: Vec<Tag>) {
+        let i = match self.indices.iter().position(|pi| pi.index == index) {
+            Some(i) => i,
+            None => {
+                self.indices.push(PartialIndex {
+                    index,
+                    filled: PartialMap::new(),
+                });
+                self.indices.len() - 1
+            }
+        };
+
+        for tag in tags {
+            self.by_tag.insert(tag, i);
+        }
+    }
+
+    /// Returns the position in `indices` of the index filled by the given `tag`
+    #[allow(clippy::panic)] // Documented invariant of `State::mark_filled` and `State::mark_hole`
+    fn index_for_tag(&self, tag: Tag) -> usize {
+        *self
+            .by_tag
+            .get(&tag)
+            .unwrap_or_else(|| panic!("Unknown partial tag {tag:?}"))
+    }
+
+    /// Returns true if the given point `key` is filled in any partial index on `columns`, or if
+    /// there are no partial indices on `columns` at all
+    fn is_filled(&self, columns: &[usize], key: &[DfValue]) -> bool {
+        let mut indices = self
+            .indices
+            .iter()
+            .filter(|pi| pi.index.columns == columns)
+            .peekable();
+
+        indices.peek().is_none() || indices.any(|pi| pi.filled.contains_key(key))
+    }
+
+    /// Returns true if the key of the given `row` is filled in any of our partial indices
+    fn covers(&self, row: &[DfValue]) -> bool {
+        self.indices.iter().any(|pi| {
+            let key = pi
+                .index
+                .columns
+                .iter()
+                .map(|c| row[*c].clone())
+                .collect::<Vec<_>>();
+            pi.filled.contains_key(&key)
+        })
+    }
+
+    fn mark_filled(&mut self, i: usize, key: KeyComparison) {
+        match key {
+            KeyComparison::Equal(key) => {
+                let key = key.into_vec();
+                self.mem_size += key_bytes(&key);
+                self.indices[i].filled.insert(key, ());
+            }
+            KeyComparison::Range((lower, upper)) => {
+                self.indices[i]
+                    .filled
+                    .insert_range((lower.map(Vec1::into_vec), upper.map(Vec1::into_vec)));
+            }
+        }
+    }
+
+    /// Mark the given `key` as a hole in the index at position `i`, returning true if it was
+    /// previously filled
+    fn mark_hole(&mut self, i: usize, key: &KeyComparison) -> bool {
+        let filled = &mut self.indices[i].filled;
+        match key {
+            KeyComparison::Equal(key) => {
+                if !filled.contains_key(key.as_slice()) {
+                    return false;
+                }
+                filled.remove(key.as_slice());
+                self.mem_size = self.mem_size.saturating_sub(key_bytes(key));
+                true
+            }
+            KeyComparison::Range((lower, upper)) => {
+                let range = (
+                    lower.clone().map(Vec1::into_vec),
+                    upper.clone().map(Vec1::into_vec),
+                );
+                if !filled.overlaps_range(&range) {
+                    return false;
+                }
+                let freed = filled
+                    .remove_range::<Vec<DfValue>, _>(range)
+                    .map(|(key, _)| key_bytes(&key))
+                    .sum::<u64>();
+                self.mem_size = self.mem_size.saturating_sub(freed);
+                true
+            }
+        }
+    }
+}
+
+/// The number of bytes used to track the given key as filled in a [`PartialState`]
+fn key_bytes(key: &[DfValue]) -> u64 {
+    key.iter().map(SizeOf::deep_size_of).sum::<u64>() + mem::size_of::<Vec<DfValue>>() as u64
 }
 
 /// Things that are shared between read handles and the state itself, that can be locked under a
@@ -630,6 +780,10 @@ impl State for PersistentState {
         partial_tag: Option<Tag>,
         replication_offset: Option<ReplicationOffset>,
     ) -> ReadySetResult<()> {
+        if self.partial.is_some() {
+            return self.process_partial_records(records, partial_tag);
+        }
+
         invariant!(partial_tag.is_none(), "PersistentState can't be partial");
 
         // Streamline the records by eliminating pairs that would negate each other.
@@ -654,7 +808,9 @@ impl State for PersistentState {
                     self.insert(&mut batch, r)?;
                 }
                 Record::Negative(ref r) => {
-                    self.remove(&mut batch, r)?;
+                    if !self.remove(&mut batch, r, &mut HashSet::new())? {
+                        return Err(internal_err!("tried removing non-existent row"));
+                    }
                 }
             }
         }
@@ -684,10 +840,29 @@ impl State for PersistentState {
     }
 
     fn lookup(&self, columns: &[usize], key: &PointKey) -> LookupResult {
-        self.db.lookup(columns, key)
+        match &self.partial {
+            Some(partial) => {
+                if !partial.is_filled(columns, &point_key_values(key)) {
+                    return LookupResult::Missing;
+                }
+                LookupResult::Some(RecordResult::Owned(self.lookup_rows(columns, key)))
+            }
+            None => self.db.lookup(columns, key),
+        }
     }
 
     fn lookup_range<'a>(&'a self, columns: &[usize], key: &RangeKey) -> RangeLookupResult<'a> {
+        if let Some(partial) = &self.partial {
+            let range = key.as_bounded_range();
+            for pi in partial.indices.iter().filter(|pi| {
+                pi.index.columns == columns && pi.index.index_type == IndexType::BTreeMap
+            }) {
+                if let Err(misses) = pi.filled.range::<_, Vec<DfValue>>(&range) {
+                    return RangeLookupResult::Missing(misses);
+                }
+            }
+        }
+
         self.db.lookup_range(columns, key)
     }
 
@@ -700,13 +875,25 @@ impl State for PersistentState {
     }
 
     /// Add a new index to the table, the first index we add will contain the data
-    /// each additional index we add, will contain pointers to the primary index
-    /// Panics if partial is Some
+    /// each additional index we add, will contain pointers to the primary index.
+    ///
+    /// If the first index we add is partial, this state becomes partially materialized, and all
+    /// further indices must either be partial or weak. Panics if a partial index is added to a
+    /// fully materialized state.
     fn add_index(&mut self, index: Index, partial: Option<Vec<Tag>>) {
-        #[allow(clippy::panic)] // This should definitely never happen!
-        {
-            assert!(partial.is_none(), "Bases can't be partial");
+        if let Some(tags) = partial {
+            #[allow(clippy::panic)] // This should definitely never happen!
+            {
+                assert!(
+                    self.partial.is_some() || !self.is_useful(),
+                    "Can't add a partial index to a fully materialized PersistentState"
+                );
+            }
+            self.partial
+                .get_or_insert_with(Default::default)
+                .add_index(index.clone(), tags);
         }
+
         let columns = &index.columns;
         let existing = self
             .db
@@ -717,7 +904,7 @@ impl State for PersistentState {
             .any(|pi| pi.index == index);
 
         if existing {
-            self.db.add_index(index, partial);
+            self.db.add_index(index, None);
             return;
         }
 
@@ -741,8 +928,15 @@ impl State for PersistentState {
     /// Returns a *row* count estimate from RocksDB (not a key count as the function name would
     /// suggest), since getting a key count could be quite expensive, and we care less about the
     /// key count of persistent nodes anyway.
+    ///
+    /// For partial state, returns the exact number of filled keys instead.
     fn key_count(&self) -> KeyCount {
-        KeyCount::EstimatedRowCount(self.row_count())
+        match &self.partial {
+            Some(partial) => {
+                KeyCount::ExactKeyCount(partial.indices.iter().map(|pi| pi.filled.num_keys()).sum())
+            }
+            None => KeyCount::EstimatedRowCount(self.row_count()),
+        }
     }
 
     /// Returns a row count estimate from RocksDB.
@@ -755,7 +949,7 @@ impl State for PersistentState {
     }
 
     fn is_partial(&self) -> bool {
-        false
+        self.partial.is_some()
     }
 
     fn replay_done(&self) -> bool {
@@ -763,40 +957,101 @@ impl State for PersistentState {
         true
     }
 
-    /// Panics if called
-    #[allow(clippy::unreachable)] // this should never happen!
-    fn mark_filled(&mut self, _: KeyComparison, _: Tag) {
-        unreachable!("PersistentState can't be partial")
+    /// Panics if this state is fully materialized
+    fn mark_filled(&mut self, key: KeyComparison, tag: Tag) {
+        let i = self.expect_partial().index_for_tag(tag);
+        let columns = self.expect_partial().indices[i].index.columns.clone();
+
+        // Any rows we already have for this key are only on disk because they're covered by a
+        // filled key in another partial index. The replay that fills this key will contain them
+        // again, so delete them now to avoid duplicating them.
+        let rows = self.lookup_comparison_rows(&columns, &key);
+        self.delete_rows(rows);
+
+        self.expect_partial_mut().mark_filled(i, key);
     }
 
-    /// Panics if called
-    #[allow(clippy::unreachable)] // this should never happen!
-    fn mark_hole(&mut self, _: &KeyComparison, _: Tag) {
-        unreachable!("PersistentState can't be partial")
+    /// Panics if this state is fully materialized
+    fn mark_hole(&mut self, key: &KeyComparison, tag: Tag) {
+        let i = self.expect_partial().index_for_tag(tag);
+        self.evict_partial_keys(i, std::slice::from_ref(key));
     }
 
-    /// Panics if called
-    #[allow(clippy::unreachable)] // this should never happen!
-    fn evict_bytes(&mut self, _: usize) -> Option<super::EvictBytesResult> {
-        unreachable!("can't evict keys from PersistentState")
+    /// Evicts randomly chosen keys from one of the partial indices of this state, until the
+    /// tracking of filled keys uses `bytes` fewer bytes of memory.
+    ///
+    /// Panics if this state is fully materialized
+    fn evict_bytes(&mut self, bytes: usize) -> Option<EvictBytesResult> {
+        let mut rng = rand::thread_rng();
+        let candidates = self
+            .expect_partial()
+            .indices
+            .iter()
+            .enumerate()
+            .filter(|(_, pi)| pi.filled.num_keys() > 0)
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+        if candidates.is_empty() {
+            return None;
+        }
+        let i = candidates[rng.gen_range(0..candidates.len())];
+
+        let mut keys_evicted = Vec::new();
+        let mut bytes_freed = 0;
+        while bytes_freed < bytes as u64 {
+            let Some(key) = self.random_filled_key(i, &mut rng) else {
+                break;
+            };
+            bytes_freed += self.evict_partial_keys(i, &[KeyComparison::from(key.clone())]);
+            keys_evicted.push(key.into_vec());
+        }
+
+        Some(EvictBytesResult {
+            index: &self.expect_partial().indices[i].index,
+            keys_evicted,
+            bytes_freed,
+        })
     }
 
-    /// Panics if called
-    #[allow(clippy::unreachable)] // this should never happen!
-    fn evict_keys(&mut self, _: Tag, _: &[KeyComparison]) -> Option<EvictKeysResult> {
-        unreachable!("can't evict keys from PersistentState")
+    /// Panics if this state is fully materialized
+    fn evict_keys(&mut self, tag: Tag, keys: &[KeyComparison]) -> Option<EvictKeysResult> {
+        // we may be told to evict from a tag that add_index hasn't been called for yet, just like
+        // for MemoryState
+        let i = *self.expect_partial().by_tag.get(&tag)?;
+        let bytes_freed = self.evict_partial_keys(i, keys);
+        Some(EvictKeysResult {
+            index: &self.expect_partial().indices[i].index,
+            bytes_freed,
+        })
     }
 
-    /// Panics if called
-    #[allow(clippy::unreachable)] // this should never happen!
-    fn evict_random<R: rand::Rng>(&mut self, _: Tag, _: &mut R) -> Option<EvictRandomResult> {
-        unreachable!("can't evict keys from PersistentState")
+    /// Panics if this state is fully materialized
+    fn evict_random<R: rand::Rng>(&mut self, tag: Tag, rng: &mut R) -> Option<EvictRandomResult> {
+        let i = *self.expect_partial().by_tag.get(&tag)?;
+        let key = self.random_filled_key(i, rng)?;
+        let bytes_freed = self.evict_partial_keys(i, &[KeyComparison::from(key.clone())]);
+        Some(EvictRandomResult {
+            index: &self.expect_partial().indices[i].index,
+            key_evicted: key.into_vec(),
+            bytes_freed,
+        })
     }
 
-    /// Panics if called
-    #[allow(clippy::unreachable)] // this should never happen!
+    /// Panics if this state is fully materialized
     fn clear(&mut self) {
-        unreachable!("can't clear PersistentState")
+        let partial = self.expect_partial_mut();
+        for pi in &mut partial.indices {
+            pi.filled = PartialMap::new();
+        }
+        partial.mem_size = 0;
+
+        let rows = self
+            .read_handle()
+            .all_records()
+            .read()
+            .iter()
+            .collect::<Vec<_>>();
+        self.delete_rows(rows);
     }
 
     fn add_weak_index(&mut self, index: Index) {
@@ -804,7 +1059,10 @@ impl State for PersistentState {
     }
 
     fn lookup_weak<'a>(&'a self, columns: &[usize], key: &PointKey) -> Option<RecordResult<'a>> {
-        self.db.lookup_weak(columns, key)
+        // Weak lookups into partial state must never return an empty result
+        self.db
+            .lookup_weak(columns, key)
+            .filter(|rows| self.partial.is_none() || !rows.is_empty())
     }
 
     fn shut_down(&mut self) -> ReadySetResult<()> {
@@ -1057,6 +1315,11 @@ fn build_key(row: &[DfValue], columns: &[usize]) -> PointKey {
     PointKey::from(columns.iter().map(|i| row[*i].clone()))
 }
 
+/// Returns the values in the given (non-empty) [`PointKey`]
+fn point_key_values(key: &PointKey) -> Vec<DfValue> {
+    (0..key.len()).filter_map(|i| key.get(i).cloned()).collect()
+}
+
 /// Our RocksDB keys come in three forms, and are encoded as follows:
 ///
 /// * Unique Primary Keys
@@ -1424,7 +1687,15 @@ impl PersistentState {
                 (None, path)
             }
             _ => {
-                let dir = tempdir()?;
+                let dir = match &params.storage_dir {
+                    Some(storage_dir) => {
+                        if !storage_dir.is_dir() {
+                            fs::create_dir_all(storage_dir)?;
+                        }
+                        tempdir_in(storage_dir)?
+                    }
+                    None => tempdir()?,
+                };
                 let path = dir.path().join(&name);
                 (Some(dir), path)
             }
@@ -1590,6 +1861,7 @@ impl PersistentState {
             snapshot_mode: SnapshotMode::SnapshotModeDisabled,
             compaction_threads: vec![],
             wal_flush_thread_handle,
+            partial: None,
         };
 
         if let Some(pk) = state.unique_keys.first().cloned() {
@@ -1608,6 +1880,74 @@ impl PersistentState {
         self.db.clone()
     }
 
+    /// Creates a consistent on-disk checkpoint of the RocksDB database backing this state, in a
+    /// new directory in `dir` with the same name as the database. The checkpoint includes the
+    /// current replication offset of the state.
+    ///
+    /// Files are hard-linked into the checkpoint where possible, so creating a checkpoint on the
+    /// same filesystem as the database is cheap.
+    pub fn create_checkpoint(&self, dir: &Path) -> Result<PathBuf> {
+        if !dir.is_dir() {
+            fs::create_dir_all(dir)?;
+        }
+        let path = dir.join(self.name.as_str());
+
+        let inner = self.db.inner();
+        Checkpoint::new(&inner.db)?.create_checkpoint(&path)?;
+        info!(base = %self.name, path = %path.display(), "Created checkpoint");
+
+        Ok(path)
+    }
+
+    /// Restores all the database checkpoints in `checkpoint_dir` that were created by
+    /// [`create_checkpoint`](Self::create_checkpoint) into `storage_dir`, so that they will be
+    /// opened (along with their replication offsets) by the next [`PersistentState`] with the same
+    /// name and [`DurabilityMode::Permanent`].
+    ///
+    /// Returns an error without restoring anything if any of the databases already exist in
+    /// `storage_dir`.
+    pub fn restore_checkpoints(checkpoint_dir: &Path, storage_dir: &Path) -> Result<Vec<PathBuf>> {
+        let checkpoints = fs::read_dir(checkpoint_dir)?
+            .map(|entry| Ok(entry?.path()))
+            .filter(|path| {
+                path.as_ref().map_or(true, |path| {
+                    path.is_dir() && path.extension().map_or(false, |ext| ext == "db")
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        for checkpoint in &checkpoints {
+            #[allow(clippy::unwrap_used)] // read_dir never returns paths ending in `..`
+            let target = storage_dir.join(checkpoint.file_name().unwrap());
+            if target.exists() {
+                return Err(Error::Io(io::Error::new(
+                    io::ErrorKind::AlreadyExists,
+                    format!("{} already exists", target.display()),
+                )));
+            }
+        }
+
+        let mut restored = Vec::with_capacity(checkpoints.len());
+        for checkpoint in checkpoints {
+            #[allow(clippy::unwrap_used)] // read_dir never returns paths ending in `..`
+            let target = storage_dir.join(checkpoint.file_name().unwrap());
+            fs::create_dir_all(&target)?;
+            for file in fs::read_dir(&checkpoint)? {
+                let file = file?.path();
+                #[allow(clippy::unwrap_used)] // read_dir never returns paths ending in `..`
+                let dest = target.join(file.file_name().unwrap());
+                // Checkpoints are immutable, so hard-link their files if we can
+                if fs::hard_link(&file, &dest).is_err() {
+                    fs::copy(&file, &dest)?;
+                }
+            }
+            info!(path = %target.display(), "Restored checkpoint");
+            restored.push(target);
+        }
+
+        Ok(restored)
+    }
+
     /// Adds a new primary index, assuming there are none present
     fn add_primary_index(&mut self, columns: &[usize], is_unique: bool) -> Result<()> {
         if self.db.inner().shared_state.indices.is_empty() {
@@ -1822,6 +2162,63 @@ impl PersistentState {
         }
     }
 
+    /// Mark the given `key` as filled in the partial index for `tag`, with `rows` as all of the
+    /// rows materialized for it.
+    ///
+    /// Unlike [`State::mark_filled`] followed by a replay, this never deletes rows which are
+    /// already on disk because they're covered by a filled key in another partial index - they
+    /// just aren't written again. Everything is written in a single batch, so if that write fails
+    /// the error is returned and both the rows on disk and the key (which remains a hole) are left
+    /// unchanged.
+    ///
+    /// Panics if this state is fully materialized
+    pub fn fill_with_rows(
+        &mut self,
+        key: KeyComparison,
+        tag: Tag,
+        mut rows: Vec<Vec<DfValue>>,
+    ) -> ReadySetResult<()> {
+        let partial = self.expect_partial();
+        let i = partial.index_for_tag(tag);
+        let columns = partial.indices[i].index.columns.clone();
+
+        let mut batch = WriteBatch::default();
+        let mut removed = HashSet::new();
+        for row in self.lookup_comparison_rows(&columns, &key) {
+            if self.expect_partial().covers(&row) {
+                if let Some(pos) = rows.iter().position(|r| *r == row) {
+                    rows.swap_remove(pos);
+                }
+            } else {
+                // Left behind by a previous eviction of this key which failed to delete its rows
+                self.remove(&mut batch, &row, &mut removed)?;
+            }
+        }
+        for row in &rows {
+            self.insert(&mut batch, row)?;
+        }
+        self.write_to_db(batch, &None)?;
+
+        self.expect_partial_mut().mark_filled(i, key);
+        Ok(())
+    }
+
+    /// Mark the given `keys` as holes in the partial index for `tag`, and delete any rows
+    /// materialized for them that aren't covered by a filled key in another partial index.
+    /// Returns the number of bytes of memory freed.
+    ///
+    /// Unlike [`State::evict_keys`], this returns an error rather than panicking if the rows can't
+    /// be deleted from disk. The keys are marked as holes either way, and any rows left behind are
+    /// deleted when the keys are next filled.
+    ///
+    /// Panics if this state is fully materialized
+    pub fn try_evict_keys(&mut self, tag: Tag, keys: &[KeyComparison]) -> ReadySetResult<u64> {
+        let i = self.expect_partial().index_for_tag(tag);
+        let (bytes_freed, rows) = self.mark_partial_holes(i, keys);
+        self.try_delete_rows(rows)?;
+        Ok(bytes_freed)
+    }
+
     fn enable_snapshot_mode(&mut self) {
         self.db.replication_offset = None; // Remove any replication offset first (although it should be None already)
         let meta = self.meta();
@@ -1947,7 +2344,18 @@ impl PersistentState {
         Ok(())
     }
 
-    fn remove(&self, batch: &mut WriteBatch, r: &[DfValue]) -> ReadySetResult<()> {
+    /// Removes one copy of the row from the database, from all of the column families, as part of
+    /// the given [`rocksdb::WriteBatch`]. Primary keys which have already been removed as part of
+    /// the same batch are recorded in `removed`, so that removing multiple copies of the same row
+    /// removes distinct copies.
+    ///
+    /// Returns false if there was no (remaining) copy of the row to remove.
+    fn remove(
+        &self,
+        batch: &mut WriteBatch,
+        r: &[DfValue],
+        removed: &mut HashSet<Vec<u8>>,
+    ) -> ReadySetResult<bool> {
         let inner = self.db.inner();
 
         let primary_index = inner
@@ -1971,13 +2379,14 @@ impl PersistentState {
             iter.seek(&prefix); // Find the first key
 
             loop {
-                let key = iter
-                    .key()
-                    .filter(|k| k.starts_with(&prefix))
-                    .ok_or_else(|| internal_err!("tried removing non-existent row"))?;
-                let val = deserialize_row(iter.value().unwrap());
-                if val == r {
-                    break key.to_vec();
+                let Some(key) = iter.key().filter(|k| k.starts_with(&prefix)) else {
+                    return Ok(false);
+                };
+                if !removed.contains(key) {
+                    let val = deserialize_row(iter.value().unwrap());
+                    if val == r {
+                        break key.to_vec();
+                    }
                 }
                 iter.next();
             }
@@ -2001,7 +2410,182 @@ impl PersistentState {
             batch.delete_cf(cf, &serialized_key);
         }
 
-        Ok(())
+        removed.insert(serialized_pk);
+
+        Ok(true)
+    }
+
+    #[allow(clippy::unreachable)] // Only called from methods documented to panic on full state
+    fn expect_partial(&self) -> &PartialState {
+        match &self.partial {
+            Some(partial) => partial,
+            None => unreachable!("PersistentState is not partial"),
+        }
+    }
+
+    #[allow(clippy::unreachable)] // Only called from methods documented to panic on full state
+    fn expect_partial_mut(&mut self) -> &mut PartialState {
+        match &mut self.partial {
+            Some(partial) => partial,
+            None => unreachable!("PersistentState is not partial"),
+        }
+    }
+
+    /// Implementation of [`State::process_records`] for partially materialized state
+    fn process_partial_records(
+        &mut self,
+        records: &mut Records,
+        partial_tag: Option<Tag>,
+    ) -> ReadySetResult<()> {
+        let partial = self.expect_partial();
+        match partial_tag {
+            // Replays are only ever sent to keys that have already been marked as filled
+            Some(tag) if partial.by_tag.contains_key(&tag) => {}
+            // We got a replay for an unknown tag. This will happen if a node on an old replay path
+            // is now materialized. Keep all the records (which are destined for a downstream
+            // materialization), but don't store any of them.
+            Some(_) => return Ok(()),
+            // Normal writes are only stored (and forwarded) if they fall into a filled key
+            None => records.retain(|r| partial.covers(r.rec())),
+        }
+
+        if records.is_empty() {
+            return Ok(());
+        }
+
+        let mut batch = WriteBatch::default();
+        let mut removed = HashSet::new();
+        for r in records.iter() {
+            match r {
+                Record::Positive(r) => self.insert(&mut batch, r)?,
+                Record::Negative(r) => {
+                    if !self.remove(&mut batch, r, &mut removed)? {
+                        warn!(table = %self.name, "Tried removing non-existent row");
+                    }
+                }
+            }
+        }
+        self.write_to_db(batch, &None)
+    }
+
+    /// Look up all the rows stored on disk for the given key, regardless of whether that key is
+    /// filled.
+    fn lookup_rows(&self, columns: &[usize], key: &PointKey) -> Vec<Vec<DfValue>> {
+        let has_hash_index = self
+            .db
+            .inner()
+            .shared_state
+            .indices
+            .iter()
+            .any(|pi| pi.index.index_type == IndexType::HashMap && pi.index.columns == columns);
+
+        let rows = if has_hash_index {
+            self.db.lookup(columns, key).records()
+        } else {
+            // Partial BTreeMap indices don't necessarily have a HashMap index on the same columns,
+            // so do a range lookup for just the one key instead
+            #[allow(clippy::unwrap_used)] // Point keys can't be empty
+            let key = Vec1::try_from_vec(point_key_values(key)).unwrap();
+            self.db
+                .lookup_range(
+                    columns,
+                    &RangeKey::from(&(Bound::Included(key.clone()), Bound::Included(key))),
+                )
+                .records()
+        };
+
+        rows.into_iter()
+            .flatten()
+            .map(|row| row.into_owned())
+            .collect()
+    }
+
+    /// Look up all the rows stored on disk for the given key comparison, regardless of whether
+    /// those keys are filled.
+    fn lookup_comparison_rows(&self, columns: &[usize], key: &KeyComparison) -> Vec<Vec<DfValue>> {
+        match key {
+            KeyComparison::Equal(key) => {
+                self.lookup_rows(columns, &PointKey::from(key.iter().cloned()))
+            }
+            KeyComparison::Range(range) => self
+                .db
+                .lookup_range(columns, &RangeKey::from(range))
+                .records()
+                .into_iter()
+                .flatten()
+                .map(|row| row.into_owned())
+                .collect(),
+        }
+    }
+
+    /// Delete all the given rows from disk
+    #[allow(clippy::panic)] // Can't return a result, panicking is the best we can do
+    fn delete_rows(&mut self, rows: Vec<Vec<DfValue>>) {
+        if let Err(error) = self.try_delete_rows(rows) {
+            panic!("Failed to remove rows from partial PersistentState: {error}");
+        }
+    }
+
+    /// Delete all the given rows from disk, returning an error (and leaving all of them on disk)
+    /// if that fails
+    fn try_delete_rows(&mut self, rows: Vec<Vec<DfValue>>) -> ReadySetResult<()> {
+        if rows.is_empty() {
+            return Ok(());
+        }
+
+        let mut batch = WriteBatch::default();
+        let mut removed = HashSet::new();
+        for row in &rows {
+            self.remove(&mut batch, row, &mut removed)?;
+        }
+        self.write_to_db(batch, &None)
+    }
+
+    /// Mark the given keys as holes in the partial index at position `i` in
+    /// [`PartialState::indices`], and delete any rows materialized for those keys that aren't
+    /// covered by a filled key in any other partial index. Returns the number of bytes of memory
+    /// freed.
+    fn evict_partial_keys(&mut self, i: usize, keys: &[KeyComparison]) -> u64 {
+        let (bytes_freed, rows) = self.mark_partial_holes(i, keys);
+        self.delete_rows(rows);
+        bytes_freed
+    }
+
+    /// Mark the given keys as holes in the partial index at position `i` in
+    /// [`PartialState::indices`], without deleting anything from disk. Returns the number of bytes
+    /// of memory freed, and the rows materialized for those keys that are no longer covered by a
+    /// filled key in any partial index.
+    fn mark_partial_holes(&mut self, i: usize, keys: &[KeyComparison]) -> (u64, Vec<Vec<DfValue>>) {
+        let partial = self.expect_partial_mut();
+        let mem_size_before = partial.mem_size;
+        let columns = partial.indices[i].index.columns.clone();
+        let evicted = keys
+            .iter()
+            .filter(|key| partial.mark_hole(i, key))
+            .collect::<Vec<_>>();
+        let bytes_freed = mem_size_before - partial.mem_size;
+
+        let partial = self.expect_partial();
+        let rows = evicted
+            .into_iter()
+            .flat_map(|key| self.lookup_comparison_rows(&columns, key))
+            .filter(|row| !partial.covers(row))
+            .collect();
+
+        (bytes_freed, rows)
+    }
+
+    /// Choose a random filled point key in the partial index at position `i` in
+    /// [`PartialState::indices`], if there are any
+    fn random_filled_key<R: rand::Rng>(&self, i: usize, rng: &mut R) -> Option<Vec1<DfValue>> {
+        let filled = &self.expect_partial().indices[i].filled;
+        if filled.num_keys() == 0 {
+            return None;
+        }
+        filled
+            .keys()
+            .nth(rng.gen_range(0..filled.num_keys()))
+            .and_then(|key| Vec1::try_from_vec(key.clone()).ok())
     }
 
     pub fn is_snapshotting(&self) -> bool {
@@ -2032,7 +2616,11 @@ impl PersistentState {
     ) -> ReadySetResult<()> {
         let mut batch = batch;
         let mut write_options = rocksdb::WriteOptions::default();
-        if self.snapshot_mode.is_enabled()
+        if self.partial.is_some() {
+            // Partial state can always be recomputed from upstream and is never reopened, so
+            // there's no reason to pay for durability
+            write_options.disable_wal(true);
+        } else if self.snapshot_mode.is_enabled()
             // if we're setting the replication offset, that means we've snapshot the full table, so
             // set sync to true there even if snapshot_mode is enabled, to make sure that makes it
             // onto disk (not doing this *will* cause the write to get lost if the server restarts!)
@@ -2226,8 +2814,15 @@ impl SizeOf for PersistentState {
         mem::size_of::<Self>() as u64
     }
 
+    /// For fully materialized state, returns the size of the data stored in RocksDB. For partial
+    /// state, which is only evicted from to free memory, returns the number of bytes of memory
+    /// used to track filled keys instead.
     #[allow(clippy::panic)] // Can't return a result, panicking is the best we can do
     fn deep_size_of(&self) -> u64 {
+        if let Some(partial) = &self.partial {
+            return partial.mem_size;
+        }
+
         let inner = self.db.inner();
         inner
             .shared_state
@@ -2782,6 +3377,65 @@ mod tests {
         }
     }
 
+    #[test]
+    fn checkpoint_restore() {
+        let storage_dir = tempdir().unwrap();
+        let checkpoint_dir = tempdir().unwrap();
+        let restore_dir = tempdir().unwrap();
+        let params = PersistenceParameters {
+            mode: DurabilityMode::Permanent,
+            storage_dir: Some(storage_dir.path().into()),
+            ..Default::default()
+        };
+        let row: Vec<DfValue> = vec![10.into(), "Cat".into()];
+        let replication_offset = ReplicationOffset::MySql(
+            MySqlPosition::from_file_name_and_position("binlog.00001".to_owned(), 12).unwrap(),
+        );
+
+        let mut state =
+            PersistentState::new("checkpoint".into(), Vec::<Box<[usize]>>::new(), &params).unwrap();
+        state.add_index(Index::new(IndexType::HashMap, vec![0]), None);
+        state
+            .process_records(
+                &mut vec![row.clone()].into(),
+                None,
+                Some(replication_offset.clone()),
+            )
+            .unwrap();
+        let checkpoint = state.create_checkpoint(checkpoint_dir.path()).unwrap();
+        assert_eq!(checkpoint, checkpoint_dir.path().join("checkpoint.db"));
+
+        // Writes after the checkpoint shouldn't be restored
+        insert(&mut state, vec![20.into(), "Bob".into()]);
+
+        let restored =
+            PersistentState::restore_checkpoints(checkpoint_dir.path(), restore_dir.path())
+                .unwrap();
+        assert_eq!(restored, vec![restore_dir.path().join("checkpoint.db")]);
+        // Restoring over existing databases fails
+        PersistentState::restore_checkpoints(checkpoint_dir.path(), restore_dir.path())
+            .unwrap_err();
+
+        let state = PersistentState::new(
+            "checkpoint".into(),
+            Vec::<Box<[usize]>>::new(),
+            &PersistenceParameters {
+                storage_dir: Some(restore_dir.path().into()),
+                ..params
+            },
+        )
+        .unwrap();
+        assert_eq!(state.replication_offset(), Some(&replication_offset));
+        assert_eq!(
+            state.lookup(&[0], &PointKey::Single(10.into())).unwrap(),
+            vec![row].into()
+        );
+        assert_eq!(
+            state.lookup(&[0], &PointKey::Single(20.into())).unwrap(),
+            RecordResult::default()
+        );
+    }
+
     #[test]
     fn persistent_state_recover_unique_key() {
         let (_dir, name) = get_tmp_path();
@@ -3737,4 +4391,176 @@ mod tests {
             )
         }
     }
+
+    mod partial {
+        use pretty_assertions::assert_eq;
+        use vec1::vec1;
+
+        use super::*;
+
+        fn setup(name: &str) -> PersistentState {
+            let mut state = setup_persistent(name, None);
+            state.add_index(Index::hash_map(vec![0]), Some(vec![Tag::new(0)]));
+            state
+        }
+
+        fn all_rows(state: &PersistentState) -> Vec<Vec<DfValue>> {
+            let mut all_records = state.all_records();
+            let mut rows = all_records.read().iter().collect::<Vec<_>>();
+            rows.sort();
+            rows
+        }
+
+        #[test]
+        fn lookup_misses_until_filled() {
+            let mut state = setup("partial_lookup_misses_until_filled");
+            assert!(state.is_partial());
+            assert!(state.lookup(&[0], &PointKey::Single(1.into())).is_missing());
+
+            state.mark_filled(KeyComparison::from(vec1![1.into()]), Tag::new(0));
+            assert_eq!(
+                state.lookup(&[0], &PointKey::Single(1.into())).unwrap(),
+                RecordResult::default()
+            );
+
+            let mut records: Records = vec![
+                Record::from(vec![1.into(), "a".into()]),
+                Record::from(vec![2.into(), "b".into()]),
+            ]
+            .into();
+            state.process_records(&mut records, None, None).unwrap();
+
+            // Only the write to the filled key is kept
+            assert_eq!(*records, vec![Record::from(vec![1.into(), "a".into()])]);
+            assert_eq!(
+                state.lookup(&[0], &PointKey::Single(1.into())).unwrap(),
+                vec![vec![1.into(), "a".into()]].into()
+            );
+            assert!(state.lookup(&[0], &PointKey::Single(2.into())).is_missing());
+            assert_eq!(all_rows(&state), vec![vec![1.into(), "a".into()]]);
+
+            state
+                .process_records(
+                    &mut vec![Record::Negative(vec![1.into(), "a".into()])].into(),
+                    None,
+                    None,
+                )
+                .unwrap();
+            assert_eq!(
+                state.lookup(&[0], &PointKey::Single(1.into())).unwrap(),
+                RecordResult::default()
+            );
+        }
+
+        #[test]
+        fn evict_deletes_rows() {
+            let mut state = setup("partial_evict_deletes_rows");
+            for key in [1, 2] {
+                state.mark_filled(KeyComparison::from(vec1![key.into()]), Tag::new(0));
+                state
+                    .process_records(
+                        &mut vec![Record::from(vec![key.into(), "a".into()])].into(),
+                        Some(Tag::new(0)),
+                        None,
+                    )
+                    .unwrap();
+            }
+            assert_eq!(state.key_count(), KeyCount::ExactKeyCount(2));
+            assert!(state.deep_size_of() > 0);
+
+            let res = state
+                .evict_keys(Tag::new(0), &[KeyComparison::from(vec1![1.into()])])
+                .unwrap();
+            assert!(res.bytes_freed > 0);
+            assert!(state.lookup(&[0], &PointKey::Single(1.into())).is_missing());
+            assert_eq!(all_rows(&state), vec![vec![2.into(), "a".into()]]);
+
+            let res = state.evict_bytes(usize::MAX).unwrap();
+            assert_eq!(res.keys_evicted, vec![vec![DfValue::from(2)]]);
+            assert_eq!(state.key_count(), KeyCount::ExactKeyCount(0));
+            assert_eq!(state.deep_size_of(), 0);
+            assert!(all_rows(&state).is_empty());
+        }
+
+        #[test]
+        fn fill_with_rows() {
+            let mut state = setup("partial_fill_with_rows");
+            state.add_index(Index::hash_map(vec![1]), Some(vec![Tag::new(1)]));
+            let covered = vec![DfValue::from(1), DfValue::from(2)];
+            let uncovered = vec![DfValue::from(1), DfValue::from(3)];
+
+            state
+                .fill_with_rows(
+                    KeyComparison::from(vec1![2.into()]),
+                    Tag::new(1),
+                    vec![covered.clone()],
+                )
+                .unwrap();
+            state
+                .fill_with_rows(
+                    KeyComparison::from(vec1![1.into()]),
+                    Tag::new(0),
+                    vec![covered.clone(), uncovered.clone()],
+                )
+                .unwrap();
+
+            // Rows covered by the other index aren't duplicated
+            assert_eq!(all_rows(&state), vec![covered.clone(), uncovered]);
+
+            // Rows left behind by an eviction that didn't delete them are deleted when the key is
+            // filled again
+            state.mark_partial_holes(0, &[KeyComparison::from(vec1![1.into()])]);
+            state
+                .fill_with_rows(
+                    KeyComparison::from(vec1![1.into()]),
+                    Tag::new(0),
+                    vec![covered.clone()],
+                )
+                .unwrap();
+            assert_eq!(all_rows(&state), vec![covered.clone()]);
+            assert_eq!(
+                state.lookup(&[0], &PointKey::Single(1.into())).unwrap(),
+                vec![covered].into()
+            );
+        }
+
+        #[test]
+        fn overlapping_indices_dont_duplicate_rows() {
+            let mut state = setup("partial_overlapping_indices");
+            state.add_index(Index::hash_map(vec![1]), Some(vec![Tag::new(1)]));
+            let row = vec![DfValue::from(1), DfValue::from(2)];
+
+            state.mark_filled(KeyComparison::from(vec1![1.into()]), Tag::new(0));
+            state
+                .process_records(
+                    &mut vec![Record::from(row.clone())].into(),
+                    Some(Tag::new(0)),
+                    None,
+                )
+                .unwrap();
+
+            // The replay to fill the other index contains the same row again
+            state.mark_filled(KeyComparison::from(vec1![2.into()]), Tag::new(1));
+            state
+                .process_records(
+                    &mut vec![Record::from(row.clone())].into(),
+                    Some(Tag::new(1)),
+                    None,
+                )
+                .unwrap();
+            assert_eq!(all_rows(&state), vec![row.clone()]);
+
+            // Evicting from one index keeps rows covered by the other
+            state.mark_hole(&KeyComparison::from(vec1![1.into()]), Tag::new(0));
+            assert!(state.lookup(&[0], &PointKey::Single(1.into())).is_missing());
+            assert_eq!(
+                state.lookup(&[1], &PointKey::Single(2.into())).unwrap(),
+                vec![row].into()
+            );
+
+            state.clear();
+            assert!(all_rows(&state).is_empty());
+            assert!(state.lookup(&[1], &PointKey::Single(2.into())).is_missing());
+        }
+    }
 }
@@ -0,0 +1,418 @@
+//! Partially materialized state which keeps recently used keys in memory, and spills cold keys to
+//! disk rather than evicting them outright.
+//!
+//! A [`TieredState`] consists of two tiers: a *hot* [`MemoryState`], which behaves exactly like the
+//! state of any other partially materialized node, and a *cold* partial [`PersistentState`] backed
+//! by a temporary RocksDB database. When the domain asks the state to free memory via
+//! [`State::evict_bytes`], randomly chosen keys from [`HashMap`] indices in the hot tier are
+//! *demoted* to the cold tier, along with all the rows materialized for them, instead of being
+//! turned back into holes. Lookups that miss in the hot tier fall back to the cold tier, so demoted
+//! keys can still be served (albeit more slowly) without an upquery, and nothing downstream needs
+//! to be evicted.
+//!
+//! Keys are only ever *promoted* back to the hot tier by a replay: filling a hole in the hot tier
+//! first removes the key from the cold tier, so that each key is filled in at most one of the two
+//! tiers at a time.
+//!
+//! Keys in [`BTreeMap`] indices are never demoted, since range lookups can't be split between the
+//! two tiers - evicting from those indices drops keys just like [`MemoryState`] would.
+//!
+//! [`HashMap`]: IndexType::HashMap
+//! [`BTreeMap`]: IndexType::BTreeMap
+
+use std::collections::BTreeMap;
+use std::mem;
+
+use common::{IndexType, Record, Records, SizeOf, Tag};
+use rand::Rng;
+use readyset_client::debug::info::KeyCount;
+use readyset_client::internal::Index;
+use readyset_client::{KeyComparison, PersistencePoint};
+use readyset_data::DfValue;
+use readyset_errors::ReadySetResult;
+use replication_offset::ReplicationOffset;
+use tracing::warn;
+use vec1::Vec1;
+
+use crate::{
+    AllRecords, EvictBytesResult, EvictKeysResult, EvictRandomResult, LookupResult, MemoryState,
+    PersistentState, PointKey, RangeKey, RangeLookupResult, RecordResult, Rows, State,
+};
+
+/// Partially materialized state which spills cold keys to disk instead of evicting them.
+///
+/// See [the module documentation](self) for more information
+pub struct TieredState {
+    hot: MemoryState,
+    cold: PersistentState,
+}
+
+impl TieredState {
+    /// Construct a new, empty [`TieredState`] which will spill keys into the given `cold` state.
+    ///
+    /// `cold` should be a new, empty [`PersistentState`] which no other node has access to.
+    pub fn new(cold: PersistentState) -> Self {
+        Self {
+            hot: MemoryState::default(),
+            cold,
+        }
+    }
+
+    /// Move the keys evicted from the hot tier into the cold tier, removing each one from `keys`
+    /// once it's been demoted.
+    ///
+    /// If spilling a key to disk fails, the error is returned and that key, along with any others
+    /// that haven't been demoted yet, is left in `keys` without having been filled in the cold
+    /// tier.
+    fn demote(&mut self, tag: Tag, keys: &mut Vec<(Vec<DfValue>, Rows)>) -> ReadySetResult<()> {
+        while let Some((key, rows)) = keys.last() {
+            if let Ok(key) = Vec1::try_from_vec(key.clone()) {
+                let rows = rows.iter().map(|row| Vec::clone(row)).collect();
+                self.cold
+                    .fill_with_rows(KeyComparison::from(key), tag, rows)?;
+            }
+            keys.pop();
+        }
+        Ok(())
+    }
+}
+
+/// Remove one copy of `record` from the given multiset of records, returning true if there was
+/// one to remove
+fn take(records: &mut BTreeMap<Record, usize>, record: &Record) -> bool {
+    match records.get_mut(record) {
+        Some(count) if *count > 0 => {
+            *count -= 1;
+            true
+        }
+        _ => false,
+    }
+}
+
+fn counts(records: Records) -> BTreeMap<Record, usize> {
+    let mut counts = BTreeMap::new();
+    for record in Vec::from(records) {
+        *counts.entry(record).or_default() += 1;
+    }
+    counts
+}
+
+impl SizeOf for TieredState {
+    fn size_of(&self) -> u64 {
+        mem::size_of::<Self>() as u64
+    }
+
+    fn deep_size_of(&self) -> u64 {
+        self.hot.deep_size_of() + self.cold.deep_size_of()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.hot.is_empty() && self.cold.key_count() == KeyCount::ExactKeyCount(0)
+    }
+}
+
+impl State for TieredState {
+    fn add_index(&mut self, index: Index, tags: Option<Vec<Tag>>) {
+        self.cold.add_index(index.clone(), tags.clone());
+        self.hot.add_index(index, tags);
+    }
+
+    fn add_weak_index(&mut self, index: Index) {
+        self.cold.add_weak_index(index.clone());
+        self.hot.add_weak_index(index);
+    }
+
+    fn is_useful(&self) -> bool {
+        self.hot.is_useful()
+    }
+
+    fn is_partial(&self) -> bool {
+        self.hot.is_partial()
+    }
+
+    fn replay_done(&self) -> bool {
+        self.hot.replay_done()
+    }
+
+    fn process_records(
+        &mut self,
+        records: &mut Records,
+        partial_tag: Option<Tag>,
+        replication_offset: Option<ReplicationOffset>,
+    ) -> ReadySetResult<()> {
+        if partial_tag.is_some() {
+            // Replays always fill keys in the hot tier
+            return self
+                .hot
+                .process_records(records, partial_tag, replication_offset);
+        }
+
+        // Normal writes need to be applied to both tiers, and should be kept if they fall into a
+        // filled key in either of them
+        let original = records.clone();
+        let mut cold_records = records.clone();
+        self.hot
+            .process_records(records, None, replication_offset)?;
+        self.cold.process_records(&mut cold_records, None, None)?;
+
+        let mut hot_retained = counts(mem::take(records));
+        let mut cold_retained = counts(cold_records);
+        *records = Vec::from(original)
+            .into_iter()
+            .filter(|r| {
+                let in_hot = take(&mut hot_retained, r);
+                let in_cold = take(&mut cold_retained, r);
+                in_hot || in_cold
+            })
+            .collect();
+
+        Ok(())
+    }
+
+    fn replication_offset(&self) -> Option<&ReplicationOffset> {
+        self.hot.replication_offset()
+    }
+
+    fn persisted_up_to(&self) -> ReadySetResult<PersistencePoint> {
+        Ok(PersistencePoint::Persisted)
+    }
+
+    fn mark_filled(&mut self, key: KeyComparison, tag: Tag) {
+        // Remove the key from the cold tier (if it was there) first, since the replay will contain
+        // all its rows again
+        self.cold.mark_hole(&key, tag);
+        self.hot.mark_filled(key, tag);
+    }
+
+    fn mark_hole(&mut self, key: &KeyComparison, tag: Tag) {
+        self.hot.mark_hole(key, tag);
+        self.cold.mark_hole(key, tag);
+    }
+
+    fn lookup<'a>(&'a self, columns: &[usize], key: &PointKey) -> LookupResult<'a> {
+        match self.hot.lookup(columns, key) {
+            LookupResult::Missing => self.cold.lookup(columns, key),
+            res => res,
+        }
+    }
+
+    fn lookup_range<'a>(&'a self, columns: &[usize], key: &RangeKey) -> RangeLookupResult<'a> {
+        // Keys in BTreeMap indices are never demoted, so the hot tier has everything
+        self.hot.lookup_range(columns, key)
+    }
+
+    fn lookup_weak<'a>(&'a self, columns: &[usize], key: &PointKey) -> Option<RecordResult<'a>> {
+        let hot = self.hot.lookup_weak(columns, key);
+        let Some(cold) = self.cold.lookup_weak(columns, key) else {
+            return hot;
+        };
+
+        // Rows materialized for keys in other indices can be in both tiers at once
+        let cold_rows = cold
+            .into_iter()
+            .filter(|row| !self.hot.contains_row(row))
+            .map(|row| row.into_owned());
+        let rows = hot
+            .into_iter()
+            .flatten()
+            .map(|row| row.into_owned())
+            .chain(cold_rows)
+            .collect::<Vec<_>>();
+
+        if rows.is_empty() {
+            None
+        } else {
+            Some(RecordResult::Owned(rows))
+        }
+    }
+
+    fn key_count(&self) -> KeyCount {
+        match (self.hot.key_count(), self.cold.key_count()) {
+            (KeyCount::ExactKeyCount(hot), KeyCount::ExactKeyCount(cold)) => {
+                KeyCount::ExactKeyCount(hot + cold)
+            }
+            (hot, _) => hot,
+        }
+    }
+
+    fn row_count(&self) -> usize {
+        self.hot.row_count() + self.cold.row_count()
+    }
+
+    /// Never called, since [`TieredState`] is only used for partially materialized nodes, and only
+    /// fully materialized nodes ever have all their records replayed. Returning just the hot tier
+    /// would silently drop every demoted key, so this panics instead.
+    #[allow(clippy::unreachable)]
+    fn all_records(&self) -> AllRecords {
+        unreachable!("all_records called on partially materialized TieredState")
+    }
+
+    /// Frees `bytes` of memory by demoting random keys from the hot tier to the cold tier. Keys
+    /// in [`BTreeMap`] indices are evicted rather than demoted, and if the hot tier is empty keys
+    /// are evicted from the cold tier instead.
+    ///
+    /// Since demoted keys can still be looked up, only keys that were actually evicted are
+    /// included in the returned [`EvictBytesResult::keys_evicted`]. If spilling keys to disk fails,
+    /// the keys that couldn't be demoted are evicted instead.
+    ///
+    /// [`BTreeMap`]: IndexType::BTreeMap
+    fn evict_bytes(&mut self, bytes: usize) -> Option<EvictBytesResult> {
+        let Some(evicted) = self.hot.evict_random_keys(bytes) else {
+            return self.cold.evict_bytes(bytes);
+        };
+
+        let (index, tag) = self.hot.index_and_tag(evicted.state_index);
+        let tag = match tag {
+            Some(tag) if index.index_type == IndexType::HashMap => tag,
+            _ => {
+                return Some(EvictBytesResult {
+                    index: self.hot.index_and_tag(evicted.state_index).0,
+                    keys_evicted: evicted.keys.into_iter().map(|(key, _)| key).collect(),
+                    bytes_freed: evicted.bytes_freed,
+                })
+            }
+        };
+
+        let size_before = self.cold.deep_size_of();
+        let mut keys = evicted.keys;
+        if let Err(error) = self.demote(tag, &mut keys) {
+            // The keys we couldn't demote have already been removed from the hot tier, so fall
+            // back to evicting them outright
+            warn!(%error, keys = keys.len(), "Failed to spill keys to disk, evicting them instead");
+        }
+        let bytes_added = self.cold.deep_size_of().saturating_sub(size_before);
+
+        Some(EvictBytesResult {
+            index: self.hot.index_and_tag(evicted.state_index).0,
+            keys_evicted: keys.into_iter().map(|(key, _)| key).collect(),
+            bytes_freed: evicted.bytes_freed.saturating_sub(bytes_added),
+        })
+    }
+
+    fn evict_keys(&mut self, tag: Tag, keys: &[KeyComparison]) -> Option<EvictKeysResult> {
+        let cold_bytes_freed = self
+            .cold
+            .evict_keys(tag, keys)
+            .map(|res| res.bytes_freed)
+            .unwrap_or(0);
+        self.hot.evict_keys(tag, keys).map(|res| EvictKeysResult {
+            bytes_freed: res.bytes_freed + cold_bytes_freed,
+            ..res
+        })
+    }
+
+    fn evict_random<R: Rng>(&mut self, tag: Tag, rng: &mut R) -> Option<EvictRandomResult> {
+        match self.hot.evict_random(tag, rng) {
+            Some(res) => Some(res),
+            None => self.cold.evict_random(tag, rng),
+        }
+    }
+
+    fn clear(&mut self) {
+        self.hot.clear();
+        self.cold.clear();
+    }
+
+    fn shut_down(&mut self) -> ReadySetResult<()> {
+        self.hot.shut_down()?;
+        self.cold.shut_down()
+    }
+
+    fn tear_down(self) -> ReadySetResult<()> {
+        self.hot.tear_down()?;
+        self.cold.tear_down()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vec1::vec1;
+
+    use super::*;
+    use crate::{DurabilityMode, PersistenceParameters};
+
+    fn setup(name: &str) -> TieredState {
+        let cold = PersistentState::new(
+            name.to_owned(),
+            Vec::<Box<[usize]>>::new(),
+            &PersistenceParameters {
+                mode: DurabilityMode::DeleteOnExit,
+                ..PersistenceParameters::default()
+            },
+        )
+        .unwrap();
+        let mut state = TieredState::new(cold);
+        state.add_index(Index::hash_map(vec![0]), Some(vec![Tag::new(0)]));
+        state
+    }
+
+    fn fill(state: &mut TieredState, key: i32, rows: Vec<Vec<DfValue>>) {
+        state.mark_filled(KeyComparison::from(vec1![key.into()]), Tag::new(0));
+        state
+            .process_records(&mut rows.into(), Some(Tag::new(0)), None)
+            .unwrap();
+    }
+
+    #[test]
+    fn demoted_keys_still_hit() {
+        let mut state = setup("tiered_demoted_keys_still_hit");
+        fill(&mut state, 1, vec![vec![1.into(), "a".into()]]);
+
+        let res = state.evict_bytes(usize::MAX).unwrap();
+        assert!(res.keys_evicted.is_empty());
+        assert!(state
+            .hot
+            .lookup(&[0], &PointKey::Single(1.into()))
+            .is_missing());
+        assert_eq!(
+            state.lookup(&[0], &PointKey::Single(1.into())).unwrap(),
+            vec![vec![DfValue::from(1), "a".into()]].into()
+        );
+
+        // Once the hot tier is empty, keys are evicted from the cold tier
+        let res = state.evict_bytes(usize::MAX).unwrap();
+        assert_eq!(res.keys_evicted, vec![vec![DfValue::from(1)]]);
+        assert!(state.lookup(&[0], &PointKey::Single(1.into())).is_missing());
+    }
+
+    #[test]
+    fn writes_to_demoted_keys() {
+        let mut state = setup("tiered_writes_to_demoted_keys");
+        fill(&mut state, 1, vec![vec![1.into(), "a".into()]]);
+        fill(&mut state, 2, vec![vec![2.into(), "a".into()]]);
+        state
+            .evict_keys(Tag::new(0), &[KeyComparison::from(vec1![2.into()])])
+            .unwrap();
+        state.evict_bytes(usize::MAX).unwrap();
+
+        let mut records: Records = vec![
+            Record::Positive(vec![1.into(), "b".into()]),
+            Record::Positive(vec![2.into(), "b".into()]),
+            Record::Negative(vec![1.into(), "a".into()]),
+        ]
+        .into();
+        state.process_records(&mut records, None, None).unwrap();
+
+        // Writes to keys that are filled in either tier are kept
+        assert_eq!(
+            *records,
+            vec![
+                Record::Positive(vec![1.into(), "b".into()]),
+                Record::Negative(vec![1.into(), "a".into()]),
+            ]
+        );
+        assert_eq!(
+            state.lookup(&[0], &PointKey::Single(1.into())).unwrap(),
+            vec![vec![DfValue::from(1), "b".into()]].into()
+        );
+        assert!(state.lookup(&[0], &PointKey::Single(2.into())).is_missing());
+
+        // Replaying the key again promotes it back into memory
+        fill(&mut state, 1, vec![vec![1.into(), "b".into()]]);
+        assert_eq!(state.cold.key_count(), KeyCount::ExactKeyCount(0));
+        assert_eq!(
+            state.lookup(&[0], &PointKey::Single(1.into())).unwrap(),
+            vec![vec![DfValue::from(1), "b".into()]].into()
+        );
+    }
+}
@@ -1,22 +1,85 @@
-//! Implementation of MySQL's [Secure Password Authentication][0] authentication method.
+//! Implementation of MySQL's authentication methods.
 //!
-//! The way the authentication scheme works:
+//! Two auth plugins are supported, both of which start the same way: the server sends 20-bytes of
+//! [random data](AuthData) along with the initial handshake packet (or an `AuthSwitchRequest`),
+//! and the client returns a response derived from that data and the user's password.
 //!
-//! 1. The server sends 20-bytes of [random data](AuthData) along with the initial handshake packet
-//! 2. The client returns a 20-byte random response based on the algorithm in [`hash_password`]
-//! 3. The server runs the same algorithm, and checks the response against the result
+//! For [`mysql_native_password`][0]:
+//!
+//! 1. The client returns a 20-byte response based on the algorithm in [`hash_password`]
+//! 2. The server runs the same algorithm, and checks the response against the result
+//!
+//! For [`caching_sha2_password`][1]:
+//!
+//! 1. The client returns a 32-byte response based on the algorithm in [`scramble_sha256`]
+//! 2. If the server can verify that response ("fast auth"), it sends [`FAST_AUTH_SUCCESS`] followed
+//!    by an OK packet
+//! 3. Otherwise, it sends [`PERFORM_FULL_AUTHENTICATION`], and the client sends its password either
+//!    in cleartext (if the connection is over TLS), or encrypted with the server's RSA public key,
+//!    which the client can request by sending [`REQUEST_PUBLIC_KEY`] (see [`public_key_pem`] and
+//!    [`decrypt_password`])
 //!
 //! [0]: https://dev.mysql.com/doc/internals/en/secure-password-authentication.html
+//! [1]: https://dev.mysql.com/doc/dev/mysql-server/latest/page_caching_sha2_authentication_exchanges.html
+
+use std::sync::OnceLock;
 
 use getrandom::getrandom;
+use openssl::pkey::Private;
+use openssl::rsa::{Padding, Rsa};
 use sha1::{Digest, Sha1};
+use sha2::Sha256;
 
 use crate::error::MsqlSrvError;
 
 pub type AuthData = [u8; 20];
 
-/// The name of the (currently only) supported auth plugin
-pub const AUTH_PLUGIN_NAME: &str = "mysql_native_password";
+/// The auth plugins supported by the server
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthPlugin {
+    /// `mysql_native_password`
+    MySqlNativePassword,
+    /// `caching_sha2_password`
+    CachingSha2Password,
+}
+
+impl AuthPlugin {
+    /// The auth plugin advertised to clients in the initial handshake and in `AuthSwitchRequest`
+    /// packets. This is the default plugin as of MySQL 8.
+    pub const DEFAULT: AuthPlugin = AuthPlugin::CachingSha2Password;
+
+    /// Returns the name of this auth plugin, as sent over the wire
+    pub fn name(self) -> &'static str {
+        match self {
+            AuthPlugin::MySqlNativePassword => "mysql_native_password",
+            AuthPlugin::CachingSha2Password => "caching_sha2_password",
+        }
+    }
+
+    /// Returns the auth plugin with the given name, if it's one we support
+    pub fn from_name(name: &str) -> Option<AuthPlugin> {
+        match name {
+            "mysql_native_password" => Some(AuthPlugin::MySqlNativePassword),
+            "caching_sha2_password" => Some(AuthPlugin::CachingSha2Password),
+            _ => None,
+        }
+    }
+}
+
+/// Status byte of the `AuthMoreData` packet sent by the server during `caching_sha2_password`
+/// authentication
+pub const AUTH_MORE_DATA: u8 = 0x01;
+
+/// Sent by a client during `caching_sha2_password` full authentication to request the server's RSA
+/// public key
+pub const REQUEST_PUBLIC_KEY: u8 = 0x02;
+
+/// Sent by the server in an `AuthMoreData` packet when `caching_sha2_password` fast auth succeeds
+pub const FAST_AUTH_SUCCESS: u8 = 0x03;
+
+/// Sent by the server in an `AuthMoreData` packet when the client must send its full password to
+/// complete `caching_sha2_password` authentication
+pub const PERFORM_FULL_AUTHENTICATION: u8 = 0x04;
 
 /// Bytewise-XOR b1 with b2 in-place
 fn xor_slice_mut<const N: usize>(b1: &mut [u8; N], b2: &[u8; N]) {
@@ -67,6 +130,77 @@ pub fn hash_password(password: &[u8], auth_data: &AuthData) -> [u8; 20] {
     res
 }
 
+fn sha256(input: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(input);
+    hasher.finalize().into()
+}
+
+/// Hash a password alongside random challenge data per the `caching_sha2_password` [fast auth
+/// algorithm][0].
+///
+/// The algorithm is:
+///
+/// ```notrust
+/// SHA256(password) XOR SHA256(SHA256(SHA256(password)) <concat> "20-bytes random data")
+/// ```
+///
+/// Clients send an empty response rather than a scramble when the password is empty, so in that
+/// case this returns an empty vector.
+///
+/// [0]: https://dev.mysql.com/doc/dev/mysql-server/latest/page_caching_sha2_authentication_exchanges.html
+pub fn scramble_sha256(password: &[u8], auth_data: &AuthData) -> Vec<u8> {
+    if password.is_empty() {
+        return vec![];
+    }
+
+    let mut res = sha256(password);
+    let mut salted = [0u8; 52];
+    salted[..32].clone_from_slice(&sha256(&res));
+    salted[32..].clone_from_slice(auth_data);
+    xor_slice_mut(&mut res, &sha256(&salted));
+    res.to_vec()
+}
+
+/// The RSA key pair used to exchange passwords during `caching_sha2_password` full authentication
+/// over connections without TLS. Generated on first use, and shared by all connections.
+static RSA_KEY: OnceLock<Rsa<Private>> = OnceLock::new();
+
+fn rsa_key() -> Result<&'static Rsa<Private>, MsqlSrvError> {
+    if let Some(key) = RSA_KEY.get() {
+        return Ok(key);
+    }
+    let key = Rsa::generate(2048)?;
+    Ok(RSA_KEY.get_or_init(|| key))
+}
+
+/// Returns the PEM-encoded RSA public key sent to clients which request it during
+/// `caching_sha2_password` full authentication
+pub fn public_key_pem() -> Result<Vec<u8>, MsqlSrvError> {
+    Ok(rsa_key()?.public_key_to_pem()?)
+}
+
+/// Decrypt a password sent by the client during `caching_sha2_password` full authentication, which
+/// has been XORed with the auth challenge data and then encrypted with our [RSA public
+/// key](public_key_pem).
+pub fn decrypt_password(encrypted: &[u8], auth_data: &AuthData) -> Result<Vec<u8>, MsqlSrvError> {
+    let key = rsa_key()?;
+    let mut password = vec![0; key.size() as usize];
+    let len = key.private_decrypt(encrypted, &mut password, Padding::PKCS1_OAEP)?;
+    password.truncate(len);
+    password
+        .iter_mut()
+        .zip(auth_data.iter().cycle())
+        .for_each(|(x, y)| *x ^= y);
+    Ok(cleartext_password(&password).to_vec())
+}
+
+/// Strip the trailing null byte from a password sent by the client during `caching_sha2_password`
+/// full authentication
+pub fn cleartext_password(password: &[u8]) -> &[u8] {
+    password.strip_suffix(b"\0").unwrap_or(password)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -84,4 +218,34 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn scramble_sha256_matches_client() {
+        let auth_data: AuthData = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0];
+        let password = b"password";
+        assert_eq!(
+            scramble_sha256(password, &auth_data),
+            myc::scramble::scramble_sha256(&auth_data, password)
+                .unwrap()
+                .to_vec()
+        );
+        assert!(scramble_sha256(b"", &auth_data).is_empty());
+    }
+
+    #[test]
+    fn decrypt_password_works() {
+        let auth_data: AuthData = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0];
+        // This is what clients send: the null-terminated password, XORed with the auth data, then
+        // encrypted with the server's public key
+        let mut password = b"a password longer than the auth data\0".to_vec();
+        password
+            .iter_mut()
+            .zip(auth_data.iter().cycle())
+            .for_each(|(x, y)| *x ^= y);
+        let encrypted = myc::crypto::encrypt(&password, &public_key_pem().unwrap());
+        assert_eq!(
+            decrypt_password(&encrypted, &auth_data).unwrap(),
+            b"a password longer than the auth data"
+        );
+    }
 }
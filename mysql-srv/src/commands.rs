@@ -111,6 +111,13 @@ pub fn change_user(
     ))
 }
 
+/// Parse the capability flags at the start of either a HandshakeResponse41 or an SSLRequest packet
+///
+/// <https://dev.mysql.com/doc/dev/mysql-server/latest/page_protocol_connection_phase_packets_protocol_ssl_request.html>
+pub fn client_capabilities(i: &[u8]) -> IResult<&[u8], CapabilityFlags> {
+    map(le_u32, CapabilityFlags::from_bits_truncate)(i)
+}
+
 /// <https://dev.mysql.com/doc/internals/en/connection-phase-packets.html#packet-Protocol::HandshakeResponse41>
 pub fn client_handshake(i: &[u8]) -> IResult<&[u8], ClientHandshake<'_>> {
     let (i, capabilities) = map(le_u32, CapabilityFlags::from_bits_truncate)(i)?;
@@ -52,6 +52,10 @@ pub enum MsqlSrvError {
     /// Error from mysql_common indicating that the column type is unknown.
     #[error("Unknown column type")]
     UnknownColumnType(#[from] myc::constants::UnknownColumnType),
+    /// Error from OpenSSL while generating or using the RSA key pair for `caching_sha2_password`
+    /// authentication.
+    #[error("RSA error: {0}")]
+    RsaError(#[from] openssl::error::ErrorStack),
 }
 
 impl From<MsqlSrvError> for io::Error {
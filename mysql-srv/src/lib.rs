@@ -169,17 +169,24 @@ use std::collections::HashMap;
 use std::io;
 use std::sync::Arc;
 
-use constants::{CLIENT_PLUGIN_AUTH, CONNECT_WITH_DB, PROTOCOL_41, RESERVED, SECURE_CONNECTION};
+use constants::{
+    CLIENT_PLUGIN_AUTH, CONNECT_WITH_DB, PROTOCOL_41, RESERVED, SECURE_CONNECTION, SSL,
+};
 use error::{other_error, OtherErrorKind};
 use mysql_common::constants::CapabilityFlags;
 use readyset_adapter_types::{DeallocateId, ParsedCommand};
 use readyset_data::DfType;
-use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::io::{AsyncRead, AsyncWrite, WriteHalf};
 use tokio::net;
+use tokio_native_tls::{TlsAcceptor, TlsStream};
 use tracing::{debug, info, trace};
 use writers::write_err;
 
-use crate::authentication::{generate_auth_data, hash_password, AUTH_PLUGIN_NAME};
+use crate::authentication::{
+    cleartext_password, decrypt_password, generate_auth_data, hash_password, public_key_pem,
+    scramble_sha256, AuthPlugin, AUTH_MORE_DATA, FAST_AUTH_SUCCESS, PERFORM_FULL_AUTHENTICATION,
+    REQUEST_PUBLIC_KEY,
+};
 use crate::commands::change_user;
 pub use crate::myc::constants::{ColumnFlags, ColumnType, StatusFlags};
 pub use crate::writers::prepare_column_definitions;
@@ -336,6 +343,8 @@ pub struct MySqlIntermediary<B, R: AsyncRead + Unpin, W: AsyncWrite + Unpin> {
     client_capabilities: CapabilityFlags,
     /// Auth data sent to client
     auth_data: [u8; 20],
+    /// Whether the connection to the client is over TLS
+    tls: bool,
 }
 
 impl<B: MySqlShim<net::tcp::OwnedWriteHalf> + Send>
@@ -355,6 +364,87 @@ impl<B: MySqlShim<net::tcp::OwnedWriteHalf> + Send>
     }
 }
 
+impl<B> MySqlIntermediary<B, net::TcpStream, net::TcpStream>
+where
+    B: MySqlShim<net::tcp::OwnedWriteHalf> + MySqlShim<WriteHalf<TlsStream<net::TcpStream>>> + Send,
+{
+    /// Create a new server over a TCP stream which advertises `CLIENT_SSL` to the client, and
+    /// process client commands until the client disconnects or an error occurs.
+    ///
+    /// If the client responds to the initial handshake with an `SSLRequest`, the stream is upgraded
+    /// to TLS using `tls_acceptor` before authentication continues. Clients which don't request TLS
+    /// are sent an error and disconnected if `require_tls` is set, and otherwise continue over the
+    /// plaintext stream, as with
+    /// [`MySqlIntermediary::run_on_tcp`](struct.MySqlIntermediary.html#method.run_on_tcp).
+    pub async fn run_on_tls_tcp(
+        shim: B,
+        mut stream: net::TcpStream,
+        enable_statement_logging: bool,
+        tls_acceptor: Arc<TlsAcceptor>,
+        require_tls: bool,
+    ) -> Result<(), io::Error> {
+        stream.set_nodelay(true)?;
+
+        let auth_data =
+            generate_auth_data().map_err(|_| other_error(OtherErrorKind::AuthDataErr))?;
+        let version = MySqlShim::<net::tcp::OwnedWriteHalf>::version(&shim);
+        let mut writer = packet::PacketWriter::new(&mut stream);
+        writer
+            .write_packet(&initial_handshake_packet(
+                &version,
+                &auth_data,
+                CAPABILITIES | SSL,
+            ))
+            .await?;
+        writer.flush().await?;
+
+        // Read the client's first packet without buffering past its end, since if it's an
+        // SSLRequest, the TLS handshake follows it immediately.
+        let (seq, handshake_bytes) = packet::read_one_packet(&mut stream).await?;
+        let client_capabilities = commands::client_capabilities(&handshake_bytes)
+            .map_err(handshake_error)?
+            .1;
+
+        if client_capabilities.contains(CapabilityFlags::CLIENT_SSL) {
+            let stream = tls_acceptor
+                .accept(stream)
+                .await
+                .map_err(|e| io::Error::new(io::ErrorKind::ConnectionAborted, e))?;
+            debug!("Established TLS connection");
+            let (reader, writer) = tokio::io::split(stream);
+            let mut mi = MySqlIntermediary::new(shim, reader, writer, enable_statement_logging);
+            mi.auth_data = auth_data;
+            mi.tls = true;
+            let (seq, handshake_bytes) = mi.reader.next().await?.ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::ConnectionAborted,
+                    "peer terminated connection",
+                )
+            })?;
+            let handshake_bytes = handshake_bytes.to_vec();
+            let init = mi.handshake(seq, &handshake_bytes).await?;
+            mi.run_after_init(init).await
+        } else if require_tls {
+            debug!("Rejecting client which didn't request TLS");
+            let mut writer = packet::PacketWriter::new(&mut stream);
+            writer.set_seq(seq + 1);
+            writers::write_err(
+                ErrorKind::ER_ACCESS_DENIED_ERROR,
+                b"Connections using insecure transport are prohibited",
+                &mut writer,
+            )
+            .await?;
+            writer.flush().await
+        } else {
+            let (reader, writer) = stream.into_split();
+            let mut mi = MySqlIntermediary::new(shim, reader, writer, enable_statement_logging);
+            mi.auth_data = auth_data;
+            let init = mi.handshake(seq, &handshake_bytes).await?;
+            mi.run_after_init(init).await
+        }
+    }
+}
+
 impl<B: MySqlShim<S> + Send, S: AsyncRead + AsyncWrite + Clone + Unpin + Send>
     MySqlIntermediary<B, S, S>
 {
@@ -389,6 +479,56 @@ struct StatementData {
 const CAPABILITIES: u32 =
     PROTOCOL_41 | SECURE_CONNECTION | RESERVED | CLIENT_PLUGIN_AUTH | CONNECT_WITH_DB;
 
+/// Build the HandshakeV10 packet which the server sends to the client when it first connects.
+fn initial_handshake_packet(version: &str, auth_data: &[u8; 20], capabilities: u32) -> Vec<u8> {
+    let auth_plugin_name = AuthPlugin::DEFAULT.name();
+    let mut init_packet = Vec::with_capacity(
+        1 + 16 + 4 + 8 + 1 + 2 + 1 + 2 + 2 + 1 + 6 + 4 + 12 + 1 + auth_plugin_name.len() + 1,
+    );
+    init_packet.extend_from_slice(&[10]); // protocol 10
+    init_packet.extend_from_slice(version.as_bytes());
+    init_packet.extend_from_slice(&[0x08, 0x00, 0x00, 0x00]); // TODO: connection ID
+    init_packet.extend_from_slice(&auth_data[..8]);
+    init_packet.push(0);
+    init_packet.extend_from_slice(&capabilities.to_le_bytes()[..2]);
+    init_packet.extend_from_slice(&[0x21]); // UTF8_GENERAL_CI
+    init_packet.extend_from_slice(&[0x00, 0x00]); // status flags
+    init_packet.extend_from_slice(&capabilities.to_le_bytes()[2..]);
+    // We will add a \0 byte below so we need to account for that when sending the length, since
+    // rust strings don't add the null terminator
+    init_packet.extend_from_slice(&[(auth_data.len() + 1) as u8]);
+    init_packet.extend_from_slice(&[0x00; 10][..]); // filler
+    init_packet.extend_from_slice(&auth_data[8..]);
+    init_packet.push(0);
+    init_packet.extend_from_slice(auth_plugin_name.as_bytes());
+    init_packet.push(0);
+    init_packet
+}
+
+/// Convert an error from parsing the client's handshake response into an [`io::Error`]
+fn handshake_error(e: nom::Err<nom::error::Error<&[u8]>>) -> io::Error {
+    match e {
+        nom::Err::Incomplete(_) => io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "client sent incomplete handshake",
+        ),
+        nom::Err::Failure(nom::error::Error { input, code })
+        | nom::Err::Error(nom::error::Error { input, code }) => {
+            if let nom::error::ErrorKind::Eof = code {
+                io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    format!("client did not complete handshake; got {:?}", input),
+                )
+            } else {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("bad client handshake; got {:?} ({:?})", input, code),
+                )
+            }
+        }
+    }
+}
+
 impl<B: MySqlShim<W> + Send, R: AsyncRead + Unpin, W: AsyncWrite + Unpin + Send>
     MySqlIntermediary<B, R, W>
 {
@@ -400,22 +540,35 @@ impl<B: MySqlShim<W> + Send, R: AsyncRead + Unpin, W: AsyncWrite + Unpin + Send>
         writer: W,
         enable_statement_logging: bool,
     ) -> Result<(), io::Error> {
-        let r = packet::PacketReader::new(reader);
-        let w = packet::PacketWriter::new(writer);
-        let mut mi = MySqlIntermediary {
+        let mut mi = MySqlIntermediary::new(shim, reader, writer, enable_statement_logging);
+        let init = mi.init().await?;
+        mi.run_after_init(init).await
+    }
+
+    fn new(shim: B, reader: R, writer: W, enable_statement_logging: bool) -> Self {
+        MySqlIntermediary {
             shim,
-            reader: r,
-            writer: w,
+            reader: packet::PacketReader::new(reader),
+            writer: packet::PacketWriter::new(writer),
             schema_cache: HashMap::new(),
             enable_statement_logging,
             client_capabilities: CapabilityFlags::empty(),
             auth_data: [0; 20],
-        };
-        if let (true, database) = mi.init().await? {
+            tls: false,
+        }
+    }
+
+    /// Given the result of [`Self::init`], process client commands until the client disconnects
+    /// or an error occurs, if authentication was successful.
+    async fn run_after_init(
+        mut self,
+        (auth_success, database): (bool, Option<String>),
+    ) -> Result<(), io::Error> {
+        if auth_success {
             if let Some(database) = database {
-                mi.shim.on_init(&database, None).await?;
+                self.shim.on_init(&database, None).await?;
             }
-            mi.run().await?;
+            self.run().await?;
         }
         Ok(())
     }
@@ -431,29 +584,10 @@ impl<B: MySqlShim<W> + Send, R: AsyncRead + Unpin, W: AsyncWrite + Unpin + Send>
     /// whether authentication was successful, and a database name if one was specified by the
     /// client in the handshake response.
     async fn init(&mut self) -> Result<(bool, Option<String>), io::Error> {
-        let auth_data =
+        self.auth_data =
             generate_auth_data().map_err(|_| other_error(OtherErrorKind::AuthDataErr))?;
-        self.auth_data = auth_data;
-        let mut init_packet = Vec::with_capacity(
-            1 + 16 + 4 + 8 + 1 + 2 + 1 + 2 + 2 + 1 + 6 + 4 + 12 + 1 + AUTH_PLUGIN_NAME.len() + 1,
-        );
-        init_packet.extend_from_slice(&[10]); // protocol 10
-        init_packet.extend_from_slice(self.shim.version().as_bytes());
-        init_packet.extend_from_slice(&[0x08, 0x00, 0x00, 0x00]); // TODO: connection ID
-        init_packet.extend_from_slice(&auth_data[..8]);
-        init_packet.push(0);
-        init_packet.extend_from_slice(&CAPABILITIES.to_le_bytes()[..2]);
-        init_packet.extend_from_slice(&[0x21]); // UTF8_GENERAL_CI
-        init_packet.extend_from_slice(&[0x00, 0x00]); // status flags
-        init_packet.extend_from_slice(&CAPABILITIES.to_le_bytes()[2..]);
-        // We will add a \0 byte below so we need to account for that when sending the length, since
-        // rust strings don't add the null terminator
-        init_packet.extend_from_slice(&[(auth_data.len() + 1) as u8]);
-        init_packet.extend_from_slice(&[0x00; 10][..]); // filler
-        init_packet.extend_from_slice(&auth_data[8..]);
-        init_packet.push(0);
-        init_packet.extend_from_slice(AUTH_PLUGIN_NAME.as_bytes());
-        init_packet.push(0);
+        let init_packet =
+            initial_handshake_packet(&self.shim.version(), &self.auth_data, CAPABILITIES);
 
         self.writer.write_packet(&init_packet).await?;
         self.writer.flush().await?;
@@ -464,27 +598,20 @@ impl<B: MySqlShim<W> + Send, R: AsyncRead + Unpin, W: AsyncWrite + Unpin + Send>
                 "peer terminated connection",
             )
         })?;
-        let handshake = commands::client_handshake(&handshake_bytes)
-            .map_err(|e| match e {
-                nom::Err::Incomplete(_) => io::Error::new(
-                    io::ErrorKind::UnexpectedEof,
-                    "client sent incomplete handshake",
-                ),
-                nom::Err::Failure(nom::error::Error { input, code })
-                | nom::Err::Error(nom::error::Error { input, code }) => {
-                    if let nom::error::ErrorKind::Eof = code {
-                        io::Error::new(
-                            io::ErrorKind::UnexpectedEof,
-                            format!("client did not complete handshake; got {:?}", input),
-                        )
-                    } else {
-                        io::Error::new(
-                            io::ErrorKind::InvalidData,
-                            format!("bad client handshake; got {:?} ({:?})", input, code),
-                        )
-                    }
-                }
-            })?
+        let handshake_bytes = handshake_bytes.to_vec();
+        self.handshake(seq, &handshake_bytes).await
+    }
+
+    /// Handle the HandshakeResponse packet sent by the client, with the given sequence number, and
+    /// complete authentication. See [`Self::init`] for a description of the return value.
+    async fn handshake(
+        &mut self,
+        seq: u8,
+        handshake_bytes: &[u8],
+    ) -> Result<(bool, Option<String>), io::Error> {
+        let auth_data = self.auth_data;
+        let handshake = commands::client_handshake(handshake_bytes)
+            .map_err(handshake_error)?
             .1;
 
         self.writer.set_seq(seq + 1);
@@ -495,69 +622,59 @@ impl<B: MySqlShim<W> + Send, R: AsyncRead + Unpin, W: AsyncWrite + Unpin + Send>
         let database = handshake.database.map(String::from);
         let client_auth_plugin = handshake.auth_plugin_name.map(|s| s.to_owned());
 
-        let handshake_password = if client_auth_plugin.iter().all(|apn| apn != AUTH_PLUGIN_NAME)
+        let (auth_plugin, auth_response) = match client_auth_plugin
+            .as_deref()
+            .and_then(AuthPlugin::from_name)
+        {
             // Some clients (at the very least certain versions of PHP's MySQL PDO library) send an
             // empty password response in the initial handshake, even if the auth plugin is set and
             // correct. We want to send a switch-authentication request in that case too
-            || password.is_empty()
-        {
-            // Authentication mismatch - try to switch auth plugins
+            Some(auth_plugin) if !password.is_empty() => (auth_plugin, password),
+            _ => {
+                // Authentication mismatch - try to switch auth plugins
 
-            if !handshake
-                .capabilities
-                .contains(CapabilityFlags::CLIENT_SECURE_CONNECTION)
-            {
-                debug!("Client does not support SECURE_CONNECTION, returning authentication error");
-                writers::write_err(
-                    ErrorKind::ER_NOT_SUPPORTED_AUTH_MODE,
-                    b"Client does not support authentication protocol requested by server; \
-                      consider upgrading MySQL client",
-                    &mut self.writer,
-                )
-                .await?;
-                return Ok((false, database));
-            }
+                if !handshake
+                    .capabilities
+                    .contains(CapabilityFlags::CLIENT_SECURE_CONNECTION)
+                {
+                    debug!(
+                        "Client does not support SECURE_CONNECTION, returning authentication error"
+                    );
+                    writers::write_err(
+                        ErrorKind::ER_NOT_SUPPORTED_AUTH_MODE,
+                        b"Client does not support authentication protocol requested by server; \
+                          consider upgrading MySQL client",
+                        &mut self.writer,
+                    )
+                    .await?;
+                    return Ok((false, database));
+                }
 
-            debug!(
-                ?client_auth_plugin,
-                "Client offered incorrect authentication plugin, sending switch request",
-            );
-
-            let mut auth_switch_request_packet =
-                Vec::with_capacity(1 + AUTH_PLUGIN_NAME.len() + 1 + auth_data.len() + 1);
-            auth_switch_request_packet.push(0xfe);
-            auth_switch_request_packet.extend_from_slice(AUTH_PLUGIN_NAME.as_bytes());
-            auth_switch_request_packet.push(0);
-            auth_switch_request_packet.extend_from_slice(&auth_data);
-            auth_switch_request_packet.push(0);
-            self.writer
-                .write_packet(&auth_switch_request_packet)
-                .await?;
-            self.writer.flush().await?;
+                debug!(
+                    ?client_auth_plugin,
+                    "Client offered incorrect authentication plugin, sending switch request",
+                );
 
-            let (seq, auth_switch_response) = self.reader.next().await?.ok_or_else(|| {
-                io::Error::new(
-                    io::ErrorKind::ConnectionAborted,
-                    "peer terminated connection",
-                )
-            })?;
-            self.writer.set_seq(seq + 1);
+                let auth_plugin_name = AuthPlugin::DEFAULT.name();
+                let mut auth_switch_request_packet =
+                    Vec::with_capacity(1 + auth_plugin_name.len() + 1 + auth_data.len() + 1);
+                auth_switch_request_packet.push(0xfe);
+                auth_switch_request_packet.extend_from_slice(auth_plugin_name.as_bytes());
+                auth_switch_request_packet.push(0);
+                auth_switch_request_packet.extend_from_slice(&auth_data);
+                auth_switch_request_packet.push(0);
+                self.writer
+                    .write_packet(&auth_switch_request_packet)
+                    .await?;
+                self.writer.flush().await?;
 
-            auth_switch_response.to_vec()
-        } else {
-            password
+                (AuthPlugin::DEFAULT, self.read_auth_response().await?)
+            }
         };
 
-        let auth_success = !self.shim.require_authentication()
-            || self
-                .shim
-                .password_for_username(&username)
-                .map_or(false, |password| {
-                    let expected = hash_password(&password, &auth_data);
-                    let actual = handshake_password.as_slice();
-                    trace!(?expected, ?actual);
-                    expected == actual
-                });
+        let auth_success = self
+            .authenticate(&username, auth_plugin, &auth_response)
+            .await?;
 
         if auth_success {
             debug!(%username, "Successfully authenticated client");
@@ -576,6 +693,87 @@ impl<B: MySqlShim<W> + Send, R: AsyncRead + Unpin, W: AsyncWrite + Unpin + Send>
         Ok((auth_success, database))
     }
 
+    /// Read a packet sent by the client in response to an authentication request
+    async fn read_auth_response(&mut self) -> Result<Vec<u8>, io::Error> {
+        let (seq, auth_response) = self.reader.next().await?.ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::ConnectionAborted,
+                "peer terminated connection",
+            )
+        })?;
+        self.writer.set_seq(seq + 1);
+        Ok(auth_response.to_vec())
+    }
+
+    /// Check the auth response sent by the client for the given user using the given auth plugin,
+    /// performing any further exchanges with the client required by that plugin.
+    ///
+    /// Returns whether authentication was successful. The caller is responsible for sending the
+    /// final OK or ERR packet to the client.
+    async fn authenticate(
+        &mut self,
+        username: &str,
+        auth_plugin: AuthPlugin,
+        auth_response: &[u8],
+    ) -> Result<bool, io::Error> {
+        let password = self.shim.password_for_username(username);
+        let require_authentication = self.shim.require_authentication();
+        let scramble = |password: &[u8]| match auth_plugin {
+            AuthPlugin::MySqlNativePassword => hash_password(password, &self.auth_data).to_vec(),
+            AuthPlugin::CachingSha2Password => scramble_sha256(password, &self.auth_data),
+        };
+        let scramble_matches = !require_authentication
+            || password.as_ref().map_or(false, |password| {
+                let expected = scramble(password);
+                trace!(?expected, actual = ?auth_response);
+                expected == auth_response
+            });
+
+        if auth_plugin == AuthPlugin::MySqlNativePassword {
+            return Ok(scramble_matches);
+        }
+
+        if scramble_matches {
+            self.writer
+                .write_packet(&[AUTH_MORE_DATA, FAST_AUTH_SUCCESS])
+                .await?;
+            return Ok(true);
+        }
+
+        let Some(password) = password else {
+            return Ok(false);
+        };
+
+        // We couldn't verify the scramble, so ask the client to send us its full password - in
+        // cleartext if the connection is secure, or encrypted with our public key otherwise
+        debug!(%username, "Performing full caching_sha2_password authentication");
+        self.writer
+            .write_packet(&[AUTH_MORE_DATA, PERFORM_FULL_AUTHENTICATION])
+            .await?;
+        self.writer.flush().await?;
+        let mut auth_response = self.read_auth_response().await?;
+
+        if self.tls {
+            return Ok(cleartext_password(&auth_response) == password);
+        }
+
+        if auth_response == [REQUEST_PUBLIC_KEY] {
+            let mut public_key_packet = vec![AUTH_MORE_DATA];
+            public_key_packet.extend(public_key_pem()?);
+            self.writer.write_packet(&public_key_packet).await?;
+            self.writer.flush().await?;
+            auth_response = self.read_auth_response().await?;
+        }
+
+        match decrypt_password(&auth_response, &self.auth_data) {
+            Ok(decrypted) => Ok(decrypted == password),
+            Err(error) => {
+                debug!(%error, "Could not decrypt password sent by client");
+                Ok(false)
+            }
+        }
+    }
+
     async fn run(mut self) -> Result<(), io::Error> {
         use crate::commands::Command;
 
@@ -612,10 +810,10 @@ impl<B: MySqlShim<W> + Send, R: AsyncRead + Unpin, W: AsyncWrite + Unpin + Send>
                         .1;
                     let username = change_user.username.to_owned();
                     let authpassword = change_user.password.to_vec();
+                    let database = change_user.database.unwrap_or_default().to_owned();
 
-                    if change_user.auth_plugin_name != AUTH_PLUGIN_NAME {
-                        // This should never happen, as we already accepted a connection using
-                        // AUTH_PLUGIN_NAME
+                    let Some(auth_plugin) = AuthPlugin::from_name(change_user.auth_plugin_name)
+                    else {
                         writers::write_err(
                             ErrorKind::ER_ACCESS_DENIED_ERROR,
                             format!(
@@ -628,15 +826,11 @@ impl<B: MySqlShim<W> + Send, R: AsyncRead + Unpin, W: AsyncWrite + Unpin + Send>
                         .await?;
                         self.writer.flush().await?;
                         continue;
-                    }
+                    };
+                    let auth_success = self
+                        .authenticate(&username, auth_plugin, &authpassword)
+                        .await?;
                     let plain_password = self.shim.password_for_username(&username);
-                    let auth_success = !self.shim.require_authentication()
-                        || plain_password.as_ref().map_or(false, |password| {
-                            let expected = hash_password(password, &self.auth_data);
-                            let actual = authpassword.as_slice();
-                            trace!(?expected, ?actual);
-                            expected == actual
-                        });
 
                     if auth_success {
                         debug!("Successfully authenticated client");
@@ -648,7 +842,7 @@ impl<B: MySqlShim<W> + Send, R: AsyncRead + Unpin, W: AsyncWrite + Unpin + Send>
                                     .as_ref()
                                     .map(|p| String::from_utf8_lossy(p))
                                     .unwrap_or_default(),
-                                change_user.database.unwrap_or_default(),
+                                &database,
                             )
                             .await
                         {
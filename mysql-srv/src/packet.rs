@@ -313,6 +313,27 @@ impl<R: AsyncRead + Unpin> PacketReader<R> {
     }
 }
 
+/// Read exactly one packet from `r`, without reading any bytes past the end of it.
+///
+/// Used for the client's `SSLRequest` during the handshake: the client starts the TLS handshake as
+/// soon as it has sent that packet, so anything buffered past its end would belong to the TLS
+/// stream rather than to us.
+pub async fn read_one_packet<R: AsyncRead + Unpin>(r: &mut R) -> io::Result<(u8, Vec<u8>)> {
+    let mut header = [0u8; 4];
+    r.read_exact(&mut header).await?;
+    let [l0, l1, l2, seq] = header;
+    let length = u32::from_le_bytes([l0, l1, l2, 0]) as usize;
+    if length == U24_MAX {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "unexpected multi-part packet",
+        ));
+    }
+    let mut bytes = vec![0; length];
+    r.read_exact(&mut bytes).await?;
+    Ok((seq, bytes))
+}
+
 pub fn fullpacket(i: &[u8]) -> nom::IResult<&[u8], (u8, &[u8])> {
     let (i, _) = nom::bytes::complete::tag(&[0xff, 0xff, 0xff])(i)?;
     let (i, seq) = nom::bytes::complete::take(1u8)(i)?;
@@ -460,6 +481,16 @@ mod tests {
         assert_eq!(&p.1[U24_MAX..], &[0x10]);
     }
 
+    #[tokio::test]
+    async fn read_one_packet_stops_at_packet_end() {
+        let data = [0x02, 0, 0, 1, 0x10, 0x20, 0x16, 0x03];
+        let mut r = &data[..];
+        let (seq, bytes) = read_one_packet(&mut r).await.unwrap();
+        assert_eq!(seq, 1);
+        assert_eq!(bytes, vec![0x10, 0x20]);
+        assert_eq!(r, &[0x16, 0x03]);
+    }
+
     #[tokio::test]
     #[slow]
     async fn test_large_packet_write() {
@@ -0,0 +1,190 @@
+use std::collections::HashMap;
+use std::io;
+use std::sync::Arc;
+
+use mysql_async::prelude::Queryable;
+use mysql_async::{OptsBuilder, SslOpts};
+use mysql_srv::{
+    CachedSchema, ErrorKind, InitWriter, MySqlIntermediary, MySqlShim, ParamParser,
+    QueryResultWriter, QueryResultsResponse, StatementMetaWriter,
+};
+use readyset_adapter_types::DeallocateId;
+use tokio::io::AsyncWrite;
+use tokio::net::TcpListener;
+use tokio::sync::oneshot;
+use tokio_native_tls::{native_tls, TlsAcceptor};
+
+struct TestBackend;
+
+impl<W: AsyncWrite + Unpin + Send + 'static> MySqlShim<W> for TestBackend {
+    async fn on_prepare(
+        &mut self,
+        _query: &str,
+        _info: StatementMetaWriter<'_, W>,
+        _schema_cache: &mut HashMap<u32, CachedSchema>,
+    ) -> io::Result<()> {
+        panic!() // never called
+    }
+
+    async fn on_execute(
+        &mut self,
+        _id: u32,
+        _params: ParamParser<'_>,
+        _results: QueryResultWriter<'_, W>,
+        _schema_cache: &mut HashMap<u32, CachedSchema>,
+    ) -> io::Result<()> {
+        panic!() // never called
+    }
+
+    async fn on_close(&mut self, _stmt: DeallocateId) {}
+
+    async fn on_reset(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    async fn on_init(&mut self, _: &str, w: Option<InitWriter<'_, W>>) -> io::Result<()> {
+        match w {
+            Some(w) => w.ok().await,
+            None => Ok(()),
+        }
+    }
+
+    async fn on_change_user(&mut self, _: &str, _: &str, _: &str) -> io::Result<()> {
+        Ok(())
+    }
+
+    async fn on_query(
+        &mut self,
+        _query: &str,
+        results: QueryResultWriter<'_, W>,
+    ) -> QueryResultsResponse {
+        // Dummy response
+        QueryResultsResponse::IoResult(results.completed(1, 0, None).await)
+    }
+
+    fn password_for_username(&self, _username: &str) -> Option<Vec<u8>> {
+        Some(b"password".to_vec())
+    }
+
+    fn version(&self) -> String {
+        "8.0.31-readyset\0".to_string()
+    }
+}
+
+/// Start a server accepting a single connection with TLS enabled, and return its port
+async fn start_server() -> u16 {
+    start_server_with_require_tls(false).await
+}
+
+/// Start a server accepting a single connection with TLS enabled, optionally rejecting clients
+/// which don't connect over TLS, and return its port
+async fn start_server_with_require_tls(require_tls: bool) -> u16 {
+    // Load the identity file as bytes (using relative path)
+    let identity_file = include_bytes!("tls_certs/keyStore.p12");
+    let identity = native_tls::Identity::from_pkcs12(identity_file, "password").unwrap();
+    let tls_acceptor = Arc::new(TlsAcceptor::from(
+        native_tls::TlsAcceptor::new(identity).unwrap(),
+    ));
+
+    let (send_port, recv_port) = oneshot::channel();
+    tokio::spawn(async move {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        send_port
+            .send(listener.local_addr().unwrap().port())
+            .unwrap();
+        let (socket, _) = listener.accept().await.unwrap();
+        MySqlIntermediary::run_on_tls_tcp(TestBackend, socket, false, tls_acceptor, require_tls)
+            .await
+            .unwrap();
+    });
+
+    recv_port.await.unwrap()
+}
+
+fn opts(port: u16) -> OptsBuilder {
+    OptsBuilder::default()
+        .ip_or_hostname("127.0.0.1")
+        .tcp_port(port)
+        .user(Some("root"))
+        .pass(Some("password"))
+}
+
+fn assert_access_denied(res: Result<mysql_async::Conn, mysql_async::Error>) {
+    match res {
+        Err(mysql_async::Error::Server(err)) => {
+            assert_eq!(err.code, u16::from(ErrorKind::ER_ACCESS_DENIED_ERROR))
+        }
+        Err(err) => panic!("Not a mysql error: {:?}", err),
+        Ok(_) => panic!("Expected authentication to fail"),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn connect() {
+    let port = start_server().await;
+
+    // With SslOpts set, the client errors if the server does not support TLS.
+    let mut conn = mysql_async::Conn::new(opts(port).ssl_opts(
+        // The test certificate is self signed, which by default the client rejects
+        SslOpts::default().with_danger_accept_invalid_certs(true),
+    ))
+    .await
+    .unwrap();
+    // The server should then accept queries.
+    conn.query_drop("FAKE QUERY").await.unwrap();
+    conn.disconnect().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn connect_without_tls() {
+    let port = start_server().await;
+
+    let mut conn = mysql_async::Conn::new(opts(port)).await.unwrap();
+    conn.query_drop("FAKE QUERY").await.unwrap();
+    conn.disconnect().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn require_tls_rejects_plaintext_clients() {
+    let port = start_server_with_require_tls(true).await;
+
+    assert_access_denied(mysql_async::Conn::new(opts(port)).await);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn require_tls_accepts_tls_clients() {
+    let port = start_server_with_require_tls(true).await;
+
+    let mut conn = mysql_async::Conn::new(
+        opts(port).ssl_opts(SslOpts::default().with_danger_accept_invalid_certs(true)),
+    )
+    .await
+    .unwrap();
+    conn.query_drop("FAKE QUERY").await.unwrap();
+    conn.disconnect().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn full_auth_over_tls_with_wrong_password() {
+    let port = start_server().await;
+
+    // The scramble won't match, so the server asks for the full password, which the client sends
+    // in cleartext over the TLS connection
+    assert_access_denied(
+        mysql_async::Conn::new(
+            opts(port)
+                .pass(Some("wrong password"))
+                .ssl_opts(SslOpts::default().with_danger_accept_invalid_certs(true)),
+        )
+        .await,
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn full_auth_with_public_key_with_wrong_password() {
+    let port = start_server().await;
+
+    // Without TLS, the client requests the server's public key and sends the full password
+    // encrypted with it
+    assert_access_denied(mysql_async::Conn::new(opts(port).pass(Some("wrong password"))).await);
+}
@@ -39,7 +39,8 @@ fn op(es: ExprStrategy) -> impl Strategy<Value = Expr> {
         Just(BinaryOperator::Add),
         Just(BinaryOperator::Subtract),
         Just(BinaryOperator::Multiply),
-        Just(BinaryOperator::Divide)
+        Just(BinaryOperator::Divide),
+        Just(BinaryOperator::Modulo)
     ];
     prop_oneof![
         // Choose a random op ~94% of the time
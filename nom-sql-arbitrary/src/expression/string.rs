@@ -42,6 +42,8 @@ mod call {
                 coalesce(es.string.clone()),
                 concat(es.clone()),
                 split_part(es.clone()),
+                replace(es.clone()),
+                reverse(es.clone()),
                 substring(es)
             ]
             .boxed(),
@@ -49,6 +51,8 @@ mod call {
                 if_null(es.string.clone()),
                 coalesce(es.string.clone()),
                 concat(es.clone()),
+                replace(es.clone()),
+                reverse(es.clone()),
                 substring(es)
             ]
             .boxed(),
@@ -85,6 +89,28 @@ mod call {
         })
     }
 
+    /// Produces a [`Strategy`] that generates a string [`Expr::Call`] with
+    /// [`BuiltinFunction::Replace`].
+    fn replace(es: ExprStrategy) -> impl Strategy<Value = Expr> {
+        (es.string.clone(), es.string.clone(), es.string).prop_map(|(s, from, to)| {
+            Expr::Call(FunctionExpr::Call {
+                name: "replace".into(),
+                arguments: vec![s, from, to],
+            })
+        })
+    }
+
+    /// Produces a [`Strategy`] that generates a string [`Expr::Call`] with
+    /// [`BuiltinFunction::Reverse`].
+    fn reverse(es: ExprStrategy) -> impl Strategy<Value = Expr> {
+        es.string.prop_map(|s| {
+            Expr::Call(FunctionExpr::Call {
+                name: "reverse".into(),
+                arguments: vec![s],
+            })
+        })
+    }
+
     /// Produces a [`Strategy`] that generates a string [`Expr::Call`] with
     /// [`BuiltinFunction::Substring`].
     fn substring(es: ExprStrategy) -> impl Strategy<Value = Expr> {
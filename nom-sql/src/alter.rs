@@ -1,4 +1,4 @@
-//! ALTER TABLE Statement AST and parsing (incomplete)
+//! ALTER TABLE (incomplete) and ALTER CACHE Statement AST and parsing
 //!
 //! See https://dev.mysql.com/doc/refman/8.0/en/alter-table.html
 
@@ -10,7 +10,7 @@ use nom::branch::alt;
 use nom::bytes::complete::tag_no_case;
 use nom::combinator::{map, opt, value};
 use nom::multi::separated_list1;
-use nom::sequence::{preceded, terminated};
+use nom::sequence::{preceded, terminated, tuple};
 use nom_locate::LocatedSpan;
 use readyset_util::fmt::fmt_with;
 use serde::{Deserialize, Serialize};
@@ -21,7 +21,7 @@ use crate::common::{
     debug_print, parse_fallible, statement_terminator, until_statement_terminator, ws_sep_comma,
     TableKey,
 };
-use crate::create::key_specification;
+use crate::create::{cache_options, key_specification, CacheOptions};
 use crate::literal::literal;
 use crate::table::{relation, Relation};
 use crate::whitespace::whitespace1;
@@ -463,10 +463,105 @@ pub fn alter_table_statement(
     }
 }
 
+/// A change to make to an existing cache with an [`AlterCacheStatement`]
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize, Arbitrary)]
+pub enum AlterCacheDefinition {
+    /// `RENAME TO <new_name>`
+    RenameTo(Relation),
+    /// `SET ALWAYS` or `SET NOT ALWAYS`
+    SetAlways(bool),
+    /// `WITH (<option> = <value>, ...)`, which sets each of the given [`CacheOptions`] and leaves
+    /// any others unchanged
+    SetOptions(CacheOptions),
+}
+
+impl DialectDisplay for AlterCacheDefinition {
+    fn display(&self, dialect: Dialect) -> impl fmt::Display + '_ {
+        fmt_with(move |f| match self {
+            Self::RenameTo(new_name) => write!(f, "RENAME TO {}", new_name.display(dialect)),
+            Self::SetAlways(true) => write!(f, "SET ALWAYS"),
+            Self::SetAlways(false) => write!(f, "SET NOT ALWAYS"),
+            Self::SetOptions(options) => write!(f, "{options}"),
+        })
+    }
+}
+
+/// `ALTER CACHE <name> <definition>`, where the definition is one of `RENAME TO <new_name>`,
+/// `SET [NOT] ALWAYS`, or `WITH (<option> = <value>, ...)`
+///
+/// This is a non-standard ReadySet specific extension to SQL, which changes the metadata of an
+/// existing cache in place, without recreating it.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize, Arbitrary)]
+pub struct AlterCacheStatement {
+    pub name: Relation,
+    pub definition: AlterCacheDefinition,
+}
+
+impl DialectDisplay for AlterCacheStatement {
+    fn display(&self, dialect: Dialect) -> impl fmt::Display + '_ {
+        fmt_with(move |f| {
+            write!(
+                f,
+                "ALTER CACHE {} {}",
+                self.name.display(dialect),
+                self.definition.display(dialect)
+            )
+        })
+    }
+}
+
+fn alter_cache_definition(
+    dialect: Dialect,
+) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], AlterCacheDefinition> {
+    move |i| {
+        alt((
+            map(
+                preceded(
+                    tuple((
+                        tag_no_case("rename"),
+                        whitespace1,
+                        tag_no_case("to"),
+                        whitespace1,
+                    )),
+                    relation(dialect),
+                ),
+                AlterCacheDefinition::RenameTo,
+            ),
+            map(
+                preceded(
+                    tuple((tag_no_case("set"), whitespace1)),
+                    terminated(
+                        opt(terminated(tag_no_case("not"), whitespace1)),
+                        tag_no_case("always"),
+                    ),
+                ),
+                |not| AlterCacheDefinition::SetAlways(not.is_none()),
+            ),
+            map(cache_options(dialect), AlterCacheDefinition::SetOptions),
+        ))(i)
+    }
+}
+
+pub fn alter_cache_statement(
+    dialect: Dialect,
+) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], AlterCacheStatement> {
+    move |i| {
+        let (i, _) = tag_no_case("alter")(i)?;
+        let (i, _) = whitespace1(i)?;
+        let (i, _) = tag_no_case("cache")(i)?;
+        let (i, _) = whitespace1(i)?;
+        let (i, name) = relation(dialect)(i)?;
+        let (i, _) = whitespace1(i)?;
+        let (i, definition) = alter_cache_definition(dialect)(i)?;
+        let (i, _) = statement_terminator(i)?;
+        Ok((i, AlterCacheStatement { name, definition }))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{Column, Dialect, SqlType};
+    use crate::{Column, Dialect, EvictionPriority, SqlType};
 
     #[test]
     fn parse_add_column_no_column_tag() {
@@ -504,6 +599,64 @@ mod tests {
         assert_eq!(result.unwrap().1, expected);
     }
 
+    #[test]
+    fn alter_cache_options() {
+        let res = test_parse!(
+            alter_cache_statement(Dialect::MySQL),
+            b"ALTER CACHE foo WITH (eviction_priority = 'low', memory_limit = '16MB');"
+        );
+        assert_eq!(
+            res,
+            AlterCacheStatement {
+                name: "foo".into(),
+                definition: AlterCacheDefinition::SetOptions(CacheOptions {
+                    memory_limit: Some(16 << 20),
+                    eviction_priority: Some(EvictionPriority::Low),
+                    ..Default::default()
+                }),
+            }
+        );
+        assert_eq!(
+            res.display(Dialect::MySQL).to_string(),
+            "ALTER CACHE `foo` WITH (memory_limit = '16MB', eviction_priority = 'low')"
+        );
+    }
+
+    #[test]
+    fn alter_cache_rename() {
+        let res = test_parse!(
+            alter_cache_statement(Dialect::PostgreSQL),
+            b"ALTER CACHE foo RENAME TO bar"
+        );
+        assert_eq!(res.name, "foo".into());
+        assert_eq!(res.definition, AlterCacheDefinition::RenameTo("bar".into()));
+        assert_eq!(
+            res.display(Dialect::PostgreSQL).to_string(),
+            "ALTER CACHE \"foo\" RENAME TO \"bar\""
+        );
+    }
+
+    #[test]
+    fn alter_cache_set_always() {
+        for (query, always) in [
+            (b"ALTER CACHE foo SET ALWAYS".as_slice(), true),
+            (b"alter cache foo set not always;".as_slice(), false),
+        ] {
+            let res = test_parse!(alter_cache_statement(Dialect::MySQL), query);
+            assert_eq!(res.definition, AlterCacheDefinition::SetAlways(always));
+            let displayed = res.display(Dialect::MySQL).to_string();
+            assert_eq!(
+                test_parse!(alter_cache_statement(Dialect::MySQL), displayed.as_bytes()),
+                res
+            );
+        }
+    }
+
+    #[test]
+    fn alter_cache_without_definition() {
+        test_parse_expect_err!(alter_cache_statement(Dialect::MySQL), b"ALTER CACHE foo");
+    }
+
     mod mysql {
         use super::*;
         use crate::common::ReferentialAction;
@@ -18,16 +18,17 @@ use crate::set::Variable;
 use crate::transaction::{CommitStatement, RollbackStatement, StartTransactionStatement};
 use crate::truncate::TruncateStatement;
 use crate::{
-    AlterColumnOperation, AlterTableDefinition, AlterTableStatement, CacheInner, CaseWhenBranch,
-    Column, ColumnConstraint, ColumnSpecification, CommentStatement, CommonTableExpr,
-    CompoundSelectStatement, CreateCacheStatement, CreateTableStatement, CreateViewStatement,
-    DeallocateStatement, DeleteStatement, DropAllCachesStatement, DropAllProxiedQueriesStatement,
-    DropCacheStatement, DropTableStatement, DropViewStatement, ExplainStatement, Expr,
-    FieldDefinitionExpr, FieldReference, FunctionExpr, GroupByClause, InValue, InsertStatement,
-    JoinClause, JoinConstraint, JoinRightSide, Literal, OrderBy, OrderClause, Relation,
-    SelectSpecification, SelectStatement, SetNames, SetPostgresParameter, SetStatement,
-    SetVariables, ShowStatement, SqlIdentifier, SqlQuery, SqlType, TableExpr, TableExprInner,
-    TableKey, UpdateStatement, UseStatement,
+    AlterCacheDefinition, AlterCacheStatement, AlterColumnOperation, AlterTableDefinition,
+    AlterTableStatement, CacheInner, CaseWhenBranch, Column, ColumnConstraint, ColumnSpecification,
+    CommentStatement, CommonTableExpr, CompoundSelectStatement, CopySource, CopyStatement,
+    CreateCacheStatement, CreateTableStatement, CreateViewStatement, DeallocateStatement,
+    DeleteStatement, DropAllCachesStatement, DropAllProxiedQueriesStatement, DropCacheStatement,
+    DropTableStatement, DropViewStatement, ExplainStatement, Expr, FieldDefinitionExpr,
+    FieldReference, FunctionExpr, GroupByClause, InValue, InsertStatement, JoinClause,
+    JoinConstraint, JoinRightSide, Literal, OrderBy, OrderClause, Relation, SelectSpecification,
+    SelectStatement, SetNames, SetPostgresParameter, SetStatement, SetVariables, ShowStatement,
+    SqlIdentifier, SqlQuery, SqlType, TableExpr, TableExprInner, TableKey, UpdateStatement,
+    UseStatement,
 };
 
 /// Each method of the `VisitorMut` trait is a hook to be potentially overridden when recursively
@@ -392,6 +393,17 @@ pub trait VisitorMut<'ast>: Sized {
         walk_create_cache_statement(self, create_cache_statement)
     }
 
+    fn visit_alter_cache_statement(
+        &mut self,
+        alter_cache_statement: &'ast mut AlterCacheStatement,
+    ) -> Result<(), Self::Error> {
+        walk_relation(self, &mut alter_cache_statement.name)?;
+        if let AlterCacheDefinition::RenameTo(new_name) = &mut alter_cache_statement.definition {
+            walk_relation(self, new_name)?;
+        }
+        Ok(())
+    }
+
     fn visit_drop_cache_statement(
         &mut self,
         drop_cache_statement: &'ast mut DropCacheStatement,
@@ -462,6 +474,13 @@ pub trait VisitorMut<'ast>: Sized {
         walk_truncate_statement(self, truncate_statement)
     }
 
+    fn visit_copy_statement(
+        &mut self,
+        copy_statement: &'ast mut CopyStatement,
+    ) -> Result<(), Self::Error> {
+        walk_copy_statement(self, copy_statement)
+    }
+
     fn visit_sql_query(&mut self, sql_query: &'ast mut SqlQuery) -> Result<(), Self::Error> {
         walk_sql_query(self, sql_query)
     }
@@ -512,6 +531,7 @@ pub fn walk_expr<'ast, V: VisitorMut<'ast>>(
             visitor.visit_expr(expr.as_mut())?;
             visitor.visit_sql_type(ty)
         }
+        Expr::Interval { value, .. } => visitor.visit_expr(value.as_mut()),
         Expr::Array(exprs) => {
             for expr in exprs {
                 visitor.visit_expr(expr)?;
@@ -549,6 +569,14 @@ pub fn walk_function_expr<'ast, V: VisitorMut<'ast>>(
         FunctionExpr::Max(expr) => visitor.visit_expr(expr.as_mut()),
         FunctionExpr::Min(expr) => visitor.visit_expr(expr.as_mut()),
         FunctionExpr::GroupConcat { expr, .. } => visitor.visit_expr(expr.as_mut()),
+        FunctionExpr::Stddev { expr, .. } | FunctionExpr::Variance { expr, .. } => {
+            visitor.visit_expr(expr.as_mut())
+        }
+        FunctionExpr::BoolAnd(expr)
+        | FunctionExpr::BoolOr(expr)
+        | FunctionExpr::BitAnd(expr)
+        | FunctionExpr::BitOr(expr)
+        | FunctionExpr::BitXor(expr) => visitor.visit_expr(expr.as_mut()),
         FunctionExpr::Call { arguments, .. } => {
             for arg in arguments {
                 visitor.visit_expr(arg)?;
@@ -565,6 +593,20 @@ pub fn walk_function_expr<'ast, V: VisitorMut<'ast>>(
             }
             Ok(())
         }
+        FunctionExpr::Window {
+            function,
+            partition_by,
+            order_by,
+        } => {
+            visitor.visit_function_expr(function.as_mut())?;
+            for expr in partition_by {
+                visitor.visit_expr(expr)?;
+            }
+            for (expr, _) in order_by {
+                visitor.visit_expr(expr)?;
+            }
+            Ok(())
+        }
     }
 }
 
@@ -1194,6 +1236,16 @@ pub fn walk_truncate_statement<'a, V: VisitorMut<'a>>(
     Ok(())
 }
 
+pub fn walk_copy_statement<'a, V: VisitorMut<'a>>(
+    visitor: &mut V,
+    copy_statement: &'a mut CopyStatement,
+) -> Result<(), V::Error> {
+    match &mut copy_statement.source {
+        CopySource::Table { table, .. } => visitor.visit_table(table),
+        CopySource::Query(query) => visitor.visit_select_statement(query),
+    }
+}
+
 pub fn walk_sql_query<'a, V: VisitorMut<'a>>(
     visitor: &mut V,
     sql_query: &'a mut SqlQuery,
@@ -1216,6 +1268,7 @@ pub fn walk_sql_query<'a, V: VisitorMut<'a>>(
         SqlQuery::Rollback(statement) => visitor.visit_rollback_statement(statement),
         SqlQuery::RenameTable(statement) => visitor.visit_rename_table_statement(statement),
         SqlQuery::CreateCache(statement) => visitor.visit_create_cache_statement(statement),
+        SqlQuery::AlterCache(statement) => visitor.visit_alter_cache_statement(statement),
         SqlQuery::DropCache(statement) => visitor.visit_drop_cache_statement(statement),
         SqlQuery::DropAllCaches(statement) => visitor.visit_drop_all_caches_statement(statement),
         SqlQuery::DropAllProxiedQueries(statement) => {
@@ -1229,6 +1282,7 @@ pub fn walk_sql_query<'a, V: VisitorMut<'a>>(
         SqlQuery::Deallocate(statement) => visitor.visit_deallocate_statement(statement),
         SqlQuery::Truncate(statement) => visitor.visit_truncate_statement(statement),
         SqlQuery::CreateDatabase(statement) => visitor.visit_create_database_statement(statement),
+        SqlQuery::Copy(statement) => visitor.visit_copy_statement(statement),
     }
 }
 
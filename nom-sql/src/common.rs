@@ -8,7 +8,7 @@ use itertools::Itertools;
 use nom::branch::alt;
 use nom::bytes::complete::{tag, tag_no_case};
 use nom::character::complete::{char, digit1, line_ending};
-use nom::combinator::{map, map_res, not, opt, peek};
+use nom::combinator::{map, map_opt, map_res, not, opt, peek};
 use nom::error::{ErrorKind, ParseError};
 use nom::multi::{separated_list0, separated_list1};
 use nom::sequence::{delimited, pair, preceded, separated_pair, terminated, tuple};
@@ -21,9 +21,10 @@ use test_strategy::Arbitrary;
 use crate::column::Column;
 use crate::dialect::{Dialect, DialectDisplay};
 use crate::expression::expression;
+use crate::order::{order_type, OrderType};
 use crate::table::Relation;
 use crate::whitespace::{whitespace0, whitespace1};
-use crate::{Expr, FunctionExpr, Literal, NomSqlResult, SqlIdentifier};
+use crate::{Expr, FunctionExpr, InValue, Literal, NomSqlResult, SqlIdentifier};
 
 #[cfg(feature = "debug")]
 pub fn debug_print(tag: &str, i: &[u8]) {
@@ -457,6 +458,103 @@ fn agg_fx_args(
     }
 }
 
+/// Parses the parenthesized argument to an aggregate function which doesn't support `DISTINCT`
+fn non_distinct_agg_fx_arg(
+    dialect: Dialect,
+) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], Box<Expr>> {
+    move |i| {
+        let (i, _) = whitespace0(i)?;
+        map(
+            delimited(
+                terminated(tag("("), whitespace0),
+                expression(dialect),
+                preceded(whitespace0, tag(")")),
+            ),
+            Box::new,
+        )(i)
+    }
+}
+
+/// Parses the statistical, boolean, and bitwise aggregate functions, whose names (and in some
+/// cases, semantics) differ between dialects
+fn statistical_aggregate(
+    dialect: Dialect,
+) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], FunctionExpr> {
+    move |i| {
+        // `STDDEV` and `VARIANCE` compute the population variants in MySQL, but the sample variants
+        // in PostgreSQL
+        let sample = dialect == Dialect::PostgreSQL;
+        alt((
+            map(
+                preceded(tag_no_case("stddev_pop"), non_distinct_agg_fx_arg(dialect)),
+                |expr| FunctionExpr::Stddev {
+                    expr,
+                    sample: false,
+                },
+            ),
+            map(
+                preceded(tag_no_case("stddev_samp"), non_distinct_agg_fx_arg(dialect)),
+                |expr| FunctionExpr::Stddev { expr, sample: true },
+            ),
+            map(
+                preceded(tag_no_case("stddev"), non_distinct_agg_fx_arg(dialect)),
+                move |expr| FunctionExpr::Stddev { expr, sample },
+            ),
+            map(
+                preceded(
+                    |i| match dialect {
+                        Dialect::MySQL => tag_no_case("std")(i),
+                        Dialect::PostgreSQL => Err(nom::Err::Error(ParseError::from_error_kind(
+                            i,
+                            ErrorKind::Tag,
+                        ))),
+                    },
+                    non_distinct_agg_fx_arg(dialect),
+                ),
+                |expr| FunctionExpr::Stddev {
+                    expr,
+                    sample: false,
+                },
+            ),
+            map(
+                preceded(tag_no_case("var_pop"), non_distinct_agg_fx_arg(dialect)),
+                |expr| FunctionExpr::Variance {
+                    expr,
+                    sample: false,
+                },
+            ),
+            map(
+                preceded(tag_no_case("var_samp"), non_distinct_agg_fx_arg(dialect)),
+                |expr| FunctionExpr::Variance { expr, sample: true },
+            ),
+            map(
+                preceded(tag_no_case("variance"), non_distinct_agg_fx_arg(dialect)),
+                move |expr| FunctionExpr::Variance { expr, sample },
+            ),
+            map(
+                preceded(tag_no_case("bool_and"), non_distinct_agg_fx_arg(dialect)),
+                FunctionExpr::BoolAnd,
+            ),
+            map(
+                preceded(tag_no_case("bool_or"), non_distinct_agg_fx_arg(dialect)),
+                FunctionExpr::BoolOr,
+            ),
+            map(
+                preceded(tag_no_case("bit_and"), non_distinct_agg_fx_arg(dialect)),
+                FunctionExpr::BitAnd,
+            ),
+            map(
+                preceded(tag_no_case("bit_or"), non_distinct_agg_fx_arg(dialect)),
+                FunctionExpr::BitOr,
+            ),
+            map(
+                preceded(tag_no_case("bit_xor"), non_distinct_agg_fx_arg(dialect)),
+                FunctionExpr::BitXor,
+            ),
+        ))(i)
+    }
+}
+
 fn delim_fx_args(
     dialect: Dialect,
 ) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], Vec<Expr>> {
@@ -675,11 +773,65 @@ fn substring(dialect: Dialect) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[
     }
 }
 
+/// Parses the SQL `POSITION(substring IN string)` function, which can't be called with regular
+/// function call syntax.
+///
+/// This is desugared into the equivalent regular function call for the dialect, which is
+/// `LOCATE(substring, string)` in MySQL and `STRPOS(string, substring)` in PostgreSQL.
+fn position(dialect: Dialect) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], FunctionExpr> {
+    move |i| {
+        let (i, _) = tag_no_case("position")(i)?;
+        let (i, _) = whitespace0(i)?;
+        let (i, _) = tag("(")(i)?;
+        let (i, _) = whitespace0(i)?;
+        let (i, (substring, string)) = alt((
+            separated_pair(
+                expression(dialect),
+                tuple((whitespace1, tag_no_case("in"), whitespace1)),
+                expression(dialect),
+            ),
+            // If the string is parenthesized, the whole argument gets parsed as an `IN` expression
+            // with a single-element list
+            map_opt(expression(dialect), |expr| match expr {
+                Expr::In {
+                    lhs,
+                    rhs: InValue::List(mut exprs),
+                    negated: false,
+                } if exprs.len() == 1 => Some((*lhs, exprs.pop()?)),
+                _ => None,
+            }),
+        ))(i)?;
+        let (i, _) = whitespace0(i)?;
+        let (i, _) = tag(")")(i)?;
+
+        let (name, arguments) = match dialect {
+            Dialect::MySQL => ("locate", vec![substring, string]),
+            Dialect::PostgreSQL => ("strpos", vec![string, substring]),
+        };
+
+        Ok((
+            i,
+            FunctionExpr::Call {
+                name: name.into(),
+                arguments,
+            },
+        ))
+    }
+}
+
 fn function_call(
     dialect: Dialect,
 ) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], FunctionExpr> {
     move |i| {
-        let (i, name) = dialect.function_identifier()(i)?;
+        let (i, name) = alt((
+            dialect.function_identifier(),
+            // `RIGHT` is a reserved keyword (for `RIGHT JOIN`), but is also the name of a builtin
+            // function
+            map(tag_no_case("right"), |_| "right"),
+            // Likewise, `TRUNCATE` is a keyword (for `TRUNCATE TABLE`) as well as a MySQL math
+            // function
+            map(tag_no_case("truncate"), |_| "truncate"),
+        ))(i)?;
         let (i, _) = whitespace0(i)?;
         let (i, arguments) = delim_fx_args(dialect)(i)?;
         Ok((
@@ -692,8 +844,82 @@ fn function_call(
     }
 }
 
+/// Parse the window specification following the `OVER` keyword in a window function call,
+/// returning the `PARTITION BY` and `ORDER BY` expressions
+#[allow(clippy::type_complexity)]
+fn window_spec(
+    dialect: Dialect,
+) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], (Vec<Expr>, Vec<(Expr, OrderType)>)> {
+    move |i| {
+        let (i, _) = whitespace0(i)?;
+        let (i, _) = tag_no_case("over")(i)?;
+        let (i, _) = whitespace0(i)?;
+        let (i, _) = tag("(")(i)?;
+        let (i, _) = whitespace0(i)?;
+        let (i, partition_by) = opt(map(
+            tuple((
+                tag_no_case("partition"),
+                whitespace1,
+                tag_no_case("by"),
+                whitespace1,
+                separated_list1(ws_sep_comma, expression(dialect)),
+                whitespace0,
+            )),
+            |(_, _, _, _, exprs, _)| exprs,
+        ))(i)?;
+        let (i, order_by) = opt(map(
+            tuple((
+                tag_no_case("order"),
+                whitespace1,
+                tag_no_case("by"),
+                whitespace1,
+                separated_list1(
+                    ws_sep_comma,
+                    pair(
+                        expression(dialect),
+                        map(opt(preceded(whitespace1, order_type)), |ot| {
+                            ot.unwrap_or(OrderType::OrderAscending)
+                        }),
+                    ),
+                ),
+                whitespace0,
+            )),
+            |(_, _, _, _, order, _)| order,
+        ))(i)?;
+        let (i, _) = tag(")")(i)?;
+
+        Ok((
+            i,
+            (
+                partition_by.unwrap_or_default(),
+                order_by.unwrap_or_default(),
+            ),
+        ))
+    }
+}
+
 pub fn function_expr(
     dialect: Dialect,
+) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], FunctionExpr> {
+    move |i| {
+        let (i, function) = function_expr_without_window(dialect)(i)?;
+        let (i, window) = opt(window_spec(dialect))(i)?;
+        Ok((
+            i,
+            match window {
+                Some((partition_by, order_by)) => FunctionExpr::Window {
+                    function: Box::new(function),
+                    partition_by,
+                    order_by,
+                },
+                None => function,
+            },
+        ))
+    }
+}
+
+fn function_expr_without_window(
+    dialect: Dialect,
 ) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], FunctionExpr> {
     move |i| {
         alt((
@@ -751,8 +977,10 @@ pub fn function_expr(
                     separator,
                 },
             ),
+            statistical_aggregate(dialect),
             extract(dialect),
             substring(dialect),
+            position(dialect),
             function_call(dialect),
             function_call_without_parens,
         ))(i)
@@ -1091,6 +1319,58 @@ mod tests {
         );
     }
 
+    #[test]
+    fn statistical_aggregates() {
+        let x = || Box::new(Expr::Column("x".into()));
+        for (dialect, sample) in [(Dialect::MySQL, false), (Dialect::PostgreSQL, true)] {
+            assert_eq!(
+                test_parse!(function_expr(dialect), b"stddev(x)"),
+                FunctionExpr::Stddev { expr: x(), sample }
+            );
+            assert_eq!(
+                test_parse!(function_expr(dialect), b"VARIANCE (x)"),
+                FunctionExpr::Variance { expr: x(), sample }
+            );
+            assert_eq!(
+                test_parse!(function_expr(dialect), b"stddev_samp(x)"),
+                FunctionExpr::Stddev {
+                    expr: x(),
+                    sample: true
+                }
+            );
+            assert_eq!(
+                test_parse!(function_expr(dialect), b"var_pop( x )"),
+                FunctionExpr::Variance {
+                    expr: x(),
+                    sample: false
+                }
+            );
+            assert_eq!(
+                test_parse!(function_expr(dialect), b"bool_and(x)"),
+                FunctionExpr::BoolAnd(x())
+            );
+            assert_eq!(
+                test_parse!(function_expr(dialect), b"bit_xor(x)"),
+                FunctionExpr::BitXor(x())
+            );
+        }
+
+        assert_eq!(
+            test_parse!(function_expr(Dialect::MySQL), b"std(x)"),
+            FunctionExpr::Stddev {
+                expr: x(),
+                sample: false
+            }
+        );
+        assert_eq!(
+            test_parse!(function_expr(Dialect::PostgreSQL), b"std(x)"),
+            FunctionExpr::Call {
+                name: "std".into(),
+                arguments: vec![Expr::Column("x".into())]
+            }
+        );
+    }
+
     #[test]
     fn simple_generic_function() {
         let qlist = [
@@ -1241,6 +1521,55 @@ mod tests {
         );
     }
 
+    #[test]
+    fn position_mysql() {
+        let res = test_parse!(function_expr(Dialect::MySQL), b"position('b' IN a)");
+        assert_eq!(
+            res,
+            FunctionExpr::Call {
+                name: "locate".into(),
+                arguments: vec![Expr::Literal("b".into()), Expr::Column("a".into())]
+            }
+        );
+        assert_eq!(res.display(Dialect::MySQL).to_string(), "locate('b', `a`)");
+    }
+
+    #[test]
+    fn position_postgres() {
+        let res = test_parse!(function_expr(Dialect::PostgreSQL), b"POSITION('b' in (a))");
+        assert_eq!(
+            res,
+            FunctionExpr::Call {
+                name: "strpos".into(),
+                arguments: vec![Expr::Column("a".into()), Expr::Literal("b".into())]
+            }
+        );
+    }
+
+    #[test]
+    fn right_function_call() {
+        let res = test_parse!(function_expr(Dialect::MySQL), b"right(a, 2)");
+        assert_eq!(
+            res,
+            FunctionExpr::Call {
+                name: "right".into(),
+                arguments: vec![Expr::Column("a".into()), Expr::Literal(2.into())]
+            }
+        );
+    }
+
+    #[test]
+    fn truncate_function_call() {
+        let res = test_parse!(function_expr(Dialect::MySQL), b"truncate(a, 2)");
+        assert_eq!(
+            res,
+            FunctionExpr::Call {
+                name: "truncate".into(),
+                arguments: vec![Expr::Column("a".into()), Expr::Literal(2.into())]
+            }
+        );
+    }
+
     #[test]
     fn count_star() {
         assert_eq!(
@@ -1257,6 +1586,65 @@ mod tests {
         );
     }
 
+    #[test]
+    fn window_function() {
+        let res = test_parse!(
+            function_expr(Dialect::MySQL),
+            b"row_number() OVER (PARTITION BY a, b ORDER BY c DESC, d)"
+        );
+        assert_eq!(
+            res,
+            FunctionExpr::Window {
+                function: Box::new(FunctionExpr::Call {
+                    name: "row_number".into(),
+                    arguments: vec![],
+                }),
+                partition_by: vec![Expr::Column("a".into()), Expr::Column("b".into())],
+                order_by: vec![
+                    (Expr::Column("c".into()), OrderType::OrderDescending),
+                    (Expr::Column("d".into()), OrderType::OrderAscending),
+                ],
+            }
+        );
+        assert_eq!(
+            res.display(Dialect::MySQL).to_string(),
+            "row_number() OVER (PARTITION BY `a`, `b` ORDER BY `c` DESC, `d` ASC)"
+        );
+    }
+
+    #[test]
+    fn aggregate_window_function() {
+        let res = test_parse!(function_expr(Dialect::PostgreSQL), b"sum(x) over ()");
+        assert_eq!(
+            res,
+            FunctionExpr::Window {
+                function: Box::new(FunctionExpr::Sum {
+                    expr: Box::new(Expr::Column("x".into())),
+                    distinct: false,
+                }),
+                partition_by: vec![],
+                order_by: vec![],
+            }
+        );
+        assert_eq!(
+            res.display(Dialect::PostgreSQL).to_string(),
+            "sum(\"x\") OVER ()"
+        );
+
+        let res = test_parse!(
+            function_expr(Dialect::PostgreSQL),
+            b"count(*) OVER (ORDER BY x)"
+        );
+        assert_eq!(
+            res,
+            FunctionExpr::Window {
+                function: Box::new(FunctionExpr::CountStar),
+                partition_by: vec![],
+                order_by: vec![(Expr::Column("x".into()), OrderType::OrderAscending)],
+            }
+        );
+    }
+
     #[test]
     fn disallow_trailing_comma_in_column_list() {
         let expected = [
@@ -21,7 +21,9 @@ pub enum CompoundSelectOperator {
     Union,
     DistinctUnion,
     Intersect,
+    IntersectAll,
     Except,
+    ExceptAll,
 }
 
 impl fmt::Display for CompoundSelectOperator {
@@ -30,7 +32,9 @@ impl fmt::Display for CompoundSelectOperator {
             CompoundSelectOperator::Union => write!(f, "UNION"),
             CompoundSelectOperator::DistinctUnion => write!(f, "UNION DISTINCT"),
             CompoundSelectOperator::Intersect => write!(f, "INTERSECT"),
+            CompoundSelectOperator::IntersectAll => write!(f, "INTERSECT ALL"),
             CompoundSelectOperator::Except => write!(f, "EXCEPT"),
+            CompoundSelectOperator::ExceptAll => write!(f, "EXCEPT ALL"),
         }
     }
 }
@@ -91,13 +95,38 @@ fn compound_op(i: LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], CompoundSelectOpera
                 }
             },
         ),
-        map(tag_no_case("intersect"), |_| {
-            CompoundSelectOperator::Intersect
+        map(preceded(tag_no_case("intersect"), set_quantifier), |all| {
+            if all {
+                CompoundSelectOperator::IntersectAll
+            } else {
+                CompoundSelectOperator::Intersect
+            }
+        }),
+        map(preceded(tag_no_case("except"), set_quantifier), |all| {
+            if all {
+                CompoundSelectOperator::ExceptAll
+            } else {
+                CompoundSelectOperator::Except
+            }
         }),
-        map(tag_no_case("except"), |_| CompoundSelectOperator::Except),
     ))(i)
 }
 
+/// Parse the optional `ALL` or `DISTINCT` following `INTERSECT` or `EXCEPT`, returning whether
+/// `ALL` was specified
+fn set_quantifier(i: LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], bool> {
+    map(
+        opt(preceded(
+            whitespace1,
+            alt((
+                map(tag_no_case("all"), |_| true),
+                map(tag_no_case("distinct"), |_| false),
+            )),
+        )),
+        |all| all.unwrap_or(false),
+    )(i)
+}
+
 fn other_selects(
     dialect: Dialect,
 ) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], (Option<CompoundSelectOperator>, SelectStatement)>
@@ -327,6 +356,33 @@ mod tests {
         assert_eq!(res.unwrap().1, SelectSpecification::Compound(expected));
     }
 
+    #[test]
+    fn intersect_and_except() {
+        let qstr = "SELECT id FROM Vote INTERSECT SELECT id FROM Rating \
+                    INTERSECT ALL SELECT id FROM Rating EXCEPT DISTINCT SELECT id FROM Rating \
+                    EXCEPT ALL SELECT id FROM Vote";
+        let res = test_parse!(
+            nested_compound_selection(Dialect::PostgreSQL),
+            qstr.as_bytes()
+        );
+        let SelectSpecification::Compound(res) = res else {
+            panic!("expected a compound select, got {res:?}");
+        };
+        assert_eq!(
+            res.selects
+                .iter()
+                .map(|(op, _)| op.clone())
+                .collect::<Vec<_>>(),
+            vec![
+                None,
+                Some(CompoundSelectOperator::Intersect),
+                Some(CompoundSelectOperator::IntersectAll),
+                Some(CompoundSelectOperator::Except),
+                Some(CompoundSelectOperator::ExceptAll),
+            ]
+        );
+    }
+
     #[test]
     #[ignore]
     fn union_flarum_1() {
@@ -0,0 +1,364 @@
+use std::fmt;
+
+use itertools::Itertools;
+use nom::branch::alt;
+use nom::bytes::complete::{tag, tag_no_case};
+use nom::combinator::{map, opt, value};
+use nom::error::ErrorKind;
+use nom::multi::separated_list1;
+use nom::sequence::{delimited, preceded, terminated, tuple};
+use nom_locate::LocatedSpan;
+use readyset_util::fmt::fmt_with;
+use serde::{Deserialize, Serialize};
+use test_strategy::Arbitrary;
+
+use crate::common::ws_sep_comma;
+use crate::select::nested_selection;
+use crate::table::relation;
+use crate::whitespace::{whitespace0, whitespace1};
+use crate::{
+    Dialect, DialectDisplay, NomSqlError, NomSqlResult, Relation, SelectStatement, SqlIdentifier,
+};
+
+/// The format of the data written by a `COPY` statement
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize, Arbitrary)]
+pub enum CopyFormat {
+    /// Tab-separated columns, with `\N` for NULL and backslash escapes for special characters
+    #[default]
+    Text,
+    /// Comma-separated values, as described in [RFC 4180][]
+    ///
+    /// [RFC 4180]: https://datatracker.ietf.org/doc/html/rfc4180
+    Csv,
+}
+
+impl fmt::Display for CopyFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Text => write!(f, "text"),
+            Self::Csv => write!(f, "csv"),
+        }
+    }
+}
+
+/// The rows copied by a `COPY` statement
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize, Arbitrary)]
+pub enum CopySource {
+    /// `COPY table_name [ ( column_name [, ...] ) ]`
+    Table {
+        table: Relation,
+        /// The columns to copy, or all of the table's columns if empty
+        columns: Vec<SqlIdentifier>,
+    },
+    /// `COPY ( query )`
+    Query(Box<SelectStatement>),
+}
+
+/// A PostgreSQL `COPY ... TO STDOUT` statement.
+///
+/// Only copying to the client in the text and CSV formats is represented here - other forms of
+/// `COPY` (to or from a file or program, or from the client) fail to parse.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize, Arbitrary)]
+pub struct CopyStatement {
+    pub source: CopySource,
+    pub format: CopyFormat,
+    /// Whether to write a header line containing the names of the copied columns
+    pub header: bool,
+}
+
+impl DialectDisplay for CopyStatement {
+    fn display(&self, dialect: Dialect) -> impl fmt::Display + '_ {
+        fmt_with(move |f| {
+            write!(f, "COPY ")?;
+            match &self.source {
+                CopySource::Table { table, columns } => {
+                    write!(f, "{}", table.display(dialect))?;
+                    if !columns.is_empty() {
+                        write!(
+                            f,
+                            " ({})",
+                            columns
+                                .iter()
+                                .map(|c| dialect.quote_identifier(c))
+                                .join(", ")
+                        )?;
+                    }
+                }
+                CopySource::Query(query) => write!(f, "({})", query.display(dialect))?,
+            }
+            write!(f, " TO STDOUT")?;
+
+            if self.format != CopyFormat::Text || self.header {
+                write!(f, " WITH (FORMAT {}", self.format)?;
+                if self.header {
+                    write!(f, ", HEADER")?;
+                }
+                write!(f, ")")?;
+            }
+
+            Ok(())
+        })
+    }
+}
+
+/// A single option in the parenthesized option list of a `COPY` statement
+#[derive(Clone, Copy)]
+enum CopyOption {
+    Format(CopyFormat),
+    Header(bool),
+}
+
+fn copy_format(i: LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], CopyFormat> {
+    alt((
+        value(CopyFormat::Text, tag_no_case("text")),
+        value(CopyFormat::Csv, tag_no_case("csv")),
+    ))(i)
+}
+
+fn copy_boolean(i: LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], bool> {
+    alt((
+        value(
+            true,
+            alt((tag_no_case("true"), tag_no_case("on"), tag("1"))),
+        ),
+        value(
+            false,
+            alt((tag_no_case("false"), tag_no_case("off"), tag("0"))),
+        ),
+    ))(i)
+}
+
+fn copy_option(i: LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], CopyOption> {
+    alt((
+        map(
+            preceded(
+                terminated(tag_no_case("format"), whitespace1),
+                alt((copy_format, delimited(tag("'"), copy_format, tag("'")))),
+            ),
+            CopyOption::Format,
+        ),
+        map(
+            preceded(
+                tag_no_case("header"),
+                opt(preceded(whitespace1, copy_boolean)),
+            ),
+            |header| CopyOption::Header(header.unwrap_or(true)),
+        ),
+    ))(i)
+}
+
+/// Parse the options following `TO STDOUT`, in either the current parenthesized syntax or the
+/// legacy `CSV [ HEADER ]` syntax, returning the format and whether a header was requested
+fn copy_options(i: LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], (CopyFormat, bool)> {
+    let (i, _) = opt(terminated(tag_no_case("with"), whitespace0))(i)?;
+    alt((
+        map(
+            delimited(
+                terminated(tag("("), whitespace0),
+                separated_list1(ws_sep_comma, copy_option),
+                preceded(whitespace0, tag(")")),
+            ),
+            |options| {
+                options.into_iter().fold(
+                    (CopyFormat::default(), false),
+                    |(format, header), option| match option {
+                        CopyOption::Format(format) => (format, header),
+                        CopyOption::Header(header) => (format, header),
+                    },
+                )
+            },
+        ),
+        map(
+            tuple((
+                tag_no_case("csv"),
+                opt(preceded(whitespace1, tag_no_case("header"))),
+            )),
+            |(_, header)| (CopyFormat::Csv, header.is_some()),
+        ),
+    ))(i)
+}
+
+fn copy_source(dialect: Dialect) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], CopySource> {
+    move |i| {
+        alt((
+            map(
+                delimited(
+                    tuple((whitespace0, tag("("), whitespace0)),
+                    nested_selection(dialect),
+                    tuple((whitespace0, tag(")"))),
+                ),
+                |query| CopySource::Query(Box::new(query)),
+            ),
+            map(
+                tuple((
+                    preceded(whitespace1, relation(dialect)),
+                    opt(delimited(
+                        tuple((whitespace0, tag("("), whitespace0)),
+                        separated_list1(ws_sep_comma, dialect.identifier()),
+                        tuple((whitespace0, tag(")"))),
+                    )),
+                )),
+                |(table, columns)| CopySource::Table {
+                    table,
+                    columns: columns.unwrap_or_default(),
+                },
+            ),
+        ))(i)
+    }
+}
+
+/// Parse a `COPY ... TO STDOUT` statement.
+///
+/// The supported subset of the [Postgres][] grammar is:
+///
+/// ```sql
+/// COPY { table_name [ ( column_name [, ...] ) ] | ( query ) }
+///     TO STDOUT
+///     [ [ WITH ] ( option [, ...] ) | [ WITH ] CSV [ HEADER ] ]
+///
+/// where option can be one of:
+///
+///     FORMAT { text | csv }
+///     HEADER [ boolean ]
+/// ```
+///
+/// [Postgres]: https://www.postgresql.org/docs/current/sql-copy.html
+pub fn copy(dialect: Dialect) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], CopyStatement> {
+    move |i| {
+        if dialect != Dialect::PostgreSQL {
+            return Err(nom::Err::Error(NomSqlError {
+                input: i,
+                kind: ErrorKind::Fail,
+            }));
+        }
+        let (i, _) = tag_no_case("copy")(i)?;
+        let (i, source) = copy_source(dialect)(i)?;
+        let (i, _) = whitespace0(i)?;
+        let (i, _) = tag_no_case("to")(i)?;
+        let (i, _) = whitespace1(i)?;
+        let (i, _) = tag_no_case("stdout")(i)?;
+        let (i, options) = opt(preceded(whitespace1, copy_options))(i)?;
+        let (format, header) = options.unwrap_or_default();
+
+        Ok((
+            i,
+            CopyStatement {
+                source,
+                format,
+                header,
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_query, Column, Expr, FieldDefinitionExpr, TableExpr};
+
+    fn parse(input: &str) -> CopyStatement {
+        test_parse!(copy(Dialect::PostgreSQL), input.as_bytes())
+    }
+
+    fn select_from_t() -> Box<SelectStatement> {
+        Box::new(SelectStatement {
+            tables: vec![TableExpr::from(Relation::from("t"))],
+            fields: vec![FieldDefinitionExpr::Expr {
+                expr: Expr::Column(Column::from("x")),
+                alias: None,
+            }],
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn copy_query_to_stdout() {
+        assert_eq!(
+            parse("COPY (SELECT x FROM t) TO STDOUT"),
+            CopyStatement {
+                source: CopySource::Query(select_from_t()),
+                format: CopyFormat::Text,
+                header: false,
+            }
+        );
+        assert_eq!(
+            parse("copy(select x from t)to stdout"),
+            parse("COPY (SELECT x FROM t) TO STDOUT")
+        );
+    }
+
+    #[test]
+    fn copy_table_to_stdout() {
+        assert_eq!(
+            parse("COPY public.t (a, b) TO STDOUT"),
+            CopyStatement {
+                source: CopySource::Table {
+                    table: Relation {
+                        schema: Some("public".into()),
+                        name: "t".into(),
+                    },
+                    columns: vec!["a".into(), "b".into()],
+                },
+                format: CopyFormat::Text,
+                header: false,
+            }
+        );
+        assert_eq!(
+            parse("COPY t TO STDOUT").source,
+            CopySource::Table {
+                table: Relation::from("t"),
+                columns: vec![],
+            }
+        );
+    }
+
+    #[test]
+    fn copy_options() {
+        let res = parse("COPY (SELECT x FROM t) TO STDOUT WITH (FORMAT csv, HEADER)");
+        assert_eq!(res.format, CopyFormat::Csv);
+        assert!(res.header);
+
+        let res = parse("COPY (SELECT x FROM t) TO STDOUT (header false, format 'csv')");
+        assert_eq!(res.format, CopyFormat::Csv);
+        assert!(!res.header);
+
+        let res = parse("COPY (SELECT x FROM t) TO STDOUT WITH (FORMAT text)");
+        assert_eq!(res.format, CopyFormat::Text);
+        assert!(!res.header);
+
+        let res = parse("COPY (SELECT x FROM t) TO STDOUT WITH CSV HEADER");
+        assert_eq!(res.format, CopyFormat::Csv);
+        assert!(res.header);
+
+        let res = parse("COPY t TO STDOUT CSV");
+        assert_eq!(res.format, CopyFormat::Csv);
+        assert!(!res.header);
+    }
+
+    #[test]
+    fn unsupported_copy_forms() {
+        test_parse_expect_err!(copy(Dialect::PostgreSQL), b"COPY t FROM STDIN");
+        test_parse_expect_err!(copy(Dialect::PostgreSQL), b"COPY t TO '/tmp/t.csv'");
+        test_parse_expect_err!(copy(Dialect::MySQL), b"COPY t TO STDOUT");
+
+        // Unsupported options are left unparsed, which fails the parse of the full statement
+        for input in [
+            "COPY t TO STDOUT WITH (FORMAT binary)",
+            "COPY t TO STDOUT WITH (DELIMITER '|')",
+            "COPY t TO STDOUT WITH CSV FORCE QUOTE *",
+        ] {
+            assert!(parse_query(Dialect::PostgreSQL, input).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn display() {
+        for input in [
+            "COPY \"t\" TO STDOUT",
+            "COPY \"public\".\"t\" (\"a\", \"b\") TO STDOUT WITH (FORMAT text, HEADER)",
+            "COPY (SELECT \"x\" FROM \"t\") TO STDOUT WITH (FORMAT csv)",
+        ] {
+            assert_eq!(parse(input).display(Dialect::PostgreSQL).to_string(), input);
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use std::str::FromStr;
+use std::time::Duration;
 use std::{fmt, str};
 
 use derive_more::From;
@@ -12,6 +13,7 @@ use nom::multi::{separated_list0, separated_list1};
 use nom::sequence::{delimited, preceded, separated_pair, terminated, tuple};
 use nom::{Compare, CompareResult};
 use nom_locate::LocatedSpan;
+use proptest::strategy::Strategy;
 use readyset_util::fmt::fmt_with;
 use serde::{Deserialize, Serialize};
 use test_strategy::Arbitrary;
@@ -332,7 +334,184 @@ struct CreateCacheOptions {
     concurrently: bool,
 }
 
-/// `CREATE CACHE [CONCURRENTLY] [ALWAYS] [<name>] FROM ...`
+/// Per-cache policies, set with `CREATE CACHE ... WITH (<option> = <value>, ...) FROM ...`
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize, Arbitrary)]
+pub struct CacheOptions {
+    /// The maximum amount of replication lag that reads from the cache may observe. Reads which
+    /// can't be served from data at most this stale are proxied to the upstream database instead.
+    #[strategy(proptest::option::of((0..=u32::MAX as u64).prop_map(Duration::from_millis)))]
+    pub max_staleness: Option<Duration>,
+    /// The maximum amount of time that results may stay in the cache. Once it elapses, the cache
+    /// is emptied and results are recomputed from the base tables on their next read. This is
+    /// useful for caches over data which isn't replicated, such as views or foreign tables.
+    ///
+    /// Expiry is checked along with memory usage, so it's only as precise as the memory check
+    /// frequency, and fully materialized caches are never expired.
+    #[strategy(proptest::option::of((0..=u32::MAX as u64).prop_map(Duration::from_millis)))]
+    pub ttl: Option<Duration>,
+    /// The maximum amount of memory, in bytes, that the materialized state of the cache may use
+    /// before keys are evicted from it.
+    #[strategy(proptest::option::of((0..=u32::MAX as u64).prop_map(|n| n * 1024)))]
+    pub memory_limit: Option<u64>,
+    /// The order in which the cache is evicted from when the server as a whole runs out of memory
+    pub eviction_priority: Option<EvictionPriority>,
+}
+
+/// The priority with which a cache is evicted from, relative to other caches, when the server
+/// exceeds its memory limit. Lower priority caches are evicted from first.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize,
+    Arbitrary,
+)]
+pub enum EvictionPriority {
+    Low,
+    #[default]
+    Normal,
+    High,
+}
+
+impl fmt::Display for EvictionPriority {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Low => write!(f, "low"),
+            Self::Normal => write!(f, "normal"),
+            Self::High => write!(f, "high"),
+        }
+    }
+}
+
+impl FromStr for EvictionPriority {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "low" => Ok(Self::Low),
+            "normal" => Ok(Self::Normal),
+            "high" => Ok(Self::High),
+            _ => Err("invalid eviction priority"),
+        }
+    }
+}
+
+impl CacheOptions {
+    /// Returns true if none of the options have been set
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Overwrites each option in `self` with the corresponding option in `other`, if it's set
+    pub fn update(&mut self, other: CacheOptions) {
+        let CacheOptions {
+            max_staleness,
+            ttl,
+            memory_limit,
+            eviction_priority,
+        } = other;
+        self.max_staleness = max_staleness.or(self.max_staleness);
+        self.ttl = ttl.or(self.ttl);
+        self.memory_limit = memory_limit.or(self.memory_limit);
+        self.eviction_priority = eviction_priority.or(self.eviction_priority);
+    }
+}
+
+impl fmt::Display for CacheOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut options = vec![];
+        if let Some(max_staleness) = self.max_staleness {
+            options.push(format!(
+                "max_staleness = '{}'",
+                DisplayDuration(max_staleness)
+            ));
+        }
+        if let Some(ttl) = self.ttl {
+            options.push(format!("ttl = '{}'", DisplayDuration(ttl)));
+        }
+        if let Some(memory_limit) = self.memory_limit {
+            options.push(format!("memory_limit = '{}'", DisplayBytes(memory_limit)));
+        }
+        if let Some(eviction_priority) = self.eviction_priority {
+            options.push(format!("eviction_priority = '{eviction_priority}'"));
+        }
+        write!(f, "WITH ({})", options.join(", "))
+    }
+}
+
+/// Displays a [`Duration`] (truncated to whole milliseconds) using the largest unit that
+/// represents it exactly, in the form accepted by [`parse_duration`]
+struct DisplayDuration(Duration);
+
+impl fmt::Display for DisplayDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ms = self.0.as_millis();
+        match [(3_600_000, "h"), (60_000, "m"), (1_000, "s")]
+            .into_iter()
+            .find(|(unit_ms, _)| ms != 0 && ms % unit_ms == 0)
+        {
+            Some((unit_ms, unit)) => write!(f, "{}{unit}", ms / unit_ms),
+            None => write!(f, "{ms}ms"),
+        }
+    }
+}
+
+/// Parses a duration of the form `<integer><unit>`, where the unit is one of `ms`, `s`, `m`, or
+/// `h`
+fn parse_duration(s: &str) -> Option<Duration> {
+    let s = s.trim();
+    let unit_start = s.find(|c: char| !c.is_ascii_digit())?;
+    let (n, unit) = s.split_at(unit_start);
+    let n = n.parse::<u64>().ok()?;
+    match unit.trim() {
+        "ms" => Some(Duration::from_millis(n)),
+        "s" => Some(Duration::from_secs(n)),
+        "m" => Some(Duration::from_secs(n.checked_mul(60)?)),
+        "h" => Some(Duration::from_secs(n.checked_mul(60 * 60)?)),
+        _ => None,
+    }
+}
+
+/// Displays a number of bytes using the largest unit that represents it exactly, in the form
+/// accepted by [`parse_bytes`]
+struct DisplayBytes(u64);
+
+impl fmt::Display for DisplayBytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match [(1 << 30, "GB"), (1 << 20, "MB"), (1 << 10, "KB")]
+            .into_iter()
+            .find(|(unit_bytes, _)| self.0 != 0 && self.0 % unit_bytes == 0)
+        {
+            Some((unit_bytes, unit)) => write!(f, "{}{unit}", self.0 / unit_bytes),
+            None => write!(f, "{}B", self.0),
+        }
+    }
+}
+
+/// Parses a number of bytes of the form `<integer><unit>`, where the unit is one of `B`, `KB`,
+/// `MB`, or `GB` (case-insensitive, with each unit 1024 times the previous one)
+fn parse_bytes(s: &str) -> Option<u64> {
+    let s = s.trim();
+    let unit_start = s.find(|c: char| !c.is_ascii_digit())?;
+    let (n, unit) = s.split_at(unit_start);
+    let n = n.parse::<u64>().ok()?;
+    match unit.trim().to_ascii_uppercase().as_str() {
+        "B" => Some(n),
+        "KB" => n.checked_mul(1 << 10),
+        "MB" => n.checked_mul(1 << 20),
+        "GB" => n.checked_mul(1 << 30),
+        _ => None,
+    }
+}
+
+/// `CREATE CACHE [CONCURRENTLY] [ALWAYS] [<name>] [WITH (<option> = <value>, ...)] FROM ...`
 ///
 /// This is a non-standard ReadySet specific extension to SQL
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize, Arbitrary)]
@@ -354,6 +533,8 @@ pub struct CreateCacheStatement {
     pub always: bool,
     /// Whether the CREATE CACHE STATEMENT should block or run concurrently
     pub concurrently: bool,
+    /// Policies for the cache, set with `WITH (...)`
+    pub options: CacheOptions,
 }
 
 impl DialectDisplay for CreateCacheStatement {
@@ -369,6 +550,9 @@ impl DialectDisplay for CreateCacheStatement {
             if let Some(name) = &self.name {
                 write!(f, "{} ", name.display(dialect))?;
             }
+            if !self.options.is_empty() {
+                write!(f, "{} ", self.options)?;
+            }
             write!(f, "FROM ")?;
             match &self.inner {
                 Ok(inner) => write!(f, "{}", inner.display(dialect)),
@@ -1102,6 +1286,82 @@ fn cached_query_options(mut i: LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], Create
     Ok((i, opts))
 }
 
+/// Parse the `WITH (<option> = <value>, ...)` clause of a `CREATE CACHE` statement
+pub fn cache_options(
+    dialect: Dialect,
+) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], CacheOptions> {
+    // A single `<option> = <value>` pair
+    enum CacheOption {
+        MaxStaleness(Duration),
+        Ttl(Duration),
+        MemoryLimit(u64),
+        EvictionPriority(EvictionPriority),
+    }
+
+    fn option_value<'a, O>(
+        dialect: Dialect,
+        name: &'static str,
+        parse: impl Fn(&str) -> Option<O>,
+    ) -> impl FnMut(LocatedSpan<&'a [u8]>) -> NomSqlResult<&'a [u8], O> {
+        map_res(
+            preceded(
+                tuple((tag_no_case(name), whitespace0, tag("="), whitespace0)),
+                dialect.utf8_string_literal(),
+            ),
+            move |s| parse(&s).ok_or("invalid cache option value"),
+        )
+    }
+
+    move |i| {
+        let (i, _) = tag_no_case("with")(i)?;
+        let (i, _) = whitespace0(i)?;
+        let (i, _) = tag("(")(i)?;
+        let (i, _) = whitespace0(i)?;
+        let (remaining, parsed) = separated_list1(
+            ws_sep_comma,
+            alt((
+                map(
+                    option_value(dialect, "max_staleness", parse_duration),
+                    CacheOption::MaxStaleness,
+                ),
+                map(
+                    option_value(dialect, "ttl", parse_duration),
+                    CacheOption::Ttl,
+                ),
+                map(
+                    option_value(dialect, "memory_limit", parse_bytes),
+                    CacheOption::MemoryLimit,
+                ),
+                map(
+                    option_value(dialect, "eviction_priority", |s| s.parse().ok()),
+                    CacheOption::EvictionPriority,
+                ),
+            )),
+        )(i)?;
+
+        // Error if the same option appears twice.
+        let mut options = CacheOptions::default();
+        for option in parsed {
+            let duplicate = match option {
+                CacheOption::MaxStaleness(v) => options.max_staleness.replace(v).is_some(),
+                CacheOption::Ttl(v) => options.ttl.replace(v).is_some(),
+                CacheOption::MemoryLimit(v) => options.memory_limit.replace(v).is_some(),
+                CacheOption::EvictionPriority(v) => options.eviction_priority.replace(v).is_some(),
+            };
+            if duplicate {
+                return Err(nom::Err::Failure(NomSqlError::from_error_kind(
+                    i,
+                    ErrorKind::Permutation,
+                )));
+            }
+        }
+
+        let (i, _) = whitespace0(remaining)?;
+        let (i, _) = tag(")")(i)?;
+        Ok((i, options))
+    }
+}
+
 /// Extract the [`SelectStatement`] or Query ID from a CREATE CACHE statement. Query ID is
 /// parsed as a SqlIdentifier
 pub fn cached_query_inner(
@@ -1127,6 +1387,7 @@ pub fn create_cached_query(
         let (i, _) = whitespace1(i)?;
         let (i, opts) = cached_query_options(i)?;
         let (i, name) = opt(terminated(relation(dialect), whitespace1))(i)?;
+        let (i, options) = opt(terminated(cache_options(dialect), whitespace1))(i)?;
         let (i, _) = tag_no_case("from")(i)?;
         let (i, _) = whitespace1(i)?;
         let (i, inner) =
@@ -1139,6 +1400,7 @@ pub fn create_cached_query(
                 unparsed_create_cache_statement,
                 always: opts.always,
                 concurrently: opts.concurrently,
+                options: options.unwrap_or_default(),
             },
         ))
     }
@@ -1949,6 +2211,105 @@ mod tests {
             }
         }
 
+        #[test]
+        fn create_cached_query_with_max_staleness() {
+            let res = test_parse!(
+                create_cached_query(Dialect::MySQL),
+                b"CREATE CACHE foo WITH (max_staleness = '5s') FROM SELECT id FROM users WHERE name = ?"
+            );
+            assert_eq!(res.name, Some("foo".into()));
+            assert_eq!(res.options.max_staleness, Some(Duration::from_secs(5)));
+            assert_eq!(
+                res.display(Dialect::MySQL).to_string(),
+                "CREATE CACHE `foo` WITH (max_staleness = '5s') FROM SELECT `id` FROM `users` WHERE (`name` = ?)"
+            );
+
+            let res = test_parse!(
+                create_cached_query(Dialect::MySQL),
+                b"CREATE CACHE ALWAYS WITH(MAX_STALENESS='1500ms') FROM SELECT id FROM users"
+            );
+            assert!(res.always);
+            assert_eq!(res.name, None);
+            assert_eq!(res.options.max_staleness, Some(Duration::from_millis(1500)));
+        }
+
+        #[test]
+        fn create_cached_query_with_ttl() {
+            let res = test_parse!(
+                create_cached_query(Dialect::MySQL),
+                b"CREATE CACHE foo WITH (ttl = '10m', max_staleness = '5s') FROM SELECT id FROM v"
+            );
+            assert_eq!(res.options.ttl, Some(Duration::from_secs(600)));
+            assert_eq!(res.options.max_staleness, Some(Duration::from_secs(5)));
+            assert_eq!(
+                res.display(Dialect::MySQL).to_string(),
+                "CREATE CACHE `foo` WITH (max_staleness = '5s', ttl = '10m') FROM SELECT `id` FROM `v`"
+            );
+        }
+
+        #[test]
+        fn create_cached_query_with_memory_options() {
+            let res = test_parse!(
+                create_cached_query(Dialect::MySQL),
+                b"CREATE CACHE foo WITH (memory_limit = '64MB', eviction_priority = 'high', max_staleness = '1m') FROM SELECT id FROM users"
+            );
+            assert_eq!(res.options.memory_limit, Some(64 * 1024 * 1024));
+            assert_eq!(res.options.eviction_priority, Some(EvictionPriority::High));
+            assert_eq!(res.options.max_staleness, Some(Duration::from_secs(60)));
+            assert_eq!(
+                res.display(Dialect::MySQL).to_string(),
+                "CREATE CACHE `foo` WITH (max_staleness = '1m', memory_limit = '64MB', eviction_priority = 'high') FROM SELECT `id` FROM `users`"
+            );
+        }
+
+        #[test]
+        fn create_cached_query_with_duplicate_option() {
+            assert!(create_cached_query(Dialect::MySQL)(LocatedSpan::new(
+                b"CREATE CACHE foo WITH (memory_limit = '1GB', memory_limit = '2GB') FROM SELECT id FROM users"
+                    .as_slice()
+            ))
+            .is_err());
+        }
+
+        #[test]
+        fn display_bytes() {
+            for (bytes, expected) in [
+                (1000, "1000B"),
+                (2048, "2KB"),
+                (5 << 20, "5MB"),
+                (3 << 30, "3GB"),
+                (0, "0B"),
+            ] {
+                let displayed = DisplayBytes(bytes).to_string();
+                assert_eq!(displayed, expected);
+                assert_eq!(parse_bytes(&displayed), Some(bytes));
+            }
+        }
+
+        #[test]
+        fn create_cached_query_with_invalid_max_staleness() {
+            assert!(create_cached_query(Dialect::MySQL)(LocatedSpan::new(
+                b"CREATE CACHE foo WITH (max_staleness = '5 fortnights') FROM SELECT id FROM users"
+                    .as_slice()
+            ))
+            .is_err());
+        }
+
+        #[test]
+        fn display_durations() {
+            for (duration, expected) in [
+                (Duration::from_millis(1500), "1500ms"),
+                (Duration::from_secs(5), "5s"),
+                (Duration::from_secs(120), "2m"),
+                (Duration::from_secs(7200), "2h"),
+                (Duration::ZERO, "0ms"),
+            ] {
+                let displayed = DisplayDuration(duration).to_string();
+                assert_eq!(displayed, expected);
+                assert_eq!(parse_duration(&displayed), Some(duration));
+            }
+        }
+
         #[test]
         fn display_create_query_cache() {
             let stmt = test_parse!(
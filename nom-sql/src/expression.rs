@@ -21,12 +21,14 @@ use test_strategy::Arbitrary;
 
 use crate::common::{column_identifier_no_alias, function_expr, ws_sep_comma, TimestampField};
 use crate::literal::{literal, Double, Float};
+use crate::order::OrderType;
 use crate::select::nested_selection;
 use crate::set::{variable_scope_prefix, Variable};
-use crate::sql_type::{mysql_int_cast_targets, type_identifier};
+use crate::sql_type::{interval_fields, interval_unit, mysql_int_cast_targets, type_identifier};
 use crate::whitespace::{whitespace0, whitespace1};
 use crate::{
-    Column, Dialect, DialectDisplay, Literal, NomSqlResult, SelectStatement, SqlIdentifier, SqlType,
+    Column, Dialect, DialectDisplay, IntervalUnit, Literal, NomSqlResult, SelectStatement,
+    SqlIdentifier, SqlType,
 };
 
 /// Function call expressions
@@ -61,6 +63,28 @@ pub enum FunctionExpr {
         separator: Option<String>,
     },
 
+    /// `STDDEV_POP`/`STDDEV_SAMP` aggregation. The boolean argument is `true` for the sample
+    /// standard deviation
+    Stddev { expr: Box<Expr>, sample: bool },
+
+    /// `VAR_POP`/`VAR_SAMP` aggregation. The boolean argument is `true` for the sample variance
+    Variance { expr: Box<Expr>, sample: bool },
+
+    /// `BOOL_AND` aggregation
+    BoolAnd(Box<Expr>),
+
+    /// `BOOL_OR` aggregation
+    BoolOr(Box<Expr>),
+
+    /// `BIT_AND` aggregation
+    BitAnd(Box<Expr>),
+
+    /// `BIT_OR` aggregation
+    BitOr(Box<Expr>),
+
+    /// `BIT_XOR` aggregation
+    BitXor(Box<Expr>),
+
     /// The SQL `SUBSTRING`/`SUBSTR` function.
     ///
     /// The supported syntax is one of:
@@ -81,6 +105,19 @@ pub enum FunctionExpr {
         name: SqlIdentifier,
         arguments: Vec<Expr>,
     },
+
+    /// A window function call, of the form:
+    ///
+    /// `<function> OVER ([PARTITION BY <expr>, ...] [ORDER BY <expr> [ASC|DESC], ...])`
+    ///
+    /// where `function` is either an aggregate function or one of the ranking functions
+    /// (`ROW_NUMBER()`, `RANK()`, `DENSE_RANK()`)
+    #[weight(0)]
+    Window {
+        function: Box<FunctionExpr>,
+        partition_by: Vec<Expr>,
+        order_by: Vec<(Expr, OrderType)>,
+    },
 }
 
 impl FunctionExpr {
@@ -95,6 +132,13 @@ impl FunctionExpr {
             | FunctionExpr::Max(arg)
             | FunctionExpr::Min(arg)
             | FunctionExpr::GroupConcat { expr: arg, .. }
+            | FunctionExpr::Stddev { expr: arg, .. }
+            | FunctionExpr::Variance { expr: arg, .. }
+            | FunctionExpr::BoolAnd(arg)
+            | FunctionExpr::BoolOr(arg)
+            | FunctionExpr::BitAnd(arg)
+            | FunctionExpr::BitOr(arg)
+            | FunctionExpr::BitXor(arg)
             | FunctionExpr::Extract { expr: arg, .. } => {
                 concrete_iter!(iter::once(arg.as_ref()))
             }
@@ -105,6 +149,18 @@ impl FunctionExpr {
                     .chain(pos.iter().map(|p| p.as_ref()))
                     .chain(len.iter().map(|p| p.as_ref())))
             }
+            FunctionExpr::Window {
+                function,
+                partition_by,
+                order_by,
+            } => {
+                // Boxed, since otherwise the return type of this function would be recursive
+                let function_args: Box<dyn Iterator<Item = &'a Expr> + 'a> =
+                    Box::new(function.arguments());
+                concrete_iter!(function_args
+                    .chain(partition_by)
+                    .chain(order_by.iter().map(|(expr, _)| expr)))
+            }
         }
     }
 }
@@ -141,6 +197,23 @@ impl DialectDisplay for FunctionExpr {
                 }
                 write!(f, ")")
             }
+            FunctionExpr::Stddev { expr, sample } => write!(
+                f,
+                "stddev_{}({})",
+                if *sample { "samp" } else { "pop" },
+                expr.display(dialect)
+            ),
+            FunctionExpr::Variance { expr, sample } => write!(
+                f,
+                "var_{}({})",
+                if *sample { "samp" } else { "pop" },
+                expr.display(dialect)
+            ),
+            FunctionExpr::BoolAnd(expr) => write!(f, "bool_and({})", expr.display(dialect)),
+            FunctionExpr::BoolOr(expr) => write!(f, "bool_or({})", expr.display(dialect)),
+            FunctionExpr::BitAnd(expr) => write!(f, "bit_and({})", expr.display(dialect)),
+            FunctionExpr::BitOr(expr) => write!(f, "bit_or({})", expr.display(dialect)),
+            FunctionExpr::BitXor(expr) => write!(f, "bit_xor({})", expr.display(dialect)),
             FunctionExpr::Call { name, arguments } => {
                 write!(
                     f,
@@ -165,6 +238,34 @@ impl DialectDisplay for FunctionExpr {
             FunctionExpr::Extract { field, expr } => {
                 write!(f, "EXTRACT({field} FROM {})", expr.display(dialect))
             }
+            FunctionExpr::Window {
+                function,
+                partition_by,
+                order_by,
+            } => {
+                write!(f, "{} OVER (", function.display(dialect))?;
+                if !partition_by.is_empty() {
+                    write!(
+                        f,
+                        "PARTITION BY {}",
+                        partition_by.iter().map(|e| e.display(dialect)).join(", ")
+                    )?;
+                    if !order_by.is_empty() {
+                        write!(f, " ")?;
+                    }
+                }
+                if !order_by.is_empty() {
+                    write!(
+                        f,
+                        "ORDER BY {}",
+                        order_by
+                            .iter()
+                            .map(|(e, ot)| format!("{} {ot}", e.display(dialect)))
+                            .join(", ")
+                    )?;
+                }
+                write!(f, ")")
+            }
         })
     }
 }
@@ -191,6 +292,14 @@ pub enum BinaryOperator {
     ILike,
     /// `NOT ILIKE`
     NotILike,
+    /// MySQL `REGEXP` or `RLIKE`
+    Regexp,
+    /// MySQL `NOT REGEXP` or `NOT RLIKE`
+    NotRegexp,
+    /// `SIMILAR TO`
+    SimilarTo,
+    /// `NOT SIMILAR TO`
+    NotSimilarTo,
     /// `=`
     Equal,
     /// `!=` or `<>`
@@ -222,6 +331,8 @@ pub enum BinaryOperator {
     Multiply,
     /// `/`
     Divide,
+    /// `%`
+    Modulo,
 
     /// `?`
     ///
@@ -284,6 +395,28 @@ pub enum BinaryOperator {
     /// Postgres-specific JSONB operator. Behaves like [`BinaryOperator::AtArrowRight`] with
     /// switched sides for the operands.
     AtArrowLeft,
+
+    /// `~`
+    ///
+    /// Postgres-specific operator which matches a string against a POSIX regular expression,
+    /// case-sensitively.
+    Tilde,
+
+    /// `~*`
+    ///
+    /// Postgres-specific operator which matches a string against a POSIX regular expression,
+    /// case-insensitively.
+    TildeAsterisk,
+
+    /// `!~`
+    ///
+    /// Negation of [`BinaryOperator::Tilde`].
+    ExclamationTilde,
+
+    /// `!~*`
+    ///
+    /// Negation of [`BinaryOperator::TildeAsterisk`].
+    ExclamationTildeAsterisk,
 }
 
 impl BinaryOperator {
@@ -316,6 +449,10 @@ impl Display for BinaryOperator {
             Self::NotLike => "NOT LIKE",
             Self::ILike => "ILIKE",
             Self::NotILike => "NOT ILIKE",
+            Self::Regexp => "REGEXP",
+            Self::NotRegexp => "NOT REGEXP",
+            Self::SimilarTo => "SIMILAR TO",
+            Self::NotSimilarTo => "NOT SIMILAR TO",
             Self::Equal => "=",
             Self::NotEqual => "!=",
             Self::Greater => ">",
@@ -329,6 +466,7 @@ impl Display for BinaryOperator {
             Self::HashSubtract => "#-",
             Self::Multiply => "*",
             Self::Divide => "/",
+            Self::Modulo => "%",
             Self::QuestionMark => "?",
             Self::QuestionMarkPipe => "?|",
             Self::QuestionMarkAnd => "?&",
@@ -339,6 +477,10 @@ impl Display for BinaryOperator {
             Self::HashArrow2 => "#>>",
             Self::AtArrowRight => "@>",
             Self::AtArrowLeft => "<@",
+            Self::Tilde => "~",
+            Self::TildeAsterisk => "~*",
+            Self::ExclamationTilde => "!~",
+            Self::ExclamationTildeAsterisk => "!~*",
         };
         f.write_str(op)
     }
@@ -497,6 +639,16 @@ pub enum Expr {
         postgres_style: bool,
     },
 
+    /// MySQL's `INTERVAL expression unit` temporal interval, as used in date arithmetic and
+    /// `DATE_ADD`/`DATE_SUB`.
+    ///
+    /// PostgreSQL's `INTERVAL 'string'` typed literal is instead parsed as a [`Expr::Cast`] to
+    /// [`SqlType::Interval`].
+    Interval {
+        value: Box<Expr>,
+        unit: IntervalUnit,
+    },
+
     /// `ARRAY[expr1, expr2, ...]`
     Array(Vec<Expr>),
 
@@ -597,6 +749,9 @@ impl DialectDisplay for Expr {
                 expr.display(dialect),
                 ty.display(dialect)
             ),
+            Expr::Interval { value, unit } => {
+                write!(f, "INTERVAL {} {unit}", value.display(dialect))
+            }
             Expr::Array(exprs) => {
                 fn write_value(
                     expr: &Expr,
@@ -706,6 +861,15 @@ impl Arbitrary for Expr {
                     (box_expr.clone(), any::<Option<String>>()).prop_map(|(expr, separator)| {
                         FunctionExpr::GroupConcat { expr, separator }
                     }),
+                    (box_expr.clone(), any::<bool>())
+                        .prop_map(|(expr, sample)| FunctionExpr::Stddev { expr, sample }),
+                    (box_expr.clone(), any::<bool>())
+                        .prop_map(|(expr, sample)| FunctionExpr::Variance { expr, sample }),
+                    box_expr.clone().prop_map(FunctionExpr::BoolAnd),
+                    box_expr.clone().prop_map(FunctionExpr::BoolOr),
+                    box_expr.clone().prop_map(FunctionExpr::BitAnd),
+                    box_expr.clone().prop_map(FunctionExpr::BitOr),
+                    box_expr.clone().prop_map(FunctionExpr::BitXor),
                     (
                         box_expr.clone(),
                         option::of(box_expr.clone()),
@@ -762,7 +926,7 @@ impl Arbitrary for Expr {
                     any::<bool>(),
                 )
                     .prop_map(|(lhs, rhs, negated)| Expr::In { lhs, rhs, negated }),
-                (box_expr, any::<SqlType>(), any::<bool>()).prop_map(
+                (box_expr.clone(), any::<SqlType>(), any::<bool>()).prop_map(
                     |(expr, ty, postgres_style)| {
                         Expr::Cast {
                             expr,
@@ -771,6 +935,8 @@ impl Arbitrary for Expr {
                         }
                     }
                 ),
+                (box_expr, any::<IntervalUnit>())
+                    .prop_map(|(value, unit)| Expr::Interval { value, unit }),
                 proptest::collection::vec(element, 0..24).prop_map(Expr::Array),
                 // TODO: once we have Arbitrary for SelectStatement
                 // any::<Box<SelectStatement>>().prop_map(Expr::NestedSelect),
@@ -856,6 +1022,39 @@ fn binary_operator_no_and_or(i: LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], Binar
 
             Ok((i, BinaryOperator::NotLike))
         },
+        map(
+            terminated(
+                alt((tag_no_case("regexp"), tag_no_case("rlike"))),
+                whitespace1,
+            ),
+            |_| BinaryOperator::Regexp,
+        ),
+        move |i| {
+            let (i, _) = tag_no_case("not")(i)?;
+            let (i, _) = whitespace1(i)?;
+            let (i, _) = alt((tag_no_case("regexp"), tag_no_case("rlike")))(i)?;
+            let (i, _) = whitespace1(i)?;
+
+            Ok((i, BinaryOperator::NotRegexp))
+        },
+        move |i| {
+            let (i, _) = tag_no_case("similar")(i)?;
+            let (i, _) = whitespace1(i)?;
+            let (i, _) = tag_no_case("to")(i)?;
+            let (i, _) = whitespace1(i)?;
+
+            Ok((i, BinaryOperator::SimilarTo))
+        },
+        move |i| {
+            let (i, _) = tag_no_case("not")(i)?;
+            let (i, _) = whitespace1(i)?;
+            let (i, _) = tag_no_case("similar")(i)?;
+            let (i, _) = whitespace1(i)?;
+            let (i, _) = tag_no_case("to")(i)?;
+            let (i, _) = whitespace1(i)?;
+
+            Ok((i, BinaryOperator::NotSimilarTo))
+        },
         move |i| {
             let (i, _) = tag_no_case("is")(i)?;
             let (i, _) = whitespace1(i)?;
@@ -893,6 +1092,13 @@ fn binary_operator_no_and_or(i: LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], Binar
             map(tag("#>"), |_| BinaryOperator::HashArrow1),
         )),
         map(tag("#-"), |_| BinaryOperator::HashSubtract),
+        map(char('%'), |_| BinaryOperator::Modulo),
+        alt((
+            map(tag("!~*"), |_| BinaryOperator::ExclamationTildeAsterisk),
+            map(tag("!~"), |_| BinaryOperator::ExclamationTilde),
+            map(tag("~*"), |_| BinaryOperator::TildeAsterisk),
+            map(char('~'), |_| BinaryOperator::Tilde),
+        )),
     ))(i)
 }
 
@@ -1106,6 +1312,7 @@ where
             Prefix(Neg) => Affix::Prefix(Precedence(14)),
             Infix(Multiply) => Affix::Infix(Precedence(12), Associativity::Right),
             Infix(Divide) => Affix::Infix(Precedence(12), Associativity::Right),
+            Infix(Modulo) => Affix::Infix(Precedence(12), Associativity::Right),
             Infix(Add) => Affix::Infix(Precedence(11), Associativity::Right),
             Infix(Subtract) => Affix::Infix(Precedence(11), Associativity::Right),
             // All JSON operators have the same precedence.
@@ -1124,11 +1331,21 @@ where
             Infix(AtArrowRight) => Affix::Infix(Precedence(8), Associativity::Left),
             Infix(AtArrowLeft) => Affix::Infix(Precedence(8), Associativity::Left),
             Infix(HashSubtract) => Affix::Infix(Precedence(8), Associativity::Left),
+            // Postgres gives regular expression match operators the same precedence as all other
+            // non-builtin operators (such as the JSON operators above)
+            Infix(Tilde) => Affix::Infix(Precedence(8), Associativity::Left),
+            Infix(TildeAsterisk) => Affix::Infix(Precedence(8), Associativity::Left),
+            Infix(ExclamationTilde) => Affix::Infix(Precedence(8), Associativity::Left),
+            Infix(ExclamationTildeAsterisk) => Affix::Infix(Precedence(8), Associativity::Left),
 
             Infix(Like) => Affix::Infix(Precedence(7), Associativity::Right),
             Infix(NotLike) => Affix::Infix(Precedence(7), Associativity::Right),
             Infix(ILike) => Affix::Infix(Precedence(7), Associativity::Right),
             Infix(NotILike) => Affix::Infix(Precedence(7), Associativity::Right),
+            Infix(Regexp) => Affix::Infix(Precedence(7), Associativity::Right),
+            Infix(NotRegexp) => Affix::Infix(Precedence(7), Associativity::Right),
+            Infix(SimilarTo) => Affix::Infix(Precedence(7), Associativity::Right),
+            Infix(NotSimilarTo) => Affix::Infix(Precedence(7), Associativity::Right),
             Infix(Equal) => Affix::Infix(Precedence(7), Associativity::Right),
             Infix(NotEqual) => Affix::Infix(Precedence(7), Associativity::Right),
             Infix(Greater) => Affix::Infix(Precedence(7), Associativity::Right),
@@ -1414,6 +1631,51 @@ fn cast(dialect: Dialect) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[u8],
     }
 }
 
+/// Parse a temporal interval.
+///
+/// In MySQL, this is `INTERVAL expr unit`, while in PostgreSQL this is the typed string literal
+/// `INTERVAL 'string' [fields]`, which is equivalent to a cast of the string to the `INTERVAL`
+/// type.
+fn interval_expr(dialect: Dialect) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], Expr> {
+    move |i| {
+        let (i, _) = tag_no_case("interval")(i)?;
+
+        match dialect {
+            Dialect::MySQL => {
+                let (i, _) = alt((whitespace1, peek(tag("(")), peek(tag("'"))))(i)?;
+                let (i, value) = expression(dialect)(i)?;
+                let (i, _) = whitespace1(i)?;
+                let (i, unit) = interval_unit(i)?;
+
+                Ok((
+                    i,
+                    Expr::Interval {
+                        value: Box::new(value),
+                        unit,
+                    },
+                ))
+            }
+            Dialect::PostgreSQL => {
+                let (i, _) = whitespace0(i)?;
+                let (i, value) = dialect.utf8_string_literal()(i)?;
+                let (i, fields) = opt(preceded(whitespace1, interval_fields))(i)?;
+
+                Ok((
+                    i,
+                    Expr::Cast {
+                        expr: Box::new(Expr::Literal(Literal::String(value))),
+                        ty: SqlType::Interval {
+                            fields,
+                            precision: None,
+                        },
+                        postgres_style: false,
+                    },
+                ))
+            }
+        }
+    }
+}
+
 fn nested_select(dialect: Dialect) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], Expr> {
     move |i| {
         let (i, _) = char('(')(i)?;
@@ -1545,6 +1807,7 @@ pub(crate) fn simple_expr(
             between_expr(dialect),
             row_expr_explicit(dialect),
             row_expr_implicit(dialect),
+            interval_expr(dialect),
             map(function_expr(dialect), Expr::Call),
             map(literal(dialect), Expr::Literal),
             case_when_expr(dialect),
@@ -1830,6 +2093,17 @@ mod tests {
             );
         }
 
+        #[test]
+        fn modulo_expression() {
+            let cond = "x % 4";
+
+            let res = expression(Dialect::MySQL)(LocatedSpan::new(cond.as_bytes()));
+            assert_eq!(
+                res.unwrap().1,
+                x_operator_value(BinaryOperator::Modulo, 4.into())
+            );
+        }
+
         #[test]
         fn expression_with_arithmetics() {
             let cond = "x * 3 = 21";
@@ -2367,6 +2641,100 @@ mod tests {
     mod mysql {
         use super::*;
 
+        #[test]
+        fn interval() {
+            let res = test_parse!(
+                expression(Dialect::MySQL),
+                b"created_at >= NOW() - INTERVAL 30 DAY"
+            );
+            assert_eq!(
+                res,
+                Expr::BinaryOp {
+                    lhs: Box::new(Expr::Column("created_at".into())),
+                    op: BinaryOperator::GreaterOrEqual,
+                    rhs: Box::new(Expr::BinaryOp {
+                        lhs: Box::new(Expr::Call(FunctionExpr::Call {
+                            name: "NOW".into(),
+                            arguments: vec![],
+                        })),
+                        op: BinaryOperator::Subtract,
+                        rhs: Box::new(Expr::Interval {
+                            value: Box::new(Expr::Literal(30.into())),
+                            unit: IntervalUnit::Day,
+                        }),
+                    }),
+                }
+            );
+        }
+
+        #[test]
+        fn regexp() {
+            for (qs, op) in [
+                ("name REGEXP '^a.*'", BinaryOperator::Regexp),
+                ("name rlike '^a.*'", BinaryOperator::Regexp),
+                ("name NOT REGEXP '^a.*'", BinaryOperator::NotRegexp),
+                ("name not rlike '^a.*'", BinaryOperator::NotRegexp),
+            ] {
+                let res = test_parse!(expression(Dialect::MySQL), qs.as_bytes());
+                assert_eq!(
+                    res,
+                    Expr::BinaryOp {
+                        lhs: Box::new(Expr::Column("name".into())),
+                        op,
+                        rhs: Box::new(Expr::Literal("^a.*".into())),
+                    }
+                );
+            }
+        }
+
+        #[test]
+        fn regexp_precedence() {
+            let res = test_parse!(
+                expression(Dialect::MySQL),
+                b"a REGEXP 'x' AND b NOT RLIKE 'y'"
+            );
+            assert_eq!(
+                res.display(Dialect::MySQL).to_string(),
+                "((`a` REGEXP 'x') AND (`b` NOT REGEXP 'y'))"
+            );
+        }
+
+        #[test]
+        fn interval_compound_unit() {
+            let res = test_parse!(expression(Dialect::MySQL), b"interval '1:30' hour_minute");
+            assert_eq!(
+                res,
+                Expr::Interval {
+                    value: Box::new(Expr::Literal("1:30".into())),
+                    unit: IntervalUnit::HourMinute,
+                }
+            );
+            assert_eq!(
+                res.display(Dialect::MySQL).to_string(),
+                "INTERVAL '1:30' HOUR_MINUTE"
+            );
+        }
+
+        #[test]
+        fn interval_expression_value() {
+            let res = test_parse!(expression(Dialect::MySQL), b"INTERVAL x + 1 WEEK");
+            assert_eq!(
+                res,
+                Expr::Interval {
+                    value: Box::new(Expr::BinaryOp {
+                        lhs: Box::new(Expr::Column("x".into())),
+                        op: BinaryOperator::Add,
+                        rhs: Box::new(Expr::Literal(1.into())),
+                    }),
+                    unit: IntervalUnit::Week,
+                }
+            );
+            assert_eq!(
+                res.display(Dialect::MySQL).to_string(),
+                "INTERVAL (`x` + 1) WEEK"
+            );
+        }
+
         #[test]
         fn column_beginning_with_null() {
             let res = test_parse!(expression(Dialect::MySQL), b"nullable");
@@ -2648,6 +3016,76 @@ mod tests {
     mod postgres {
         use super::*;
 
+        #[test]
+        fn interval_literal() {
+            let res = test_parse!(
+                expression(Dialect::PostgreSQL),
+                b"created_at + INTERVAL '1 day'"
+            );
+            assert_eq!(
+                res,
+                Expr::BinaryOp {
+                    lhs: Box::new(Expr::Column("created_at".into())),
+                    op: BinaryOperator::Add,
+                    rhs: Box::new(Expr::Cast {
+                        expr: Box::new(Expr::Literal("1 day".into())),
+                        ty: SqlType::Interval {
+                            fields: None,
+                            precision: None,
+                        },
+                        postgres_style: false,
+                    }),
+                }
+            );
+        }
+
+        #[test]
+        fn regex_match_operators() {
+            for (qs, op) in [
+                ("name ~ 'a+'", BinaryOperator::Tilde),
+                ("name ~* 'a+'", BinaryOperator::TildeAsterisk),
+                ("name !~ 'a+'", BinaryOperator::ExclamationTilde),
+                ("name !~* 'a+'", BinaryOperator::ExclamationTildeAsterisk),
+                ("name SIMILAR TO 'a+'", BinaryOperator::SimilarTo),
+                ("name not similar to 'a+'", BinaryOperator::NotSimilarTo),
+            ] {
+                let res = test_parse!(expression(Dialect::PostgreSQL), qs.as_bytes());
+                assert_eq!(
+                    res,
+                    Expr::BinaryOp {
+                        lhs: Box::new(Expr::Column("name".into())),
+                        op,
+                        rhs: Box::new(Expr::Literal("a+".into())),
+                    }
+                );
+            }
+        }
+
+        #[test]
+        fn regex_match_precedence() {
+            let res = test_parse!(expression(Dialect::PostgreSQL), b"a || b ~ 'x' = true");
+            assert_eq!(
+                res.display(Dialect::PostgreSQL).to_string(),
+                "(((\"a\" || \"b\") ~ 'x') = TRUE)"
+            );
+        }
+
+        #[test]
+        fn interval_literal_with_fields() {
+            let res = test_parse!(expression(Dialect::PostgreSQL), b"interval '3' hour");
+            assert_eq!(
+                res,
+                Expr::Cast {
+                    expr: Box::new(Expr::Literal("3".into())),
+                    ty: SqlType::Interval {
+                        fields: Some(crate::IntervalFields::Hour),
+                        precision: None,
+                    },
+                    postgres_style: false,
+                }
+            );
+        }
+
         #[test]
         fn column_beginning_with_null() {
             let res = test_parse!(expression(Dialect::PostgreSQL), b"nullable");
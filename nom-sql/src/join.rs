@@ -50,8 +50,14 @@ pub enum JoinOperator {
     LeftOuterJoin,
     #[weight(0)]
     RightJoin,
+    #[weight(0)]
+    RightOuterJoin,
     InnerJoin,
     #[weight(0)]
+    FullJoin,
+    #[weight(0)]
+    FullOuterJoin,
+    #[weight(0)]
     CrossJoin,
     #[weight(0)]
     StraightJoin,
@@ -59,7 +65,10 @@ pub enum JoinOperator {
 
 impl JoinOperator {
     pub fn is_inner_join(&self) -> bool {
-        matches!(self, JoinOperator::Join | JoinOperator::InnerJoin)
+        matches!(
+            self,
+            JoinOperator::Join | JoinOperator::InnerJoin | JoinOperator::CrossJoin
+        )
     }
 }
 
@@ -70,7 +79,10 @@ impl fmt::Display for JoinOperator {
             JoinOperator::LeftJoin => write!(f, "LEFT JOIN")?,
             JoinOperator::LeftOuterJoin => write!(f, "LEFT OUTER JOIN")?,
             JoinOperator::RightJoin => write!(f, "RIGHT JOIN")?,
+            JoinOperator::RightOuterJoin => write!(f, "RIGHT OUTER JOIN")?,
             JoinOperator::InnerJoin => write!(f, "INNER JOIN")?,
+            JoinOperator::FullJoin => write!(f, "FULL JOIN")?,
+            JoinOperator::FullOuterJoin => write!(f, "FULL OUTER JOIN")?,
             JoinOperator::CrossJoin => write!(f, "CROSS JOIN")?,
             JoinOperator::StraightJoin => write!(f, "STRAIGHT JOIN")?,
         }
@@ -108,7 +120,14 @@ pub fn join_operator(i: LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], JoinOperator>
             JoinOperator::LeftOuterJoin
         }),
         map(tag_no_case("right join"), |_| JoinOperator::RightJoin),
+        map(tag_no_case("right outer join"), |_| {
+            JoinOperator::RightOuterJoin
+        }),
         map(tag_no_case("inner join"), |_| JoinOperator::InnerJoin),
+        map(tag_no_case("full join"), |_| JoinOperator::FullJoin),
+        map(tag_no_case("full outer join"), |_| {
+            JoinOperator::FullOuterJoin
+        }),
         map(tag_no_case("cross join"), |_| JoinOperator::CrossJoin),
         map(tag_no_case("straight_join"), |_| JoinOperator::StraightJoin),
     ))(i)
@@ -121,6 +140,20 @@ mod tests {
     use crate::select::{selection, JoinClause, SelectStatement};
     use crate::{BinaryOperator, Dialect, Relation};
 
+    #[test]
+    fn outer_join_operators() {
+        for (qstring, operator) in [
+            ("RIGHT JOIN", JoinOperator::RightJoin),
+            ("RIGHT OUTER JOIN", JoinOperator::RightOuterJoin),
+            ("FULL JOIN", JoinOperator::FullJoin),
+            ("FULL OUTER JOIN", JoinOperator::FullOuterJoin),
+        ] {
+            let res = join_operator(LocatedSpan::new(qstring.as_bytes()));
+            assert_eq!(res.unwrap().1, operator);
+            assert_eq!(operator.to_string(), qstring);
+        }
+    }
+
     mod mysql {
         use super::*;
 
@@ -162,6 +162,7 @@ fn keyword_p_to_t(i: LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], &[u8]> {
             terminated(tag_no_case("RENAME"), keyword_follow_char),
             terminated(tag_no_case("RESTRICT"), keyword_follow_char),
             terminated(tag_no_case("RIGHT"), keyword_follow_char),
+            terminated(tag_no_case("RLIKE"), keyword_follow_char),
             terminated(tag_no_case("ROLLBACK"), keyword_follow_char),
             terminated(tag_no_case("ROW"), keyword_follow_char),
             terminated(tag_no_case("SAVEPOINT"), keyword_follow_char),
@@ -170,7 +171,6 @@ fn keyword_p_to_t(i: LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], &[u8]> {
             terminated(tag_no_case("TABLE"), keyword_follow_char),
             terminated(tag_no_case("TEMP"), keyword_follow_char),
             terminated(tag_no_case("TEMPORARY"), keyword_follow_char),
-            terminated(tag_no_case("THEN"), keyword_follow_char),
         )),
         |i| *i,
     )(i)
@@ -179,6 +179,7 @@ fn keyword_p_to_t(i: LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], &[u8]> {
 fn keyword_t_to_z(i: LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], &[u8]> {
     map(
         alt((
+            terminated(tag_no_case("THEN"), keyword_follow_char),
             terminated(tag_no_case("TO"), keyword_follow_char),
             terminated(tag_no_case("TRANSACTION"), keyword_follow_char),
             terminated(tag_no_case("TRIGGER"), keyword_follow_char),
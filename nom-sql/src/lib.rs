@@ -13,15 +13,17 @@ use nom::{AsBytes, Err, HexDisplay, IResult};
 use nom_locate::LocatedSpan;
 
 pub use self::alter::{
-    AlterColumnOperation, AlterTableDefinition, AlterTableStatement, ReplicaIdentity,
+    AlterCacheDefinition, AlterCacheStatement, AlterColumnOperation, AlterTableDefinition,
+    AlterTableStatement, ReplicaIdentity,
 };
 pub use self::column::{Column, ColumnConstraint, ColumnSpecification};
 pub use self::comment::CommentStatement;
 pub use self::common::{FieldDefinitionExpr, FieldReference, IndexType, TableKey, TimestampField};
 pub use self::compound_select::{CompoundSelectOperator, CompoundSelectStatement};
+pub use self::copy::{CopyFormat, CopySource, CopyStatement};
 pub use self::create::{
-    CacheInner, CreateCacheStatement, CreateTableBody, CreateTableStatement, CreateViewStatement,
-    SelectSpecification,
+    CacheInner, CacheOptions, CreateCacheStatement, CreateTableBody, CreateTableStatement,
+    CreateViewStatement, EvictionPriority, SelectSpecification,
 };
 pub use self::create_table_options::CreateTableOption;
 pub use self::deallocate::{DeallocateStatement, StatementIdentifier};
@@ -53,7 +55,9 @@ pub use self::set::{
 };
 pub use self::show::ShowStatement;
 pub use self::sql_identifier::SqlIdentifier;
-pub use self::sql_type::{EnumVariants, SqlType, SqlTypeArbitraryOptions};
+pub use self::sql_type::{
+    EnumVariants, IntervalFields, IntervalUnit, SqlType, SqlTypeArbitraryOptions,
+};
 pub use self::table::{
     replicator_table_list, NonReplicatedRelation, NotReplicatedReason, Relation, TableExpr,
     TableExprInner,
@@ -74,6 +78,7 @@ mod column;
 mod comment;
 mod common;
 mod compound_select;
+mod copy;
 mod create;
 mod create_table_options;
 mod deallocate;
@@ -8,10 +8,13 @@ use readyset_util::redacted::Sensitive;
 use serde::{Deserialize, Serialize};
 use test_strategy::Arbitrary;
 
-use crate::alter::{alter_table_statement, AlterTableStatement};
+use crate::alter::{
+    alter_cache_statement, alter_table_statement, AlterCacheStatement, AlterTableStatement,
+};
 use crate::comment::{comment, CommentStatement};
 use crate::common::statement_terminator;
 use crate::compound_select::{simple_or_compound_selection, CompoundSelectStatement};
+use crate::copy::{copy, CopyStatement};
 use crate::create::{
     create_cached_query, create_database, create_table, key_specification, view_creation,
     CreateCacheStatement, CreateDatabaseStatement, CreateTableStatement, CreateViewStatement,
@@ -50,6 +53,7 @@ pub enum SqlQuery {
     CreateTable(CreateTableStatement),
     CreateView(CreateViewStatement),
     CreateCache(CreateCacheStatement),
+    AlterCache(AlterCacheStatement),
     DropCache(DropCacheStatement),
     DropAllCaches(DropAllCachesStatement),
     DropAllProxiedQueries(DropAllProxiedQueriesStatement),
@@ -72,6 +76,7 @@ pub enum SqlQuery {
     Comment(CommentStatement),
     Deallocate(DeallocateStatement),
     Truncate(TruncateStatement),
+    Copy(CopyStatement),
 }
 
 impl DialectDisplay for SqlQuery {
@@ -82,6 +87,7 @@ impl DialectDisplay for SqlQuery {
             Self::CreateTable(create) => write!(f, "{}", create.display(dialect)),
             Self::CreateView(create) => write!(f, "{}", create.display(dialect)),
             Self::CreateCache(create) => write!(f, "{}", create.display(dialect)),
+            Self::AlterCache(alter) => write!(f, "{}", alter.display(dialect)),
             Self::DropCache(drop) => write!(f, "{}", drop.display(dialect)),
             Self::DropAllCaches(drop) => write!(f, "{}", drop),
             Self::Delete(delete) => write!(f, "{}", delete.display(dialect)),
@@ -103,6 +109,7 @@ impl DialectDisplay for SqlQuery {
             Self::Deallocate(dealloc) => write!(f, "{}", dealloc.display(dialect)),
             Self::Truncate(truncate) => write!(f, "{}", truncate.display(dialect)),
             Self::CreateDatabase(create) => write!(f, "{}", create.display(dialect)),
+            Self::Copy(copy) => write!(f, "{}", copy.display(dialect)),
         })
     }
 }
@@ -140,6 +147,7 @@ impl SqlQuery {
             Self::CreateTable(_) => "CREATE TABLE",
             Self::CreateView(_) => "CREATE VIEW",
             Self::CreateCache(_) => "CREATE CACHE",
+            Self::AlterCache(_) => "ALTER CACHE",
             Self::DropCache(_) => "DROP CACHE",
             Self::DropAllCaches(_) => "DROP ALL CACHES",
             Self::DropAllProxiedQueries(_) => "DROP ALL PROXIED QUERIES",
@@ -160,6 +168,7 @@ impl SqlQuery {
             Self::Comment(_) => "COMMENT",
             Self::Deallocate(_) => "DEALLOCATE",
             Self::Truncate(_) => "TRUNCATE",
+            Self::Copy(_) => "COPY",
         }
     }
 
@@ -173,6 +182,7 @@ impl SqlQuery {
         match self {
             SqlQuery::Explain(_)
             | SqlQuery::CreateCache(_)
+            | SqlQuery::AlterCache(_)
             | SqlQuery::DropCache(_)
             | SqlQuery::DropAllCaches(_)
             | SqlQuery::DropAllProxiedQueries(_) => true,
@@ -180,6 +190,7 @@ impl SqlQuery {
                 ShowStatement::Events | ShowStatement::Tables(_) => false,
                 ShowStatement::CachedQueries(_)
                 | ShowStatement::ProxiedQueries(_)
+                | ShowStatement::CacheRecommendations(_)
                 | ShowStatement::ReadySetStatus
                 | ShowStatement::ReadySetStatusAdapter
                 | ShowStatement::ReadySetMigrationStatus(_)
@@ -206,6 +217,7 @@ impl SqlQuery {
             | SqlQuery::RenameTable(_)
             | SqlQuery::Use(_)
             | SqlQuery::Truncate(_)
+            | SqlQuery::Copy(_)
             | SqlQuery::Comment(_) => false,
         }
     }
@@ -265,7 +277,9 @@ fn sql_query_part2(
             map(truncate(dialect), SqlQuery::Truncate),
             // This does a more expensive clone of `i`, so process it last.
             map(create_cached_query(dialect), SqlQuery::CreateCache),
+            map(alter_cache_statement(dialect), SqlQuery::AlterCache),
             map(comment(dialect), SqlQuery::Comment),
+            map(copy(dialect), SqlQuery::Copy),
         ))(i)
     }
 }
@@ -888,5 +902,15 @@ mod tests {
                 })
             )
         }
+
+        #[test]
+        fn copy_to_stdout() {
+            let res = parse_query(
+                Dialect::PostgreSQL,
+                "COPY (SELECT * FROM t1) TO STDOUT WITH CSV;",
+            );
+            assert!(matches!(res, Ok(SqlQuery::Copy(_))), "{res:?}");
+            assert!(parse_query(Dialect::PostgreSQL, "COPY t1 FROM STDIN").is_err());
+        }
     }
 }
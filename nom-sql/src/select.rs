@@ -302,6 +302,13 @@ impl SelectStatement {
                         | FunctionExpr::Max(_)
                         | FunctionExpr::Min(_)
                         | FunctionExpr::GroupConcat { .. }
+                        | FunctionExpr::Stddev { .. }
+                        | FunctionExpr::Variance { .. }
+                        | FunctionExpr::BoolAnd(_)
+                        | FunctionExpr::BoolOr(_)
+                        | FunctionExpr::BitAnd(_)
+                        | FunctionExpr::BitOr(_)
+                        | FunctionExpr::BitXor(_)
                 ),
                 Expr::NestedSelect(select) => select.contains_aggregate_select(),
                 _ => false,
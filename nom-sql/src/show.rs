@@ -23,6 +23,7 @@ pub enum ShowStatement {
     Tables(Tables),
     CachedQueries(Option<String>),
     ProxiedQueries(ProxiedQueriesOptions),
+    CacheRecommendations(CacheRecommendationsOptions),
     ReadySetStatus,
     ReadySetStatusAdapter,
     ReadySetMigrationStatus(u64),
@@ -56,6 +57,16 @@ impl DialectDisplay for ShowStatement {
                         write!(f, "QUERIES")
                     }
                 }
+                Self::CacheRecommendations(options) => {
+                    write!(f, "CACHE RECOMMENDATIONS")?;
+                    if options.with_create_statements {
+                        write!(f, " WITH CREATE STATEMENTS")?;
+                    }
+                    if let Some(limit) = options.limit {
+                        write!(f, " LIMIT {}", limit)?;
+                    }
+                    Ok(())
+                }
                 Self::ReadySetStatus => write!(f, "READYSET STATUS"),
                 Self::ReadySetStatusAdapter => write!(f, "READYSET STATUS ADAPTER"),
                 Self::ReadySetMigrationStatus(id) => write!(f, "READYSET MIGRATION STATUS {}", id),
@@ -139,6 +150,44 @@ fn proxied_queries(
     }
 }
 
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize, Arbitrary)]
+pub struct CacheRecommendationsOptions {
+    /// Whether to include a ready-to-run `CREATE CACHE` statement for each recommendation
+    pub with_create_statements: bool,
+    pub limit: Option<u64>,
+}
+
+/// Parses SHOW CACHE RECOMMENDATIONS [WITH CREATE STATEMENTS] [LIMIT <n>]
+fn cache_recommendations(
+    dialect: Dialect,
+) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], ShowStatement> {
+    move |i| {
+        let (i, _) = tag_no_case("cache")(i)?;
+        let (i, _) = whitespace1(i)?;
+        let (i, _) = tag_no_case("recommendations")(i)?;
+        let (i, with_create_statements) = map(
+            opt(tuple((
+                whitespace1,
+                tag_no_case("with"),
+                whitespace1,
+                tag_no_case("create"),
+                whitespace1,
+                tag_no_case("statements"),
+            ))),
+            |with_create_statements| with_create_statements.is_some(),
+        )(i)?;
+        let (i, limit) = opt(preceded(whitespace1, limit(dialect)))(i)?;
+
+        Ok((
+            i,
+            ShowStatement::CacheRecommendations(CacheRecommendationsOptions {
+                with_create_statements,
+                limit,
+            }),
+        ))
+    }
+}
+
 /// Parses READYSET STATUS and any READYSET STATUS <COMMAND> statements.
 fn readyset_status() -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], ShowStatement> {
     move |i| {
@@ -177,6 +226,7 @@ pub fn show(dialect: Dialect) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[u
         let (i, _) = whitespace1(i)?;
         let (i, statement) = alt((
             cached_queries(dialect),
+            cache_recommendations(dialect),
             proxied_queries(dialect),
             readyset_migration_status,
             readyset_status(),
@@ -416,6 +466,39 @@ mod tests {
         proxied_queries_test("SHOW\tPROXIED\tSUPPORTED\tQUERIES LIMIT 20", true, Some(20));
     }
 
+    #[test]
+    fn show_cache_recommendations() {
+        let cache_recommendations_test =
+            |qstring: &str, with_create_statements: bool, limit: Option<u64>| {
+                let res = show(Dialect::MySQL)(LocatedSpan::new(qstring.as_bytes()))
+                    .unwrap()
+                    .1;
+                let expected = ShowStatement::CacheRecommendations(CacheRecommendationsOptions {
+                    with_create_statements,
+                    limit,
+                });
+                assert_eq!(res, expected);
+                assert_eq!(
+                    expected.display(Dialect::MySQL).to_string(),
+                    qstring.replace('\t', " ")
+                );
+            };
+
+        cache_recommendations_test("SHOW CACHE RECOMMENDATIONS", false, None);
+        cache_recommendations_test("SHOW\tCACHE\tRECOMMENDATIONS", false, None);
+        cache_recommendations_test("SHOW CACHE RECOMMENDATIONS LIMIT 5", false, Some(5));
+        cache_recommendations_test(
+            "SHOW CACHE RECOMMENDATIONS WITH CREATE STATEMENTS",
+            true,
+            None,
+        );
+        cache_recommendations_test(
+            "SHOW CACHE RECOMMENDATIONS WITH CREATE STATEMENTS LIMIT 10",
+            true,
+            Some(10),
+        );
+    }
+
     #[test]
     fn show_proxied_queries_where() {
         let proxied_queries_where_test =
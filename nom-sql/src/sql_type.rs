@@ -63,6 +63,134 @@ impl fmt::Display for IntervalFields {
     }
 }
 
+/// The unit of a MySQL `INTERVAL expr unit` expression.
+///
+/// See [the MySQL docs](https://dev.mysql.com/doc/refman/8.0/en/expressions.html#temporal-intervals)
+/// for the expected format of the interval expression for each unit.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+    test_strategy::Arbitrary,
+)]
+pub enum IntervalUnit {
+    Microsecond,
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+    SecondMicrosecond,
+    MinuteMicrosecond,
+    MinuteSecond,
+    HourMicrosecond,
+    HourSecond,
+    HourMinute,
+    DayMicrosecond,
+    DaySecond,
+    DayMinute,
+    DayHour,
+    YearMonth,
+}
+
+impl fmt::Display for IntervalUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IntervalUnit::Microsecond => write!(f, "MICROSECOND"),
+            IntervalUnit::Second => write!(f, "SECOND"),
+            IntervalUnit::Minute => write!(f, "MINUTE"),
+            IntervalUnit::Hour => write!(f, "HOUR"),
+            IntervalUnit::Day => write!(f, "DAY"),
+            IntervalUnit::Week => write!(f, "WEEK"),
+            IntervalUnit::Month => write!(f, "MONTH"),
+            IntervalUnit::Quarter => write!(f, "QUARTER"),
+            IntervalUnit::Year => write!(f, "YEAR"),
+            IntervalUnit::SecondMicrosecond => write!(f, "SECOND_MICROSECOND"),
+            IntervalUnit::MinuteMicrosecond => write!(f, "MINUTE_MICROSECOND"),
+            IntervalUnit::MinuteSecond => write!(f, "MINUTE_SECOND"),
+            IntervalUnit::HourMicrosecond => write!(f, "HOUR_MICROSECOND"),
+            IntervalUnit::HourSecond => write!(f, "HOUR_SECOND"),
+            IntervalUnit::HourMinute => write!(f, "HOUR_MINUTE"),
+            IntervalUnit::DayMicrosecond => write!(f, "DAY_MICROSECOND"),
+            IntervalUnit::DaySecond => write!(f, "DAY_SECOND"),
+            IntervalUnit::DayMinute => write!(f, "DAY_MINUTE"),
+            IntervalUnit::DayHour => write!(f, "DAY_HOUR"),
+            IntervalUnit::YearMonth => write!(f, "YEAR_MONTH"),
+        }
+    }
+}
+
+impl IntervalUnit {
+    /// Returns `true` if intervals with this unit only ever contain whole days (or larger
+    /// components).
+    ///
+    /// Adding such an interval to a `DATE` in MySQL yields a `DATE` rather than a `DATETIME`.
+    pub fn is_date_unit(&self) -> bool {
+        matches!(
+            self,
+            IntervalUnit::Day
+                | IntervalUnit::Week
+                | IntervalUnit::Month
+                | IntervalUnit::Quarter
+                | IntervalUnit::Year
+                | IntervalUnit::YearMonth
+        )
+    }
+}
+
+/// Parse the unit of a MySQL `INTERVAL expr unit` expression
+pub(crate) fn interval_unit(i: LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], IntervalUnit> {
+    // The compound units have to come before the units they're prefixed by, and `alt` has an upper
+    // limit on the number of items it supports in tuples, so we have to split the parsing into
+    // separate invocations
+    let compound = alt((
+        value(
+            IntervalUnit::SecondMicrosecond,
+            tag_no_case("SECOND_MICROSECOND"),
+        ),
+        value(
+            IntervalUnit::MinuteMicrosecond,
+            tag_no_case("MINUTE_MICROSECOND"),
+        ),
+        value(IntervalUnit::MinuteSecond, tag_no_case("MINUTE_SECOND")),
+        value(
+            IntervalUnit::HourMicrosecond,
+            tag_no_case("HOUR_MICROSECOND"),
+        ),
+        value(IntervalUnit::HourSecond, tag_no_case("HOUR_SECOND")),
+        value(IntervalUnit::HourMinute, tag_no_case("HOUR_MINUTE")),
+        value(IntervalUnit::DayMicrosecond, tag_no_case("DAY_MICROSECOND")),
+        value(IntervalUnit::DaySecond, tag_no_case("DAY_SECOND")),
+        value(IntervalUnit::DayMinute, tag_no_case("DAY_MINUTE")),
+        value(IntervalUnit::DayHour, tag_no_case("DAY_HOUR")),
+        value(IntervalUnit::YearMonth, tag_no_case("YEAR_MONTH")),
+    ));
+
+    let simple = alt((
+        value(IntervalUnit::Microsecond, tag_no_case("MICROSECOND")),
+        value(IntervalUnit::Second, tag_no_case("SECOND")),
+        value(IntervalUnit::Minute, tag_no_case("MINUTE")),
+        value(IntervalUnit::Hour, tag_no_case("HOUR")),
+        value(IntervalUnit::Day, tag_no_case("DAY")),
+        value(IntervalUnit::Week, tag_no_case("WEEK")),
+        value(IntervalUnit::Month, tag_no_case("MONTH")),
+        value(IntervalUnit::Quarter, tag_no_case("QUARTER")),
+        value(IntervalUnit::Year, tag_no_case("YEAR")),
+    ));
+
+    alt((compound, simple))(i)
+}
+
 #[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum SqlType {
     Bool,
@@ -679,7 +807,7 @@ fn enum_type(dialect: Dialect) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[
     }
 }
 
-fn interval_fields(i: LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], IntervalFields> {
+pub(crate) fn interval_fields(i: LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], IntervalFields> {
     alt((
         value(
             IntervalFields::YearToMonth,
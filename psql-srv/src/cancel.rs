@@ -0,0 +1,248 @@
+//! Support for canceling in-flight queries with the PostgreSQL [query cancellation][0] flow.
+//!
+//! Every connection is assigned a random process ID and secret key, which are sent to the client in
+//! a `BackendKeyData` message once startup completes. To cancel the query running on a
+//! connection, the client opens a new connection and sends a `CancelRequest` message containing
+//! that key data, which we look up in a process-wide registry to signal the connection that owns
+//! it.
+//!
+//! [0]: https://www.postgresql.org/docs/current/protocol-flow.html#PROTOCOL-FLOW-CANCELING-REQUESTS
+
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex, OnceLock};
+
+use futures::future::BoxFuture;
+use rand::Rng;
+use tokio::sync::Notify;
+use tracing::{debug, warn};
+
+use crate::error::Error;
+
+/// Key data identifying a single connection, which a client must provide to cancel the query
+/// running on that connection
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BackendKeyData {
+    pub process_id: i32,
+    pub secret_key: i32,
+}
+
+/// A handle that can be used to cancel the query currently running against an upstream database.
+///
+/// Returned by [`PsqlBackend::upstream_canceler`](crate::PsqlBackend::upstream_canceler) so that
+/// cancellation of a query proxied to the upstream is forwarded to the upstream itself.
+pub trait UpstreamCanceler: Send + Sync {
+    /// Request that the upstream cancel the query currently running on the connection this handle
+    /// was created for
+    fn cancel(&self) -> BoxFuture<'static, Result<(), Error>>;
+}
+
+/// State shared between a connection and any `CancelRequest`s targeting it
+#[derive(Default)]
+struct CancelState {
+    /// Notified when the query running on the connection should be canceled
+    notify: Notify,
+    /// Handle to cancel the query running on the connection's upstream, if a query is currently
+    /// running
+    upstream: Mutex<Option<Arc<dyn UpstreamCanceler>>>,
+}
+
+type Registry = Mutex<HashMap<BackendKeyData, Arc<CancelState>>>;
+
+fn registry() -> &'static Registry {
+    static REGISTRY: OnceLock<Registry> = OnceLock::new();
+    REGISTRY.get_or_init(Default::default)
+}
+
+/// The registration of a connection in the process-wide cancellation registry. The connection is
+/// removed from the registry when this is dropped.
+pub(crate) struct CancelRegistration {
+    key_data: BackendKeyData,
+    state: Arc<CancelState>,
+}
+
+impl CancelRegistration {
+    /// Register a new connection under freshly generated random key data
+    pub(crate) fn new() -> Self {
+        let mut rng = rand::thread_rng();
+        let state = Arc::new(CancelState::default());
+        let mut registry = registry().lock().unwrap_or_else(|e| e.into_inner());
+        loop {
+            let key_data = BackendKeyData {
+                process_id: rng.gen_range(1..=i32::MAX),
+                secret_key: rng.gen(),
+            };
+            if let Entry::Vacant(entry) = registry.entry(key_data) {
+                entry.insert(state.clone());
+                return Self { key_data, state };
+            }
+        }
+    }
+
+    /// The key data a client must send in a `CancelRequest` to cancel queries on this connection
+    pub(crate) fn key_data(&self) -> BackendKeyData {
+        self.key_data
+    }
+
+    /// Run `fut`, returning [`Error::QueryCanceled`] instead if a `CancelRequest` for this
+    /// connection is received before it completes.
+    ///
+    /// `upstream` is used to forward the cancellation to the upstream database while `fut` is
+    /// running.
+    pub(crate) async fn run<T, F>(
+        &self,
+        upstream: Option<Arc<dyn UpstreamCanceler>>,
+        fut: F,
+    ) -> Result<T, Error>
+    where
+        F: Future<Output = Result<T, Error>>,
+    {
+        // Create the `Notified` future before doing anything else, so that a cancellation received
+        // at any point after this is observed
+        let canceled = self.state.notify.notified();
+        *self
+            .state
+            .upstream
+            .lock()
+            .unwrap_or_else(|e| e.into_inner()) = upstream;
+
+        let res = tokio::select! {
+            biased;
+            res = fut => res,
+            _ = canceled => Err(Error::QueryCanceled),
+        };
+
+        self.state
+            .upstream
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .take();
+        res
+    }
+}
+
+impl Drop for CancelRegistration {
+    fn drop(&mut self) {
+        registry()
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&self.key_data);
+    }
+}
+
+/// Cancel the query currently running on the connection identified by `key_data`, if any.
+///
+/// As in PostgreSQL, a request with unknown key data, or for a connection that isn't running a
+/// query, is silently ignored.
+pub(crate) async fn cancel(key_data: BackendKeyData) {
+    let Some(state) = registry()
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(&key_data)
+        .cloned()
+    else {
+        debug!(?key_data, "Ignoring CancelRequest for unknown connection");
+        return;
+    };
+
+    // Cancel the query on the upstream first, so that it stops running the query before we stop
+    // waiting on it
+    let upstream = state
+        .upstream
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone();
+    if let Some(upstream) = upstream {
+        if let Err(error) = upstream.cancel().await {
+            warn!(%error, "Failed to cancel query on upstream database");
+        }
+    }
+
+    state.notify.notify_waiters();
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::time::Duration;
+
+    use futures::FutureExt;
+
+    use super::*;
+
+    struct TestCanceler(Arc<AtomicBool>);
+
+    impl UpstreamCanceler for TestCanceler {
+        fn cancel(&self) -> BoxFuture<'static, Result<(), Error>> {
+            self.0.store(true, Ordering::SeqCst);
+            async { Ok(()) }.boxed()
+        }
+    }
+
+    #[tokio::test]
+    async fn cancel_running_query() {
+        let registration = CancelRegistration::new();
+        let key_data = registration.key_data();
+        let upstream_canceled = Arc::new(AtomicBool::new(false));
+
+        let (res, ()) = tokio::join!(
+            registration.run::<(), _>(
+                Some(Arc::new(TestCanceler(upstream_canceled.clone()))),
+                futures::future::pending()
+            ),
+            async {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                cancel(key_data).await;
+            }
+        );
+
+        assert!(matches!(res, Err(Error::QueryCanceled)));
+        assert!(upstream_canceled.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn cancel_while_idle_does_not_affect_next_query() {
+        let registration = CancelRegistration::new();
+        let upstream_canceled = Arc::new(AtomicBool::new(false));
+        registration
+            .run(
+                Some(Arc::new(TestCanceler(upstream_canceled.clone()))),
+                async { Ok(()) },
+            )
+            .await
+            .unwrap();
+
+        cancel(registration.key_data()).await;
+        assert!(!upstream_canceled.load(Ordering::SeqCst));
+
+        registration.run(None, async { Ok(()) }).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn cancel_wrong_secret_key() {
+        let registration = CancelRegistration::new();
+        let key_data = BackendKeyData {
+            secret_key: registration.key_data().secret_key.wrapping_add(1),
+            ..registration.key_data()
+        };
+
+        registration
+            .run(None, async {
+                cancel(key_data).await;
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                Ok(())
+            })
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    fn deregister_on_drop() {
+        let registration = CancelRegistration::new();
+        let key_data = registration.key_data();
+        assert!(registry().lock().unwrap().contains_key(&key_data));
+        drop(registration);
+        assert!(!registry().lock().unwrap().contains_key(&key_data));
+    }
+}
@@ -8,7 +8,7 @@ use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZon
 use cidr::IpInet;
 use eui48::MacAddress;
 use postgres_types::{FromSql, Kind, Type};
-use readyset_data::{Array, Collation, PassThroughFormat};
+use readyset_data::{Array, Collation, Interval, PassThroughFormat};
 use rust_decimal::prelude::FromStr;
 use rust_decimal::Decimal;
 use tokio_util::codec::Decoder;
@@ -41,6 +41,7 @@ const CLOSE_TYPE_PREPARED_STATEMENT: u8 = b'S';
 const DESCRIBE_TYPE_PORTAL: u8 = b'P';
 const DESCRIBE_TYPE_PREPARED_STATEMENT: u8 = b'S';
 
+const CANCEL_REQUEST_CODE: i32 = 80877102;
 const SSL_REQUEST_CODE: i32 = 80877103;
 
 const STARTUP_MESSAGE_DATABASE_PARAMETER: &str = "database";
@@ -93,6 +94,15 @@ impl Decoder for Codec {
             let ret = match token {
                 SSL_REQUEST_CODE => Ok(Some(SSLRequest)),
 
+                CANCEL_REQUEST_CODE => {
+                    let process_id = get_i32(msg)?;
+                    let secret_key = get_i32(msg)?;
+                    Ok(Some(CancelRequest {
+                        process_id,
+                        secret_key,
+                    }))
+                }
+
                 // Parse StartupMessage
                 protocol_version => {
                     let mut user: Option<BytesStr> = None;
@@ -367,6 +377,7 @@ fn get_binary_value(src: &mut Bytes, t: &Type) -> Result<PsqlValue, Error> {
             Type::TEXT => Ok(PsqlValue::Text(<&str>::from_sql(t, buf)?.into())),
             Type::DATE => Ok(PsqlValue::Date(NaiveDate::from_sql(t, buf)?)),
             Type::TIME => Ok(PsqlValue::Time(NaiveTime::from_sql(t, buf)?)),
+            Type::INTERVAL => Ok(PsqlValue::Interval(Interval::from_sql(t, buf)?)),
             Type::TIMESTAMP => Ok(PsqlValue::Timestamp(NaiveDateTime::from_sql(t, buf)?)),
             Type::TIMESTAMPTZ => Ok(PsqlValue::TimestampTz(DateTime::<FixedOffset>::from_sql(
                 t, buf,
@@ -519,6 +530,10 @@ fn get_text_value(src: &mut Bytes, t: &Type) -> Result<PsqlValue, Error> {
             .map(PsqlValue::Jsonb),
         Type::BIT => get_bitvec_from_str(text_str).map(PsqlValue::Bit),
         Type::VARBIT => get_bitvec_from_str(text_str).map(PsqlValue::VarBit),
+        Type::INTERVAL => text_str
+            .parse::<Interval>()
+            .map_err(|e| DecodeError::InvalidTextIntervalValue(e.to_string()))
+            .map(PsqlValue::Interval),
         ref t if matches!(t.kind(), Kind::Array(_)) => {
             let inner_t = match t.kind() {
                 Kind::Array(inner_t) => inner_t.clone(),
@@ -576,6 +591,23 @@ mod tests {
         codec.decode(&mut buf).unwrap_err();
     }
 
+    #[test]
+    fn test_decode_cancel_request() {
+        let mut codec = Codec::new();
+        let mut buf = BytesMut::new();
+        buf.put_i32(16); // size
+        buf.put_i32(80877102); // cancel request code
+        buf.put_i32(1234); // process id
+        buf.put_i32(5678); // secret key
+        assert_eq!(
+            codec.decode(&mut buf).unwrap(),
+            Some(CancelRequest {
+                process_id: 1234,
+                secret_key: 5678
+            })
+        );
+    }
+
     #[test]
     fn test_decode_startup_message() {
         let mut codec = Codec::new();
@@ -2,6 +2,7 @@ use std::convert::TryFrom;
 
 use bytes::{BufMut, BytesMut};
 use eui48::MacAddressFormat;
+use nom_sql::CopyFormat;
 use postgres::error::ErrorPosition;
 use postgres_types::{ToSql, Type};
 use readyset_util::fmt::FastEncode;
@@ -17,9 +18,13 @@ use crate::scram::{SCRAM_SHA_256_AUTHENTICATION_METHOD, SCRAM_SHA_256_SSL_AUTHEN
 use crate::value::PsqlValue;
 
 const ID_AUTHENTICATION_REQUEST: u8 = b'R';
+const ID_BACKEND_KEY_DATA: u8 = b'K';
 const ID_BIND_COMPLETE: u8 = b'2';
 const ID_CLOSE_COMPLETE: u8 = b'3';
 const ID_COMMAND_COMPLETE: u8 = b'C';
+const ID_COPY_DATA: u8 = b'd';
+const ID_COPY_DONE: u8 = b'c';
+const ID_COPY_OUT_RESPONSE: u8 = b'H';
 const ID_DATA_ROW: u8 = b'D';
 const ID_ERROR_RESPONSE: u8 = b'E';
 const ID_PARAMETER_DESCRIPTION: u8 = b't';
@@ -40,6 +45,7 @@ const COMMAND_COMPLETE_INSERT_TAG: &str = "INSERT";
 const COMMAND_COMPLETE_INSERT_LEGACY_OID: &str = "0";
 const COMMAND_COMPLETE_SELECT_TAG: &str = "SELECT";
 const COMMAND_COMPLETE_UPDATE_TAG: &str = "UPDATE";
+const COMMAND_COMPLETE_COPY_TAG: &str = "COPY";
 const COMMAND_COMPLETE_DEALLOCATE_TAG: &str = "DEALLOCATE";
 const COMMAND_COMPLETE_DEALLOCATE_ALL_TAG: &str = "ALL";
 const COMMAND_COMPLETE_TAG_BUF_LEN: usize = 32;
@@ -70,6 +76,13 @@ const ERROR_RESPONSE_SEVERITY_FATAL: &str = "FATAL";
 const ERROR_RESPONSE_SEVERITY_PANIC: &str = "PANIC";
 const ERROR_RESPONSE_TERMINATOR: u8 = b'\0';
 
+const COPY_OVERALL_FORMAT_TEXT: i8 = 0;
+const COPY_TEXT_DELIMITER: u8 = b'\t';
+const COPY_TEXT_NULL: &[u8] = b"\\N";
+const COPY_CSV_DELIMITER: u8 = b',';
+const COPY_CSV_QUOTE: u8 = b'"';
+const COPY_ROW_TERMINATOR: u8 = b'\n';
+
 const BOOL_FALSE_TEXT_REP: &str = "f";
 const BOOL_TRUE_TEXT_REP: &str = "t";
 const COUNT_PLACEHOLDER: i16 = -1;
@@ -142,6 +155,16 @@ fn encode(message: BackendMessage, dst: &mut BytesMut) -> Result<(), Error> {
             put_i32(AUTHENTICATION_OK_SUCCESS, dst);
         }
 
+        BackendKeyData {
+            process_id,
+            secret_key,
+        } => {
+            put_u8(ID_BACKEND_KEY_DATA, dst);
+            put_i32(LENGTH_PLACEHOLDER, dst);
+            put_i32(process_id, dst);
+            put_i32(secret_key, dst);
+        }
+
         BindComplete => {
             put_u8(ID_BIND_COMPLETE, dst);
             put_i32(LENGTH_PLACEHOLDER, dst);
@@ -168,6 +191,7 @@ fn encode(message: BackendMessage, dst: &mut BytesMut) -> Result<(), Error> {
                 )?,
                 Select(n) => write!(&mut tag_buf[..], "{} {}", COMMAND_COMPLETE_SELECT_TAG, n)?,
                 Update(n) => write!(&mut tag_buf[..], "{} {}", COMMAND_COMPLETE_UPDATE_TAG, n)?,
+                Copy(n) => write!(&mut tag_buf[..], "{} {}", COMMAND_COMPLETE_COPY_TAG, n)?,
                 Deallocate(t) => match t {
                     DeallocationType::All => {
                         write!(
@@ -201,6 +225,33 @@ fn encode(message: BackendMessage, dst: &mut BytesMut) -> Result<(), Error> {
             put_str(tag_str, dst);
         }
 
+        CopyOutResponse { column_formats } => {
+            put_u8(ID_COPY_OUT_RESPONSE, dst);
+            put_i32(LENGTH_PLACEHOLDER, dst);
+            dst.put_i8(COPY_OVERALL_FORMAT_TEXT);
+            put_i16(i16::try_from(column_formats.len())?, dst);
+            for format in column_formats {
+                put_format(format, dst);
+            }
+        }
+
+        CopyData { values, format } => {
+            put_u8(ID_COPY_DATA, dst);
+            put_i32(LENGTH_PLACEHOLDER, dst);
+            put_copy_row(values, format, dst)?;
+        }
+
+        PassThroughCopyData(data) => {
+            put_u8(ID_COPY_DATA, dst);
+            put_i32(LENGTH_PLACEHOLDER, dst);
+            put_slice(&data, dst);
+        }
+
+        CopyDone => {
+            put_u8(ID_COPY_DONE, dst);
+            put_i32(LENGTH_PLACEHOLDER, dst);
+        }
+
         DataRow {
             values,
             explicit_transfer_formats,
@@ -548,6 +599,9 @@ fn put_binary_value(val: PsqlValue, dst: &mut BytesMut) -> Result<(), Error> {
         PsqlValue::Time(v) => {
             v.to_sql(&Type::TIME, dst)?;
         }
+        PsqlValue::Interval(v) => {
+            v.to_sql(&Type::INTERVAL, dst)?;
+        }
         PsqlValue::ByteArray(b) => {
             b.to_sql(&Type::BYTEA, dst)?;
         }
@@ -586,23 +640,38 @@ fn put_binary_value(val: PsqlValue, dst: &mut BytesMut) -> Result<(), Error> {
     Ok(())
 }
 
-fn put_text_value(val: PsqlValue, dst: &mut BytesMut) -> Result<(), Error> {
-    use std::fmt::Write;
+/// Returns true if `val` should be sent as NULL in the text format.
+///
+/// A void type (OID 2278) indicates that the called function returns no value. This is handled as
+/// a special case since we don't support PassThrough values in the Text protocol
+fn is_text_null(val: &PsqlValue) -> bool {
+    *val == PsqlValue::Null || matches!(val, PsqlValue::PassThrough(p) if p.ty.oid() == 2278)
+}
 
-    // A void type (OID 2278) indicates that the called function returns no value. This is handled
-    // as a special case since we don't support PassThrough values in the Text protocol
-    if val == PsqlValue::Null || matches!(val, PsqlValue::PassThrough(ref p) if p.ty.oid() == 2278)
-    {
+fn put_text_value(val: PsqlValue, dst: &mut BytesMut) -> Result<(), Error> {
+    if is_text_null(&val) {
         put_i32(LENGTH_NULL_SENTINEL, dst);
         return Ok(());
     }
 
     let start_ofs = dst.len();
     put_i32(LENGTH_PLACEHOLDER, dst);
+    write_text_value(val, dst)?;
+    // Update the length field to match the recently serialized data length in `dst`. The 4 byte
+    // length field itself is excluded from the length calculation.
+    let value_len = dst.len() - start_ofs - 4;
+    set_i32(i32::try_from(value_len)?, dst, start_ofs)?;
+    Ok(())
+}
+
+/// Write the text representation of the (non-NULL) `val` to `dst`, without a length prefix
+fn write_text_value(val: PsqlValue, dst: &mut BytesMut) -> Result<(), Error> {
+    use std::fmt::Write;
+
     match val {
         #[allow(clippy::unreachable)]
         PsqlValue::Null => {
-            unreachable!("Null is handled as a special case above.");
+            unreachable!("Null is handled as a special case by callers.");
         }
         PsqlValue::Bool(v) => {
             let text = if v {
@@ -657,6 +726,9 @@ fn put_text_value(val: PsqlValue, dst: &mut BytesMut) -> Result<(), Error> {
         PsqlValue::Time(v) => {
             v.put(dst);
         }
+        PsqlValue::Interval(v) => {
+            write!(dst, "{}", v)?;
+        }
         PsqlValue::ByteArray(b) => {
             write!(
                 dst,
@@ -688,10 +760,75 @@ fn put_text_value(val: PsqlValue, dst: &mut BytesMut) -> Result<(), Error> {
             )));
         }
     };
-    // Update the length field to match the recently serialized data length in `dst`. The 4 byte
-    // length field itself is excluded from the length calculation.
-    let value_len = dst.len() - start_ofs - 4;
-    set_i32(i32::try_from(value_len)?, dst, start_ofs)?;
+    Ok(())
+}
+
+/// Write `values` to `dst` as a single line of `COPY` output in the given `format`, as described
+/// in the [PostgreSQL documentation][0].
+///
+/// [0]: https://www.postgresql.org/docs/current/sql-copy.html#id-1.9.3.55.9
+fn put_copy_row(
+    values: Vec<PsqlValue>,
+    format: CopyFormat,
+    dst: &mut BytesMut,
+) -> Result<(), Error> {
+    let mut buf = BytesMut::new();
+    for (i, v) in values.into_iter().enumerate() {
+        if i > 0 {
+            put_u8(
+                match format {
+                    CopyFormat::Text => COPY_TEXT_DELIMITER,
+                    CopyFormat::Csv => COPY_CSV_DELIMITER,
+                },
+                dst,
+            );
+        }
+
+        if is_text_null(&v) {
+            // NULL is written as an unquoted empty string in CSV
+            if format == CopyFormat::Text {
+                put_slice(COPY_TEXT_NULL, dst);
+            }
+            continue;
+        }
+
+        buf.clear();
+        write_text_value(v, &mut buf)?;
+        match format {
+            CopyFormat::Text => {
+                for &byte in buf.iter() {
+                    match byte {
+                        b'\\' => put_slice(b"\\\\", dst),
+                        b'\n' => put_slice(b"\\n", dst),
+                        b'\r' => put_slice(b"\\r", dst),
+                        b'\t' => put_slice(b"\\t", dst),
+                        _ => put_u8(byte, dst),
+                    }
+                }
+            }
+            CopyFormat::Csv => {
+                // Quote values which would otherwise be ambiguous, including the empty string,
+                // which would otherwise be read back as NULL
+                let needs_quotes = buf.is_empty()
+                    || buf
+                        .iter()
+                        .any(|b| matches!(*b, COPY_CSV_DELIMITER | COPY_CSV_QUOTE | b'\n' | b'\r'));
+                if needs_quotes {
+                    put_u8(COPY_CSV_QUOTE, dst);
+                    for &byte in buf.iter() {
+                        if byte == COPY_CSV_QUOTE {
+                            put_u8(COPY_CSV_QUOTE, dst);
+                        }
+                        put_u8(byte, dst);
+                    }
+                    put_u8(COPY_CSV_QUOTE, dst);
+                } else {
+                    put_slice(&buf, dst);
+                }
+            }
+        }
+    }
+    put_u8(COPY_ROW_TERMINATOR, dst);
     Ok(())
 }
 
@@ -700,7 +837,7 @@ mod tests {
     use std::sync::Arc;
 
     use bit_vec::BitVec;
-    use bytes::{BufMut, BytesMut};
+    use bytes::{BufMut, Bytes, BytesMut};
     use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone};
     use eui48::MacAddress;
     use postgres::SimpleQueryRow;
@@ -736,6 +873,27 @@ mod tests {
         assert_eq!(buf, exp);
     }
 
+    #[test]
+    fn test_encode_backend_key_data() {
+        let mut codec = Codec::new();
+        let mut buf = BytesMut::new();
+        codec
+            .encode(
+                BackendKeyData {
+                    process_id: 1234,
+                    secret_key: 5678,
+                },
+                &mut buf,
+            )
+            .unwrap();
+        let mut exp = BytesMut::new();
+        exp.put_u8(b'K'); // message id
+        exp.put_i32(12); // message length
+        exp.put_i32(1234); // process id
+        exp.put_i32(5678); // secret key
+        assert_eq!(buf, exp);
+    }
+
     #[test]
     fn test_encode_authentication_cleartext_password() {
         let mut codec = Codec::new();
@@ -831,6 +989,120 @@ mod tests {
         assert_eq!(buf, exp);
     }
 
+    #[test]
+    fn test_encode_command_complete_copy() {
+        let mut codec = Codec::new();
+        let mut buf = BytesMut::new();
+        codec
+            .encode(CommandComplete { tag: Copy(3) }, &mut buf)
+            .unwrap();
+        let mut exp = BytesMut::new();
+        exp.put_u8(b'C'); // message id
+        exp.put_i32(4 + 7); // message length
+        exp.extend_from_slice(b"COPY 3\0");
+        assert_eq!(buf, exp);
+    }
+
+    #[test]
+    fn test_encode_copy_out_response() {
+        let mut codec = Codec::new();
+        let mut buf = BytesMut::new();
+        codec
+            .encode(
+                CopyOutResponse {
+                    column_formats: vec![Text, Text],
+                },
+                &mut buf,
+            )
+            .unwrap();
+        let mut exp = BytesMut::new();
+        exp.put_u8(b'H'); // message id
+        exp.put_i32(4 + 1 + 2 + 2 * 2); // message length
+        exp.put_i8(0); // overall format
+        exp.put_i16(2); // number of columns
+        exp.put_i16(0); // column 1 format
+        exp.put_i16(0); // column 2 format
+        assert_eq!(buf, exp);
+    }
+
+    #[test]
+    fn test_encode_copy_data_text() {
+        let mut codec = Codec::new();
+        let mut buf = BytesMut::new();
+        codec
+            .encode(
+                CopyData {
+                    values: vec![
+                        PsqlValue::Int(1),
+                        PsqlValue::Null,
+                        PsqlValue::Text("a\tb\\c\nd".into()),
+                        PsqlValue::Text("".into()),
+                    ],
+                    format: CopyFormat::Text,
+                },
+                &mut buf,
+            )
+            .unwrap();
+        let data: &[u8] = b"1\t\\N\ta\\tb\\\\c\\nd\t\n";
+        let mut exp = BytesMut::new();
+        exp.put_u8(b'd'); // message id
+        exp.put_i32(4 + data.len() as i32); // message length
+        exp.extend_from_slice(data);
+        assert_eq!(buf, exp);
+    }
+
+    #[test]
+    fn test_encode_copy_data_csv() {
+        let mut codec = Codec::new();
+        let mut buf = BytesMut::new();
+        codec
+            .encode(
+                CopyData {
+                    values: vec![
+                        PsqlValue::Int(1),
+                        PsqlValue::Null,
+                        PsqlValue::Text("".into()),
+                        PsqlValue::Text("say \"hi\", ok".into()),
+                        PsqlValue::Text("a\\b".into()),
+                    ],
+                    format: CopyFormat::Csv,
+                },
+                &mut buf,
+            )
+            .unwrap();
+        let data: &[u8] = b"1,,\"\",\"say \"\"hi\"\", ok\",a\\b\n";
+        let mut exp = BytesMut::new();
+        exp.put_u8(b'd'); // message id
+        exp.put_i32(4 + data.len() as i32); // message length
+        exp.extend_from_slice(data);
+        assert_eq!(buf, exp);
+    }
+
+    #[test]
+    fn test_encode_passthrough_copy_data() {
+        let mut codec = Codec::new();
+        let mut buf = BytesMut::new();
+        codec
+            .encode(PassThroughCopyData(Bytes::from_static(b"1\tb\n")), &mut buf)
+            .unwrap();
+        let mut exp = BytesMut::new();
+        exp.put_u8(b'd'); // message id
+        exp.put_i32(4 + 4); // message length
+        exp.extend_from_slice(b"1\tb\n");
+        assert_eq!(buf, exp);
+    }
+
+    #[test]
+    fn test_encode_copy_done() {
+        let mut codec = Codec::new();
+        let mut buf = BytesMut::new();
+        codec.encode(CopyDone, &mut buf).unwrap();
+        let mut exp = BytesMut::new();
+        exp.put_u8(b'c'); // message id
+        exp.put_i32(4); // message length
+        assert_eq!(buf, exp);
+    }
+
     #[test]
     fn test_encode_command_complete_deallocate() {
         let mut codec = Codec::new();
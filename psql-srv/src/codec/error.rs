@@ -64,6 +64,9 @@ pub enum DecodeError {
     #[error("invalid array value: {0}")]
     InvalidArrayValue(String),
 
+    #[error("invalid text interval value: {0}")]
+    InvalidTextIntervalValue(String),
+
     #[error("unknown enum variant: {0}")]
     UnknownEnumVariant(String),
 
@@ -43,6 +43,9 @@ pub enum Error {
     #[error("parse error: {0}")]
     ParseError(String),
 
+    #[error("canceling statement due to user request")]
+    QueryCanceled,
+
     #[error("unexpected message: {0}")]
     UnexpectedMessage(String),
 
@@ -82,6 +85,7 @@ impl From<Error> for BackendMessage {
             Error::MissingPortal(_) => SqlState::UNDEFINED_PSTATEMENT,
             Error::MissingPreparedStatement(_) => SqlState::UNDEFINED_PSTATEMENT,
             Error::ParseError(_) => SqlState::INVALID_PSTATEMENT_DEFINITION,
+            Error::QueryCanceled => SqlState::QUERY_CANCELED,
             Error::Unimplemented(_) => SqlState::FEATURE_NOT_SUPPORTED,
             Error::UnexpectedMessage(_) => SqlState::PROTOCOL_VIOLATION,
             Error::Unknown(_) => SqlState::INTERNAL_ERROR,
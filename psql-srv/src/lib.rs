@@ -14,6 +14,7 @@
 //! implementation.
 
 mod bytes;
+mod cancel;
 mod channel;
 mod codec;
 mod error;
@@ -29,7 +30,7 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use futures::Stream;
-use nom_sql::SqlIdentifier;
+use nom_sql::{CopyFormat, SqlIdentifier};
 use postgres::SimpleQueryMessage;
 use postgres_protocol::Oid;
 use postgres_types::Type;
@@ -40,6 +41,7 @@ use tokio_native_tls::TlsAcceptor;
 use tokio_postgres::OwnedField;
 
 pub use crate::bytes::BytesStr;
+pub use crate::cancel::{BackendKeyData, UpstreamCanceler};
 pub use crate::error::Error;
 pub use crate::message::{PsqlSrvRow, TransferFormat};
 pub use crate::value::PsqlValue;
@@ -128,6 +130,15 @@ pub trait PsqlBackend {
 
     /// Loads any extended types from the upstream postgres, returning a map of Oid to typelen
     async fn load_extended_types(&mut self) -> Result<HashMap<Oid, i16>, Error>;
+
+    /// Returns a handle that can be used to cancel a query running against the upstream database,
+    /// if connected to one. When the client cancels a query that is in flight, the cancellation is
+    /// forwarded to the upstream using this handle.
+    ///
+    /// This is called before running each query, and again if a query is canceled before the
+    /// backend was connected to the upstream, so that a backend which connects lazily while
+    /// running the query still has it canceled upstream.
+    fn upstream_canceler(&self) -> Option<Arc<dyn UpstreamCanceler>>;
 }
 
 // TODO: There are several representations of Column/Field, we can probably consolidate them.
@@ -191,6 +202,19 @@ pub enum QueryResponse<R> {
     /// the prepare/execute protocol.
     SimpleQuery(Vec<SimpleQueryMessage>),
     Deallocate(DeallocateId),
+    /// The response to a `COPY ... TO STDOUT` statement, which is sent to the frontend using the
+    /// copy-out sub-protocol.
+    CopyOut {
+        /// The format to write the rows in
+        format: CopyFormat,
+        /// Whether to write a line containing the names of the columns in `schema` before the rows
+        header: bool,
+        /// The schema of the copied rows, which is used to tell the client how many columns are
+        /// being copied
+        schema: Vec<Column>,
+        /// The rows to copy
+        resultset: R,
+    },
 }
 
 /// Run a `Backend` on the provided bytestream until the bytestream is remotely closed.
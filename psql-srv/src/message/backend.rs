@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use bytes::Bytes;
-use nom_sql::SqlIdentifier;
+use nom_sql::{CopyFormat, SqlIdentifier};
 use postgres::error::ErrorPosition;
 pub use postgres::error::SqlState;
 use postgres::{Row, SimpleQueryRow};
@@ -46,12 +46,31 @@ pub enum BackendMessage {
         sasl_data: Bytes,
     },
     AuthenticationOk,
+    BackendKeyData {
+        process_id: i32,
+        secret_key: i32,
+    },
     BindComplete,
     CloseComplete,
     CommandComplete {
         tag: CommandCompleteTag,
     },
     PassThroughCommandComplete(Bytes),
+    /// Start of the data sent in response to a `COPY ... TO STDOUT` query. The data is always sent
+    /// in the text overall format, with one entry in `column_formats` per copied column.
+    CopyOutResponse {
+        column_formats: Vec<TransferFormat>,
+    },
+    /// A single row of data sent in response to a `COPY ... TO STDOUT` query, formatted as a line
+    /// of text according to `format`
+    CopyData {
+        values: Vec<PsqlValue>,
+        format: CopyFormat,
+    },
+    /// Data received from the upstream database in response to a `COPY ... TO STDOUT` query,
+    /// which is forwarded to the frontend as-is
+    PassThroughCopyData(Bytes),
+    CopyDone,
     DataRow {
         values: Vec<PsqlValue>,
         explicit_transfer_formats: Option<Arc<Vec<TransferFormat>>>,
@@ -142,6 +161,7 @@ pub enum CommandCompleteTag {
     Insert(u64),
     Select(u64),
     Update(u64),
+    Copy(u64),
     /// The bool field indicates if all prepared statements were deallocated.
     Deallocate(DeallocationType),
 }
@@ -170,6 +190,8 @@ pub enum PsqlSrvRow {
     SimpleQueryMessage(SimpleQueryMessage),
     RawRow(Row),
     ValueVec(Vec<PsqlValue>),
+    /// Raw data for a single row of a `COPY ... TO STDOUT` response
+    CopyData(Bytes),
 }
 
 impl From<Vec<PsqlValue>> for PsqlSrvRow {
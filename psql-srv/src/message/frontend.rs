@@ -33,6 +33,10 @@ pub enum FrontendMessage {
         params: Vec<PsqlValue>,
         result_transfer_formats: Vec<TransferFormat>,
     },
+    CancelRequest {
+        process_id: i32,
+        secret_key: i32,
+    },
     Close {
         name: StatementName,
     },
@@ -76,6 +80,7 @@ impl fmt::Display for FrontendMessage {
         match self {
             Self::Authenticate { .. } => write!(f, "Authenticate"),
             Self::Bind { .. } => write!(f, "Bind"),
+            Self::CancelRequest { .. } => write!(f, "CancelRequest"),
             Self::Close { .. } => write!(f, "Close"),
             Self::Describe { .. } => write!(f, "Describe"),
             Self::Execute { .. } => write!(f, "Execute"),
@@ -1,17 +1,19 @@
 use std::borrow::Borrow;
 use std::collections::HashMap;
+use std::future::Future;
 use std::sync::Arc;
 
 use postgres::SimpleQueryMessage;
 use postgres_protocol::Oid;
 use postgres_types::{Kind, Type};
 use readyset_adapter_types::DeallocateId;
-use smallvec::smallvec;
+use smallvec::{smallvec, SmallVec};
 use tokio::io::{AsyncRead, AsyncWrite};
 use tokio_postgres::CommandCompleteContents;
-use tracing::trace;
+use tracing::{trace, warn};
 
 use crate::bytes::BytesStr;
+use crate::cancel::{self, BackendKeyData, CancelRegistration};
 use crate::channel::Channel;
 use crate::codec::decoder;
 use crate::error::Error;
@@ -29,7 +31,7 @@ use crate::scram::{
 };
 use crate::value::PsqlValue;
 use crate::QueryResponse::*;
-use crate::{Column, Credentials, PrepareResponse, PsqlBackend};
+use crate::{Column, Credentials, PrepareResponse, PsqlBackend, UpstreamCanceler};
 
 const ATTTYPMOD_NONE: i32 = -1;
 const TRANSFER_FORMAT_PLACEHOLDER: TransferFormat = TransferFormat::Text;
@@ -70,6 +72,7 @@ pub(crate) enum SaslState {
 /// The state transitions are:
 ///
 /// * StartingUp -> SslHandshake
+/// * StartingUp -> Canceled
 /// * SslHandshake -> StartingUp
 /// * StartingUp -> Ready
 /// * StartingUp -> AuthenticatingCleartext
@@ -109,6 +112,12 @@ pub(crate) enum State {
     /// [0]: https://www.postgresql.org/docs/13/protocol-flow.html#PROTOCOL-FLOW-EXT-QUERY
     /// [1]: psql_srv::message::frontend::FrontendMessage::Sync
     Error,
+
+    /// The client opened this connection to send a [CancelRequest][0], which has been handled.
+    /// The server should close the connection.
+    ///
+    /// [0]: https://www.postgresql.org/docs/current/protocol-flow.html#PROTOCOL-FLOW-CANCELING-REQUESTS
+    Canceled,
 }
 
 /// A struct to maintain state for an implementation of the backend side of the PostgreSQL
@@ -139,6 +148,10 @@ pub struct Protocol {
     /// TLS server endpoint data for channel binding as specified by
     /// [RFC5929](https://www.rfc-editor.org/rfc/rfc5929)
     tls_server_end_point: Option<Vec<u8>>,
+
+    /// Registration of this connection for query cancellation, if enabled. The key data it
+    /// contains is sent to the client once startup completes.
+    cancel_registration: Option<CancelRegistration>,
 }
 
 /// A prepared statement allows a frontend to specify the general form of a SQL statement while
@@ -173,6 +186,7 @@ impl Protocol {
             extended_types: HashMap::new(),
             allow_tls_connections: false,
             tls_server_end_point: None,
+            cancel_registration: None,
         }
     }
 
@@ -182,6 +196,12 @@ impl Protocol {
         self.allow_tls_connections = true;
     }
 
+    /// Register this connection for query cancellation, so that the client is sent key data it can
+    /// use in a CancelRequest to cancel queries running on this connection.
+    pub fn enable_cancellation(&mut self) {
+        self.cancel_registration = Some(CancelRegistration::new());
+    }
+
     /// The core implementation of the backend side of the PostgreSQL frontend/backend protocol.
     /// This implementation processes a message received from the frontend, forwards suitable
     /// requests to a `Backend`, and returns appropriate responses as a `Result`.
@@ -204,8 +224,9 @@ impl Protocol {
         channel: &mut Channel<C>,
     ) -> Result<Response<B::Resultset>, Error> {
         trace!(?message, "protocol on_request");
+        let backend_key_data = self.cancel_registration.as_ref().map(|r| r.key_data());
         let get_ready_message = |version| {
-            smallvec![
+            let mut messages: SmallVec<[BackendMessage; 2]> = smallvec![
                 AuthenticationOk,
                 BackendMessage::ParameterStatus {
                     parameter_name: "client_encoding".to_owned(),
@@ -227,8 +248,21 @@ impl Protocol {
                     parameter_name: "server_version".to_owned(),
                     parameter_value: version,
                 },
-                BackendMessage::ready_for_query(TransactionState::NotInTransaction),
-            ]
+            ];
+            if let Some(BackendKeyData {
+                process_id,
+                secret_key,
+            }) = backend_key_data
+            {
+                messages.push(BackendMessage::BackendKeyData {
+                    process_id,
+                    secret_key,
+                });
+            }
+            messages.push(BackendMessage::ready_for_query(
+                TransactionState::NotInTransaction,
+            ));
+            messages
         };
         match self.state {
             State::StartingUp => match message {
@@ -244,6 +278,21 @@ impl Protocol {
                     }
                 }
 
+                // A request to cancel the query running on another connection. This is the only
+                // message sent on the connection, and no response is returned.
+                CancelRequest {
+                    process_id,
+                    secret_key,
+                } => {
+                    cancel::cancel(BackendKeyData {
+                        process_id,
+                        secret_key,
+                    })
+                    .await;
+                    self.state = State::Canceled;
+                    Ok(Response::Empty)
+                }
+
                 // A request to start up a connection, with some metadata provided.
                 StartupMessage { database, user, .. } => {
                     let database = database
@@ -431,6 +480,8 @@ impl Protocol {
                 _ => Ok(Response::Empty),
             },
 
+            State::Canceled => Err(Error::UnsupportedMessage(message)),
+
             _ => match message {
                 // A request to bind parameters to a prepared statement, creating a portal.
                 Bind {
@@ -605,9 +656,20 @@ impl Protocol {
                         .portals
                         .get(portal_name.borrow() as &str)
                         .ok_or_else(|| Error::MissingPreparedStatement(portal_name.to_string()))?;
-                    let response = backend
-                        .on_execute(*prepared_statement_id, params, result_transfer_formats)
-                        .await?;
+                    let upstream_canceler = backend.upstream_canceler();
+                    let was_connected = upstream_canceler.is_some();
+                    let response = self
+                        .cancelable(
+                            upstream_canceler,
+                            backend.on_execute(
+                                *prepared_statement_id,
+                                params,
+                                result_transfer_formats,
+                            ),
+                        )
+                        .await;
+                    let response =
+                        Self::cancel_connected_upstream(backend, was_connected, response).await?;
                     let res = if let Select { resultset, .. } = response {
                         Ok(Response::Stream {
                             header: None,
@@ -648,6 +710,11 @@ impl Protocol {
                                     "Received Stream response for Execute".to_string(),
                                 ));
                             }
+                            CopyOut { .. } => {
+                                return Err(Error::Unsupported(
+                                    "COPY is not supported in prepared statements".to_string(),
+                                ));
+                            }
                         };
                         Ok(Response::Message(command_complete))
                     };
@@ -658,7 +725,13 @@ impl Protocol {
                 // A request to directly execute a complete SQL statement, without creating a
                 // prepared statement.
                 Query { query } => {
-                    let response = backend.on_query(query.borrow()).await?;
+                    let upstream_canceler = backend.upstream_canceler();
+                    let was_connected = upstream_canceler.is_some();
+                    let response = self
+                        .cancelable(upstream_canceler, backend.on_query(query.borrow()))
+                        .await;
+                    let response =
+                        Self::cancel_connected_upstream(backend, was_connected, response).await?;
                     if let Select { schema, resultset } = response {
                         let mut field_descriptions = Vec::with_capacity(schema.len());
                         for i in schema {
@@ -686,6 +759,37 @@ impl Protocol {
                                 self.transaction_state(backend),
                             )),
                         })
+                    } else if let CopyOut {
+                        format,
+                        header,
+                        schema,
+                        resultset,
+                    } = response
+                    {
+                        let header = header.then(|| {
+                            schema
+                                .iter()
+                                .map(|col| {
+                                    PsqlValue::Text(
+                                        match col {
+                                            Column::Column { name, .. } => name.as_str(),
+                                            Column::OwnedField(field) => field.name(),
+                                        }
+                                        .into(),
+                                    )
+                                })
+                                .collect()
+                        });
+
+                        Ok(Response::CopyOut {
+                            format,
+                            num_columns: schema.len(),
+                            header,
+                            resultset,
+                            trailer: Some(BackendMessage::ready_for_query(
+                                self.transaction_state(backend),
+                            )),
+                        })
                     } else if let SimpleQuery(resp) = response {
                         let mut messages = smallvec![];
                         let mut processing_select = false;
@@ -757,6 +861,9 @@ impl Protocol {
                             Stream { .. } => {
                                 return Err(Error::InternalError("Unexpected Stream".to_string()));
                             }
+                            CopyOut { .. } => {
+                                return Err(Error::InternalError("Unexpected CopyOut".to_string()));
+                            }
                         };
                         Ok(Response::Messages(smallvec![
                             command_complete,
@@ -888,6 +995,49 @@ impl Protocol {
         }
     }
 
+    /// Run `fut`, which runs a query against the backend, aborting it if the client cancels the
+    /// query via a CancelRequest on another connection.
+    async fn cancelable<T, F>(
+        &self,
+        upstream_canceler: Option<Arc<dyn UpstreamCanceler>>,
+        fut: F,
+    ) -> Result<T, Error>
+    where
+        F: Future<Output = Result<T, Error>>,
+    {
+        match &self.cancel_registration {
+            Some(registration) => registration.run(upstream_canceler, fut).await,
+            None => fut.await,
+        }
+    }
+
+    /// Forward the cancellation of a query to the upstream database if the backend connected to
+    /// the upstream while running it.
+    ///
+    /// While a query is running, cancellations are forwarded with the upstream canceler the
+    /// backend had when the query started. A backend which connects to the upstream lazily might
+    /// not have had one yet, in which case the query may have been sent upstream by the time it
+    /// was aborted, so we cancel it there afterwards instead.
+    async fn cancel_connected_upstream<B: PsqlBackend, T>(
+        backend: &B,
+        was_connected: bool,
+        res: Result<T, Error>,
+    ) -> Result<T, Error> {
+        if !was_connected && matches!(res, Err(Error::QueryCanceled)) {
+            if let Some(upstream) = backend.upstream_canceler() {
+                if let Err(error) = upstream.cancel().await {
+                    warn!(%error, "Failed to cancel query on upstream database");
+                }
+            }
+        }
+        res
+    }
+
+    /// Whether the client has sent a CancelRequest, after which the connection should be closed
+    pub fn is_canceled(&self) -> bool {
+        self.state == State::Canceled
+    }
+
     /// Whether the `Protocol` has agreed to initate a TLS handshake, and is waiting for the
     /// handshake to complete.
     pub fn is_initiating_ssl_handshake(&self) -> bool {
@@ -1261,6 +1411,9 @@ mod tests {
         async fn load_extended_types(&mut self) -> Result<HashMap<Oid, i16>, Error> {
             Ok(HashMap::default())
         }
+        fn upstream_canceler(&self) -> Option<Arc<dyn UpstreamCanceler>> {
+            None
+        }
     }
 
     impl Backend {
@@ -1529,6 +1682,67 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn startup_sends_backend_key_data() {
+        let mut protocol = Protocol::new();
+        protocol.enable_cancellation();
+        let key_data = protocol.cancel_registration.as_ref().unwrap().key_data();
+        let mut backend = Backend::new();
+        let mut channel = Channel::<NullBytestream>::new(NullBytestream);
+
+        let startup_request = FrontendMessage::StartupMessage {
+            protocol_version: 12345,
+            user: Some(bytes_str("user_name")),
+            database: Some(bytes_str("database_name")),
+        };
+        match block_on(protocol.on_request(startup_request, &mut backend, &mut channel)).unwrap() {
+            Response::Messages(ms) => {
+                // BackendKeyData is sent immediately before the first ReadyForQuery
+                assert!(matches!(
+                    ms[ms.len() - 2],
+                    BackendMessage::BackendKeyData {
+                        process_id,
+                        secret_key
+                    } if process_id == key_data.process_id && secret_key == key_data.secret_key
+                ));
+                assert!(matches!(
+                    ms[ms.len() - 1],
+                    BackendMessage::ReadyForQuery {
+                        status: READY_FOR_QUERY_IDLE
+                    }
+                ));
+            }
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn cancel_request() {
+        let mut protocol = Protocol::new();
+        let mut backend = Backend::new();
+        let mut channel = Channel::<NullBytestream>::new(NullBytestream);
+
+        // A CancelRequest is accepted in place of a StartupMessage (no response message is
+        // returned).
+        let request = FrontendMessage::CancelRequest {
+            process_id: 1234,
+            secret_key: 5678,
+        };
+        assert!(matches!(
+            block_on(protocol.on_request(request, &mut backend, &mut channel)).unwrap(),
+            Response::Empty
+        ));
+        assert!(protocol.is_canceled());
+
+        // No further messages are accepted on the connection.
+        let startup_request = FrontendMessage::StartupMessage {
+            protocol_version: 12345,
+            user: Some(bytes_str("user_name")),
+            database: Some(bytes_str("database_name")),
+        };
+        block_on(protocol.on_request(startup_request, &mut backend, &mut channel)).unwrap_err();
+    }
+
     #[tokio::test]
     async fn query_read() {
         let mut protocol = Protocol::new();
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use futures::prelude::*;
+use nom_sql::CopyFormat;
 use smallvec::SmallVec;
 use tokio_postgres::SimpleQueryMessage;
 use tracing::trace;
@@ -8,6 +9,7 @@ use tracing::trace;
 use crate::codec::EncodeError;
 use crate::error::Error;
 use crate::message::{BackendMessage, CommandCompleteTag, PsqlSrvRow, TransferFormat};
+use crate::value::PsqlValue;
 
 /// An encapsulation of a complete response produced by a Postgresql backend in response to a
 /// request. The response will be sent to the frontend as a sequence of zero or more
@@ -28,6 +30,18 @@ pub enum Response<S> {
         result_transfer_formats: Option<Arc<Vec<TransferFormat>>>,
         trailer: Option<BackendMessage>,
     },
+
+    /// Rows to be sent to the frontend in response to a `COPY ... TO STDOUT` query, using the
+    /// copy-out sub-protocol
+    CopyOut {
+        format: CopyFormat,
+        /// The number of columns being copied, if known
+        num_columns: usize,
+        /// A row of column names to send before the rows in `resultset`, if requested
+        header: Option<Vec<PsqlValue>>,
+        resultset: S,
+        trailer: Option<BackendMessage>,
+    },
 }
 
 impl<S> Response<S>
@@ -95,6 +109,13 @@ where
                             trace!("Sending error: {:?}", e);
                             sink.feed(e.into()).await?;
                         }
+                        Ok(PsqlSrvRow::CopyData(_)) => {
+                            sink.feed(
+                                Error::InternalError("Unexpected COPY data in resultset".into())
+                                    .into(),
+                            )
+                            .await?;
+                        }
                         Ok(PsqlSrvRow::SimpleQueryMessage(m)) => {
                             trace!("Sending simple query message: {:?}", m);
                             debug_assert_eq!(n_rows, 0, "should not see a mix of simple query messages and rows that we count manually");
@@ -152,6 +173,74 @@ where
 
                 Ok(())
             }
+
+            CopyOut {
+                format,
+                num_columns,
+                header,
+                mut resultset,
+                trailer,
+            } => {
+                sink.feed(BackendMessage::CopyOutResponse {
+                    column_formats: vec![TransferFormat::Text; num_columns],
+                })
+                .await?;
+
+                if let Some(header) = header {
+                    trace!("Sending copy header: {:?}", header);
+                    sink.feed(BackendMessage::CopyData {
+                        values: header,
+                        format,
+                    })
+                    .await?;
+                }
+
+                let mut n_rows = 0;
+                let mut error = None;
+                while let Some(r) = resultset.next().await {
+                    match r {
+                        Ok(PsqlSrvRow::ValueVec(values)) => {
+                            trace!("Sending copy row: {:?}", values);
+                            sink.feed(BackendMessage::CopyData { values, format })
+                                .await?;
+                        }
+                        Ok(PsqlSrvRow::CopyData(data)) => {
+                            trace!("Sending pass-through copy data: {:?}", data);
+                            sink.feed(BackendMessage::PassThroughCopyData(data)).await?;
+                        }
+                        Ok(PsqlSrvRow::RawRow(_) | PsqlSrvRow::SimpleQueryMessage(_)) => {
+                            error = Some(Error::InternalError(
+                                "Unexpected row type in COPY resultset".into(),
+                            ));
+                            break;
+                        }
+                        Err(e) => {
+                            error = Some(e);
+                            break;
+                        }
+                    }
+                    n_rows += 1;
+                }
+
+                // An error ends the copy-out sub-protocol, without a CopyDone or CommandComplete
+                if let Some(e) = error {
+                    trace!("Sending error: {:?}", e);
+                    sink.feed(e.into()).await?;
+                } else {
+                    sink.feed(BackendMessage::CopyDone).await?;
+                    sink.feed(BackendMessage::CommandComplete {
+                        tag: CommandCompleteTag::Copy(n_rows),
+                    })
+                    .await?;
+                }
+
+                if let Some(trailer) = trailer {
+                    trace!("Sending trailer: {:?}", trailer);
+                    sink.feed(trailer).await?;
+                }
+
+                Ok(())
+            }
         }
     }
 }
@@ -165,7 +254,6 @@ mod tests {
 
     use super::*;
     use crate::message::TransactionState;
-    use crate::value::PsqlValue;
 
     type TestResponse = Response<stream::Iter<vec::IntoIter<Result<PsqlSrvRow, Error>>>>;
 
@@ -327,4 +415,87 @@ mod tests {
         futures::pin_mut!(validating_sink);
         block_on(response.write(&mut validating_sink)).unwrap();
     }
+
+    #[test]
+    fn write_copy_out() {
+        let response = Response::CopyOut {
+            format: CopyFormat::Csv,
+            num_columns: 1,
+            header: Some(vec![PsqlValue::Text("x".into())]),
+            resultset: stream::iter(vec![
+                Ok(vec![PsqlValue::Int(5)].into()),
+                Ok(vec![PsqlValue::Int(99)].into()),
+            ]),
+            trailer: None,
+        };
+        let validating_sink = sink::unfold(0, |i, m: BackendMessage| {
+            async move {
+                match i {
+                    0 => assert!(matches!(
+                        m,
+                        BackendMessage::CopyOutResponse { column_formats }
+                            if column_formats == vec![TransferFormat::Text]
+                    )),
+                    1 => assert!(matches!(
+                        m,
+                        BackendMessage::CopyData { values, format: CopyFormat::Csv }
+                            if values == vec![PsqlValue::Text("x".into())]
+                    )),
+                    2 => assert!(matches!(
+                        m,
+                        BackendMessage::CopyData { values, format: CopyFormat::Csv }
+                            if values == vec![PsqlValue::Int(5)]
+                    )),
+                    3 => assert!(matches!(
+                        m,
+                        BackendMessage::CopyData { values, format: CopyFormat::Csv }
+                            if values == vec![PsqlValue::Int(99)]
+                    )),
+                    4 => assert!(matches!(m, BackendMessage::CopyDone)),
+                    5 => assert!(matches!(
+                        m,
+                        BackendMessage::CommandComplete {
+                            tag: CommandCompleteTag::Copy(2)
+                        }
+                    )),
+                    // No further messages are expected.
+                    _ => panic!(),
+                }
+                Ok::<_, EncodeError>(i + 1)
+            }
+        });
+        futures::pin_mut!(validating_sink);
+        block_on(response.write(&mut validating_sink)).unwrap();
+    }
+
+    #[test]
+    fn write_copy_out_error() {
+        let response = Response::CopyOut {
+            format: CopyFormat::Text,
+            num_columns: 1,
+            header: None,
+            resultset: stream::iter(vec![
+                Ok(vec![PsqlValue::Int(5)].into()),
+                Err(Error::InternalError("oops".into())),
+            ]),
+            trailer: Some(BackendMessage::ready_for_query(
+                TransactionState::NotInTransaction,
+            )),
+        };
+        let validating_sink = sink::unfold(0, |i, m: BackendMessage| {
+            async move {
+                match i {
+                    0 => assert!(matches!(m, BackendMessage::CopyOutResponse { .. })),
+                    1 => assert!(matches!(m, BackendMessage::CopyData { .. })),
+                    2 => assert!(matches!(m, BackendMessage::ErrorResponse { .. })),
+                    3 => assert!(matches!(m, BackendMessage::ReadyForQuery { .. })),
+                    // No further messages are expected.
+                    _ => panic!(),
+                }
+                Ok::<_, EncodeError>(i + 1)
+            }
+        });
+        futures::pin_mut!(validating_sink);
+        block_on(response.write(&mut validating_sink)).unwrap();
+    }
 }
@@ -42,6 +42,7 @@ impl<B: PsqlBackend> Runner<B, tokio::net::TcpStream> {
         tls_acceptor: Option<Arc<TlsAcceptor>>,
     ) {
         let mut protocol = Protocol::new();
+        protocol.enable_cancellation();
         if tls_acceptor.is_some() {
             protocol.allow_tls_connections()
         };
@@ -146,6 +147,10 @@ impl<B: PsqlBackend, C: AsyncRead + AsyncWrite + Unpin> Runner<B, C> {
                     if self.protocol.is_initiating_ssl_handshake() {
                         return MainLoopStatus::RestartWithTls;
                     }
+                    // The client sent a CancelRequest, after which we close the connection
+                    if self.protocol.is_canceled() {
+                        return MainLoopStatus::Terminate;
+                    }
                 }
                 // Return an error message but do not exit the loop
                 Err(e) => {
@@ -5,7 +5,7 @@ use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime};
 use cidr::IpInet;
 use eui48::MacAddress;
 use postgres_types::{FromSql, Kind, Type};
-use readyset_data::{Array, PassThroughFormat, Text};
+use readyset_data::{Array, Interval, PassThroughFormat, Text};
 use rust_decimal::Decimal;
 use uuid::Uuid;
 
@@ -30,6 +30,7 @@ pub enum PsqlValue {
     TimestampTz(DateTime<FixedOffset>),
     Date(NaiveDate),
     Time(NaiveTime),
+    Interval(Interval),
     ByteArray(Vec<u8>),
     MacAddress(MacAddress),
     Inet(IpInet),
@@ -97,6 +98,7 @@ impl<'a> FromSql<'a> for PsqlValue {
                     .map(PsqlValue::TimestampTz),
                 Type::DATE => NaiveDate::from_sql(ty, raw).map(PsqlValue::Date),
                 Type::TIME => NaiveTime::from_sql(ty, raw).map(PsqlValue::Time),
+                Type::INTERVAL => Interval::from_sql(ty, raw).map(PsqlValue::Interval),
                 Type::BYTEA => <Vec<u8>>::from_sql(ty, raw).map(PsqlValue::ByteArray),
                 Type::MACADDR => MacAddress::from_sql(ty, raw).map(PsqlValue::MacAddress),
                 Type::INET => IpInet::from_sql(ty, raw).map(PsqlValue::Inet),
@@ -10,7 +10,7 @@ use postgres_protocol::Oid;
 use postgres_types::Type;
 use psql_srv::{
     run_backend, Credentials, CredentialsNeeded, Error, PrepareResponse, PsqlBackend, PsqlSrvRow,
-    PsqlValue, QueryResponse, TransferFormat,
+    PsqlValue, QueryResponse, TransferFormat, UpstreamCanceler,
 };
 use readyset_adapter_types::DeallocateId;
 use tokio::net::TcpListener;
@@ -84,6 +84,9 @@ impl PsqlBackend for ScramSha256Backend {
     async fn load_extended_types(&mut self) -> Result<HashMap<Oid, i16>, psql_srv::Error> {
         Ok(HashMap::default())
     }
+    fn upstream_canceler(&self) -> Option<Arc<dyn UpstreamCanceler>> {
+        None
+    }
 }
 
 async fn run_server(backend: ScramSha256Backend) -> u16 {
@@ -0,0 +1,188 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use std::vec;
+
+use futures::future::BoxFuture;
+use futures::{stream, FutureExt};
+use postgres::error::SqlState;
+use postgres::NoTls;
+use postgres_protocol::Oid;
+use postgres_types::Type;
+use psql_srv::{
+    run_backend, Credentials, CredentialsNeeded, Error, PrepareResponse, PsqlBackend, PsqlSrvRow,
+    PsqlValue, QueryResponse, TransferFormat, UpstreamCanceler,
+};
+use readyset_adapter_types::DeallocateId;
+use tokio::net::TcpListener;
+use tokio_postgres::Client;
+
+struct TestUpstreamCanceler(Arc<AtomicBool>);
+
+impl UpstreamCanceler for TestUpstreamCanceler {
+    fn cancel(&self) -> BoxFuture<'static, Result<(), Error>> {
+        self.0.store(true, Ordering::SeqCst);
+        async { Ok(()) }.boxed()
+    }
+}
+
+/// A backend which blocks forever on the query `SELECT slow`, and returns an empty resultset for
+/// any other query.
+///
+/// The backend connects to its (fake) upstream lazily, on the first query other than `SELECT 1`.
+struct SlowBackend {
+    upstream_canceled: Arc<AtomicBool>,
+    connected: bool,
+}
+
+impl PsqlBackend for SlowBackend {
+    type Resultset = stream::Iter<vec::IntoIter<Result<PsqlSrvRow, psql_srv::Error>>>;
+
+    fn version(&self) -> String {
+        "13.4 ReadySet".to_owned()
+    }
+
+    fn credentials_for_user(&self, _user: &str) -> Option<Credentials> {
+        Some(Credentials::Any)
+    }
+
+    async fn on_init(&mut self, _database: &str) -> Result<CredentialsNeeded, Error> {
+        Ok(CredentialsNeeded::None)
+    }
+
+    async fn on_query(&mut self, query: &str) -> Result<QueryResponse<Self::Resultset>, Error> {
+        if query != "SELECT 1" {
+            self.connected = true;
+        }
+        if query == "SELECT slow" {
+            futures::future::pending::<()>().await;
+        }
+        Ok(QueryResponse::Select {
+            schema: vec![],
+            resultset: stream::iter(vec![]),
+        })
+    }
+
+    async fn on_prepare(
+        &mut self,
+        _query: &str,
+        _parameter_data_types: &[Type],
+    ) -> Result<PrepareResponse, Error> {
+        Ok(PrepareResponse {
+            prepared_statement_id: 1,
+            param_schema: vec![],
+            row_schema: vec![],
+        })
+    }
+
+    async fn on_execute(
+        &mut self,
+        _statement_id: u32,
+        _params: &[PsqlValue],
+        _result_transfer_formats: &[TransferFormat],
+    ) -> Result<QueryResponse<Self::Resultset>, Error> {
+        Ok(QueryResponse::Select {
+            schema: vec![],
+            resultset: stream::iter(vec![]),
+        })
+    }
+
+    async fn on_close(&mut self, _statement_id: DeallocateId) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn in_transaction(&self) -> bool {
+        false
+    }
+
+    async fn load_extended_types(&mut self) -> Result<HashMap<Oid, i16>, Error> {
+        Ok(HashMap::default())
+    }
+
+    fn upstream_canceler(&self) -> Option<Arc<dyn UpstreamCanceler>> {
+        if !self.connected {
+            return None;
+        }
+        Some(Arc::new(TestUpstreamCanceler(
+            self.upstream_canceled.clone(),
+        )))
+    }
+}
+
+/// Start a server accepting any number of connections, and connect a client to it
+async fn setup() -> (Client, Arc<AtomicBool>) {
+    let upstream_canceled = Arc::new(AtomicBool::new(false));
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    tokio::spawn({
+        let upstream_canceled = upstream_canceled.clone();
+        async move {
+            loop {
+                let (socket, _) = listener.accept().await.unwrap();
+                let backend = SlowBackend {
+                    upstream_canceled: upstream_canceled.clone(),
+                    connected: false,
+                };
+                tokio::spawn(run_backend(backend, socket, false, None));
+            }
+        }
+    });
+
+    let (client, conn) = tokio_postgres::Config::default()
+        .host("localhost")
+        .port(port)
+        .dbname("noria")
+        .connect(NoTls)
+        .await
+        .unwrap();
+    tokio::spawn(conn);
+
+    (client, upstream_canceled)
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn cancel_running_query() {
+    let (client, upstream_canceled) = setup().await;
+    let cancel_token = client.cancel_token();
+    // Connect to the upstream before running the query
+    client.simple_query("SELECT connect").await.unwrap();
+
+    let (res, ()) = tokio::join!(client.simple_query("SELECT slow"), async {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        cancel_token.cancel_query(NoTls).await.unwrap();
+    });
+
+    assert_eq!(res.unwrap_err().code().unwrap(), &SqlState::QUERY_CANCELED);
+    assert!(upstream_canceled.load(Ordering::SeqCst));
+
+    // The connection can still be used after the query is canceled
+    client.simple_query("SELECT 1").await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn cancel_while_idle() {
+    let (client, upstream_canceled) = setup().await;
+
+    client.cancel_token().cancel_query(NoTls).await.unwrap();
+    // Give the server a chance to handle the cancel request
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    assert!(!upstream_canceled.load(Ordering::SeqCst));
+    client.simple_query("SELECT 1").await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn cancel_query_which_connects_to_upstream() {
+    let (client, upstream_canceled) = setup().await;
+    let cancel_token = client.cancel_token();
+
+    let (res, ()) = tokio::join!(client.simple_query("SELECT slow"), async {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        cancel_token.cancel_query(NoTls).await.unwrap();
+    });
+
+    assert_eq!(res.unwrap_err().code().unwrap(), &SqlState::QUERY_CANCELED);
+    assert!(upstream_canceled.load(Ordering::SeqCst));
+    client.simple_query("SELECT 1").await.unwrap();
+}
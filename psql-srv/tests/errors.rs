@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::sync::Arc;
 use std::vec;
 
 use futures::{stream, Future};
@@ -7,7 +8,7 @@ use postgres_protocol::Oid;
 use postgres_types::Type;
 use psql_srv::{
     run_backend, Column, Credentials, CredentialsNeeded, Error, PrepareResponse, PsqlBackend,
-    PsqlSrvRow, PsqlValue, QueryResponse, TransferFormat,
+    PsqlSrvRow, PsqlValue, QueryResponse, TransferFormat, UpstreamCanceler,
 };
 use readyset_adapter_types::DeallocateId;
 use tokio::join;
@@ -108,6 +109,9 @@ impl PsqlBackend for ErrorBackend {
     async fn load_extended_types(&mut self) -> Result<HashMap<Oid, i16>, psql_srv::Error> {
         Ok(HashMap::default())
     }
+    fn upstream_canceler(&self) -> Option<Arc<dyn UpstreamCanceler>> {
+        None
+    }
 }
 
 async fn error_test<F, R>(error_pos: ErrorPosition, inner: F)
@@ -8,6 +8,7 @@ use postgres_protocol::Oid;
 use postgres_types::Type;
 use psql_srv::{
     run_backend, Credentials, CredentialsNeeded, Error, PsqlBackend, PsqlSrvRow, TransferFormat,
+    UpstreamCanceler,
 };
 use readyset_adapter_types::DeallocateId;
 use tokio::net::TcpListener;
@@ -81,6 +82,9 @@ impl PsqlBackend for TestBackend {
     async fn load_extended_types(&mut self) -> Result<HashMap<Oid, i16>, psql_srv::Error> {
         Ok(HashMap::default())
     }
+    fn upstream_canceler(&self) -> Option<Arc<dyn UpstreamCanceler>> {
+        None
+    }
 }
 
 #[tokio::test(flavor = "multi_thread")]
@@ -69,7 +69,7 @@
 //! being too heavy handed.
 
 use std::borrow::Cow;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt::{self, Debug};
 use std::marker::PhantomData;
 use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
@@ -81,7 +81,8 @@ use futures::future::{self, OptionFuture};
 use lru::LruCache;
 use mysql_common::row::convert::{FromRow, FromRowError};
 use nom_sql::{
-    CacheInner, CreateCacheStatement, DeallocateStatement, DeleteStatement, Dialect,
+    AlterCacheDefinition, AlterCacheStatement, CacheInner, CacheOptions, CopyFormat, CopySource,
+    CopyStatement, CreateCacheStatement, DeallocateStatement, DeleteStatement, Dialect,
     DialectDisplay, DropCacheStatement, InsertStatement, Relation, SelectStatement, SetStatement,
     ShowStatement, SqlIdentifier, SqlQuery, StatementIdentifier, UpdateStatement, UseStatement,
 };
@@ -121,7 +122,7 @@ use crate::{create_dummy_schema, QueryHandler, UpstreamDatabase, UpstreamDestina
 pub mod noria_connector;
 
 pub use self::noria_connector::NoriaConnector;
-use self::noria_connector::{MetaVariable, PreparedSelectTypes};
+use self::noria_connector::{MetaVariable, PreparedSelectTypes, UpstreamPositions};
 
 const UNSUPPORTED_CACHE_DDL_MSG: &str = "This instance has been provisioned through ReadySet Cloud. Please use the ReadySet Cloud UI to manage caches. You may continue to use the SQL interface to run other 'read' commands.";
 
@@ -152,6 +153,7 @@ enum PrepareMeta {
 struct PrepareSelectMeta {
     stmt: nom_sql::SelectStatement,
     rewritten: nom_sql::SelectStatement,
+    processed_query_params: ProcessedQueryParams,
     must_migrate: bool,
     should_do_noria: bool,
     always: bool,
@@ -244,6 +246,12 @@ impl ProxyState {
         *self == ProxyState::InTransaction
     }
 
+    /// Returns true if writes made in this proxy state only become visible to the replicator once
+    /// the current (explicit or implicit) transaction commits.
+    fn writes_need_commit(&self) -> bool {
+        matches!(self, Self::InTransaction | Self::AutocommitOff)
+    }
+
     /// Sets the autocommit state accordingly. If turning autocommit on, will set ProxyState to
     /// Fallback as long as current state is AutocommitOff.
     ///
@@ -290,6 +298,7 @@ pub struct BackendBuilder {
     metrics_handle: Option<MetricsHandle>,
     connections: Option<Arc<SkipSet<SocketAddr>>>,
     allow_cache_ddl: bool,
+    upstream_positions: Arc<UpstreamPositions>,
 }
 
 impl Default for BackendBuilder {
@@ -313,6 +322,7 @@ impl Default for BackendBuilder {
             metrics_handle: None,
             connections: None,
             allow_cache_ddl: true,
+            upstream_positions: Default::default(),
         }
     }
 }
@@ -359,6 +369,9 @@ impl BackendBuilder {
                 query_status_cache,
                 ticket: self.ticket,
                 timestamp_client: self.timestamp_client,
+                ryw_transaction_writes: Default::default(),
+                ryw_pending_writes: Default::default(),
+                upstream_positions: self.upstream_positions,
             },
             settings: BackendSettings {
                 slowlog: self.slowlog,
@@ -508,6 +521,9 @@ where
     /// If statement was successfully rewritten, will store all information necessary to install
     /// the view in readyset
     view_request: Option<ViewCreateRequest>,
+    /// If statement was successfully rewritten, will store the information needed to turn the
+    /// parameters it's executed with into lookup keys
+    processed_query_params: Option<ProcessedQueryParams>,
 }
 
 impl<DB> PreparedStatement<DB>
@@ -619,6 +635,61 @@ where
     /// is responsible for creating accurate RYW timestamps/tickets based on writes made by the
     /// Backend client.
     timestamp_client: Option<TimestampClient>,
+    /// Tables written to by the current transaction while RYW is enabled. Those writes aren't
+    /// visible to the replicator until the transaction commits, at which point these tables are
+    /// moved to `ryw_pending_writes`.
+    ryw_transaction_writes: HashSet<Relation>,
+    /// Tables with committed writes that haven't been folded into `ticket` yet. Writes which
+    /// can't be identified by the upstream when they're made (those made by prepared statements
+    /// or inside transactions) are recorded here, and the ticket is updated from the upstream's
+    /// replication position before the next statement is run.
+    ryw_pending_writes: HashSet<Relation>,
+    /// Recent samples of the upstream database's replication position, used to check reads from
+    /// caches with a maximum staleness, shared with every other connection to the adapter
+    upstream_positions: Arc<UpstreamPositions>,
+}
+
+impl<DB> BackendState<DB>
+where
+    DB: UpstreamDatabase,
+{
+    /// Records a successful write to `table` for the purposes of RYW, if it's enabled.
+    fn record_ryw_write(&mut self, table: &Relation) {
+        if self.timestamp_client.is_none() {
+            return;
+        }
+
+        if self.proxy_state.writes_need_commit() {
+            self.ryw_transaction_writes.insert(table.clone());
+        } else {
+            self.ryw_pending_writes.insert(table.clone());
+        }
+    }
+
+    /// Records the end of a transaction for the purposes of RYW. Writes made in the transaction
+    /// are only kept if it was committed.
+    fn end_ryw_transaction(&mut self, committed: bool) {
+        if committed {
+            self.ryw_pending_writes
+                .extend(self.ryw_transaction_writes.drain());
+        } else {
+            self.ryw_transaction_writes.clear();
+        }
+    }
+
+    /// Records the effect on RYW of a statement which was run successfully against the upstream
+    /// database without being identified by it, such as a prepared statement or a statement inside
+    /// of a transaction.
+    fn record_ryw_statement(&mut self, query: &SqlQuery) {
+        match query {
+            SqlQuery::Insert(InsertStatement { table, .. })
+            | SqlQuery::Update(UpdateStatement { table, .. })
+            | SqlQuery::Delete(DeleteStatement { table, .. }) => self.record_ryw_write(table),
+            SqlQuery::Commit(_) => self.end_ryw_transaction(true),
+            SqlQuery::Rollback(_) => self.end_ryw_transaction(false),
+            _ => {}
+        }
+    }
 }
 
 /// Settings that have no state and are constant for a given [`Backend`]
@@ -813,6 +884,13 @@ where
     /// Results from parsing a SQL statement and determining that it's a command that should
     /// be handed at an outer layer.
     Parser(ParsedCommand),
+    /// Results from noria for a `COPY ... TO STDOUT` statement, to be copied to the client in the
+    /// given format
+    Copy {
+        format: CopyFormat,
+        header: bool,
+        result: noria_connector::QueryResult<'a>,
+    },
 }
 
 impl<'a, DB: UpstreamDatabase> From<noria_connector::QueryResult<'a>> for QueryResult<'a, DB> {
@@ -833,6 +911,16 @@ where
                 f.debug_tuple("UpstreamBufferedInMemory").field(r).finish()
             }
             Self::Parser(r) => f.debug_tuple("Parser").field(r).finish(),
+            Self::Copy {
+                format,
+                header,
+                result,
+            } => f
+                .debug_struct("Copy")
+                .field("format", format)
+                .field("header", header)
+                .field("result", result)
+                .finish(),
         }
     }
 }
@@ -919,6 +1007,28 @@ where
         result.map(QueryResult::Upstream)
     }
 
+    /// Executes a `COPY ... TO STDOUT` statement, which copies from `source`, on the upstream
+    /// database, streaming the copied data back to the client. Returns an error if fallback is not
+    /// configured
+    async fn copy_out_fallback<'a>(
+        upstream: Option<&'a mut DB>,
+        query: &'a str,
+        source: &CopySource,
+        event: &mut QueryExecutionEvent,
+    ) -> Result<QueryResult<'a, DB>, DB::Error> {
+        let upstream = upstream.ok_or_else(|| {
+            ReadySetError::Internal("This case requires an upstream connector".to_string())
+        })?;
+        let _t = event.start_upstream_timer();
+        let result = upstream.copy_out(query, source).await;
+        drop(_t);
+        event.destination = Some(match &result {
+            Ok(qr) => qr.destination(),
+            Err(_) => QueryDestination::Upstream,
+        });
+        result.map(QueryResult::Upstream)
+    }
+
     /// Executes query on the upstream database using the "simple query" protocol, which buffers
     /// results in memory before returning. Note that this only applies to PostgreSQL backends, and
     /// for MySQL will return an error.
@@ -1100,7 +1210,7 @@ where
     /// Provides metadata required to prepare a select query
     fn plan_prepare_select(&mut self, stmt: nom_sql::SelectStatement) -> PrepareMeta {
         match self.rewrite_select_and_check_readyset(&stmt) {
-            Ok((rewritten, should_do_readyset)) => {
+            Ok((rewritten, processed_query_params, should_do_readyset)) => {
                 let status = self
                     .state
                     .query_status_cache
@@ -1114,6 +1224,7 @@ where
                     PrepareMeta::Select(PrepareSelectMeta {
                         stmt,
                         rewritten,
+                        processed_query_params,
                         should_do_noria: should_do_readyset,
                         // For select statements only InRequestPath should trigger migrations
                         // synchronously, or if no upstream is present.
@@ -1142,9 +1253,10 @@ where
     fn rewrite_select_and_check_readyset(
         &mut self,
         stmt: &nom_sql::SelectStatement,
-    ) -> ReadySetResult<(nom_sql::SelectStatement, bool)> {
+    ) -> ReadySetResult<(nom_sql::SelectStatement, ProcessedQueryParams, bool)> {
         let mut rewritten = stmt.clone();
-        adapter_rewrites::process_query(&mut rewritten, self.noria.rewrite_params())?;
+        let processed_query_params =
+            adapter_rewrites::process_query(&mut rewritten, self.noria.rewrite_params())?;
         // Attempt ReadySet unless the query is unsupported or dropped
         let should_do_readyset = !matches!(
             self.state
@@ -1156,7 +1268,7 @@ where
                 .1,
             MigrationState::Unsupported | MigrationState::Dropped
         );
-        Ok((rewritten, should_do_readyset))
+        Ok((rewritten, processed_query_params, should_do_readyset))
     }
 
     /// Provides metadata required to prepare a query
@@ -1278,6 +1390,10 @@ where
             .do_prepare(&meta, query, data, &mut query_event)
             .await?;
 
+        let processed_query_params = match &meta {
+            PrepareMeta::Select(select_meta) => Some(select_meta.processed_query_params.clone()),
+            _ => None,
+        };
         let (query_id, parsed_query, migration_state, view_request, always) = match meta {
             PrepareMeta::Write { stmt } | PrepareMeta::Transaction { stmt } => (
                 None,
@@ -1334,6 +1450,7 @@ where
             execution_info: None,
             parsed_query,
             view_request,
+            processed_query_params,
             always,
         });
 
@@ -1354,6 +1471,7 @@ where
         prep: &noria_connector::PrepareResult,
         params: &[DfValue],
         ticket: Option<Timestamp>,
+        upstream_positions: &UpstreamPositions,
         event: &mut QueryExecutionEvent,
     ) -> ReadySetResult<QueryResult<'a, DB>> {
         use noria_connector::PrepareResult::*;
@@ -1366,7 +1484,9 @@ where
                     ps: statement,
                     params,
                 };
-                noria.execute_select(ctx, ticket, event).await
+                noria
+                    .execute_select(ctx, ticket, upstream_positions, event)
+                    .await
             }
             Insert { statement, .. } => noria.execute_prepared_insert(statement, params).await,
             Update { statement, .. } => noria.execute_prepared_update(statement, params).await,
@@ -1420,9 +1540,11 @@ where
         exec_meta: DB::ExecMeta<'_>,
         ex_info: Option<&mut ExecutionInfo>,
         ticket: Option<Timestamp>,
+        upstream_positions: &UpstreamPositions,
         event: &mut QueryExecutionEvent,
     ) -> Result<QueryResult<'a, DB>, DB::Error> {
-        let noria_res = Self::execute_noria(noria, noria_prep, params, ticket, event).await;
+        let noria_res =
+            Self::execute_noria(noria, noria_prep, params, ticket, upstream_positions, event).await;
         match noria_res {
             Ok(noria_ok) => {
                 if let Some(info) = ex_info {
@@ -1445,6 +1567,7 @@ where
                     matches!(
                         e,
                         ReadySetError::ReaderMissingKey
+                            | ReadySetError::UpstreamPositionUnavailable
                             | ReadySetError::NoCacheForQuery
                             | ReadySetError::UnparseableQuery { .. }
                     )
@@ -1453,12 +1576,127 @@ where
                           "Error received from noria, sending query to fallback");
                 }
 
+                if let Some(upstream) = upstream.as_mut() {
+                    Self::sample_upstream_position_if_needed(
+                        upstream,
+                        &noria_err,
+                        upstream_positions,
+                    )
+                    .await;
+                }
+
                 Self::execute_upstream(upstream, upstream_prep, params, exec_meta, event, true)
                     .await
             }
         }
     }
 
+    /// Converts a position in the upstream database's replication log, as returned by
+    /// [`UpstreamDatabase::handle_ryw_write`] or [`UpstreamDatabase::replication_position`], to a
+    /// [`WriteId`]
+    fn write_id(position: String) -> WriteId {
+        match DB::SQL_DIALECT {
+            nom_sql::Dialect::MySQL => WriteId::MySqlGtid(position),
+            nom_sql::Dialect::PostgreSQL => WriteId::PostgresLsn(position),
+        }
+    }
+
+    /// Returns the [`WriteKey`] to use for a write to `table` in a RYW ticket, or `None` if
+    /// ReadySet doesn't know about the table (for example, because it isn't being replicated),
+    /// in which case there's nothing for reads to wait for.
+    async fn ryw_write_key(noria: &mut NoriaConnector, table: &Relation) -> Option<WriteKey> {
+        // TODO(andrew): Move table name to table index conversion to
+        // timestamp service https://app.clubhouse.io/readysettech/story/331
+        match noria.node_index_of(table.name.as_str()).await {
+            Ok(index) => Some(WriteKey::TableIndex(index)),
+            Err(error) => {
+                trace!(%error, ?table, "Not including write in read-your-writes ticket");
+                None
+            }
+        }
+    }
+
+    /// Folds any committed writes in [`BackendState::ryw_pending_writes`] into the current RYW
+    /// ticket.
+    ///
+    /// The upstream database doesn't identify these writes when they're made, so we use its current
+    /// replication position instead. Since that's sampled after the writes committed, it's at or
+    /// after the position of each of them.
+    ///
+    /// The pending writes are always cleared. If the position can't be sampled, they're left out
+    /// of the ticket, and reads fall back to not waiting for them.
+    async fn update_ryw_ticket(
+        noria: &mut NoriaConnector,
+        upstream: Option<&mut DB>,
+        state: &mut BackendState<DB>,
+    ) {
+        let pending_writes = std::mem::take(&mut state.ryw_pending_writes);
+        if pending_writes.is_empty() {
+            return;
+        }
+
+        let (Some(upstream), Some(timestamp_service)) = (upstream, &mut state.timestamp_client)
+        else {
+            return;
+        };
+
+        let mut affected_tables = Vec::with_capacity(pending_writes.len());
+        for table in &pending_writes {
+            affected_tables.extend(Self::ryw_write_key(noria, table).await);
+        }
+        if affected_tables.is_empty() {
+            return;
+        }
+
+        let position = match upstream.replication_position().await {
+            Ok(position) => position,
+            Err(error) => {
+                warn!(%error, "Could not sample the upstream position for a RYW ticket");
+                return;
+            }
+        };
+        let new_timestamp =
+            match timestamp_service.append_write(Self::write_id(position), affected_tables) {
+                Ok(timestamp) => timestamp,
+                Err(error) => {
+                    warn!(%error, "Could not record writes in the RYW ticket");
+                    return;
+                }
+            };
+
+        match &state.ticket {
+            Some(current_ticket) => {
+                state.ticket = Some(Timestamp::join(current_ticket, &new_timestamp));
+            }
+            None => warn!("RYW enabled backends must have a current ticket"),
+        }
+    }
+
+    /// If `noria_err` indicates that a read from a cache with a maximum staleness needed a more
+    /// recent sample of the upstream database's replication position, takes one so that
+    /// subsequent reads can be checked against it.
+    async fn sample_upstream_position_if_needed(
+        upstream: &mut DB,
+        noria_err: &ReadySetError,
+        upstream_positions: &UpstreamPositions,
+    ) {
+        if !matches!(noria_err, ReadySetError::UpstreamPositionUnavailable) {
+            return;
+        }
+
+        let position = match upstream.replication_position().await {
+            Ok(position) => position,
+            Err(error) => {
+                warn!(%error, "Could not sample the upstream replication position");
+                return;
+            }
+        };
+        match Self::write_id(position).timestamp_value() {
+            Ok(position) => upstream_positions.record(position),
+            Err(error) => warn!(%error, "Could not sample the upstream replication position"),
+        }
+    }
+
     /// Attempts to migrate a query on noria, after
     /// - the query was marked as `MigrationState::Successful` in the cache -or-
     /// - the epoch stored in `MigrationState::Inlined` advanced but the query is not yet prepared
@@ -1556,7 +1794,6 @@ where
     /// `params`.
     /// A [`QueryExecutionEvent`], is used to track metrics and behavior scoped to the
     /// execute operation.
-    // TODO(andrew, justin): add RYW support for executing prepared queries
     #[instrument(skip_all)]
     #[inline]
     pub async fn execute(
@@ -1566,6 +1803,7 @@ where
         exec_meta: DB::ExecMeta<'_>,
     ) -> Result<QueryResult<'_, DB>, DB::Error> {
         self.last_query = None;
+        Self::update_ryw_ticket(&mut self.noria, self.upstream.as_mut(), &mut self.state).await;
         let cached_statement = self
             .state
             .prepared_statements
@@ -1597,7 +1835,15 @@ where
 
             if new_migration_state == MigrationState::Successful {
                 // Attempt to prepare on ReadySet
-                let _ = Self::update_noria_prepare(noria, cached_statement).await;
+                if let Err(e) = Self::update_noria_prepare(noria, cached_statement).await {
+                    if e.caused_by_view_not_found() {
+                        // The cache was dropped, so stop retrying the prepare on every execution
+                        self.state.query_status_cache.update_query_migration_state(
+                            cached_statement.as_view_request()?,
+                            MigrationState::Pending,
+                        );
+                    }
+                }
             } else if let MigrationState::Inlined(new_state) = new_migration_state {
                 if let MigrationState::Inlined(ref old_state) = cached_statement.migration_state {
                     // if the epoch has advanced, then we've made changes to the inlined caches so
@@ -1666,11 +1912,16 @@ where
         };
 
         let result = match &cached_statement.prep.inner {
-            PrepareResultInner::Noria(prep) => {
-                Self::execute_noria(noria, prep, params, ticket, &mut event)
-                    .await
-                    .map_err(Into::into)
-            }
+            PrepareResultInner::Noria(prep) => Self::execute_noria(
+                noria,
+                prep,
+                params,
+                ticket,
+                &self.state.upstream_positions,
+                &mut event,
+            )
+            .await
+            .map_err(Into::into),
             PrepareResultInner::Upstream(prep) => {
                 // No inlined caches for this query exist if we are only prepared on upstream.
                 if cached_statement.migration_state.is_inlined() {
@@ -1699,6 +1950,7 @@ where
                     exec_meta,
                     cached_statement.execution_info.as_mut(),
                     ticket,
+                    &self.state.upstream_positions,
                     &mut event,
                 )
                 .await
@@ -1709,11 +1961,39 @@ where
             Self::update_transaction_boundaries(&mut self.state.proxy_state, q.as_ref());
         }
 
+        // Prepared writes aren't identified by the upstream database when they're executed, so
+        // hold onto the statement to record its effect on RYW state below
+        let ryw_statement = if result.is_ok() && self.state.timestamp_client.is_some() {
+            cached_statement.parsed_query.clone()
+        } else {
+            None
+        };
+
+        if let (Some(view_request), Some(processed_query_params), Some(duration)) = (
+            &cached_statement.view_request,
+            &cached_statement.processed_query_params,
+            event.upstream_duration,
+        ) {
+            // Record the same lookup keys a cache for the query would be read with, as for
+            // ad-hoc queries
+            if let Ok(keys) = processed_query_params.make_keys(params) {
+                self.state.query_status_cache.record_upstream_execution(
+                    QueryId::from(view_request),
+                    keys,
+                    duration,
+                );
+            }
+        }
+
         if let Some(e) = event.noria_error.as_ref() {
             if e.caused_by_view_not_found() {
-                // This can happen during cascade execution if the noria query was removed from
-                // another connection
+                // This can happen during cascade execution if the noria query was removed or
+                // renamed from another connection. Mark the statement as pending, so that the next
+                // execution prepares it against ReadySet again under the cache's current name.
                 cached_statement.prep.make_upstream_only();
+                if cached_statement.migration_state == MigrationState::Successful {
+                    cached_statement.migration_state = MigrationState::Pending;
+                }
             } else if e.caused_by_unsupported() {
                 // On an unsupported execute we update the query migration state to be unsupported.
                 self.state.query_status_cache.update_query_migration_state(
@@ -1737,6 +2017,10 @@ where
         });
         log_query(self.query_log_sender.as_ref(), event, self.settings.slowlog);
 
+        if let Some(q) = ryw_statement {
+            self.state.record_ryw_statement(&q);
+        }
+
         result
     }
 
@@ -1854,6 +2138,7 @@ where
         override_schema_search_path: Option<Vec<SqlIdentifier>>,
         always: bool,
         concurrently: bool,
+        options: CacheOptions,
     ) -> ReadySetResult<noria_connector::QueryResult<'static>> {
         // If we have another query with the same name, drop that query first
         if let Some(name) = name {
@@ -1877,6 +2162,7 @@ where
                 override_schema_search_path,
                 always,
                 concurrently,
+                options,
             )
             .await
         {
@@ -2000,6 +2286,37 @@ where
         })
     }
 
+    /// Forwards an `ALTER CACHE` request to noria, and updates the query status cache to reflect
+    /// the cache's new properties
+    async fn alter_cache(
+        &mut self,
+        name: &Relation,
+        definition: &AlterCacheDefinition,
+    ) -> ReadySetResult<noria_connector::QueryResult<'static>> {
+        match definition {
+            AlterCacheDefinition::RenameTo(new_name) => {
+                self.noria.rename_cache(name, new_name).await?;
+            }
+            AlterCacheDefinition::SetAlways(always) => {
+                self.noria.set_cache_always(name, *always).await?;
+                if let Some(view_request) = self.noria.view_create_request_from_name(name).await {
+                    self.state
+                        .query_status_cache
+                        .always_attempt_readyset(&view_request, *always);
+                }
+            }
+            AlterCacheDefinition::SetOptions(options) => {
+                if options.max_staleness.is_some() {
+                    // Views are built with their max_staleness, so it can only be set when the
+                    // cache is created
+                    unsupported!("max_staleness can't be changed by ALTER CACHE");
+                }
+                self.noria.set_cache_options(name, options).await?;
+            }
+        }
+        Ok(noria_connector::QueryResult::Empty)
+    }
+
     /// Forwards a `DROP ALL CACHES` request to noria
     #[instrument(skip(self))]
     async fn drop_all_caches(&mut self) -> ReadySetResult<noria_connector::QueryResult<'static>> {
@@ -2144,12 +2461,93 @@ where
         ))
     }
 
+    /// Responds to a `SHOW CACHE RECOMMENDATIONS` query
+    #[instrument(skip(self))]
+    async fn show_cache_recommendations(
+        &mut self,
+        with_create_statements: bool,
+        limit: Option<u64>,
+    ) -> ReadySetResult<noria_connector::QueryResult<'static>> {
+        let column = |name: &str, column_type| ColumnSchema {
+            column: nom_sql::Column {
+                name: name.into(),
+                table: None,
+            },
+            column_type,
+            base: None,
+        };
+        let mut schema = vec![
+            create_dummy_column("query id"),
+            create_dummy_column("proxied query"),
+            column("count", DfType::UnsignedInt),
+            column("total upstream time (ms)", DfType::Double),
+            column("average upstream latency (ms)", DfType::Double),
+            // Estimated as one row of fixed-size values per distinct key, ignoring the actual
+            // number and size of the rows
+            column("rough estimated cache size (bytes)", DfType::UnsignedInt),
+        ];
+        if with_create_statements {
+            schema.push(create_dummy_column("create cache statement"));
+        }
+        let select_schema = SelectSchema {
+            columns: Cow::Owned(schema.iter().map(|c| c.column.name.clone()).collect()),
+            schema: Cow::Owned(schema),
+        };
+
+        let mut recommendations = vec![];
+        for recommendation in self.state.query_status_cache.cache_recommendations() {
+            let num_columns = self.noria.num_result_columns(&recommendation.query).await;
+            let estimated_size = recommendation.estimated_size(num_columns);
+            recommendations.push((recommendation, estimated_size));
+        }
+        // Recommendations are already ranked by the time caching them would save, so break ties
+        // by the smallest estimated cache size
+        recommendations.sort_by(|(a, a_size), (b, b_size)| {
+            b.upstream_time
+                .cmp(&a.upstream_time)
+                .then(a_size.cmp(b_size))
+        });
+        if let Some(limit) = limit {
+            recommendations.truncate(limit as usize);
+        }
+
+        let data = recommendations
+            .into_iter()
+            .map(|(recommendation, estimated_size)| {
+                let query = recommendation.query.statement.display(DB::SQL_DIALECT);
+                let mut row = vec![
+                    DfValue::from(recommendation.id.to_string()),
+                    DfValue::from(Self::format_query_text(query.to_string())),
+                    DfValue::UnsignedInt(recommendation.executions),
+                    DfValue::Double(recommendation.upstream_time.as_secs_f64() * 1000.0),
+                    DfValue::Double(
+                        recommendation.average_upstream_latency().as_secs_f64() * 1000.0,
+                    ),
+                    DfValue::UnsignedInt(estimated_size as u64),
+                ];
+                if with_create_statements {
+                    row.push(DfValue::from(format!(
+                        "CREATE CACHE {} FROM {}",
+                        recommendation.id, query
+                    )));
+                }
+                row
+            })
+            .collect::<Vec<_>>();
+
+        Ok(noria_connector::QueryResult::from_owned(
+            select_schema,
+            vec![Results::new(data)],
+        ))
+    }
+
     /// Responds to a `SHOW CACHES` query
     async fn show_caches(
         &mut self,
         query_id: Option<&str>,
     ) -> ReadySetResult<noria_connector::QueryResult<'static>> {
         let mut views = self.noria.verbose_views().await?;
+        let memory_usage = self.noria.cache_memory_usage().await?;
 
         // Filter on query ID
         if let Some(unparsed_query_id) = query_id {
@@ -2165,10 +2563,17 @@ where
                 "cache name",
                 "query text",
                 "fallback behavior",
+                "memory usage",
                 "count"
             )
         } else {
-            create_dummy_schema!("query id", "cache name", "query text", "fallback behavior")
+            create_dummy_schema!(
+                "query id",
+                "cache name",
+                "query text",
+                "fallback behavior",
+                "memory usage"
+            )
         };
 
         // Get the cache name for each query from the view cache
@@ -2183,6 +2588,10 @@ where
                 } else {
                     "fallback allowed".into()
                 },
+                memory_usage
+                    .get(&view.name)
+                    .map(|size| DfValue::from(size.to_string()))
+                    .unwrap_or_default(),
             ];
 
             // Append metrics if we have them
@@ -2313,10 +2722,17 @@ where
                 always,
                 concurrently,
                 unparsed_create_cache_statement,
+                options,
             }) => {
                 if !self.allow_cache_ddl {
                     unsupported!("{}", UNSUPPORTED_CACHE_DDL_MSG);
                 }
+                if *always && options.max_staleness.is_some() {
+                    unsupported!(
+                        "ALWAYS caches can't have a max_staleness, since reads from them are never \
+                         proxied upstream"
+                    );
+                }
                 let (stmt, search_path) = match inner {
                     Ok(CacheInner::Statement(st)) => Ok((*st.clone(), None)),
                     Ok(CacheInner::Id(id)) => {
@@ -2365,7 +2781,14 @@ where
                 };
 
                 let res = self
-                    .create_cached_query(name.as_ref(), stmt, search_path, *always, *concurrently)
+                    .create_cached_query(
+                        name.as_ref(),
+                        stmt,
+                        search_path,
+                        *always,
+                        *concurrently,
+                        options.clone(),
+                    )
                     .await;
                 // The extend_recipe may have failed, in which case we should remove our intention
                 // to create this cache. Extend recipe waits a bit and then returns an
@@ -2431,6 +2854,38 @@ where
                 }
                 res
             }
+            SqlQuery::AlterCache(alter_cache) => {
+                if !self.allow_cache_ddl {
+                    unsupported!("{}", UNSUPPORTED_CACHE_DDL_MSG);
+                }
+                let ddl_req = CacheDDLRequest {
+                    unparsed_stmt: alter_cache.display(self.settings.dialect).to_string(),
+                    // Like drop cache statements, alter cache statements only need to resolve the
+                    // cache name, so they don't use a search path
+                    schema_search_path: vec![],
+                    dialect: self.settings.dialect.into(),
+                };
+                self.authority
+                    .add_cache_ddl_request(ddl_req.clone())
+                    .await?;
+                let AlterCacheStatement { name, definition } = alter_cache;
+                let res = self.alter_cache(name, definition).await;
+                if res.is_err() {
+                    let remove_res = retry_with_exponential_backoff(
+                        || async {
+                            let ddl_req = ddl_req.clone();
+                            self.authority.remove_cache_ddl_request(ddl_req).await
+                        },
+                        5,
+                        Duration::from_millis(1),
+                    )
+                    .await;
+                    if remove_res.is_err() {
+                        error!("Failed to remove stored 'alter cache' request. It will be re-run if there is a backwards incompatible upgrade");
+                    }
+                }
+                res
+            }
             SqlQuery::DropAllCaches(_) => {
                 if !self.allow_cache_ddl {
                     unsupported!("{}", UNSUPPORTED_CACHE_DDL_MSG);
@@ -2485,6 +2940,10 @@ where
                 )
                 .await
             }
+            SqlQuery::Show(ShowStatement::CacheRecommendations(options)) => {
+                self.show_cache_recommendations(options.with_create_statements, options.limit)
+                    .await
+            }
             _ => Err(internal_err!("Provided query is not a ReadySet extension")),
         };
 
@@ -2557,7 +3016,9 @@ where
                 create_if_missing: settings.migration_mode == MigrationMode::InRequestPath,
                 processed_query_params,
             };
-            noria.execute_select(ctx, state.ticket.clone(), event).await
+            noria
+                .execute_select(ctx, state.ticket.clone(), &state.upstream_positions, event)
+                .await
         };
 
         if status.execution_info.is_none() {
@@ -2610,6 +3071,12 @@ where
                 match (always, upstream) {
                     (true, _) | (_, None) => Err(noria_err.into()),
                     (false, Some(fallback)) => {
+                        Self::sample_upstream_position_if_needed(
+                            fallback,
+                            &noria_err,
+                            &state.upstream_positions,
+                        )
+                        .await;
                         event.destination = Some(QueryDestination::ReadysetThenUpstream);
                         let _t = event.start_upstream_timer();
                         fallback
@@ -2622,6 +3089,57 @@ where
         }
     }
 
+    /// Executes a `COPY ( query ) TO STDOUT` statement whose query is cached in ReadySet, by
+    /// reading the copied rows from ReadySet. If that fails, the whole statement is proxied to the
+    /// upstream database instead.
+    #[allow(clippy::too_many_arguments)]
+    async fn query_copy_cached<'a>(
+        noria: &'a mut NoriaConnector,
+        mut upstream: Option<&'a mut DB>,
+        state: &mut BackendState<DB>,
+        original_query: &'a str,
+        source: &CopySource,
+        view_request: &ViewCreateRequest,
+        processed_query_params: ProcessedQueryParams,
+        format: CopyFormat,
+        header: bool,
+        event: &mut QueryExecutionEvent,
+    ) -> Result<QueryResult<'a, DB>, DB::Error> {
+        event.destination = Some(QueryDestination::Readyset);
+        let ctx = ExecuteSelectContext::AdHoc {
+            statement: &view_request.statement,
+            create_if_missing: false,
+            processed_query_params,
+        };
+        match noria
+            .execute_select(ctx, state.ticket.clone(), &state.upstream_positions, event)
+            .await
+        {
+            Ok(result) => Ok(QueryResult::Copy {
+                format,
+                header,
+                result,
+            }),
+            Err(noria_err) => {
+                event.set_noria_error(&noria_err);
+                match upstream.as_deref_mut() {
+                    Some(upstream) => {
+                        Self::sample_upstream_position_if_needed(
+                            upstream,
+                            &noria_err,
+                            &state.upstream_positions,
+                        )
+                        .await
+                    }
+                    None => return Err(noria_err.into()),
+                }
+                let res = Self::copy_out_fallback(upstream, original_query, source, event).await;
+                event.destination = Some(QueryDestination::ReadysetThenUpstream);
+                res
+            }
+        }
+    }
+
     /// Checks if noria should try to execute a given select and in the process mutates the
     /// supplied select statement by rewriting it.
     /// Returns whether noria should try the select, along with the query status if it was obtained
@@ -2717,6 +3235,10 @@ where
                         return Err(e.into());
                     }
                     UnsupportedSetMode::Proxy => {
+                        // Turning autocommit back on implicitly commits the open transaction
+                        if on && state.proxy_state == ProxyState::AutocommitOff {
+                            state.end_ryw_transaction(true);
+                        }
                         state.proxy_state.set_autocommit(on);
                     }
                     _ => {}
@@ -2726,6 +3248,22 @@ where
                 trace!(?search_path, "Setting search_path");
                 noria.set_schema_search_path(search_path);
             }
+            SetBehavior::SetReadYourWrites(on) => {
+                trace!(on, "Setting read-your-writes");
+                if on {
+                    // initialize with an empty timestamp, which will be satisfied by any data
+                    // version, unless we already have a ticket from earlier writes
+                    state.ticket.get_or_insert_with(Timestamp::default);
+                    state
+                        .timestamp_client
+                        .get_or_insert_with(TimestampClient::default);
+                } else {
+                    state.ticket = None;
+                    state.timestamp_client = None;
+                    state.ryw_transaction_writes.clear();
+                    state.ryw_pending_writes.clear();
+                }
+            }
         }
 
         Ok(())
@@ -2770,19 +3308,26 @@ where
                         event.destination = Some(QueryDestination::Upstream);
                         let _t = event.start_upstream_timer();
 
-                        // Update ticket if RYW enabled
-                        let query_result = if cfg!(feature = "ryw") {
-                            if let Some(timestamp_service) = &mut state.timestamp_client {
+                        // Update ticket if RYW enabled. Writes inside of a transaction aren't
+                        // visible to the replicator until the transaction commits, so those are
+                        // recorded and added to the ticket once it does. The table is looked up
+                        // before the write is made, so that failing to find it can't turn a
+                        // successful write into an error.
+                        let ryw_key = if state.timestamp_client.is_some()
+                            && !state.proxy_state.writes_need_commit()
+                        {
+                            Self::ryw_write_key(noria, &t).await
+                        } else {
+                            None
+                        };
+
+                        let query_result = match (ryw_key, &mut state.timestamp_client) {
+                            (Some(key), Some(timestamp_service)) => {
                                 let (query_result, identifier) =
                                     upstream.handle_ryw_write(raw_query).await?;
 
-                                // TODO(andrew): Move table name to table index conversion to
-                                // timestamp service https://app.clubhouse.io/readysettech/story/331
-                                let index = noria.node_index_of(t.name.as_str()).await?;
-                                let affected_tables = vec![WriteKey::TableIndex(index)];
-
                                 let new_timestamp = timestamp_service
-                                    .append_write(WriteId::MySqlGtid(identifier), affected_tables)
+                                    .append_write(Self::write_id(identifier), vec![key])
                                     .map_err(|e| internal_err!("{e}"))?;
 
                                 // TODO(andrew, justin): solidify error handling in client
@@ -2794,11 +3339,14 @@ where
                                 state.ticket =
                                     Some(Timestamp::join(current_ticket, &new_timestamp));
                                 Ok(query_result)
-                            } else {
-                                upstream.query(raw_query).await
                             }
-                        } else {
-                            upstream.query(raw_query).await
+                            _ => {
+                                let query_result = upstream.query(raw_query).await;
+                                if query_result.is_ok() && state.proxy_state.writes_need_commit() {
+                                    state.record_ryw_write(&t);
+                                }
+                                query_result
+                            }
                         };
 
                         query_result.map(QueryResult::Upstream)
@@ -2820,6 +3368,16 @@ where
                     SqlQuery::RenameTable(_) => {
                         unsupported!("{} not yet supported", query.query_type());
                     }
+                    // The upstream database doesn't know about our own session variables
+                    SqlQuery::Set(ref s)
+                        if matches!(
+                            Handler::handle_set_statement(s),
+                            SetBehavior::SetReadYourWrites(_)
+                        ) =>
+                    {
+                        event.sql_type = SqlQueryType::Other;
+                        Ok(QueryResult::Noria(noria_connector::QueryResult::Empty))
+                    }
                     SqlQuery::Set(_)
                     | SqlQuery::CompoundSelect(_)
                     | SqlQuery::Show(_)
@@ -2829,19 +3387,25 @@ where
                     }
 
                     SqlQuery::StartTransaction(_) | SqlQuery::Commit(_) | SqlQuery::Rollback(_) => {
-                        Self::handle_transaction_boundaries(
+                        let res = Self::handle_transaction_boundaries(
                             Some(upstream),
                             &mut state.proxy_state,
                             &query,
                         )
-                        .await
+                        .await;
+                        if res.is_ok() {
+                            state.record_ryw_statement(&query);
+                        }
+                        res
                     }
                     SqlQuery::CreateCache(_)
+                    | SqlQuery::AlterCache(_)
                     | SqlQuery::Deallocate(_)
                     | SqlQuery::DropCache(_)
                     | SqlQuery::DropAllCaches(_)
                     | SqlQuery::DropAllProxiedQueries(_)
-                    | SqlQuery::Explain(_) => {
+                    | SqlQuery::Explain(_)
+                    | SqlQuery::Copy(_) => {
                         unreachable!("path returns prior")
                     }
                 }
@@ -2909,6 +3473,8 @@ where
         let query_log_sender = self.query_log_sender.clone();
         let slowlog = self.settings.slowlog;
 
+        Self::update_ryw_ticket(&mut self.noria, self.upstream.as_mut(), &mut self.state).await;
+
         let parse_result = {
             let _t = event.start_parse_timer();
             self.parse_query(query)
@@ -2974,9 +3540,12 @@ where
                 .map(Into::into)
                 .map_err(Into::into),
             // SET autocommit=1 needs to be handled explicitly or it will end up getting proxied in
-            // most cases.
+            // most cases, and SETs of our own session variables must never be proxied.
             Ok(SqlQuery::Set(s))
-                if Handler::handle_set_statement(&s) == SetBehavior::SetAutocommit(true) =>
+                if matches!(
+                    Handler::handle_set_statement(&s),
+                    SetBehavior::SetAutocommit(true) | SetBehavior::SetReadYourWrites(_)
+                ) =>
             {
                 Self::query_adhoc_non_select(
                     &mut self.noria,
@@ -3024,7 +3593,15 @@ where
                     self.noria_should_try_select(&mut view_request);
                 let processed_query_params = processed_query_params?;
 
-                if noria_should_try {
+                // Keep track of the keys of queries that might be proxied, so that we can estimate
+                // the size of a cache for them
+                let keys = if status.as_ref().is_some_and(|s| s.is_successful()) {
+                    None
+                } else {
+                    processed_query_params.make_keys::<DfValue>(&[]).ok()
+                };
+
+                let res = if noria_should_try {
                     Self::query_adhoc_select(
                         &mut self.noria,
                         self.upstream.as_mut(),
@@ -3039,11 +3616,69 @@ where
                     .await
                 } else {
                     Self::query_fallback(self.upstream.as_mut(), query, &mut event).await
+                };
+
+                if let (Some(keys), Some(duration)) = (keys, event.upstream_duration) {
+                    self.state.query_status_cache.record_upstream_execution(
+                        QueryId::from(&view_request),
+                        keys,
+                        duration,
+                    );
                 }
+
+                res
+            }
+            // `COPY` of a cached query is served from ReadySet; all other `COPY` statements are
+            // proxied upstream
+            Ok(SqlQuery::Copy(CopyStatement {
+                source: CopySource::Query(stmt),
+                format,
+                header,
+            })) if !self.state.proxy_state.should_proxy() => {
+                // Kept for proxying, since the statement in the view request is rewritten
+                let source = CopySource::Query(stmt.clone());
+                let mut view_request =
+                    ViewCreateRequest::new(*stmt, self.noria.schema_search_path().to_owned());
+
+                event.sql_type = SqlQueryType::Read;
+                event.query_id = Some(QueryId::from(&view_request));
+
+                let (noria_should_try, status, processed_query_params) =
+                    self.noria_should_try_select(&mut view_request);
+                let processed_query_params = processed_query_params?;
+                let cached =
+                    status.is_some_and(|s| s.migration_state == MigrationState::Successful);
+
+                if noria_should_try && cached {
+                    Self::query_copy_cached(
+                        &mut self.noria,
+                        self.upstream.as_mut(),
+                        &mut self.state,
+                        query,
+                        &source,
+                        &view_request,
+                        processed_query_params,
+                        format,
+                        header,
+                        &mut event,
+                    )
+                    .await
+                } else {
+                    Self::copy_out_fallback(self.upstream.as_mut(), query, &source, &mut event)
+                        .await
+                }
+            }
+            Ok(SqlQuery::Copy(CopyStatement { source, .. })) => {
+                event.sql_type = SqlQueryType::Read;
+                Self::copy_out_fallback(self.upstream.as_mut(), query, &source, &mut event).await
             }
             Ok(SqlQuery::Deallocate(stmt)) => Ok(Self::handle_deallocate_statement(stmt)),
-            Ok(_) if self.state.proxy_state.should_proxy() => {
-                Self::query_fallback(self.upstream.as_mut(), query, &mut event).await
+            Ok(parsed_query) if self.state.proxy_state.should_proxy() => {
+                let res = Self::query_fallback(self.upstream.as_mut(), query, &mut event).await;
+                if res.is_ok() {
+                    self.state.record_ryw_statement(&parsed_query);
+                }
+                res
             }
             Ok(parsed_query) => {
                 Self::query_adhoc_non_select(
@@ -3078,6 +3713,11 @@ where
         self.upstream.is_some()
     }
 
+    /// Returns a reference to the upstream database, if we are using fallback.
+    pub fn upstream(&self) -> Option<&DB> {
+        self.upstream.as_ref()
+    }
+
     /// If we are using fallback, this will return the database that was in the original connection
     /// string, if it exists, otherwise it will return None. If we are not using fallback this will
     /// always return None.
@@ -1,19 +1,22 @@
 use std::borrow::Cow;
 use std::cmp::Reverse;
-use std::collections::{BTreeMap, HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 use std::convert::{TryFrom, TryInto};
 use std::sync::{atomic, Arc};
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use itertools::Itertools;
 use nom_sql::{
-    self, ColumnConstraint, DeleteStatement, DialectDisplay, Expr, InsertStatement, Relation,
-    SqlIdentifier, SqlQuery, TruncateStatement, UnaryOperator, UpdateStatement,
+    self, CacheOptions, ColumnConstraint, DeleteStatement, DialectDisplay, Expr,
+    FieldDefinitionExpr, InsertStatement, Relation, SqlIdentifier, SqlQuery, TableExpr,
+    TruncateStatement, UnaryOperator, UpdateStatement,
 };
+use parking_lot::Mutex;
 use readyset_client::consistency::Timestamp;
+use readyset_client::debug::info::NodeMaterializedSize;
 use readyset_client::internal::LocalNodeIndex;
 use readyset_client::query::QueryId;
-use readyset_client::recipe::changelist::{Change, ChangeList, IntoChanges};
+use readyset_client::recipe::changelist::{Change, ChangeList, CreateCache, IntoChanges};
 use readyset_client::recipe::CacheExpr;
 use readyset_client::results::{ResultIterator, Results};
 use readyset_client::{
@@ -30,7 +33,7 @@ use readyset_sql_passes::adapter_rewrites::{self, AdapterRewriteParams, Processe
 use readyset_util::redacted::Sensitive;
 use readyset_util::shared_cache::{self, LocalCache};
 use tokio::sync::RwLock;
-use tracing::{error, info, instrument, trace, warn};
+use tracing::{debug, error, info, instrument, trace, warn};
 
 use crate::backend::SelectSchema;
 use crate::utils;
@@ -334,6 +337,44 @@ impl ReadBehavior {
     }
 }
 
+/// The number of samples of the upstream database's replication position kept by
+/// [`UpstreamPositions`]
+const MAX_UPSTREAM_POSITION_SAMPLES: usize = 32;
+
+/// Recent samples of the upstream database's replication position, used to check reads from
+/// caches with a maximum staleness.
+///
+/// If a reader has applied every write up to a position the upstream database was at some time
+/// `t`, then reads from that reader are at most `now - t` stale.
+///
+/// This is shared by every connection to the adapter, so that a sample taken by one connection
+/// can be used to check reads made by all of them.
+#[derive(Debug, Default)]
+pub struct UpstreamPositions {
+    /// (time the sample was taken, position as a timestamp value), oldest first
+    samples: Mutex<VecDeque<(Instant, u64)>>,
+}
+
+impl UpstreamPositions {
+    /// Records the upstream database's replication position, as a timestamp value, as of now
+    pub fn record(&self, position: u64) {
+        let mut samples = self.samples.lock();
+        if samples.len() == MAX_UPSTREAM_POSITION_SAMPLES {
+            samples.pop_front();
+        }
+        samples.push_back((Instant::now(), position));
+    }
+
+    /// Returns the oldest position sampled at most `max_staleness` ago, if any
+    pub fn position_within(&self, max_staleness: Duration) -> Option<u64> {
+        self.samples
+            .lock()
+            .iter()
+            .find(|(taken_at, _)| taken_at.elapsed() <= max_staleness)
+            .map(|(_, position)| *position)
+    }
+}
+
 /// Provides the necessary context to execute a select statement against noria, either for a
 /// prepared or an ad-hoc query
 #[allow(clippy::large_enum_variant)]
@@ -540,6 +581,12 @@ impl NoriaConnector {
         self.inner.get_mut()?.noria.verbose_views().await
     }
 
+    pub(crate) async fn cache_memory_usage(
+        &mut self,
+    ) -> ReadySetResult<HashMap<Relation, NodeMaterializedSize>> {
+        self.inner.get_mut()?.noria.cache_memory_usage().await
+    }
+
     pub(crate) async fn list_create_cache_stmts(&mut self) -> ReadySetResult<Vec<String>> {
         Ok(self
             .verbose_views()
@@ -970,6 +1017,7 @@ impl NoriaConnector {
         override_schema_search_path: Option<Vec<SqlIdentifier>>,
         always: bool,
         concurrently: bool,
+        options: CacheOptions,
     ) -> ReadySetResult<Option<u64>> {
         let name = name
             .cloned()
@@ -977,7 +1025,12 @@ impl NoriaConnector {
         let schema_search_path =
             override_schema_search_path.unwrap_or_else(|| self.schema_search_path.clone());
         let changelist = ChangeList::from_change(
-            Change::create_cache(name.clone(), statement.clone(), always),
+            Change::CreateCache(CreateCache {
+                name: Some(name.clone()),
+                statement: Box::new(statement.clone()),
+                always,
+                options,
+            }),
             self.dialect,
         )
         .with_schema_search_path(schema_search_path.clone());
@@ -1128,6 +1181,43 @@ impl NoriaConnector {
             .cloned()
     }
 
+    /// Checks that the cache with the given name still exists, and fetches its view. If no cache
+    /// with that name exists anymore (for example because it was renamed, possibly through a
+    /// different adapter), the stale name is removed from the view name cache and the name of the
+    /// cache for `statement` is resolved again from the server.
+    async fn refresh_view_name(
+        &mut self,
+        qname: Relation,
+        statement: &nom_sql::SelectStatement,
+        is_prepared: bool,
+        override_schema_search_path: Option<&[SqlIdentifier]>,
+    ) -> ReadySetResult<Relation> {
+        let view_failed = self.failed_views.take(&qname).is_some();
+        match self
+            .inner
+            .get_mut()?
+            .get_noria_view(&qname, view_failed)
+            .await
+        {
+            Ok(_) => Ok(qname),
+            Err(error) if error.caused_by_view_not_found() => {
+                debug!(
+                    name = %qname.display_unquoted(),
+                    "Cache not found, resolving its name again"
+                );
+                self.view_name_cache.remove_val(&qname).await;
+                self.get_view_name_cached(
+                    statement,
+                    is_prepared,
+                    false,
+                    override_schema_search_path.map(|p| p.to_vec()),
+                )
+                .await
+            }
+            Err(error) => Err(error),
+        }
+    }
+
     /// Make a request to ReadySet to drop the query with the given name, and remove it from all
     /// internal state.
     pub async fn drop_view(&mut self, name: &Relation) -> ReadySetResult<u64> {
@@ -1139,6 +1229,47 @@ impl NoriaConnector {
         Ok(result)
     }
 
+    /// Make a request to ReadySet to change the [`CacheOptions`] of the cache with the given name
+    pub async fn set_cache_options(
+        &mut self,
+        name: &Relation,
+        options: &CacheOptions,
+    ) -> ReadySetResult<()> {
+        noria_await!(
+            self.inner.get_mut()?,
+            self.inner.get_mut()?.noria.set_cache_options(name, options)
+        )
+    }
+
+    /// Make a request to ReadySet to rename the cache with the given name to `new_name`, and update
+    /// the cached name for its query
+    pub async fn rename_cache(
+        &mut self,
+        name: &Relation,
+        new_name: &Relation,
+    ) -> ReadySetResult<()> {
+        noria_await!(
+            self.inner.get_mut()?,
+            self.inner.get_mut()?.noria.rename_cache(name, new_name)
+        )?;
+        if let Some(view_request) = self.view_name_cache.key_for_val(name).await {
+            self.view_name_cache.remove_val(name).await;
+            self.view_name_cache
+                .insert(view_request, new_name.clone())
+                .await;
+        }
+        Ok(())
+    }
+
+    /// Make a request to ReadySet to set whether the cache with the given name should always be
+    /// served from ReadySet, even inside of transactions
+    pub async fn set_cache_always(&mut self, name: &Relation, always: bool) -> ReadySetResult<()> {
+        noria_await!(
+            self.inner.get_mut()?,
+            self.inner.get_mut()?.noria.set_cache_always(name, always)
+        )
+    }
+
     /// Make a request to ReadySet to drop all cached queries, and empty all internal state
     pub async fn drop_all_caches(&mut self) -> ReadySetResult<()> {
         noria_await!(
@@ -1156,6 +1287,73 @@ impl NoriaConnector {
         self.view_name_cache.key_for_val(name).await
     }
 
+    /// Returns the number of columns returned by the given query, looking up the columns of the
+    /// tables selected from with a wildcard. Any of those tables which ReadySet doesn't know about
+    /// (and any subqueries) are counted as a single column.
+    pub async fn num_result_columns(&mut self, query: &ViewCreateRequest) -> usize {
+        let statement = &query.statement;
+        let all_tables = statement
+            .tables
+            .iter()
+            .chain(statement.join.iter().flat_map(|j| j.right.table_exprs()))
+            .collect::<Vec<_>>();
+        let mut num_columns = 0;
+        for field in &statement.fields {
+            let tables = match field {
+                FieldDefinitionExpr::Expr { .. } => {
+                    num_columns += 1;
+                    continue;
+                }
+                FieldDefinitionExpr::All => all_tables.clone(),
+                FieldDefinitionExpr::AllInTable(name) => all_tables
+                    .iter()
+                    .copied()
+                    .filter(|table| match &table.alias {
+                        Some(alias) => name.schema.is_none() && *alias == name.name,
+                        None => table.inner.as_table().is_some_and(|t| {
+                            t.name == name.name
+                                && (name.schema.is_none() || t.schema == name.schema)
+                        }),
+                    })
+                    .collect(),
+            };
+            for table in tables {
+                num_columns += self
+                    .num_table_columns(table, &query.schema_search_path)
+                    .await
+                    .unwrap_or(1);
+            }
+        }
+        num_columns
+    }
+
+    /// Returns the number of columns in the given table, resolving an unqualified table name
+    /// against `schema_search_path`, or `None` if it isn't a table ReadySet knows about
+    async fn num_table_columns(
+        &mut self,
+        table: &TableExpr,
+        schema_search_path: &[SqlIdentifier],
+    ) -> Option<usize> {
+        let relation = table.inner.as_table()?;
+        let candidates = match &relation.schema {
+            Some(_) => vec![relation.clone()],
+            None => schema_search_path
+                .iter()
+                .map(|schema| Relation {
+                    schema: Some(schema.clone()),
+                    name: relation.name.clone(),
+                })
+                .collect(),
+        };
+        let inner = self.inner.get_mut().ok()?;
+        for candidate in candidates {
+            if let Ok(table) = inner.get_noria_table(&candidate).await {
+                return Some(table.columns().len());
+            }
+        }
+        None
+    }
+
     async fn do_insert(
         &mut self,
         q: &InsertStatement,
@@ -1440,17 +1638,20 @@ impl NoriaConnector {
                 &statement,
                 true,
                 create_if_not_exist,
-                override_schema_search_path,
+                override_schema_search_path.clone(),
             )
             .await?;
-
-        let view_failed = self.failed_views.take(&qname).is_some();
-        let getter = self
-            .inner
-            .get_mut()?
-            .get_noria_view(&qname, view_failed)
+        let qname = self
+            .refresh_view_name(
+                qname,
+                &statement,
+                true,
+                override_schema_search_path.as_deref(),
+            )
             .await?;
 
+        let getter = self.inner.get_mut()?.get_noria_view(&qname, false).await?;
+
         // extract result schema
         let getter_schema = match getter {
             View::MultipleReused(_) => None,
@@ -1497,6 +1698,7 @@ impl NoriaConnector {
         &mut self,
         ctx: ExecuteSelectContext<'_>,
         ticket: Option<Timestamp>,
+        upstream_positions: &UpstreamPositions,
         event: &mut readyset_client_metrics::QueryExecutionEvent,
     ) -> ReadySetResult<QueryResult<'_>> {
         let start = Instant::now();
@@ -1521,6 +1723,7 @@ impl NoriaConnector {
                 let name = self
                     .get_view_name_cached(statement, false, create_if_missing, None)
                     .await?;
+                let name = self.refresh_view_name(name, statement, false, None).await?;
                 (
                     Cow::Owned(name),
                     Cow::Owned(processed_query_params),
@@ -1541,6 +1744,7 @@ impl NoriaConnector {
             processed_query_params.as_ref(),
             params,
             ticket,
+            upstream_positions,
             self.read_behavior,
             self.read_request_handler.as_mut(),
             self.dialect,
@@ -1633,20 +1837,37 @@ fn build_view_query<'a>(
     processed_query_params: &ProcessedQueryParams,
     params: &[DfValue],
     ticket: Option<Timestamp>,
+    upstream_positions: &UpstreamPositions,
     read_behavior: ReadBehavior,
     dialect: Dialect,
 ) -> ReadySetResult<Option<(&'a mut ReaderHandle, ViewQuery)>> {
     let (limit, offset) = processed_query_params.limit_offset_params(params)?;
     let raw_keys = processed_query_params.make_keys(params)?;
 
-    getter.build_view_query(
-        raw_keys,
-        limit,
-        offset,
-        ticket,
-        read_behavior.is_blocking(),
-        dialect,
-    )
+    // A read-your-writes ticket is only useful if we wait for the reader to catch up to it,
+    // rather than returning a miss as soon as the reader is found to be behind
+    let wait_for_ticket = ticket.as_ref().is_some_and(|ticket| !ticket.map.is_empty());
+
+    // Reads from a cache with a maximum staleness require the reader to have caught up to a
+    // position the upstream database was at within that bound. Readers that haven't yet return a
+    // miss rather than blocking, so that the read is proxied upstream instead.
+    let staleness_ticket = getter
+        .max_staleness()
+        .map(|max_staleness| {
+            upstream_positions
+                .position_within(max_staleness)
+                .map(|position| getter.timestamp_at(position))
+                .ok_or(ReadySetError::UpstreamPositionUnavailable)
+        })
+        .transpose()?;
+    let blocking_read =
+        wait_for_ticket || (read_behavior.is_blocking() && staleness_ticket.is_none());
+    let ticket = match (ticket, staleness_ticket) {
+        (Some(ticket), Some(staleness_ticket)) => Some(Timestamp::join(&ticket, &staleness_ticket)),
+        (ticket, staleness_ticket) => ticket.or(staleness_ticket),
+    };
+
+    getter.build_view_query(raw_keys, limit, offset, ticket, blocking_read, dialect)
 }
 
 struct ReadResult<'a> {
@@ -1664,6 +1885,7 @@ async fn do_read<'a>(
     processed_query_params: &ProcessedQueryParams,
     params: &[DfValue],
     ticket: Option<Timestamp>,
+    upstream_positions: &UpstreamPositions,
     read_behavior: ReadBehavior,
     read_request_handler: Option<&'a mut ReadRequestHandler>,
     dialect: Dialect,
@@ -1673,6 +1895,7 @@ async fn do_read<'a>(
         processed_query_params,
         params,
         ticket,
+        upstream_positions,
         read_behavior,
         dialect,
     )? {
@@ -180,6 +180,7 @@ impl MigrationHandler {
                         Some(query.query().schema_search_path.clone()),
                         /* always */ false,
                         /* concurrently */ false,
+                        Default::default(),
                     )
                     .await;
                 // Inform the query status cache of completed migrations
@@ -302,6 +303,7 @@ impl MigrationHandler {
                 Some(view_request.schema_search_path.clone()),
                 false,
                 false,
+                Default::default(),
             )
             .await?;
         Ok(())
@@ -14,6 +14,9 @@ pub enum SetBehavior {
     SetAutocommit(bool),
     /// This `SET` statement represents the current schema search path being changed
     SetSearchPath(Vec<SqlIdentifier>),
+    /// This `SET` statement turns read-your-writes consistency on or off for the session. It is
+    /// handled entirely by ReadySet, and never proxied upstream.
+    SetReadYourWrites(bool),
 }
 
 impl SetBehavior {
@@ -28,6 +31,13 @@ impl SetBehavior {
     }
 }
 
+/// The name of the session variable that turns read-your-writes consistency on or off, with eg
+/// `SET readyset_read_your_writes = 1`.
+///
+/// With read-your-writes enabled, a read from a cache issued after a write through the same
+/// session waits until the cache reflects that write.
+pub const READ_YOUR_WRITES_VARIABLE: &str = "readyset_read_your_writes";
+
 /// A trait describing the behavior of how specific queries should be handled by a noria-client
 /// [`Backend`].
 pub trait QueryHandler: Sized + Send {
@@ -18,10 +18,15 @@ use readyset_client::metrics::recorded;
 use readyset_client::query::*;
 use readyset_client::ViewCreateRequest;
 use readyset_data::DfValue;
+use readyset_util::hash::hash;
 use tracing::{error, warn};
 
 pub const DEFAULT_QUERY_STATUS_CAPACITY: usize = 100_000;
 
+/// The maximum number of distinct keys we remember for each proxied query, for the purposes of
+/// estimating the size of a cache for that query
+const MAX_TRACKED_KEYS_PER_QUERY: usize = 10_000;
+
 /// A metadata cache for all queries that have been processed by this
 /// adapter. Thread-safe.
 #[derive(Debug)]
@@ -47,6 +52,59 @@ pub struct QueryStatusCache {
     ///
     /// Currently unused.
     enable_experimental_placeholder_inlining: bool,
+
+    /// Statistics about the executions of each query against the upstream database, used to
+    /// recommend which queries should be cached.
+    ///
+    /// Only kept for queries in the persistent status cache, so that this is bounded by its
+    /// capacity.
+    proxied_query_stats: DashMap<QueryId, ProxiedQueryStats, ahash::RandomState>,
+}
+
+/// Statistics about the executions of a single query against the upstream database
+#[derive(Debug, Default)]
+struct ProxiedQueryStats {
+    /// The number of times the query was executed against the upstream database
+    executions: u64,
+    /// The total amount of time spent executing the query against the upstream database
+    upstream_time: Duration,
+    /// Hashes of the distinct keys the query was executed with, up to
+    /// [`MAX_TRACKED_KEYS_PER_QUERY`]
+    keys: HashSet<u64>,
+}
+
+/// A supported, proxied query which we recommend caching, along with the statistics it was ranked
+/// by. Returned by [`QueryStatusCache::cache_recommendations`].
+#[derive(Debug, Clone)]
+pub struct CacheRecommendation {
+    /// The query id
+    pub id: QueryId,
+    /// The query
+    pub query: Arc<ViewCreateRequest>,
+    /// The number of times the query was executed against the upstream database
+    pub executions: u64,
+    /// The total amount of time spent executing the query against the upstream database, which is
+    /// the time that caching the query would save
+    pub upstream_time: Duration,
+    /// The number of distinct keys the query was executed with, up to
+    /// [`MAX_TRACKED_KEYS_PER_QUERY`]
+    pub distinct_keys: usize,
+}
+
+impl CacheRecommendation {
+    /// Returns a rough estimate of the size of a cache for the query, in bytes, given the number of
+    /// columns it returns, assuming one row per distinct key the query was executed with
+    pub fn estimated_size(&self, num_columns: usize) -> usize {
+        // Queries without any parameters still have a single (empty) key
+        self.distinct_keys.max(1) * num_columns * std::mem::size_of::<DfValue>()
+    }
+
+    /// Returns the average latency of executing the query against the upstream database
+    pub fn average_upstream_latency(&self) -> Duration {
+        self.upstream_time
+            .checked_div(self.executions.try_into().unwrap_or(u32::MAX))
+            .unwrap_or_default()
+    }
 }
 
 #[derive(Debug)]
@@ -84,19 +142,26 @@ impl PersistentStatusCacheHandle {
         }
     }
 
-    fn insert_with_status(&self, q: Query, id: QueryId, status: QueryStatus) {
+    /// Inserts a query with the given status, returning the id of the query that was evicted to
+    /// make room for it, if any
+    fn insert_with_status(&self, q: Query, id: QueryId, status: QueryStatus) -> Option<QueryId> {
         // Deadlock avoidance: If `with_mut_status` is passed a Fn that tries to write the RwLock,
         // it will result in a deadlock.
         match self.statuses.try_write_for(Duration::from_millis(10)) {
             Some(mut status_guard) => {
-                status_guard.put(id, (q, status));
+                let evicted = status_guard
+                    .push(id, (q, status))
+                    .map(|(evicted_id, _)| evicted_id)
+                    .filter(|evicted_id| *evicted_id != id);
                 gauge!(
                     recorded::QUERY_STATUS_CACHE_PERSISTENT_CACHE_SIZE,
                     status_guard.len() as f64
                 );
+                evicted
             }
             None => {
-                warn!(query_id=%id, "Avoiding deadlock when trying to insert")
+                warn!(query_id=%id, "Avoiding deadlock when trying to insert");
+                None
             }
         }
     }
@@ -268,6 +333,7 @@ impl QueryStatusCache {
             persistent_handle: Default::default(),
             style: MigrationStyle::InRequestPath,
             enable_experimental_placeholder_inlining: false,
+            proxied_query_stats: Default::default(),
         }
     }
 
@@ -279,6 +345,7 @@ impl QueryStatusCache {
             persistent_handle: PersistentStatusCacheHandle::with_capacity(capacity),
             style: MigrationStyle::InRequestPath,
             enable_experimental_placeholder_inlining: false,
+            proxied_query_stats: Default::default(),
         }
     }
 
@@ -335,7 +402,9 @@ impl QueryStatusCache {
         };
         let id = QueryId::from(&q);
         self.id_to_status.insert(id, status.clone());
-        self.persistent_handle.insert_with_status(q, id, status);
+        if let Some(evicted_id) = self.persistent_handle.insert_with_status(q, id, status) {
+            self.proxied_query_stats.remove(&evicted_id);
+        }
         gauge!(
             recorded::QUERY_STATUS_CACHE_SIZE,
             self.id_to_status.len() as f64
@@ -644,6 +713,24 @@ impl QueryStatusCache {
         })
     }
 
+    /// Updates the always flag of the query with the given id to match the one stored for its
+    /// cache in the controller. Like [`Self::always_attempt_readyset`], will not apply the flag to
+    /// unsupported queries or queries that have not been registered.
+    pub fn sync_always_attempt_readyset(&self, id: &QueryId, always: bool) {
+        let needs_update = |s: &QueryStatus| {
+            s.migration_state != MigrationState::Unsupported && s.always != always
+        };
+        match self.id_to_status.get_mut(id) {
+            Some(mut s) if needs_update(&s) => s.always = always,
+            // Avoid taking the persistent write lock when nothing changed
+            _ => return,
+        }
+        let mut statuses = self.persistent_handle.statuses.write();
+        if let Some((_, s)) = statuses.get_mut(id).filter(|(_, s)| needs_update(s)) {
+            s.always = always;
+        }
+    }
+
     /// Updates a queries status to `status` unless the queries migration state was
     /// `MigrationState::Unsupported`. An unsupported query cannot currently become supported once
     /// again.
@@ -694,6 +781,7 @@ impl QueryStatusCache {
     pub fn clear_proxied_queries(&self) {
         self.id_to_status
             .retain(|_query_id, status| status.is_successful());
+        self.proxied_query_stats.clear();
 
         let mut statuses = self.persistent_handle.statuses.write();
         let keys_to_remove: Vec<QueryId> = statuses
@@ -829,6 +917,75 @@ impl QueryStatusCache {
         self.persistent_handle.deny_list(self.style)
     }
 
+    /// Records that the query with the given id was executed against the upstream database with
+    /// the given lookup keys, taking `duration`.
+    ///
+    /// Does nothing if the query isn't in the persistent status cache.
+    pub fn record_upstream_execution<K>(
+        &self,
+        id: QueryId,
+        keys: impl IntoIterator<Item = K>,
+        duration: Duration,
+    ) where
+        K: Hash,
+    {
+        // Statistics are removed when their query is evicted, so if the query already has some
+        // it's still in the persistent status cache. That way only the first execution of each
+        // query takes the persistent status cache's lock, rather than every proxied execution
+        // contending with inserts.
+        let mut stats = match self.proxied_query_stats.get_mut(&id) {
+            Some(stats) => stats,
+            None => {
+                // Hold the lock while adding the query's statistics, so that the query can't be
+                // evicted (which removes its statistics) in the meantime
+                let statuses = self.persistent_handle.statuses.read();
+                if !statuses.contains(&id) {
+                    return;
+                }
+                self.proxied_query_stats.entry(id).or_default()
+            }
+        };
+        stats.executions += 1;
+        stats.upstream_time += duration;
+        for key in keys {
+            if stats.keys.len() >= MAX_TRACKED_KEYS_PER_QUERY {
+                break;
+            }
+            stats.keys.insert(hash(&key));
+        }
+    }
+
+    /// Returns the supported queries in the deny list which have been executed against the
+    /// upstream database, ranked by the total time spent executing them upstream (the time that
+    /// caching them would save), and then by the number of distinct keys they were executed with.
+    pub fn cache_recommendations(&self) -> Vec<CacheRecommendation> {
+        let mut recommendations = self
+            .deny_list()
+            .into_iter()
+            .filter(|q| q.status.migration_state.is_supported())
+            .filter_map(|DeniedQuery { id, query, .. }| {
+                let Query::Parsed(query) = query else {
+                    return None;
+                };
+                let stats = self.proxied_query_stats.get(&id)?;
+                Some(CacheRecommendation {
+                    id,
+                    executions: stats.executions,
+                    upstream_time: stats.upstream_time,
+                    distinct_keys: stats.keys.len(),
+                    query,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        recommendations.sort_by(|a, b| {
+            b.upstream_time
+                .cmp(&a.upstream_time)
+                .then(a.distinct_keys.cmp(&b.distinct_keys))
+        });
+        recommendations
+    }
+
     /// Returns a query given a query hash
     pub fn query(&self, id: &str) -> Option<Query> {
         let id = id.parse::<QueryId>().ok()?;
@@ -956,6 +1113,33 @@ mod tests {
         assert_eq!(r2, q2.into());
     }
 
+    #[test]
+    fn sync_always_attempt_readyset_by_controller_query_id() {
+        let cache = QueryStatusCache::new();
+        let q = ViewCreateRequest::new(select_statement("SELECT * FROM t1").unwrap(), vec![]);
+        cache.update_query_migration_state(&q, MigrationState::Successful);
+
+        // The controller identifies caches by the id of their statement and search path
+        let id = QueryId::from_select(&q.statement, &q.schema_search_path);
+        let persisted_always = || {
+            cache
+                .persistent_handle
+                .statuses
+                .write()
+                .get(&id)
+                .unwrap()
+                .1
+                .always
+        };
+        cache.sync_always_attempt_readyset(&id, true);
+        assert!(cache.query_status(&q).always);
+        assert!(persisted_always());
+
+        cache.sync_always_attempt_readyset(&id, false);
+        assert!(!cache.query_status(&q).always);
+        assert!(!persisted_always());
+    }
+
     #[test]
     fn query_is_allowed() {
         let cache = QueryStatusCache::new();
@@ -1289,6 +1473,101 @@ mod tests {
         });
     }
 
+    #[test]
+    fn cache_recommendations() {
+        let cache = QueryStatusCache::new().style(MigrationStyle::Explicit);
+        let frequent = ViewCreateRequest::new(
+            select_statement("SELECT a, b FROM t1 WHERE id = ?").unwrap(),
+            vec![],
+        );
+        let slow = ViewCreateRequest::new(
+            select_statement("SELECT a FROM t1 WHERE b = ?").unwrap(),
+            vec![],
+        );
+        let unsupported =
+            ViewCreateRequest::new(select_statement("SELECT y FROM t2").unwrap(), vec![]);
+        let cached = ViewCreateRequest::new(select_statement("SELECT x FROM t2").unwrap(), vec![]);
+
+        cache.update_query_migration_state(&frequent, MigrationState::DryRunSucceeded);
+        cache.update_query_migration_state(&slow, MigrationState::DryRunSucceeded);
+        cache.update_query_migration_state(&unsupported, MigrationState::Unsupported);
+        cache.update_query_migration_state(&cached, MigrationState::Successful);
+
+        for i in 0..10 {
+            cache.record_upstream_execution(
+                QueryId::from(&frequent),
+                [[DfValue::from(i % 2)]],
+                Duration::from_millis(1),
+            );
+        }
+        cache.record_upstream_execution(
+            QueryId::from(&slow),
+            [[DfValue::from(1)]],
+            Duration::from_millis(20),
+        );
+        for q in [&unsupported, &cached] {
+            cache.record_upstream_execution(
+                QueryId::from(q),
+                Vec::<Vec<DfValue>>::new(),
+                Duration::from_secs(1),
+            );
+        }
+
+        let recommendations = cache.cache_recommendations();
+        assert_eq!(recommendations.len(), 2);
+
+        assert_eq!(recommendations[0].id, QueryId::from(&slow));
+        assert_eq!(recommendations[0].executions, 1);
+        assert_eq!(
+            recommendations[0].average_upstream_latency(),
+            Duration::from_millis(20)
+        );
+        assert_eq!(recommendations[0].distinct_keys, 1);
+        assert_eq!(
+            recommendations[0].estimated_size(1),
+            std::mem::size_of::<DfValue>()
+        );
+
+        assert_eq!(recommendations[1].id, QueryId::from(&frequent));
+        assert_eq!(recommendations[1].executions, 10);
+        assert_eq!(recommendations[1].upstream_time, Duration::from_millis(10));
+        assert_eq!(recommendations[1].distinct_keys, 2);
+        assert_eq!(
+            recommendations[1].estimated_size(2),
+            2 * 2 * std::mem::size_of::<DfValue>()
+        );
+
+        cache.clear_proxied_queries();
+        assert!(cache.cache_recommendations().is_empty());
+    }
+
+    #[test]
+    fn proxied_query_stats_bounded_by_capacity() {
+        let cache = QueryStatusCache::with_capacity(1).style(MigrationStyle::Explicit);
+        let q1 = ViewCreateRequest::new(select_statement("SELECT a FROM t1").unwrap(), vec![]);
+        let q2 = ViewCreateRequest::new(select_statement("SELECT b FROM t1").unwrap(), vec![]);
+
+        cache.update_query_migration_state(&q1, MigrationState::DryRunSucceeded);
+        cache.record_upstream_execution(
+            QueryId::from(&q1),
+            Vec::<Vec<DfValue>>::new(),
+            Duration::from_millis(1),
+        );
+        assert!(cache.proxied_query_stats.contains_key(&QueryId::from(&q1)));
+
+        // Evicting a query from the persistent cache drops its statistics...
+        cache.update_query_migration_state(&q2, MigrationState::DryRunSucceeded);
+        assert!(cache.proxied_query_stats.is_empty());
+
+        // ...and we don't keep statistics for queries which aren't in it
+        cache.record_upstream_execution(
+            QueryId::from(&q1),
+            Vec::<Vec<DfValue>>::new(),
+            Duration::from_millis(1),
+        );
+        assert!(cache.proxied_query_stats.is_empty());
+    }
+
     #[test]
     fn clear_proxied_queries() {
         let cache = QueryStatusCache::new().style(MigrationStyle::Explicit);
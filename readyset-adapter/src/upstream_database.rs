@@ -3,7 +3,7 @@ use std::fmt::Debug;
 
 use async_trait::async_trait;
 pub use database_utils::UpstreamConfig;
-use nom_sql::{SqlIdentifier, StartTransactionStatement};
+use nom_sql::{CopySource, SqlIdentifier, StartTransactionStatement};
 use readyset_adapter_types::DeallocateId;
 use readyset_client_metrics::QueryDestination;
 use readyset_data::DfValue;
@@ -180,8 +180,23 @@ pub trait UpstreamDatabase: Sized + Send {
         query: &'a str,
     ) -> Result<Self::QueryResult<'a>, Self::Error>;
 
+    /// Execute a raw `COPY ... TO STDOUT` query, returning a result that streams the data copied
+    /// by the upstream database. `source` is the parsed source of the copied rows, which is used
+    /// to describe the copied columns to the client.
+    ///
+    /// Note that this is only relevant for PostgreSQL upstreams.
+    async fn copy_out<'a>(
+        &'a mut self,
+        query: &'a str,
+        source: &CopySource,
+    ) -> Result<Self::QueryResult<'a>, Self::Error>;
+
     /// Execute a raw, un-prepared write query, constructing and returning a RYW ticket for the
-    /// write
+    /// write.
+    ///
+    /// The ticket identifies the position of the write in the upstream database's replication
+    /// log: the GTID of the transaction for MySQL, or the LSN of the end of the WAL after the
+    /// write committed for PostgreSQL.
     // TODO: newtype RYW ticket, not just String
     async fn handle_ryw_write<'a, S>(
         &'a mut self,
@@ -190,6 +205,10 @@ pub trait UpstreamDatabase: Sized + Send {
     where
         S: AsRef<str> + Send + Sync + 'a;
 
+    /// Returns the current position of the upstream database's replication log, in the same form
+    /// as the tickets returned by [`handle_ryw_write`](Self::handle_ryw_write).
+    async fn replication_position(&mut self) -> Result<String, Self::Error>;
+
     /// Handle starting a transaction with the upstream database.
     async fn start_tx<'a>(
         &'a mut self,
@@ -241,6 +260,11 @@ where
 
         Ok(self.upstream.as_mut().unwrap())
     }
+
+    /// Returns a reference to the underlying upstream, if a connection to it has been established.
+    pub fn connected(&self) -> Option<&U> {
+        self.upstream.as_ref()
+    }
 }
 
 #[async_trait]
@@ -339,6 +363,14 @@ where
         self.upstream().await?.simple_query(query).await
     }
 
+    async fn copy_out<'a>(
+        &'a mut self,
+        query: &'a str,
+        source: &CopySource,
+    ) -> Result<Self::QueryResult<'a>, Self::Error> {
+        self.upstream().await?.copy_out(query, source).await
+    }
+
     // TODO: newtype RYW ticket, not just String
     async fn handle_ryw_write<'a, S>(
         &'a mut self,
@@ -350,6 +382,10 @@ where
         self.upstream().await?.handle_ryw_write(query).await
     }
 
+    async fn replication_position(&mut self) -> Result<String, Self::Error> {
+        self.upstream().await?.replication_position().await
+    }
+
     async fn start_tx<'a>(
         &'a mut self,
         stmt: &StartTransactionStatement,
@@ -123,5 +123,21 @@ impl ViewsSynchronizer {
             }
             Err(error) => warn!(%error, "Could not get view statuses from leader"),
         }
+
+        self.sync_always().await;
+    }
+
+    /// Updates the always flag of every cached query to match the one stored in the controller,
+    /// so that `ALTER CACHE ... SET ALWAYS` run through any adapter applies to this one as well
+    async fn sync_always(&mut self) {
+        match self.controller.verbose_views().await {
+            Ok(caches) => {
+                for cache in caches {
+                    self.query_status_cache
+                        .sync_always_attempt_readyset(&cache.query_id, cache.always);
+                }
+            }
+            Err(error) => warn!(%error, "Could not get caches from leader"),
+        }
     }
 }
@@ -2,6 +2,7 @@ use std::borrow::Cow;
 use std::collections::{BTreeMap, HashMap, HashSet};
 use std::future::Future;
 use std::net::SocketAddr;
+use std::path::PathBuf;
 use std::pin::Pin;
 use std::sync::Arc;
 use std::task::{Context, Poll};
@@ -9,7 +10,7 @@ use std::time::{Duration, Instant};
 
 use futures_util::future;
 use hyper::client::HttpConnector;
-use nom_sql::{NonReplicatedRelation, Relation};
+use nom_sql::{CacheOptions, NonReplicatedRelation, Relation};
 use parking_lot::RwLock;
 use petgraph::graph::NodeIndex;
 use readyset_errors::{
@@ -26,7 +27,7 @@ use tracing::{debug, trace};
 use url::Url;
 
 use crate::consensus::{Authority, AuthorityControl};
-use crate::debug::info::{GraphInfo, MaterializationInfo, NodeSize};
+use crate::debug::info::{GraphInfo, MaterializationInfo, NodeMaterializedSize, NodeSize};
 use crate::debug::stats;
 use crate::internal::{DomainIndex, ReplicaAddress};
 use crate::metrics::MetricsDump;
@@ -784,6 +785,45 @@ impl ReadySetHandle {
         remove_query(name: &Relation) -> u64
     );
 
+    simple_request!(
+        /// Set the given per-cache policies (such as the memory limit and eviction priority) on the
+        /// cache with the given name, without recreating the cache. Policies which aren't set in
+        /// `options` are left unchanged.
+        ///
+        /// `Self::poll_ready` must have returned `Async::Ready` before you call this method.
+        set_cache_options(
+            name: &Relation,
+            options: &CacheOptions,
+        ) -> ()
+    );
+
+    simple_request!(
+        /// Rename the cache with the given name to `new_name`, without recreating the cache.
+        ///
+        /// `Self::poll_ready` must have returned `Async::Ready` before you call this method.
+        rename_cache(
+            name: &Relation,
+            new_name: &Relation,
+        ) -> ()
+    );
+
+    simple_request!(
+        /// Set whether the cache with the given name should always be served from ReadySet, even
+        /// inside of transactions, without recreating the cache.
+        ///
+        /// `Self::poll_ready` must have returned `Async::Ready` before you call this method.
+        set_cache_always(
+            name: &Relation,
+            always: bool,
+        ) -> ()
+    );
+
+    simple_request!(
+        /// Return a map from the name of each cache to the approximate size of the materialized
+        /// state of its reader.
+        cache_memory_usage() -> HashMap<Relation, NodeMaterializedSize>
+    );
+
     simple_request!(
         /// Remove all non-base nodes from the graph
         ///
@@ -888,6 +928,18 @@ impl ReadySetHandle {
         replication_offsets() -> ReplicationOffsets
     );
 
+    simple_request!(
+        /// Create a consistent RocksDB checkpoint of every base table in a new subdirectory of
+        /// `dir`, which is interpreted relative to the filesystem of the worker running each base
+        /// table. Returns the replication offsets that the base tables were checkpointed at.
+        ///
+        /// The controller's dataflow state, including the recipe and the schema replication
+        /// offset, is saved in `dir` along with the base tables. A new server can be started from
+        /// the checkpoint by passing `dir` to `--restore-from-checkpoint`, after which it will
+        /// resume replication from those offsets rather than snapshotting again.
+        checkpoint_base_tables(dir: PathBuf) -> ReplicationOffsets
+    );
+
     simple_request!(
         /// Each base table has an offset up to which data has been persisted to disk, and this
         /// method returns the minimum of those offsets. If no base tables have unpersisted data,
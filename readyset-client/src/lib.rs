@@ -450,6 +450,7 @@ pub fn shard_by(dt: &DfValue, shards: usize) -> usize {
         | DfValue::Numeric(_)
         | DfValue::BitVector(_)
         | DfValue::Array(_)
+        | DfValue::Interval(_)
         | DfValue::PassThrough(_) => {
             let hash = ahash::RandomState::with_seeds(0x3306, 0x6033, 0x5432, 0x6034).hash_one(dt);
             hash as usize % shards
@@ -37,7 +37,7 @@ use std::hash::{Hash, Hasher};
 use dataflow_expression::Dialect;
 use nom_locate::LocatedSpan;
 use nom_sql::{
-    AlterTableStatement, CacheInner, CreateCacheStatement, CreateTableStatement,
+    AlterTableStatement, CacheInner, CacheOptions, CreateCacheStatement, CreateTableStatement,
     CreateViewStatement, DropTableStatement, DropViewStatement, NonReplicatedRelation, Relation,
     SelectStatement, SqlIdentifier, SqlQuery,
 };
@@ -201,6 +201,7 @@ impl ChangeList {
                                 name,
                                 inner,
                                 always,
+                                options,
                                 ..
                             }) => {
                                 let statement = match inner {
@@ -223,6 +224,7 @@ impl ChangeList {
                                     name,
                                     statement,
                                     always,
+                                    options,
                                 }))
                             }
                             SqlQuery::AlterTable(ats) => changes.push(Change::AlterTable(ats)),
@@ -327,6 +329,8 @@ pub struct CreateCache {
     /// If set to `true`, execution of this cache will bypass transaction handling in the
     /// adapter
     pub always: bool,
+    /// Per-cache policies, such as the maximum staleness of reads from the cache
+    pub options: CacheOptions,
 }
 
 /// Metadata about a PostgreSQL table
@@ -422,6 +426,7 @@ impl Change {
             name: Some(name.into()),
             statement: Box::new(statement),
             always,
+            options: CacheOptions::default(),
         })
     }
 
@@ -512,6 +517,7 @@ impl Change {
                         name,
                         inner,
                         always,
+                        options,
                         ..
                     }) => {
                         let mut statement = match inner {
@@ -538,6 +544,7 @@ impl Change {
                             name,
                             statement,
                             always,
+                            options,
                         })
                     }
                     SqlQuery::DropCache(dcs) => Change::Drop {
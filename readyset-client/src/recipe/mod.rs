@@ -5,7 +5,9 @@ pub mod changelist;
 use std::borrow::Cow;
 use std::fmt::Display;
 
-use nom_sql::{CacheInner, CreateCacheStatement, DialectDisplay, Relation, SelectStatement};
+use nom_sql::{
+    CacheInner, CacheOptions, CreateCacheStatement, DialectDisplay, Relation, SelectStatement,
+};
 use readyset_errors::ReadySetError;
 use readyset_util::fmt::fmt_with;
 use serde::{Deserialize, Serialize};
@@ -100,6 +102,7 @@ pub struct CacheExpr {
     pub name: Relation,
     pub statement: SelectStatement,
     pub always: bool,
+    pub options: CacheOptions,
     pub query_id: QueryId,
 }
 
@@ -109,6 +112,7 @@ impl From<CacheExpr> for CreateCacheStatement {
             name: Some(value.name),
             inner: Ok(CacheInner::Statement(Box::new(value.statement))),
             always: value.always,
+            options: value.options,
             // CacheExpr represents a migrated query, and the below fields are not relevant for an
             // already-migrated query
             concurrently: false,
@@ -53,6 +53,7 @@ pub(crate) mod results;
 
 use self::results::{ResultIterator, Results};
 use crate::consistency::Timestamp;
+use crate::internal::LocalNodeIndex;
 use crate::{ReaderAddress, Tagged, Tagger};
 
 type Transport = AsyncBincodeStream<
@@ -133,6 +134,12 @@ pub enum ViewPlaceholder {
         /// The `LIMIT` (page size) in the query
         limit: u64,
     },
+
+    /// This column is compared for equality against the given placeholder in the `ON` clause of a
+    /// `LEFT JOIN`, on the right-hand side of the join. These columns aren't part of the lookup
+    /// key - instead, the results of the lookup are filtered by the value for the placeholder
+    /// afterwards.
+    LeftJoinFilter(PlaceholderIdx),
 }
 
 #[derive(Debug)]
@@ -892,6 +899,13 @@ pub struct ReaderHandleBuilder {
 
     /// The amount of time before a view request RPC is terminated.
     pub view_request_timeout: Duration,
+
+    /// The maximum staleness of reads from the view, if the cache was created with one
+    pub max_staleness: Option<Duration>,
+
+    /// The local addresses of the base tables the view reads from. Only populated if
+    /// `max_staleness` is set.
+    pub base_tables: Vec<LocalNodeIndex>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -1019,6 +1033,8 @@ impl ReaderHandleBuilder {
                     self.name.display_unquoted()
                 )
             })?,
+            max_staleness: self.max_staleness,
+            base_tables: self.base_tables.clone().into(),
         })
     }
 }
@@ -1068,6 +1084,8 @@ pub struct ReaderHandle {
     key_mapping: Vec<(ViewPlaceholder, KeyColumnIdx)>,
     shards: Vec1<ViewRpc>,
     shard_addrs: Vec<SocketAddr>,
+    max_staleness: Option<Duration>,
+    base_tables: Arc<[LocalNodeIndex]>,
 }
 
 impl fmt::Debug for ReaderHandle {
@@ -1133,8 +1151,6 @@ pub struct ViewQuery {
     /// Timestamp to compare against for reads, if a timestamp is passed into the
     /// view query, a read will only return once the timestamp is less than
     /// the timestamp associated with the data.
-    // TODO(justin): Verify reads block on timestamps once timestamps have a definition
-    // with Ord.
     pub timestamp: Option<Timestamp>,
 }
 
@@ -1369,6 +1385,26 @@ impl ReaderHandle {
         self.shard_addrs.len()
     }
 
+    /// Returns the maximum staleness of reads from this view, if the cache was created with one
+    #[must_use]
+    pub fn max_staleness(&self) -> Option<Duration> {
+        self.max_staleness
+    }
+
+    /// Returns a [`Timestamp`] which is satisfied once this view reflects every write the
+    /// replicator has applied up to the given timestamp value in all of the base tables it reads
+    /// from
+    #[must_use]
+    pub fn timestamp_at(&self, value: u64) -> Timestamp {
+        Timestamp {
+            map: self
+                .base_tables
+                .iter()
+                .map(|table| (*table, value))
+                .collect(),
+        }
+    }
+
     /// Get the current size of this view.
     ///
     /// Note that you must also continue to poll this `View` for the returned future to resolve.
@@ -1575,7 +1611,12 @@ impl ReaderHandle {
 
 struct KeyComparisonBuilder<'a> {
     mixed_binops: bool,
+    /// Whether the only key column in the reader is a generated bogokey, with all placeholders
+    /// filtered post-lookup
+    bogokey: bool,
     filters: Vec<DfExpr>,
+    /// The values we've seen for placeholders filtered post-lookup, keyed by column
+    left_join_filter_values: HashMap<KeyColumnIdx, DfValue>,
     key_map: &'a [(ViewPlaceholder, KeyColumnIdx)],
     key_types: HashMap<usize, &'a DfType>,
     key_remap: Option<&'a HashMap<PlaceholderIdx, Literal>>,
@@ -1614,8 +1655,11 @@ impl<'a> KeyComparisonBuilder<'a> {
                     }
                     // Between uses mixed binops
                     ViewPlaceholder::Between(_, _) => false,
-                    // Generated and PageNumber placeholders can be used
-                    ViewPlaceholder::Generated | ViewPlaceholder::PageNumber { .. } => true,
+                    // Generated and PageNumber placeholders can be used, and LeftJoinFilter
+                    // placeholders aren't part of the key
+                    ViewPlaceholder::Generated
+                    | ViewPlaceholder::PageNumber { .. }
+                    | ViewPlaceholder::LeftJoinFilter(_) => true,
                 });
         // The binary operator we will use to build our key if we do not have a mixed comparison
         let binop_to_use = current_binop.unwrap_or(BinaryOperator::Equal);
@@ -1627,14 +1671,23 @@ impl<'a> KeyComparisonBuilder<'a> {
             .map(|((_, key_column_idx), key_type)| (*key_column_idx, key_type))
             .collect();
 
+        let bogokey = reader_handle.key_map().iter().all(|(placeholder, _)| {
+            matches!(
+                placeholder,
+                ViewPlaceholder::Generated | ViewPlaceholder::LeftJoinFilter(_)
+            )
+        });
+
         Ok(Self {
             key_remap,
             dialect,
             mixed_binops,
+            bogokey,
             binop_to_use,
             key_types,
             key_map: reader_handle.key_map(),
             filters: Vec::new(),
+            left_join_filter_values: HashMap::new(),
         })
     }
 
@@ -1682,7 +1735,67 @@ impl<'a> KeyComparisonBuilder<'a> {
         // All ViewPlaceholder indices must be remapped using key_remap
         for (view_placeholder, key_column_idx) in self.key_map {
             match view_placeholder {
-                ViewPlaceholder::Generated => continue,
+                ViewPlaceholder::Generated => {
+                    if self.bogokey {
+                        k.push(DfValue::from(0i32));
+                    }
+                }
+                ViewPlaceholder::LeftJoinFilter(idx) => {
+                    let key_type = *self
+                        .key_types
+                        .get(key_column_idx)
+                        .ok_or_else(|| internal_err!("No key_type for key"))?;
+
+                    let value = self.remap_key(raw_key.as_ref(), idx, key_type)?;
+
+                    // The filter applies to the results for all keys, so we can only build it once
+                    match self.left_join_filter_values.get(key_column_idx) {
+                        Some(existing) if *existing == value => continue,
+                        Some(_) => unsupported!(
+                            "Multiple different values for parameters in the ON clause of a LEFT \
+                             JOIN"
+                        ),
+                        None => {}
+                    }
+                    self.left_join_filter_values
+                        .insert(*key_column_idx, value.clone());
+
+                    let column = DfExpr::Column {
+                        index: *key_column_idx,
+                        ty: key_type.clone(),
+                    };
+                    let is_null = DfExpr::Op {
+                        left: Box::new(column.clone()),
+                        op: DfBinaryOperator::Is,
+                        right: Box::new(DfExpr::Literal {
+                            val: DfValue::None,
+                            ty: key_type.clone(),
+                        }),
+                        ty: DfType::Bool,
+                    };
+
+                    // Rows which were joined against NULLs always pass the filter, and are removed
+                    // afterwards if their row in the left-hand side of the join had any matches.
+                    // We compare with IS rather than = since neither side of the OR can be NULL
+                    self.filters.push(if value.is_none() {
+                        is_null
+                    } else {
+                        DfExpr::Op {
+                            left: Box::new(DfExpr::Op {
+                                left: Box::new(column),
+                                op: DfBinaryOperator::Is,
+                                right: Box::new(DfExpr::Literal {
+                                    val: value,
+                                    ty: key_type.clone(),
+                                }),
+                                ty: DfType::Bool,
+                            }),
+                            op: DfBinaryOperator::Or,
+                            right: Box::new(is_null),
+                            ty: DfType::Bool,
+                        }
+                    });
+                }
                 ViewPlaceholder::OneToOne(idx, binop) => {
                     let key_type = *self
                         .key_types
@@ -1767,7 +1880,14 @@ impl<'a> KeyComparisonBuilder<'a> {
                                         BinaryOperator::Less | BinaryOperator::Greater
                                     )
                                         // As long as the range is actually compound
-                                        && self.key_map.len() > 1
+                                        && self
+                                            .key_map
+                                            .iter()
+                                            .filter(|(placeholder, _)| !matches!(
+                                                placeholder,
+                                                ViewPlaceholder::LeftJoinFilter(_)
+                                            ))
+                                            .count() > 1
                         ) || (
                             // Or all other range keys beyond the *first* key within a
                             // compound range
@@ -1977,6 +2097,31 @@ impl View {
             View::MultipleReused(_) => None,
         }
     }
+
+    /// Returns the maximum staleness of reads from this view. If self is [`View::MultipleReused`],
+    /// this is the smallest maximum staleness of any of the reused caches.
+    pub fn max_staleness(&self) -> Option<Duration> {
+        match self {
+            View::Single(rh) => rh.max_staleness(),
+            View::MultipleReused(handles) => handles
+                .iter()
+                .filter_map(|handle| handle.inner().max_staleness())
+                .min(),
+        }
+    }
+
+    /// Returns a [`Timestamp`] which is satisfied once every reader in this view reflects every
+    /// write the replicator has applied up to the given timestamp value.
+    ///
+    /// See [`ReaderHandle::timestamp_at`].
+    pub fn timestamp_at(&self, value: u64) -> Timestamp {
+        match self {
+            View::Single(rh) => rh.timestamp_at(value),
+            View::MultipleReused(handles) => handles.iter().fold(Timestamp::default(), |ts, h| {
+                Timestamp::join(&ts, &h.inner().timestamp_at(value))
+            }),
+        }
+    }
 }
 
 #[derive(Debug, Default)]
@@ -2186,6 +2331,8 @@ mod tests {
                 key_mapping: key_map.to_vec(),
                 shards: Vec1::new(c), // Not used for test
                 shard_addrs: vec![],  // Not used for test
+                max_staleness: None,
+                base_tables: Arc::new([]),
             };
             let dataflow_dialect = match dialect {
                 Dialect::MySQL => DfDialect::DEFAULT_MYSQL,
@@ -2457,5 +2604,89 @@ mod tests {
                 .into()]
             );
         }
+
+        #[test]
+        fn left_join_filter_with_key() {
+            // "SELECT t.x FROM t LEFT JOIN u ON t.x = u.x AND u.y = $2 WHERE t.x = $1"
+            let query = make_build_query(
+                vec![Cow::Owned(vec![DfValue::from(1), DfValue::from("a")])],
+                None,
+                None,
+                &[
+                    (ViewPlaceholder::OneToOne(1, BinaryOperator::Equal), 0),
+                    (ViewPlaceholder::LeftJoinFilter(2), 1),
+                ],
+                Dialect::MySQL,
+            );
+
+            let column = DfExpr::Column {
+                index: 1,
+                ty: DfType::DEFAULT_TEXT,
+            };
+            assert_eq!(
+                query.filter,
+                Some(DfExpr::Op {
+                    left: Box::new(DfExpr::Op {
+                        left: Box::new(column.clone()),
+                        op: DfBinaryOperator::Is,
+                        right: Box::new(DfExpr::Literal {
+                            val: "a".into(),
+                            ty: DfType::DEFAULT_TEXT
+                        }),
+                        ty: DfType::Bool,
+                    }),
+                    op: DfBinaryOperator::Or,
+                    right: Box::new(DfExpr::Op {
+                        left: Box::new(column),
+                        op: DfBinaryOperator::Is,
+                        right: Box::new(DfExpr::Literal {
+                            val: DfValue::None,
+                            ty: DfType::DEFAULT_TEXT
+                        }),
+                        ty: DfType::Bool,
+                    }),
+                    ty: DfType::Bool,
+                })
+            );
+            assert_eq!(
+                query.key_comparisons,
+                vec![KeyComparison::from(vec1![DfValue::from(1)])]
+            );
+        }
+
+        #[test]
+        fn left_join_filter_null_with_bogokey() {
+            // "SELECT t.x FROM t LEFT JOIN u ON t.x = u.x AND u.y = $1"
+            let query = make_build_query(
+                vec![Cow::Owned(vec![DfValue::None])],
+                None,
+                None,
+                &[
+                    (ViewPlaceholder::Generated, 0),
+                    (ViewPlaceholder::LeftJoinFilter(1), 1),
+                ],
+                Dialect::MySQL,
+            );
+
+            assert_eq!(
+                query.filter,
+                Some(DfExpr::Op {
+                    left: Box::new(DfExpr::Column {
+                        index: 1,
+                        ty: DfType::DEFAULT_TEXT,
+                    }),
+                    op: DfBinaryOperator::Is,
+                    right: Box::new(DfExpr::Literal {
+                        val: DfValue::None,
+                        ty: DfType::DEFAULT_TEXT
+                    }),
+                    ty: DfType::Bool,
+                })
+            );
+            assert_eq!(
+                query.key_comparisons,
+                vec![KeyComparison::from(vec1![DfValue::from(0i32)])]
+            );
+        }
     }
 }
@@ -1,7 +1,8 @@
 use std::cmp::Ordering;
+use std::collections::HashSet;
 use std::sync::Arc;
 
-use dataflow_expression::{Expr, PostLookup, PostLookupAggregates};
+use dataflow_expression::{Expr, PostLookup, PostLookupAggregates, PostLookupLeftJoinFilter};
 use nom_sql::OrderType;
 use readyset_data::DfValue;
 use smallvec::SmallVec;
@@ -165,9 +166,23 @@ impl ResultIterator {
             returned_cols,
             aggregates,
             default_row,
-            ..
+            left_join_filter,
         } = post_lookup;
 
+        // Removing the extra NULL-padded rows for a LEFT JOIN depends on which rows pass the
+        // filter, so we have to apply the filter eagerly here
+        let data = match left_join_filter {
+            Some(left_join_filter) => {
+                let data = data
+                    .into_iter()
+                    .map(|rows| apply_left_join_filter(rows, left_join_filter, filter.as_ref()))
+                    .collect();
+                filter = None;
+                data
+            }
+            None => data,
+        };
+
         let limit = adapter_limit.or(*limit); // Limit specifies total number of results to return
 
         let inner = match (order_by, aggregates) {
@@ -360,6 +375,49 @@ impl ResultIterator {
     }
 }
 
+/// Filter the results for a single key of a query with parameters on the right-hand side of a
+/// LEFT JOIN, removing rows which don't pass `filter`, and rows joined against NULLs whose row in
+/// the left-hand side of the join also has rows which *do* match (see [`PostLookupLeftJoinFilter`])
+fn apply_left_join_filter(
+    rows: SharedRows,
+    left_join_filter: &PostLookupLeftJoinFilter,
+    filter: Option<&Expr>,
+) -> SharedRows {
+    let passes_filter = |row: &Row| {
+        filter.map_or(true, |filter| {
+            filter.eval(row).map(|r| r.is_truthy()).unwrap_or(false)
+        })
+    };
+    let is_null_padded = |row: &Row| {
+        left_join_filter
+            .right_columns
+            .iter()
+            .all(|&col| row[col].is_none())
+    };
+    let left_key = |row: &'_ Row| -> Vec<DfValue> {
+        left_join_filter
+            .left_columns
+            .iter()
+            .map(|&col| row[col].clone())
+            .collect()
+    };
+
+    let matched = rows
+        .iter()
+        .filter(|row| !is_null_padded(row) && passes_filter(row))
+        .map(left_key)
+        .collect::<HashSet<_>>();
+
+    triomphe::Arc::new(
+        rows.iter()
+            .filter(|row| {
+                passes_filter(row) && (!is_null_padded(row) || !matched.contains(&left_key(row)))
+            })
+            .cloned()
+            .collect(),
+    )
+}
+
 impl StreamingIterator for OwnedResultIterator {
     type Item = [DfValue];
 
@@ -517,6 +575,7 @@ impl StreamingIterator for AggregateIterator {
         };
 
         self.advance_filtered();
+        let mut merged = false;
         while let Some(row) = self.inner.get() {
             if self
                 .aggregate
@@ -528,16 +587,21 @@ impl StreamingIterator for AggregateIterator {
             }
 
             for agg in &self.aggregate.aggregates {
-                let col = agg.column;
-                aggregate_row[col] = agg
-                    .function
-                    .apply(&aggregate_row[col], &row[col])
-                    .expect("no fail");
+                agg.merge(&mut aggregate_row, row).expect("no fail");
             }
+            merged = true;
 
             self.advance_filtered();
         }
 
+        // Values for groups which only appear for a single key were already computed in full by
+        // the dataflow graph
+        if merged {
+            for agg in &self.aggregate.aggregates {
+                agg.finalize(&mut aggregate_row).expect("no fail");
+            }
+        }
+
         self.out_row = Some(aggregate_row)
     }
 
@@ -650,17 +650,17 @@ async fn cached_queries_filtering() {
     let query_ids = adapter
         .as_mysql_conn()
         .unwrap()
-        .query::<(String, String, String, String, String), _>("SHOW CACHES")
+        .query::<(String, String, String, String, Option<String>, String), _>("SHOW CACHES")
         .await
         .unwrap();
     assert_eq!(query_ids.len(), 2);
 
     // Filter on one of the IDs
-    let (query_id, _, _, _, _) = query_ids.first().unwrap();
+    let (query_id, _, _, _, _, _) = query_ids.first().unwrap();
     let cached_queries = adapter
         .as_mysql_conn()
         .unwrap()
-        .query::<(String, String, String, String, String), _>(&format!(
+        .query::<(String, String, String, String, Option<String>, String), _>(&format!(
             "SHOW CACHES WHERE query_id = '{}'",
             query_id
         ))
@@ -1792,7 +1792,7 @@ async fn show_query_metrics() {
 
     // Check `SHOW CACHES`
     #[allow(clippy::type_complexity)]
-    let caches_result: Vec<(String, String, String, String, String)> = adapter
+    let caches_result: Vec<(String, String, String, String, Option<String>, String)> = adapter
         .as_mysql_conn()
         .unwrap()
         .query(r"SHOW CACHES")
@@ -168,7 +168,8 @@ pub(crate) fn coerce_f64(val: f64, to_ty: &DfType, from_ty: &DfType) -> ReadySet
         | DfType::Uuid
         | DfType::Bit(_)
         | DfType::VarBit(_)
-        | DfType::Array(_) => Err(err("not allowed")),
+        | DfType::Array(_)
+        | DfType::Interval => Err(err("not allowed")),
     }
 }
 
@@ -268,7 +269,8 @@ pub(crate) fn coerce_decimal(
         | DfType::Uuid
         | DfType::Bit(_)
         | DfType::VarBit(_)
-        | DfType::Array(_) => Err(ReadySetError::DfValueConversionError {
+        | DfType::Array(_)
+        | DfType::Interval => Err(ReadySetError::DfValueConversionError {
             src_type: "Decimal".to_string(),
             target_type: to_ty.to_string(),
             details: "Not allowed".to_string(),
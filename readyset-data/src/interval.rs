@@ -0,0 +1,1061 @@
+use std::cmp::Ordering;
+use std::fmt::{self, Display};
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+use bytes::BufMut;
+use chrono::{
+    Datelike, Duration, LocalResult, Months, NaiveDate, NaiveDateTime, Offset, TimeZone, Timelike,
+};
+use nom_sql::IntervalUnit;
+use readyset_errors::{invalid_query_err, ReadySetError, ReadySetResult};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+use test_strategy::Arbitrary;
+use tokio_postgres::types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+use crate::TimestampTz;
+
+const MICROSECONDS_PER_SECOND: i64 = 1_000_000;
+const MICROSECONDS_PER_MINUTE: i64 = 60 * MICROSECONDS_PER_SECOND;
+const MICROSECONDS_PER_HOUR: i64 = 60 * MICROSECONDS_PER_MINUTE;
+const MICROSECONDS_PER_DAY: i64 = 24 * MICROSECONDS_PER_HOUR;
+const DAYS_PER_MONTH: i64 = 30;
+const MONTHS_PER_YEAR: i32 = 12;
+
+/// Internal representation of an SQL interval: a span of time, which can be added to or subtracted
+/// from dates and times.
+///
+/// Like PostgreSQL's `interval` type, intervals are stored as a separate number of months, days,
+/// and microseconds, since the length of a month or a day in absolute time depends on the date
+/// it's added to. When comparing or hashing intervals, though, months are treated as 30 days and
+/// days as 24 hours, so (again like PostgreSQL) `'1 month'` is equal to `'30 days'`.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, Arbitrary)]
+pub struct Interval {
+    months: i32,
+    days: i32,
+    microseconds: i64,
+}
+
+impl Interval {
+    /// The smallest representable interval
+    pub const MIN: Self = Self::new(i32::MIN, i32::MIN, i64::MIN);
+
+    /// The largest representable interval
+    pub const MAX: Self = Self::new(i32::MAX, i32::MAX, i64::MAX);
+
+    /// Construct a new [`Interval`] from a number of months, days, and microseconds
+    pub const fn new(months: i32, days: i32, microseconds: i64) -> Self {
+        Self {
+            months,
+            days,
+            microseconds,
+        }
+    }
+
+    /// Returns the number of months in this interval
+    pub fn months(&self) -> i32 {
+        self.months
+    }
+
+    /// Returns the number of days in this interval, not including those in [`Self::months`]
+    pub fn days(&self) -> i32 {
+        self.days
+    }
+
+    /// Returns the time component of this interval, in microseconds
+    pub fn microseconds(&self) -> i64 {
+        self.microseconds
+    }
+
+    /// Returns true if this interval has no months, days, or time
+    pub fn is_zero(&self) -> bool {
+        self.months == 0 && self.days == 0 && self.microseconds == 0
+    }
+
+    /// Returns true if this interval only consists of whole days (and months), with no time
+    /// component
+    pub fn is_whole_days(&self) -> bool {
+        self.microseconds == 0
+    }
+
+    /// The total length of this interval in microseconds, using 30-day months and 24-hour days.
+    /// Used for comparisons.
+    fn total_microseconds(&self) -> i128 {
+        (i128::from(self.months) * i128::from(DAYS_PER_MONTH) + i128::from(self.days))
+            * i128::from(MICROSECONDS_PER_DAY)
+            + i128::from(self.microseconds)
+    }
+
+    /// Returns a canonical representation of this interval, which is equal to `self` and identical
+    /// to the canonical representation of every other interval equal to `self`.
+    ///
+    /// The canonical representation carries every 24 hours of time into days, and every 30 days
+    /// into months. If that would overflow the months field, returns `self` unchanged.
+    pub fn normalize(&self) -> Self {
+        let total = self.total_microseconds();
+        let per_month = i128::from(MICROSECONDS_PER_DAY) * i128::from(DAYS_PER_MONTH);
+        let Ok(months) = i32::try_from(total / per_month) else {
+            return *self;
+        };
+        let rem = total % per_month;
+        Self {
+            months,
+            days: (rem / i128::from(MICROSECONDS_PER_DAY)) as i32,
+            microseconds: (rem % i128::from(MICROSECONDS_PER_DAY)) as i64,
+        }
+    }
+
+    /// Add two intervals together field-by-field, returning `None` on overflow
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(Self {
+            months: self.months.checked_add(other.months)?,
+            days: self.days.checked_add(other.days)?,
+            microseconds: self.microseconds.checked_add(other.microseconds)?,
+        })
+    }
+
+    /// Subtract `other` from this interval field-by-field, returning `None` on overflow
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        self.checked_add(&other.checked_neg()?)
+    }
+
+    /// Negate this interval, returning `None` on overflow
+    pub fn checked_neg(&self) -> Option<Self> {
+        Some(Self {
+            months: self.months.checked_neg()?,
+            days: self.days.checked_neg()?,
+            microseconds: self.microseconds.checked_neg()?,
+        })
+    }
+
+    /// Multiply this interval by `factor`, like PostgreSQL's `interval * float8`, returning `None`
+    /// if the result is out of range.
+    ///
+    /// Each field is scaled separately, and any fractional months or days are carried down into
+    /// days (at 30 days per month) and microseconds (at 24 hours per day) respectively, so
+    /// `'1 month' * 1.5` is `'1 month 15 days'`.
+    pub fn checked_mul_f64(&self, factor: f64) -> Option<Self> {
+        self.scale(|x| x * factor)
+    }
+
+    /// Divide this interval by `divisor`, like PostgreSQL's `interval / float8`, returning `None`
+    /// if the divisor is zero or the result is out of range.
+    ///
+    /// Fractional months and days are carried down as in [`Self::checked_mul_f64`].
+    pub fn checked_div_f64(&self, divisor: f64) -> Option<Self> {
+        if divisor == 0.0 {
+            return None;
+        }
+        self.scale(|x| x / divisor)
+    }
+
+    /// Apply `op` to each field of this interval, carrying fractional months and days down into
+    /// the smaller units the same way PostgreSQL's `interval_mul` does.
+    fn scale(&self, op: impl Fn(f64) -> f64) -> Option<Self> {
+        const SECONDS_PER_DAY: f64 = (MICROSECONDS_PER_DAY / MICROSECONDS_PER_SECOND) as f64;
+
+        // Round away floating point error below the precision of an interval, so that
+        // carried-down fractions which should be whole numbers are
+        fn round_to_microseconds(x: f64) -> f64 {
+            (x * MICROSECONDS_PER_SECOND as f64).round_ties_even() / MICROSECONDS_PER_SECOND as f64
+        }
+
+        fn to_i32(x: f64) -> Option<i32> {
+            let x = x.trunc();
+            (x >= f64::from(i32::MIN) && x <= f64::from(i32::MAX)).then_some(x as i32)
+        }
+
+        let months = op(f64::from(self.months));
+        let days = op(f64::from(self.days));
+        let result_months = to_i32(months)?;
+        let mut result_days = to_i32(days)?;
+
+        let month_remainder_days =
+            round_to_microseconds((months - f64::from(result_months)) * DAYS_PER_MONTH as f64);
+        let mut second_remainder = round_to_microseconds(
+            (days - f64::from(result_days) + month_remainder_days.fract()) * SECONDS_PER_DAY,
+        );
+        if second_remainder.abs() >= SECONDS_PER_DAY {
+            let carried_days = (second_remainder / SECONDS_PER_DAY).trunc();
+            result_days = result_days.checked_add(carried_days as i32)?;
+            second_remainder -= carried_days * SECONDS_PER_DAY;
+        }
+        result_days = result_days.checked_add(month_remainder_days.trunc() as i32)?;
+
+        let microseconds = (op(self.microseconds as f64)
+            + second_remainder * MICROSECONDS_PER_SECOND as f64)
+            .round_ties_even();
+        if !(microseconds >= i64::MIN as f64 && microseconds < i64::MAX as f64) {
+            return None;
+        }
+
+        Some(Self::new(result_months, result_days, microseconds as i64))
+    }
+
+    /// Add this interval to the given date and time.
+    ///
+    /// Months are added first, clamping the day of the month to the last day of the resulting
+    /// month if necessary (so `'2020-01-31' + '1 month'` is `'2020-02-29'`), followed by days,
+    /// followed by the time component. Returns `None` if the result is out of range.
+    pub fn add_to_datetime(&self, dt: NaiveDateTime) -> Option<NaiveDateTime> {
+        let months = Months::new(self.months.unsigned_abs());
+        let dt = if self.months >= 0 {
+            dt.checked_add_months(months)?
+        } else {
+            dt.checked_sub_months(months)?
+        };
+
+        dt.checked_add_signed(Duration::days(self.days.into()))?
+            .checked_add_signed(Duration::microseconds(self.microseconds))
+    }
+
+    /// Add this interval to the given timestamp, in the timestamp's local time.
+    ///
+    /// The result keeps the timezone offset and display precision of `ts`. If `ts` is a date, the
+    /// result is only a date if this interval has no time component. Returns `None` if the result
+    /// is out of range.
+    pub fn add_to_timestamp(&self, ts: &TimestampTz) -> Option<TimestampTz> {
+        let dt = ts.to_chrono();
+        let local = self.add_to_datetime(dt.naive_local())?;
+        let mut res = TimestampTz {
+            extra: ts.extra,
+            datetime: local
+                .checked_sub_signed(Duration::seconds(dt.offset().local_minus_utc().into()))?,
+        };
+        if !self.is_whole_days() {
+            res.clear_date_only();
+        }
+        Some(res)
+    }
+
+    /// Add this interval to the given timestamp, adding the months and days in the local time of
+    /// `zone` so that the result is correct across daylight saving time transitions.
+    ///
+    /// This matches PostgreSQL's semantics for `timestamptz + interval`: months and days are added
+    /// to the local date (so `'2024-03-09 12:00 EST' + '1 day'` is `'2024-03-10 12:00 EDT'`), and
+    /// then the time component is added as an absolute duration. Local times which were skipped
+    /// by the clocks going forward use the offset from before the transition, and ambiguous local
+    /// times resolve to the later of the two. The result keeps the timezone offset and display
+    /// precision of `ts`, and is `None` if out of range.
+    pub fn add_to_timestamp_in<Z: TimeZone>(
+        &self,
+        ts: &TimestampTz,
+        zone: &Z,
+    ) -> Option<TimestampTz> {
+        let local = zone
+            .from_utc_datetime(&ts.to_chrono().naive_utc())
+            .naive_local();
+        let local = Self {
+            microseconds: 0,
+            ..*self
+        }
+        .add_to_datetime(local)?;
+        let utc = match zone.from_local_datetime(&local) {
+            LocalResult::Single(dt) | LocalResult::Ambiguous(_, dt) => dt.naive_utc(),
+            LocalResult::None => {
+                let offset = zone.offset_from_utc_datetime(&local).fix();
+                local.checked_sub_signed(Duration::seconds(offset.local_minus_utc().into()))?
+            }
+        };
+        let mut res = TimestampTz {
+            extra: ts.extra,
+            datetime: utc.checked_add_signed(Duration::microseconds(self.microseconds))?,
+        };
+        if !self.is_whole_days() {
+            res.clear_date_only();
+        }
+        Some(res)
+    }
+
+    /// Returns the interval between `start` and `end` (`end - start`), as a number of days and
+    /// microseconds.
+    ///
+    /// This matches the semantics of subtracting two timestamps in PostgreSQL: the difference
+    /// is never expressed in months, and the days and time always have the same sign. Returns
+    /// `None` if the difference is too large to represent.
+    pub fn between(end: NaiveDateTime, start: NaiveDateTime) -> Option<Self> {
+        let micros = end.signed_duration_since(start).num_microseconds()?;
+        Some(Self {
+            months: 0,
+            days: (micros / MICROSECONDS_PER_DAY).try_into().ok()?,
+            microseconds: micros % MICROSECONDS_PER_DAY,
+        })
+    }
+
+    /// Returns the "symbolic" interval between `start` and `end`, using years, months, and days
+    /// rather than just days.
+    ///
+    /// This implements the PostgreSQL [`age`][pg-docs] function, and uses the same algorithm: each
+    /// date and time field is subtracted separately, and negative fields then borrow from the next
+    /// largest field, using the length of the month of whichever of the two arguments is earlier.
+    ///
+    /// [pg-docs]: https://www.postgresql.org/docs/current/functions-datetime.html
+    pub fn age(end: NaiveDateTime, start: NaiveDateTime) -> Option<Self> {
+        let negative = end < start;
+        let (later, earlier) = if negative { (start, end) } else { (end, start) };
+
+        let mut microseconds = i64::from(later.nanosecond() / 1000)
+            - i64::from(earlier.nanosecond() / 1000)
+            + (i64::from(later.num_seconds_from_midnight())
+                - i64::from(earlier.num_seconds_from_midnight()))
+                * MICROSECONDS_PER_SECOND;
+        let mut days = i64::from(later.day()) - i64::from(earlier.day());
+        let mut months = i64::from(later.month()) - i64::from(earlier.month());
+        let mut years = i64::from(later.year()) - i64::from(earlier.year());
+
+        if microseconds < 0 {
+            microseconds += MICROSECONDS_PER_DAY;
+            days -= 1;
+        }
+        if days < 0 {
+            days += i64::from(days_in_month(earlier.year(), earlier.month())?);
+            months -= 1;
+        }
+        if months < 0 {
+            months += i64::from(MONTHS_PER_YEAR);
+            years -= 1;
+        }
+
+        let res = Self {
+            months: (years * i64::from(MONTHS_PER_YEAR) + months)
+                .try_into()
+                .ok()?,
+            days: days.try_into().ok()?,
+            microseconds,
+        };
+        if negative {
+            res.checked_neg()
+        } else {
+            Some(res)
+        }
+    }
+
+    /// Parse an interval from the value of a MySQL `INTERVAL <expr> <unit>` expression, where
+    /// `value` is the value of the expression converted to a string.
+    ///
+    /// For units consisting of a single field, `value` is parsed as a (possibly fractional)
+    /// number, which is rounded to an integer for all units other than `SECOND`. For compound
+    /// units such as `DAY_SECOND`, each group of digits in `value` is taken as one of the unit's
+    /// fields, and if fewer groups are given than the unit has fields, the groups are taken to
+    /// be the rightmost fields (so `'1:30' DAY_SECOND` is 1 minute and 30 seconds).
+    ///
+    /// Returns `None` if `value` cannot be interpreted as an interval in the given unit, in which
+    /// case MySQL returns `NULL`.
+    pub fn from_mysql_unit(value: &str, unit: IntervalUnit) -> Option<Self> {
+        use IntervalUnit::*;
+        use MySqlIntervalField::*;
+
+        let fields: &[MySqlIntervalField] = match unit {
+            Microsecond | Second | Minute | Hour | Day | Week | Month | Quarter | Year => {
+                return Self::from_mysql_number(value, unit)
+            }
+            SecondMicrosecond => &[Seconds, Micros],
+            MinuteMicrosecond => &[Minutes, Seconds, Micros],
+            MinuteSecond => &[Minutes, Seconds],
+            HourMicrosecond => &[Hours, Minutes, Seconds, Micros],
+            HourSecond => &[Hours, Minutes, Seconds],
+            HourMinute => &[Hours, Minutes],
+            DayMicrosecond => &[Days, Hours, Minutes, Seconds, Micros],
+            DaySecond => &[Days, Hours, Minutes, Seconds],
+            DayMinute => &[Days, Hours, Minutes],
+            DayHour => &[Days, Hours],
+            YearMonth => &[Years, Months],
+        };
+
+        let value = value.trim_start();
+        let (negative, value) = match value.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, value),
+        };
+
+        let groups = value
+            .split(|c: char| !c.is_ascii_digit())
+            .filter(|group| !group.is_empty())
+            .collect::<Vec<_>>();
+        if groups.is_empty() || groups.len() > fields.len() {
+            return None;
+        }
+
+        let mut res = Self::default();
+        for (field, group) in fields[fields.len() - groups.len()..].iter().zip(groups) {
+            let n = group.parse::<i64>().ok()?;
+            match field {
+                Years => {
+                    res.months = res
+                        .months
+                        .checked_add(i32::try_from(n).ok()?.checked_mul(MONTHS_PER_YEAR)?)?
+                }
+                Months => res.months = res.months.checked_add(n.try_into().ok()?)?,
+                Days => res.days = n.try_into().ok()?,
+                Hours | Minutes | Seconds => {
+                    let factor = match field {
+                        Hours => MICROSECONDS_PER_HOUR,
+                        Minutes => MICROSECONDS_PER_MINUTE,
+                        _ => MICROSECONDS_PER_SECOND,
+                    };
+                    res.microseconds = res.microseconds.checked_add(n.checked_mul(factor)?)?;
+                }
+                Micros => {
+                    // Like a fractional part of a number, fewer than 6 digits of microseconds are
+                    // padded on the right, so `'1.5' SECOND_MICROSECOND` is one and a half seconds
+                    let n = match 6_u32.checked_sub(group.len() as u32) {
+                        Some(pad) => n * 10_i64.pow(pad),
+                        None => n,
+                    };
+                    res.microseconds = res.microseconds.checked_add(n)?;
+                }
+            }
+        }
+
+        if negative {
+            res.checked_neg()
+        } else {
+            Some(res)
+        }
+    }
+
+    fn from_mysql_number(value: &str, unit: IntervalUnit) -> Option<Self> {
+        let value = value.trim();
+        // Like when converting any string to a number, MySQL ignores everything after the numeric
+        // prefix of the string
+        let len = value
+            .char_indices()
+            .find(|&(i, c)| !(c.is_ascii_digit() || c == '.' || (i == 0 && (c == '-' || c == '+'))))
+            .map_or(value.len(), |(i, _)| i);
+        let n = value[..len].parse::<Decimal>().ok()?;
+
+        let micros = |factor: i64| -> Option<Self> {
+            let micros = n.checked_mul(Decimal::from(factor))?;
+            Some(Self::new(0, 0, round_half_away(micros).to_i64()?))
+        };
+        let rounded = round_half_away(n);
+
+        match unit {
+            IntervalUnit::Microsecond => micros(1),
+            IntervalUnit::Second => micros(MICROSECONDS_PER_SECOND),
+            IntervalUnit::Minute => Some(Self::new(
+                0,
+                0,
+                rounded.to_i64()?.checked_mul(MICROSECONDS_PER_MINUTE)?,
+            )),
+            IntervalUnit::Hour => Some(Self::new(
+                0,
+                0,
+                rounded.to_i64()?.checked_mul(MICROSECONDS_PER_HOUR)?,
+            )),
+            IntervalUnit::Day => Some(Self::new(0, rounded.to_i32()?, 0)),
+            IntervalUnit::Week => Some(Self::new(0, rounded.to_i32()?.checked_mul(7)?, 0)),
+            IntervalUnit::Month => Some(Self::new(rounded.to_i32()?, 0, 0)),
+            IntervalUnit::Quarter => Some(Self::new(rounded.to_i32()?.checked_mul(3)?, 0, 0)),
+            IntervalUnit::Year => Some(Self::new(
+                rounded.to_i32()?.checked_mul(MONTHS_PER_YEAR)?,
+                0,
+                0,
+            )),
+            _ => None,
+        }
+    }
+}
+
+/// The fields which make up a MySQL compound interval unit, such as `DAY_SECOND`
+enum MySqlIntervalField {
+    Years,
+    Months,
+    Days,
+    Hours,
+    Minutes,
+    Seconds,
+    Micros,
+}
+
+fn round_half_away(n: Decimal) -> Decimal {
+    n.round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero)
+}
+
+fn days_in_month(year: i32, month: u32) -> Option<u32> {
+    let first = NaiveDate::from_ymd_opt(year, month, 1)?;
+    let next = if month == 12 {
+        NaiveDate::from_ymd_opt(year + 1, 1, 1)?
+    } else {
+        NaiveDate::from_ymd_opt(year, month + 1, 1)?
+    };
+    Some(next.signed_duration_since(first).num_days() as u32)
+}
+
+impl PartialEq for Interval {
+    fn eq(&self, other: &Self) -> bool {
+        self.total_microseconds() == other.total_microseconds()
+    }
+}
+
+impl Eq for Interval {}
+
+impl PartialOrd for Interval {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Interval {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.total_microseconds().cmp(&other.total_microseconds())
+    }
+}
+
+impl Hash for Interval {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.total_microseconds().hash(state)
+    }
+}
+
+/// Formats intervals the same way as PostgreSQL's default (`postgres`) `IntervalStyle`, eg
+/// `1 year 2 mons -3 days +04:05:06.789`
+impl Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut is_zero = true;
+        let mut is_before = false;
+        let mut write_part = |f: &mut fmt::Formatter<'_>, value: i64, unit: &str| -> fmt::Result {
+            if value == 0 {
+                return Ok(());
+            }
+            write!(
+                f,
+                "{}{}{value} {unit}{}",
+                if is_zero { "" } else { " " },
+                if is_before && value > 0 { "+" } else { "" },
+                if value == 1 { "" } else { "s" }
+            )?;
+            is_before = value < 0;
+            is_zero = false;
+            Ok(())
+        };
+
+        let years = self.months / MONTHS_PER_YEAR;
+        let months = self.months % MONTHS_PER_YEAR;
+        write_part(f, years.into(), "year")?;
+        write_part(f, months.into(), "mon")?;
+        write_part(f, self.days.into(), "day")?;
+
+        if is_zero || self.microseconds != 0 {
+            let micros = self.microseconds.unsigned_abs();
+            let hours = micros / MICROSECONDS_PER_HOUR as u64;
+            let minutes = micros % MICROSECONDS_PER_HOUR as u64 / MICROSECONDS_PER_MINUTE as u64;
+            let seconds = micros % MICROSECONDS_PER_MINUTE as u64 / MICROSECONDS_PER_SECOND as u64;
+            let fraction = micros % MICROSECONDS_PER_SECOND as u64;
+
+            write!(
+                f,
+                "{}{}{hours:02}:{minutes:02}:{seconds:02}",
+                if is_zero { "" } else { " " },
+                if self.microseconds < 0 {
+                    "-"
+                } else if is_before {
+                    "+"
+                } else {
+                    ""
+                }
+            )?;
+            if fraction != 0 {
+                write!(f, ".{}", format!("{fraction:06}").trim_end_matches('0'))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses intervals in the PostgreSQL input format, eg `1 day 2 hours`, `3 mons -1 days
+/// 04:05:06`, `@ 1 year ago`, or `1-2` (one year and two months).
+impl FromStr for Interval {
+    type Err = ReadySetError;
+
+    fn from_str(s: &str) -> ReadySetResult<Self> {
+        let err = || invalid_query_err!("invalid input syntax for type interval: \"{s}\"");
+
+        let input = s.trim().to_lowercase();
+        let input = input.strip_prefix('@').unwrap_or(&input);
+
+        let mut res = Self::default();
+        let mut found_any = false;
+        let mut ago = false;
+        let mut rest = input.trim_start();
+        while !rest.is_empty() {
+            if let Some(after) = rest.strip_prefix("ago") {
+                if !after.trim().is_empty() {
+                    return Err(err());
+                }
+                ago = true;
+                break;
+            }
+
+            let number_len = rest
+                .char_indices()
+                .find(|&(i, c)| {
+                    !(c.is_ascii_digit()
+                        || c == '.'
+                        || c == ':'
+                        || (i > 0 && c == '-')
+                        || (i == 0 && (c == '-' || c == '+')))
+                })
+                .map_or(rest.len(), |(i, _)| i);
+            if number_len == 0 {
+                return Err(err());
+            }
+            let (number, after) = rest.split_at(number_len);
+            let after = after.trim_start();
+            let unit_len = after
+                .find(|c: char| !c.is_ascii_alphabetic())
+                .unwrap_or(after.len());
+            let (unit, after) = after.split_at(unit_len);
+            rest = after.trim_start();
+
+            let part = if number.contains(':') {
+                if !unit.is_empty() {
+                    return Err(err());
+                }
+                parse_time(number).ok_or_else(err)?
+            } else if number[1..].contains('-') {
+                if !unit.is_empty() {
+                    return Err(err());
+                }
+                parse_year_month(number).ok_or_else(err)?
+            } else if unit == "ago" {
+                // A bare number followed by `ago` is a number of seconds
+                rest = "ago";
+                parse_number_with_unit(number, "").ok_or_else(err)?
+            } else {
+                parse_number_with_unit(number, unit).ok_or_else(err)?
+            };
+            res = res
+                .checked_add(&part)
+                .ok_or_else(|| invalid_query_err!("interval out of range"))?;
+            found_any = true;
+        }
+
+        if !found_any {
+            return Err(err());
+        }
+
+        if ago {
+            res = res
+                .checked_neg()
+                .ok_or_else(|| invalid_query_err!("interval out of range"))?;
+        }
+
+        Ok(res)
+    }
+}
+
+/// Parse a time of the form `[+-]H:M[:S[.F]]`
+fn parse_time(s: &str) -> Option<Interval> {
+    let (negative, s) = match s.as_bytes().first()? {
+        b'-' => (true, &s[1..]),
+        b'+' => (false, &s[1..]),
+        _ => (false, s),
+    };
+
+    let mut parts = s.split(':');
+    let hours = parts.next()?.parse::<i64>().ok()?;
+    let minutes = parts.next()?.parse::<i64>().ok()?;
+    let seconds = match parts.next() {
+        Some(seconds) => seconds.parse::<Decimal>().ok()?,
+        None => Decimal::ZERO,
+    };
+    if parts.next().is_some() || !(0..60).contains(&minutes) || seconds >= Decimal::from(60) {
+        return None;
+    }
+
+    let micros = hours
+        .checked_mul(MICROSECONDS_PER_HOUR)?
+        .checked_add(minutes * MICROSECONDS_PER_MINUTE)?
+        .checked_add(
+            (seconds * Decimal::from(MICROSECONDS_PER_SECOND))
+                .round()
+                .to_i64()?,
+        )?;
+    Some(Interval::new(0, 0, if negative { -micros } else { micros }))
+}
+
+/// Parse a year-month interval of the form `[+-]Y-M`
+fn parse_year_month(s: &str) -> Option<Interval> {
+    let (negative, s) = match s.as_bytes().first()? {
+        b'-' => (true, &s[1..]),
+        b'+' => (false, &s[1..]),
+        _ => (false, s),
+    };
+    let (years, months) = s.split_once('-')?;
+    let months = years
+        .parse::<i32>()
+        .ok()?
+        .checked_mul(MONTHS_PER_YEAR)?
+        .checked_add(months.parse::<i32>().ok()?)?;
+    Some(Interval::new(if negative { -months } else { months }, 0, 0))
+}
+
+/// Parse a (possibly fractional) number of the given PostgreSQL interval unit, such as `1.5 days`.
+/// Fractional months are carried into days, and fractional days into time, using 30-day months
+/// and 24-hour days. A number with no unit is a number of seconds.
+fn parse_number_with_unit(number: &str, unit: &str) -> Option<Interval> {
+    enum Field {
+        Months(i64),
+        Days(i64),
+        Microseconds(i64),
+    }
+
+    let field = match unit {
+        "millennium" | "millenniums" | "millennia" | "mil" | "mils" => Field::Months(12_000),
+        "century" | "centuries" | "cent" | "c" => Field::Months(1_200),
+        "decade" | "decades" | "dec" | "decs" => Field::Months(120),
+        "year" | "years" | "yr" | "yrs" | "y" => Field::Months(12),
+        "month" | "months" | "mon" | "mons" => Field::Months(1),
+        "week" | "weeks" | "w" => Field::Days(7),
+        "day" | "days" | "d" => Field::Days(1),
+        "hour" | "hours" | "hr" | "hrs" | "h" => Field::Microseconds(MICROSECONDS_PER_HOUR),
+        "minute" | "minutes" | "min" | "mins" | "m" => Field::Microseconds(MICROSECONDS_PER_MINUTE),
+        "second" | "seconds" | "sec" | "secs" | "s" | "" => {
+            Field::Microseconds(MICROSECONDS_PER_SECOND)
+        }
+        "millisecond" | "milliseconds" | "msec" | "msecs" | "ms" => Field::Microseconds(1_000),
+        "microsecond" | "microseconds" | "usec" | "usecs" | "us" => Field::Microseconds(1),
+        _ => return None,
+    };
+
+    let n = number.parse::<Decimal>().ok()?;
+    let mut res = Interval::default();
+    let spill = |n: Decimal, per: i64| n.checked_mul(Decimal::from(per));
+
+    let days = match field {
+        Field::Months(factor) => {
+            let months = n.checked_mul(Decimal::from(factor))?;
+            res.months = months.trunc().to_i32()?;
+            spill(months.fract(), DAYS_PER_MONTH)?
+        }
+        Field::Days(factor) => n.checked_mul(Decimal::from(factor))?,
+        Field::Microseconds(factor) => {
+            res.microseconds = n.checked_mul(Decimal::from(factor))?.round().to_i64()?;
+            return Some(res);
+        }
+    };
+    res.days = days.trunc().to_i32()?;
+    res.microseconds = spill(days.fract(), MICROSECONDS_PER_DAY)?
+        .round()
+        .to_i64()?;
+
+    Some(res)
+}
+
+impl<'a> FromSql<'a> for Interval {
+    fn from_sql(_: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        if raw.len() != 16 {
+            return Err("invalid message length: interval size mismatch".into());
+        }
+
+        let (microseconds, rest) = raw.split_at(8);
+        let (days, months) = rest.split_at(4);
+        Ok(Self {
+            microseconds: i64::from_be_bytes(microseconds.try_into()?),
+            days: i32::from_be_bytes(days.try_into()?),
+            months: i32::from_be_bytes(months.try_into()?),
+        })
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::INTERVAL
+    }
+}
+
+impl ToSql for Interval {
+    fn to_sql(
+        &self,
+        _: &Type,
+        out: &mut bytes::BytesMut,
+    ) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        out.put_i64(self.microseconds);
+        out.put_i32(self.days);
+        out.put_i32(self.months);
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::INTERVAL
+    }
+
+    to_sql_checked!();
+}
+
+#[cfg(test)]
+mod tests {
+    use readyset_util::{eq_laws, hash_laws, ord_laws};
+    use test_strategy::proptest;
+
+    use super::*;
+
+    eq_laws!(Interval);
+    hash_laws!(Interval);
+    ord_laws!(Interval);
+
+    fn dt(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[test]
+    fn months_equal_thirty_days() {
+        assert_eq!(Interval::new(1, 0, 0), Interval::new(0, 30, 0));
+        assert_eq!(
+            Interval::new(0, 1, 0),
+            Interval::new(0, 0, MICROSECONDS_PER_DAY)
+        );
+        assert!(Interval::new(1, 0, 0) < Interval::new(0, 31, 0));
+    }
+
+    #[proptest]
+    fn normalize_is_equal(interval: Interval) {
+        assert_eq!(interval.normalize(), interval);
+    }
+
+    #[proptest]
+    fn normalize_is_canonical(a: Interval, b: Interval) {
+        if a == b {
+            let (a, b) = (a.normalize(), b.normalize());
+            assert_eq!(
+                (a.months, a.days, a.microseconds),
+                (b.months, b.days, b.microseconds)
+            );
+        }
+    }
+
+    #[proptest]
+    fn display_parse_round_trip(
+        #[strategy(-100_000..100_000)] months: i32,
+        #[strategy(-100_000..100_000)] days: i32,
+        #[strategy(-1_000_000_000_000_i64..1_000_000_000_000)] microseconds: i64,
+    ) {
+        let interval = Interval::new(months, days, microseconds);
+        let parsed = interval.to_string().parse::<Interval>().unwrap();
+        assert_eq!(
+            (parsed.months, parsed.days, parsed.microseconds),
+            (months, days, microseconds)
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Interval::default().to_string(), "00:00:00");
+        assert_eq!(Interval::new(0, 1, 0).to_string(), "1 day");
+        assert_eq!(
+            Interval::new(
+                14,
+                3,
+                4 * MICROSECONDS_PER_HOUR + 5 * MICROSECONDS_PER_MINUTE + 6
+            )
+            .to_string(),
+            "1 year 2 mons 3 days 04:05:00.000006"
+        );
+        assert_eq!(
+            Interval::new(
+                0,
+                -1,
+                2 * MICROSECONDS_PER_HOUR + 3 * MICROSECONDS_PER_MINUTE
+            )
+            .to_string(),
+            "-1 days +02:03:00"
+        );
+        assert_eq!(
+            Interval::new(-13, 0, -MICROSECONDS_PER_SECOND / 2).to_string(),
+            "-1 years -1 mons -00:00:00.5"
+        );
+        assert_eq!(
+            Interval::new(0, 0, 25 * MICROSECONDS_PER_HOUR).to_string(),
+            "25:00:00"
+        );
+    }
+
+    #[test]
+    fn parse() {
+        #[track_caller]
+        fn check(s: &str, months: i32, days: i32, microseconds: i64) {
+            let interval = s.parse::<Interval>().unwrap();
+            assert_eq!(
+                (interval.months, interval.days, interval.microseconds),
+                (months, days, microseconds),
+                "{s}"
+            );
+        }
+
+        check("1 day", 0, 1, 0);
+        check("1 DAY", 0, 1, 0);
+        check("2 weeks", 0, 14, 0);
+        check("1 year 2 months", 14, 0, 0);
+        check("1.5 months", 1, 15, 0);
+        check("1.5 days", 0, 1, 12 * MICROSECONDS_PER_HOUR);
+        check("3 hours 30 minutes", 0, 0, 210 * MICROSECONDS_PER_MINUTE);
+        check("1 day 02:03:04.5", 0, 1, 7_384_500_000);
+        check(
+            "-1 days +02:03",
+            0,
+            -1,
+            2 * MICROSECONDS_PER_HOUR + 3 * MICROSECONDS_PER_MINUTE,
+        );
+        check("@ 1 year ago", -12, 0, 0);
+        check("1-2", 14, 0, 0);
+        check("90", 0, 0, 90 * MICROSECONDS_PER_SECOND);
+        check("1d2h", 0, 1, 2 * MICROSECONDS_PER_HOUR);
+
+        "".parse::<Interval>().unwrap_err();
+        "1 fortnight".parse::<Interval>().unwrap_err();
+        "one day".parse::<Interval>().unwrap_err();
+    }
+
+    #[test]
+    fn add_to_datetime_clamps_month_end() {
+        assert_eq!(
+            Interval::new(1, 0, 0)
+                .add_to_datetime(dt("2020-01-31 12:00:00"))
+                .unwrap(),
+            dt("2020-02-29 12:00:00")
+        );
+        assert_eq!(
+            Interval::new(-1, -1, -MICROSECONDS_PER_HOUR)
+                .add_to_datetime(dt("2020-03-31 00:00:00"))
+                .unwrap(),
+            dt("2020-02-27 23:00:00")
+        );
+    }
+
+    #[test]
+    fn add_to_date_keeps_date_only_for_whole_days() {
+        let date = TimestampTz::from(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap());
+        let res = Interval::new(0, 1, 0).add_to_timestamp(&date).unwrap();
+        assert!(res.has_date_only());
+        assert_eq!(res.to_string(), "2020-01-02");
+
+        let res = Interval::new(0, 0, MICROSECONDS_PER_HOUR)
+            .add_to_timestamp(&date)
+            .unwrap();
+        assert!(!res.has_date_only());
+        assert_eq!(res.to_string(), "2020-01-01 01:00:00");
+    }
+
+    #[test]
+    fn between() {
+        let res = Interval::between(dt("2020-01-02 03:00:00"), dt("2020-01-01 00:00:00")).unwrap();
+        assert_eq!(
+            (res.months, res.days, res.microseconds),
+            (0, 1, 3 * MICROSECONDS_PER_HOUR)
+        );
+        let res = Interval::between(dt("2020-01-01 00:00:00"), dt("2020-01-02 03:00:00")).unwrap();
+        assert_eq!(
+            (res.months, res.days, res.microseconds),
+            (0, -1, -3 * MICROSECONDS_PER_HOUR)
+        );
+    }
+
+    #[test]
+    fn scale() {
+        let one_month = Interval::new(1, 0, 0);
+        assert_eq!(
+            one_month.checked_mul_f64(1.5).unwrap().to_string(),
+            "1 mon 15 days"
+        );
+        assert_eq!(
+            Interval::new(0, 1, 0)
+                .checked_div_f64(3.0)
+                .unwrap()
+                .to_string(),
+            "08:00:00"
+        );
+        assert_eq!(
+            Interval::new(0, 0, MICROSECONDS_PER_HOUR)
+                .checked_mul_f64(3.5)
+                .unwrap()
+                .to_string(),
+            "03:30:00"
+        );
+        assert_eq!(
+            Interval::new(1, 1, MICROSECONDS_PER_HOUR)
+                .checked_mul_f64(-1.0)
+                .unwrap()
+                .to_string(),
+            "-1 mons -1 days -01:00:00"
+        );
+        assert_eq!(one_month.checked_div_f64(0.0), None);
+        assert_eq!(Interval::MAX.checked_mul_f64(2.0), None);
+        assert_eq!(Interval::new(i32::MIN, 0, 0).checked_mul_f64(-1.0), None);
+    }
+
+    #[test]
+    fn age() {
+        assert_eq!(
+            Interval::age(dt("2001-04-10 00:00:00"), dt("1957-06-13 00:00:00"))
+                .unwrap()
+                .to_string(),
+            "43 years 9 mons 27 days"
+        );
+        assert_eq!(
+            Interval::age(dt("1957-06-13 00:00:00"), dt("2001-04-10 00:00:00"))
+                .unwrap()
+                .to_string(),
+            "-43 years -9 mons -27 days"
+        );
+        assert_eq!(
+            Interval::age(dt("2020-03-01 01:00:00"), dt("2020-02-28 02:00:00"))
+                .unwrap()
+                .to_string(),
+            "1 day 23:00:00"
+        );
+    }
+
+    #[test]
+    fn mysql_units() {
+        #[track_caller]
+        fn check(value: &str, unit: IntervalUnit, months: i32, days: i32, microseconds: i64) {
+            let interval = Interval::from_mysql_unit(value, unit).unwrap();
+            assert_eq!(
+                (interval.months, interval.days, interval.microseconds),
+                (months, days, microseconds),
+                "{value} {unit}"
+            );
+        }
+
+        check("30", IntervalUnit::Day, 0, 30, 0);
+        check("1.5", IntervalUnit::Day, 0, 2, 0);
+        check("-2", IntervalUnit::Week, 0, -14, 0);
+        check("1", IntervalUnit::Quarter, 3, 0, 0);
+        check("1.5", IntervalUnit::Second, 0, 0, 1_500_000);
+        check("1-2", IntervalUnit::YearMonth, 14, 0, 0);
+        check("1 2:3:4", IntervalUnit::DaySecond, 0, 1, 7_384_000_000);
+        check(
+            "1:30",
+            IntervalUnit::DaySecond,
+            0,
+            0,
+            90 * MICROSECONDS_PER_SECOND,
+        );
+        check(
+            "-1:30",
+            IntervalUnit::HourMinute,
+            0,
+            0,
+            -90 * MICROSECONDS_PER_MINUTE,
+        );
+        check("1.5", IntervalUnit::SecondMicrosecond, 0, 0, 1_500_000);
+        check("1.000001", IntervalUnit::SecondMicrosecond, 0, 0, 1_000_001);
+
+        assert!(Interval::from_mysql_unit("1:2:3", IntervalUnit::HourMinute).is_none());
+        assert!(Interval::from_mysql_unit("abc", IntervalUnit::Day).is_none());
+    }
+
+    #[test]
+    fn postgres_binary_round_trip() {
+        let interval = Interval::new(14, -3, 123_456_789);
+        let mut buf = bytes::BytesMut::new();
+        interval.to_sql(&Type::INTERVAL, &mut buf).unwrap();
+        let res = Interval::from_sql(&Type::INTERVAL, &buf).unwrap();
+        assert_eq!(
+            (res.months, res.days, res.microseconds),
+            (14, -3, 123_456_789)
+        );
+    }
+}
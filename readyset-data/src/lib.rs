@@ -5,7 +5,7 @@ use std::cmp::Ordering;
 use std::convert::{TryFrom, TryInto};
 use std::error::Error;
 use std::hash::{Hash, Hasher};
-use std::ops::{Add, Div, Mul, Sub};
+use std::ops::{Add, Div, Mul, Rem, Sub};
 use std::sync::Arc;
 use std::{fmt, io, str};
 
@@ -36,6 +36,7 @@ pub mod dialect;
 mod r#enum;
 mod float;
 mod integer;
+mod interval;
 mod ranges;
 mod serde;
 mod text;
@@ -49,6 +50,7 @@ use proptest::prelude::*;
 pub use crate::array::Array;
 pub use crate::collation::Collation;
 pub use crate::dialect::Dialect;
+pub use crate::interval::Interval;
 pub use crate::r#type::{DfType, PgEnumMetadata, PgTypeCategory};
 pub use crate::ranges::{Bound, BoundedRange, IntoBoundedRange, RangeBounds};
 pub use crate::serde::TextRef;
@@ -124,6 +126,8 @@ pub enum DfValue {
     BitVector(Arc<BitVec>),
     /// An array of [`DfValue`]s.
     Array(Arc<Array>),
+    /// An interval of time, to add to or subtract from dates and times.
+    Interval(Arc<Interval>),
     /// Container type for arbitrary unserialized, unsupported types
     PassThrough(Arc<PassThrough>),
     /// A sentinel maximal value.
@@ -174,6 +178,7 @@ impl fmt::Display for DfValue {
                 )
             }
             DfValue::Array(ref arr) => write!(f, "{}", arr),
+            DfValue::Interval(ref i) => write!(f, "{}", i),
             DfValue::PassThrough(ref p) => {
                 write!(f, "[{}:{:x?}]", p.ty.name(), p.data)
             }
@@ -242,6 +247,7 @@ impl DfValue {
             DfValue::Numeric(_) => DfValue::from(Decimal::MIN),
             DfValue::BitVector(_) => DfValue::from(BitVec::new()),
             DfValue::Array(_) => DfValue::empty_array(),
+            DfValue::Interval(_) => DfValue::from(Interval::MIN),
             DfValue::PassThrough(p) => DfValue::PassThrough(Arc::new(PassThrough {
                 ty: p.ty.clone(),
                 format: PassThroughFormat::Binary,
@@ -269,6 +275,7 @@ impl DfValue {
             DfValue::UnsignedInt(_) => DfValue::UnsignedInt(u64::MAX),
             DfValue::Time(_) => DfValue::Time(MySqlTime::MAX),
             DfValue::Numeric(_) => DfValue::from(Decimal::MAX),
+            DfValue::Interval(_) => DfValue::from(Interval::MAX),
             DfValue::TinyText(_)
             | DfValue::Text(_)
             | DfValue::ByteArray(_)
@@ -352,6 +359,7 @@ impl DfValue {
             DfValue::ByteArray(ref array) => !array.is_empty(),
             DfValue::Numeric(ref d) => !d.is_zero(),
             DfValue::BitVector(ref bits) => !bits.is_empty(),
+            DfValue::Interval(ref i) => !i.is_zero(),
             // Truthiness only matters for mysql, and mysql doesn't have arrays, so we can kind of
             // pick whatever we want here - but it makes the most sense to try to limit falsiness to
             // only the things that mysql considers falsey
@@ -418,6 +426,10 @@ impl DfValue {
             Self::ByteArray(_) => Some(ByteArray),
             Self::Numeric(_) => Some(Numeric(None)),
             Self::BitVector(_) => Some(VarBit(None)),
+            Self::Interval(_) => Some(SqlType::Interval {
+                fields: None,
+                precision: None,
+            }),
             // TODO: Once this returns DfType instead of SqlType, an empty array and an array of
             // null should be Array(Unknown) not Unknown.
             Self::Array(vs) => Some(SqlType::Array(Box::new(
@@ -453,6 +465,7 @@ impl DfValue {
             Self::ByteArray(_) => Blob,
             Self::Numeric(_) => DfType::DEFAULT_NUMERIC,
             Self::BitVector(_) => VarBit(None),
+            Self::Interval(_) => DfType::Interval,
             Self::Array(array) => Array(Box::new(
                 array
                     .values()
@@ -578,6 +591,13 @@ impl DfValue {
                     Err(mk_err())
                 }
             }
+            DfValue::Interval(i) => {
+                if let DfType::Text(collation) = to_ty {
+                    Ok(DfValue::from_str_and_collation(&i.to_string(), *collation))
+                } else {
+                    Err(mk_err())
+                }
+            }
             DfValue::BitVector(vec) => match to_ty {
                 DfType::VarBit(None) => Ok(self.clone()),
                 DfType::VarBit(max_size_opt) => match max_size_opt {
@@ -663,6 +683,23 @@ impl DfValue {
         }
     }
 
+    /// If `self` is [`DfValue::Interval`], return a reference to the underlying [`Interval`],
+    /// otherwise return a [`ReadySetError::DfValueConversionError`] for all other [`DfValue`]
+    /// variants.
+    pub fn as_interval(&self) -> ReadySetResult<&Interval> {
+        match self {
+            DfValue::Interval(interval) => Ok(interval),
+            _ => Err(ReadySetError::DfValueConversionError {
+                src_type: match self.sql_type() {
+                    Some(ty) => ty.display(nom_sql::Dialect::PostgreSQL).to_string(),
+                    None => "Null".to_string(),
+                },
+                target_type: "Interval".to_string(),
+                details: "".to_string(),
+            }),
+        }
+    }
+
     /// If `self` is [`DfValue::Text`], [`DfValue::TinyText`] or [`DfValue::ByteArray`], return a
     /// reference to the underlying [`Array`], otherwise return a
     /// [`ReadySetError::DfValueConversionError`] for all other [`DfValue`] variants.
@@ -697,6 +734,7 @@ impl DfValue {
             },
             None => match self {
                 DfValue::Float(f) => Cow::Owned((*f as f64).try_into().unwrap()),
+                DfValue::Interval(i) => Cow::Owned(i.normalize().into()),
                 _ => Cow::Borrowed(self),
             },
         }
@@ -771,6 +809,7 @@ impl DfValue {
     pub fn normalize(self) -> Self {
         match self {
             DfValue::Numeric(d) => DfValue::from(d.normalize()),
+            DfValue::Interval(i) => DfValue::from(i.normalize()),
             _ => self,
         }
     }
@@ -876,6 +915,7 @@ impl PartialEq for DfValue {
                 bits_a.as_ref() == bits_b.as_ref()
             }
             (DfValue::Array(vs_a), DfValue::Array(vs_b)) => vs_a == vs_b,
+            (DfValue::Interval(ia), DfValue::Interval(ib)) => ia == ib,
             (&DfValue::None, &DfValue::None) => true,
             (&DfValue::Max, &DfValue::Max) => true,
             _ => false,
@@ -1028,6 +1068,7 @@ impl Ord for DfValue {
             (DfValue::ByteArray(array_a), DfValue::ByteArray(array_b)) => array_a.cmp(array_b),
             (DfValue::BitVector(bits_a), DfValue::BitVector(bits_b)) => bits_a.cmp(bits_b),
             (DfValue::Array(vs_a), DfValue::Array(vs_b)) => vs_a.cmp(vs_b),
+            (DfValue::Interval(ia), DfValue::Interval(ib)) => ia.cmp(ib),
 
             // for all other kinds of data types, just compare the variants in order
             (_, _) => DfValueKind::from(self).cmp(&DfValueKind::from(other)),
@@ -1062,6 +1103,7 @@ impl Hash for DfValue {
             DfValue::Numeric(ref d) => d.hash(state),
             DfValue::BitVector(ref bits) => bits.hash(state),
             DfValue::Array(ref vs) => vs.hash(state),
+            DfValue::Interval(ref i) => i.hash(state),
             DfValue::PassThrough(ref p) => p.hash(state),
         }
     }
@@ -1290,7 +1332,7 @@ impl TryFrom<DfValue> for Literal {
             DfValue::TimestampTz(_) => Ok(Literal::String(String::try_from(
                 value.coerce_to(&DfType::DEFAULT_TEXT, &DfType::Unknown)?,
             )?)),
-            DfValue::Time(_) => Ok(Literal::String(String::try_from(
+            DfValue::Time(_) | DfValue::Interval(_) => Ok(Literal::String(String::try_from(
                 value.coerce_to(&DfType::DEFAULT_TEXT, &DfType::Unknown)?,
             )?)),
             DfValue::ByteArray(ref array) => Ok(Literal::ByteArray(array.as_ref().clone())),
@@ -1632,6 +1674,12 @@ impl From<Array> for DfValue {
     }
 }
 
+impl From<Interval> for DfValue {
+    fn from(interval: Interval) -> Self {
+        Self::Interval(Arc::new(interval))
+    }
+}
+
 impl From<Vec<DfValue>> for DfValue {
     fn from(vs: Vec<DfValue>) -> Self {
         Self::from(Array::from(vs))
@@ -1818,6 +1866,16 @@ impl ToSql for DfValue {
                     })
                     .and_then(|v| v.to_sql(ty, out))
             }
+            (Self::Text(_) | Self::TinyText(_), &Type::INTERVAL) => <&str>::try_from(self)
+                .unwrap()
+                .parse::<Interval>()
+                .map_err(|e| {
+                    Box::<dyn Error + Send + Sync>::from(format!(
+                        "Could not convert Text into an Interval: {}",
+                        e
+                    ))
+                })
+                .and_then(|i| i.to_sql(ty, out)),
             (Self::Text(_) | Self::TinyText(_), _) => {
                 <&str>::try_from(self).unwrap().to_sql(ty, out)
             }
@@ -1830,6 +1888,7 @@ impl ToSql for DfValue {
             (Self::ByteArray(ref array), _) => array.as_ref().to_sql(ty, out),
             (Self::BitVector(ref bits), _) => bits.as_ref().to_sql(ty, out),
             (Self::Array(ref array), _) => array.as_ref().to_sql(ty, out),
+            (Self::Interval(ref interval), _) => interval.as_ref().to_sql(ty, out),
             (Self::PassThrough(p), _) => p.data.as_ref().to_sql(&p.ty, out),
         }
     }
@@ -1966,6 +2025,7 @@ impl<'a> FromSql<'a> for DfValue {
                     Ok(DfValue::from(out))
                 }
                 Type::BIT | Type::VARBIT => mk_from_sql!(BitVec),
+                Type::INTERVAL => Ok(DfValue::from(Interval::from_sql(ty, raw)?)),
                 ref ty if ty.name() == "citext" => Ok(DfValue::from_str_and_collation(
                     <&str>::from_sql(ty, raw)?,
                     Collation::Citext,
@@ -2024,6 +2084,9 @@ impl TryFrom<&DfValue> for mysql_common::value::Value {
             }
             DfValue::BitVector(_) => internal!("MySQL does not support bit vector types"),
             DfValue::Array(_) => internal!("MySQL does not support array types"),
+            // MySQL has no interval type, so the closest we can get is the interval's text
+            // representation
+            DfValue::Interval(i) => Ok(Value::Bytes(i.to_string().into_bytes())),
         }
     }
 }
@@ -2129,11 +2192,56 @@ macro_rules! arithmetic_operation (
     );
 );
 
+/// Returns the total number of microseconds in the given [`MySqlTime`]
+fn time_microseconds(t: &MySqlTime) -> i64 {
+    let micros = ((i64::from(t.hour()) * 60 + i64::from(t.minutes())) * 60
+        + i64::from(t.seconds()))
+        * 1_000_000
+        + i64::from(t.microseconds());
+    if t.is_positive() {
+        micros
+    } else {
+        -micros
+    }
+}
+
+/// Adds the given interval to a date/time or interval value.
+///
+/// Adding an interval to a time only adds the time component of the interval, and wraps around
+/// midnight, like in PostgreSQL.
+fn add_interval(value: &DfValue, interval: &Interval) -> ReadySetResult<DfValue> {
+    match value {
+        DfValue::None => Ok(DfValue::None),
+        DfValue::TimestampTz(ts) => interval
+            .add_to_timestamp(ts)
+            .map(DfValue::TimestampTz)
+            .ok_or_else(|| invalid_query_err!("timestamp out of range")),
+        DfValue::Time(t) => Ok(DfValue::Time(MySqlTime::from_microseconds(
+            (time_microseconds(t) + interval.microseconds() % (24 * 60 * 60 * 1_000_000))
+                .rem_euclid(24 * 60 * 60 * 1_000_000),
+        ))),
+        DfValue::Interval(other) => other
+            .checked_add(interval)
+            .map(DfValue::from)
+            .ok_or_else(|| invalid_query_err!("interval out of range")),
+        _ => Err(invalid_query_err!(
+            "can't add an interval to a {:?}",
+            DfValueKind::from(value)
+        )),
+    }
+}
+
 impl<'a, 'b> Add<&'b DfValue> for &'a DfValue {
     type Output = ReadySetResult<DfValue>;
 
     fn add(self, other: &'b DfValue) -> Self::Output {
-        Ok(arithmetic_operation!(+, checked_add, self, other))
+        match (self, other) {
+            (DfValue::None, _) | (_, DfValue::None) => Ok(DfValue::None),
+            (value, DfValue::Interval(interval)) | (DfValue::Interval(interval), value) => {
+                add_interval(value, interval)
+            }
+            _ => Ok(arithmetic_operation!(+, checked_add, self, other)),
+        }
     }
 }
 
@@ -2141,7 +2249,38 @@ impl<'a, 'b> Sub<&'b DfValue> for &'a DfValue {
     type Output = ReadySetResult<DfValue>;
 
     fn sub(self, other: &'b DfValue) -> Self::Output {
-        Ok(arithmetic_operation!(-, checked_sub, self, other))
+        match (self, other) {
+            (DfValue::None, _) | (_, DfValue::None) => Ok(DfValue::None),
+            (value, DfValue::Interval(interval)) => add_interval(
+                value,
+                &interval
+                    .checked_neg()
+                    .ok_or_else(|| invalid_query_err!("interval out of range"))?,
+            ),
+            // Like in PostgreSQL, subtracting two dates gives the number of days between them,
+            // whereas subtracting two timestamps gives an interval
+            (DfValue::TimestampTz(a), DfValue::TimestampTz(b))
+                if a.has_date_only() && b.has_date_only() =>
+            {
+                Ok(DfValue::Int(
+                    a.to_chrono()
+                        .date_naive()
+                        .signed_duration_since(b.to_chrono().date_naive())
+                        .num_days(),
+                ))
+            }
+            (DfValue::TimestampTz(a), DfValue::TimestampTz(b)) => {
+                Interval::between(a.to_chrono().naive_utc(), b.to_chrono().naive_utc())
+                    .map(DfValue::from)
+                    .ok_or_else(|| invalid_query_err!("interval out of range"))
+            }
+            (DfValue::Time(a), DfValue::Time(b)) => Ok(DfValue::from(Interval::new(
+                0,
+                0,
+                time_microseconds(a) - time_microseconds(b),
+            ))),
+            _ => Ok(arithmetic_operation!(-, checked_sub, self, other)),
+        }
     }
 }
 
@@ -2149,7 +2288,16 @@ impl<'a, 'b> Mul<&'b DfValue> for &'a DfValue {
     type Output = ReadySetResult<DfValue>;
 
     fn mul(self, other: &'b DfValue) -> Self::Output {
-        Ok(arithmetic_operation!(*, checked_mul, self, other))
+        match (self, other) {
+            (DfValue::None, _) | (_, DfValue::None) => Ok(DfValue::None),
+            (DfValue::Interval(interval), factor) | (factor, DfValue::Interval(interval)) => {
+                interval
+                    .checked_mul_f64(f64::try_from(factor)?)
+                    .map(DfValue::from)
+                    .ok_or_else(|| invalid_query_err!("interval out of range"))
+            }
+            _ => Ok(arithmetic_operation!(*, checked_mul, self, other)),
+        }
     }
 }
 
@@ -2157,7 +2305,33 @@ impl<'a, 'b> Div<&'b DfValue> for &'a DfValue {
     type Output = ReadySetResult<DfValue>;
 
     fn div(self, other: &'b DfValue) -> Self::Output {
-        Ok(arithmetic_operation!(/, checked_div, self, other))
+        match (self, other) {
+            (DfValue::None, _) | (_, DfValue::None) => Ok(DfValue::None),
+            (DfValue::Interval(interval), divisor) => {
+                let divisor = f64::try_from(divisor)?;
+                if divisor == 0.0 {
+                    return Err(invalid_query_err!("division by zero"));
+                }
+                interval
+                    .checked_div_f64(divisor)
+                    .map(DfValue::from)
+                    .ok_or_else(|| invalid_query_err!("interval out of range"))
+            }
+            _ => Ok(arithmetic_operation!(/, checked_div, self, other)),
+        }
+    }
+}
+
+impl<'a, 'b> Rem<&'b DfValue> for &'a DfValue {
+    type Output = ReadySetResult<DfValue>;
+
+    fn rem(self, other: &'b DfValue) -> Self::Output {
+        // `checked_rem` returns `None` for `i64::MIN % -1` since the corresponding division
+        // overflows, but the remainder itself is well-defined
+        if let (DfValue::Int(_), DfValue::Int(-1)) = (self, other) {
+            return Ok(DfValue::Int(0));
+        }
+        Ok(arithmetic_operation!(%, checked_rem, self, other))
     }
 }
 
@@ -2192,6 +2366,7 @@ impl Arbitrary for DfValue {
                 .prop_map(|bs| DfValue::BitVector(Arc::new(BitVec::from_bytes(&bs))))
                 .boxed(),
             Some(DfValueKind::Array) => any::<Array>().prop_map(DfValue::from).boxed(),
+            Some(DfValueKind::Interval) => any::<Interval>().prop_map(DfValue::from).boxed(),
             Some(DfValueKind::PassThrough) => any::<(u32, Vec<u8>)>()
                 .prop_map(|(oid, data)| {
                     DfValue::PassThrough(Arc::new(PassThrough {
@@ -2220,7 +2395,8 @@ impl Arbitrary for DfValue {
                     .prop_map(DfValue::Time),
                 any::<Vec<u8>>().prop_map(|b| DfValue::ByteArray(Arc::new(b))),
                 arbitrary_decimal().prop_map(DfValue::from),
-                any::<Array>().prop_map(DfValue::from)
+                any::<Array>().prop_map(DfValue::from),
+                any::<Interval>().prop_map(DfValue::from)
             ]
             .boxed(),
         }
@@ -2238,7 +2414,7 @@ mod arbitrary {
     use readyset_util::arbitrary::arbitrary_decimal;
 
     use crate::array::Array;
-    use crate::{DfType, DfValue, TimestampTz};
+    use crate::{DfType, DfValue, Interval, TimestampTz};
 
     #[allow(dead_code)]
     pub(crate) fn generate_dfvalue(ty: Option<&DfType>) -> impl Strategy<Value = DfValue> {
@@ -2323,6 +2499,7 @@ mod arbitrary {
                 any::<TimestampTz>().prop_map(DfValue::TimestampTz).boxed()
             }
             Some(DfType::Time { .. }) => any::<MySqlTime>().prop_map(DfValue::Time).boxed(),
+            Some(DfType::Interval) => any::<Interval>().prop_map(DfValue::from).boxed(),
             Some(DfType::Enum { variants, .. }) => proptest::sample::select(variants.to_vec())
                 .prop_map(DfValue::from)
                 .boxed(),
@@ -2460,8 +2637,11 @@ mod tests {
                 if t.to_chrono().naive_local().date().year() < 1000
                     || t.to_chrono().naive_local().date().year() > 9999 =>
                 false,
-            DfValue::ByteArray(_) | DfValue::BitVector(_) | DfValue::Array(_) | DfValue::Max =>
-                false,
+            DfValue::ByteArray(_)
+            | DfValue::BitVector(_)
+            | DfValue::Array(_)
+            | DfValue::Interval(_)
+            | DfValue::Max => false,
             _ => true,
         });
 
@@ -2748,6 +2928,82 @@ mod tests {
         assert_eq!((&DfValue::Int(4) / &DfValue::from(2)).unwrap(), 2.into());
     }
 
+    #[test]
+    fn rem_data_types() {
+        assert_arithmetic!(%, 7, 2, 1);
+        assert_arithmetic!(%, -7, 2, -1);
+        assert_arithmetic!(%, 7.5_f32, 2, 1.5_f32);
+        assert_arithmetic!(%, 7.5_f64, 2, 1.5_f64);
+        assert_arithmetic!(%, Decimal::new(75, 1), 2, Decimal::new(15, 1));
+        assert_arithmetic!(%, 7, 2.5_f64, 2.0_f64);
+        assert_arithmetic!(%, 7, Decimal::new(25, 1), Decimal::new(20, 1));
+        assert_arithmetic!(%, 3.5_f64, Decimal::new(20, 1), Decimal::new(15, 1));
+        assert_arithmetic!(%, 1, 0, None::<i64>);
+        assert_arithmetic!(%, 1_u64, 0_u64, None::<u64>);
+        assert_arithmetic!(%, Decimal::ONE, Decimal::ZERO, None::<Decimal>);
+        assert_arithmetic!(%, i64::MIN, -1, 0);
+        assert_eq!((&DfValue::Int(5) % &DfValue::Int(3)).unwrap(), 2.into());
+        assert_eq!(
+            (&DfValue::UnsignedInt(5) % &DfValue::Int(-3)).unwrap(),
+            2.into()
+        );
+    }
+
+    #[test]
+    fn date_time_interval_arithmetic() {
+        let date = DfValue::from(NaiveDate::from_ymd_opt(2020, 1, 31).unwrap());
+        let timestamp = DfValue::from(
+            NaiveDate::from_ymd_opt(2020, 1, 31)
+                .unwrap()
+                .and_hms_opt(12, 0, 0)
+                .unwrap(),
+        );
+        let one_month = DfValue::from(Interval::new(1, 0, 0));
+        let one_hour = DfValue::from(Interval::new(0, 0, 3_600_000_000));
+
+        assert_eq!((&date + &one_month).unwrap().to_string(), "2020-02-29");
+        assert_eq!(
+            (&one_hour + &date).unwrap().to_string(),
+            "2020-01-31 01:00:00"
+        );
+        assert_eq!(
+            (&timestamp - &one_month).unwrap().to_string(),
+            "2019-12-31 12:00:00"
+        );
+        assert_eq!(
+            (&timestamp - &date).unwrap(),
+            DfValue::from(Interval::new(0, 0, 12 * 3_600_000_000))
+        );
+        assert_eq!(
+            (&(&date + &one_month).unwrap() - &date).unwrap(),
+            DfValue::Int(29)
+        );
+        assert_eq!(
+            (&DfValue::Time(MySqlTime::from_hmsus(true, 23, 30, 0, 0)) + &one_hour).unwrap(),
+            DfValue::Time(MySqlTime::from_hmsus(true, 0, 30, 0, 0))
+        );
+        assert_eq!(
+            (&one_month + &one_hour).unwrap().to_string(),
+            "1 mon 01:00:00"
+        );
+        assert_eq!((&DfValue::None + &one_hour).unwrap(), DfValue::None);
+        (&DfValue::from(1) + &one_hour).unwrap_err();
+        assert_eq!(
+            (&one_hour * &DfValue::from(2)).unwrap().to_string(),
+            "02:00:00"
+        );
+        assert_eq!(
+            (&DfValue::Double(1.5) * &one_month).unwrap().to_string(),
+            "1 mon 15 days"
+        );
+        assert_eq!(
+            (&one_hour / &DfValue::from(4)).unwrap().to_string(),
+            "00:15:00"
+        );
+        (&one_hour / &DfValue::from(0)).unwrap_err();
+        (&DfValue::from(2) / &one_hour).unwrap_err();
+    }
+
     #[test]
     fn invalid_arithmetic_returns_error() {
         (&DfValue::from(0) + &DfValue::from("abc")).unwrap_err();
@@ -11,7 +11,7 @@ use lazy_static::lazy_static;
 use readyset_errors::{ReadySetError, ReadySetResult};
 use regex::Regex;
 
-use crate::{Array, Collation, DfType, DfValue};
+use crate::{Array, Collation, DfType, DfValue, Interval};
 
 pub(crate) const TINYTEXT_WIDTH: usize = 14;
 
@@ -557,6 +557,11 @@ pub(crate) trait TextCoerce: Sized + Clone + Into<DfValue> {
                 Err(e) => Err(Self::coerce_err(to_ty, e)),
             },
 
+            DfType::Interval => Ok(str
+                .parse::<Interval>()
+                .map_err(|e| Self::coerce_err(to_ty, e))?
+                .into()),
+
             DfType::Float => str
                 .parse::<f32>()
                 .map_err(|e| Self::coerce_err(to_ty, e))?
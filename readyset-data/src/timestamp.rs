@@ -103,6 +103,12 @@ impl TimestampTz {
         self.extra[2] |= TimestampTz::DATE_FLAG
     }
 
+    /// Mark this timestamp as containing a time as well as a date
+    #[inline(always)]
+    pub(crate) fn clear_date_only(&mut self) {
+        self.extra[2] &= !TimestampTz::DATE_FLAG
+    }
+
     /// Return the timezone offset from UTC in seconds
     #[inline(always)]
     fn get_offset(&self) -> i32 {
@@ -460,7 +466,8 @@ impl TimestampTz {
             | DfType::Uuid
             | DfType::Bit(_)
             | DfType::VarBit(_)
-            | DfType::Array(_) => Err(ReadySetError::DfValueConversionError {
+            | DfType::Array(_)
+            | DfType::Interval => Err(ReadySetError::DfValueConversionError {
                 src_type: "DfValue::TimestampTz".to_string(),
                 target_type: format!("{:?}", to_ty),
                 details: "Not allowed".to_string(),
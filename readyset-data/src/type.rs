@@ -194,6 +194,12 @@ pub enum DfType {
 
     /// [PostgreSQL `jsonb`](https://www.postgresql.org/docs/current/datatype-json.html).
     Jsonb,
+
+    /// [PostgreSQL `interval`](https://www.postgresql.org/docs/current/datatype-datetime.html).
+    ///
+    /// MySQL has no interval column type, but this is also the type of MySQL `INTERVAL`
+    /// expressions, which can only be used in date and time arithmetic.
+    Interval,
 }
 
 /// Defaults.
@@ -309,7 +315,7 @@ impl DfType {
             TimestampTz => Self::TimestampTz {
                 subsecond_digits: dialect.default_subsecond_digits(),
             },
-            Interval { .. } => unsupported!("Unsupported type: INTERVAL"),
+            Interval { .. } => Self::Interval,
             Uuid => Self::Uuid,
             MacAddr => unsupported!("Unsupported type: MacAddr"),
             Inet => unsupported!("Unsupported type: Inet"),
@@ -368,6 +374,7 @@ impl DfType {
             | DfType::Time { .. }
             | DfType::Timestamp { .. }
             | DfType::TimestampTz { .. } => PgTypeCategory::DateTime,
+            DfType::Interval => PgTypeCategory::Timespan,
             DfType::MacAddr | DfType::Inet => PgTypeCategory::NetworkAddress,
             DfType::Uuid | DfType::Enum { .. } | DfType::Json | DfType::Jsonb => {
                 PgTypeCategory::UserDefined
@@ -683,6 +690,7 @@ impl Arbitrary for DfType {
                 .prop_map(|(variants, metadata)| DfType::Enum { variants, metadata }),
             Just(DfType::Json),
             Just(DfType::Jsonb),
+            Just(DfType::Interval),
         ];
 
         base_type
@@ -767,7 +775,8 @@ impl fmt::Display for DfType {
             | Self::MacAddr
             | Self::Uuid
             | Self::Json
-            | Self::Jsonb => write!(f, "{kind:?}"),
+            | Self::Jsonb
+            | Self::Interval => write!(f, "{kind:?}"),
 
             Self::Text(collation) => {
                 write!(f, "Text")?;
@@ -1,5 +1,6 @@
 use std::borrow::Cow;
 use std::cmp::Ordering;
+use std::sync::atomic::{self, AtomicUsize};
 use std::sync::Arc;
 
 use ahash::RandomState;
@@ -14,6 +15,8 @@ use readyset_data::Bound;
 use vec1::Vec1;
 
 pub use self::multir::LookupError;
+pub(crate) use self::spill::ReaderSpill;
+use self::spill::SpilledKeys;
 use crate::prelude::*;
 
 /// The kind of reader update notification, currently the eviction epoch of the writer
@@ -144,6 +147,7 @@ fn new_inner(
 
     let (notifier, receiver) = tokio::sync::broadcast::channel(1);
     let partial = trigger.is_some();
+    let published_size = Arc::new(AtomicUsize::new(0));
     let w = WriteHandle {
         partial,
         replay_done: partial,
@@ -152,8 +156,10 @@ fn new_inner(
         cols,
         contiguous,
         mem_size: 0,
+        published_size: Arc::clone(&published_size),
         notifier,
         eviction_epoch: 0,
+        spill: None,
     };
 
     let r = SingleReadHandle {
@@ -163,6 +169,9 @@ fn new_inner(
         post_lookup: post_processing,
         receiver,
         eviction_epoch: 0,
+        published_size,
+        writer: None,
+        spill: None,
     };
 
     (r, w)
@@ -170,6 +179,7 @@ fn new_inner(
 
 mod multir;
 mod multiw;
+mod spill;
 
 fn key_to_single(k: Key) -> Cow<DfValue> {
     assert_eq!(k.len(), 1);
@@ -188,10 +198,14 @@ pub(crate) struct WriteHandle {
     index: Index,
     contiguous: bool,
     mem_size: usize,
+    /// The value of `mem_size` as of the last call to `swap()`, shared with the read handles
+    published_size: Arc<AtomicUsize>,
     /// The notifier can be used to notify readers that are waiting on changes
     notifier: ReaderUpdatedSender,
     /// How many eviction rounds this handle had
     eviction_epoch: usize,
+    /// On-disk storage for keys evicted from this reader, if it spills evicted keys to disk
+    spill: Option<ReaderSpill>,
 }
 
 type Key<'a> = Cow<'a, [DfValue]>;
@@ -220,6 +234,11 @@ impl<'a> MutWriteHandleEntry<'a> {
             .iter()
             .any(LookupError::is_miss)
         {
+            // The replay filling this key contains all its rows, so promote it out of the spilled
+            // state (if it's there) to avoid serving it from both
+            if let Some(spill) = &self.handle.spill {
+                spill.remove(&self.key);
+            }
             // TODO(ENG-726): Trying to introspect how much memory these data structures
             // are using for storing key value pairs can provide a poor estimate. Handling
             // memory tracking closer to where the data is stored will be beneficial.
@@ -245,8 +264,14 @@ impl<'a> MutWriteHandleEntry<'a> {
             })
             .unwrap_or(0);
         self.handle.mem_size = self.handle.mem_size.saturating_sub(size);
+        let spill_size = self
+            .handle
+            .spill
+            .as_ref()
+            .map(|spill| spill.remove(&self.key))
+            .unwrap_or(0);
         self.handle.handle.empty(self.key);
-        size as u64
+        size as u64 + spill_size
     }
 }
 
@@ -281,7 +306,7 @@ impl WriteHandle {
     }
 
     pub(crate) fn interval_difference(&self, key: KeyComparison) -> Option<Vec<KeyComparison>> {
-        match self.handle.read().get_multi(&[key]) {
+        match self.handle.read().get_multi(&[key], None) {
             Err(LookupError::Miss((misses, _))) => {
                 Some(misses.into_iter().map(|c| c.into_owned()).collect())
             }
@@ -295,6 +320,8 @@ impl WriteHandle {
 
     pub(crate) fn swap(&mut self) {
         self.handle.refresh();
+        self.published_size
+            .store(self.deep_size_of() as usize, atomic::Ordering::Relaxed);
     }
 
     pub(crate) fn len(&self) -> usize {
@@ -319,6 +346,28 @@ impl WriteHandle {
             _ => {}
         }
     }
+
+    /// Returns true if this reader spills evicted keys to disk
+    pub(crate) fn spills(&self) -> bool {
+        self.spill.is_some()
+    }
+
+    /// Apply the given records, which missed in this reader's map, to any keys that have been
+    /// spilled to disk.
+    ///
+    /// Unlike [`WriteHandle::add`], these are visible to readers immediately.
+    pub(crate) fn add_spilled(&mut self, rs: Vec<Record>) {
+        if let Some(spill) = &self.spill {
+            spill.add(rs);
+        }
+    }
+
+    /// Spill keys evicted from this reader to disk, rather than dropping them
+    pub(crate) fn set_spill(&mut self, spill: ReaderSpill) {
+        debug_assert!(self.partial);
+        self.spill = Some(spill);
+    }
+
     pub(crate) fn set_timestamp(&mut self, t: Timestamp) {
         self.handle.set_timestamp(t);
     }
@@ -328,8 +377,13 @@ impl WriteHandle {
     }
 
     /// Evict from state according to the [`EvictionQuantity`]. Returns the number of bytes freed
-    /// and if the request is EvictionQuantity::SingleKey, returns the key that was evicted.
-    fn evict_inner(&mut self, request: EvictionQuantity) -> (u64, Option<Vec<DfValue>>) {
+    /// and if the request is EvictionQuantity::SingleKey, returns the key that was evicted. If
+    /// `spilled` is given, each evicted key is pushed onto it along with all its rows.
+    fn evict_inner(
+        &mut self,
+        request: EvictionQuantity,
+        spilled: Option<&mut SpilledKeys>,
+    ) -> (u64, Option<Vec<DfValue>>) {
         let (bytes_to_be_freed, eviction) = if self.mem_size > 0 {
             debug_assert!(
                 !self.handle.is_empty(),
@@ -337,7 +391,7 @@ impl WriteHandle {
                 self.mem_size
             );
 
-            self.handle.evict(request)
+            self.handle.evict(request, spilled)
         } else {
             (0, None)
         };
@@ -348,15 +402,43 @@ impl WriteHandle {
 
     /// Attempt to evict `bytes` from state. This approximates the number of keys to evict,
     /// these keys may not have exactly `bytes` worth of state.
+    ///
+    /// If this reader spills to disk, keys evicted from a [`HashMap`] index are demoted to disk
+    /// rather than dropped, and once nothing is left in memory keys are evicted from disk instead.
+    ///
+    /// [`HashMap`]: IndexType::HashMap
     pub(crate) fn evict_bytes(&mut self, bytes: usize) -> u64 {
+        let Some(spill) = self.spill.clone() else {
+            let request = EvictionQuantity::Ratio(bytes as f64 / self.mem_size as f64);
+            return self.evict_inner(request, None).0;
+        };
+
+        if self.mem_size == 0 || self.handle.is_empty() {
+            return spill.evict_bytes(bytes);
+        }
         let request = EvictionQuantity::Ratio(bytes as f64 / self.mem_size as f64);
-        self.evict_inner(request).0
+        if self.index.index_type != IndexType::HashMap {
+            return self.evict_inner(request, None).0;
+        }
+        let mut spilled = SpilledKeys::new();
+        let (bytes_freed, _) = self.evict_inner(request, Some(&mut spilled));
+        bytes_freed.saturating_sub(spill.demote(spilled))
+    }
+
+    /// Evict every key from state, including any that have been spilled to disk, returning the
+    /// number of bytes freed
+    pub(crate) fn evict_all(&mut self) -> u64 {
+        let freed = self.evict_inner(EvictionQuantity::Ratio(1.0), None).0;
+        match &self.spill {
+            Some(spill) => freed + spill.evict_bytes(usize::MAX),
+            None => freed,
+        }
     }
 
     /// Evict a single key from state
     pub(crate) fn evict_random(&mut self) -> (u64, Option<Vec<DfValue>>) {
         let request = EvictionQuantity::SingleKey;
-        self.evict_inner(request)
+        self.evict_inner(request, None)
     }
 
     pub(crate) fn mark_hole(&mut self, key: &KeyComparison) -> ReadySetResult<u64> {
@@ -373,7 +455,7 @@ impl WriteHandle {
                 let size = self
                     .handle
                     .read()
-                    .get_multi(std::slice::from_ref(&range_key))
+                    .get_multi(std::slice::from_ref(&range_key), None)
                     .map(|rs| {
                         rs.iter()
                             .flat_map(|rs| rs.iter().map(SizeOf::deep_size_of))
@@ -458,6 +540,11 @@ impl SizeOf for WriteHandle {
 
     fn deep_size_of(&self) -> u64 {
         self.mem_size as u64
+            + self
+                .spill
+                .as_ref()
+                .map(|spill| spill.deep_size_of())
+                .unwrap_or(0)
     }
 
     fn is_empty(&self) -> bool {
@@ -483,6 +570,13 @@ pub struct SingleReadHandle {
     receiver: ReaderUpdatedNotifier,
     /// Caches the eviction epoch of the associated [`WriteHandle`]
     eviction_epoch: usize,
+    /// The approximate size of the state as of the last time the [`WriteHandle`] was swapped
+    published_size: Arc<AtomicUsize>,
+    /// The address of the domain replica that writes to this reader, and the index of the reader
+    /// within that domain, if it's partial
+    writer: Option<(ReplicaAddress, LocalNodeIndex)>,
+    /// On-disk storage for keys evicted from this reader, which lookups fall back to
+    spill: Option<ReaderSpill>,
 }
 
 impl Clone for SingleReadHandle {
@@ -494,6 +588,9 @@ impl Clone for SingleReadHandle {
             post_lookup: self.post_lookup.clone(),
             receiver: self.receiver.resubscribe(),
             eviction_epoch: self.eviction_epoch,
+            published_size: Arc::clone(&self.published_size),
+            writer: self.writer,
+            spill: self.spill.clone(),
         }
     }
 }
@@ -542,7 +639,7 @@ impl SingleReadHandle {
         &self,
         keys: &'a [KeyComparison],
     ) -> Result<SharedResults, LookupError<'a>> {
-        match self.handle.get_multi(keys) {
+        match self.handle.get_multi(keys, self.spill.as_ref()) {
             Err(e) if e.is_miss() && self.trigger.is_none() => Ok(SharedResults::default()),
             r => r,
         }
@@ -556,7 +653,7 @@ impl SingleReadHandle {
     ) -> Result<SharedResults, LookupError<'a, ReaderUpdatedNotifier>> {
         match self
             .handle
-            .get_multi_and_map_error(keys, || self.receiver.resubscribe())
+            .get_multi_and_map_error(keys, self.spill.as_ref(), || self.receiver.resubscribe())
         {
             Err(e) if e.is_miss() && self.trigger.is_none() => Ok(SharedResults::default()),
             r => r,
@@ -584,6 +681,35 @@ impl SingleReadHandle {
         self.handle.was_dropped()
     }
 
+    /// Returns true if this handle is for a partially materialized reader, which can be evicted
+    /// from
+    pub fn is_partial(&self) -> bool {
+        self.trigger.is_some()
+    }
+
+    /// Returns the approximate size in bytes of the state visible through this handle
+    pub fn state_size(&self) -> usize {
+        self.published_size.load(atomic::Ordering::Relaxed)
+    }
+
+    /// Returns the address of the domain replica that writes to this reader, along with the index
+    /// of the reader within that domain, which evictions from the reader should be sent to
+    pub fn writer(&self) -> Option<(ReplicaAddress, LocalNodeIndex)> {
+        self.writer
+    }
+
+    /// Records the address of the domain replica that writes to this reader, and the index of the
+    /// reader within that domain
+    pub(crate) fn set_writer(&mut self, domain: ReplicaAddress, node: LocalNodeIndex) {
+        self.writer = Some((domain, node));
+    }
+
+    /// Fall back to the given on-disk storage for keys evicted from this reader on lookups that
+    /// miss
+    pub(crate) fn set_spill(&mut self, spill: ReaderSpill) {
+        self.spill = Some(spill);
+    }
+
     pub fn eviction_epoch(&mut self) -> usize {
         while !self.receiver.is_empty() {
             if let Ok(epoch) = self.receiver.try_recv() {
@@ -880,4 +1006,104 @@ mod tests {
             assert!(r.get_multi(range_key).err().unwrap().is_miss());
         }
     }
+
+    mod spill {
+        use dataflow_state::{DurabilityMode, PersistenceParameters};
+
+        use super::*;
+
+        fn setup(name: &str) -> (SingleReadHandle, WriteHandle) {
+            let index = Index::hash_map(vec![0]);
+            let (mut r, mut w) = new_partial(
+                2,
+                index.clone(),
+                |_: &mut dyn Iterator<Item = KeyComparison>, _| true,
+                EvictionKind::Random,
+                ReaderProcessing::default(),
+            );
+            let state = PersistentState::new(
+                name.to_owned(),
+                Vec::<Box<[usize]>>::new(),
+                &PersistenceParameters {
+                    mode: DurabilityMode::DeleteOnExit,
+                    ..PersistenceParameters::default()
+                },
+            )
+            .unwrap();
+            let spill = ReaderSpill::new(state, &index, Default::default());
+            r.set_spill(spill.clone());
+            w.set_spill(spill);
+            w.swap();
+            (r, w)
+        }
+
+        fn fill(w: &mut WriteHandle, key: i32, rows: Vec<Vec<DfValue>>) {
+            w.mark_filled(vec1![DfValue::from(key)].into()).unwrap();
+            w.add(rows.into_iter().map(Record::Positive));
+            w.swap();
+        }
+
+        fn lookup(r: &SingleReadHandle, key: i32) -> Option<Vec<Vec<DfValue>>> {
+            match r.get_multi(&[vec1![DfValue::from(key)].into()]) {
+                Ok(res) => Some(
+                    res.into_iter()
+                        .flat_map(|rows| rows.iter().map(|row| row.to_vec()).collect::<Vec<_>>())
+                        .collect(),
+                ),
+                Err(e) if e.is_miss() => None,
+                Err(e) => panic!("{e:?}"),
+            }
+        }
+
+        #[test]
+        fn evicted_keys_are_served_from_disk() {
+            let (r, mut w) = setup("reader_spill_evicted_keys_are_served_from_disk");
+            fill(&mut w, 1, vec![vec![1.into(), "a".into()]]);
+            fill(&mut w, 2, vec![vec![2.into(), "b".into()]]);
+
+            w.evict_bytes(usize::MAX);
+            w.swap();
+            assert!(!w.contains_key(&[1.into()]).unwrap());
+            assert!(!w.contains_key(&[2.into()]).unwrap());
+
+            assert_eq!(lookup(&r, 1), Some(vec![vec![1.into(), "a".into()]]));
+            assert_eq!(lookup(&r, 2), Some(vec![vec![2.into(), "b".into()]]));
+            assert_eq!(lookup(&r, 3), None);
+
+            // Once nothing is left in memory, keys are evicted from disk
+            w.evict_bytes(usize::MAX);
+            w.swap();
+            assert_eq!(lookup(&r, 1), None);
+            assert_eq!(lookup(&r, 2), None);
+        }
+
+        #[test]
+        fn writes_to_spilled_keys() {
+            let (r, mut w) = setup("reader_spill_writes_to_spilled_keys");
+            fill(&mut w, 1, vec![vec![1.into(), "a".into()]]);
+            w.evict_bytes(usize::MAX);
+            w.swap();
+
+            w.add_spilled(vec![
+                Record::Negative(vec![1.into(), "a".into()]),
+                Record::Positive(vec![1.into(), "b".into()]),
+                Record::Positive(vec![2.into(), "b".into()]),
+            ]);
+            assert_eq!(lookup(&r, 1), Some(vec![vec![1.into(), "b".into()]]));
+            assert_eq!(lookup(&r, 2), None);
+
+            // Replaying the key again promotes it back into memory
+            fill(&mut w, 1, vec![vec![1.into(), "b".into()]]);
+            assert!(w.contains_key(&[1.into()]).unwrap());
+            assert_eq!(lookup(&r, 1), Some(vec![vec![1.into(), "b".into()]]));
+
+            // Evicting a key drops it from both memory and disk
+            w.evict_bytes(usize::MAX);
+            w.swap();
+            assert_eq!(lookup(&r, 1), Some(vec![vec![1.into(), "b".into()]]));
+            w.mark_hole(&vec1![DfValue::from(1)].into()).unwrap();
+            w.swap();
+            assert_eq!(lookup(&r, 1), None);
+        }
+    }
 }
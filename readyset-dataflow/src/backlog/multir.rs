@@ -15,6 +15,8 @@ use serde::{Deserialize, Serialize};
 use tracing::warn;
 use vec1::{vec1, Vec1};
 
+use super::spill::ReaderSpill;
+
 /// A [`ReadHandle`] to a map whose key is a single [`DfValue`], for faster lookup (compared to a
 /// Vec with len == 1)
 type HandleSingle = reader_map::handles::ReadHandle<
@@ -112,6 +114,7 @@ impl Handle {
     fn get_multi_single_handle<'a, T, F: Fn() -> T>(
         handle: &HandleSingle,
         keys: &'a [KeyComparison],
+        spill: Option<&ReaderSpill>,
         miss_meta: F,
     ) -> Result<SharedResults, LookupError<'a, T>> {
         let mut prev_keys = HashSet::new();
@@ -132,7 +135,10 @@ impl Handle {
                 }
                 KeyComparison::Equal(k) => match map.get(&k[0]) {
                     Some(v) => hits.push(v.as_ref().clone()),
-                    None => misses.push(Cow::Borrowed(key)),
+                    None => match spill.and_then(|spill| spill.lookup(key)) {
+                        Some(rows) => hits.push(rows),
+                        None => misses.push(Cow::Borrowed(key)),
+                    },
                 },
                 KeyComparison::Range((start, end)) => {
                     if key.is_reversed_range() {
@@ -166,6 +172,7 @@ impl Handle {
     fn get_multi_many_handle<'a, T, F: Fn() -> T>(
         handle: &HandleMany,
         keys: &'a [KeyComparison],
+        spill: Option<&ReaderSpill>,
         miss_meta: F,
     ) -> Result<SharedResults, LookupError<'a, T>> {
         let mut prev_keys = HashSet::new();
@@ -186,7 +193,10 @@ impl Handle {
                 }
                 KeyComparison::Equal(k) => match map.get(k.as_slice()) {
                     Some(v) => hits.push(v.as_ref().clone()),
-                    None => misses.push(Cow::Borrowed(key)),
+                    None => match spill.and_then(|spill| spill.lookup(key)) {
+                        Some(rows) => hits.push(rows),
+                        None => misses.push(Cow::Borrowed(key)),
+                    },
                 },
                 KeyComparison::Range((start, end)) => {
                     if key.is_reversed_range() {
@@ -219,29 +229,33 @@ impl Handle {
     }
 
     /// Retrieve results for multiple keys from the map under the same read guard, assuring that all
-    /// of the values refer to the same state map.
+    /// of the values refer to the same state map. Keys that miss in the map are looked up in
+    /// `spill`, if given.
     pub(super) fn get_multi<'a>(
         &self,
         keys: &'a [KeyComparison],
+        spill: Option<&ReaderSpill>,
     ) -> Result<SharedResults, LookupError<'a>> {
         match self {
-            Handle::Single(h) => Self::get_multi_single_handle(h, keys, || {}),
-            Handle::Many(h) => Self::get_multi_many_handle(h, keys, || {}),
+            Handle::Single(h) => Self::get_multi_single_handle(h, keys, spill, || {}),
+            Handle::Many(h) => Self::get_multi_many_handle(h, keys, spill, || {}),
         }
     }
 
     /// Retrieve results for multiple keys from the map under the same read guard, assuring that all
     /// of the values refer to the same state map. If the get misses, the provided closure will be
     /// used to map the results of the miss, also under the same read guard, ensuring no writer swap
-    /// took place between the miss and the closure being called.
+    /// took place between the miss and the closure being called. Keys that miss in the map are
+    /// looked up in `spill`, if given.
     pub(super) fn get_multi_and_map_error<'a, T, F: Fn() -> T>(
         &self,
         keys: &'a [KeyComparison],
+        spill: Option<&ReaderSpill>,
         miss_meta: F,
     ) -> Result<SharedResults, LookupError<'a, T>> {
         match self {
-            Handle::Single(h) => Self::get_multi_single_handle(h, keys, miss_meta),
-            Handle::Many(h) => Self::get_multi_many_handle(h, keys, miss_meta),
+            Handle::Single(h) => Self::get_multi_single_handle(h, keys, spill, miss_meta),
+            Handle::Many(h) => Self::get_multi_many_handle(h, keys, spill, miss_meta),
         }
     }
 
@@ -415,7 +429,7 @@ mod tests {
             Bound::Included(vec1![3i32.into()]),
         ));
 
-        let res = handle.get_multi(&[key]).unwrap();
+        let res = handle.get_multi(&[key], None).unwrap();
         assert_eq!(
             res.iter()
                 .flat_map(|rs| rs.iter())
@@ -464,7 +478,7 @@ mod tests {
             Bound::Included(vec1![3i32.into(), 3i32.into()]),
         ));
 
-        let res = handle.get_multi(&[key]).unwrap();
+        let res = handle.get_multi(&[key], None).unwrap();
         assert_eq!(
             res.iter()
                 .flat_map(|rs| rs.iter())
@@ -496,7 +510,7 @@ mod tests {
         ];
 
         // Ensure that get_multi() deduplicates equal keys
-        let res = handle.get_multi(&keys).unwrap();
+        let res = handle.get_multi(&keys, None).unwrap();
         assert_eq!(
             res.iter()
                 .flat_map(|rs| rs.iter())
@@ -532,7 +546,7 @@ mod tests {
         ];
 
         // Ensure that get_multi() deduplicates equal keys
-        let res = handle.get_multi(&keys).unwrap();
+        let res = handle.get_multi(&keys, None).unwrap();
         assert_eq!(
             res.iter()
                 .flat_map(|rs| rs.iter())
@@ -5,6 +5,7 @@ use readyset_client::consistency::Timestamp;
 use readyset_data::Bound;
 use readyset_util::ranges::RangeBounds;
 
+use super::spill::SpilledKeys;
 use super::{key_to_single, Key};
 use crate::prelude::*;
 
@@ -92,12 +93,20 @@ impl Handle {
     /// of keys evicted will be ceil(len() * ratio).
     ///
     /// Returns the number of bytes evicted, and if passed an EvictionQuantity::SingleKey, returns
-    /// the key that was evicted.
-    pub fn evict(&mut self, keys_to_evict: EvictionQuantity) -> (u64, Option<Vec<DfValue>>) {
+    /// the key that was evicted. If `spilled` is given, each evicted key is pushed onto it along
+    /// with all its rows.
+    pub fn evict(
+        &mut self,
+        keys_to_evict: EvictionQuantity,
+        mut spilled: Option<&mut SpilledKeys>,
+    ) -> (u64, Option<Vec<DfValue>>) {
         let base_value_size = self.base_value_size() as u64;
         match *self {
             Handle::Single(ref mut h) => {
                 let (bytes, key) = h.evict_keys(keys_to_evict, |k, v| {
+                    if let Some(spilled) = spilled.as_mut() {
+                        spilled.push((vec![k.clone()], v.as_ref().clone()));
+                    }
                     // Each row's state is composed of: The key, the set of Values in the row
                     // (DfValues) and the bytes required to hold the Row data
                     // structure.
@@ -109,6 +118,9 @@ impl Handle {
                 (bytes, key.map(|k| vec![k]))
             }
             Handle::Many(ref mut h) => h.evict_keys(keys_to_evict, |k, v| {
+                if let Some(spilled) = spilled.as_mut() {
+                    spilled.push((k.clone(), v.as_ref().clone()));
+                }
                 k.deep_size_of() + v.iter().map(|r| r.deep_size_of()).sum::<u64>() + base_value_size
             }),
         }
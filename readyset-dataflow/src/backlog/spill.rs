@@ -0,0 +1,326 @@
+//! On-disk storage for keys evicted from a partially materialized reader.
+//!
+//! When a reader with a [`ReaderSpill`] is asked to free memory, the keys chosen for eviction from
+//! its [`HashMap`] index are *demoted* to a partial [`PersistentState`] along with their rows,
+//! rather than being dropped. Lookups that miss in the reader's map fall back to the spilled keys,
+//! so demoted keys can still be served (albeit more slowly) without an upquery. Writes to demoted
+//! keys are applied to the spilled state directly, and keys are only ever *promoted* back into the
+//! reader's map by a replay, which first removes the key from the spilled state.
+//!
+//! Unlike writes to the reader's map, writes to the spilled state become visible to readers
+//! immediately rather than on the next swap.
+//!
+//! Lookups never wait for the domain to write to disk: rows are read through a
+//! [`PersistentStateHandle`], which can read concurrently with writes, and the set of spilled keys
+//! is tracked separately in memory. Since a key's rows are only written before it's added to that
+//! set and only deleted after it's removed from it, the only race is a key being removed while its
+//! rows are being read, which lookups detect and treat as a miss.
+//!
+//! [`HashMap`]: IndexType::HashMap
+
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+
+use common::SizeOf;
+use dataflow_expression::PreInsertion;
+use dataflow_state::{PersistentStateHandle, PointKey};
+use partial_map::InsertionOrder;
+use readyset_client::results::SharedRows;
+use readyset_client::KeyComparison;
+use tracing::warn;
+use vec1::Vec1;
+
+use crate::prelude::*;
+
+/// The tag of the (only) partial index of the spilled state
+fn spill_tag() -> Tag {
+    Tag::new(0)
+}
+
+/// Keys evicted from a reader's map, along with all their rows, to be demoted to disk
+pub(super) type SpilledKeys = Vec<(Vec<DfValue>, SharedRows)>;
+
+/// The set of keys which are currently spilled, along with the memory used to track them
+#[derive(Default)]
+struct SpilledKeySet {
+    keys: HashSet<Vec<DfValue>>,
+    size: u64,
+}
+
+impl SpilledKeySet {
+    fn insert(&mut self, key: Vec<DfValue>) {
+        let size = key.deep_size_of();
+        if self.keys.insert(key) {
+            self.size += size;
+        }
+    }
+
+    fn remove(&mut self, key: &[DfValue]) {
+        if let Some(key) = self.keys.take(key) {
+            self.size = self.size.saturating_sub(key.deep_size_of());
+        }
+    }
+}
+
+/// Marks keys as being removed from the spilled state for as long as it's alive, by keeping
+/// [`ReaderSpill::removals`] odd
+struct Removing<'a>(&'a AtomicU64);
+
+impl<'a> Removing<'a> {
+    fn new(removals: &'a AtomicU64) -> Self {
+        removals.fetch_add(1, Ordering::SeqCst);
+        Self(removals)
+    }
+}
+
+impl<'a> Drop for Removing<'a> {
+    fn drop(&mut self) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+/// Shared handle to the on-disk state of keys evicted from a partially materialized reader.
+///
+/// See [the module documentation](self) for more information
+#[derive(Clone)]
+pub(crate) struct ReaderSpill {
+    /// The spilled state, which is only ever written to (and locked) by the reader's domain
+    state: Arc<Mutex<PersistentState>>,
+    /// Handle for lookups to read rows from `state` without locking it
+    rows: PersistentStateHandle,
+    /// The keys which are currently spilled to `state`
+    keys: Arc<RwLock<SpilledKeySet>>,
+    /// Incremented both before and after removing keys from `state`, so that lookups can tell if
+    /// the key they found might have been removed while they were reading its rows
+    removals: Arc<AtomicU64>,
+    columns: Vec<usize>,
+    insertion_order: PreInsertion,
+}
+
+impl ReaderSpill {
+    /// Construct a new [`ReaderSpill`] for a reader keyed on `index`, which will spill keys into
+    /// the given `state`.
+    ///
+    /// `state` should be a new, empty [`PersistentState`] which no other node has access to.
+    pub(crate) fn new(
+        mut state: PersistentState,
+        index: &Index,
+        insertion_order: PreInsertion,
+    ) -> Self {
+        state.add_index(
+            Index::hash_map(index.columns.clone()),
+            Some(vec![spill_tag()]),
+        );
+        Self {
+            rows: state.read_handle(),
+            state: Arc::new(Mutex::new(state)),
+            keys: Default::default(),
+            removals: Default::default(),
+            columns: index.columns.clone(),
+            insertion_order,
+        }
+    }
+
+    /// Look up the rows for `key` in the spilled state, returning `None` if the key isn't
+    /// spilled.
+    ///
+    /// Since only keys from [`HashMap`] indices are ever spilled, range lookups always miss. Keys
+    /// which are being removed from the spilled state while they're looked up miss too.
+    ///
+    /// [`HashMap`]: IndexType::HashMap
+    #[allow(clippy::unwrap_used)] // lock poisoning is unrecoverable
+    pub(super) fn lookup(&self, key: &KeyComparison) -> Option<SharedRows> {
+        let KeyComparison::Equal(key) = key else {
+            return None;
+        };
+        let removals = self.removals.load(Ordering::SeqCst);
+        if removals % 2 == 1 || !self.keys.read().unwrap().keys.contains(key.as_slice()) {
+            return None;
+        }
+        let rows = self
+            .rows
+            .lookup(&self.columns, &PointKey::from(key.iter().cloned()))
+            .records()?;
+
+        // Rows in the reader's map are kept in insertion order, which post-lookup processing can
+        // rely on
+        let mut sorted: Vec<Box<[DfValue]>> = Vec::new();
+        for row in rows {
+            let row = row.into_owned().into_boxed_slice();
+            let idx = self
+                .insertion_order
+                .get_insertion_order(&sorted, &row)
+                .unwrap_or_else(|idx| idx);
+            sorted.insert(idx, row);
+        }
+
+        // If any keys were removed while we were reading, this might have been one of them, in
+        // which case we might not have read all its rows
+        if self.removals.load(Ordering::SeqCst) != removals {
+            return None;
+        }
+        Some(SharedRows::new(sorted.into()))
+    }
+
+    /// Write all the given keys evicted from the reader's map, along with their rows, to disk.
+    /// Returns the number of bytes of memory used to track them.
+    ///
+    /// If writing a key to disk fails, that key and any others that haven't been written yet are
+    /// dropped instead, just like they would be by a reader that doesn't spill.
+    #[allow(clippy::unwrap_used)] // lock poisoning is unrecoverable
+    pub(super) fn demote(&self, keys: SpilledKeys) -> u64 {
+        let mut state = self.state.lock().unwrap();
+        let size_before = self.deep_size_of_inner(&state);
+        let num_keys = keys.len();
+        let mut spilled = Vec::with_capacity(num_keys);
+        for (demoted, (key, rows)) in keys.into_iter().enumerate() {
+            let Ok(key) = Vec1::try_from_vec(key) else {
+                continue;
+            };
+            let rows = rows.iter().map(|row| row.to_vec()).collect();
+            if let Err(error) =
+                state.fill_with_rows(KeyComparison::from(key.clone()), spill_tag(), rows)
+            {
+                warn!(
+                    %error,
+                    keys = num_keys - demoted,
+                    "Failed to spill reader keys to disk, evicting them instead"
+                );
+                break;
+            }
+            spilled.push(key.into_vec());
+        }
+
+        // Only make the keys visible to lookups once all their rows have been written
+        let mut keys = self.keys.write().unwrap();
+        for key in spilled {
+            keys.insert(key);
+        }
+        drop(keys);
+
+        self.deep_size_of_inner(&state).saturating_sub(size_before)
+    }
+
+    /// Apply the given writes, which missed in the reader's map, to any keys that are spilled.
+    ///
+    /// If the writes can't be applied, the keys they're for are evicted from the spilled state
+    /// instead, so that they'll be replayed again the next time they're read.
+    #[allow(clippy::unwrap_used)] // lock poisoning is unrecoverable
+    pub(super) fn add(&self, records: Vec<Record>) {
+        if records.is_empty() {
+            return;
+        }
+        let keys = records
+            .iter()
+            .map(|r| {
+                self.columns
+                    .iter()
+                    .map(|c| r[*c].clone())
+                    .collect::<Vec<_>>()
+            })
+            .filter_map(|key| KeyComparison::try_from(key).ok())
+            .collect::<Vec<_>>();
+        let mut state = self.state.lock().unwrap();
+        if let Err(error) = state.process_records(&mut records.into(), None, None) {
+            warn!(%error, "Failed to write to spilled reader rows, evicting them instead");
+            let _removing = Removing::new(&self.removals);
+            let mut spilled = self.keys.write().unwrap();
+            for key in &keys {
+                if let KeyComparison::Equal(key) = key {
+                    spilled.remove(key);
+                }
+            }
+            drop(spilled);
+            if let Err(error) = state.try_evict_keys(spill_tag(), &keys) {
+                warn!(%error, "Failed to delete evicted reader rows from disk");
+            }
+        }
+    }
+
+    /// Remove `key` from the spilled state, if it's there, returning the number of bytes of memory
+    /// freed
+    #[allow(clippy::unwrap_used)] // lock poisoning is unrecoverable
+    pub(super) fn remove(&self, key: &[DfValue]) -> u64 {
+        if !self.keys.read().unwrap().keys.contains(key) {
+            return 0;
+        }
+        let Ok(comparison) = KeyComparison::try_from(key.to_vec()) else {
+            return 0;
+        };
+        let mut state = self.state.lock().unwrap();
+        let size_before = self.deep_size_of_inner(&state);
+        let _removing = Removing::new(&self.removals);
+        self.keys.write().unwrap().remove(key);
+        if let Err(error) = state.try_evict_keys(spill_tag(), &[comparison]) {
+            warn!(%error, "Failed to delete evicted reader rows from disk");
+        }
+        size_before.saturating_sub(self.deep_size_of_inner(&state))
+    }
+
+    /// Evict randomly chosen keys from the spilled state until `bytes` bytes of memory have been
+    /// freed, returning the number of bytes actually freed
+    #[allow(clippy::unwrap_used)] // lock poisoning is unrecoverable
+    pub(super) fn evict_bytes(&self, bytes: usize) -> u64 {
+        let mut state = self.state.lock().unwrap();
+        let _removing = Removing::new(&self.removals);
+        let Some(res) = state.evict_bytes(bytes) else {
+            return 0;
+        };
+        let mut keys = self.keys.write().unwrap();
+        let size_before = keys.size;
+        for key in &res.keys_evicted {
+            keys.remove(key);
+        }
+        res.bytes_freed + size_before.saturating_sub(keys.size)
+    }
+
+    /// Returns the number of bytes of memory used to track the spilled keys
+    #[allow(clippy::unwrap_used)] // lock poisoning is unrecoverable
+    pub(super) fn deep_size_of(&self) -> u64 {
+        self.deep_size_of_inner(&self.state.lock().unwrap())
+    }
+
+    /// Returns the number of bytes of memory used to track the spilled keys, given the already
+    /// locked `state`
+    #[allow(clippy::unwrap_used)] // lock poisoning is unrecoverable
+    fn deep_size_of_inner(&self, state: &PersistentState) -> u64 {
+        state.deep_size_of() + self.keys.read().unwrap().size
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vec1::vec1;
+
+    use super::*;
+
+    #[test]
+    fn lookups_dont_lock_state() {
+        let state = PersistentState::new(
+            "reader_spill_lookups_dont_lock_state".to_owned(),
+            Vec::<Box<[usize]>>::new(),
+            &PersistenceParameters {
+                mode: DurabilityMode::DeleteOnExit,
+                ..PersistenceParameters::default()
+            },
+        )
+        .unwrap();
+        let spill = ReaderSpill::new(state, &Index::hash_map(vec![0]), Default::default());
+        let row: Box<[DfValue]> = vec![1.into(), "a".into()].into_boxed_slice();
+        spill.demote(vec![(
+            vec![1.into()],
+            SharedRows::new(vec![row.clone()].into()),
+        )]);
+
+        let _state = spill.state.lock().unwrap();
+        let rows = spill
+            .lookup(&vec1![DfValue::from(1)].into())
+            .expect("key should be spilled");
+        assert_eq!(
+            rows.iter().map(|r| r.to_vec()).collect::<Vec<_>>(),
+            vec![row.to_vec()]
+        );
+        assert!(spill.lookup(&vec1![DfValue::from(2)].into()).is_none());
+    }
+}
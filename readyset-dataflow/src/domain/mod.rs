@@ -7,6 +7,7 @@ use std::collections::{HashMap, HashSet, VecDeque};
 use std::convert::{TryFrom, TryInto};
 use std::fmt::Debug;
 use std::ops::Bound;
+use std::path::Path;
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
@@ -16,7 +17,7 @@ use ahash::RandomState;
 use backoff::ExponentialBackoffBuilder;
 use dataflow_state::{
     BaseTableState, EvictBytesResult, EvictKeysResult, EvictRandomResult, MaterializedNodeState,
-    PointKey, RangeKey, RangeLookupResult,
+    PointKey, RangeKey, RangeLookupResult, TieredState,
 };
 use failpoint_macros::failpoint;
 use futures_util::future::FutureExt;
@@ -32,7 +33,7 @@ use readyset_client::debug::info::KeyCount;
 use readyset_client::internal::{self, Index};
 use readyset_client::metrics::recorded;
 use readyset_client::{KeyComparison, PersistencePoint, ReaderAddress};
-use readyset_errors::{internal, internal_err, ReadySetError, ReadySetResult};
+use readyset_errors::{internal, internal_err, unsupported_err, ReadySetError, ReadySetResult};
 use readyset_util::futures::abort_on_panic;
 use readyset_util::progress::report_progress_with;
 use readyset_util::ranges::RangeBounds;
@@ -82,6 +83,16 @@ pub struct Config {
     /// Whether to emit verbose metrics for the domain.
     #[serde(default)]
     pub verbose_metrics: bool,
+
+    /// If set to `true`, the state of partially materialized nodes (including readers) will spill
+    /// keys to disk when evicted to free memory, rather than dropping them.
+    #[serde(default)]
+    pub spill_partial_state: bool,
+
+    /// The time zone in which `NOW()` is evaluated by temporal filters, which should be the
+    /// upstream database's. If `None`, the server's local time zone is used.
+    #[serde(default)]
+    pub time_zone: Option<chrono_tz::Tz>,
 }
 
 const BATCH_SIZE: usize = 256;
@@ -477,6 +488,12 @@ impl DomainBuilder {
             channel_coordinator,
 
             timed_purges: Default::default(),
+            time_dependent_nodes: self
+                .nodes
+                .iter()
+                .filter(|(_, node)| node.borrow().is_time_dependent())
+                .map(|(n, _)| n)
+                .collect(),
 
             delayed_for_self: Default::default(),
 
@@ -495,6 +512,7 @@ impl DomainBuilder {
             metrics: domain_metrics::DomainMetrics::new(self.config.verbose_metrics),
 
             eviction_kind: self.config.eviction_kind,
+            spill_partial_state: self.config.spill_partial_state,
             remapped_keys: Default::default(),
 
             init_state_tx,
@@ -683,6 +701,14 @@ pub struct Domain {
     /// * Each node referenced by a `view` of a TimedPurge must be a reader node
     timed_purges: VecDeque<TimedPurge>,
 
+    /// Set of nodes whose output depends on the current time, which need to be sent an empty
+    /// message whenever their [`Node::next_timeout`] elapses
+    ///
+    /// # Invariants
+    ///
+    /// * Each node in this set must be in `self.nodes`
+    time_dependent_nodes: HashSet<LocalNodeIndex>,
+
     readers: Readers,
     channel_coordinator: Arc<ChannelCoordinator>,
 
@@ -708,6 +734,8 @@ pub struct Domain {
 
     metrics: domain_metrics::DomainMetrics,
     eviction_kind: crate::EvictionKind,
+    /// See [`Config::spill_partial_state`]
+    spill_partial_state: bool,
 
     /// This channel is used to notify the replica that a base node has its persistent state
     /// initialized.
@@ -797,6 +825,36 @@ impl Domain {
         self.replica
     }
 
+    /// Construct new, empty state for the partially materialized node `node`, which spills keys to
+    /// disk if [`Config::spill_partial_state`] is enabled
+    fn new_partial_state(&self, node: LocalNodeIndex) -> ReadySetResult<MaterializedNodeState> {
+        if !self.spill_partial_state {
+            return Ok(MaterializedNodeState::Memory(MemoryState::default()));
+        }
+
+        let cold = self.new_spill_state(node)?;
+        Ok(MaterializedNodeState::Tiered(TieredState::new(cold)))
+    }
+
+    /// Construct a new, empty [`PersistentState`] in a temporary database, for the partially
+    /// materialized node `node` to spill keys into
+    fn new_spill_state(&self, node: LocalNodeIndex) -> ReadySetResult<PersistentState> {
+        let mut params = self.persistence_parameters.clone();
+        params.mode = DurabilityMode::DeleteOnExit;
+        let name = format!(
+            "{}-spill-{}.{}-{}",
+            params.db_filename_prefix.replace('-', "_"),
+            self.index.index(),
+            self.shard.unwrap_or(0),
+            node.id(),
+        );
+        Ok(PersistentState::new(
+            name,
+            Vec::<Box<[usize]>>::new(),
+            &params,
+        )?)
+    }
+
     fn snapshotting_base_nodes(&self) -> Vec<LocalNodeIndex> {
         self.state
             .iter()
@@ -1451,6 +1509,9 @@ impl Domain {
                 let addr = node.local_addr();
                 let aux_state = node.initial_auxiliary_state();
                 self.not_ready.insert(addr);
+                if node.is_time_dependent() {
+                    self.time_dependent_nodes.insert(addr);
+                }
 
                 for p in parents {
                     self.nodes
@@ -1477,6 +1538,7 @@ impl Domain {
                         state.tear_down()?;
                     };
                     self.auxiliary_node_states.remove(node);
+                    self.time_dependent_nodes.remove(&node);
                     self.reader_write_handles.remove(node);
                     trace!(local = node.id(), "node removed");
                 }
@@ -1629,10 +1691,8 @@ impl Domain {
                         weak_indices,
                     } => {
                         if !self.state.contains_key(node) {
-                            self.state.insert(
-                                node,
-                                MaterializedNodeState::Memory(MemoryState::default()),
-                            );
+                            let state = self.new_partial_state(node)?;
+                            self.state.insert(node, state);
                         }
                         let state = self.state.get_mut(node).unwrap();
                         for (index, tags) in strict_indices {
@@ -1741,7 +1801,16 @@ impl Domain {
                         #[allow(clippy::unwrap_used)] // checked it was a reader above
                         let r = n.as_mut_reader().unwrap();
 
-                        let (r_part, w_part) = backlog::new_partial(
+                        let spill = if self.spill_partial_state {
+                            Some(backlog::ReaderSpill::new(
+                                self.new_spill_state(node)?,
+                                &index,
+                                r.reader_processing().pre_processing.clone(),
+                            ))
+                        } else {
+                            None
+                        };
+                        let (mut r_part, mut w_part) = backlog::new_partial(
                             num_columns,
                             index,
                             move |misses: &mut dyn Iterator<Item = KeyComparison>, cache_name| {
@@ -1781,6 +1850,11 @@ impl Domain {
                             self.eviction_kind,
                             r.reader_processing().clone(),
                         );
+                        r_part.set_writer(self.address(), node);
+                        if let Some(spill) = spill {
+                            r_part.set_spill(spill.clone());
+                            w_part.set_spill(spill);
+                        }
 
                         let shard = *self.shard.as_ref().unwrap_or(&0);
                         // TODO(ENG-838): Don't recreate every single node on leader failure.
@@ -2340,6 +2414,9 @@ impl Domain {
             DomainRequest::RequestReplicationOffsets => {
                 Ok(Some(bincode::serialize(&self.replication_offsets())?))
             }
+            DomainRequest::CheckpointBaseTables { dir } => Ok(Some(bincode::serialize(
+                &self.checkpoint_base_tables(&dir)?,
+            )?)),
             DomainRequest::RequestSnapshottingTables => {
                 Ok(Some(bincode::serialize(&self.snapshotting_base_nodes())?))
             }
@@ -2388,9 +2465,13 @@ impl Domain {
                     .insert_generated_columns(node, index.columns.clone(), tag);
                 // ...and also make sure we use that tag to index those columns in this node, so we
                 // know what hole to fill when we've satisfied replays to those columns
+                if !self.state.contains_key(node) {
+                    let state = self.new_partial_state(node)?;
+                    self.state.insert(node, state);
+                }
                 self.state
-                    .entry(node)
-                    .or_insert_with(|| MaterializedNodeState::Memory(MemoryState::default()))
+                    .get_mut(node)
+                    .unwrap()
                     .add_index(index, Some(vec![tag]));
                 Ok(None)
             }
@@ -4169,6 +4250,8 @@ impl Domain {
                     if n.is_dropped() {
                         continue; // Node was dropped. Skip.
                     } else if let Some(state) = self.reader_write_handles.get_mut(node) {
+                        // Readers that spill to disk demote keys there first, and only evict keys
+                        // outright once nothing is left in memory
                         freed += state.evict_bytes(num_bytes);
                         state.swap();
                         state.notify_readers_of_eviction()?;
@@ -4218,6 +4301,24 @@ impl Domain {
                 self.metrics.rec_eviction_time(start.elapsed(), total_freed);
                 None
             }
+            EvictRequest::Reader { node } => {
+                let Some(n) = self.nodes.get(node) else {
+                    return Ok(None);
+                };
+                // Fully materialized readers can't recover evicted keys, so they're left alone
+                if let Some(state) = self
+                    .reader_write_handles
+                    .get_mut(node)
+                    .filter(|state| !n.borrow().is_dropped() && state.is_partial())
+                {
+                    let freed = state.evict_all();
+                    state.swap();
+                    state.notify_readers_of_eviction()?;
+                    debug!(%freed, ?node, "expired reader");
+                    self.state_size.fetch_sub(freed as usize, Ordering::AcqRel);
+                }
+                None
+            }
             EvictRequest::Keys {
                 link: Link { dst, .. },
                 keys,
@@ -4498,6 +4599,40 @@ impl Domain {
         Ok(BaseTableState::Initialized(cur_min))
     }
 
+    /// Create a checkpoint of the persistent state of each base table node in this domain in the
+    /// given directory, returning the replication offset of each checkpoint. Base tables whose
+    /// state hasn't been initialized yet are skipped, and reported as
+    /// [`BaseTableState::Pending`].
+    pub fn checkpoint_base_tables(
+        &self,
+        dir: &Path,
+    ) -> ReadySetResult<NodeMap<BaseTableState<Option<ReplicationOffset>>>> {
+        let mut res = NodeMap::default();
+        for (idx, n) in self.nodes.iter() {
+            let node = n.borrow();
+            if !node.is_base() || node.is_dropped() {
+                continue;
+            }
+
+            let offset = match self.state.get(idx) {
+                Some(state) => {
+                    let persistent_state = state.as_persistent().ok_or_else(|| {
+                        unsupported_err!(
+                            "Base table {} is not persisted to disk, and can't be checkpointed",
+                            node.name().display_unquoted()
+                        )
+                    })?;
+                    persistent_state.create_checkpoint(dir)?;
+                    BaseTableState::Initialized(persistent_state.replication_offset().cloned())
+                }
+                None => BaseTableState::Pending,
+            };
+            res.insert(idx, offset);
+        }
+
+        Ok(res)
+    }
+
     pub fn replication_offsets(&self) -> NodeMap<BaseTableState<Option<ReplicationOffset>>> {
         self.nodes
             .iter()
@@ -4518,18 +4653,25 @@ impl Domain {
             .collect()
     }
 
-    /// If there is a pending timed purge, return the duration until it needs
-    /// to happen
+    /// If there is a pending timed purge or a time-dependent node that needs to be woken up,
+    /// return the duration until the earliest of those needs to happen
     pub fn next_poll_duration(&mut self) -> Option<time::Duration> {
         // when do we need to be woken up again?
         let now = time::Instant::now();
-        self.timed_purges.front().map(|tp| {
+        let purge = self.timed_purges.front().map(|tp| {
             if tp.time > now {
                 tp.time - now
             } else {
                 time::Duration::from_millis(0)
             }
-        })
+        });
+
+        self.time_dependent_nodes
+            .iter()
+            .filter(|&&n| self.can_wake_up(n))
+            .filter_map(|&n| self.nodes.get(n)?.borrow().next_timeout())
+            .chain(purge)
+            .min()
     }
 
     /// Handle a single message for this domain
@@ -4562,8 +4704,53 @@ impl Domain {
         Ok(())
     }
 
+    /// Returns true if the given time-dependent node can process a message right now. Nodes that
+    /// are still being built, or are the target of an ongoing replay, are woken up once they're
+    /// done instead, since waking them up now would just drop or buffer the message.
+    fn can_wake_up(&self, node: LocalNodeIndex) -> bool {
+        !self.not_ready.contains(&node)
+            && !matches!(self.mode, DomainMode::Replaying { ref to, .. } if *to == node)
+    }
+
+    /// Send an empty message to every time-dependent node whose timeout has elapsed, so that it
+    /// can update its output for the current time
+    fn handle_time_dependent_nodes(&mut self, executor: &mut dyn Executor) -> ReadySetResult<()> {
+        let due = self
+            .time_dependent_nodes
+            .iter()
+            .filter(|&&n| self.can_wake_up(n))
+            .filter_map(|&n| {
+                let node = self.nodes.get(n)?.borrow();
+                if node.next_timeout()? > time::Duration::ZERO {
+                    return None;
+                }
+                let parent = *node.parents().first()?;
+                Some((parent, n))
+            })
+            .collect::<Vec<_>>();
+
+        for (parent, node) in due {
+            trace!(local = node.id(), "waking up time-dependent node");
+            self.dispatch(
+                Packet::Message {
+                    link: Link::new(parent, node),
+                    data: Records::default(),
+                    trace: None,
+                },
+                executor,
+            )?;
+        }
+
+        while let Some(message) = self.delayed_for_self.pop_front() {
+            trace!("handling local transmission");
+            self.handle(message, executor)?;
+        }
+
+        Ok(())
+    }
+
     /// Handle an expired timeout from `next_poll_duration`
-    pub fn handle_timeout(&mut self) -> ReadySetResult<()> {
+    pub fn handle_timeout(&mut self, executor: &mut dyn Executor) -> ReadySetResult<()> {
         if self.wait_time.is_running() {
             self.wait_time.stop();
         }
@@ -4572,6 +4759,10 @@ impl Domain {
             self.handle_timed_purges()?;
         }
 
+        if !self.time_dependent_nodes.is_empty() {
+            self.handle_time_dependent_nodes(executor)?;
+        }
+
         if self.aggressively_update_state_sizes {
             self.update_state_sizes();
         }
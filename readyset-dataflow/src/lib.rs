@@ -45,7 +45,7 @@ pub type DomainConfig = domain::Config;
 
 pub use dataflow_expression::{
     BinaryOperator, BuiltinFunction, Expr, LowerContext, PostLookup, PostLookupAggregate,
-    PostLookupAggregateFunction, PostLookupAggregates, ReaderProcessing,
+    PostLookupAggregateFunction, PostLookupAggregates, PostLookupLeftJoinFilter, ReaderProcessing,
 };
 pub use dataflow_state::{
     BaseTableState, DurabilityMode, MaterializedNodeState, PersistenceParameters, PersistentState,
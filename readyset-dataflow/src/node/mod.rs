@@ -7,6 +7,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::ops::grouped::aggregate::AggregatorState;
 use crate::ops::grouped::concat::GroupConcatState;
+use crate::ops::set_operation::SetOperationState;
 use crate::ops::{self};
 use crate::prelude::*;
 use crate::processing::LookupIndex;
@@ -191,6 +192,7 @@ impl DanglingDomainNode {
 pub enum AuxiliaryNodeState {
     Aggregation(AggregatorState),
     Concat(GroupConcatState),
+    SetOperation(SetOperationState),
 }
 
 // external parts of Ingredient
@@ -321,6 +323,17 @@ impl Node {
             .map_or(false, Ingredient::requires_full_materialization)
     }
 
+    /// Returns true if the output of this operator depends on the current time
+    pub fn is_time_dependent(&self) -> bool {
+        self.as_internal()
+            .map_or(false, Ingredient::is_time_dependent)
+    }
+
+    /// Returns how long until this operator next needs to be woken up for the passage of time
+    pub fn next_timeout(&self) -> Option<std::time::Duration> {
+        self.as_internal().and_then(Ingredient::next_timeout)
+    }
+
     pub fn can_query_through(&self) -> bool {
         self.as_internal()
             .map_or(false, Ingredient::can_query_through)
@@ -370,6 +383,9 @@ impl Node {
                     Some(AuxiliaryNodeState::Aggregation(Default::default()))
                 }
                 NodeOperator::Concat(_) => Some(AuxiliaryNodeState::Concat(Default::default())),
+                NodeOperator::SetOperation(_) => {
+                    Some(AuxiliaryNodeState::SetOperation(Default::default()))
+                }
                 NodeOperator::Extremum(_)
                 | NodeOperator::Join(_)
                 | NodeOperator::Paginate(_)
@@ -377,7 +393,9 @@ impl Node {
                 | NodeOperator::Union(_)
                 | NodeOperator::Identity(_)
                 | NodeOperator::Filter(_)
-                | NodeOperator::TopK(_) => None,
+                | NodeOperator::TemporalFilter(_)
+                | NodeOperator::TopK(_)
+                | NodeOperator::Window(_) => None,
             },
             NodeType::Ingress
             | NodeType::Base(_)
@@ -143,12 +143,18 @@ impl Reader {
             let data = m.mut_data();
             trace!(?data, "reader received regular message");
             if state.is_partial() {
+                let mut missed = Vec::new();
                 data.retain(|row| {
                     match state.contains_record(&row[..]) {
                         Ok(false) => {
                             // row would miss in partial state.
                             // leave it blank so later lookup triggers replay.
                             trace!(?row, "dropping row that hit partial hole");
+                            // the key might have been spilled to disk though, in which case the
+                            // spilled rows need to be kept up to date.
+                            if state.spills() {
+                                missed.push(row.clone());
+                            }
                             false
                         }
                         Ok(true) => {
@@ -172,6 +178,7 @@ impl Reader {
                         }
                     }
                 });
+                state.add_spilled(missed);
             }
         } else if state.is_partial() {
             // it *can* happen that multiple readers miss (and thus request replay for) the
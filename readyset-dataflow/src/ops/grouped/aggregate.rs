@@ -6,6 +6,8 @@ pub use nom_sql::{BinaryOperator, Literal, SqlType};
 use readyset_data::dialect::SqlEngine;
 use readyset_data::{Collation, DfType, Dialect};
 use readyset_errors::{invariant, ReadySetResult};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 use crate::node::AuxiliaryNodeState;
@@ -23,6 +25,27 @@ pub enum Aggregation {
     Avg,
     /// Concatenates using the given separator between values.
     GroupConcat { separator: String },
+    /// Standard deviation of the `over` column, either of the sample or of the whole population.
+    /// Maintains count, sum, and sum of squares in HashMap
+    Stddev { sample: bool },
+    /// Variance of the `over` column, either of the sample or of the whole population. Maintains
+    /// count, sum, and sum of squares in HashMap
+    Variance { sample: bool },
+    /// Logical AND of the `over` column. Maintains counts of true and false values in HashMap
+    BoolAnd,
+    /// Logical OR of the `over` column. Maintains counts of true and false values in HashMap
+    BoolOr,
+    /// Bitwise AND of the `over` column. Maintains counts of each set bit in HashMap
+    BitAnd,
+    /// Bitwise OR of the `over` column. Maintains counts of each set bit in HashMap
+    BitOr,
+    /// Bitwise XOR of the `over` column. Maintains counts of each set bit in HashMap
+    BitXor,
+    /// Collects the non-null values of the `over` column into a sorted array.
+    ///
+    /// This isn't exposed in SQL - it's placed after a distinct node to keep the set of distinct
+    /// values for each group, so that `DISTINCT` aggregates can be re-aggregated post-lookup.
+    ArrayAgg,
 }
 
 impl Aggregation {
@@ -39,7 +62,24 @@ impl Aggregation {
         over_col_ty: &DfType,
         dialect: &Dialect,
     ) -> ReadySetResult<GroupedOperator<Aggregator>> {
-        let out_ty = match &self {
+        let out_ty = self.output_type(over_col_ty, dialect)?;
+
+        Ok(GroupedOperator::new(
+            src,
+            Aggregator {
+                op: self,
+                over,
+                group: group_by.into(),
+                over_else: None,
+                out_ty,
+            },
+        ))
+    }
+
+    /// Returns the type of the values produced by this aggregation when aggregating over a column
+    /// of the given type
+    pub fn output_type(&self, over_col_ty: &DfType, dialect: &Dialect) -> ReadySetResult<DfType> {
+        Ok(match self {
             Aggregation::Count { .. } => DfType::BigInt,
             Aggregation::Sum => match dialect.engine() {
                 SqlEngine::MySQL => {
@@ -71,18 +111,34 @@ impl Aggregation {
                 }
             }
             Aggregation::GroupConcat { .. } => DfType::Text(/* TODO */ Collation::default()),
-        };
-
-        Ok(GroupedOperator::new(
-            src,
-            Aggregator {
-                op: self,
-                over,
-                group: group_by.into(),
-                over_else: None,
-                out_ty,
+            Aggregation::Stddev { .. } | Aggregation::Variance { .. } => match dialect.engine() {
+                SqlEngine::MySQL => DfType::Double,
+                SqlEngine::PostgreSQL => {
+                    if over_col_ty.is_any_float() {
+                        DfType::Double
+                    } else {
+                        DfType::DEFAULT_NUMERIC
+                    }
+                }
             },
-        ))
+            Aggregation::BoolAnd | Aggregation::BoolOr => DfType::Bool,
+            Aggregation::BitAnd | Aggregation::BitOr | Aggregation::BitXor => {
+                match dialect.engine() {
+                    SqlEngine::MySQL => DfType::UnsignedBigInt,
+                    SqlEngine::PostgreSQL => {
+                        if over_col_ty.is_any_int() {
+                            over_col_ty.clone()
+                        } else {
+                            invalid_query!(
+                                "Cannot compute bitwise aggregate over type {}",
+                                over_col_ty
+                            )
+                        }
+                    }
+                }
+            }
+            Aggregation::ArrayAgg => DfType::Array(Box::new(over_col_ty.clone())),
+        })
     }
 }
 
@@ -146,10 +202,237 @@ impl AverageDataPair {
     }
 }
 
+/// For storing (Count, Sum, Sum of squares) in additional state for Variance and Stddev.
+#[derive(Debug, Clone, Default)]
+struct VarianceData {
+    count: i64,
+    sums: VarianceSums,
+}
+
+/// The sum and sum of squares of the values in a group, for computing their variance
+#[derive(Debug, Clone, Copy)]
+enum VarianceSums {
+    /// Sums of integer or fixed-point values, which are kept exactly so that the variance doesn't
+    /// suffer from cancellation when subtracting the (large) square of the sum from the sum of
+    /// squares
+    Exact {
+        sum: Decimal,
+        sum_of_squares: Decimal,
+    },
+    /// Sums of floating-point values, or of values too large for their sums to be kept exactly
+    Approximate { sum: f64, sum_of_squares: f64 },
+}
+
+impl Default for VarianceSums {
+    fn default() -> Self {
+        Self::Exact {
+            sum: Decimal::ZERO,
+            sum_of_squares: Decimal::ZERO,
+        }
+    }
+}
+
+/// The variance of the values in a group, which is exact if the sums of the values were kept
+/// exactly
+enum Variance {
+    Exact(Decimal),
+    Approximate(f64),
+}
+
+/// Returns the variance of `count` values with the given sum and sum of squares, divided by
+/// `denominator`
+fn approximate_variance(count: i64, sum: f64, sum_of_squares: f64, denominator: i64) -> f64 {
+    // Rounding error can make the variance of (nearly) equal values slightly negative
+    ((sum_of_squares - sum * sum / count as f64) / denominator as f64).max(0.0)
+}
+
+impl VarianceData {
+    fn apply_diff(&mut self, d: NumericalDiff) -> ReadySetResult<()> {
+        if d.positive {
+            self.count += 1;
+        } else {
+            self.count -= 1;
+        }
+
+        if let VarianceSums::Exact {
+            sum,
+            sum_of_squares,
+        } = self.sums
+        {
+            let exact = match d.value {
+                DfValue::Int(_) | DfValue::UnsignedInt(_) | DfValue::Numeric(_) => {
+                    Decimal::try_from(&d.value).ok()
+                }
+                _ => None,
+            };
+            let sums = exact.and_then(|value| {
+                let square = value.checked_mul(value)?;
+                Some(if d.positive {
+                    VarianceSums::Exact {
+                        sum: sum.checked_add(value)?,
+                        sum_of_squares: sum_of_squares.checked_add(square)?,
+                    }
+                } else {
+                    VarianceSums::Exact {
+                        sum: sum.checked_sub(value)?,
+                        sum_of_squares: sum_of_squares.checked_sub(square)?,
+                    }
+                })
+            });
+            if let Some(sums) = sums {
+                self.sums = sums;
+                return Ok(());
+            }
+
+            // Either this is a floating-point value, or the sums no longer fit in a Decimal, so
+            // carry on with floating-point sums from here on
+            self.sums = VarianceSums::Approximate {
+                sum: sum.to_f64().unwrap_or_default(),
+                sum_of_squares: sum_of_squares.to_f64().unwrap_or_default(),
+            };
+        }
+
+        let value = f64::try_from(&d.value)?;
+        if let VarianceSums::Approximate {
+            sum,
+            sum_of_squares,
+        } = &mut self.sums
+        {
+            if d.positive {
+                *sum += value;
+                *sum_of_squares += value * value;
+            } else {
+                *sum -= value;
+                *sum_of_squares -= value * value;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the sample or population variance of the values, or [`None`] if there aren't
+    /// enough values to compute it
+    fn variance(&self, sample: bool) -> Option<Variance> {
+        let denominator = if sample { self.count - 1 } else { self.count };
+        if denominator <= 0 {
+            return None;
+        }
+        match self.sums {
+            VarianceSums::Exact {
+                sum,
+                sum_of_squares,
+            } => {
+                // (n * Σx² - (Σx)²) / (n * denominator), which only rounds once, in the division
+                let count = Decimal::from(self.count);
+                let exact = count
+                    .checked_mul(sum_of_squares)
+                    .zip(sum.checked_mul(sum))
+                    .and_then(|(a, b)| a.checked_sub(b))
+                    .zip(count.checked_mul(Decimal::from(denominator)))
+                    .and_then(|(numerator, denominator)| numerator.checked_div(denominator));
+                Some(match exact {
+                    Some(variance) => Variance::Exact(variance),
+                    None => Variance::Approximate(approximate_variance(
+                        self.count,
+                        sum.to_f64().unwrap_or_default(),
+                        sum_of_squares.to_f64().unwrap_or_default(),
+                        denominator,
+                    )),
+                })
+            }
+            VarianceSums::Approximate {
+                sum,
+                sum_of_squares,
+            } => Some(Variance::Approximate(approximate_variance(
+                self.count,
+                sum,
+                sum_of_squares,
+                denominator,
+            ))),
+        }
+    }
+}
+
+/// For storing counts of true and false values in additional state for BoolAnd and BoolOr.
+#[derive(Debug, Clone, Default)]
+struct BoolData {
+    trues: i64,
+    falses: i64,
+}
+
+impl BoolData {
+    fn apply_diff(&mut self, d: NumericalDiff) -> ReadySetResult<()> {
+        let count = if bool::try_from(&d.value)? {
+            &mut self.trues
+        } else {
+            &mut self.falses
+        };
+        if d.positive {
+            *count += 1;
+        } else {
+            *count -= 1;
+        }
+        Ok(())
+    }
+}
+
+/// For storing the number of values with each bit set in additional state for BitAnd, BitOr and
+/// BitXor, which (other than BitXor) can't be undone from the aggregated value alone
+#[derive(Debug, Clone)]
+struct BitData {
+    count: i64,
+    bits: [i64; 64],
+}
+
+impl Default for BitData {
+    fn default() -> Self {
+        Self {
+            count: 0,
+            bits: [0; 64],
+        }
+    }
+}
+
+impl BitData {
+    fn apply_diff(&mut self, d: NumericalDiff) -> ReadySetResult<()> {
+        // Values are aggregated as their 64-bit two's complement representation
+        let value = i128::try_from(&d.value)? as u64;
+        let delta = if d.positive { 1 } else { -1 };
+        self.count += delta;
+        for (bit, count) in self.bits.iter_mut().enumerate() {
+            if value & (1 << bit) != 0 {
+                *count += delta;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the result of the given bitwise aggregation over the values, or [`None`] if there
+    /// aren't any values
+    fn value(&self, op: &Aggregation) -> Option<u64> {
+        if self.count == 0 {
+            return None;
+        }
+        Some(
+            self.bits
+                .iter()
+                .enumerate()
+                .filter(|(_, &count)| match op {
+                    Aggregation::BitAnd => count == self.count,
+                    Aggregation::BitOr => count > 0,
+                    _ => count % 2 != 0,
+                })
+                .fold(0, |acc, (bit, _)| acc | (1 << bit)),
+        )
+    }
+}
+
 #[derive(Debug, Default)]
 /// Auxiliary State for an Aggregator node, which is owned by a Domain
 pub struct AggregatorState {
     count_sum_map: HashMap<GroupHash, AverageDataPair>,
+    variance_map: HashMap<GroupHash, VarianceData>,
+    bool_map: HashMap<GroupHash, BoolData>,
+    bit_map: HashMap<GroupHash, BitData>,
 }
 
 impl Aggregator {
@@ -166,14 +449,56 @@ impl Aggregator {
     }
 
     fn new_data(&self) -> ReadySetResult<DfValue> {
+        match self.op {
+            Aggregation::Stddev { .. }
+            | Aggregation::Variance { .. }
+            | Aggregation::BoolAnd
+            | Aggregation::BoolOr => return Ok(DfValue::None),
+            Aggregation::BitAnd | Aggregation::BitOr | Aggregation::BitXor => {
+                return Ok(self.bit_output(None))
+            }
+            _ => {}
+        }
+
         match &self.out_ty {
             DfType::BigInt => Ok(DfValue::Int(Default::default())),
             DfType::Double => Ok(DfValue::Double(Default::default())),
             DfType::Numeric { .. } => Ok(DfValue::Numeric(Default::default())),
             DfType::Text { .. } => Ok(DfValue::from("" /* TODO(aspen): Use collation here */)),
+            DfType::Array(_) => Ok(DfValue::from(Vec::<DfValue>::new())),
             _ => internal!(),
         }
     }
+
+    /// Converts the (population or sample) variance of a group into the output value of a
+    /// Variance or Stddev aggregation
+    fn variance_output(&self, variance: Option<Variance>) -> ReadySetResult<DfValue> {
+        let value = match (variance, &self.op) {
+            (None, _) => return Ok(DfValue::None),
+            (Some(Variance::Exact(variance)), Aggregation::Variance { .. }) => {
+                return DfValue::from(variance).coerce_to(&self.out_ty, &DfType::DEFAULT_NUMERIC);
+            }
+            // There's no exact square root, so standard deviations are always approximate
+            (Some(Variance::Exact(variance)), _) => variance.to_f64().unwrap_or_default().sqrt(),
+            (Some(Variance::Approximate(variance)), Aggregation::Stddev { .. }) => variance.sqrt(),
+            (Some(Variance::Approximate(variance)), _) => variance,
+        };
+        DfValue::Double(value).coerce_to(&self.out_ty, &DfType::Double)
+    }
+
+    /// Converts the result of a bitwise aggregation into the output value. If there are no values
+    /// in the group, MySQL returns the identity of the operation, whereas PostgreSQL returns NULL
+    fn bit_output(&self, value: Option<u64>) -> DfValue {
+        match (value, &self.out_ty) {
+            (Some(value), DfType::UnsignedBigInt) => DfValue::UnsignedInt(value),
+            (None, DfType::UnsignedBigInt) => DfValue::UnsignedInt(match self.op {
+                Aggregation::BitAnd => u64::MAX,
+                _ => 0,
+            }),
+            (Some(value), _) => DfValue::Int(value as i64),
+            (None, _) => DfValue::None,
+        }
+    }
 }
 
 impl GroupedOperation for Aggregator {
@@ -227,10 +552,28 @@ impl GroupedOperation for Aggregator {
             }
         };
 
-        let count_sum_map = match auxiliary_node_state {
-            Some(AuxiliaryNodeState::Aggregation(ref mut aggregator_state)) => {
-                &mut aggregator_state.count_sum_map
+        let apply_array_agg = |curr: DfValue, diff: Self::Diff| -> ReadySetResult<DfValue> {
+            let mut values = match curr {
+                DfValue::Array(arr) => arr.values().cloned().collect::<Vec<_>>(),
+                _ => internal!("ArrayAgg state must be an array"),
+            };
+            match (values.binary_search(&diff.value), diff.positive) {
+                (Ok(idx) | Err(idx), true) => values.insert(idx, diff.value),
+                (Ok(idx), false) => {
+                    values.remove(idx);
+                }
+                (Err(_), false) => internal!("ArrayAgg couldn't remove value from array"),
             }
+            Ok(values.into())
+        };
+
+        let AggregatorState {
+            count_sum_map,
+            variance_map,
+            bool_map,
+            bit_map,
+        } = match auxiliary_node_state {
+            Some(AuxiliaryNodeState::Aggregation(ref mut aggregator_state)) => aggregator_state,
             Some(_) => internal!("Incorrect auxiliary state for Aggregation node"),
             None => internal!("Missing auxiliary state for Aggregation node"),
         };
@@ -245,21 +588,53 @@ impl GroupedOperation for Aggregator {
                 .apply_diff(diff)
         };
 
-        let apply_diff =
-            |curr: ReadySetResult<DfValue>, diff: Self::Diff| -> ReadySetResult<DfValue> {
-                if diff.value.is_none() {
-                    return curr;
-                }
+        let mut apply_variance = |sample: bool, diff: Self::Diff| -> ReadySetResult<DfValue> {
+            let data = variance_map.entry(diff.group_hash).or_default();
+            data.apply_diff(diff)?;
+            self.variance_output(data.variance(sample))
+        };
+
+        let mut apply_bool = |diff: Self::Diff| -> ReadySetResult<DfValue> {
+            let data = bool_map.entry(diff.group_hash).or_default();
+            data.apply_diff(diff)?;
+            if data.trues + data.falses == 0 {
+                return Ok(DfValue::None);
+            }
+            Ok(match self.op {
+                Aggregation::BoolAnd => data.falses == 0,
+                _ => data.trues > 0,
+            }
+            .into())
+        };
+
+        let mut apply_bit = |diff: Self::Diff| -> ReadySetResult<DfValue> {
+            let data = bit_map.entry(diff.group_hash).or_default();
+            data.apply_diff(diff)?;
+            Ok(self.bit_output(data.value(&self.op)))
+        };
+
+        let apply_diff = |curr: ReadySetResult<DfValue>,
+                          diff: Self::Diff|
+         -> ReadySetResult<DfValue> {
+            if diff.value.is_none() {
+                return curr;
+            }
 
-                match self.op {
-                    Aggregation::Count { .. } => apply_count(curr?, diff),
-                    Aggregation::Sum => apply_sum(curr?, diff),
-                    Aggregation::Avg => apply_avg(curr?, diff),
-                    Aggregation::GroupConcat { separator: _ } => internal!(
-                        "GroupConcats are separate from the other aggregations in the dataflow."
-                    ),
+            match self.op {
+                Aggregation::Count { .. } => apply_count(curr?, diff),
+                Aggregation::Sum => apply_sum(curr?, diff),
+                Aggregation::Avg => apply_avg(curr?, diff),
+                Aggregation::ArrayAgg => apply_array_agg(curr?, diff),
+                Aggregation::Stddev { sample } | Aggregation::Variance { sample } => {
+                    apply_variance(sample, diff)
                 }
-            };
+                Aggregation::BoolAnd | Aggregation::BoolOr => apply_bool(diff),
+                Aggregation::BitAnd | Aggregation::BitOr | Aggregation::BitXor => apply_bit(diff),
+                Aggregation::GroupConcat { separator: _ } => internal!(
+                    "GroupConcats are separate from the other aggregations in the dataflow."
+                ),
+            }
+        };
 
         diffs
             .fold(Ok(current.cloned().unwrap_or(self.new_data()?)), apply_diff)
@@ -272,6 +647,14 @@ impl GroupedOperation for Aggregator {
                 Aggregation::Count { .. } => "+".to_owned(),
                 Aggregation::Sum => "𝛴".to_owned(),
                 Aggregation::Avg => "Avg".to_owned(),
+                Aggregation::ArrayAgg => "[]".to_owned(),
+                Aggregation::Stddev { .. } => "σ".to_owned(),
+                Aggregation::Variance { .. } => "σ²".to_owned(),
+                Aggregation::BoolAnd => "∧".to_owned(),
+                Aggregation::BoolOr => "∨".to_owned(),
+                Aggregation::BitAnd => "&".to_owned(),
+                Aggregation::BitOr => "|".to_owned(),
+                Aggregation::BitXor => "^".to_owned(),
                 Aggregation::GroupConcat { separator: ref s } => {
                     format!("||({})", s)
                 }
@@ -282,6 +665,20 @@ impl GroupedOperation for Aggregator {
             Aggregation::Count { .. } => "|*|".to_owned(),
             Aggregation::Sum => format!("𝛴({})", self.over),
             Aggregation::Avg => format!("Avg({})", self.over),
+            Aggregation::ArrayAgg => format!("[{}]", self.over),
+            Aggregation::Stddev { sample } => format!(
+                "Stddev{}({})",
+                if sample { "Samp" } else { "Pop" },
+                self.over
+            ),
+            Aggregation::Variance { sample } => {
+                format!("Var{}({})", if sample { "Samp" } else { "Pop" }, self.over)
+            }
+            Aggregation::BoolAnd => format!("∧({})", self.over),
+            Aggregation::BoolOr => format!("∨({})", self.over),
+            Aggregation::BitAnd => format!("&({})", self.over),
+            Aggregation::BitOr => format!("|({})", self.over),
+            Aggregation::BitXor => format!("^({})", self.over),
             Aggregation::GroupConcat { separator: ref s } => format!("||({}, {})", s, self.over),
         };
         let group_cols = self
@@ -1099,6 +1496,224 @@ mod tests {
         }
     }
 
+    #[test]
+    fn array_agg_forwards() {
+        let mut c = setup(Aggregation::ArrayAgg, true);
+        let mut narrow = |r: Record| {
+            c.narrow_one(r, true)
+                .into_iter()
+                .map(|r| (r[..2].to_vec(), r.is_positive()))
+                .collect::<Vec<_>>()
+        };
+
+        // Add Group=1, Value=2
+        let rs = narrow(vec![1.into(), 2.into()].into());
+        assert_eq!(
+            rs,
+            vec![(vec![1.into(), DfValue::from(vec![DfValue::from(2)])], true)]
+        );
+
+        // Add Group=1, Value=1; values are kept sorted
+        let rs = narrow(vec![1.into(), 1.into()].into());
+        assert_eq!(
+            rs,
+            vec![
+                (vec![1.into(), DfValue::from(vec![DfValue::from(2)])], false),
+                (
+                    vec![
+                        1.into(),
+                        DfValue::from(vec![DfValue::from(1), DfValue::from(2)])
+                    ],
+                    true
+                ),
+            ]
+        );
+
+        // Remove Group=1, Value=2
+        let rs = narrow((vec![1.into(), 2.into()], false).into());
+        assert_eq!(
+            rs,
+            vec![
+                (
+                    vec![
+                        1.into(),
+                        DfValue::from(vec![DfValue::from(1), DfValue::from(2)])
+                    ],
+                    false
+                ),
+                (vec![1.into(), DfValue::from(vec![DfValue::from(1)])], true),
+            ]
+        );
+    }
+
+    /// Returns the aggregated values (and whether they're positive) emitted for a single record
+    fn narrow_aggregates(c: &mut ops::test::MockGraph, r: Record) -> Vec<(DfValue, bool)> {
+        c.narrow_one(r, true)
+            .into_iter()
+            .map(|r| (r[1].clone(), r.is_positive()))
+            .collect()
+    }
+
+    #[test]
+    fn variance_forwards() {
+        let mut c = setup(Aggregation::Variance { sample: false }, true);
+        assert_eq!(
+            narrow_aggregates(&mut c, vec![1.into(), 2.into()].into()),
+            vec![(DfValue::Double(0.0), true)]
+        );
+        assert_eq!(
+            narrow_aggregates(&mut c, vec![1.into(), 4.into()].into()),
+            vec![(DfValue::Double(0.0), false), (DfValue::Double(1.0), true)]
+        );
+        assert_eq!(
+            narrow_aggregates(&mut c, (vec![1.into(), 2.into()], false).into()),
+            vec![(DfValue::Double(1.0), false), (DfValue::Double(0.0), true)]
+        );
+    }
+
+    #[test]
+    fn sample_stddev_forwards() {
+        let mut c = setup(Aggregation::Stddev { sample: true }, true);
+        // The sample standard deviation of a single value is NULL
+        assert_eq!(
+            narrow_aggregates(&mut c, vec![1.into(), 2.into()].into()),
+            vec![(DfValue::None, true)]
+        );
+        assert_eq!(
+            narrow_aggregates(&mut c, vec![1.into(), 4.into()].into()),
+            vec![
+                (DfValue::None, false),
+                (DfValue::Double(2.0_f64.sqrt()), true)
+            ]
+        );
+    }
+
+    #[test]
+    fn variance_of_large_integers_is_exact() {
+        let mut c = setup(Aggregation::Variance { sample: false }, true);
+        // Computing these with floating-point sums of squares loses all the precision
+        narrow_aggregates(&mut c, vec![1.into(), 1_000_000_001.into()].into());
+        assert_eq!(
+            narrow_aggregates(&mut c, vec![1.into(), 1_000_000_004.into()].into()),
+            vec![(DfValue::Double(0.0), false), (DfValue::Double(2.25), true)]
+        );
+        assert_eq!(
+            narrow_aggregates(&mut c, vec![1.into(), 1_000_000_007.into()].into()),
+            vec![(DfValue::Double(2.25), false), (DfValue::Double(6.0), true)]
+        );
+    }
+
+    #[test]
+    fn bool_and_forwards() {
+        let mut c = setup(Aggregation::BoolAnd, true);
+        assert_eq!(
+            narrow_aggregates(&mut c, vec![1.into(), 1.into()].into()),
+            vec![(true.into(), true)]
+        );
+        assert_eq!(
+            narrow_aggregates(&mut c, vec![1.into(), 0.into()].into()),
+            vec![(true.into(), false), (false.into(), true)]
+        );
+        assert_eq!(
+            narrow_aggregates(&mut c, (vec![1.into(), 0.into()], false).into()),
+            vec![(false.into(), false), (true.into(), true)]
+        );
+    }
+
+    #[test]
+    fn bool_or_forwards() {
+        let mut c = setup(Aggregation::BoolOr, true);
+        assert_eq!(
+            narrow_aggregates(&mut c, vec![1.into(), 0.into()].into()),
+            vec![(false.into(), true)]
+        );
+        assert_eq!(
+            narrow_aggregates(&mut c, vec![1.into(), 1.into()].into()),
+            vec![(false.into(), false), (true.into(), true)]
+        );
+        assert_eq!(
+            narrow_aggregates(&mut c, (vec![1.into(), 1.into()], false).into()),
+            vec![(true.into(), false), (false.into(), true)]
+        );
+    }
+
+    #[test]
+    fn bit_and_forwards() {
+        let mut c = setup(Aggregation::BitAnd, true);
+        assert_eq!(
+            narrow_aggregates(&mut c, vec![1.into(), 6.into()].into()),
+            vec![(DfValue::UnsignedInt(6), true)]
+        );
+        assert_eq!(
+            narrow_aggregates(&mut c, vec![1.into(), 3.into()].into()),
+            vec![
+                (DfValue::UnsignedInt(6), false),
+                (DfValue::UnsignedInt(2), true)
+            ]
+        );
+        // Removing a value can set bits again
+        assert_eq!(
+            narrow_aggregates(&mut c, (vec![1.into(), 6.into()], false).into()),
+            vec![
+                (DfValue::UnsignedInt(2), false),
+                (DfValue::UnsignedInt(3), true)
+            ]
+        );
+        // MySQL's BIT_AND returns all bits set if there are no values
+        assert_eq!(
+            narrow_aggregates(&mut c, vec![2.into(), DfValue::None].into()),
+            vec![(DfValue::UnsignedInt(u64::MAX), true)]
+        );
+    }
+
+    #[test]
+    fn bit_xor_forwards() {
+        let mut c = setup(Aggregation::BitXor, true);
+        assert_eq!(
+            narrow_aggregates(&mut c, vec![1.into(), 6.into()].into()),
+            vec![(DfValue::UnsignedInt(6), true)]
+        );
+        assert_eq!(
+            narrow_aggregates(&mut c, vec![1.into(), 3.into()].into()),
+            vec![
+                (DfValue::UnsignedInt(6), false),
+                (DfValue::UnsignedInt(5), true)
+            ]
+        );
+        assert_eq!(
+            narrow_aggregates(&mut c, (vec![1.into(), 6.into()], false).into()),
+            vec![
+                (DfValue::UnsignedInt(5), false),
+                (DfValue::UnsignedInt(3), true)
+            ]
+        );
+    }
+
+    #[test]
+    fn it_determines_bitwise_output_type() {
+        assert_eq!(
+            Aggregation::BitOr
+                .output_type(&DfType::Int, &Dialect::DEFAULT_MYSQL)
+                .unwrap(),
+            DfType::UnsignedBigInt
+        );
+        assert_eq!(
+            Aggregation::BitOr
+                .output_type(&DfType::SmallInt, &Dialect::DEFAULT_POSTGRESQL)
+                .unwrap(),
+            DfType::SmallInt
+        );
+        Aggregation::BitOr
+            .output_type(&DfType::Double, &Dialect::DEFAULT_POSTGRESQL)
+            .unwrap_err();
+        assert_eq!(
+            Aggregation::Stddev { sample: true }
+                .output_type(&DfType::Int, &Dialect::DEFAULT_POSTGRESQL)
+                .unwrap(),
+            DfType::DEFAULT_NUMERIC
+        );
+    }
+
     #[test]
     fn it_suggests_indices() {
         let me = 1.into();
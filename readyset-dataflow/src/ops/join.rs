@@ -1,6 +1,7 @@
 use std::collections::{HashMap, HashSet};
 use std::convert::{TryFrom, TryInto};
 
+use dataflow_expression::Expr;
 use dataflow_state::PointKey;
 use itertools::Itertools;
 use readyset_client::KeyComparison;
@@ -22,6 +23,19 @@ pub enum JoinType {
     Left,
     /// Inner join between two views
     Inner,
+    /// Left join between two views which joins *every* row in the left against NULLs, in addition
+    /// to joining it against all of its matching rows in the right.
+    ///
+    /// This is used for left joins where the set of rows in the right to join against is only
+    /// known at lookup time (such as when the right-hand side is compared against a query
+    /// parameter), in which case the extra NULL rows are filtered out after the lookup
+    LeftWithNulls,
+    /// Full outer join between two views, which joins rows on *either* side with no matching rows
+    /// in the other side against NULLs.
+    ///
+    /// Full outer joins must be fully materialized, since rows in the right with no matches in the
+    /// left can't be found by replaying from the left.
+    FullOuter,
 }
 
 /// Join rows between two nodes based on a (compound) equal join key
@@ -48,6 +62,14 @@ pub struct Join {
     generated_column_buffer: HashMap<(Vec<usize>, Side), Records>,
 
     kind: JoinType,
+
+    /// An optional additional condition which must hold for a pair of rows with equal join keys
+    /// to be joined, evaluated against the columns of the left parent followed by the columns of
+    /// the right parent.
+    ///
+    /// For left joins, a row in the left with no matching rows in the right that satisfy this
+    /// condition is joined against NULLs.
+    residual: Option<Expr>,
 }
 
 impl Join {
@@ -111,9 +133,20 @@ impl Join {
             in_place_right_emit,
             generated_column_buffer: Default::default(),
             kind,
+            residual: None,
         }
     }
 
+    /// Set the residual condition for this join, which must hold (in addition to the join keys
+    /// being equal) for a pair of rows to be joined.
+    ///
+    /// `residual` is evaluated against the columns of the left parent followed by the columns of
+    /// the right parent.
+    pub fn with_residual(mut self, residual: Expr) -> Self {
+        self.residual = Some(residual);
+        self
+    }
+
     fn on_left(&self) -> Vec<usize> {
         self.on.iter().map(|(l, _)| *l).collect()
     }
@@ -132,6 +165,19 @@ impl Join {
             .collect()
     }
 
+    /// Returns true if the given pair of rows from the left and right parents satisfy the residual
+    /// condition of this join (if any).
+    fn residual_matches(&self, left: &[DfValue], right: &[DfValue]) -> bool {
+        match &self.residual {
+            None => true,
+            // As in the filter operator, rows for which the condition fails to evaluate are
+            // treated as not matching
+            Some(residual) => residual
+                .eval(&left.iter().chain(right).collect::<Vec<_>>())
+                .map_or(false, |v| v.is_truthy()),
+        }
+    }
+
     /// Build a hash map from one of the sides of the join.
     fn build_join_hash_map<'a>(
         &'a self,
@@ -188,13 +234,12 @@ impl Join {
                         "replays should only include positive records"
                     );
 
-                    match probe_is_left {
-                        true => ret.push(Record::Positive(
-                            self.generate_row(prob_rec.row(), build_rec.row()),
-                        )),
-                        false => ret.push(Record::Positive(
-                            self.generate_row(build_rec.row(), prob_rec.row()),
-                        )),
+                    let (left, right) = match probe_is_left {
+                        true => (prob_rec.row(), build_rec.row()),
+                        false => (build_rec.row(), prob_rec.row()),
+                    };
+                    if self.residual_matches(left, right) {
+                        ret.push(Record::Positive(self.generate_row(left, right)));
                     }
                 }
             };
@@ -202,13 +247,15 @@ impl Join {
         Ok(ret.into())
     }
 
+    /// Generate an output row for a row from the given side of the join which has no matching rows
+    /// in the other side, by joining it against NULLs
     // TODO: make non-allocating
-    fn generate_null(&self, left: &[DfValue]) -> Vec<DfValue> {
+    fn generate_null(&self, side: Side, row: &[DfValue]) -> Vec<DfValue> {
         self.emit
             .iter()
-            .map(|&(side, col)| {
-                if side == Side::Left {
-                    left[col].clone()
+            .map(|&(from_side, col)| {
+                if from_side == side {
+                    row[col].clone()
                 } else {
                     DfValue::None
                 }
@@ -253,6 +300,11 @@ impl Ingredient for Join {
     }
 
     fn must_replay_among(&self) -> Option<HashSet<NodeIndex>> {
+        if self.kind == JoinType::FullOuter {
+            // Rows in the right with no matches in the left can only be found by replaying from the
+            // right, so full outer joins replay from both parents
+            return None;
+        }
         Some(Some(self.left.as_global()).into_iter().collect())
     }
 
@@ -279,6 +331,8 @@ impl Ingredient for Join {
         let mut lookups = Vec::new();
 
         let from_left = from == *self.left;
+        let from_side = if from_left { Side::Left } else { Side::Right };
+        let other_side = from_side.other_side();
 
         let other = if from_left { *self.right } else { *self.left };
 
@@ -378,6 +432,7 @@ impl Ingredient for Join {
             .group_by(|rec| from_key.iter().map(|i| rec[*i].clone()).collect::<Vec<_>>());
 
         let is_replay = replay_key_cols.is_some();
+        let full_replay = matches!(replay, ReplayContext::Full { .. });
 
         // Only do a lookup into a weak index if we're processing regular updates,
         // not if we're processing a replay, since regular updates should represent
@@ -403,12 +458,26 @@ impl Ingredient for Join {
             // The difference between a left join and an inner join, is that for the former we must
             // emit rows with nulls even if we later get no match in the other side.
 
-            let mut new_right_count = None;
+            let mut new_from_count = None;
+            // If we have a residual condition, we need the actual rows in the side we got the
+            // update from (rather than just how many there are) to know which rows in the other
+            // side they match
+            let mut new_from_rows = None;
+
+            // Rows in the other side which aren't matched by any row in the side we got the update
+            // from are joined against NULLs for left joins with updates from the right, and for
+            // all full outer joins. We don't need to do this during full replays, since those
+            // don't change which rows have matches.
+            let nulls_for_other = match self.kind {
+                JoinType::Left => !from_left,
+                JoinType::FullOuter => !full_replay,
+                JoinType::Inner | JoinType::LeftWithNulls => false,
+            };
 
-            if self.kind == JoinType::Left && !from_left {
+            if nulls_for_other {
                 let rc = self.lookup(
-                    *self.right,
-                    &self.on_right(),
+                    from,
+                    &from_key,
                     &PointKey::from(join_key.iter().cloned()),
                     nodes,
                     state,
@@ -419,8 +488,8 @@ impl Ingredient for Join {
                     IngredientLookupResult::Records(rc) => {
                         if replay_key_cols.is_some() && !nulls {
                             lookups.push(Lookup {
-                                on: *self.right,
-                                cols: self.on_right(),
+                                on: from,
+                                cols: from_key.clone(),
                                 key: join_key
                                     .clone()
                                     .try_into()
@@ -428,8 +497,11 @@ impl Ingredient for Join {
                             });
                         }
 
-                        let rc = rc.count();
-                        new_right_count = Some(rc);
+                        if self.residual.is_some() {
+                            new_from_rows = Some(rc.collect::<Result<Vec<_>, _>>()?);
+                        } else {
+                            new_from_count = Some(rc.count());
+                        }
                     }
                     IngredientLookupResult::Miss => {
                         // we got something from right, but that row's key is not in right??
@@ -487,39 +559,98 @@ impl Ingredient for Join {
 
             let other_rows = other_records.collect::<Result<Vec<_>, _>>()?;
 
+            // Returns the given pair of rows from the side we got the update from and the other
+            // side as a pair of (left, right) rows
+            fn left_right<'a>(
+                from_left: bool,
+                from_row: &'a [DfValue],
+                other_row: &'a [DfValue],
+            ) -> (&'a [DfValue], &'a [DfValue]) {
+                if from_left {
+                    (from_row, other_row)
+                } else {
+                    (other_row, from_row)
+                }
+            }
+
             let mut rc_diff = 0isize;
+            let mut group_rows = vec![];
             for r in group {
                 let (row, positive) = r.extract();
 
                 rc_diff += if positive { 1 } else { -1 };
 
-                if other_rows.is_empty() {
-                    if self.kind == JoinType::Left && from_left {
-                        // left join, got a thing from left, no rows in right == NULL
-                        ret.push((self.generate_null(&row), positive).into());
-                    }
-                } else {
-                    for other in other_rows.iter() {
-                        if from == *self.left {
-                            ret.push((self.generate_row(&row, other), positive).into());
-                        } else {
-                            ret.push((self.generate_row(other, &row), positive).into());
+                let mut matched = false;
+                for other in other_rows.iter() {
+                    let (left, right) = left_right(from_left, &row, other);
+                    if self.residual_matches(left, right) {
+                        matched = true;
+                        // A full replay of a full outer join from the right only needs to find the
+                        // rows in the right with no matches, since all the matching pairs of rows
+                        // are found by the replay from the left
+                        if !(full_replay && self.kind == JoinType::FullOuter && !from_left) {
+                            ret.push((self.generate_row(left, right), positive).into());
                         }
                     }
                 }
+
+                if !matched
+                    && ((self.kind == JoinType::Left && from_left)
+                        || self.kind == JoinType::FullOuter)
+                {
+                    // left join, got a thing from left, no (matching) rows in right == NULL
+                    ret.push((self.generate_null(from_side, &row), positive).into());
+                }
+
+                if self.kind == JoinType::LeftWithNulls && from_left {
+                    // every row in the left gets joined against NULLs, whether or not it matched
+                    ret.push((self.generate_null(Side::Left, &row), positive).into());
+                }
+
+                if new_from_rows.is_some() {
+                    group_rows.push((row, positive));
+                }
             }
 
             // For a left join with updates from the right side, we also have to emit/delete NULL
             // rows if row count changed to/from zero
-            if let Some(new_rc) = new_right_count {
+            if let Some(new_rc) = new_from_count {
                 let old_rc = new_rc as isize - rc_diff;
                 if new_rc == 0 && old_rc != 0 {
                     for other in other_rows.iter() {
-                        ret.push((self.generate_null(other), true).into());
+                        ret.push((self.generate_null(other_side, other), true).into());
                     }
                 } else if new_rc != 0 && old_rc == 0 {
                     for other in other_rows.iter() {
-                        ret.push((self.generate_null(other), false).into());
+                        ret.push((self.generate_null(other_side, other), false).into());
+                    }
+                }
+            }
+
+            // Same as above, but with a residual condition the count of matching rows in the
+            // side we got the update from can be different for each row in the other side
+            if let Some(new_from_rows) = new_from_rows {
+                for other in other_rows.iter() {
+                    let new_rc = new_from_rows
+                        .iter()
+                        .filter(|row| {
+                            let (left, right) = left_right(from_left, row, other);
+                            self.residual_matches(left, right)
+                        })
+                        .count();
+                    let diff = group_rows
+                        .iter()
+                        .filter(|(row, _)| {
+                            let (left, right) = left_right(from_left, row, other);
+                            self.residual_matches(left, right)
+                        })
+                        .map(|(_, positive)| if *positive { 1 } else { -1 })
+                        .sum::<isize>();
+                    let old_rc = new_rc as isize - diff;
+                    if new_rc == 0 && old_rc != 0 {
+                        ret.push((self.generate_null(other_side, other), true).into());
+                    } else if new_rc != 0 && old_rc == 0 {
+                        ret.push((self.generate_null(other_side, other), false).into());
                     }
                 }
             }
@@ -532,12 +663,12 @@ impl Ingredient for Join {
         })
     }
 
-    fn suggest_indexes(&self, _this: NodeIndex) -> HashMap<NodeIndex, LookupIndex> {
+    fn suggest_indexes(&self, this: NodeIndex) -> HashMap<NodeIndex, LookupIndex> {
         // Replays might have happened through our parents into keys *other* than the join key, and
         // we need to find those rows when looking up values to perform the join as part of forward
         // processing of normal writes - so we use a weak index here to avoid dropping writes in
         // that case.
-        HashMap::from([
+        let mut indexes = HashMap::from([
             (
                 self.left.as_global(),
                 LookupIndex::Weak(Index::hash_map(self.on_left())),
@@ -546,14 +677,31 @@ impl Ingredient for Join {
                 self.right.as_global(),
                 LookupIndex::Weak(Index::hash_map(self.on_right())),
             ),
-        ])
+        ]);
+
+        if self.kind == JoinType::FullOuter {
+            // Full outer joins can't be partially materialized, so make sure we're materialized
+            // ourselves to force everything above us to be fully materialized as well
+            indexes.insert(
+                this,
+                LookupIndex::Strict(Index::hash_map((0..self.emit.len()).collect())),
+            );
+        }
+
+        indexes
+    }
+
+    fn requires_full_materialization(&self) -> bool {
+        self.kind == JoinType::FullOuter
     }
 
     fn description(&self, detailed: bool) -> String {
         if !detailed {
             return String::from(match self.kind {
                 JoinType::Left => "⋉",
+                JoinType::LeftWithNulls => "⋉*",
                 JoinType::Inner => "⋈",
+                JoinType::FullOuter => "⟗",
             });
         }
 
@@ -572,11 +720,13 @@ impl Ingredient for Join {
 
         let op = match self.kind {
             JoinType::Left => "⋉",
+            JoinType::LeftWithNulls => "⋉*",
             JoinType::Inner => "⋈",
+            JoinType::FullOuter => "⟗",
         };
 
         format!(
-            "[{}] {}:({}) {} {}:({})",
+            "[{}] {}:({}) {} {}:({}){}",
             emit,
             self.left.as_global().index(),
             self.on_left().into_iter().map(|i| i.to_string()).join(", "),
@@ -585,7 +735,11 @@ impl Ingredient for Join {
             self.on_right()
                 .into_iter()
                 .map(|i| i.to_string())
-                .join(", ")
+                .join(", "),
+            self.residual
+                .iter()
+                .map(|residual| format!(" if {residual}"))
+                .join("")
         )
     }
 
@@ -756,6 +910,10 @@ impl Ingredient for Join {
 
 #[cfg(test)]
 mod tests {
+    use dataflow_expression::utils::column_with_type;
+    use dataflow_expression::BinaryOperator;
+    use readyset_data::DfType;
+
     use super::*;
     use crate::ops;
 
@@ -919,6 +1077,242 @@ mod tests {
         assert_eq!(rs, Records::default());
     }
 
+    fn setup_residual(kind: JoinType) -> (ops::test::MockGraph, IndexPair, IndexPair) {
+        let mut g = ops::test::MockGraph::new();
+        let l = g.add_base("left", &["l0", "l1"]);
+        let r = g.add_base("right", &["r0", "r1"]);
+
+        // l0 = r0 AND l1 < r1
+        let j = Join::new(
+            l.as_global(),
+            r.as_global(),
+            kind,
+            vec![(0, 0)],
+            vec![(Side::Left, 0), (Side::Left, 1), (Side::Right, 1)],
+        )
+        .with_residual(Expr::Op {
+            left: Box::new(column_with_type(1, DfType::Int)),
+            op: BinaryOperator::Less,
+            right: Box::new(column_with_type(3, DfType::Int)),
+            ty: DfType::Bool,
+        });
+
+        g.set_op("join", &["j0", "j1", "j2"], j, false);
+        (g, l, r)
+    }
+
+    #[test]
+    fn inner_join_with_residual() {
+        let (mut j, l, r) = setup_residual(JoinType::Inner);
+
+        let r_1_5 = vec![1.into(), 5.into()];
+        j.seed(r, r_1_5.clone());
+        j.one_row(r, r_1_5, false);
+
+        let l_1_10 = vec![1.into(), 10.into()];
+        j.seed(l, l_1_10.clone());
+        let rs = j.one_row(l, l_1_10, false);
+        assert!(rs.is_empty());
+
+        let l_1_3 = vec![1.into(), 3.into()];
+        j.seed(l, l_1_3.clone());
+        let rs = j.one_row(l, l_1_3, false);
+        assert_eq!(rs, vec![(vec![1.into(), 3.into(), 5.into()], true)].into());
+
+        let r_1_20 = vec![1.into(), 20.into()];
+        j.seed(r, r_1_20.clone());
+        let rs = j.one_row(r, r_1_20, false);
+        assert_eq!(rs.len(), 2);
+        assert!(rs.has_positive(&[1.into(), 10.into(), 20.into()][..]));
+        assert!(rs.has_positive(&[1.into(), 3.into(), 20.into()][..]));
+    }
+
+    #[test]
+    fn left_join_with_residual() {
+        let (mut j, l, r) = setup_residual(JoinType::Left);
+
+        let r_1_5 = vec![1.into(), 5.into()];
+        j.seed(r, r_1_5.clone());
+        j.one_row(r, r_1_5, false);
+
+        // no rows in the right satisfy the residual, so we should get a NULL row
+        let l_1_10 = vec![1.into(), 10.into()];
+        j.seed(l, l_1_10.clone());
+        let rs = j.one_row(l, l_1_10, false);
+        assert_eq!(
+            rs,
+            vec![(vec![1.into(), 10.into(), DfValue::None], true)].into()
+        );
+
+        let l_1_3 = vec![1.into(), 3.into()];
+        j.seed(l, l_1_3.clone());
+        let rs = j.one_row(l, l_1_3, false);
+        assert_eq!(rs, vec![(vec![1.into(), 3.into(), 5.into()], true)].into());
+
+        // a new row in the right matching both rows in the left should revoke the NULL row for
+        // only the left row which didn't have a match before
+        let r_1_20 = vec![1.into(), 20.into()];
+        j.seed(r, r_1_20.clone());
+        let rs = j.one_row(r, r_1_20, false);
+        assert_eq!(rs.len(), 3);
+        assert!(rs.has_positive(&[1.into(), 10.into(), 20.into()][..]));
+        assert!(rs.has_positive(&[1.into(), 3.into(), 20.into()][..]));
+        assert!(rs.has_negative(&[1.into(), 10.into(), DfValue::None][..]));
+
+        // a new row in the right matching neither row in the left should have no effect
+        let r_1_0 = vec![1.into(), 0.into()];
+        j.seed(r, r_1_0.clone());
+        let rs = j.one_row(r, r_1_0, false);
+        assert!(rs.is_empty());
+    }
+
+    #[test]
+    fn left_join_with_nulls() {
+        let mut g = ops::test::MockGraph::new();
+        let l = g.add_base("left", &["l0", "l1"]);
+        let r = g.add_base("right", &["r0", "r1"]);
+        g.set_op(
+            "join",
+            &["j0", "j1", "j2"],
+            Join::new(
+                l.as_global(),
+                r.as_global(),
+                JoinType::LeftWithNulls,
+                vec![(0, 0)],
+                vec![(Side::Left, 0), (Side::Left, 1), (Side::Right, 1)],
+            ),
+            false,
+        );
+
+        let r_1_x = vec![1.into(), "x".into()];
+        g.seed(r, r_1_x.clone());
+        g.one_row(r, r_1_x, false);
+
+        // rows in the left get joined against NULLs in addition to their matches
+        let l_1_a = vec![1.into(), "a".into()];
+        g.seed(l, l_1_a.clone());
+        let rs = g.one_row(l, l_1_a, false);
+        assert_eq!(rs.len(), 2);
+        assert!(rs.has_positive(&[1.into(), "a".into(), "x".into()][..]));
+        assert!(rs.has_positive(&[1.into(), "a".into(), DfValue::None][..]));
+
+        let l_2_b = vec![2.into(), "b".into()];
+        g.seed(l, l_2_b.clone());
+        let rs = g.one_row(l, l_2_b, false);
+        assert_eq!(
+            rs,
+            vec![(vec![2.into(), "b".into(), DfValue::None], true)].into()
+        );
+
+        // rows in the right never change the NULL rows
+        let r_2_y = vec![2.into(), "y".into()];
+        g.seed(r, r_2_y.clone());
+        let rs = g.one_row(r, r_2_y, false);
+        assert_eq!(
+            rs,
+            vec![(vec![2.into(), "b".into(), "y".into()], true)].into()
+        );
+
+        let rs = g.one_row(r, (vec![1.into(), "x".into()], false), false);
+        assert_eq!(
+            rs,
+            vec![(vec![1.into(), "a".into(), "x".into()], false)].into()
+        );
+    }
+
+    #[test]
+    fn full_outer_join() {
+        let mut g = ops::test::MockGraph::new();
+        let l = g.add_base("left", &["l0", "l1"]);
+        let r = g.add_base("right", &["r0", "r1"]);
+        g.set_op(
+            "join",
+            &["j0", "j1", "j2", "j3"],
+            Join::new(
+                l.as_global(),
+                r.as_global(),
+                JoinType::FullOuter,
+                vec![(0, 0)],
+                vec![
+                    (Side::Left, 0),
+                    (Side::Left, 1),
+                    (Side::Right, 0),
+                    (Side::Right, 1),
+                ],
+            ),
+            false,
+        );
+
+        // rows from the right with no matches in the left get joined against NULLs
+        let r_1_x = vec![1.into(), "x".into()];
+        g.seed(r, r_1_x.clone());
+        let rs = g.one_row(r, r_1_x, false);
+        assert_eq!(
+            rs,
+            vec![(
+                vec![DfValue::None, DfValue::None, 1.into(), "x".into()],
+                true
+            )]
+            .into()
+        );
+
+        // as do rows from the left with no matches in the right
+        let l_2_b = vec![2.into(), "b".into()];
+        g.seed(l, l_2_b.clone());
+        let rs = g.one_row(l, l_2_b, false);
+        assert_eq!(
+            rs,
+            vec![(
+                vec![2.into(), "b".into(), DfValue::None, DfValue::None],
+                true
+            )]
+            .into()
+        );
+
+        // a matching row from the left should revoke the NULL row for the right
+        let l_1_a = vec![1.into(), "a".into()];
+        g.seed(l, l_1_a.clone());
+        let rs = g.one_row(l, l_1_a, false);
+        assert_eq!(rs.len(), 2);
+        assert!(rs.has_positive(&[1.into(), "a".into(), 1.into(), "x".into()][..]));
+        assert!(rs.has_negative(&[DfValue::None, DfValue::None, 1.into(), "x".into()][..]));
+
+        // and vice versa
+        let r_2_y = vec![2.into(), "y".into()];
+        g.seed(r, r_2_y.clone());
+        let rs = g.one_row(r, r_2_y, false);
+        assert_eq!(rs.len(), 2);
+        assert!(rs.has_positive(&[2.into(), "b".into(), 2.into(), "y".into()][..]));
+        assert!(rs.has_negative(&[2.into(), "b".into(), DfValue::None, DfValue::None][..]));
+    }
+
+    #[test]
+    fn full_outer_join_with_residual() {
+        let (mut j, l, r) = setup_residual(JoinType::FullOuter);
+
+        let l_1_10 = vec![1.into(), 10.into()];
+        j.seed(l, l_1_10.clone());
+        j.one_row(l, l_1_10, false);
+
+        // no rows in the left satisfy the residual, so we should get a NULL row
+        let r_1_5 = vec![1.into(), 5.into()];
+        j.seed(r, r_1_5.clone());
+        let rs = j.one_row(r, r_1_5, false);
+        assert_eq!(
+            rs,
+            vec![(vec![DfValue::None, DfValue::None, 5.into()], true)].into()
+        );
+
+        // a new row in the left satisfying the residual for the row in the right should revoke
+        // its NULL row
+        let l_1_3 = vec![1.into(), 3.into()];
+        j.seed(l, l_1_3.clone());
+        let rs = j.one_row(l, l_1_3, false);
+        assert_eq!(rs.len(), 2);
+        assert!(rs.has_positive(&[1.into(), 3.into(), 5.into()][..]));
+        assert!(rs.has_negative(&[DfValue::None, DfValue::None, 5.into()][..]));
+    }
+
     #[test]
     fn it_suggests_indices() {
         let me = 2.into();
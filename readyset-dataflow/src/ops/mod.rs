@@ -18,9 +18,12 @@ pub mod identity;
 pub mod join;
 pub mod paginate;
 pub mod project;
+pub mod set_operation;
+pub mod temporal_filter;
 pub mod topk;
 pub mod union;
 pub(crate) mod utils;
+pub mod window;
 
 use crate::ops::grouped::concat::GroupConcat;
 use crate::processing::{
@@ -55,9 +58,12 @@ pub enum NodeOperator {
     Paginate(paginate::Paginate),
     Project(project::Project),
     Union(union::Union),
+    SetOperation(set_operation::SetOperation),
     Identity(identity::Identity),
     Filter(filter::Filter),
+    TemporalFilter(temporal_filter::TemporalFilter),
     TopK(topk::TopK),
+    Window(window::Window),
 }
 
 impl fmt::Display for NodeOperator {
@@ -70,9 +76,12 @@ impl fmt::Display for NodeOperator {
             NodeOperator::Paginate(_) => write!(f, "Paginate"),
             NodeOperator::Project(_) => write!(f, "Project"),
             NodeOperator::Union(_) => write!(f, "Union"),
+            NodeOperator::SetOperation(_) => write!(f, "SetOperation"),
             NodeOperator::Identity(_) => write!(f, "Identity"),
             NodeOperator::Filter(_) => write!(f, "Filter"),
+            NodeOperator::TemporalFilter(_) => write!(f, "TemporalFilter"),
             NodeOperator::TopK(_) => write!(f, "TopK"),
+            NodeOperator::Window(_) => write!(f, "Window"),
         }
     }
 }
@@ -87,9 +96,12 @@ macro_rules! impl_ingredient_fn_mut {
             NodeOperator::Paginate(ref mut i) => i.$fn($($arg),*),
             NodeOperator::Project(ref mut i) => i.$fn($($arg),*),
             NodeOperator::Union(ref mut i) => i.$fn($($arg),*),
+            NodeOperator::SetOperation(ref mut i) => i.$fn($($arg),*),
             NodeOperator::Identity(ref mut i) => i.$fn($($arg),*),
             NodeOperator::Filter(ref mut i) => i.$fn($($arg),*),
+            NodeOperator::TemporalFilter(ref mut i) => i.$fn($($arg),*),
             NodeOperator::TopK(ref mut i) => i.$fn($($arg),*),
+            NodeOperator::Window(ref mut i) => i.$fn($($arg),*),
         }
     }
 }
@@ -104,9 +116,12 @@ macro_rules! impl_ingredient_fn_ref {
             NodeOperator::Paginate(ref i) => i.$fn($($arg),*),
             NodeOperator::Project(ref i) => i.$fn($($arg),*),
             NodeOperator::Union(ref i) => i.$fn($($arg),*),
+            NodeOperator::SetOperation(ref i) => i.$fn($($arg),*),
             NodeOperator::Identity(ref i) => i.$fn($($arg),*),
             NodeOperator::Filter(ref i) => i.$fn($($arg),*),
+            NodeOperator::TemporalFilter(ref i) => i.$fn($($arg),*),
             NodeOperator::TopK(ref i) => i.$fn($($arg),*),
+            NodeOperator::Window(ref i) => i.$fn($($arg),*),
         }
     }
 }
@@ -224,6 +239,12 @@ impl Ingredient for NodeOperator {
     fn requires_full_materialization(&self) -> bool {
         impl_ingredient_fn_ref!(self, requires_full_materialization,)
     }
+    fn is_time_dependent(&self) -> bool {
+        impl_ingredient_fn_ref!(self, is_time_dependent,)
+    }
+    fn next_timeout(&self) -> Option<std::time::Duration> {
+        impl_ingredient_fn_ref!(self, next_timeout,)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,315 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use readyset_errors::{internal, ReadySetResult};
+use serde::{Deserialize, Serialize};
+use vec1::vec1;
+
+use crate::node::AuxiliaryNodeState;
+use crate::ops::Side;
+use crate::prelude::*;
+use crate::processing::{ColumnSource, LookupIndex};
+
+/// The kind of set operation performed by a [`SetOperation`] node
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SetOperationKind {
+    /// `INTERSECT`: rows which appear on both sides
+    Intersect,
+    /// `EXCEPT`: rows which appear on the left side but not on the right side
+    Except,
+}
+
+impl fmt::Display for SetOperationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SetOperationKind::Intersect => write!(f, "∩"),
+            SetOperationKind::Except => write!(f, "∖"),
+        }
+    }
+}
+
+impl SetOperationKind {
+    /// Returns the number of times a row should appear in the output of this operation, given the
+    /// number of times it appears on the left and right sides of the input
+    fn multiplicity(self, distinct: bool, (left, right): (usize, usize)) -> usize {
+        match (self, distinct) {
+            (SetOperationKind::Intersect, false) => left.min(right),
+            (SetOperationKind::Intersect, true) => usize::from(left > 0 && right > 0),
+            (SetOperationKind::Except, false) => left.saturating_sub(right),
+            (SetOperationKind::Except, true) => usize::from(left > 0 && right == 0),
+        }
+    }
+}
+
+/// The multiplicity of every row seen by a [`SetOperation`] node, split by which side of the
+/// operation it came from
+#[derive(Default)]
+pub struct SetOperationState {
+    counts: HashMap<Vec<DfValue>, (usize, usize)>,
+}
+
+/// Computes the intersection or difference of the two sides of a compound select.
+///
+/// Both sides arrive through a single parent (typically a union), which tags each row with the side
+/// it came from in the `side` column: `0` for the left side and `1` for the right side. The node
+/// keeps a count of how many times each row (without the tag) has been seen on each side, which
+/// allows both the `ALL` and `DISTINCT` variants of the operation to handle deletes incrementally.
+/// The output of the node is the columns of the parent with the `side` column removed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetOperation {
+    src: IndexPair,
+    us: Option<IndexPair>,
+    kind: SetOperationKind,
+    distinct: bool,
+    side: usize,
+    /// The number of columns emitted by this node
+    cols: usize,
+}
+
+impl SetOperation {
+    /// Construct a new set operation node over the given parent, which should emit `cols + 1`
+    /// columns including the side tag in column `side`.
+    pub fn new(
+        src: NodeIndex,
+        kind: SetOperationKind,
+        distinct: bool,
+        side: usize,
+        cols: usize,
+    ) -> SetOperation {
+        SetOperation {
+            src: src.into(),
+            us: None,
+            kind,
+            distinct,
+            side,
+            cols,
+        }
+    }
+}
+
+impl Ingredient for SetOperation {
+    fn ancestors(&self) -> Vec<NodeIndex> {
+        vec![self.src.as_global()]
+    }
+
+    impl_replace_sibling!(src);
+
+    fn on_commit(&mut self, us: NodeIndex, remap: &HashMap<NodeIndex, IndexPair>) {
+        self.src.remap(remap);
+        self.us = Some(remap[&us]);
+    }
+
+    fn on_input(
+        &mut self,
+        _: LocalNodeIndex,
+        rs: Records,
+        _: &ReplayContext<'_>,
+        _: &DomainNodes,
+        _: &StateMap,
+        auxiliary_node_states: &mut AuxiliaryNodeStateMap,
+    ) -> ReadySetResult<ProcessingResult> {
+        let us = self.us.unwrap();
+        let counts = match auxiliary_node_states.get_mut(*us) {
+            Some(AuxiliaryNodeState::SetOperation(ref mut state)) => &mut state.counts,
+            Some(_) => internal!("Incorrect auxiliary state for SetOperation node"),
+            None => internal!("Missing auxiliary state for SetOperation node"),
+        };
+
+        let mut results = Vec::new();
+        for rec in rs {
+            let (mut row, positive) = rec.extract();
+            let side = match row.remove(self.side) {
+                DfValue::Int(0) | DfValue::UnsignedInt(0) => Side::Left,
+                DfValue::Int(1) | DfValue::UnsignedInt(1) => Side::Right,
+                v => internal!("Invalid side tag for SetOperation node: {v}"),
+            };
+
+            let entry = counts.entry(row.clone()).or_default();
+            let old = self.kind.multiplicity(self.distinct, *entry);
+            let count = match side {
+                Side::Left => &mut entry.0,
+                Side::Right => &mut entry.1,
+            };
+            if positive {
+                *count += 1;
+            } else if *count == 0 {
+                internal!("SetOperation node received a delete for a row it has not seen");
+            } else {
+                *count -= 1;
+            }
+            let new = self.kind.multiplicity(self.distinct, *entry);
+            if *entry == (0, 0) {
+                counts.remove(&row);
+            }
+
+            results
+                .extend(std::iter::repeat(Record::from((row, new > old))).take(new.abs_diff(old)));
+        }
+
+        Ok(ProcessingResult {
+            results: results.into(),
+            ..Default::default()
+        })
+    }
+
+    fn suggest_indexes(&self, this: NodeIndex) -> HashMap<NodeIndex, LookupIndex> {
+        // We need to be fully materialized, since the output can only be computed with knowledge of
+        // every row on both sides
+        HashMap::from([(
+            this,
+            LookupIndex::Strict(Index::hash_map((0..self.cols).collect())),
+        )])
+    }
+
+    fn column_source(&self, _: &[usize]) -> ColumnSource {
+        ColumnSource::RequiresFullReplay(vec1![self.src.as_global()])
+    }
+
+    fn requires_full_materialization(&self) -> bool {
+        true
+    }
+
+    fn description(&self, _: bool) -> String {
+        if self.distinct {
+            self.kind.to_string()
+        } else {
+            format!("{} ALL", self.kind)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ops;
+
+    fn setup(kind: SetOperationKind, distinct: bool) -> ops::test::MockGraph {
+        let mut g = ops::test::MockGraph::new();
+        let s = g.add_base("source", &["x", "side"]);
+        g.set_op(
+            "setop",
+            &["x"],
+            SetOperation::new(s.as_global(), kind, distinct, 1, 1),
+            false,
+        );
+        g
+    }
+
+    fn left(x: i32) -> Vec<DfValue> {
+        vec![x.into(), 0.into()]
+    }
+
+    fn right(x: i32) -> Vec<DfValue> {
+        vec![x.into(), 1.into()]
+    }
+
+    #[test]
+    fn intersect_all() {
+        let mut g = setup(SetOperationKind::Intersect, false);
+
+        assert!(g.narrow_one_row(left(1), false).is_empty());
+        assert!(g.narrow_one_row(left(1), false).is_empty());
+        assert_eq!(
+            g.narrow_one_row(right(1), false),
+            vec![vec![DfValue::from(1)]].into()
+        );
+        assert_eq!(
+            g.narrow_one_row(right(1), false),
+            vec![vec![DfValue::from(1)]].into()
+        );
+        // a third copy on the right has nothing to match against on the left
+        assert!(g.narrow_one_row(right(1), false).is_empty());
+        assert!(g.narrow_one_row((right(1), false), false).is_empty());
+        assert_eq!(
+            g.narrow_one_row((left(1), false), false),
+            vec![(vec![DfValue::from(1)], false)].into()
+        );
+    }
+
+    #[test]
+    fn intersect_distinct() {
+        let mut g = setup(SetOperationKind::Intersect, true);
+
+        assert!(g.narrow_one_row(left(1), false).is_empty());
+        assert!(g.narrow_one_row(left(1), false).is_empty());
+        assert_eq!(
+            g.narrow_one_row(right(1), false),
+            vec![vec![DfValue::from(1)]].into()
+        );
+        assert!(g.narrow_one_row(right(1), false).is_empty());
+        assert!(g.narrow_one_row((right(1), false), false).is_empty());
+        assert_eq!(
+            g.narrow_one_row((right(1), false), false),
+            vec![(vec![DfValue::from(1)], false)].into()
+        );
+    }
+
+    #[test]
+    fn except_all() {
+        let mut g = setup(SetOperationKind::Except, false);
+
+        assert_eq!(
+            g.narrow_one(vec![left(1), left(1), left(2)], false),
+            vec![
+                vec![DfValue::from(1)],
+                vec![DfValue::from(1)],
+                vec![DfValue::from(2)]
+            ]
+            .into()
+        );
+        assert_eq!(
+            g.narrow_one_row(right(1), false),
+            vec![(vec![DfValue::from(1)], false)].into()
+        );
+        assert!(g.narrow_one_row(right(3), false).is_empty());
+        assert_eq!(
+            g.narrow_one_row((right(1), false), false),
+            vec![vec![DfValue::from(1)]].into()
+        );
+    }
+
+    #[test]
+    fn except_distinct() {
+        let mut g = setup(SetOperationKind::Except, true);
+
+        assert_eq!(
+            g.narrow_one(vec![left(1), left(1)], false),
+            vec![vec![DfValue::from(1)]].into()
+        );
+        assert!(g.narrow_one_row(right(2), false).is_empty());
+        assert_eq!(
+            g.narrow_one_row(right(1), false),
+            vec![(vec![DfValue::from(1)], false)].into()
+        );
+        assert!(g.narrow_one_row((left(1), false), false).is_empty());
+        // once the last matching row on the right side goes away, the row reappears
+        assert_eq!(
+            g.narrow_one_row((right(1), false), false),
+            vec![vec![DfValue::from(1)]].into()
+        );
+        assert!(g.narrow_one_row((right(2), false), false).is_empty());
+        assert_eq!(
+            g.narrow_one_row(left(2), false),
+            vec![vec![DfValue::from(2)]].into()
+        );
+    }
+
+    #[test]
+    fn it_suggests_indices() {
+        let g = setup(SetOperationKind::Intersect, false);
+        let me = 1.into();
+        let idx = g.node().suggest_indexes(me);
+        assert_eq!(idx.len(), 1);
+        assert_eq!(
+            *idx.get(&me).unwrap(),
+            LookupIndex::Strict(Index::hash_map(vec![0]))
+        );
+    }
+
+    #[test]
+    fn it_requires_full_replay() {
+        let g = setup(SetOperationKind::Except, true);
+        assert_eq!(g.node().resolve(0), None);
+        assert!(g.node().requires_full_materialization());
+    }
+}
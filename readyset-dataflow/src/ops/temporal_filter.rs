@@ -0,0 +1,647 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::time::Duration;
+
+use chrono::{DateTime, Local, LocalResult, NaiveDateTime, Offset, TimeZone, Utc};
+use chrono_tz::Tz;
+use readyset_data::Interval;
+use readyset_errors::ReadySetResult;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+use crate::processing::{ColumnSource, LookupIndex};
+
+/// The comparison a [`TemporalFilter`] performs between a column and the current time
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TemporalComparison {
+    /// `column > NOW() + offset`
+    Greater,
+    /// `column >= NOW() + offset`
+    GreaterOrEqual,
+    /// `column < NOW() + offset`
+    Less,
+    /// `column <= NOW() + offset`
+    LessOrEqual,
+}
+
+impl TemporalComparison {
+    /// Returns true if rows matching this comparison stop matching as time passes (rather than
+    /// starting to match)
+    fn expires(self) -> bool {
+        matches!(
+            self,
+            TemporalComparison::Greater | TemporalComparison::GreaterOrEqual
+        )
+    }
+
+    fn compare(self, value: DateTime<Utc>, threshold: DateTime<Utc>) -> bool {
+        match self {
+            TemporalComparison::Greater => value > threshold,
+            TemporalComparison::GreaterOrEqual => value >= threshold,
+            TemporalComparison::Less => value < threshold,
+            TemporalComparison::LessOrEqual => value <= threshold,
+        }
+    }
+}
+
+impl fmt::Display for TemporalComparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TemporalComparison::Greater => write!(f, ">"),
+            TemporalComparison::GreaterOrEqual => write!(f, ">="),
+            TemporalComparison::Less => write!(f, "<"),
+            TemporalComparison::LessOrEqual => write!(f, "<="),
+        }
+    }
+}
+
+/// The temporal filter operator
+///
+/// The temporal filter operator only emits records for which `column <comparison> NOW() + offset`
+/// holds, and keeps its output correct as the current time advances: rows whose predicate will
+/// flip at some point in the future are indexed by the time at which that happens, and are
+/// retracted (for `>` and `>=`) or inserted (for `<` and `<=`) once that time has passed. The
+/// domain drives this by polling [`Ingredient::next_timeout`] and sending the node an empty
+/// message when it elapses.
+///
+/// `NOW()` is evaluated in the upstream database's time zone, as configured for the server (or
+/// the server's local time zone if none was configured): timestamps without a time zone are taken
+/// to be in that zone, and the months and days of `offset` are added to `NOW()` in it, so that
+/// `NOW() + INTERVAL '1 day'` is the same time of day tomorrow even across a DST transition. All
+/// other comparisons and deadlines are computed in UTC.
+///
+/// Since the set of rows this operator emits depends on when they were processed, the operator
+/// can't be partially materialized, and rows with a NULL or non-timestamp value in `column`
+/// never match.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TemporalFilter {
+    src: IndexPair,
+    column: usize,
+    comparison: TemporalComparison,
+    offset: Interval,
+    /// The time zone `NOW()` is evaluated in, or `None` for the server's local time zone
+    #[serde(default)]
+    time_zone: Option<Tz>,
+
+    /// Rows whose predicate will flip in the future, keyed by the value of `column`
+    ///
+    /// This isn't serialized, which is only correct as long as the node's state is always rebuilt
+    /// by replaying every row of its parent through [`Ingredient::on_input`] after recovery (as
+    /// happens for fully materialized nodes). If the operator's output were ever restored some
+    /// other way, such as from persisted state, the rows tracked here would be lost and would
+    /// never be retracted or inserted once their predicate flips.
+    #[serde(skip)]
+    pending: BTreeMap<DateTime<Utc>, Vec<Vec<DfValue>>>,
+    /// The latest value of `NOW() + offset` that records have been processed against. Never
+    /// moves backwards, so that a clock adjustment can't flip rows back.
+    #[serde(skip)]
+    threshold: Option<DateTime<Utc>>,
+}
+
+/// Returns the time `local` in `zone` as a UTC time. Times which are ambiguous because the clocks
+/// went back resolve to the earlier of the two, and times which were skipped because the clocks
+/// went forward are taken to use the offset from before the transition.
+fn local_to_utc<Z: TimeZone>(zone: &Z, local: NaiveDateTime) -> DateTime<Utc> {
+    match zone.from_local_datetime(&local) {
+        LocalResult::Single(dt) | LocalResult::Ambiguous(dt, _) => dt.with_timezone(&Utc),
+        LocalResult::None => {
+            let offset = zone.offset_from_utc_datetime(&local).fix();
+            Utc.from_utc_datetime(
+                &(local - chrono::Duration::seconds(offset.local_minus_utc().into())),
+            )
+        }
+    }
+}
+
+impl TemporalFilter {
+    /// Construct a new temporal filter operator, filtering on `column <comparison> NOW() +
+    /// offset`, with `NOW()` evaluated in `time_zone` (or the server's local time zone, if `None`)
+    pub fn new(
+        src: NodeIndex,
+        column: usize,
+        comparison: TemporalComparison,
+        offset: Interval,
+        time_zone: Option<Tz>,
+    ) -> TemporalFilter {
+        TemporalFilter {
+            src: src.into(),
+            column,
+            comparison,
+            offset,
+            time_zone,
+            pending: Default::default(),
+            threshold: None,
+        }
+    }
+
+    /// Returns the wall-clock time at `at` in the time zone `NOW()` is evaluated in
+    fn local_time(&self, at: DateTime<Utc>) -> NaiveDateTime {
+        match &self.time_zone {
+            Some(tz) => at.with_timezone(tz).naive_local(),
+            None => at.with_timezone(&Local).naive_local(),
+        }
+    }
+
+    /// Returns the wall-clock time `local` in the time zone `NOW()` is evaluated in as a UTC time
+    fn utc_time(&self, local: NaiveDateTime) -> DateTime<Utc> {
+        match &self.time_zone {
+            Some(tz) => local_to_utc(tz, local),
+            None => local_to_utc(&Local, local),
+        }
+    }
+
+    /// Adds `interval` to `at`, adding its months and days in the time zone `NOW()` is evaluated
+    /// in, and its time component in UTC
+    fn add_interval(&self, at: DateTime<Utc>, interval: &Interval) -> Option<DateTime<Utc>> {
+        let months_and_days = Interval::new(interval.months(), interval.days(), 0);
+        let shifted = if months_and_days.is_zero() {
+            at
+        } else {
+            self.utc_time(months_and_days.add_to_datetime(self.local_time(at))?)
+        };
+        shifted.checked_add_signed(chrono::Duration::microseconds(interval.microseconds()))
+    }
+
+    /// Returns the value of `NOW() + offset` when `NOW()` is `now`
+    fn threshold_at(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.add_interval(now, &self.offset)
+    }
+
+    /// Returns the value of `column` in `row` as a UTC time
+    fn value_of(&self, row: &[DfValue]) -> Option<DateTime<Utc>> {
+        match row.get(self.column) {
+            Some(DfValue::TimestampTz(ts)) if ts.has_timezone() => {
+                Some(ts.to_chrono().with_timezone(&Utc))
+            }
+            // Timestamps without a time zone (MySQL `DATETIME`s and PostgreSQL `timestamp`s) are
+            // compared with `NOW()` as if they were in its time zone
+            Some(DfValue::TimestampTz(ts)) => Some(self.utc_time(ts.to_chrono().naive_local())),
+            _ => None,
+        }
+    }
+
+    /// Advance the threshold to `NOW() + offset` as of `now`, and return the records emitted for
+    /// pending rows whose predicate flipped since the last call
+    fn advance(&mut self, now: DateTime<Utc>) -> Vec<Record> {
+        let threshold = match self.threshold_at(now) {
+            Some(threshold) => threshold,
+            None => return vec![],
+        };
+        let threshold = self.threshold.map_or(threshold, |last| last.max(threshold));
+        self.threshold = Some(threshold);
+
+        let expires = self.comparison.expires();
+        let mut flipped = vec![];
+        while let Some(entry) = self.pending.first_entry() {
+            if self.comparison.compare(*entry.key(), threshold) == expires {
+                break;
+            }
+            flipped.extend(entry.remove().into_iter().map(|row| {
+                if expires {
+                    Record::Negative(row)
+                } else {
+                    Record::Positive(row)
+                }
+            }));
+        }
+        flipped
+    }
+
+    /// Process `rs` as of `now`, returning the records to emit
+    fn process(&mut self, now: DateTime<Utc>, rs: Records) -> Vec<Record> {
+        let mut results = self.advance(now);
+        let threshold = match self.threshold {
+            Some(threshold) => threshold,
+            None => return results,
+        };
+        let expires = self.comparison.expires();
+
+        for r in rs {
+            let value = match self.value_of(r.rec()) {
+                Some(value) => value,
+                None => continue,
+            };
+            let passes = self.comparison.compare(value, threshold);
+            // A row needs to be tracked if its predicate will flip later: rows that pass now will
+            // stop passing if the comparison expires, and rows that fail now will start passing
+            // if it doesn't
+            if passes == expires {
+                if r.is_positive() {
+                    self.pending
+                        .entry(value)
+                        .or_default()
+                        .push(r.rec().to_vec());
+                } else if let Some(rows) = self.pending.get_mut(&value) {
+                    if let Some(pos) = rows.iter().position(|row| row == r.rec()) {
+                        rows.swap_remove(pos);
+                    }
+                    if rows.is_empty() {
+                        self.pending.remove(&value);
+                    }
+                }
+            }
+
+            if passes {
+                results.push(r);
+            }
+        }
+
+        results
+    }
+
+    /// Returns the time remaining as of `now` until a pending row's predicate flips
+    fn timeout_at(&self, now: DateTime<Utc>) -> Option<Duration> {
+        let value = *self.pending.first_key_value()?.0;
+        let expires = self.comparison.expires();
+        let flipped = |at: DateTime<Utc>| {
+            self.threshold_at(at).map_or(false, |threshold| {
+                self.comparison.compare(value, threshold) != expires
+            })
+        };
+
+        // Undo the time component of the offset first, since it was added last
+        let time = Interval::new(0, 0, self.offset.microseconds());
+        let months_and_days = Interval::new(self.offset.months(), self.offset.days(), 0);
+        let mut deadline = self.add_interval(
+            self.add_interval(value, &time.checked_neg()?)?,
+            &months_and_days.checked_neg()?,
+        )?;
+        // `>=` and `<` only flip once the threshold is strictly past the value
+        if !flipped(deadline) {
+            deadline += chrono::Duration::microseconds(1);
+        }
+        // Adding months clamps the day of the month (and a local time may not exist on the day we
+        // land on), so subtracting the offset isn't always an exact inverse - if we landed early,
+        // the predicate flips at the start of some later day
+        for _ in 0..4 {
+            if flipped(deadline) {
+                break;
+            }
+            let next_day = self.local_time(deadline).date().succ_opt()?;
+            deadline = self.utc_time(next_day.and_hms_opt(0, 0, 0)?);
+        }
+
+        Some((deadline - now).to_std().unwrap_or(Duration::ZERO))
+    }
+}
+
+impl Ingredient for TemporalFilter {
+    fn ancestors(&self) -> Vec<NodeIndex> {
+        vec![self.src.as_global()]
+    }
+
+    impl_replace_sibling!(src);
+
+    fn on_commit(&mut self, _: NodeIndex, remap: &HashMap<NodeIndex, IndexPair>) {
+        self.src.remap(remap);
+    }
+
+    fn on_input(
+        &mut self,
+        _: LocalNodeIndex,
+        rs: Records,
+        _: &ReplayContext,
+        _: &DomainNodes,
+        _: &StateMap,
+        _: &mut AuxiliaryNodeStateMap,
+    ) -> ReadySetResult<ProcessingResult> {
+        Ok(ProcessingResult {
+            results: self.process(Utc::now(), rs).into(),
+            ..Default::default()
+        })
+    }
+
+    fn suggest_indexes(&self, _: NodeIndex) -> HashMap<NodeIndex, LookupIndex> {
+        HashMap::new()
+    }
+
+    fn column_source(&self, cols: &[usize]) -> ColumnSource {
+        ColumnSource::exact_copy(self.src.as_global(), cols.to_vec())
+    }
+
+    fn description(&self, detailed: bool) -> String {
+        if !detailed {
+            String::from("σ")
+        } else {
+            format!(
+                "σ[{} {} now() + {}]",
+                self.column, self.comparison, self.offset
+            )
+        }
+    }
+
+    fn requires_full_materialization(&self) -> bool {
+        true
+    }
+
+    fn is_time_dependent(&self) -> bool {
+        true
+    }
+
+    fn next_timeout(&self) -> Option<Duration> {
+        self.timeout_at(Utc::now())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{FixedOffset, NaiveDate};
+    use chrono_tz::America::New_York;
+
+    use super::*;
+    use crate::ops;
+
+    fn at(day: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2024, 1, day)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+    }
+
+    fn now(day: u32) -> DateTime<Utc> {
+        Utc.from_utc_datetime(&at(day))
+    }
+
+    fn row(id: i32, day: u32) -> Vec<DfValue> {
+        vec![id.into(), at(day).into()]
+    }
+
+    fn operator(comparison: TemporalComparison) -> TemporalFilter {
+        TemporalFilter::new(
+            NodeIndex::new(0),
+            1,
+            comparison,
+            Interval::default(),
+            Some(Tz::UTC),
+        )
+    }
+
+    fn setup(comparison: TemporalComparison) -> ops::test::MockGraph {
+        let mut g = ops::test::MockGraph::new();
+        let s = g.add_base("source", &["id", "expires_at"]);
+        g.set_op(
+            "temporal_filter",
+            &["id", "expires_at"],
+            TemporalFilter::new(s.as_global(), 1, comparison, Interval::default(), None),
+            true,
+        );
+        g
+    }
+
+    fn far_past() -> Vec<DfValue> {
+        vec![
+            1.into(),
+            NaiveDate::from_ymd_opt(1970, 1, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
+                .into(),
+        ]
+    }
+
+    fn far_future() -> Vec<DfValue> {
+        vec![
+            2.into(),
+            NaiveDate::from_ymd_opt(9000, 1, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
+                .into(),
+        ]
+    }
+
+    #[test]
+    fn greater_forwards_future_rows() {
+        let mut g = setup(TemporalComparison::Greater);
+        assert!(g.narrow_one_row(far_past(), false).is_empty());
+        assert_eq!(
+            g.narrow_one_row(far_future(), false),
+            vec![far_future()].into()
+        );
+        assert!(g.node().next_timeout().is_some());
+    }
+
+    #[test]
+    fn less_forwards_past_rows() {
+        let mut g = setup(TemporalComparison::Less);
+        assert_eq!(g.narrow_one_row(far_past(), false), vec![far_past()].into());
+        assert!(g.narrow_one_row(far_future(), false).is_empty());
+        assert!(g.node().next_timeout().is_some());
+    }
+
+    #[test]
+    fn null_never_matches() {
+        let mut g = setup(TemporalComparison::Less);
+        assert!(g
+            .narrow_one_row(vec![1.into(), DfValue::None], false)
+            .is_empty());
+        assert!(g.node().next_timeout().is_none());
+    }
+
+    #[test]
+    fn retracts_expired_rows() {
+        let mut f = operator(TemporalComparison::Greater);
+        let rs = f.process(now(1), vec![row(1, 2), row(2, 3), row(3, 1)].into());
+        assert_eq!(
+            rs,
+            vec![Record::Positive(row(1, 2)), Record::Positive(row(2, 3))]
+        );
+        assert_eq!(f.timeout_at(now(1)), Some(Duration::from_secs(86400)));
+
+        // At exactly the value, `>` no longer holds
+        assert_eq!(f.advance(now(2)), vec![Record::Negative(row(1, 2))]);
+        assert_eq!(f.timeout_at(now(2)), Some(Duration::from_secs(86400)));
+        assert!(f.advance(now(2)).is_empty());
+
+        assert_eq!(f.advance(now(10)), vec![Record::Negative(row(2, 3))]);
+        assert_eq!(f.timeout_at(now(10)), None);
+    }
+
+    #[test]
+    fn inserts_rows_once_they_match() {
+        let mut f = operator(TemporalComparison::LessOrEqual);
+        let rs = f.process(now(1), vec![row(1, 1), row(2, 3)].into());
+        assert_eq!(rs, vec![Record::Positive(row(1, 1))]);
+        assert_eq!(f.timeout_at(now(2)), Some(Duration::from_secs(86400)));
+
+        assert!(f.advance(now(2)).is_empty());
+        assert_eq!(f.advance(now(3)), vec![Record::Positive(row(2, 3))]);
+    }
+
+    #[test]
+    fn strict_boundaries() {
+        let mut f = operator(TemporalComparison::Less);
+        assert!(f.process(now(1), vec![row(1, 2)].into()).is_empty());
+        assert_eq!(f.timeout_at(now(2)), Some(Duration::from_micros(1)));
+        assert!(f.advance(now(2)).is_empty());
+
+        let mut f = operator(TemporalComparison::GreaterOrEqual);
+        assert_eq!(
+            f.process(now(1), vec![row(1, 2)].into()),
+            vec![Record::Positive(row(1, 2))]
+        );
+        assert!(f.advance(now(2)).is_empty());
+        assert_eq!(
+            f.advance(now(2) + chrono::Duration::microseconds(1)),
+            vec![Record::Negative(row(1, 2))]
+        );
+    }
+
+    #[test]
+    fn negatives_remove_pending_rows() {
+        let mut f = operator(TemporalComparison::Greater);
+        f.process(now(1), vec![row(1, 2), row(2, 2)].into());
+
+        let rs = f.process(now(1), vec![Record::Negative(row(1, 2))].into());
+        assert_eq!(rs, vec![Record::Negative(row(1, 2))]);
+        assert_eq!(f.advance(now(3)), vec![Record::Negative(row(2, 2))]);
+
+        // A negative for a row that already expired is dropped
+        assert!(f
+            .process(now(3), vec![Record::Negative(row(2, 2))].into())
+            .is_empty());
+    }
+
+    #[test]
+    fn compares_time_zones_as_instants() {
+        let mut f = operator(TemporalComparison::Greater);
+        // Midnight on the 3rd in UTC+5 is 19:00 on the 2nd in UTC
+        let in_utc_plus_5 = |day| -> DfValue {
+            FixedOffset::east_opt(5 * 3600)
+                .unwrap()
+                .from_local_datetime(&at(day))
+                .unwrap()
+                .into()
+        };
+        let rs = f.process(
+            now(2),
+            vec![
+                vec![1.into(), in_utc_plus_5(3)],
+                vec![2.into(), in_utc_plus_5(2)],
+            ]
+            .into(),
+        );
+        assert_eq!(rs, vec![Record::Positive(vec![1.into(), in_utc_plus_5(3)])]);
+        assert_eq!(f.timeout_at(now(2)), Some(Duration::from_secs(19 * 3600)));
+    }
+
+    #[test]
+    fn naive_timestamps_are_in_the_configured_time_zone() {
+        let mut f = TemporalFilter::new(
+            NodeIndex::new(0),
+            1,
+            TemporalComparison::Greater,
+            Interval::default(),
+            Some(New_York),
+        );
+        // Midnight on the 2nd in New York is 05:00 on the 2nd in UTC, so the row still matches at
+        // midnight UTC, and expires five hours later
+        let rs = f.process(now(2), vec![row(1, 2)].into());
+        assert_eq!(rs, vec![Record::Positive(row(1, 2))]);
+        assert_eq!(f.timeout_at(now(2)), Some(Duration::from_secs(5 * 3600)));
+        assert!(f.advance(now(2) + chrono::Duration::hours(4)).is_empty());
+        assert_eq!(
+            f.advance(now(2) + chrono::Duration::hours(5)),
+            vec![Record::Negative(row(1, 2))]
+        );
+    }
+
+    #[test]
+    fn day_offsets_across_dst_transitions() {
+        // `NOW() - INTERVAL '1 day'` is the same time of day yesterday in New York, even though
+        // the clocks went forward on 2024-03-10, making that day only 23 hours long
+        let mut f = TemporalFilter::new(
+            NodeIndex::new(0),
+            1,
+            TemporalComparison::Greater,
+            Interval::new(0, -1, 0),
+            Some(New_York),
+        );
+        let noon = |day| {
+            NaiveDate::from_ymd_opt(2024, 3, day)
+                .unwrap()
+                .and_hms_opt(12, 0, 0)
+                .unwrap()
+        };
+        let value: DfValue = noon(10).into();
+        let rs = f.process(
+            local_to_utc(&New_York, noon(10)),
+            vec![vec![1.into(), value.clone()]].into(),
+        );
+        assert_eq!(rs, vec![Record::Positive(vec![1.into(), value])]);
+
+        // The row expires at noon on the 11th in New York, which is 24 hours later, since both
+        // times are in EDT
+        assert_eq!(
+            f.timeout_at(local_to_utc(&New_York, noon(10))),
+            Some(Duration::from_secs(24 * 3600))
+        );
+
+        // Whereas a row at noon on the 9th, in EST, expires at noon on the 10th, 23 hours later
+        let mut f = TemporalFilter::new(
+            NodeIndex::new(0),
+            1,
+            TemporalComparison::Greater,
+            Interval::new(0, -1, 0),
+            Some(New_York),
+        );
+        f.process(
+            local_to_utc(&New_York, noon(9)),
+            vec![vec![1.into(), noon(9).into()]].into(),
+        );
+        assert_eq!(
+            f.timeout_at(local_to_utc(&New_York, noon(9))),
+            Some(Duration::from_secs(23 * 3600))
+        );
+    }
+
+    #[test]
+    fn threshold_never_moves_backwards() {
+        let mut f = operator(TemporalComparison::Greater);
+        f.process(now(1), vec![row(1, 2)].into());
+        f.advance(now(5));
+        assert!(f.process(now(1), vec![row(2, 3)].into()).is_empty());
+    }
+
+    #[test]
+    fn month_offset_deadline() {
+        let mut f = TemporalFilter::new(
+            NodeIndex::new(0),
+            1,
+            TemporalComparison::Greater,
+            Interval::new(1, 0, 0),
+            Some(Tz::UTC),
+        );
+        let value = NaiveDate::from_ymd_opt(2024, 3, 31)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        let feb_1 = Utc.from_utc_datetime(
+            &NaiveDate::from_ymd_opt(2024, 2, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap(),
+        );
+        f.process(feb_1, vec![vec![1.into(), value.into()]].into());
+
+        // '2024-02-29' + '1 month' is only '2024-03-29', so the row expires on '2024-03-01'
+        assert_eq!(f.timeout_at(feb_1), Some(Duration::from_secs(29 * 86400)));
+    }
+
+    #[test]
+    fn applies_offset() {
+        let mut f = TemporalFilter::new(
+            NodeIndex::new(0),
+            1,
+            TemporalComparison::Less,
+            Interval::new(0, -1, 0),
+            Some(Tz::UTC),
+        );
+        assert!(f.process(now(2), vec![row(1, 2)].into()).is_empty());
+        assert_eq!(
+            f.timeout_at(now(2)),
+            Some(Duration::from_secs(86400) + Duration::from_micros(1))
+        );
+        assert_eq!(f.advance(now(4)), vec![Record::Positive(row(1, 2))]);
+    }
+}
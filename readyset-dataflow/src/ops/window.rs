@@ -0,0 +1,772 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::convert::TryInto;
+use std::fmt;
+
+use dataflow_state::PointKey;
+use itertools::Itertools;
+use nom_sql::OrderType;
+use readyset_data::{DfType, Dialect};
+use readyset_util::Indices;
+use serde::{Deserialize, Serialize};
+
+use crate::ops::grouped::aggregate::Aggregation;
+use crate::ops::utils::Order;
+use crate::prelude::*;
+use crate::processing::{ColumnMiss, LookupIndex};
+
+/// The function computed by a [`Window`] operator for each row in a partition
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WindowFunction {
+    /// `ROW_NUMBER()` - the 1-based position of the row within its partition
+    RowNumber,
+    /// `RANK()` - the 1-based position of the first peer of the row within its partition, with
+    /// gaps for ties
+    Rank,
+    /// `DENSE_RANK()` - the 1-based position of the row's peer group within its partition,
+    /// without gaps for ties
+    DenseRank,
+    /// `COUNT` of the non-null values of the `over` column, or `COUNT(*)` if there is no `over`
+    /// column
+    Count,
+    /// `SUM` of the `over` column
+    Sum,
+    /// `AVG` of the `over` column
+    Avg,
+    /// `MIN` of the `over` column
+    Min,
+    /// `MAX` of the `over` column
+    Max,
+}
+
+impl WindowFunction {
+    /// Returns true if this function aggregates over the rows in its window frame, rather than
+    /// ranking rows within the partition
+    pub fn is_aggregate(&self) -> bool {
+        !matches!(
+            self,
+            WindowFunction::RowNumber | WindowFunction::Rank | WindowFunction::DenseRank
+        )
+    }
+
+    /// Returns the type of the values produced by this window function, given the type of the
+    /// column it aggregates over, if any
+    pub fn output_type(
+        &self,
+        over_col_ty: Option<&DfType>,
+        dialect: &Dialect,
+    ) -> ReadySetResult<DfType> {
+        match (self, over_col_ty) {
+            (
+                WindowFunction::RowNumber
+                | WindowFunction::Rank
+                | WindowFunction::DenseRank
+                | WindowFunction::Count,
+                _,
+            ) => Ok(DfType::BigInt),
+            (WindowFunction::Sum, Some(ty)) => Aggregation::Sum.output_type(ty, dialect),
+            (WindowFunction::Avg, Some(ty)) => Aggregation::Avg.output_type(ty, dialect),
+            (WindowFunction::Min | WindowFunction::Max, Some(ty)) => Ok(ty.clone()),
+            (_, None) => internal!("{self} requires a column to aggregate over"),
+        }
+    }
+}
+
+impl fmt::Display for WindowFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WindowFunction::RowNumber => write!(f, "row_number"),
+            WindowFunction::Rank => write!(f, "rank"),
+            WindowFunction::DenseRank => write!(f, "dense_rank"),
+            WindowFunction::Count => write!(f, "count"),
+            WindowFunction::Sum => write!(f, "sum"),
+            WindowFunction::Avg => write!(f, "avg"),
+            WindowFunction::Min => write!(f, "min"),
+            WindowFunction::Max => write!(f, "max"),
+        }
+    }
+}
+
+/// Running state for an aggregate window function, accumulated over the rows in a window frame
+#[derive(Default)]
+struct Accumulator {
+    count: u64,
+    sum: Option<DfValue>,
+    min: Option<DfValue>,
+    max: Option<DfValue>,
+}
+
+impl Accumulator {
+    fn add(&mut self, value: &DfValue, zero: &DfValue) -> ReadySetResult<()> {
+        if value.is_none() {
+            return Ok(());
+        }
+
+        self.count += 1;
+        self.sum = Some((self.sum.as_ref().unwrap_or(zero) + value)?);
+        if self.min.as_ref().map_or(true, |min| value < min) {
+            self.min = Some(value.clone());
+        }
+        if self.max.as_ref().map_or(true, |max| value > max) {
+            self.max = Some(value.clone());
+        }
+        Ok(())
+    }
+}
+
+/// Operator which computes a window function (`<function> OVER (PARTITION BY ... ORDER BY
+/// ...)`) for every row in its parent, emitting each row with the value of the window function
+/// appended as an extra column.
+///
+/// Like [`Paginate`](crate::ops::paginate::Paginate), this operator materializes its own output
+/// keyed on the partition columns. Whenever a partition changes, the value of the window function
+/// is recomputed for every row in that partition, and retractions and insertions are emitted for
+/// just the rows whose value actually changed.
+///
+/// If the window has an `ORDER BY`, aggregate functions are computed over the rows from the start
+/// of the partition up to and including the current row's peers (the default `RANGE BETWEEN
+/// UNBOUNDED PRECEDING AND CURRENT ROW` frame); otherwise, they are computed over the entire
+/// partition.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Window {
+    /// The direct Ingredient or Base ancestor of this node
+    src: IndexPair,
+    /// The index of this node. Used to look up into our own state
+    our_index: Option<IndexPair>,
+    /// The column index of the window function column emitted by this node
+    ///
+    /// This is always equal to the number of columns in the parent node (there is one more output
+    /// column than input columns)
+    ///
+    /// Set during [`Ingredient::on_connected`]
+    output_col: Option<usize>,
+    /// The function to compute for each row
+    function: WindowFunction,
+    /// The column the function aggregates over, if any
+    over: Option<usize>,
+    /// The list of column indices that we're partitioning by
+    partition_by: Vec<usize>,
+    /// The ordering of rows within each partition
+    order: Order,
+    /// True if the window has an `ORDER BY` clause
+    ordered: bool,
+    /// The type of the window function column emitted by this node
+    out_ty: DfType,
+}
+
+impl Window {
+    pub fn new(
+        src: NodeIndex,
+        function: WindowFunction,
+        over: Option<usize>,
+        partition_by: Vec<usize>,
+        order: Vec<(usize, OrderType)>,
+        out_ty: DfType,
+    ) -> Self {
+        Window {
+            src: src.into(),
+            our_index: None,
+            output_col: None,
+            function,
+            over,
+            partition_by,
+            ordered: !order.is_empty(),
+            order: order.into(),
+            out_ty,
+        }
+    }
+
+    /// Project the columns we are partitioning by out of the given record
+    fn project_partition<'rec, R>(&self, rec: &'rec R) -> ReadySetResult<Vec<&'rec DfValue>>
+    where
+        R: Indices<'static, usize, Output = DfValue> + ?Sized,
+    {
+        rec.indices(self.partition_by.clone())
+            .map_err(|_| ReadySetError::InvalidRecordLength)
+    }
+
+    /// Return the column index of the window function column output by this node (which will
+    /// always be the last column)
+    ///
+    /// # Panics
+    ///
+    /// Panics if called before [`Ingredient::on_connected`]
+    fn output_column(&self) -> usize {
+        self.output_col
+            .expect("output_column called before Ingredient::on_connected")
+    }
+
+    /// Compare two rows within a partition, breaking ties in the window's ordering by comparing the
+    /// rows themselves so that functions like `ROW_NUMBER()` are assigned deterministically
+    fn cmp_rows(&self, a: &[DfValue], b: &[DfValue]) -> Ordering {
+        let oc = self.output_column();
+        self.order.cmp(a, b).then_with(|| a[..oc].cmp(&b[..oc]))
+    }
+
+    /// The value to start summing from, based on our output type
+    fn zero(&self) -> DfValue {
+        match self.out_ty {
+            DfType::Double => DfValue::Double(0.0),
+            DfType::Float => DfValue::Float(0.0),
+            DfType::Numeric { .. } => DfValue::Numeric(Default::default()),
+            _ => DfValue::Int(0),
+        }
+    }
+
+    /// Compute the value of the window function for every row in the given partition, which must
+    /// be sorted according to [`Self::cmp_rows`]
+    fn compute(&self, partition: &[Vec<DfValue>]) -> ReadySetResult<Vec<DfValue>> {
+        let zero = self.zero();
+        let aggregate_result = |acc: &Accumulator, frame_len: usize| -> ReadySetResult<DfValue> {
+            Ok(match self.function {
+                WindowFunction::Count if self.over.is_none() => DfValue::from(frame_len as i64),
+                WindowFunction::Count => DfValue::from(acc.count as i64),
+                WindowFunction::Sum => acc.sum.clone().unwrap_or(DfValue::None),
+                WindowFunction::Avg => match &acc.sum {
+                    Some(sum) => (sum / &DfValue::from(acc.count as i64))?,
+                    None => DfValue::None,
+                },
+                WindowFunction::Min => acc.min.clone().unwrap_or(DfValue::None),
+                WindowFunction::Max => acc.max.clone().unwrap_or(DfValue::None),
+                WindowFunction::RowNumber | WindowFunction::Rank | WindowFunction::DenseRank => {
+                    internal!("{} is not an aggregate window function", self.function)
+                }
+            })
+        };
+
+        let mut res = Vec::with_capacity(partition.len());
+        match self.function {
+            WindowFunction::RowNumber => {
+                res.extend((1..=partition.len() as i64).map(DfValue::from));
+            }
+            WindowFunction::Rank | WindowFunction::DenseRank => {
+                let mut rank = 0i64;
+                for (i, row) in partition.iter().enumerate() {
+                    let new_peer_group =
+                        i == 0 || self.order.cmp(&partition[i - 1], row) != Ordering::Equal;
+                    if new_peer_group {
+                        rank = if self.function == WindowFunction::Rank {
+                            i as i64 + 1
+                        } else {
+                            rank + 1
+                        };
+                    }
+                    res.push(DfValue::from(rank));
+                }
+            }
+            _ if !self.ordered => {
+                // No ORDER BY, so the frame for every row is the whole partition
+                let mut acc = Accumulator::default();
+                if let Some(over) = self.over {
+                    for row in partition {
+                        acc.add(&row[over], &zero)?;
+                    }
+                }
+                let value = aggregate_result(&acc, partition.len())?;
+                res.resize(partition.len(), value);
+            }
+            _ => {
+                // The frame for each row extends from the start of the partition through the last
+                // of that row's peers, so accumulate one peer group at a time
+                let mut acc = Accumulator::default();
+                let mut start = 0;
+                while start < partition.len() {
+                    let end = partition[start..]
+                        .iter()
+                        .position(|row| self.order.cmp(&partition[start], row) != Ordering::Equal)
+                        .map_or(partition.len(), |len| start + len);
+                    if let Some(over) = self.over {
+                        for row in &partition[start..end] {
+                            acc.add(&row[over], &zero)?;
+                        }
+                    }
+                    let value = aggregate_result(&acc, end)?;
+                    res.resize(end, value);
+                    start = end;
+                }
+            }
+        }
+
+        Ok(res)
+    }
+
+    /// Recompute the window function for all the rows in a partition, emitting records for all
+    /// rows whose value changed.
+    ///
+    /// Rows in `current_partition` which were loaded from our state have the window function
+    /// column as their last column, and new rows are one column shorter.
+    fn post_partition(
+        &self,
+        out: &mut Vec<Record>,
+        current_partition: &mut Vec<Vec<DfValue>>,
+    ) -> ReadySetResult<()> {
+        let oc = self.output_column();
+        current_partition.sort_by(|a, b| self.cmp_rows(a, b));
+        let values = self.compute(current_partition)?;
+
+        for (mut row, value) in current_partition.drain(..).zip(values) {
+            if row.len() > oc {
+                // The row started out in the partition, so we only need to emit anything if its
+                // value changed
+                if row[oc] != value {
+                    out.push(Record::Negative(row.clone()));
+                    row[oc] = value;
+                    out.push(Record::Positive(row));
+                }
+            } else {
+                row.push(value);
+                out.push(Record::Positive(row));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Ingredient for Window {
+    fn ancestors(&self) -> Vec<NodeIndex> {
+        vec![self.src.as_global()]
+    }
+
+    impl_replace_sibling!(src);
+
+    fn on_connected(&mut self, graph: &Graph) {
+        self.output_col = Some(graph[self.src.as_global()].columns().len());
+    }
+
+    fn on_commit(&mut self, us: NodeIndex, remap: &HashMap<NodeIndex, IndexPair>) {
+        self.src.remap(remap);
+        self.our_index = Some(remap[&us]);
+    }
+
+    fn on_input(
+        &mut self,
+        from: LocalNodeIndex,
+        rs: Records,
+        replay: &ReplayContext,
+        _nodes: &DomainNodes,
+        state: &StateMap,
+        _auxiliary_node_states: &mut AuxiliaryNodeStateMap,
+    ) -> ReadySetResult<ProcessingResult> {
+        debug_assert_eq!(from, *self.src);
+
+        if rs.is_empty() {
+            return Ok(ProcessingResult {
+                results: rs,
+                ..Default::default()
+            });
+        }
+
+        let mut rs = Vec::from(rs);
+        rs.sort_by(|a: &Record, b: &Record| {
+            self.project_partition(&***a)
+                .unwrap_or_default()
+                .cmp(&self.project_partition(&***b).unwrap_or_default())
+        });
+
+        let us = self.our_index.unwrap();
+        let db = state
+            .get(*us)
+            .ok_or_else(|| internal_err!("window must have its own state materialized"))?;
+
+        let oc = self.output_column();
+        let mut current_partition_key: Option<Vec<DfValue>> = None;
+        let mut current_partition: Vec<Vec<DfValue>> = vec![];
+        let mut partition_missed = false;
+
+        let mut out = vec![];
+        let mut lookups = vec![];
+        let mut misses = vec![];
+
+        for r in rs {
+            let record_partition = self.project_partition(r.rec())?;
+            if current_partition_key
+                .as_ref()
+                .map_or(true, |key| key.iter().ne(record_partition.iter().copied()))
+            {
+                // New partition!
+                if current_partition_key.is_some() && !partition_missed {
+                    self.post_partition(&mut out, &mut current_partition)?;
+                }
+                current_partition.clear();
+
+                let key = record_partition.into_iter().cloned().collect::<Vec<_>>();
+
+                // Load all the rows in the partition into memory
+                match db.lookup(&self.partition_by, &PointKey::from(key.clone())) {
+                    LookupResult::Some(local_records) => {
+                        if replay.is_partial() {
+                            lookups.push(Lookup {
+                                on: *us,
+                                cols: self.partition_by.clone(),
+                                key: key.clone().try_into().expect("Empty partition"),
+                            });
+                        }
+
+                        partition_missed = false;
+                        current_partition
+                            .extend(local_records.into_iter().map(|row| row.into_owned()));
+                    }
+                    LookupResult::Missing => {
+                        partition_missed = true;
+                    }
+                }
+
+                current_partition_key = Some(key);
+            }
+
+            if partition_missed {
+                misses.push(
+                    Miss::builder()
+                        .on(*us)
+                        .lookup_idx(self.partition_by.clone())
+                        .lookup_key(self.partition_by.clone())
+                        .replay(replay)
+                        .record(r.into_row())
+                        .build(),
+                );
+                continue;
+            }
+
+            match r {
+                Record::Positive(r) => current_partition.push(r),
+                Record::Negative(r) => {
+                    // Prefer retracting a row that we've already emitted, since that's the one
+                    // downstream nodes know about
+                    let pos = current_partition
+                        .iter()
+                        .position(|row| row.len() > oc && row[..oc] == r[..])
+                        .or_else(|| current_partition.iter().position(|row| *row == r));
+                    if let Some(pos) = pos {
+                        let row = current_partition.swap_remove(pos);
+                        if row.len() > oc {
+                            out.push(Record::Negative(row));
+                        }
+                    }
+                }
+            }
+        }
+
+        if current_partition_key.is_some() && !partition_missed {
+            self.post_partition(&mut out, &mut current_partition)?;
+        }
+
+        Ok(ProcessingResult {
+            results: out.into(),
+            lookups,
+            misses,
+        })
+    }
+
+    fn suggest_indexes(&self, this: NodeIndex) -> HashMap<NodeIndex, LookupIndex> {
+        HashMap::from([(
+            this,
+            LookupIndex::Strict(Index::hash_map(self.partition_by.clone())),
+        )])
+    }
+
+    fn column_source(&self, cols: &[usize]) -> ColumnSource {
+        if cols.contains(&self.output_column()) {
+            if cols.len() == 1 {
+                // Lookups on just the window function value require a full replay
+                return ColumnSource::RequiresFullReplay(vec1![self.src.as_global()]);
+            }
+
+            let columns = cols
+                .iter()
+                .copied()
+                .filter(|c| *c != self.output_column())
+                .collect::<Vec<_>>();
+            ColumnSource::GeneratedFromColumns(vec1![ColumnRef {
+                node: self.our_index.unwrap().as_global(),
+                columns,
+            }])
+        } else {
+            ColumnSource::ExactCopy(ColumnRef {
+                node: self.src.as_global(),
+                columns: cols.to_vec(),
+            })
+        }
+    }
+
+    fn handle_upquery(&mut self, miss: ColumnMiss) -> ReadySetResult<Vec<ColumnMiss>> {
+        let output_column = miss
+            .column_indices
+            .iter()
+            .position(|ci| *ci == self.output_column())
+            .expect("handle_upquery invariant");
+
+        Ok(vec![ColumnMiss {
+            node: *self.our_index.unwrap(),
+            column_indices: self.partition_by.clone(),
+            missed_keys: miss.missed_keys.mapped(|k| {
+                k.map_endpoints(|mut r| {
+                    r.remove(output_column).expect("handle_upquery invariant");
+                    r
+                })
+            }),
+        }])
+    }
+
+    fn description(&self, detailed: bool) -> String {
+        if !detailed {
+            return "Window".into();
+        }
+
+        format!(
+            "Window {}({}) π[{}] o[{}]",
+            self.function,
+            self.over
+                .map(|c| c.to_string())
+                .unwrap_or_else(|| "*".into()),
+            self.partition_by.iter().join(", "),
+            self.order
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ops::test::MockGraph;
+
+    fn setup(
+        function: WindowFunction,
+        over: Option<usize>,
+        order: Vec<(usize, OrderType)>,
+    ) -> MockGraph {
+        let mut g = MockGraph::new();
+        let s = g.add_base("source", &["x", "y"]);
+
+        // <function> OVER (PARTITION BY y ORDER BY <order>)
+        g.set_op(
+            "window",
+            &["x", "y", "w"],
+            Window::new(
+                s.as_global(),
+                function,
+                over,
+                vec![1],
+                order,
+                DfType::BigInt,
+            ),
+            true,
+        );
+        g
+    }
+
+    fn with_value(row: &[DfValue], value: i64) -> Vec<DfValue> {
+        let mut res = row.to_vec();
+        res.push(value.into());
+        res
+    }
+
+    #[test]
+    fn suggest_indexes() {
+        let g = setup(
+            WindowFunction::RowNumber,
+            None,
+            vec![(0, OrderType::OrderAscending)],
+        );
+        let res = g.node().suggest_indexes(g.node_index().as_global());
+        assert_eq!(res.len(), 1);
+        assert_eq!(
+            res[&g.node_index().as_global()],
+            LookupIndex::Strict(Index::hash_map(vec![1]))
+        );
+    }
+
+    #[test]
+    fn column_source_for_window_column() {
+        let g = setup(
+            WindowFunction::RowNumber,
+            None,
+            vec![(0, OrderType::OrderAscending)],
+        );
+        let src = g.node().column_source(&[1, 2]);
+        assert_eq!(
+            src,
+            ColumnSource::GeneratedFromColumns(vec1![ColumnRef {
+                node: g.node_index().as_global(),
+                columns: vec![1],
+            }])
+        );
+    }
+
+    #[test]
+    fn row_number_multiple_partitions() {
+        let mut g = setup(
+            WindowFunction::RowNumber,
+            None,
+            vec![(0, OrderType::OrderDescending)],
+        );
+
+        let r1a = vec![1.into(), "a".into()];
+        let r2a = vec![2.into(), "a".into()];
+        let r1b = vec![1.into(), "b".into()];
+
+        let res = g.narrow_one(vec![r1a.clone(), r2a.clone(), r1b.clone()], true);
+        assert_eq!(
+            res,
+            vec![
+                with_value(&r2a, 1),
+                with_value(&r1a, 2),
+                with_value(&r1b, 1)
+            ]
+            .into()
+        );
+    }
+
+    #[test]
+    fn row_number_insert_shifts_later_rows() {
+        let mut g = setup(
+            WindowFunction::RowNumber,
+            None,
+            vec![(0, OrderType::OrderAscending)],
+        );
+
+        let r1a = vec![1.into(), "a".into()];
+        let r2a = vec![2.into(), "a".into()];
+        let r3a = vec![3.into(), "a".into()];
+        g.narrow_one(vec![r1a, r3a.clone()], true);
+
+        let res = g.narrow_one_row(r2a.clone(), true);
+        assert_eq!(
+            res,
+            vec![
+                (with_value(&r2a, 2), true),
+                (with_value(&r3a, 2), false),
+                (with_value(&r3a, 3), true),
+            ]
+            .into()
+        );
+    }
+
+    #[test]
+    fn row_number_delete_shifts_later_rows() {
+        let mut g = setup(
+            WindowFunction::RowNumber,
+            None,
+            vec![(0, OrderType::OrderAscending)],
+        );
+
+        let r1a = vec![1.into(), "a".into()];
+        let r2a = vec![2.into(), "a".into()];
+        let r3a = vec![3.into(), "a".into()];
+        g.narrow_one(vec![r1a.clone(), r2a.clone(), r3a.clone()], true);
+
+        let res = g.narrow_one_row((r1a.clone(), false), true);
+        assert_eq!(
+            res,
+            vec![
+                (with_value(&r1a, 1), false),
+                (with_value(&r2a, 2), false),
+                (with_value(&r2a, 1), true),
+                (with_value(&r3a, 3), false),
+                (with_value(&r3a, 2), true),
+            ]
+            .into()
+        );
+    }
+
+    #[test]
+    fn rank_and_dense_rank_with_ties() {
+        let r1a = vec![1.into(), "a".into()];
+        let r1a_2 = vec![1.into(), "a".into()];
+        let r2a = vec![2.into(), "a".into()];
+
+        let mut g = setup(
+            WindowFunction::Rank,
+            None,
+            vec![(0, OrderType::OrderAscending)],
+        );
+        let res = g.narrow_one(vec![r1a.clone(), r1a_2.clone(), r2a.clone()], true);
+        assert_eq!(
+            res,
+            vec![
+                with_value(&r1a, 1),
+                with_value(&r1a_2, 1),
+                with_value(&r2a, 3)
+            ]
+            .into()
+        );
+
+        let mut g = setup(
+            WindowFunction::DenseRank,
+            None,
+            vec![(0, OrderType::OrderAscending)],
+        );
+        let res = g.narrow_one(vec![r1a.clone(), r1a_2.clone(), r2a.clone()], true);
+        assert_eq!(
+            res,
+            vec![
+                with_value(&r1a, 1),
+                with_value(&r1a_2, 1),
+                with_value(&r2a, 2)
+            ]
+            .into()
+        );
+    }
+
+    #[test]
+    fn sum_over_whole_partition() {
+        let mut g = setup(WindowFunction::Sum, Some(0), vec![]);
+
+        let r1a = vec![1.into(), "a".into()];
+        let r2a = vec![2.into(), "a".into()];
+        let r4b = vec![4.into(), "b".into()];
+        let res = g.narrow_one(vec![r1a.clone(), r2a.clone(), r4b.clone()], true);
+        assert_eq!(
+            res,
+            vec![
+                with_value(&r1a, 3),
+                with_value(&r2a, 3),
+                with_value(&r4b, 4)
+            ]
+            .into()
+        );
+
+        let r3a = vec![3.into(), "a".into()];
+        let res = g.narrow_one_row(r3a.clone(), true);
+        assert_eq!(
+            res,
+            vec![
+                (with_value(&r1a, 3), false),
+                (with_value(&r1a, 6), true),
+                (with_value(&r2a, 3), false),
+                (with_value(&r2a, 6), true),
+                (with_value(&r3a, 6), true),
+            ]
+            .into()
+        );
+    }
+
+    #[test]
+    fn running_count_with_order() {
+        let mut g = setup(
+            WindowFunction::Count,
+            None,
+            vec![(0, OrderType::OrderAscending)],
+        );
+
+        let r1a = vec![1.into(), "a".into()];
+        let r2a = vec![2.into(), "a".into()];
+        let r2a_2 = vec![2.into(), "a".into()];
+        let r3a = vec![3.into(), "a".into()];
+        let res = g.narrow_one(
+            vec![r1a.clone(), r2a.clone(), r2a_2.clone(), r3a.clone()],
+            true,
+        );
+        // Peers share the same frame
+        assert_eq!(
+            res,
+            vec![
+                with_value(&r1a, 1),
+                with_value(&r2a, 3),
+                with_value(&r2a_2, 3),
+                with_value(&r3a, 4)
+            ]
+            .into()
+        );
+    }
+}
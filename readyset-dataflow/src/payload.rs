@@ -1,5 +1,6 @@
 use std::collections::HashSet;
 use std::fmt::{self, Display};
+use std::path::PathBuf;
 
 use dataflow_state::MaterializedNodeState;
 use itertools::Itertools;
@@ -231,6 +232,11 @@ pub enum EvictRequest {
         num_bytes: usize,
     },
 
+    /// Evict every key from the target reader node, including any that have been spilled to disk.
+    ///
+    /// Used to expire the contents of caches with a `ttl`.
+    Reader { node: LocalNodeIndex },
+
     /// Evict the indicated keys from the materialization targeted by the replay path `tag` (along
     /// with any other materializations below it).
     Keys {
@@ -400,6 +406,12 @@ pub enum DomainRequest {
     /// Request a map of all replication offsets of the base table nodes in the domain
     RequestReplicationOffsets,
 
+    /// Create a checkpoint of the persistent state of each base table node in the domain in the
+    /// given directory, and return a map of the replication offsets of those checkpoints
+    CheckpointBaseTables {
+        dir: PathBuf,
+    },
+
     /// Request a list of base table nodes that are currently involved in snapshotting.
     RequestSnapshottingTables,
 
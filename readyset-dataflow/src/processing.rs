@@ -821,4 +821,16 @@ where
     fn requires_full_materialization(&self) -> bool {
         false
     }
+
+    /// Returns true if the output of this operator depends on the current time, and so must be
+    /// woken up by the domain periodically (see [`Ingredient::next_timeout`])
+    fn is_time_dependent(&self) -> bool {
+        false
+    }
+
+    /// Returns how long until this operator next needs to be sent an (empty) message to update
+    /// its output for the passage of time, or `None` if it doesn't need to be woken up.
+    fn next_timeout(&self) -> Option<std::time::Duration> {
+        None
+    }
 }
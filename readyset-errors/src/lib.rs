@@ -334,6 +334,12 @@ pub enum ReadySetError {
     #[error("the queries lookup key is not found at the reader")]
     ReaderMissingKey,
 
+    /// A read from a cache with a maximum staleness could not be checked against that bound,
+    /// because no recent enough sample of the upstream database's replication position was
+    /// available.
+    #[error("No recent upstream replication position to check the staleness of the read against")]
+    UpstreamPositionUnavailable,
+
     /// A prepared statement is missing.
     #[error("Prepared statement with ID {statement_id} not found")]
     PreparedStatementMissing {
@@ -456,6 +456,7 @@ impl TryFrom<DfValue> for Value {
             DfValue::Numeric(ref d) => Ok(Value::Numeric(*d.as_ref())),
             DfValue::BitVector(ref b) => Ok(Value::BitVector(b.as_ref().clone())),
             DfValue::Array(_) => bail!("Arrays not supported"),
+            DfValue::Interval(ref i) => Ok(Value::Text(i.to_string())),
             DfValue::PassThrough(_) => unimplemented!(),
         }
     }
@@ -104,6 +104,7 @@ fn is_ddl(query: &SqlQuery) -> bool {
         | SqlQuery::Explain(_)
         | SqlQuery::Deallocate(_)
         | SqlQuery::Truncate(_)
+        | SqlQuery::Copy(_)
         | SqlQuery::Comment(_) => false,
         SqlQuery::CreateDatabase(_)
         | SqlQuery::CreateTable(_)
@@ -114,6 +115,7 @@ fn is_ddl(query: &SqlQuery) -> bool {
         | SqlQuery::RenameTable(_)
         | SqlQuery::Use(_)
         | SqlQuery::CreateCache(_)
+        | SqlQuery::AlterCache(_)
         | SqlQuery::DropCache(_)
         | SqlQuery::DropAllProxiedQueries(_)
         | SqlQuery::DropAllCaches(_) => true,
@@ -211,6 +211,7 @@ impl MirGraph {
                 order_by,
                 returned_cols,
                 aggregates,
+                left_join_filter,
                 ..
             } => {
                 let mut columns = self.columns(node);
@@ -224,6 +225,13 @@ impl MirGraph {
                                 .clone()
                                 .into_iter()
                                 .chain(aggs.aggregates.iter().map(|agg| agg.column.clone()))
+                        }))
+                        .chain(left_join_filter.iter().flat_map(|filter| {
+                            filter
+                                .left_columns
+                                .iter()
+                                .chain(&filter.right_columns)
+                                .cloned()
                         })),
                 );
                 columns
@@ -237,7 +245,14 @@ impl MirGraph {
                 }
                 columns
             }
-            MirNodeInner::Join { on, project } | MirNodeInner::LeftJoin { on, project } => {
+            MirNodeInner::TemporalFilter { column, .. } => {
+                let mut columns = self.columns(node);
+                if !columns.contains(column) {
+                    columns.push(column.clone())
+                }
+                columns
+            }
+            MirNodeInner::Join { on, project } | MirNodeInner::FullJoin { on, project } => {
                 let mut columns = project.clone();
                 for c in on.iter().flat_map(|(lc, rc)| [lc, rc]) {
                     if !columns.iter().any(|col| col == c) {
@@ -246,6 +261,47 @@ impl MirGraph {
                 }
                 columns
             }
+            MirNodeInner::LeftJoin {
+                on,
+                project,
+                residual,
+                ..
+            } => {
+                let mut columns = project.clone();
+                for c in on.iter().flat_map(|(lc, rc)| [lc, rc]) {
+                    if !columns.iter().any(|col| col == c) {
+                        columns.push(c.clone())
+                    }
+                }
+                for c in residual.iter().flat_map(|r| r.referred_columns()) {
+                    if !columns.iter().any(|col| col == c) {
+                        columns.push(c.clone().into())
+                    }
+                }
+                columns
+            }
+            MirNodeInner::Window {
+                over,
+                partition_by,
+                order,
+                output_column,
+                ..
+            } => {
+                // Windows pass through all of their parent's columns, and additionally need the
+                // columns they partition, order, and aggregate by
+                let mut columns = self.columns(node);
+                columns.retain(|c| c != output_column);
+                for c in over
+                    .iter()
+                    .chain(partition_by)
+                    .chain(order.iter().flatten().map(|(c, _)| c))
+                {
+                    if !columns.contains(c) {
+                        columns.push(c.clone());
+                    }
+                }
+                columns
+            }
             _ => self.columns(node),
         }
     }
@@ -264,6 +320,7 @@ impl MirGraph {
                 .map(|spec| spec.column.clone().into())
                 .collect(),
             MirNodeInner::Filter { .. }
+            | MirNodeInner::TemporalFilter { .. }
             | MirNodeInner::ViewKey { .. }
             | MirNodeInner::Leaf { .. }
             | MirNodeInner::Identity
@@ -292,6 +349,7 @@ impl MirGraph {
                 .collect(),
             MirNodeInner::Join { project, .. }
             | MirNodeInner::LeftJoin { project, .. }
+            | MirNodeInner::FullJoin { project, .. }
             | MirNodeInner::DependentJoin { project, .. }
             | MirNodeInner::DependentLeftJoin { project, .. } => project.clone(),
             MirNodeInner::JoinAggregates => {
@@ -320,10 +378,17 @@ impl MirGraph {
                 .first()
                 .cloned()
                 .expect("Union must have at least one set of emit columns"),
+            MirNodeInner::SetOperation { side, .. } => {
+                parent_columns().into_iter().filter(|c| c != side).collect()
+            }
             MirNodeInner::Paginate { .. } => parent_columns()
                 .into_iter()
                 .chain(iter::once(MirColumn::named(&*PAGE_NUMBER_COL)))
                 .collect(),
+            MirNodeInner::Window { output_column, .. } => parent_columns()
+                .into_iter()
+                .chain(iter::once(output_column.clone()))
+                .collect(),
             MirNodeInner::Distinct { group_by } => group_by
                 .iter()
                 .cloned()
@@ -3,14 +3,18 @@ use std::fmt::{self, Debug, Display};
 use common::{DfValue, IndexType};
 use dataflow::ops::grouped::aggregate::Aggregation;
 use dataflow::ops::grouped::extremum::Extremum;
+use dataflow::ops::set_operation::SetOperationKind;
+use dataflow::ops::temporal_filter::TemporalComparison;
 use dataflow::ops::union;
-use dataflow::PostLookupAggregates;
+use dataflow::ops::window::WindowFunction;
+use dataflow::{PostLookupAggregates, PostLookupLeftJoinFilter};
 use derive_more::From;
 use itertools::Itertools;
 use nom_sql::{
     BinaryOperator, ColumnSpecification, DialectDisplay, Expr, OrderType, Relation, SqlIdentifier,
 };
 use readyset_client::{PlaceholderIdx, ViewPlaceholder};
+use readyset_data::Interval;
 use readyset_errors::{internal, ReadySetResult};
 use serde::{Deserialize, Serialize};
 use vec1::Vec1;
@@ -126,6 +130,20 @@ pub enum MirNodeInner {
         /// name and table (and don't support aliases).
         conditions: Expr,
     },
+    /// Node which filters its input on a comparison between a column and the current time (plus
+    /// an offset), keeping its output correct as time passes.
+    ///
+    /// Converted to [`TemporalFilter`] when lowering to dataflow.
+    ///
+    /// [`TemporalFilter`]: dataflow::ops::temporal_filter::TemporalFilter
+    TemporalFilter {
+        /// Column to compare against the current time
+        column: Column,
+        /// How to compare `column` against `NOW() + offset`
+        comparison: TemporalComparison,
+        /// Offset to add to the current time before comparing
+        offset: Interval,
+    },
     /// Node which makes no changes to its input
     ///
     /// Converted to [`Identity`] when lowering to dataflow.
@@ -171,6 +189,37 @@ pub enum MirNodeInner {
         on: Vec<(Column, Column)>,
         /// Columns (from both parents) to project in the output.
         project: Vec<Column>,
+        /// An additional condition, referencing columns from both parents, which must hold for a
+        /// pair of rows with equal join keys to be joined. Rows in the left with no matching rows
+        /// in the right that satisfy this condition are joined against NULLs.
+        ///
+        /// This is used for join conditions (such as inequalities) which can't be expressed as
+        /// equal join keys, and can't be evaluated by a filter after the join without changing
+        /// which rows are NULL-padded.
+        residual: Option<Expr>,
+        /// If set, every row in the left is *also* joined against NULLs, whether or not it has any
+        /// matching rows in the right.
+        ///
+        /// This is used when the right-hand side of the join is filtered by a query parameter,
+        /// which can't be done before the join without knowing which rows should be NULL-padded.
+        /// Instead, the rows are filtered at the reader after lookup (see
+        /// [`PostLookupLeftJoinFilter`]), which removes the extra NULL-padded rows for left rows
+        /// that did match.
+        with_nulls: bool,
+    },
+    /// Node which computes a *full outer* join on its two parents, joining rows in either parent
+    /// which have no matching rows in the other parent against NULLs
+    ///
+    /// Converted to [`Join`] with [`JoinType::FullOuter`] when lowering to dataflow.
+    ///
+    /// [`Join`]: dataflow::ops::join::Join
+    /// [`JoinType::FullOuter`]: dataflow::ops::join::JoinType::FullOuter
+    FullJoin {
+        /// Columns to use as the join keys. Each tuple corresponds to a column in the left parent
+        /// and column in the right parent.
+        on: Vec<(Column, Column)>,
+        /// Columns (from both parents) to project in the output.
+        project: Vec<Column>,
     },
     /// Join where nodes in the right-hand side depend on columns in the left-hand side
     /// (referencing tables in `dependent_tables`). These are created during compilation for
@@ -244,6 +293,26 @@ pub enum MirNodeInner {
         /// in all parents.
         duplicate_mode: union::DuplicateMode,
     },
+    /// Node which computes the intersection or difference of the two sides of a compound select.
+    ///
+    /// Both sides are combined into the single parent of this node (typically a [`Union`]), with
+    /// the `side` column identifying which side each row came from. Emits every column of its
+    /// parent except for `side`.
+    ///
+    /// Converted to [`SetOperation`] when lowering to dataflow.
+    ///
+    /// [`Union`]: MirNodeInner::Union
+    /// [`SetOperation`]: dataflow::ops::set_operation::SetOperation
+    SetOperation {
+        /// Whether to compute the intersection or the difference of the two sides
+        kind: SetOperationKind,
+        /// If true, emit each row at most once (the default for `INTERSECT` and `EXCEPT`),
+        /// otherwise retain duplicates (`INTERSECT ALL` and `EXCEPT ALL`)
+        distinct: bool,
+        /// Column in the parent identifying which side of the operation each row came from - `0`
+        /// for the left side and `1` for the right side
+        side: Column,
+    },
     /// Node which orders its input rows within a group, then emits an extra page number column
     /// (which will always have a name given by [`PAGE_NUMBER_COL`]) for the page number of the
     /// rows within that group, with page size given by `limit`.
@@ -274,6 +343,25 @@ pub enum MirNodeInner {
         /// LIMIT clause
         limit: usize,
     },
+    /// Node which computes a window function over partitions of its input rows, and emits every
+    /// input row with the value of the window function for that row in an extra column (named by
+    /// `output_column`)
+    ///
+    /// Converted to [`Window`] when lowering to dataflow.
+    ///
+    /// [`Window`]: dataflow::ops::window::Window
+    Window {
+        /// The window function to compute
+        function: WindowFunction,
+        /// The column the window function aggregates over, if any
+        over: Option<Column>,
+        /// Set of columns used to partition the input rows
+        partition_by: Vec<Column>,
+        /// Set of columns used for ordering the rows within each partition
+        order: Option<Vec<(Column, OrderType)>>,
+        /// Name of the column containing the value of the window function
+        output_column: Column,
+    },
     /// Node which emits only distinct rows per some group.
     ///
     /// Converted to [`Aggregator`] with [`Aggregation::Count`] when lowering to dataflow.
@@ -311,6 +399,9 @@ pub enum MirNodeInner {
         default_row: Option<Vec<DfValue>>,
         /// Aggregates to perform in the reader on result sets for keys after performing the lookup
         aggregates: Option<PostLookupAggregates<Column>>,
+        /// Filtering to perform in the reader on result sets for keys after performing the lookup,
+        /// for queries with parameters on the right-hand side of a LEFT JOIN
+        left_join_filter: Option<PostLookupLeftJoinFilter<Column>>,
     },
 }
 
@@ -327,6 +418,7 @@ impl MirNodeInner {
             returned_cols: None,
             default_row: None,
             aggregates: None,
+            left_join_filter: None,
         }
     }
 
@@ -352,6 +444,7 @@ impl MirNodeInner {
             }
             MirNodeInner::Join { project, .. }
             | MirNodeInner::LeftJoin { project, .. }
+            | MirNodeInner::FullJoin { project, .. }
             | MirNodeInner::DependentJoin { project, .. }
             | MirNodeInner::DependentLeftJoin { project, .. } => {
                 if !project.contains(&c) {
@@ -381,6 +474,10 @@ impl MirNodeInner {
                 group_by.push(c);
                 Ok(true)
             }
+            MirNodeInner::Window { partition_by, .. } => {
+                partition_by.push(c);
+                Ok(true)
+            }
             _ => Ok(false),
         }
     }
@@ -415,6 +512,22 @@ impl MirNodeInner {
                     Aggregation::Count { .. } => format!("|*|({})", on.name.as_str()),
                     Aggregation::Sum => format!("𝛴({})", on.name.as_str()),
                     Aggregation::Avg => format!("AVG({})", on.name.as_str()),
+                    Aggregation::ArrayAgg => format!("[{}]", on.name.as_str()),
+                    Aggregation::Stddev { sample } => format!(
+                        "STDDEV_{}({})",
+                        if sample { "SAMP" } else { "POP" },
+                        on.name.as_str()
+                    ),
+                    Aggregation::Variance { sample } => format!(
+                        "VAR_{}({})",
+                        if sample { "SAMP" } else { "POP" },
+                        on.name.as_str()
+                    ),
+                    Aggregation::BoolAnd => format!("BOOL_AND({})", on.name.as_str()),
+                    Aggregation::BoolOr => format!("BOOL_OR({})", on.name.as_str()),
+                    Aggregation::BitAnd => format!("BIT_AND({})", on.name.as_str()),
+                    Aggregation::BitOr => format!("BIT_OR({})", on.name.as_str()),
+                    Aggregation::BitXor => format!("BIT_XOR({})", on.name.as_str()),
                     Aggregation::GroupConcat { separator: ref s } => {
                         format!("||([{}], \"{}\")", on.name.as_str(), s.as_str())
                     }
@@ -466,6 +579,13 @@ impl MirNodeInner {
             MirNodeInner::Filter { ref conditions, .. } => {
                 format!("σ[{}]", conditions.display(nom_sql::Dialect::MySQL))
             }
+            MirNodeInner::TemporalFilter {
+                ref column,
+                comparison,
+                ref offset,
+            } => {
+                format!("σ[{} {} now() + {}]", column.name, comparison, offset)
+            }
             MirNodeInner::ViewKey { ref key } => {
                 format!("σ[{}]", key.iter().join(" AND "))
             }
@@ -502,7 +622,32 @@ impl MirNodeInner {
             MirNodeInner::LeftJoin {
                 ref on,
                 ref project,
-                ..
+                ref residual,
+                with_nulls,
+            } => {
+                let jc = on
+                    .iter()
+                    .map(|(l, r)| format!("{}:{}", l.name, r.name))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!(
+                    "{} [{} on {}{}]",
+                    if *with_nulls { "⟕*" } else { "⟕" },
+                    project
+                        .iter()
+                        .map(|c| c.name.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    jc,
+                    residual
+                        .iter()
+                        .map(|r| format!(" and {}", r.display(nom_sql::Dialect::MySQL)))
+                        .join("")
+                )
+            }
+            MirNodeInner::FullJoin {
+                ref on,
+                ref project,
             } => {
                 let jc = on
                     .iter()
@@ -510,7 +655,7 @@ impl MirNodeInner {
                     .collect::<Vec<_>>()
                     .join(", ");
                 format!(
-                    "⟕ [{} on {}]",
+                    "⟗ [{} on {}]",
                     project
                         .iter()
                         .map(|c| c.name.as_str())
@@ -568,6 +713,21 @@ impl MirNodeInner {
             } => {
                 format!("TopK [k: {}, {:?}]", limit, order)
             }
+            MirNodeInner::Window {
+                ref function,
+                ref over,
+                ref partition_by,
+                ref order,
+                ..
+            } => {
+                format!(
+                    "Window [{}({}), partition: [{}], {:?}]",
+                    function,
+                    over.as_ref().map(|c| c.name.as_str()).unwrap_or("*"),
+                    partition_by.iter().map(|c| &c.name).join(", "),
+                    order
+                )
+            }
             MirNodeInner::Union {
                 ref emit,
                 ref duplicate_mode,
@@ -585,6 +745,16 @@ impl MirNodeInner {
                     })
                     .join(&format!(" {} ", symbol))
             }
+            MirNodeInner::SetOperation {
+                kind,
+                distinct,
+                ref side,
+            } => format!(
+                "{}{} [side: {}]",
+                kind,
+                if *distinct { "" } else { " ALL" },
+                side.name
+            ),
             MirNodeInner::AliasTable { ref table } => {
                 format!("AliasTable [{}]", table.display_unquoted())
             }
@@ -10,16 +10,20 @@ use crate::node::{MirNode, MirNodeInner};
 use crate::query::MirQuery;
 use crate::Column;
 
-/// If the given query has a Leaf but doesn't have any keys, create a key for it by adding a new
-/// node to the query that projects out a constant literal value (a "bogokey", from "bogus key") and
-/// making that the key for the query.
+/// If the given query has a Leaf but doesn't have any keys (other than keys which are filtered
+/// post-lookup), create a key for it by adding a new node to the query that projects out a
+/// constant literal value (a "bogokey", from "bogus key") and making that the key for the query.
 ///
-/// This pass will also handle ensuring that any topk or paginate nodes in leaf position in such
-/// queries have `group_by` columns, by lifting the bogokey project node over those nodes and adding
-/// the bogokey to their `group_by`
+/// This pass will also handle ensuring that any topk, paginate, or window nodes in leaf position in
+/// such queries have `group_by` (or `partition_by`) columns, by lifting the bogokey project node
+/// over those nodes and adding the bogokey to their `group_by`
 pub(crate) fn add_bogokey_if_necessary(query: &mut MirQuery<'_>) -> ReadySetResult<()> {
     match &query.leaf_node().inner {
-        MirNodeInner::Leaf { keys, .. } if keys.is_empty() => {}
+        // Keys which are filtered post-lookup aren't part of the index, so don't count
+        MirNodeInner::Leaf { keys, .. }
+            if keys.iter().all(|(_, placeholder)| {
+                matches!(placeholder, ViewPlaceholder::LeftJoinFilter(_))
+            }) => {}
         _ => {
             // Either the query has a Leaf with keys (so no bogokey is necessary) or the query has
             // no Leaf at all (which is the case for eg VIEWs). Either way, we don't need to do
@@ -45,6 +49,10 @@ pub(crate) fn add_bogokey_if_necessary(query: &mut MirQuery<'_>) -> ReadySetResu
                     | MirNodeInner::Paginate { group_by, .. }
                     if group_by.is_empty()
                 )
+                || matches!(
+                    inner,
+                    MirNodeInner::Window { partition_by, .. } if partition_by.is_empty()
+                )
         })
     {
         node_to_insert_above = *parent;
@@ -83,10 +91,18 @@ pub(crate) fn add_bogokey_if_necessary(query: &mut MirQuery<'_>) -> ReadySetResu
         keys.push((Column::named("bogokey"), ViewPlaceholder::Generated))
     }
 
-    if let MirNodeInner::TopK { group_by, .. } =
-        &mut query.get_node_mut(node_to_insert_above).unwrap().inner
-    {
-        group_by.push(Column::named("bogokey"))
+    // Add the bogokey to the group_by of any topk or window nodes we lifted the bogokey project
+    // node over
+    let mut node = Some(node_to_insert_above);
+    while let Some(idx) = node {
+        match &mut query.get_node_mut(idx).unwrap().inner {
+            MirNodeInner::TopK { group_by, .. } => group_by.push(Column::named("bogokey")),
+            MirNodeInner::Window { partition_by, .. } => {
+                partition_by.push(Column::named("bogokey"))
+            }
+            _ => {}
+        }
+        node = query.descendants(idx)?.first().copied();
     }
 
     Ok(())
@@ -99,9 +99,11 @@ fn push_dependent_filter(
         }
         MirNodeInner::Project { .. }
         | MirNodeInner::Filter { .. }
+        | MirNodeInner::TemporalFilter { .. }
         | MirNodeInner::Join { .. }
         | MirNodeInner::LeftJoin { .. }
         | MirNodeInner::DependentJoin { .. }
+        | MirNodeInner::DependentLeftJoin { .. }
         | MirNodeInner::AliasTable { .. } => true,
         MirNodeInner::Aggregation { .. }
         | MirNodeInner::Extremum { .. }
@@ -281,6 +283,8 @@ pub(crate) fn eliminate_dependent_joins(query: &mut MirQuery<'_>) -> ReadySetRes
                 MirNodeInner::DependentLeftJoin { on, project } => MirNodeInner::LeftJoin {
                     on: on.clone(),
                     project: project.clone(),
+                    residual: None,
+                    with_nulls: false,
                 },
                 _ => unreachable!("Already checked is_dependent_join above"),
             };
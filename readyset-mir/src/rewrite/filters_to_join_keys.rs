@@ -71,6 +71,15 @@ pub(crate) fn convert_filters_to_join_keys(query: &mut MirQuery<'_>) -> ReadySet
                         continue 'filter;
                     }
                 }
+                MirNodeInner::Window { partition_by, .. } => {
+                    if !(partition_by.contains(&c1) && partition_by.contains(&c2)) {
+                        trace!(
+                            "Columns in filter not in partition_by of ancestor window node; \
+                             can't turn filter into join key"
+                        );
+                        continue 'filter;
+                    }
+                }
                 MirNodeInner::AliasTable { .. } => {
                     let alias_table_parent = *query
                         .ancestors(ancestor_idx)?
@@ -99,11 +108,11 @@ pub(crate) fn convert_filters_to_join_keys(query: &mut MirQuery<'_>) -> ReadySet
 
                     trace!(c1 = %c1, c2 = %c2, "Remapped columns through AliasTable ancestor");
                 }
-                MirNodeInner::LeftJoin { .. } => {
+                MirNodeInner::LeftJoin { .. } | MirNodeInner::FullJoin { .. } => {
                     // TODO: figure out what to do about left joins
                     continue 'filter;
                 }
-                MirNodeInner::Union { .. } => {
+                MirNodeInner::Union { .. } | MirNodeInner::SetOperation { .. } => {
                     // TODO: figure out what to do about unions
                     continue 'filter;
                 }
@@ -142,6 +151,7 @@ pub(crate) fn convert_filters_to_join_keys(query: &mut MirQuery<'_>) -> ReadySet
                 }
                 MirNodeInner::Base { .. }
                 | MirNodeInner::Filter { .. }
+                | MirNodeInner::TemporalFilter { .. }
                 | MirNodeInner::Identity
                 | MirNodeInner::JoinAggregates
                 | MirNodeInner::DependentJoin { .. }
@@ -19,8 +19,12 @@ fn commutes_with(conditions: &Expr, inner: &MirNodeInner) -> bool {
         | MirNodeInner::Extremum { group_by, .. } => conditions
             .referred_columns()
             .all(|col| group_by.iter().any(|c| c == col)),
+        MirNodeInner::Window { partition_by, .. } => conditions
+            .referred_columns()
+            .all(|col| partition_by.iter().any(|c| c == col)),
 
         MirNodeInner::Filter { .. }
+        | MirNodeInner::TemporalFilter { .. }
         | MirNodeInner::Identity
         | MirNodeInner::Join { .. }
         | MirNodeInner::JoinAggregates
@@ -32,7 +36,9 @@ fn commutes_with(conditions: &Expr, inner: &MirNodeInner) -> bool {
         | MirNodeInner::Leaf { .. } => true,
 
         MirNodeInner::Base { .. }
+        | MirNodeInner::SetOperation { .. }
         | MirNodeInner::LeftJoin { .. }
+        | MirNodeInner::FullJoin { .. }
         | MirNodeInner::DependentLeftJoin { .. } => false,
     }
 }
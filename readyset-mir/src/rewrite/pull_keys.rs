@@ -1,12 +1,150 @@
-use nom_sql::BinaryOperator;
+use dataflow::PostLookupLeftJoinFilter;
+use nom_sql::analysis::ReferredColumns;
+use nom_sql::{BinaryOperator, Expr, Literal};
 use readyset_client::ViewPlaceholder;
 use readyset_errors::{internal, invariant_eq, unsupported, ReadySetResult};
 use tracing::{instrument, trace};
+use vec1::Vec1;
 
 use crate::node::{MirNodeInner, ViewKeyColumn};
 use crate::query::MirQuery;
 use crate::NodeIndex;
 
+/// Handle a view key on the right-hand side of a LEFT JOIN, by turning the join into one which
+/// joins every row on the left against NULLs (see the `with_nulls` field of
+/// [`MirNodeInner::LeftJoin`]) and filtering on the key columns post-lookup instead of using them
+/// as part of the lookup key.
+///
+/// The view key node itself is replaced with a filter which removes rows in the right with NULL
+/// values for the key columns, since those can never match the key
+fn push_left_join_filter(
+    query: &mut MirQuery<'_>,
+    node_idx: NodeIndex,
+    join_idx: NodeIndex,
+    key: Vec1<ViewKeyColumn>,
+) -> ReadySetResult<()> {
+    if key
+        .iter()
+        .any(|ViewKeyColumn { op, .. }| *op != BinaryOperator::Equal)
+    {
+        unsupported!(
+            "Only equality comparisons against parameters on the right-hand side of LEFT JOIN \
+             are supported"
+        );
+    }
+
+    let mut left_columns = match &mut query.get_node_mut(join_idx).unwrap().inner {
+        MirNodeInner::LeftJoin {
+            on,
+            residual: None,
+            with_nulls: with_nulls @ false,
+            ..
+        } => {
+            *with_nulls = true;
+            on.iter().map(|(left, _)| left.clone()).collect::<Vec<_>>()
+        }
+        MirNodeInner::LeftJoin { .. } => unsupported!(
+            "Parameters on the right-hand side of LEFT JOIN not supported in combination with \
+             other parameters or non-equality join conditions"
+        ),
+        _ => internal!("push_left_join_filter must be called with a LeftJoin node"),
+    };
+
+    let conditions = key
+        .iter()
+        .map(|ViewKeyColumn { column, .. }| Expr::BinaryOp {
+            lhs: Box::new(Expr::Column(nom_sql::Column {
+                name: column.name.clone(),
+                table: column.table.clone(),
+            })),
+            op: BinaryOperator::IsNot,
+            rhs: Box::new(Expr::Literal(Literal::Null)),
+        })
+        .reduce(|acc, cond| Expr::BinaryOp {
+            lhs: Box::new(acc),
+            op: BinaryOperator::And,
+            rhs: Box::new(cond),
+        })
+        .unwrap();
+    query.get_node_mut(node_idx).unwrap().inner = MirNodeInner::Filter { conditions };
+
+    let mut right_columns = key
+        .iter()
+        .map(|ViewKeyColumn { column, .. }| column.clone())
+        .collect::<Vec<_>>();
+    let right_tables = right_columns
+        .iter()
+        .map(|c| c.table.clone())
+        .collect::<Vec<_>>();
+
+    // Walk down to the leaf, making sure nothing between the join and the leaf depends on which
+    // rows in the right have been joined against NULLs
+    let mut node_idx = join_idx;
+    loop {
+        let children = query.descendants(node_idx)?;
+        if children.len() != 1 {
+            unsupported!("Don't know how to push view key below multi-child node");
+        }
+        node_idx = *children.first().unwrap();
+
+        match &mut query.get_node_mut(node_idx).unwrap().inner {
+            MirNodeInner::AliasTable { table } => {
+                for column in left_columns.iter_mut().chain(right_columns.iter_mut()) {
+                    column.add_table_alias(table.clone());
+                }
+            }
+            MirNodeInner::Filter { conditions } => {
+                if conditions
+                    .referred_columns()
+                    .any(|c| right_tables.contains(&c.table))
+                {
+                    unsupported!(
+                        "Filters on the right-hand side of a LEFT JOIN with parameters not \
+                         supported"
+                    );
+                }
+            }
+            MirNodeInner::Project { .. }
+            | MirNodeInner::Identity
+            | MirNodeInner::Join { .. }
+            | MirNodeInner::LeftJoin {
+                with_nulls: false, ..
+            } => {}
+            MirNodeInner::Leaf {
+                keys,
+                left_join_filter,
+                ..
+            } => {
+                if left_join_filter.is_some() {
+                    unsupported!("Parameters on the right-hand side of multiple LEFT JOINs");
+                }
+                keys.extend(key.into_iter().zip(&right_columns).map(
+                    |(
+                        ViewKeyColumn {
+                            placeholder_idx, ..
+                        },
+                        column,
+                    )| {
+                        (
+                            column.clone(),
+                            ViewPlaceholder::LeftJoinFilter(placeholder_idx),
+                        )
+                    },
+                ));
+                *left_join_filter = Some(PostLookupLeftJoinFilter {
+                    left_columns,
+                    right_columns,
+                });
+                return Ok(());
+            }
+            _ => unsupported!(
+                "Parameters on the right-hand side of LEFT JOIN not supported in queries with \
+                 aggregates, DISTINCT, ORDER BY with LIMIT, or subqueries"
+            ),
+        }
+    }
+}
+
 fn push_view_key(query: &mut MirQuery<'_>, node_idx: NodeIndex) -> ReadySetResult<()> {
     let children = query.descendants(node_idx)?;
     if children.len() != 1 {
@@ -50,15 +188,48 @@ fn push_view_key(query: &mut MirQuery<'_>, node_idx: NodeIndex) -> ReadySetResul
             }
             query.swap_with_child(node_idx)?;
         }
+        MirNodeInner::Window { partition_by, .. } => {
+            // Partitioning by the key columns is only correct if every lookup is a single point
+            // lookup: range keys are rejected when building MIR, and window functions with
+            // parameterized IN (which looks up multiple keys at once) are rejected in the adapter
+            for ViewKeyColumn { column, op, .. } in &key {
+                invariant_eq!(
+                    *op,
+                    BinaryOperator::Equal,
+                    "TODO: support non-equal ops for window functions"
+                );
+                partition_by.push(column.clone());
+            }
+            query.swap_with_child(node_idx)?;
+        }
         MirNodeInner::JoinAggregates => todo!(),
-        // TODO: left joins are tricky if we're coming from the right side
-        MirNodeInner::LeftJoin { .. } => unsupported!(
-            "Parameters in subqueries on the right-hand side of LEFT JOIN not supported"
-        ),
+        MirNodeInner::LeftJoin { .. } => {
+            if query.ancestors(child_idx)?.first() == Some(&node_idx) {
+                trace!(
+                    "Pushing `{}` below `{}`",
+                    node_idx.index(),
+                    child_idx.index()
+                );
+                query.swap_with_child(node_idx)?;
+            } else {
+                trace!(
+                    "Filtering on `{}` post-lookup, below `{}`",
+                    node_idx.index(),
+                    child_idx.index()
+                );
+                push_left_join_filter(query, node_idx, child_idx, key)?;
+            }
+        }
         // TODO: we might support this already? Will have to see
         MirNodeInner::Union { .. } => {
             unsupported!("Parameters on one side of a UNION not yet supported")
         }
+        MirNodeInner::SetOperation { .. } => {
+            unsupported!("Parameters on one side of an INTERSECT or EXCEPT not yet supported")
+        }
+        MirNodeInner::FullJoin { .. } => {
+            unsupported!("Parameters on one side of a FULL OUTER JOIN not supported")
+        }
         // Note that we don't need to add any projected columns; these will just be added by the
         // pull_columns pass
         MirNodeInner::Project { .. }
@@ -67,6 +238,7 @@ fn push_view_key(query: &mut MirQuery<'_>, node_idx: NodeIndex) -> ReadySetResul
         | MirNodeInner::DependentJoin { .. }
         | MirNodeInner::DependentLeftJoin { .. }
         | MirNodeInner::Filter { .. }
+        | MirNodeInner::TemporalFilter { .. }
         | MirNodeInner::Identity => {
             trace!(
                 "Pushing `{}` below `{}`",
@@ -187,4 +359,109 @@ mod tests {
             _ => panic!(),
         }
     }
+
+    #[test]
+    fn right_side_of_left_join() {
+        let mut graph = MirGraph::new();
+        let query_name = Relation::from("q");
+
+        let base = |graph: &mut MirGraph, table: &str, cols: &[&str]| {
+            let node = graph.add_node(MirNode::new(
+                table.into(),
+                MirNodeInner::Base {
+                    column_specs: cols
+                        .iter()
+                        .map(|col| ColumnSpecification {
+                            column: format!("{table}.{col}").as_str().into(),
+                            sql_type: SqlType::Int(None),
+                            generated: None,
+                            constraints: vec![],
+                            comment: None,
+                        })
+                        .collect(),
+                    primary_key: None,
+                    unique_keys: Default::default(),
+                },
+            ));
+            graph[node].add_owner(query_name.clone());
+            node
+        };
+        let t = base(&mut graph, "t", &["a", "x"]);
+        let u = base(&mut graph, "u", &["a", "y"]);
+
+        let vk = graph.add_node(MirNode::new(
+            "vk".into(),
+            MirNodeInner::ViewKey {
+                key: vec1![ViewKeyColumn {
+                    column: Column::new(Some("u"), "y"),
+                    op: BinaryOperator::Equal,
+                    placeholder_idx: 1
+                }],
+            },
+        ));
+        graph[vk].add_owner(query_name.clone());
+        graph.add_edge(u, vk, 0);
+
+        let join = graph.add_node(MirNode::new(
+            "join".into(),
+            MirNodeInner::LeftJoin {
+                on: vec![(Column::new(Some("t"), "a"), Column::new(Some("u"), "a"))],
+                project: vec![
+                    Column::new(Some("t"), "a"),
+                    Column::new(Some("t"), "x"),
+                    Column::new(Some("u"), "y"),
+                ],
+                residual: None,
+                with_nulls: false,
+            },
+        ));
+        graph[join].add_owner(query_name.clone());
+        graph.add_edge(t, join, 0);
+        graph.add_edge(vk, join, 1);
+
+        let leaf = graph.add_node(MirNode::new(
+            "leaf".into(),
+            MirNodeInner::leaf(vec![], IndexType::HashMap),
+        ));
+        graph[leaf].add_owner(query_name.clone());
+        graph.add_edge(join, leaf, 0);
+
+        let mut query = MirQuery::new(query_name, leaf, &mut graph);
+        pull_view_keys_to_leaf(&mut query).unwrap();
+
+        assert!(matches!(
+            graph.node_weight(vk).unwrap().inner,
+            MirNodeInner::Filter { .. }
+        ));
+        assert!(matches!(
+            graph.node_weight(join).unwrap().inner,
+            MirNodeInner::LeftJoin {
+                with_nulls: true,
+                ..
+            }
+        ));
+        match &graph.node_weight(leaf).unwrap().inner {
+            MirNodeInner::Leaf {
+                keys,
+                left_join_filter,
+                ..
+            } => {
+                assert_eq!(
+                    *keys,
+                    vec![(
+                        Column::new(Some("u"), "y"),
+                        ViewPlaceholder::LeftJoinFilter(1)
+                    )]
+                );
+                assert_eq!(
+                    *left_join_filter,
+                    Some(PostLookupLeftJoinFilter {
+                        left_columns: vec![Column::new(Some("t"), "a")],
+                        right_columns: vec![Column::new(Some("u"), "y")],
+                    })
+                );
+            }
+            _ => panic!(),
+        }
+    }
 }
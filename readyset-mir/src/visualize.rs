@@ -161,6 +161,18 @@ impl GraphViz for MirNodeInner {
                     AggregationKind::Count { .. } => format!("\\|*\\|({})", on),
                     AggregationKind::Sum => format!("𝛴({})", on),
                     AggregationKind::Avg => format!("AVG({})", on),
+                    AggregationKind::ArrayAgg => format!("[{}]", on),
+                    AggregationKind::Stddev { sample } => {
+                        format!("STDDEV_{}({})", if *sample { "SAMP" } else { "POP" }, on)
+                    }
+                    AggregationKind::Variance { sample } => {
+                        format!("VAR_{}({})", if *sample { "SAMP" } else { "POP" }, on)
+                    }
+                    AggregationKind::BoolAnd => format!("BOOL_AND({})", on),
+                    AggregationKind::BoolOr => format!("BOOL_OR({})", on),
+                    AggregationKind::BitAnd => format!("BIT_AND({})", on),
+                    AggregationKind::BitOr => format!("BIT_OR({})", on),
+                    AggregationKind::BitXor => format!("BIT_XOR({})", on),
                     AggregationKind::GroupConcat { separator: s } => {
                         format!("\\|\\|({}, \\\"{}\\\")", on, s)
                     }
@@ -207,6 +219,13 @@ impl GraphViz for MirNodeInner {
                 // FIXME(ENG-2502): Use correct dialect.
                 write!(f, "σ: {}", conditions.display(nom_sql::Dialect::MySQL))
             }
+            MirNodeInner::TemporalFilter {
+                ref column,
+                comparison,
+                ref offset,
+            } => {
+                write!(f, "σ: {} {} now() + {}", column, comparison, offset)
+            }
             MirNodeInner::ViewKey { ref key } => {
                 write!(f, "σ: {}", key.iter().join(" AND "))
             }
@@ -240,6 +259,9 @@ impl GraphViz for MirNodeInner {
                             offset_placeholder,
                             limit,
                         } => write!(f, " PAGE ${offset_placeholder} PER {limit}"),
+                        ViewPlaceholder::LeftJoinFilter(idx) => {
+                            write!(f, " = ${idx} (post-lookup)")
+                        }
                     }?
                 }
                 write!(f, "]")?;
@@ -278,6 +300,16 @@ impl GraphViz for MirNodeInner {
                                     PostLookupAggregateFunction::GroupConcat { .. } => "GC",
                                     PostLookupAggregateFunction::Max => "Max",
                                     PostLookupAggregateFunction::Min => "Min",
+                                    PostLookupAggregateFunction::Avg { .. } => "Avg",
+                                    PostLookupAggregateFunction::CountDistinct { .. } => {
+                                        "CountDistinct"
+                                    }
+                                    PostLookupAggregateFunction::SumDistinct { .. } => {
+                                        "SumDistinct"
+                                    }
+                                    PostLookupAggregateFunction::AvgDistinct { .. } => {
+                                        "AvgDistinct"
+                                    }
                                 },
                                 &aggregate.column
                             ))
@@ -288,9 +320,22 @@ impl GraphViz for MirNodeInner {
 
                 Ok(())
             }
-            MirNodeInner::LeftJoin { ref on, .. } => {
+            MirNodeInner::LeftJoin {
+                ref on,
+                ref residual,
+                with_nulls,
+                ..
+            } => {
+                let jc = on.iter().map(|(l, r)| format!("{}:{}", l, r)).join(", ");
+                write!(f, "{} | on: {}", if *with_nulls { "⟕*" } else { "⟕" }, jc)?;
+                if let Some(residual) = residual {
+                    write!(f, " | and: {}", residual.display(nom_sql::Dialect::MySQL))?;
+                }
+                Ok(())
+            }
+            MirNodeInner::FullJoin { ref on, .. } => {
                 let jc = on.iter().map(|(l, r)| format!("{}:{}", l, r)).join(", ");
-                write!(f, "⟕ | on: {}", jc)
+                write!(f, "⟗ | on: {}", jc)
             }
             MirNodeInner::DependentJoin { ref on, .. } => {
                 write!(
@@ -345,6 +390,31 @@ impl GraphViz for MirNodeInner {
                     .unwrap_or_else(|| "".into());
                 write!(f, "TopK [k: {}; {}]", limit, order)
             }
+            MirNodeInner::Window {
+                ref function,
+                ref over,
+                ref partition_by,
+                ref order,
+                ..
+            } => {
+                let order = order
+                    .as_ref()
+                    .map(|v| {
+                        v.iter()
+                            .map(|(c, o)| format!("{}: {}", c.name.as_str(), o))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    })
+                    .unwrap_or_else(|| "".into());
+                write!(
+                    f,
+                    "Window [{}({}); π: {}; {}]",
+                    function,
+                    over.as_ref().map(|c| c.name.as_str()).unwrap_or("*"),
+                    partition_by.iter().join(", "),
+                    order
+                )
+            }
             MirNodeInner::Union {
                 ref emit,
                 ref duplicate_mode,
@@ -360,6 +430,17 @@ impl GraphViz for MirNodeInner {
 
                 write!(f, "{}", cols)
             }
+            MirNodeInner::SetOperation {
+                kind,
+                distinct,
+                ref side,
+            } => write!(
+                f,
+                "{}{} [side: {}]",
+                kind,
+                if *distinct { "" } else { " ALL" },
+                side
+            ),
             MirNodeInner::AliasTable { ref table } => {
                 write!(f, "AliasTable [{}]", table.display_unquoted())
             }
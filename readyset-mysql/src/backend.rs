@@ -146,6 +146,8 @@ async fn write_column<W: AsyncWrite + Unpin>(
         },
         DfValue::Time(ref t) => rw.write_col(t),
         DfValue::ByteArray(ref bytes) => rw.write_col(BinaryDisplay(bytes.as_ref()).to_string()),
+        // MySQL has no interval column type, so intervals are sent as their string representation
+        DfValue::Interval(ref i) => rw.write_col(i.to_string()),
         // These types are PostgreSQL specific
         DfValue::Array(_) => {
             internal!("Cannot write MySQL column: MySQL does not support arrays")
@@ -479,6 +481,12 @@ where
             )),
             writer
         ),
+        Ok(QueryResult::Copy { .. }) => handle_error!(
+            Error::ReadySet(readyset_errors::unsupported_err!(
+                "MySQL does not support COPY"
+            )),
+            writer
+        ),
         Err(error) => handle_error!(error, writer),
     }
 }
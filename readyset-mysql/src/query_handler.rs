@@ -6,7 +6,7 @@ use lazy_static::lazy_static;
 use nom_sql::{Column, Expr, FieldDefinitionExpr, Literal, SqlIdentifier, SqlQuery, VariableScope};
 use readyset_adapter::backend::noria_connector::QueryResult;
 use readyset_adapter::backend::SelectSchema;
-use readyset_adapter::{QueryHandler, SetBehavior};
+use readyset_adapter::{QueryHandler, SetBehavior, READ_YOUR_WRITES_VARIABLE};
 use readyset_client::results::Results;
 use readyset_client::ColumnSchema;
 use readyset_data::{Collation, DfType, DfValue, TinyText};
@@ -915,6 +915,27 @@ impl QueryHandler for MySqlQueryHandler {
 
         match stmt {
             nom_sql::SetStatement::Variable(set) => {
+                if let Some(val) = set.variables.iter().find_map(|(var, val)| {
+                    if var.scope != VariableScope::User
+                        && var
+                            .name
+                            .as_str()
+                            .eq_ignore_ascii_case(READ_YOUR_WRITES_VARIABLE)
+                    {
+                        Some(val)
+                    } else {
+                        None
+                    }
+                }) {
+                    return SetReadYourWrites(match val {
+                        Expr::Literal(Literal::Integer(i)) => *i == 1,
+                        Expr::Literal(Literal::Boolean(b)) => *b,
+                        Expr::Literal(Literal::String(s)) => s.eq_ignore_ascii_case("on"),
+                        Expr::Column(c) => c.name.as_str().eq_ignore_ascii_case("on"),
+                        _ => false,
+                    });
+                }
+
                 if let Some(val) = set.variables.iter().find_map(|(var, val)| {
                     if var.name.as_str().eq_ignore_ascii_case("autocommit") {
                         Some(val)
@@ -1015,6 +1036,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn read_your_writes_state() {
+        let set = |value: Expr| {
+            SetStatement::Variable(SetVariables {
+                variables: vec![(
+                    Variable {
+                        scope: VariableScope::Session,
+                        name: "readyset_read_your_writes".into(),
+                    },
+                    value,
+                )],
+            })
+        };
+
+        assert_eq!(
+            MySqlQueryHandler::handle_set_statement(&set(Expr::Literal(Literal::Integer(1)))),
+            SetBehavior::SetReadYourWrites(true)
+        );
+        assert_eq!(
+            MySqlQueryHandler::handle_set_statement(&set(Expr::Literal(Literal::Integer(0)))),
+            SetBehavior::SetReadYourWrites(false)
+        );
+        assert_eq!(
+            MySqlQueryHandler::handle_set_statement(&set(Expr::Literal(Literal::from("ON")))),
+            SetBehavior::SetReadYourWrites(true)
+        );
+    }
+
     #[test]
     fn all_required_sql_modes_are_allowed() {
         for mode in REQUIRED_SQL_MODES {
@@ -85,6 +85,8 @@ pub(crate) fn convert_column(col: &ColumnSchema) -> ReadySetResult<mysql_srv::Co
         }
         DfType::VarBit(_) => unsupported!("MySQL does not support the bit varying type"),
         DfType::Array(_) => unsupported!("MySQL does not support arrays"),
+        // MySQL has no interval column type, so intervals are sent as strings
+        DfType::Interval => MYSQL_TYPE_VAR_STRING,
     };
 
     for c in col.base.iter().flat_map(|b| &b.constraints) {
@@ -10,7 +10,7 @@ use mysql_async::{
     ChangeUserOpts, Column, Conn, Opts, OptsBuilder, ResultSetStream, Row, SslOpts, TxOpts,
     UrlError,
 };
-use nom_sql::{SqlIdentifier, StartTransactionStatement};
+use nom_sql::{CopySource, SqlIdentifier, StartTransactionStatement};
 use pin_project::pin_project;
 use readyset_adapter::upstream_database::UpstreamDestination;
 use readyset_adapter::{UpstreamConfig, UpstreamDatabase, UpstreamPrepare};
@@ -28,6 +28,21 @@ type StatementID = u32;
 /// during connection phase if the version for the upstream server is too low.
 const MIN_UPSTREAM_VERSION: u16 = 8;
 
+/// Returns the last GTID of the server with the given UUID in a GTID set such as
+/// `3E11FA47-71CA-11E1-9E33-C80AA9429562:1-5:11-18`
+///
+/// A GTID set can hold the transactions of several servers (for example, after a failover), and
+/// only the current server's sequence numbers are comparable to the GTIDs of the writes we make.
+fn last_gtid(gtid_set: &str, server_uuid: &str) -> Option<String> {
+    let (server_id, intervals) = gtid_set
+        .split(',')
+        .filter_map(|server_set| server_set.trim().split_once(':'))
+        .find(|(server_id, _)| server_id.eq_ignore_ascii_case(server_uuid))?;
+    let last_interval = intervals.rsplit(':').next()?;
+    let sequence_number = last_interval.rsplit('-').next()?;
+    Some(format!("{server_id}:{sequence_number}"))
+}
+
 fn dt_to_value_params(dt: &[DfValue]) -> ReadySetResult<Vec<mysql_async::Value>> {
     dt.iter().map(|v| v.try_into()).collect()
 }
@@ -185,17 +200,13 @@ impl MySqlUpstream {
             user = %opts.user().unwrap_or("<NO USER>"),
         );
         span.in_scope(|| debug!("Establishing connection"));
-        let conn = if cfg!(feature = "ryw") {
-            Conn::new(
-                OptsBuilder::from_opts(opts).add_capability(CapabilityFlags::CLIENT_SESSION_TRACK),
-            )
-            .instrument(span.clone())
-            .await?
-        } else {
-            Conn::new(OptsBuilder::from_opts(opts))
-                .instrument(span.clone())
-                .await?
-        };
+        // Session tracking lets us learn the GTID of read-your-writes enabled writes when they
+        // commit
+        let conn = Conn::new(
+            OptsBuilder::from_opts(opts).add_capability(CapabilityFlags::CLIENT_SESSION_TRACK),
+        )
+        .instrument(span.clone())
+        .await?;
 
         // Check that the server version is supported.
         let (major, minor, _) = conn.server_version();
@@ -357,6 +368,14 @@ impl UpstreamDatabase for MySqlUpstream {
         unsupported!("MySQL does not have a simple_query protocol");
     }
 
+    async fn copy_out<'a>(
+        &'a mut self,
+        _query: &'a str,
+        _source: &CopySource,
+    ) -> Result<Self::QueryResult<'a>, Error> {
+        unsupported!("MySQL does not support COPY");
+    }
+
     /// Executes the given query on the mysql backend.
     async fn handle_ryw_write<'a, S>(
         &'a mut self,
@@ -390,6 +409,23 @@ impl UpstreamDatabase for MySqlUpstream {
         ))
     }
 
+    /// Returns the server's last transaction in its executed GTID set, in the same
+    /// `<server-id>:<sequence number>` form as the GTIDs of read-your-writes enabled writes
+    async fn replication_position(&mut self) -> Result<String, Error> {
+        let (gtid_executed, server_uuid): (String, String) = self
+            .conn
+            .query_first("SELECT @@GLOBAL.gtid_executed, @@GLOBAL.server_uuid")
+            .await?
+            .unwrap_or_default();
+        last_gtid(&gtid_executed, &server_uuid).ok_or_else(|| {
+            internal_err!(
+                "Could not determine the last executed GTID of {server_uuid} from \
+                 {gtid_executed:?}"
+            )
+            .into()
+        })
+    }
+
     async fn start_tx<'a>(
         &'a mut self,
         stmt: &StartTransactionStatement,
@@ -429,3 +465,41 @@ impl Drop for MySqlUpstream {
         metrics::decrement_gauge!(recorded::CLIENT_UPSTREAM_CONNECTIONS, 1.0);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn last_gtid_of_set() {
+        let uuid = "3E11FA47-71CA-11E1-9E33-C80AA9429562";
+        assert_eq!(
+            last_gtid("3E11FA47-71CA-11E1-9E33-C80AA9429562:1-5:11-18", uuid).as_deref(),
+            Some("3E11FA47-71CA-11E1-9E33-C80AA9429562:18")
+        );
+        assert_eq!(
+            last_gtid("3E11FA47-71CA-11E1-9E33-C80AA9429562:7", uuid).as_deref(),
+            Some("3E11FA47-71CA-11E1-9E33-C80AA9429562:7")
+        );
+        assert_eq!(last_gtid("", uuid), None);
+    }
+
+    #[test]
+    fn last_gtid_of_set_with_multiple_servers() {
+        let gtid_set = "1D9C3C3A-2E8B-11EE-B1C6-0242AC120002:1-2000,\n\
+                        3e11fa47-71ca-11e1-9e33-c80aa9429562:1-5:11-18,\n\
+                        F2A4B7C1-9D2E-11EE-8C90-0242AC120003:1-7";
+        assert_eq!(
+            last_gtid(gtid_set, "3E11FA47-71CA-11E1-9E33-C80AA9429562").as_deref(),
+            Some("3e11fa47-71ca-11e1-9e33-c80aa9429562:18")
+        );
+        assert_eq!(
+            last_gtid(gtid_set, "f2a4b7c1-9d2e-11ee-8c90-0242ac120003").as_deref(),
+            Some("F2A4B7C1-9D2E-11EE-8C90-0242AC120003:7")
+        );
+        assert_eq!(
+            last_gtid(gtid_set, "00000000-0000-0000-0000-000000000000"),
+            None
+        );
+    }
+}
@@ -731,6 +731,56 @@ async fn transaction_proxies() {
     shutdown_tx.shutdown().await;
 }
 
+#[tokio::test(flavor = "multi_thread")]
+#[serial]
+#[slow]
+async fn read_your_writes_prepared_and_in_transaction() {
+    let (opts, _handle, shutdown_tx) = setup().await;
+    let mut conn = mysql_async::Conn::new(opts).await.unwrap();
+
+    conn.query_drop("CREATE TABLE t (x int)").await.unwrap();
+    sleep().await;
+
+    conn.query_drop("CREATE CACHE FROM SELECT x FROM t WHERE x = ?")
+        .await
+        .unwrap();
+    conn.query_drop("SET readyset_read_your_writes = on")
+        .await
+        .unwrap();
+
+    // A prepared write is visible to the next read without waiting for replication
+    conn.exec_drop("INSERT INTO t (x) VALUES (?)", (1,))
+        .await
+        .unwrap();
+    let row: Option<(i32,)> = conn
+        .exec_first("SELECT x FROM t WHERE x = ?", (1,))
+        .await
+        .unwrap();
+    assert_eq!(row, Some((1,)));
+    assert_eq!(
+        last_query_info(&mut conn).await.destination,
+        QueryDestination::Readyset
+    );
+
+    // As is a write made inside of a transaction, once it commits
+    conn.query_drop("BEGIN").await.unwrap();
+    conn.query_drop("INSERT INTO t (x) VALUES (2)")
+        .await
+        .unwrap();
+    conn.query_drop("COMMIT").await.unwrap();
+    let row: Option<(i32,)> = conn
+        .exec_first("SELECT x FROM t WHERE x = ?", (2,))
+        .await
+        .unwrap();
+    assert_eq!(row, Some((2,)));
+    assert_eq!(
+        last_query_info(&mut conn).await.destination,
+        QueryDestination::Readyset
+    );
+
+    shutdown_tx.shutdown().await;
+}
+
 #[tokio::test(flavor = "multi_thread")]
 #[serial]
 #[slow]
@@ -749,7 +799,7 @@ async fn show_caches_index_hints() {
     // Check we have cached this query
     // in-request-path migrations is enabled, we should have a cached query
     let cached_queries = conn
-        .query::<(String, String, String, String), _>("SHOW CACHES;")
+        .query::<(String, String, String, String, Option<String>), _>("SHOW CACHES;")
         .await
         .unwrap();
     assert!(cached_queries.len() == 1);
@@ -781,7 +831,7 @@ async fn show_caches_index_hints() {
 
     // All variants of index hints should resolve to the same base query
     let cached_queries = conn
-        .query::<(String, String, String, String), _>("SHOW CACHES;")
+        .query::<(String, String, String, String, Option<String>), _>("SHOW CACHES;")
         .await
         .unwrap();
     assert!(cached_queries.len() == 1);
@@ -1022,9 +1072,9 @@ async fn show_proxied_queries_show_caches_query_text_matches() {
     conn.query_drop("SELECT id FROM t").await.unwrap();
     sleep().await;
 
-    let cached_queries: Vec<(String, String, String, String)> =
+    let cached_queries: Vec<(String, String, String, String, Option<String>)> =
         conn.query("SHOW CACHES").await.unwrap();
-    let (_, cache_name, cached_query_text, _) = cached_queries.first().unwrap();
+    let (_, cache_name, cached_query_text, _, _) = cached_queries.first().unwrap();
 
     conn.query_drop(&format!("DROP CACHE {}", cache_name))
         .await
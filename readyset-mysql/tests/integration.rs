@@ -1820,16 +1820,17 @@ async fn create_query_cache_where_in() {
         .unwrap();
     sleep().await;
 
-    let queries: Vec<(String, String, String, String)> = conn.query("SHOW CACHES;").await.unwrap();
+    let queries: Vec<(String, String, String, String, Option<String>)> =
+        conn.query("SHOW CACHES;").await.unwrap();
     assert!(queries
         .iter()
-        .any(|(_, query_name, _, always)| query_name == "test" && always == "fallback allowed"));
+        .any(|(_, query_name, _, always, _)| query_name == "test" && always == "fallback allowed"));
 
     conn.query_drop("CREATE CACHE test FROM SELECT id FROM t WHERE id IN (?, ?);")
         .await
         .unwrap();
     sleep().await;
-    let new_queries: Vec<(String, String, String, String)> =
+    let new_queries: Vec<(String, String, String, String, Option<String>)> =
         conn.query("SHOW CACHES;").await.unwrap();
     assert_eq!(new_queries.len(), queries.len());
 
@@ -1847,10 +1848,92 @@ async fn show_caches_with_always() {
         .await
         .unwrap();
     sleep().await;
-    let queries: Vec<(String, String, String, String)> = conn.query("SHOW CACHES;").await.unwrap();
-    assert!(queries
-        .iter()
-        .any(|(_, query_name, _, always)| query_name == "test_always" && always == "no fallback"));
+    let queries: Vec<(String, String, String, String, Option<String>)> =
+        conn.query("SHOW CACHES;").await.unwrap();
+    assert!(queries.iter().any(
+        |(_, query_name, _, always, _)| query_name == "test_always" && always == "no fallback"
+    ));
+
+    shutdown_tx.shutdown().await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn alter_cache_options() {
+    let (opts, _handle, shutdown_tx) = setup().await;
+    let mut conn = mysql_async::Conn::new(opts).await.unwrap();
+    conn.query_drop("CREATE TABLE t (id INT);").await.unwrap();
+    sleep().await;
+
+    conn.query_drop(
+        "CREATE CACHE test WITH (memory_limit = '1MB') FROM SELECT id FROM t WHERE id = ?;",
+    )
+    .await
+    .unwrap();
+    sleep().await;
+
+    conn.query_drop("ALTER CACHE test WITH (memory_limit = '2MB', eviction_priority = 'high');")
+        .await
+        .unwrap();
+    conn.query_drop("ALTER CACHE test WITH (max_staleness = '1s');")
+        .await
+        .unwrap_err();
+    conn.query_drop("ALTER CACHE nonexistent WITH (eviction_priority = 'low');")
+        .await
+        .unwrap_err();
+
+    shutdown_tx.shutdown().await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn alter_cache_rename_and_always() {
+    let (opts, _handle, shutdown_tx) = setup().await;
+    let mut conn = mysql_async::Conn::new(opts).await.unwrap();
+    conn.query_drop("CREATE TABLE t (id INT);").await.unwrap();
+    sleep().await;
+
+    conn.query_drop("CREATE CACHE test FROM SELECT id FROM t WHERE id = ?;")
+        .await
+        .unwrap();
+    sleep().await;
+
+    conn.query_drop("ALTER CACHE test RENAME TO renamed;")
+        .await
+        .unwrap();
+    conn.query_drop("ALTER CACHE renamed SET ALWAYS;")
+        .await
+        .unwrap();
+
+    let queries: Vec<(String, String, String, String, Option<String>)> =
+        conn.query("SHOW CACHES;").await.unwrap();
+    assert_eq!(queries.len(), 1);
+    let (_, query_name, _, always, _) = &queries[0];
+    assert_eq!(query_name, "renamed");
+    assert_eq!(always, "no fallback");
+
+    conn.query_drop("ALTER CACHE renamed SET NOT ALWAYS;")
+        .await
+        .unwrap();
+    let queries: Vec<(String, String, String, String, Option<String>)> =
+        conn.query("SHOW CACHES;").await.unwrap();
+    assert_eq!(queries[0].3, "fallback allowed");
+
+    conn.query_drop("ALTER CACHE nonexistent RENAME TO other;")
+        .await
+        .unwrap_err();
+
+    // The old name no longer refers to the cache, but can't be reused while the cache exists
+    let _ = conn.query_drop("DROP CACHE test;").await;
+    let queries: Vec<(String, String, String, String, Option<String>)> =
+        conn.query("SHOW CACHES;").await.unwrap();
+    assert_eq!(queries.len(), 1);
+    conn.query_drop("CREATE CACHE test FROM SELECT id FROM t WHERE id > ?;")
+        .await
+        .unwrap_err();
+
+    conn.query_drop("DROP CACHE renamed;").await.unwrap();
+    let queries: Vec<(String, String, String, String, Option<String>)> =
+        conn.query("SHOW CACHES;").await.unwrap();
+    assert!(queries.is_empty());
 
     shutdown_tx.shutdown().await;
 }
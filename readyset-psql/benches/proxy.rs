@@ -15,6 +15,7 @@
 use std::collections::HashMap;
 use std::net::SocketAddr;
 use std::pin::Pin;
+use std::sync::Arc;
 use std::task::{Context, Poll};
 use std::{io, vec};
 
@@ -25,7 +26,7 @@ use futures::{ready, Stream, StreamExt, TryStreamExt};
 use postgres_types::{Oid, Type};
 use psql_srv::{
     Credentials, CredentialsNeeded, PrepareResponse, PsqlBackend, PsqlSrvRow, QueryResponse,
-    TransferFormat,
+    TransferFormat, UpstreamCanceler,
 };
 use readyset_adapter_types::DeallocateId;
 use readyset_data::DfValue;
@@ -263,6 +264,9 @@ impl PsqlBackend for Backend {
     async fn load_extended_types(&mut self) -> Result<HashMap<Oid, i16>, psql_srv::Error> {
         Ok(HashMap::new())
     }
+    fn upstream_canceler(&self) -> Option<Arc<dyn UpstreamCanceler>> {
+        None
+    }
 }
 
 async fn psql_srv_proxy<A>(listen: A, streaming: bool) -> io::Result<JoinHandle<()>>
@@ -182,6 +182,11 @@ impl ps::PsqlBackend for Backend {
         self.inner.in_transaction()
     }
 
+    fn upstream_canceler(&self) -> Option<Arc<dyn ps::UpstreamCanceler>> {
+        let upstream = self.inner.upstream()?.connected()?;
+        Some(upstream.canceler())
+    }
+
     /// Loads any extended types from the upstream postgres, returning a map of Oid to typelen
     async fn load_extended_types(&mut self) -> Result<HashMap<Oid, i16>, ps::Error> {
         let err = |m| {
@@ -244,6 +249,7 @@ impl TryFrom<ParamRef<'_>> for DfValue {
             PsqlValue::TimestampTz(v) => Ok(DfValue::from(*v)),
             PsqlValue::Date(v) => Ok((*v).into()),
             PsqlValue::Time(v) => Ok((*v).into()),
+            PsqlValue::Interval(v) => Ok(DfValue::from(*v)),
             PsqlValue::ByteArray(b) => Ok(DfValue::ByteArray(Arc::new(b.clone()))),
             PsqlValue::MacAddress(m) => Ok(DfValue::from(m.to_string(MacAddressFormat::HexString))),
             PsqlValue::Inet(ip) => Ok(DfValue::from(ip.to_string())),
@@ -8,7 +8,7 @@ use nom_sql::{
 };
 use readyset_adapter::backend::noria_connector::QueryResult;
 use readyset_adapter::backend::{noria_connector, SelectSchema};
-use readyset_adapter::{QueryHandler, SetBehavior};
+use readyset_adapter::{QueryHandler, SetBehavior, READ_YOUR_WRITES_VARIABLE};
 use readyset_errors::ReadySetResult;
 
 enum AllowedParameterValue {
@@ -376,6 +376,16 @@ impl QueryHandler for PostgreSqlQueryHandler {
                     ]
                     .contains(val),
                 }),
+                READ_YOUR_WRITES_VARIABLE => SetBehavior::SetReadYourWrites(match value {
+                    SetPostgresParameterValue::Default => false,
+                    SetPostgresParameterValue::Value(val) => [
+                        PostgresParameterValue::literal(1),
+                        PostgresParameterValue::literal(true),
+                        PostgresParameterValue::literal("on"),
+                        PostgresParameterValue::identifier("on"),
+                    ]
+                    .contains(val),
+                }),
                 "search_path" => {
                     let value_to_string = |value: &PostgresParameterValueInner| match value {
                         PostgresParameterValueInner::Identifier(id) => id.clone(),
@@ -470,6 +480,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn read_your_writes_state() {
+        assert_eq!(
+            PostgreSqlQueryHandler::handle_set_statement(&parse_set_statement(
+                "SET readyset_read_your_writes = on"
+            )),
+            SetBehavior::SetReadYourWrites(true),
+        );
+
+        assert_eq!(
+            PostgreSqlQueryHandler::handle_set_statement(&parse_set_statement(
+                "SET readyset_read_your_writes = off"
+            )),
+            SetBehavior::SetReadYourWrites(false),
+        );
+
+        assert_eq!(
+            PostgreSqlQueryHandler::handle_set_statement(&parse_set_statement(
+                "SET readyset_read_your_writes TO DEFAULT"
+            )),
+            SetBehavior::SetReadYourWrites(false),
+        );
+    }
+
     mod search_path {
         use super::*;
 
@@ -1,7 +1,7 @@
 use std::borrow::Cow;
 use std::convert::{TryFrom, TryInto};
 
-use nom_sql::SqlIdentifier;
+use nom_sql::{CopyFormat, SqlIdentifier};
 use psql_srv as ps;
 use readyset_adapter::backend::{
     self as cl, noria_connector, SinglePrepareResult, UpstreamPrepare,
@@ -233,6 +233,31 @@ impl<'a> TryFrom<QueryResponse<'a>> for ps::QueryResponse<Resultset> {
             UpstreamBufferedInMemory(..) => Err(ps::Error::InternalError(
                 "Mismatched QueryResult for UpstreamBufferedInMemory response type: Expected SimpleQuery".to_string(),
             )),
+            Copy {
+                format,
+                header,
+                result: NoriaResult::Select { rows, schema },
+            } => {
+                let select_schema = SelectSchema(schema);
+                let resultset = Resultset::from_readyset(rows, &select_schema)?;
+                Ok(CopyOut {
+                    format,
+                    header,
+                    schema: select_schema.try_into()?,
+                    resultset,
+                })
+            }
+            Copy { .. } => Err(ps::Error::InternalError(
+                "Mismatched QueryResult for Copy response type: Expected Select".to_string(),
+            )),
+            // The upstream writes the header line itself, if one was requested, and the format
+            // only affects how rows from ReadySet are written
+            Upstream(upstream::QueryResult::CopyOut { schema, stream }) => Ok(CopyOut {
+                format: CopyFormat::Text,
+                header: false,
+                schema,
+                resultset: Resultset::from_copy_out_stream(stream),
+            }),
             Parser(p) => match p {
                 ParsedCommand::Deallocate(name) => Ok(ps::QueryResponse::Deallocate(name)),
             },
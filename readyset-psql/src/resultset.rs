@@ -7,7 +7,9 @@ use ps::{PsqlSrvRow, PsqlValue};
 use psql_srv as ps;
 use readyset_client::results::ResultIterator;
 use tokio_postgres::types::Type;
-use tokio_postgres::{GenericResult, ResultStream, SimpleQueryMessage, SimpleQueryStream};
+use tokio_postgres::{
+    CopyOutStream, GenericResult, ResultStream, SimpleQueryMessage, SimpleQueryStream,
+};
 
 use crate::schema::{type_to_pgsql, SelectSchema};
 use crate::value::TypedDfValue;
@@ -23,6 +25,7 @@ enum ResultsetInner {
         first_message: Option<SimpleQueryMessage>,
         stream: Pin<Box<SimpleQueryStream>>,
     },
+    CopyOut(Pin<Box<CopyOutStream>>),
 }
 
 /// A structure that contains a `ResultIterator` and facilitates iteration over these results as
@@ -89,6 +92,13 @@ impl Resultset {
             project_field_types: Arc::new(vec![]),
         }
     }
+
+    pub fn from_copy_out_stream(stream: Pin<Box<CopyOutStream>>) -> Self {
+        Self {
+            results: ResultsetInner::CopyOut(stream),
+            project_field_types: Arc::new(vec![]),
+        }
+    }
 }
 
 impl Stream for Resultset {
@@ -135,6 +145,8 @@ impl Stream for Resultset {
                 };
                 row.map(|res| res.map(PsqlSrvRow::SimpleQueryMessage))
             }
+            ResultsetInner::CopyOut(stream) => ready!(stream.as_mut().poll_next(cx))
+                .map(|res| res.map(PsqlSrvRow::CopyData).map_err(psql_srv::Error::from)),
         };
 
         Poll::Ready(next)
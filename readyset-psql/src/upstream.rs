@@ -4,12 +4,14 @@ use std::str::FromStr;
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use futures::StreamExt;
-use nom_sql::{SqlIdentifier, StartTransactionStatement};
+use futures::future::BoxFuture;
+use futures::{FutureExt, StreamExt};
+use itertools::Itertools;
+use nom_sql::{CopySource, Dialect, DialectDisplay, SqlIdentifier, StartTransactionStatement};
 use pgsql::types::Type;
 use pgsql::{GenericResult, ResultStream, Row, SimpleQueryMessage};
 use postgres_types::Kind;
-use psql_srv::{Column, TransferFormat};
+use psql_srv::{Column, TransferFormat, UpstreamCanceler};
 use readyset_adapter::upstream_database::UpstreamDestination;
 use readyset_adapter::{UpstreamConfig, UpstreamDatabase, UpstreamPrepare};
 use readyset_adapter_types::DeallocateId;
@@ -17,7 +19,7 @@ use readyset_client_metrics::recorded;
 use readyset_data::DfValue;
 use readyset_errors::{internal_err, invariant_eq, unsupported, ReadySetError, ReadySetResult};
 use tokio_postgres as pgsql;
-use tokio_postgres::SimpleQueryStream;
+use tokio_postgres::{CopyOutStream, SimpleQueryStream};
 use tracing::{debug, info_span};
 use tracing_futures::Instrument;
 
@@ -39,6 +41,8 @@ pub struct PostgreSqlUpstream {
     statement_id_counter: u32,
     /// The user used to connect to the upstream, if any
     user: Option<String>,
+    /// Handle used to cancel queries running on this connection
+    canceler: Arc<PostgreSqlCanceler>,
 
     /// ReadySet-wrapped Postgresql version string, to return to clients
     version: String,
@@ -63,6 +67,12 @@ pub enum QueryResult {
         first_message: SimpleQueryMessage,
         stream: Pin<Box<SimpleQueryStream>>,
     },
+    CopyOut {
+        /// The columns being copied, which the client is told the number of before the copied
+        /// data is sent
+        schema: Vec<Column>,
+        stream: Pin<Box<CopyOutStream>>,
+    },
 }
 
 impl Debug for QueryResult {
@@ -91,12 +101,39 @@ impl Debug for QueryResult {
                 .field("first_message", first_message)
                 .field("stream", &"...")
                 .finish(),
+            Self::CopyOut { schema, stream: _ } => f
+                .debug_struct("CopyOut")
+                .field("schema", schema)
+                .field("stream", &"...")
+                .finish(),
         }
     }
 }
 
 impl UpstreamDestination for QueryResult {}
 
+/// Forwards cancellation requests from clients to the query running on a connection to the upstream
+/// PostgreSQL database, using that connection's cancel token
+pub struct PostgreSqlCanceler {
+    cancel_token: pgsql::CancelToken,
+    tls: postgres_native_tls::MakeTlsConnector,
+}
+
+impl UpstreamCanceler for PostgreSqlCanceler {
+    fn cancel(&self) -> BoxFuture<'static, Result<(), psql_srv::Error>> {
+        let cancel_token = self.cancel_token.clone();
+        let tls = self.tls.clone();
+        async move { Ok(cancel_token.cancel_query(tls).await?) }.boxed()
+    }
+}
+
+impl PostgreSqlUpstream {
+    /// Returns a handle that can be used to cancel the query currently running on this connection
+    pub fn canceler(&self) -> Arc<PostgreSqlCanceler> {
+        self.canceler.clone()
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct StatementMeta {
     /// The types of the query parameters used for this statement
@@ -198,7 +235,10 @@ impl UpstreamDatabase for PostgreSqlUpstream {
             port = ?pg_config.get_ports()
         );
         span.in_scope(|| debug!("Establishing connection"));
-        let (client, connection) = pg_config.connect(tls).instrument(span.clone()).await?;
+        let (client, connection) = pg_config
+            .connect(tls.clone())
+            .instrument(span.clone())
+            .await?;
         let version = connection.parameter("server_version").ok_or_else(|| {
             ReadySetError::Internal("Upstream database failed to send server version".to_string())
         })?;
@@ -220,12 +260,18 @@ impl UpstreamDatabase for PostgreSqlUpstream {
         span.in_scope(|| debug!("Established connection to upstream"));
         metrics::increment_gauge!(recorded::CLIENT_UPSTREAM_CONNECTIONS, 1.0);
 
+        let canceler = Arc::new(PostgreSqlCanceler {
+            cancel_token: client.cancel_token(),
+            tls,
+        });
+
         Ok(Self {
             client,
             _connection_handle,
             prepared_statements: Default::default(),
             statement_id_counter: 0,
             user,
+            canceler,
             version,
         })
     }
@@ -324,14 +370,73 @@ impl UpstreamDatabase for PostgreSqlUpstream {
         Ok(QueryResult::SimpleQuery(res))
     }
 
+    async fn copy_out<'a>(
+        &'a mut self,
+        query: &'a str,
+        source: &CopySource,
+    ) -> Result<Self::QueryResult<'a>, Error> {
+        // The client library doesn't expose the upstream's CopyOutResponse, so describe the copied
+        // columns by preparing a query that selects the same columns
+        let describe = match source {
+            CopySource::Table { table, columns } => format!(
+                "SELECT {} FROM {}",
+                if columns.is_empty() {
+                    "*".to_owned()
+                } else {
+                    columns
+                        .iter()
+                        .map(|c| Dialect::PostgreSQL.quote_identifier(c))
+                        .join(", ")
+                },
+                table.display(Dialect::PostgreSQL)
+            ),
+            CopySource::Query(query) => query.display(Dialect::PostgreSQL).to_string(),
+        };
+        let schema = self
+            .client
+            .prepare(&describe)
+            .await?
+            .columns()
+            .iter()
+            .map(|col| Column::Column {
+                name: col.name().into(),
+                col_type: col.type_().clone(),
+                table_oid: col.table_oid(),
+                attnum: col.column_id(),
+            })
+            .collect();
+
+        let stream = self.client.copy_out(query).await?;
+        Ok(QueryResult::CopyOut {
+            schema,
+            stream: Box::pin(stream),
+        })
+    }
+
+    /// Executes the given write query, returning the position of the end of the WAL once the write
+    /// has committed.
     async fn handle_ryw_write<'a, S>(
         &'a mut self,
-        _query: S,
+        query: S,
     ) -> Result<(Self::QueryResult<'a>, String), Error>
     where
         S: AsRef<str> + Send + Sync + 'a,
     {
-        unsupported!("Read-Your-Write not yet implemented for PostgreSQL")
+        let res = self.client.simple_query(query.as_ref()).await?;
+        let lsn: String = self
+            .client
+            .query_one("SELECT pg_current_wal_lsn()::text", &[])
+            .await?
+            .get(0);
+        Ok((QueryResult::SimpleQuery(res), lsn))
+    }
+
+    async fn replication_position(&mut self) -> Result<String, Error> {
+        Ok(self
+            .client
+            .query_one("SELECT pg_current_wal_lsn()::text", &[])
+            .await?
+            .get(0))
     }
 
     async fn execute<'a>(
@@ -82,6 +82,7 @@ impl<'a> TryFrom<TypedDfValue<'a>> for PsqlValue {
                 Ok(PsqlValue::Date(v.to_chrono().naive_local().date()))
             }
             (&Type::TIME, DfValue::Time(t)) => Ok(PsqlValue::Time((t).into())),
+            (&Type::INTERVAL, DfValue::Interval(i)) => Ok(PsqlValue::Interval(*i)),
             (&Type::BOOL, DfValue::UnsignedInt(v)) => Ok(PsqlValue::Bool(v != 0)),
             (&Type::BOOL, DfValue::Int(v)) => Ok(PsqlValue::Bool(v != 0)),
             (&Type::BYTEA, DfValue::ByteArray(b)) => Ok(PsqlValue::ByteArray(
@@ -3,10 +3,12 @@ use std::sync::Arc;
 
 use chrono::NaiveDate;
 use postgres_types::private::BytesMut;
-use readyset_adapter::backend::{MigrationMode, UnsupportedSetMode};
+use readyset_adapter::backend::{MigrationMode, QueryDestination, UnsupportedSetMode};
 use readyset_adapter::query_status_cache::MigrationStyle;
 use readyset_adapter::BackendBuilder;
-use readyset_client_test_helpers::psql_helpers::{upstream_config, PostgreSQLAdapter};
+use readyset_client_test_helpers::psql_helpers::{
+    last_query_info, upstream_config, PostgreSQLAdapter,
+};
 use readyset_client_test_helpers::{sleep, Adapter, TestBuilder};
 use readyset_data::DfValue;
 use readyset_server::Handle;
@@ -17,6 +19,7 @@ use test_utils::slow;
 
 mod common;
 use common::{connect, setup_standalone_with_authority};
+use futures::TryStreamExt;
 use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
 use tokio_postgres::{Client, CommandCompleteContents, SimpleQueryMessage};
 
@@ -2729,6 +2732,196 @@ async fn drop_all_proxied_queries() {
     shutdown_tx.shutdown().await;
 }
 
+#[tokio::test(flavor = "multi_thread")]
+#[serial]
+#[slow]
+async fn show_cache_recommendations() {
+    readyset_tracing::init_test_logging();
+    let (opts, _handle, shutdown_tx) = TestBuilder::default()
+        .recreate_database(false)
+        .fallback_url(PostgreSQLAdapter::upstream_url("noria"))
+        .migration_mode(MigrationMode::OutOfBand)
+        .migration_style(MigrationStyle::Explicit)
+        .build::<PostgreSQLAdapter>()
+        .await;
+    let conn = connect(opts).await;
+
+    conn.simple_query("DROP TABLE IF EXISTS t").await.unwrap();
+    conn.simple_query("CREATE TABLE t (x int, y int)")
+        .await
+        .unwrap();
+
+    // Wait for the DDL to propagate to ReadySet
+    eventually!(conn
+        .simple_query("CREATE CACHE FROM SELECT * FROM t")
+        .await
+        .is_ok());
+
+    async fn recommended_create_caches(conn: &Client) -> Vec<String> {
+        conn.simple_query("SHOW CACHE RECOMMENDATIONS WITH CREATE STATEMENTS")
+            .await
+            .unwrap()
+            .into_iter()
+            .filter_map(|m| match m {
+                SimpleQueryMessage::Row(r) => r.get(6).map(String::from),
+                _ => None,
+            })
+            .collect()
+    }
+
+    // Once the query has been proxied (and found to be supported), it should be recommended
+    eventually! {
+        conn.simple_query("SELECT * FROM t WHERE x = 1")
+            .await
+            .unwrap();
+        recommended_create_caches(&conn).await.len() == 1
+    }
+
+    // The estimated size counts every column of the table selected with a wildcard
+    let estimated_size = conn
+        .simple_query("SHOW CACHE RECOMMENDATIONS")
+        .await
+        .unwrap()
+        .into_iter()
+        .find_map(|m| match m {
+            SimpleQueryMessage::Row(r) => r.get(5).map(String::from),
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(
+        estimated_size,
+        (2 * std::mem::size_of::<DfValue>()).to_string()
+    );
+
+    // Running the recommended CREATE CACHE statement should cache the query, after which it's no
+    // longer recommended
+    let create_cache = recommended_create_caches(&conn).await.remove(0);
+    conn.simple_query(&create_cache).await.unwrap();
+    assert!(recommended_create_caches(&conn).await.is_empty());
+
+    shutdown_tx.shutdown().await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[serial]
+#[slow]
+async fn cache_with_max_staleness() {
+    readyset_tracing::init_test_logging();
+    let (opts, _handle, shutdown_tx) = TestBuilder::default()
+        .recreate_database(false)
+        .fallback_url(PostgreSQLAdapter::upstream_url("noria"))
+        .migration_mode(MigrationMode::OutOfBand)
+        .migration_style(MigrationStyle::Explicit)
+        .build::<PostgreSQLAdapter>()
+        .await;
+    let conn = connect(opts).await;
+
+    conn.simple_query("DROP TABLE IF EXISTS t").await.unwrap();
+    conn.simple_query("CREATE TABLE t (x int, y int)")
+        .await
+        .unwrap();
+    conn.simple_query("INSERT INTO t (x, y) VALUES (1, 1)")
+        .await
+        .unwrap();
+
+    eventually!(conn
+        .simple_query("CREATE CACHE WITH (max_staleness = '1h') FROM SELECT y FROM t WHERE x = 1")
+        .await
+        .is_ok());
+
+    // With no sample of the upstream's replication position to check the read against yet, the
+    // first read is proxied upstream
+    conn.simple_query("SELECT y FROM t WHERE x = 1")
+        .await
+        .unwrap();
+    assert_eq!(
+        last_query_info(&conn).await.destination,
+        QueryDestination::ReadysetThenUpstream
+    );
+
+    // Once the cache has caught up to the sampled position, reads are served from ReadySet. That
+    // holds even if the upstream only has writes ReadySet never replicates, such as writes to
+    // another database, since those still move the upstream's position forward.
+    let mut upstream_config = upstream_config();
+    upstream_config.dbname("postgres");
+    let other_db_conn = connect(upstream_config).await;
+    other_db_conn
+        .simple_query("CREATE TABLE IF NOT EXISTS staleness_unreplicated (x int)")
+        .await
+        .unwrap();
+    eventually! {
+        other_db_conn
+            .simple_query("INSERT INTO staleness_unreplicated (x) VALUES (1)")
+            .await
+            .unwrap();
+        conn.simple_query("SELECT y FROM t WHERE x = 1")
+            .await
+            .unwrap();
+        last_query_info(&conn).await.destination == QueryDestination::Readyset
+    }
+
+    shutdown_tx.shutdown().await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[serial]
+#[slow]
+async fn read_your_writes_with_unreplicated_writes() {
+    readyset_tracing::init_test_logging();
+    let (opts, _handle, shutdown_tx) = TestBuilder::default()
+        .recreate_database(false)
+        .fallback_url(PostgreSQLAdapter::upstream_url("noria"))
+        .migration_mode(MigrationMode::OutOfBand)
+        .migration_style(MigrationStyle::Explicit)
+        .build::<PostgreSQLAdapter>()
+        .await;
+    let conn = connect(opts).await;
+
+    // Writes to a table in another database move the upstream's WAL forward without ever being
+    // replicated
+    let mut upstream_config = upstream_config();
+    upstream_config.dbname("postgres");
+    let other_db_conn = connect(upstream_config).await;
+    other_db_conn
+        .simple_query("CREATE TABLE IF NOT EXISTS ryw_unreplicated (x int)")
+        .await
+        .unwrap();
+
+    conn.simple_query("DROP TABLE IF EXISTS t").await.unwrap();
+    conn.simple_query("CREATE TABLE t (x int)").await.unwrap();
+    eventually!(conn
+        .simple_query("CREATE CACHE FROM SELECT x FROM t WHERE x = $1")
+        .await
+        .is_ok());
+    conn.simple_query("SET readyset_read_your_writes = on")
+        .await
+        .unwrap();
+
+    // The read's ticket is sampled after the unreplicated write, but the read is still served from
+    // ReadySet once the replicator has caught up with the upstream's WAL
+    conn.simple_query("BEGIN").await.unwrap();
+    conn.simple_query("INSERT INTO t (x) VALUES (1)")
+        .await
+        .unwrap();
+    conn.simple_query("COMMIT").await.unwrap();
+    other_db_conn
+        .simple_query("INSERT INTO ryw_unreplicated (x) VALUES (1)")
+        .await
+        .unwrap();
+    let rows = conn
+        .query("SELECT x FROM t WHERE x = $1", &[&1i32])
+        .await
+        .unwrap();
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].get::<_, i32>(0), 1);
+    assert_eq!(
+        last_query_info(&conn).await.destination,
+        QueryDestination::Readyset
+    );
+
+    shutdown_tx.shutdown().await;
+}
+
 #[tokio::test(flavor = "multi_thread")]
 #[serial]
 #[slow]
@@ -2855,3 +3048,106 @@ async fn real_type() {
 
     shutdown_tx.shutdown().await;
 }
+
+/// Runs a `COPY ... TO STDOUT` statement and returns all the data it copied
+async fn copy_out(conn: &Client, query: &str) -> String {
+    let data = conn
+        .copy_out(query)
+        .await
+        .unwrap()
+        .try_fold(vec![], |mut data, chunk| async move {
+            data.extend_from_slice(&chunk);
+            Ok(data)
+        })
+        .await
+        .unwrap();
+    String::from_utf8(data).unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[serial]
+#[slow]
+async fn copy_cached_query_to_stdout() {
+    let (opts, _handle, shutdown_tx) = TestBuilder::default()
+        .fallback(true)
+        .migration_mode(MigrationMode::OutOfBand)
+        .migration_style(MigrationStyle::Explicit)
+        .build::<PostgreSQLAdapter>()
+        .await;
+    let conn = connect(opts).await;
+
+    conn.simple_query("DROP TABLE IF EXISTS copy_t")
+        .await
+        .unwrap();
+    conn.simple_query("CREATE TABLE copy_t (x int, y text)")
+        .await
+        .unwrap();
+    conn.simple_query("INSERT INTO copy_t (x, y) VALUES (1, 'a'), (2, NULL)")
+        .await
+        .unwrap();
+    eventually!(conn
+        .simple_query("CREATE CACHE FROM SELECT x, y FROM copy_t WHERE x = 1")
+        .await
+        .is_ok());
+
+    eventually! {
+        let data = copy_out(&conn, "COPY (SELECT x, y FROM copy_t WHERE x = 1) TO STDOUT").await;
+        data == "1\ta\n" && last_query_info(&conn).await.destination == QueryDestination::Readyset
+    }
+
+    let data = copy_out(
+        &conn,
+        "COPY (SELECT x, y FROM copy_t WHERE x = 1) TO STDOUT WITH (FORMAT csv, HEADER)",
+    )
+    .await;
+    assert_eq!(data, "x,y\n1,a\n");
+    assert_eq!(
+        last_query_info(&conn).await.destination,
+        QueryDestination::Readyset
+    );
+
+    shutdown_tx.shutdown().await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[serial]
+#[slow]
+async fn copy_proxied_query_to_stdout() {
+    let (opts, _handle, shutdown_tx) = TestBuilder::default()
+        .fallback(true)
+        .migration_mode(MigrationMode::OutOfBand)
+        .migration_style(MigrationStyle::Explicit)
+        .build::<PostgreSQLAdapter>()
+        .await;
+    let conn = connect(opts).await;
+
+    conn.simple_query("DROP TABLE IF EXISTS copy_t")
+        .await
+        .unwrap();
+    conn.simple_query("CREATE TABLE copy_t (x int, y text)")
+        .await
+        .unwrap();
+    conn.simple_query("INSERT INTO copy_t (x, y) VALUES (1, 'a'), (2, NULL)")
+        .await
+        .unwrap();
+
+    let data = copy_out(
+        &conn,
+        "COPY (SELECT x, y FROM copy_t WHERE x = 2) TO STDOUT WITH (FORMAT csv, HEADER)",
+    )
+    .await;
+    assert_eq!(data, "x,y\n2,\n");
+    assert_eq!(
+        last_query_info(&conn).await.destination,
+        QueryDestination::Upstream
+    );
+
+    let data = copy_out(&conn, "COPY copy_t (y) TO STDOUT").await;
+    assert_eq!(data, "a\n\\N\n");
+    assert_eq!(
+        last_query_info(&conn).await.destination,
+        QueryDestination::Upstream
+    );
+
+    shutdown_tx.shutdown().await;
+}
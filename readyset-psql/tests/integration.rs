@@ -1884,6 +1884,45 @@ async fn drop_caches_go_in_authority_list() {
     shutdown_tx.shutdown().await;
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn alter_caches_go_in_authority_list() {
+    readyset_tracing::init_test_logging();
+
+    let (builder, authority, _) =
+        setup_standalone_with_authority("alter_caches_go_in_authority_list", None);
+    let (config, _handle, shutdown_tx) = builder.build::<PostgreSQLAdapter>().await;
+
+    let queries = [
+        "CREATE TABLE t (x int);",
+        "CREATE CACHE q FROM SELECT x FROM t;",
+        "ALTER CACHE q RENAME TO r;",
+        "ALTER CACHE r SET ALWAYS;",
+    ];
+
+    let conn = connect(config).await;
+    for query in queries {
+        let _res = conn.simple_query(query).await.expect("query failed");
+        // give it some time to propagate
+        sleep().await;
+    }
+
+    let res = authority.cache_ddl_requests().await.unwrap();
+    let unparsed_stmts = res
+        .iter()
+        .skip(1)
+        .map(|req| req.unparsed_stmt.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        unparsed_stmts,
+        [
+            "ALTER CACHE \"q\" RENAME TO \"r\"",
+            "ALTER CACHE \"r\" SET ALWAYS"
+        ]
+    );
+
+    shutdown_tx.shutdown().await;
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn drop_all_caches_clears_authority_list() {
     readyset_tracing::init_test_logging();
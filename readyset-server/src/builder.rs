@@ -64,8 +64,14 @@ impl Builder {
                 opts.memory_limit,
                 Duration::from_secs(opts.memory_check_freq),
             );
+        } else if opts.memory_check_freq > 0 {
+            // Still check memory periodically, to enforce per-cache memory limits and expire
+            // caches with a ttl (the worker only checks if any cache has one of those)
+            builder.set_memory_check_frequency(Duration::from_secs(opts.memory_check_freq));
         }
         builder.set_eviction_kind(opts.eviction_kind);
+        builder.set_spill_partial_state(opts.spill_partial_state);
+        builder.set_time_zone(opts.upstream_time_zone);
 
         builder.set_sharding(match opts.shards {
             0 | 1 => None,
@@ -210,6 +216,13 @@ impl Builder {
         self.memory_check_frequency = Some(check_freq);
     }
 
+    /// Set how often we check memory usage against the memory limit of the process and of
+    /// individual caches, without setting a memory limit for the process.
+    pub fn set_memory_check_frequency(&mut self, check_freq: time::Duration) {
+        assert_ne!(check_freq, time::Duration::from_millis(0));
+        self.memory_check_frequency = Some(check_freq);
+    }
+
     /// Set the IP address that the worker should use for listening.
     pub fn set_listen_addr(&mut self, listen_addr: IpAddr) {
         self.listen_addr = listen_addr;
@@ -362,6 +375,18 @@ impl Builder {
         self.config.domain_config.eviction_kind = value;
     }
 
+    /// Sets the value of [`Config::domain_config::spill_partial_state`]. See documentation of
+    /// that field for more information.
+    pub fn set_spill_partial_state(&mut self, value: bool) {
+        self.config.domain_config.spill_partial_state = value;
+    }
+
+    /// Sets the value of [`Config::domain_config::time_zone`]. See documentation of that field for
+    /// more information.
+    pub fn set_time_zone(&mut self, value: Option<chrono_tz::Tz>) {
+        self.config.domain_config.time_zone = value;
+    }
+
     /// Assigns a telemetry reporter to this ReadySet server
     pub fn set_telemetry_sender(&mut self, value: TelemetrySender) {
         self.telemetry = value;
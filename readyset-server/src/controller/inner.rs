@@ -96,6 +96,10 @@ pub struct Leader {
     pub(super) background_task_failed: mpsc::Sender<ReadySetError>,
 
     pub(super) running_recovery: Option<watch::Receiver<ReadySetResult<()>>>,
+
+    /// Whether our state was restored from a checkpoint of the base tables, along with their
+    /// replication offsets
+    restored_from_checkpoint: bool,
 }
 
 impl Leader {
@@ -167,6 +171,7 @@ impl Leader {
         let replicator_restart_timeout = self.replicator_config.replicator_restart_timeout;
         let config = self.replicator_config.clone();
         let replicator_statement_logging = self.replicator_statement_logging;
+        let restored_from_checkpoint = self.restored_from_checkpoint;
 
         // The replication task ideally won't panic, but if it does and we arent replicating, that
         // will mean the data we return, will be more and more stale, and the transaction logs on
@@ -175,8 +180,10 @@ impl Leader {
         tokio::spawn(abort_on_panic(async move {
             let replication_future = async move {
                 // The replicator wants to know if we're restarting the server so that it can
-                // resnapshot to capture changes made to replication-tables.
-                let mut server_startup = true;
+                // resnapshot to capture changes made to replication-tables. If we were just
+                // restored from a checkpoint, though, the whole point is to resume replication
+                // from the restored offsets without snapshotting again.
+                let mut server_startup = !restored_from_checkpoint;
                 loop {
                     let noria: readyset_client::ReadySetHandle =
                         readyset_client::ReadySetHandle::new(Arc::clone(&authority)).await;
@@ -373,6 +380,13 @@ impl Leader {
                 let ds = self.dataflow_state_handle.read().await;
                 return_serialized!(ds.verbose_views())
             }
+            (&Method::POST, "/cache_memory_usage") => {
+                let res = {
+                    let ds = self.dataflow_state_handle.read().await;
+                    ds.cache_memory_usage().await
+                }?;
+                return_serialized!(res);
+            }
             (&Method::POST, "/view_names") => {
                 let (queries, dialect): (Vec<ViewCreateRequest>, _) = bincode::deserialize(&body)?;
                 gauge!(
@@ -463,6 +477,15 @@ impl Leader {
                 }?;
                 return_serialized!(res);
             }
+            (&Method::POST, "/checkpoint_base_tables") => {
+                require_leader_ready()?;
+                let dir = bincode::deserialize(&body)?;
+                let res = {
+                    let ds = self.dataflow_state_handle.read().await;
+                    ds.checkpoint_base_tables(dir).await
+                }?;
+                return_serialized!(res);
+            }
             (&Method::POST, "/snapshotting_tables") => {
                 let res = {
                     let ds = self.dataflow_state_handle.read().await;
@@ -622,6 +645,30 @@ impl Leader {
                 self.dataflow_state_handle.commit(writer, authority).await?;
                 return_serialized!(result);
             }
+            (&Method::POST, "/rename_cache") => {
+                require_leader_ready()?;
+                let (name, new_name) = bincode::deserialize(&body)?;
+                let mut writer = self.dataflow_state_handle.write().await;
+                writer.as_mut().rename_cache(&name, new_name)?;
+                self.dataflow_state_handle.commit(writer, authority).await?;
+                return_serialized!(ReadySetResult::Ok(()));
+            }
+            (&Method::POST, "/set_cache_always") => {
+                require_leader_ready()?;
+                let (name, always) = bincode::deserialize(&body)?;
+                let mut writer = self.dataflow_state_handle.write().await;
+                writer.as_mut().set_cache_always(&name, always)?;
+                self.dataflow_state_handle.commit(writer, authority).await?;
+                return_serialized!(ReadySetResult::Ok(()));
+            }
+            (&Method::POST, "/set_cache_options") => {
+                require_leader_ready()?;
+                let (name, options) = bincode::deserialize(&body)?;
+                let mut writer = self.dataflow_state_handle.write().await;
+                writer.as_mut().set_cache_options(&name, options).await?;
+                self.dataflow_state_handle.commit(writer, authority).await?;
+                return_serialized!(ReadySetResult::Ok(()));
+            }
             (&Method::POST, "/remove_all_queries") => {
                 require_leader_ready()?;
                 let mut writer = self.dataflow_state_handle.write().await;
@@ -953,6 +1000,7 @@ impl Leader {
         replicator_config: UpstreamConfig,
         worker_request_timeout: Duration,
         background_recovery_interval: Duration,
+        restored_from_checkpoint: bool,
     ) -> Self {
         assert_ne!(state.config.min_workers, 0);
 
@@ -973,6 +1021,7 @@ impl Leader {
             running_migrations: Default::default(),
             background_task_failed,
             running_recovery: None,
+            restored_from_checkpoint,
         }
     }
 }
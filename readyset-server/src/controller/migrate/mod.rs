@@ -505,6 +505,10 @@ impl DomainMigrationPlan {
         }
 
         debug!("successfully sent all domain messages for this migration!");
+
+        // Any readers we've just placed might be for caches with memory limits or eviction
+        // priorities, which the workers running them need to know about
+        mainline.send_cache_options_to_workers().await;
         Ok(())
     }
 
@@ -10,13 +10,19 @@ use std::collections::HashMap;
 use std::convert::TryInto;
 use std::iter;
 
+use chrono_tz::Tz;
 use common::DfValue;
 use dataflow::node::Column as DfColumn;
 use dataflow::ops::grouped::concat::GroupConcat;
 use dataflow::ops::join::{Join, JoinType};
 use dataflow::ops::project::Project;
+use dataflow::ops::set_operation::SetOperationKind;
+use dataflow::ops::temporal_filter::TemporalComparison;
+use dataflow::ops::window::WindowFunction;
 use dataflow::ops::Side;
-use dataflow::{node, ops, Expr as DfExpr, PostLookupAggregates, ReaderProcessing};
+use dataflow::{
+    node, ops, Expr as DfExpr, PostLookupAggregates, PostLookupLeftJoinFilter, ReaderProcessing,
+};
 use itertools::Itertools;
 use mir::graph::MirGraph;
 use mir::node::node_inner::MirNodeInner;
@@ -28,7 +34,7 @@ use petgraph::graph::NodeIndex;
 use petgraph::Direction;
 use readyset_client::internal::{Index, IndexType};
 use readyset_client::ViewPlaceholder;
-use readyset_data::{Collation, DfType, Dialect};
+use readyset_data::{Collation, DfType, Dialect, Interval};
 use readyset_errors::{
     internal, internal_err, invariant, invariant_eq, ReadySetError, ReadySetResult,
 };
@@ -149,6 +155,24 @@ pub(super) fn mir_node_to_flow_parts(
                         mig,
                     )?)
                 }
+                MirNodeInner::TemporalFilter {
+                    ref column,
+                    comparison,
+                    offset,
+                } => {
+                    invariant_eq!(ancestors.len(), 1);
+                    let parent = ancestors[0];
+                    Some(make_temporal_filter_node(
+                        graph,
+                        name,
+                        parent,
+                        &graph.columns(mir_node),
+                        column,
+                        comparison,
+                        offset,
+                        mig,
+                    )?)
+                }
                 MirNodeInner::Identity => {
                     invariant_eq!(ancestors.len(), 1);
                     let parent = ancestors[0];
@@ -176,6 +200,7 @@ pub(super) fn mir_node_to_flow_parts(
                         &graph.referenced_columns(mir_node),
                         on,
                         project,
+                        None,
                         JoinType::Inner,
                         custom_types,
                         mig,
@@ -216,6 +241,7 @@ pub(super) fn mir_node_to_flow_parts(
                     ref returned_cols,
                     ref default_row,
                     ref aggregates,
+                    ref left_join_filter,
                     ..
                 } => {
                     if !lowered_to_df {
@@ -229,6 +255,7 @@ pub(super) fn mir_node_to_flow_parts(
                             returned_cols,
                             default_row.clone(),
                             aggregates,
+                            left_join_filter,
                         )?;
                         materialize_leaf_node(
                             graph,
@@ -245,7 +272,33 @@ pub(super) fn mir_node_to_flow_parts(
                 MirNodeInner::LeftJoin {
                     ref on,
                     ref project,
-                    ..
+                    ref residual,
+                    with_nulls,
+                } => {
+                    invariant_eq!(ancestors.len(), 2);
+                    let left = ancestors[0];
+                    let right = ancestors[1];
+                    Some(make_join_node(
+                        graph,
+                        name,
+                        left,
+                        right,
+                        &graph.columns(mir_node),
+                        on,
+                        project,
+                        residual.clone(),
+                        if with_nulls {
+                            JoinType::LeftWithNulls
+                        } else {
+                            JoinType::Left
+                        },
+                        custom_types,
+                        mig,
+                    )?)
+                }
+                MirNodeInner::FullJoin {
+                    ref on,
+                    ref project,
                 } => {
                     invariant_eq!(ancestors.len(), 2);
                     let left = ancestors[0];
@@ -258,7 +311,8 @@ pub(super) fn mir_node_to_flow_parts(
                         &graph.columns(mir_node),
                         on,
                         project,
-                        JoinType::Left,
+                        None,
+                        JoinType::FullOuter,
                         custom_types,
                         mig,
                     )?)
@@ -293,6 +347,24 @@ pub(super) fn mir_node_to_flow_parts(
                         mig,
                     )?)
                 }
+                MirNodeInner::SetOperation {
+                    kind,
+                    distinct,
+                    ref side,
+                } => {
+                    invariant_eq!(ancestors.len(), 1);
+                    let parent = ancestors[0];
+                    Some(make_set_operation_node(
+                        graph,
+                        name,
+                        parent,
+                        &graph.columns(mir_node),
+                        kind,
+                        distinct,
+                        side,
+                        mig,
+                    )?)
+                }
                 MirNodeInner::Distinct { ref group_by } => {
                     invariant_eq!(ancestors.len(), 1);
                     let parent = ancestors[0];
@@ -330,6 +402,27 @@ pub(super) fn mir_node_to_flow_parts(
                         mig,
                     )?)
                 }
+                MirNodeInner::Window {
+                    function,
+                    ref over,
+                    ref partition_by,
+                    ref order,
+                    ..
+                } => {
+                    invariant_eq!(ancestors.len(), 1);
+                    let parent = ancestors[0];
+                    Some(make_window_node(
+                        graph,
+                        name,
+                        parent,
+                        &graph.columns(mir_node),
+                        function,
+                        over.as_ref(),
+                        partition_by,
+                        order,
+                        mig,
+                    )?)
+                }
                 MirNodeInner::AliasTable { .. } => None,
             };
 
@@ -478,6 +571,42 @@ fn make_union_node(
     Ok(DfNodeIndex::new(node))
 }
 
+#[allow(clippy::too_many_arguments)]
+fn make_set_operation_node(
+    graph: &MirGraph,
+    name: Relation,
+    parent: MirNodeIndex,
+    columns: &[Column],
+    kind: SetOperationKind,
+    distinct: bool,
+    side: &Column,
+    mig: &mut Migration<'_>,
+) -> ReadySetResult<DfNodeIndex> {
+    let parent_na = graph.resolve_dataflow_node(parent).ok_or_else(|| {
+        ReadySetError::MirNodeMustHaveDfNodeAssigned {
+            mir_node_index: parent.index(),
+        }
+    })?;
+    let side = graph.column_id_for_column(parent, side)?;
+    // The set operation emits every column of its parent except for the side column
+    let mut cols = mig.dataflow_state.ingredients[parent_na.address()]
+        .columns()
+        .to_vec();
+    cols.remove(side);
+    set_names(&column_names(columns), &mut cols)?;
+
+    let set_operation = ops::set_operation::SetOperation::new(
+        parent_na.address(),
+        kind,
+        distinct,
+        side,
+        cols.len(),
+    );
+    let node = mig.add_ingredient(name, cols, set_operation);
+
+    Ok(DfNodeIndex::new(node))
+}
+
 fn make_filter_node(
     graph: &MirGraph,
     name: Relation,
@@ -502,6 +631,7 @@ fn make_filter_node(
         &parent_cols,
         custom_types,
         mig.dialect,
+        mig.dataflow_state.domain_config.time_zone,
     )?;
 
     set_names(&column_names(columns), &mut parent_cols)?;
@@ -514,6 +644,44 @@ fn make_filter_node(
     Ok(DfNodeIndex::new(node))
 }
 
+#[allow(clippy::too_many_arguments)]
+fn make_temporal_filter_node(
+    graph: &MirGraph,
+    name: Relation,
+    parent: MirNodeIndex,
+    columns: &[Column],
+    column: &Column,
+    comparison: TemporalComparison,
+    offset: Interval,
+    mig: &mut Migration<'_>,
+) -> ReadySetResult<DfNodeIndex> {
+    let parent_na = graph.resolve_dataflow_node(parent).ok_or_else(|| {
+        ReadySetError::MirNodeMustHaveDfNodeAssigned {
+            mir_node_index: parent.index(),
+        }
+    })?;
+    let mut parent_cols = mig.dataflow_state.ingredients[parent_na.address()]
+        .columns()
+        .to_vec();
+    let column = graph.column_id_for_column(parent, column)?;
+
+    set_names(&column_names(columns), &mut parent_cols)?;
+
+    let time_zone = mig.dataflow_state.domain_config.time_zone;
+    let node = mig.add_ingredient(
+        name,
+        parent_cols,
+        ops::temporal_filter::TemporalFilter::new(
+            parent_na.address(),
+            column,
+            comparison,
+            offset,
+            time_zone,
+        ),
+    );
+    Ok(DfNodeIndex::new(node))
+}
+
 fn make_grouped_node(
     graph: &MirGraph,
     name: Relation,
@@ -639,6 +807,7 @@ fn make_join_node(
     columns: &[Column],
     on: &[(Column, Column)],
     proj_cols: &[Column],
+    residual: Option<Expr>,
     kind: JoinType,
     custom_types: &HashMap<Relation, DfType>,
     mig: &mut Migration<'_>,
@@ -740,7 +909,24 @@ fn make_join_node(
         ));
     }
 
-    let j = Join::new(left_na.address(), right_na.address(), kind, on_idxs, emit);
+    let mut j = Join::new(left_na.address(), right_na.address(), kind, on_idxs, emit);
+    if let Some(residual) = residual {
+        let left_cols = mig.dataflow_state.ingredients[left_na.address()].columns();
+        let right_cols = mig.dataflow_state.ingredients[right_na.address()].columns();
+        j = j.with_residual(DfExpr::lower(
+            residual,
+            mig.dialect,
+            &JoinLowerContext {
+                graph,
+                left,
+                right,
+                left_cols,
+                right_cols,
+                custom_types,
+                time_zone: mig.dataflow_state.domain_config.time_zone,
+            },
+        )?);
+    }
     let n = mig.add_ingredient(name, cols, j);
 
     Ok(DfNodeIndex::new(n))
@@ -847,6 +1033,7 @@ struct LowerContext<'a> {
     parent_node_idx: MirNodeIndex,
     parent_cols: &'a [DfColumn],
     custom_types: &'a HashMap<Relation, DfType>,
+    time_zone: Option<Tz>,
 }
 
 impl<'a> dataflow::LowerContext for LowerContext<'a> {
@@ -867,6 +1054,49 @@ impl<'a> dataflow::LowerContext for LowerContext<'a> {
     fn resolve_type(&self, ty: Relation) -> Option<DfType> {
         self.custom_types.get(&ty).cloned()
     }
+
+    fn time_zone(&self) -> Option<Tz> {
+        self.time_zone
+    }
+}
+
+/// Context for lowering the residual condition of a join, which is evaluated against the columns of
+/// the left parent followed by the columns of the right parent.
+#[derive(Clone)]
+struct JoinLowerContext<'a> {
+    graph: &'a MirGraph,
+    left: MirNodeIndex,
+    right: MirNodeIndex,
+    left_cols: &'a [DfColumn],
+    right_cols: &'a [DfColumn],
+    custom_types: &'a HashMap<Relation, DfType>,
+    time_zone: Option<Tz>,
+}
+
+impl<'a> dataflow::LowerContext for JoinLowerContext<'a> {
+    fn resolve_column(&self, col: nom_sql::Column) -> ReadySetResult<(usize, DfType)> {
+        let col = Column::new(col.table.clone(), &col.name);
+        let (index, parent_col) = match self.graph.column_id_for_column(self.left, &col) {
+            Ok(index) => (index, self.left_cols.get(index)),
+            Err(_) => {
+                let index = self.graph.column_id_for_column(self.right, &col)?;
+                (self.left_cols.len() + index, self.right_cols.get(index))
+            }
+        };
+        let ty = parent_col
+            .ok_or_else(|| internal_err!("Column index out of bounds for join parent"))?
+            .ty()
+            .clone();
+        Ok((index, ty))
+    }
+
+    fn resolve_type(&self, ty: Relation) -> Option<DfType> {
+        self.custom_types.get(&ty).cloned()
+    }
+
+    fn time_zone(&self) -> Option<Tz> {
+        self.time_zone
+    }
 }
 
 /// Lower the given nom_sql AST expression to a `DfExpr`, resolving columns by looking their
@@ -878,6 +1108,7 @@ fn lower_expression(
     parent_cols: &[DfColumn],
     custom_types: &HashMap<Relation, DfType>,
     dialect: Dialect,
+    time_zone: Option<Tz>,
 ) -> ReadySetResult<DfExpr> {
     DfExpr::lower(
         expr,
@@ -887,6 +1118,7 @@ fn lower_expression(
             parent_node_idx: parent,
             parent_cols,
             custom_types,
+            time_zone,
         },
     )
 }
@@ -936,6 +1168,7 @@ fn make_project_node(
                     parent_cols,
                     custom_types,
                     mig.dialect,
+                    mig.dataflow_state.domain_config.time_zone,
                 )?;
 
                 (alias.clone(), expr.ty().clone(), None, expr)
@@ -1112,6 +1345,87 @@ fn make_paginate_or_topk_node(
     Ok(DfNodeIndex::new(na))
 }
 
+#[allow(clippy::too_many_arguments)]
+fn make_window_node(
+    graph: &MirGraph,
+    name: Relation,
+    parent: MirNodeIndex,
+    columns: &[Column],
+    function: WindowFunction,
+    over: Option<&Column>,
+    partition_by: &[Column],
+    order: &Option<Vec<(Column, OrderType)>>,
+    mig: &mut Migration<'_>,
+) -> ReadySetResult<DfNodeIndex> {
+    let parent_na = graph.resolve_dataflow_node(parent).ok_or_else(|| {
+        ReadySetError::MirNodeMustHaveDfNodeAssigned {
+            mir_node_index: parent.index(),
+        }
+    })?;
+    let mut parent_cols = mig.dataflow_state.ingredients[parent_na.address()]
+        .columns()
+        .to_vec();
+
+    let over_col_indx = over
+        .map(|c| graph.column_id_for_column(parent, c))
+        .transpose()?;
+    let over_col_ty = over_col_indx
+        .map(|i| {
+            parent_cols
+                .get(i)
+                .map(|c| c.ty())
+                .ok_or_else(|| internal_err!("Invalid index"))
+        })
+        .transpose()?;
+    let out_ty = function.output_type(over_col_ty, &mig.dialect)?;
+
+    // set names using MIR columns to ensure aliases are used
+    let column_names = column_names(columns);
+    parent_cols.push(DfColumn::new(
+        column_names
+            .last()
+            .ok_or_else(|| internal_err!("Window has no projections"))?
+            .into(),
+        out_ty.clone(),
+        Some(name.clone()),
+    ));
+    set_names(&column_names, &mut parent_cols)?;
+
+    invariant!(
+        !partition_by.is_empty(),
+        "need bogokey for Window without partition columns"
+    );
+
+    let partition_by_indx = partition_by
+        .iter()
+        .map(|c| graph.column_id_for_column(parent, c))
+        .collect::<ReadySetResult<Vec<_>>>()?;
+
+    let order_indx = order
+        .iter()
+        .flatten()
+        .map(|(c, order_type)| {
+            graph
+                .column_id_for_column(parent, c)
+                .map(|id| (id, *order_type))
+        })
+        .collect::<ReadySetResult<Vec<_>>>()?;
+
+    let na = mig.add_ingredient(
+        name,
+        parent_cols,
+        ops::window::Window::new(
+            parent_na.address(),
+            function,
+            over_col_indx,
+            partition_by_indx,
+            order_indx,
+            out_ty,
+        ),
+    );
+    Ok(DfNodeIndex::new(na))
+}
+
 fn make_reader_processing(
     graph: &MirGraph,
     parent: &MirNodeIndex,
@@ -1120,6 +1434,7 @@ fn make_reader_processing(
     returned_cols: &Option<Vec<Column>>,
     default_row: Option<Vec<DfValue>>,
     aggregates: &Option<PostLookupAggregates<Column>>,
+    left_join_filter: &Option<PostLookupLeftJoinFilter<Column>>,
 ) -> ReadySetResult<ReaderProcessing> {
     let order_by = if let Some(order) = order_by.as_ref() {
         Some(
@@ -1151,7 +1466,19 @@ fn make_reader_processing(
         .map(|aggs| aggs.map_columns(|col| graph.column_id_for_column(*parent, &col)))
         .transpose()?;
 
-    ReaderProcessing::new(order_by, limit, returned_cols, default_row, aggregates)
+    let left_join_filter = left_join_filter
+        .clone()
+        .map(|filter| filter.map_columns(|col| graph.column_id_for_column(*parent, &col)))
+        .transpose()?;
+
+    ReaderProcessing::new(
+        order_by,
+        limit,
+        returned_cols,
+        default_row,
+        aggregates,
+        left_join_filter,
+    )
 }
 
 fn materialize_leaf_node(
@@ -1177,15 +1504,16 @@ fn materialize_leaf_node(
     // TODO(malte): consider the case when the projected columns need reordering
 
     if !key_cols.is_empty() {
-        let columns: Vec<_> = key_cols
+        let placeholder_map = key_cols
             .iter()
-            .map(|(c, _)| graph.column_id_for_column(parent, c))
+            .map(|(c, placeholder)| Ok((*placeholder, graph.column_id_for_column(parent, c)?)))
             .collect::<ReadySetResult<Vec<_>>>()?;
 
-        let placeholder_map = key_cols
+        // Columns filtered post-lookup aren't part of the index
+        let columns = placeholder_map
             .iter()
-            .zip(columns.iter())
-            .map(|((_, placeholder), col_index)| (*placeholder, *col_index))
+            .filter(|(placeholder, _)| !matches!(placeholder, ViewPlaceholder::LeftJoinFilter(_)))
+            .map(|(_, col_index)| *col_index)
             .collect::<Vec<_>>();
 
         mig.maintain(
@@ -12,7 +12,7 @@ use failpoint_macros::set_failpoint;
 use futures::future::Either;
 use hyper::http::{Method, StatusCode};
 use metrics::{counter, gauge, histogram};
-use nom_sql::Relation;
+use nom_sql::{AlterCacheDefinition, AlterCacheStatement, Relation, SqlQuery};
 use readyset_client::consensus::{
     Authority, AuthorityControl, AuthorityWorkerHeartbeatResponse, CacheDDLRequest,
     GetLeaderResult, WorkerDescriptor, WorkerId, WorkerSchedulingConfig,
@@ -41,7 +41,7 @@ use url::Url;
 use crate::controller::inner::Leader;
 use crate::controller::migrate::Migration;
 use crate::controller::sql::Recipe;
-use crate::controller::state::DfState;
+use crate::controller::state::{restored_checkpoint_state_path, DfState};
 use crate::materialization::Materializations;
 use crate::worker::{WorkerRequest, WorkerRequestKind, WorkerRequestType};
 use crate::{Config, VolumeId};
@@ -56,6 +56,8 @@ pub(crate) mod schema;
 pub(crate) mod sql;
 mod state;
 
+pub(crate) use self::state::CHECKPOINT_STATE_FILE;
+
 /// Time between leader state change checks without thread parking.
 const LEADER_STATE_CHECK_INTERVAL: Duration = Duration::from_secs(1);
 /// Amount of time to wait for watches on the authority.
@@ -272,6 +274,9 @@ pub(crate) struct LeaderElectionResults {
     /// If we were unable to deserialize the caches from the previous controller state, we need to
     /// remake the caches for it, which will be contained in this Vec.
     cache_ddl: Option<Vec<CacheDDLRequest>>,
+    /// Whether the controller state was restored from a checkpoint of the base tables, in which
+    /// case replication can resume from the restored replication offsets without snapshotting
+    restored_from_checkpoint: bool,
 }
 
 /// An update on the leader election and failure detection.
@@ -543,6 +548,7 @@ impl Controller {
             AuthorityUpdate::WonLeaderElection(LeaderElectionResults {
                 controller_state: state,
                 cache_ddl,
+                restored_from_checkpoint,
             }) => {
                 info!("won leader election, creating Leader");
                 gauge!(recorded::CONTROLLER_IS_LEADER, 1f64);
@@ -556,6 +562,7 @@ impl Controller {
                     self.config.replicator_config.clone(),
                     self.config.worker_request_timeout,
                     self.config.background_recovery_interval,
+                    restored_from_checkpoint,
                 );
                 self.leader_ready.store(false, Ordering::Release);
 
@@ -806,40 +813,73 @@ impl Controller {
             // practice they will likely all have the same schema search path.
             // We also have to separate out the changelists into CreateCache ones and Drop ones so
             // that the Drop ones can properly find the caches to drop.
-            let changelists = self
+            let steps = self
                 .separate_changelists_by_schema_search_path_and_change_type(caches.clone())
                 .await?;
 
-            for changelist in changelists {
+            for step in steps {
                 let mut guard = self.inner.write().await;
-                if let Some(ref mut inner) = *guard {
-                    let n_caches = changelist.changes.len();
-                    match retry_with_exponential_backoff(
-                        || async {
-                            let changelist = changelist.clone();
-                            let mut writer = inner.dataflow_state_handle.write().await;
-                            let ds = writer.as_mut();
-                            ds.extend_recipe(changelist.into(), false).await?;
-                            ReadySetResult::Ok(writer)
-                        },
-                        5,
-                        Duration::from_millis(250),
-                    )
-                    .await
-                    {
-                        Ok(writer) => {
-                            inner
-                                .dataflow_state_handle
-                                .commit(writer, &self.authority)
-                                .await?;
-                            info!("Successfully restored {n_caches} caches");
+                let Some(ref mut inner) = *guard else {
+                    return Err(ReadySetError::NotLeader);
+                };
+                match step {
+                    CacheDDLReplayStep::Changes(changelist) => {
+                        let n_caches = changelist.changes.len();
+                        match retry_with_exponential_backoff(
+                            || async {
+                                let changelist = changelist.clone();
+                                let mut writer = inner.dataflow_state_handle.write().await;
+                                let ds = writer.as_mut();
+                                ds.extend_recipe(changelist.into(), false).await?;
+                                ReadySetResult::Ok(writer)
+                            },
+                            5,
+                            Duration::from_millis(250),
+                        )
+                        .await
+                        {
+                            Ok(writer) => {
+                                inner
+                                    .dataflow_state_handle
+                                    .commit(writer, &self.authority)
+                                    .await?;
+                                info!("Successfully restored {n_caches} caches");
+                            }
+                            Err(error) => {
+                                error!(n_failed_caches=%n_caches, %error, "Failed to restore caches!");
+                            }
                         }
-                        Err(error) => {
-                            error!(n_failed_caches=%n_caches, %error, "Failed to restore caches!");
+                    }
+                    CacheDDLReplayStep::Alter(AlterCacheStatement { name, definition }) => {
+                        let mut writer = inner.dataflow_state_handle.write().await;
+                        let ds = writer.as_mut();
+                        let res = match definition {
+                            AlterCacheDefinition::RenameTo(new_name) => {
+                                ds.rename_cache(&name, new_name)
+                            }
+                            AlterCacheDefinition::SetAlways(always) => {
+                                ds.set_cache_always(&name, always)
+                            }
+                            AlterCacheDefinition::SetOptions(options) => {
+                                ds.set_cache_options(&name, options).await
+                            }
+                        };
+                        match res {
+                            Ok(()) => {
+                                inner
+                                    .dataflow_state_handle
+                                    .commit(writer, &self.authority)
+                                    .await?;
+                            }
+                            Err(error) => {
+                                error!(
+                                    name = %name.display_unquoted(),
+                                    %error,
+                                    "Failed to re-apply ALTER CACHE"
+                                );
+                            }
                         }
                     }
-                } else {
-                    return Err(ReadySetError::NotLeader);
                 }
             }
 
@@ -852,10 +892,13 @@ impl Controller {
     /// path and the type of change (CreateCache or Drop). Each ChangeList will contain either only
     /// CreateCache changes or only Drop changes. For Drop changes, the schema search path is not
     /// set.
+    ///
+    /// `ALTER CACHE` requests can't be expressed as a [`Change`], so they end the current
+    /// ChangeList and are returned as separate steps, to be applied in the order they were made.
     async fn separate_changelists_by_schema_search_path_and_change_type(
         &self,
         ddl_reqs: Vec<CacheDDLRequest>,
-    ) -> ReadySetResult<Vec<ChangeList>> {
+    ) -> ReadySetResult<Vec<CacheDDLReplayStep>> {
         let adapter_rewrite_params = if let Some(ref inner) = *self.inner.read().await {
             let ds = inner.dataflow_state_handle.read().await;
             ds.recipe.adapter_rewrite_params()
@@ -896,13 +939,27 @@ impl Controller {
             "All CreateCache statements should have the same dialect"
         );
 
-        let mut changelists = vec![];
+        let mut steps = vec![];
         let mut current_changes = vec![];
         let mut current_dialect = None;
         let mut current_schema_search_path = None;
         let mut last_was_drop = false;
 
         for ddl_req in ddl_reqs {
+            if let Ok(SqlQuery::AlterCache(alter_cache)) =
+                nom_sql::parse_query(ddl_req.dialect.into(), &ddl_req.unparsed_stmt)
+            {
+                if !current_changes.is_empty() {
+                    steps.push(CacheDDLReplayStep::Changes(ChangeList {
+                        changes: std::mem::take(&mut current_changes),
+                        schema_search_path: current_schema_search_path.take().unwrap_or_default(),
+                        dialect: current_dialect.expect("Dialect should be set for a batch"),
+                    }));
+                }
+                steps.push(CacheDDLReplayStep::Alter(alter_cache));
+                continue;
+            }
+
             match Change::from_cache_ddl_request(&ddl_req, adapter_rewrite_params) {
                 Ok(change) => {
                     let is_drop = matches!(change, Change::Drop { .. });
@@ -910,11 +967,11 @@ impl Controller {
                     // When the change type switches, push the current list and start a new one
                     if current_changes.is_empty() || last_was_drop != is_drop {
                         if !current_changes.is_empty() {
-                            changelists.push(ChangeList {
+                            steps.push(CacheDDLReplayStep::Changes(ChangeList {
                                 changes: current_changes,
                                 schema_search_path: current_schema_search_path.unwrap_or_default(),
                                 dialect: ddl_req.dialect,
-                            });
+                            }));
                             current_changes = Vec::new();
                         }
                         current_dialect = Some(ddl_req.dialect);
@@ -935,17 +992,25 @@ impl Controller {
         }
 
         if !current_changes.is_empty() {
-            changelists.push(ChangeList {
+            steps.push(CacheDDLReplayStep::Changes(ChangeList {
                 changes: current_changes,
                 schema_search_path: current_schema_search_path.unwrap_or_default(),
                 dialect: current_dialect.expect("Dialect should be set for the last batch"),
-            });
+            }));
         }
 
-        Ok(changelists)
+        Ok(steps)
     }
 }
 
+/// A step in re-creating caches from the cache DDL requests stored in the authority
+enum CacheDDLReplayStep {
+    /// Apply a batch of `CREATE CACHE` or `DROP CACHE` changes
+    Changes(ChangeList),
+    /// Re-apply an `ALTER CACHE` statement
+    Alter(AlterCacheStatement),
+}
+
 /// Manages this authority's leader election state and sends update
 /// along `event_tx` when the state changes.
 struct AuthorityLeaderElectionState {
@@ -1019,14 +1084,33 @@ impl AuthorityLeaderElectionState {
                 return Ok(());
             }
 
+            // If a checkpoint was restored into our storage directory, and there's no state in
+            // the authority yet, we start from the state the checkpoint was created with.
+            let restored_state = DfState::restored_from_checkpoint(&self.config.persistence)?;
+            let mut restored_from_checkpoint = false;
+
             // We are the new leader, attempt to update the leader state with our state.
             let update_res = self
                 .authority
                 .update_controller_state(
                     |state: Option<ControllerState>| -> Result<ControllerState, ()> {
+                        restored_from_checkpoint = false;
                         match state {
-                            None => {
-                                Ok(ControllerState::new(self.config.clone(), self.permissive_writes))
+                            None => match &restored_state {
+                                Some(dataflow_state) => {
+                                    restored_from_checkpoint = true;
+                                    let mut dataflow_state = dataflow_state.clone();
+                                    dataflow_state.domain_config = self.config.domain_config.clone();
+                                    dataflow_state.replication_strategy = self.config.replication_strategy;
+                                    Ok(ControllerState {
+                                        config: self.config.clone(),
+                                        dataflow_state,
+                                    })
+                                }
+                                None => Ok(ControllerState::new(
+                                    self.config.clone(),
+                                    self.permissive_writes,
+                                )),
                             },
                             Some(mut state) => {
                                 // check that running config is compatible with the new
@@ -1055,7 +1139,16 @@ impl AuthorityLeaderElectionState {
                 .await;
 
             let (state, cache_ddl) = match update_res {
-                Ok(Ok(state)) => (state, None),
+                Ok(Ok(state)) => {
+                    if restored_from_checkpoint {
+                        info!("Restored controller state from checkpoint");
+                        // The state is in the authority now, so it mustn't be restored again
+                        std::fs::remove_file(restored_checkpoint_state_path(
+                            &self.config.persistence,
+                        ))?;
+                    }
+                    (state, None)
+                }
                 Ok(Err(_)) => return Ok(()),
                 Err(error) if error.caused_by_serialization_failed() => {
                     warn!(
@@ -1092,6 +1185,7 @@ impl AuthorityLeaderElectionState {
                 .send(AuthorityUpdate::WonLeaderElection(LeaderElectionResults {
                     controller_state: state,
                     cache_ddl,
+                    restored_from_checkpoint,
                 }))
                 .await
                 .map_err(|_| internal_err!("failed to announce who won leader election"))?;
@@ -127,6 +127,11 @@ pub(super) fn make_expressions_above_grouped(
     }
 }
 
+/// Make the grouped nodes for all the aggregates in the query.
+///
+/// If `post_lookup` is true, the aggregates will be re-aggregated post-lookup, so this also makes
+/// the grouped nodes for any partial state needed to do so (see [`post_lookup_partial_state`])
+#[allow(clippy::too_many_arguments)]
 pub(super) fn make_grouped(
     mir_converter: &mut SqlToMirConverter,
     query_name: &Relation,
@@ -135,6 +140,7 @@ pub(super) fn make_grouped(
     _: &HashMap<&Relation, NodeIndex>,
     prev_node: &mut NodeIndex,
     projected_exprs: &HashMap<Expr, SqlIdentifier>,
+    post_lookup: bool,
 ) -> ReadySetResult<Vec<NodeIndex>> {
     let mut agg_nodes: Vec<NodeIndex> = Vec::new();
 
@@ -142,79 +148,79 @@ pub(super) fn make_grouped(
         // Don't need to do anything if we don't have any aggregates
         return Ok(vec![]);
     }
-    for (function, alias) in &qg.aggregates {
-        let name = mir_converter.generate_label(&name);
 
-        // Convert the GROUP BY exprs into column references
-        let group_by = qg
-            .group_by
-            .iter()
-            .map(|gb_expr| match gb_expr {
-                Expr::Column(c) => c.clone(),
-                expr => nom_sql::Column {
-                    name: expr.display(nom_sql::Dialect::MySQL).to_string().into(),
-                    table: None,
-                },
-            })
-            .collect::<Vec<_>>();
+    // Convert the GROUP BY exprs into column references
+    let group_by = qg
+        .group_by
+        .iter()
+        .map(|gb_expr| match gb_expr {
+            Expr::Column(c) => c.clone(),
+            expr => nom_sql::Column {
+                name: expr.display(nom_sql::Dialect::MySQL).to_string().into(),
+                table: None,
+            },
+        })
+        .collect::<Vec<_>>();
 
-        // get any parameter columns that aren't also in the group-by
-        // column set
-        let param_cols: Vec<_> = qg.relations.values().fold(vec![], |acc, rel| {
-            acc.into_iter()
-                .chain(
-                    rel.parameters
-                        .iter()
-                        .map(|param| &param.col)
-                        .filter(|c| !group_by.contains(c)),
-                )
-                .collect()
-        });
-        // combine and dedup
-        #[allow(clippy::needless_collect)] // necessary to avoid cloning param_cols
-        let dedup_gb_cols: Vec<_> = group_by
-            .iter()
-            .filter(|gbc| !param_cols.contains(gbc))
-            .collect();
-        let gb_and_param_cols = dedup_gb_cols
-            .into_iter()
-            .chain(param_cols.into_iter())
-            .map(Column::from);
-
-        let mut have_parent_cols = HashSet::new();
-        // we cannot have duplicate columns at the data-flow level, as it confuses our
-        // migration analysis code.
-        let group_cols = gb_and_param_cols
-            .filter_map(|mut c| {
-                let pc = mir_converter
-                    .columns(*prev_node)
+    // get any parameter columns that aren't also in the group-by
+    // column set
+    let param_cols: Vec<_> = qg.relations.values().fold(vec![], |acc, rel| {
+        acc.into_iter()
+            .chain(
+                rel.parameters
                     .iter()
-                    .position(|pc| *pc == c);
-                if let Some(pc) = pc {
-                    if !have_parent_cols.contains(&pc) {
-                        have_parent_cols.insert(pc);
-                        let pc = mir_converter.columns(*prev_node)[pc].clone();
-                        if pc.name != c.name || pc.table != c.table {
-                            // remember the alias with the parent column
-                            c.aliases.push(pc);
-                        }
-                        Some(c)
-                    } else {
-                        // we already have this column, so eliminate duplicate
-                        None
+                    .map(|param| &param.col)
+                    .filter(|c| !group_by.contains(c)),
+            )
+            .collect()
+    });
+    // combine and dedup
+    #[allow(clippy::needless_collect)] // necessary to avoid cloning param_cols
+    let dedup_gb_cols: Vec<_> = group_by
+        .iter()
+        .filter(|gbc| !param_cols.contains(gbc))
+        .collect();
+    let gb_and_param_cols = dedup_gb_cols
+        .into_iter()
+        .chain(param_cols)
+        .map(Column::from);
+
+    let mut have_parent_cols = HashSet::new();
+    // we cannot have duplicate columns at the data-flow level, as it confuses our
+    // migration analysis code.
+    let group_cols = gb_and_param_cols
+        .filter_map(|mut c| {
+            let pc = mir_converter
+                .columns(*prev_node)
+                .iter()
+                .position(|pc| *pc == c);
+            if let Some(pc) = pc {
+                if !have_parent_cols.contains(&pc) {
+                    have_parent_cols.insert(pc);
+                    let pc = mir_converter.columns(*prev_node)[pc].clone();
+                    if pc.name != c.name || pc.table != c.table {
+                        // remember the alias with the parent column
+                        c.aliases.push(pc);
                     }
-                } else {
                     Some(c)
+                } else {
+                    // we already have this column, so eliminate duplicate
+                    None
                 }
-            })
-            .collect();
+            } else {
+                Some(c)
+            }
+        })
+        .collect::<Vec<_>>();
 
+    for (function, alias) in &qg.aggregates {
+        let name = mir_converter.generate_label(&name);
         let nodes: Vec<NodeIndex> = mir_converter.make_aggregate_node(
             query_name,
             name,
             Column::named(alias.clone()),
             function.clone(),
-            group_cols,
+            group_cols.clone(),
             *prev_node,
             projected_exprs,
         )?;
@@ -222,6 +228,34 @@ pub(super) fn make_grouped(
         agg_nodes.extend(nodes);
     }
 
+    if post_lookup {
+        for (partial_state, alias) in post_lookup_partial_state(qg) {
+            let name = mir_converter.generate_label(&name);
+            let nodes = match partial_state {
+                PartialState::Aggregate(function) => mir_converter.make_aggregate_node(
+                    query_name,
+                    name,
+                    Column::named(alias),
+                    function,
+                    group_cols.clone(),
+                    *prev_node,
+                    projected_exprs,
+                )?,
+                PartialState::DistinctValues(expr) => mir_converter.make_distinct_values_node(
+                    query_name,
+                    name,
+                    Column::named(alias),
+                    &expr,
+                    group_cols.clone(),
+                    *prev_node,
+                    projected_exprs,
+                )?,
+            };
+
+            agg_nodes.extend(nodes);
+        }
+    }
+
     let joinable_agg_nodes = joinable_aggregate_nodes(mir_converter, &agg_nodes);
 
     if joinable_agg_nodes.len() >= 2 {
@@ -261,12 +295,86 @@ fn joinable_aggregate_nodes(
         .collect()
 }
 
+/// Partial state for an aggregate in a query, which is computed in the dataflow graph alongside the
+/// aggregate itself so that the aggregate can be re-aggregated post-lookup
+enum PartialState {
+    /// The result of another aggregate function
+    Aggregate(nom_sql::FunctionExpr),
+    /// An array of the distinct values of an expression
+    DistinctValues(Expr),
+}
+
+/// Returns the name of the column containing the partial state `kind` for the aggregate projected
+/// as `alias`
+fn partial_state_column(alias: &SqlIdentifier, kind: &str) -> SqlIdentifier {
+    format!("__{alias}_{kind}").into()
+}
+
+/// Returns the partial state, and the name of the column to project that partial state as, for all
+/// the aggregates in the given query which can't be re-aggregated post-lookup from their own values
+/// alone
+fn post_lookup_partial_state(query_graph: &QueryGraph) -> Vec<(PartialState, SqlIdentifier)> {
+    let mut partial_state = vec![];
+    for (function, alias) in &query_graph.aggregates {
+        match function {
+            Avg {
+                expr,
+                distinct: false,
+            } => {
+                partial_state.push((
+                    PartialState::Aggregate(Sum {
+                        expr: expr.clone(),
+                        distinct: false,
+                    }),
+                    partial_state_column(alias, "sum"),
+                ));
+                partial_state.push((
+                    PartialState::Aggregate(Count {
+                        expr: expr.clone(),
+                        distinct: false,
+                    }),
+                    partial_state_column(alias, "count"),
+                ));
+            }
+            Avg {
+                expr,
+                distinct: true,
+            }
+            | Count {
+                expr,
+                distinct: true,
+            }
+            | Sum {
+                expr,
+                distinct: true,
+            } => partial_state.push((
+                PartialState::DistinctValues((**expr).clone()),
+                partial_state_column(alias, "distinct"),
+            )),
+            _ => {}
+        }
+    }
+    partial_state
+}
+
+/// Returns the names of the columns containing partial state which must be projected all the way
+/// to the reader for the given query's aggregates to be re-aggregated post-lookup
+pub(super) fn post_lookup_partial_state_columns(query_graph: &QueryGraph) -> Vec<SqlIdentifier> {
+    post_lookup_partial_state(query_graph)
+        .into_iter()
+        .map(|(_, alias)| alias)
+        .collect()
+}
+
 /// Build up the set of [`PostLookupAggregates`] for the given query, given as both the query
 /// graph itself and the select statement that the query is built from.
 ///
 /// This function is *not* responsible for determining whether the query *requires* post-lookup
 /// aggregation - that's the responsibility of the caller. This function will only return [`None`]
 /// if the query contains no aggregates.
+///
+/// Some of the returned aggregates refer to the partial state columns projected by
+/// [`make_grouped`] when called with `post_lookup` set.
 pub(super) fn post_lookup_aggregates(
     query_graph: &QueryGraph,
     query_name: &Relation,
@@ -302,22 +410,29 @@ pub(super) fn post_lookup_aggregates(
         return Ok(None);
     }
 
+    let column = |name| Column::named(name).aliased_as_table(query_name.clone());
     let mut aggregates = vec![];
     for (function, alias) in &query_graph.aggregates {
         aggregates.push(PostLookupAggregate {
-            column: Column::named(alias.clone()).aliased_as_table(query_name.clone()),
+            column: column(alias.clone()),
             function: match function {
-                Avg { .. } => {
-                    unsupported!("Average is not supported as a post-lookup aggregate")
-                }
-                Count { distinct, .. } if *distinct => {
-                    // TODO(REA-4289)
-                    unsupported!("COUNT(DISTINCT ...) is not supported as a post-lookup aggregate")
-                }
-                Sum { distinct, .. } if *distinct => {
-                    // TODO(REA-4289)
-                    unsupported!("SUM(DISTINCT ...) is not supported as a post-lookup aggregate")
-                }
+                // Aggregates which can't be re-aggregated from their own values are recomputed from
+                // the partial state projected by `make_grouped`
+                Avg {
+                    distinct: false, ..
+                } => PostLookupAggregateFunction::Avg {
+                    sum: column(partial_state_column(alias, "sum")),
+                    count: column(partial_state_column(alias, "count")),
+                },
+                Avg { distinct: true, .. } => PostLookupAggregateFunction::AvgDistinct {
+                    values: column(partial_state_column(alias, "distinct")),
+                },
+                Count { distinct: true, .. } => PostLookupAggregateFunction::CountDistinct {
+                    values: column(partial_state_column(alias, "distinct")),
+                },
+                Sum { distinct: true, .. } => PostLookupAggregateFunction::SumDistinct {
+                    values: column(partial_state_column(alias, "distinct")),
+                },
                 // Count and sum are handled the same way, as re-aggregating counts is
                 // done by just summing the numbers together
                 Count { .. } | CountStar | Sum { .. } => PostLookupAggregateFunction::Sum,
@@ -326,7 +441,17 @@ pub(super) fn post_lookup_aggregates(
                 GroupConcat { separator, .. } => PostLookupAggregateFunction::GroupConcat {
                     separator: separator.clone().unwrap_or_else(|| ",".to_owned()),
                 },
-                Extract { .. } | Call { .. } | Substring { .. } => continue,
+                Stddev { .. }
+                | Variance { .. }
+                | BoolAnd(_)
+                | BoolOr(_)
+                | BitAnd(_)
+                | BitOr(_)
+                | BitXor(_) => unsupported!(
+                    "{} is not supported as a post-lookup aggregate",
+                    function.display(nom_sql::Dialect::MySQL)
+                ),
+                Extract { .. } | Call { .. } | Substring { .. } | Window { .. } => continue,
             },
         });
     }
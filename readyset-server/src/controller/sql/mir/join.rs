@@ -1,8 +1,11 @@
 use std::collections::{HashMap, HashSet};
 
+use mir::node::node_inner::MirNodeInner;
+use mir::node::{MirNode, ViewKeyColumn};
 use mir::NodeIndex;
-use nom_sql::Relation;
-use readyset_errors::{internal_err, invariant, unsupported, ReadySetResult};
+use nom_sql::{BinaryOperator, Expr, Relation};
+use readyset_errors::{internal_err, invariant, unsupported, unsupported_err, ReadySetResult};
+use vec1::Vec1;
 
 use super::JoinKind;
 use crate::controller::sql::mir::SqlToMirConverter;
@@ -45,30 +48,59 @@ pub(super) fn make_joins(
     let mut join_chains = Vec::new();
 
     for jref in qg.join_order.iter() {
-        let (mut join_kind, jps, left_preds, right_preds) =
-            match &qg.edges[&(jref.src.clone(), jref.dst.clone())] {
-                QueryGraphEdge::Join { on } => (JoinKind::Inner, on, None, None),
-                QueryGraphEdge::LeftJoin {
-                    on,
-                    left_local_preds,
-                    right_local_preds,
-                    global_preds,
-                    params,
-                } => {
-                    if !global_preds.is_empty() {
-                        unsupported!("Global predicates not yet supported in left joins");
+        let edge = &qg.edges[&(jref.src.clone(), jref.dst.clone())];
+        let (mut join_kind, jps, left_preds, right_preds, residual, right_params) = match edge {
+            QueryGraphEdge::Join { on } => (JoinKind::Inner, on, None, None, None, None),
+            QueryGraphEdge::FullJoin { on } => (JoinKind::Full, on, None, None, None, None),
+            QueryGraphEdge::LeftJoin {
+                on,
+                left_local_preds,
+                right_local_preds,
+                global_preds,
+                params,
+            } => {
+                // Parameters compared against columns in the right-hand side of a left join can't
+                // be part of the lookup key, since rows in the left which have no match for the
+                // parameter need to be joined against NULLs. Instead, they're filtered post-lookup
+                // (see `pull_view_keys_to_leaf`)
+                if !params.is_empty() {
+                    if params
+                        .iter()
+                        .any(|param| param.col.table.as_ref() != Some(&jref.dst))
+                    {
+                        unsupported!(
+                            "Parameters compared against the left-hand side of a LEFT JOIN not \
+                             supported"
+                        );
                     }
-                    if !params.is_empty() {
-                        unsupported!("Parameters not yet supported in left joins");
+                    if !mir_converter.config.allow_post_lookup {
+                        unsupported!(
+                            "Queries which perform operations post-lookup are not supported"
+                        );
                     }
-                    (
-                        JoinKind::Left,
-                        on,
-                        Some(left_local_preds),
-                        Some(right_local_preds),
-                    )
                 }
-            };
+                // Global predicates in the ON clause of a left join (such as inequalities
+                // between the two sides) can't be evaluated as a filter after the join, since
+                // that would drop the NULL rows for left rows which have no match, so they're
+                // evaluated as part of the join itself
+                let residual = global_preds
+                    .iter()
+                    .cloned()
+                    .reduce(|acc, pred| Expr::BinaryOp {
+                        lhs: Box::new(acc),
+                        op: BinaryOperator::And,
+                        rhs: Box::new(pred),
+                    });
+                (
+                    JoinKind::Left,
+                    on,
+                    Some(left_local_preds),
+                    Some(right_local_preds),
+                    residual,
+                    Some(params),
+                )
+            }
+        };
 
         let (left_chain, right_chain) =
             pick_join_chains(&jref.src, &jref.dst, &mut join_chains, node_for_rel)?;
@@ -81,6 +113,9 @@ pub(super) fn make_joins(
                 JoinKind::Inner => {
                     join_kind = JoinKind::DependentInner;
                 }
+                JoinKind::Full => {
+                    unsupported!("FULL OUTER JOIN not supported in correlated subqueries")
+                }
                 JoinKind::DependentInner | JoinKind::DependentLeft => {}
             }
         }
@@ -105,6 +140,40 @@ pub(super) fn make_joins(
             )?;
         }
 
+        if let Some(params) = right_params.filter(|params| !params.is_empty()) {
+            if join_kind == JoinKind::DependentLeft {
+                unsupported!(
+                    "Parameters on the right-hand side of LEFT JOIN not supported in correlated \
+                     subqueries"
+                );
+            }
+            let key = params
+                .iter()
+                .map(|param| {
+                    Ok(ViewKeyColumn {
+                        column: mir::Column::from(param.col.clone()),
+                        op: param.op,
+                        placeholder_idx: param.placeholder_idx.ok_or_else(|| {
+                            unsupported_err!(
+                                "Literal parameters on the right-hand side of a LEFT JOIN not \
+                                 supported"
+                            )
+                        })?,
+                    })
+                })
+                .collect::<ReadySetResult<Vec<_>>>()?;
+            right_parent = mir_converter.add_query_node(
+                query_name.clone(),
+                MirNode::new(
+                    mir_converter.generate_label(&"right_view_key".into()),
+                    MirNodeInner::ViewKey {
+                        key: Vec1::try_from(key).map_err(|_| internal_err!("empty view key"))?,
+                    },
+                ),
+                &[right_parent],
+            );
+        }
+
         let jn = mir_converter.make_join_node(
             query_name,
             mir_converter.generate_label(&name),
@@ -114,6 +183,16 @@ pub(super) fn make_joins(
             join_kind,
         )?;
 
+        if let Some(residual) = residual {
+            match &mut mir_converter.mir_graph[jn].inner {
+                MirNodeInner::LeftJoin { residual: r, .. } => *r = Some(residual),
+                _ => unsupported!(
+                    "Non-equality join conditions not yet supported in left joins in correlated \
+                     subqueries"
+                ),
+            }
+        }
+
         // merge node chains
         let new_chain = left_chain.merge_chain(right_chain, jn);
         join_chains.push(new_chain);
@@ -121,6 +200,33 @@ pub(super) fn make_joins(
         join_nodes.push(jn);
     }
 
+    // Any relations which aren't connected to the rest of the query by a join edge (such as tables
+    // in a comma-separated list, either in the FROM clause or on the right-hand side of a JOIN)
+    // are cross-joined with everything else. Filters on the result of those cross joins can later
+    // be turned into join keys by the optimizer.
+    if !join_chains.is_empty() {
+        let mut unjoined_rels = node_for_rel
+            .keys()
+            .filter(|rel| !join_chains.iter().any(|chain| chain.has_table(rel)))
+            .collect::<Vec<_>>();
+        unjoined_rels.sort_unstable();
+
+        let nodes = join_chains
+            .iter()
+            .map(|chain| chain.last_node)
+            .chain(unjoined_rels.into_iter().map(|rel| node_for_rel[rel]))
+            .collect::<Vec<_>>();
+        if nodes.len() > 1 {
+            join_nodes.extend(make_cross_joins(
+                mir_converter,
+                query_name,
+                &name.name,
+                nodes,
+                correlated_nodes,
+            )?);
+        }
+    }
+
     Ok(join_nodes)
 }
 
@@ -7,7 +7,10 @@ use ::serde::{Deserialize, Serialize};
 use catalog_tables::is_catalog_table;
 use common::IndexType;
 use dataflow::ops::grouped::aggregate::Aggregation;
+use dataflow::ops::set_operation::SetOperationKind;
+use dataflow::ops::temporal_filter::TemporalComparison;
 use dataflow::ops::union;
+use dataflow::ops::window::WindowFunction;
 use lazy_static::lazy_static;
 use mir::graph::MirGraph;
 use mir::node::node_inner::MirNodeInner;
@@ -25,18 +28,19 @@ use nom_sql::{
 use petgraph::visit::Reversed;
 use petgraph::Direction;
 use readyset_client::ViewPlaceholder;
+use readyset_data::Interval;
 use readyset_errors::{
     internal, internal_err, invalid_query, invalid_query_err, invariant, invariant_eq, unsupported,
     ReadySetError, ReadySetResult,
 };
-use readyset_sql_passes::is_correlated;
+use readyset_sql_passes::{is_correlated, now_offset};
 use readyset_util::redacted::Sensitive;
 use tracing::{debug, trace};
 
 use super::query_graph::{extract_limit_offset, JoinPredicate};
 use crate::controller::sql::mir::grouped::{
     make_expressions_above_grouped, make_grouped, make_predicates_above_grouped,
-    post_lookup_aggregates,
+    post_lookup_aggregates, post_lookup_partial_state_columns,
 };
 use crate::controller::sql::mir::join::{make_cross_joins, make_joins};
 use crate::controller::sql::query_graph::{
@@ -86,6 +90,54 @@ fn value_columns_needed_for_predicates(
         .collect()
 }
 
+/// Strip out the parts of the subquery of an `EXISTS` expr which can't change whether or not it
+/// returns any rows: `ORDER BY`, `DISTINCT`, and any positive `LIMIT` (which ORMs like to add to
+/// these subqueries).
+fn normalize_exists_subquery(mut subquery: SelectStatement) -> ReadySetResult<SelectStatement> {
+    match subquery.limit_clause.offset() {
+        None | Some(Literal::Integer(0) | Literal::UnsignedInteger(0)) => {}
+        Some(_) => unsupported!("OFFSET is not supported in EXISTS subqueries"),
+    }
+    match subquery.limit_clause.limit() {
+        None => {}
+        Some(Literal::Integer(n)) if *n > 0 => {}
+        Some(Literal::UnsignedInteger(n)) if *n > 0 => {}
+        Some(_) => unsupported!("LIMIT in EXISTS subqueries must be a positive integer"),
+    }
+
+    subquery.limit_clause = LimitClause::default();
+    subquery.order = None;
+    subquery.distinct = false;
+    Ok(subquery)
+}
+
+/// If `expr` compares a column against the current time, as `col <cmp> NOW() [± INTERVAL]` (or
+/// with the operands the other way around), returns the column, the comparison, and the offset
+/// from the current time, so that it can be compiled to a [`MirNodeInner::TemporalFilter`].
+fn temporal_comparison(
+    expr: &Expr,
+) -> ReadySetResult<Option<(nom_sql::Column, TemporalComparison, Interval)>> {
+    let Some((lhs, op, rhs)) = expr.as_binary_op() else {
+        return Ok(None);
+    };
+    let (column, op, now) = match (lhs, rhs) {
+        (Expr::Column(column), now) => (column, op, now),
+        (now, Expr::Column(column)) => match op.flip_ordering_comparison() {
+            Ok(op) => (column, op, now),
+            Err(_) => return Ok(None),
+        },
+        _ => return Ok(None),
+    };
+    let comparison = match op {
+        BinaryOperator::Greater => TemporalComparison::Greater,
+        BinaryOperator::GreaterOrEqual => TemporalComparison::GreaterOrEqual,
+        BinaryOperator::Less => TemporalComparison::Less,
+        BinaryOperator::LessOrEqual => TemporalComparison::LessOrEqual,
+        _ => return Ok(None),
+    };
+    Ok(now_offset(now)?.map(|offset| (column.clone(), comparison, offset)))
+}
+
 /// The result of removing a relation from MIR.
 #[derive(Default)]
 pub struct MirRemovalResult {
@@ -104,6 +156,8 @@ pub enum JoinKind {
     Inner,
     /// Left joins - see [`MirNodeInner::LeftJoin`]
     Left,
+    /// Full outer joins - see [`MirNodeInner::FullJoin`]
+    Full,
     /// Dependent inner joins - see [`MirNodeInner::DependentJoin`]
     DependentInner,
     /// Dependent left joins - see [`MirNodeInner::DependentLeftJoin`]
@@ -240,8 +294,7 @@ impl SqlToMirConverter {
     pub(super) fn compound_query_to_mir(
         &mut self,
         query_name: &Relation,
-        subquery_leaves: Vec<NodeIndex>,
-        op: CompoundSelectOperator,
+        subquery_leaves: Vec<(Option<CompoundSelectOperator>, NodeIndex)>,
         order: &Option<OrderClause>,
         limit_clause: &LimitClause,
         leaf_behavior: LeafBehavior,
@@ -255,14 +308,23 @@ impl SqlToMirConverter {
         } else {
             format!("{}_union", query_name.display_unquoted()).into()
         };
-        let mut final_node = match op {
-            CompoundSelectOperator::Union => self.make_union_node(
+        let mut final_node = if subquery_leaves.iter().all(|(op, _)| {
+            matches!(
+                op,
+                None | Some(CompoundSelectOperator::Union | CompoundSelectOperator::DistinctUnion)
+            )
+        }) {
+            self.make_union_node(
                 query_name,
                 name,
-                subquery_leaves.as_slice(),
+                &subquery_leaves
+                    .into_iter()
+                    .map(|(_, leaf)| leaf)
+                    .collect::<Vec<_>>(),
                 union::DuplicateMode::UnionAll,
-            )?,
-            _ => internal!(),
+            )?
+        } else {
+            self.make_set_operations(query_name, name, subquery_leaves)?
         };
 
         if let Some((limit, offset)) = extract_limit_offset(limit_clause)? {
@@ -632,6 +694,165 @@ impl SqlToMirConverter {
         Ok(ni)
     }
 
+    /// Combine the results of the subqueries of a compound select which contains `INTERSECT` or
+    /// `EXCEPT` operators into a single node, with the last node named `name`.
+    ///
+    /// As in the SQL standard, `INTERSECT` binds more tightly than `UNION` and `EXCEPT`, which are
+    /// evaluated left to right.
+    fn make_set_operations(
+        &mut self,
+        query_name: &Relation,
+        name: Relation,
+        subquery_leaves: Vec<(Option<CompoundSelectOperator>, NodeIndex)>,
+    ) -> ReadySetResult<NodeIndex> {
+        // First, fold each run of `INTERSECT`s into a single node...
+        let mut terms: Vec<(Option<CompoundSelectOperator>, Vec<(bool, NodeIndex)>)> = vec![];
+        for (op, leaf) in subquery_leaves {
+            match op {
+                Some(CompoundSelectOperator::Intersect) => match terms.last_mut() {
+                    Some((_, intersected)) => intersected.push((true, leaf)),
+                    None => internal!("INTERSECT must follow a select"),
+                },
+                Some(CompoundSelectOperator::IntersectAll) => match terms.last_mut() {
+                    Some((_, intersected)) => intersected.push((false, leaf)),
+                    None => internal!("INTERSECT ALL must follow a select"),
+                },
+                op => terms.push((op, vec![(false, leaf)])),
+            }
+        }
+
+        let num_terms = terms.len();
+        let mut combined = None;
+        for (i, (op, intersected)) in terms.into_iter().enumerate() {
+            let is_last_term = i == num_terms - 1;
+            let num_intersected = intersected.len();
+            let mut term = None;
+            for (j, (distinct, leaf)) in intersected.into_iter().enumerate() {
+                term = Some(match term {
+                    None => leaf,
+                    Some(left) => {
+                        let node_name = if is_last_term && op.is_none() && j == num_intersected - 1
+                        {
+                            name.clone()
+                        } else {
+                            self.generate_label(
+                                &format!("{}_intersect", query_name.display_unquoted()).into(),
+                            )
+                        };
+                        self.make_set_operation_node(
+                            query_name,
+                            node_name,
+                            left,
+                            leaf,
+                            SetOperationKind::Intersect,
+                            distinct,
+                        )?
+                    }
+                });
+            }
+            #[allow(clippy::unwrap_used)] // every term has at least one subquery
+            let term = term.unwrap();
+
+            // ...then combine those nodes from left to right
+            combined = Some(match (combined, op) {
+                (None, _) => term,
+                (Some(left), Some(op)) => {
+                    let node_name = if is_last_term {
+                        name.clone()
+                    } else {
+                        self.generate_label(
+                            &format!("{}_compound", query_name.display_unquoted()).into(),
+                        )
+                    };
+                    match op {
+                        CompoundSelectOperator::Union | CompoundSelectOperator::DistinctUnion => {
+                            self.make_union_node(
+                                query_name,
+                                node_name,
+                                &[left, term],
+                                union::DuplicateMode::UnionAll,
+                            )?
+                        }
+                        CompoundSelectOperator::Except | CompoundSelectOperator::ExceptAll => self
+                            .make_set_operation_node(
+                                query_name,
+                                node_name,
+                                left,
+                                term,
+                                SetOperationKind::Except,
+                                op == CompoundSelectOperator::Except,
+                            )?,
+                        CompoundSelectOperator::Intersect
+                        | CompoundSelectOperator::IntersectAll => {
+                            internal!("INTERSECT should have been folded into a single term")
+                        }
+                    }
+                }
+                (Some(_), None) => {
+                    internal!("Only the first select in a compound select may omit an operator")
+                }
+            });
+        }
+
+        combined.ok_or_else(|| internal_err!("Compound select must have at least one select"))
+    }
+
+    /// Make a node computing the intersection or difference (according to `kind`) of `left` and
+    /// `right`.
+    ///
+    /// Both sides are tagged with a column identifying the side they came from and combined with a
+    /// union, which becomes the single parent of the [`MirNodeInner::SetOperation`] node.
+    fn make_set_operation_node(
+        &mut self,
+        query_name: &Relation,
+        name: Relation,
+        left: NodeIndex,
+        right: NodeIndex,
+        kind: SetOperationKind,
+        distinct: bool,
+    ) -> ReadySetResult<NodeIndex> {
+        let side_col = SqlIdentifier::from("__set_side");
+        let tagged = [left, right]
+            .into_iter()
+            .enumerate()
+            .map(|(side, node)| {
+                self.make_project_node(
+                    query_name,
+                    self.generate_label(&format!("{}_set_side", name.display_unquoted()).into()),
+                    node,
+                    self.columns(node)
+                        .into_iter()
+                        .map(ProjectExpr::Column)
+                        .chain(iter::once(ProjectExpr::Expr {
+                            expr: Expr::Literal(Literal::Integer(side as i64)),
+                            alias: side_col.clone(),
+                        }))
+                        .collect(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let union = self.make_union_node(
+            query_name,
+            self.generate_label(&format!("{}_set_union", name.display_unquoted()).into()),
+            &tagged,
+            union::DuplicateMode::UnionAll,
+        )?;
+
+        Ok(self.add_query_node(
+            query_name.clone(),
+            MirNode::new(
+                name,
+                MirNodeInner::SetOperation {
+                    kind,
+                    distinct,
+                    side: Column::named(side_col),
+                },
+            ),
+            &[union],
+        ))
+    }
+
     fn make_union_node(
         &mut self,
         query_name: &Relation,
@@ -748,6 +969,36 @@ impl SqlToMirConverter {
         )
     }
 
+    fn make_temporal_filter_node(
+        &mut self,
+        query_name: &Relation,
+        name: Relation,
+        parent: NodeIndex,
+        column: Column,
+        comparison: TemporalComparison,
+        offset: Interval,
+    ) -> NodeIndex {
+        trace!(
+            name = %name.display_unquoted(),
+            %column,
+            %comparison,
+            %offset,
+            "Added temporal filter node"
+        );
+        self.add_query_node(
+            query_name.clone(),
+            MirNode::new(
+                name,
+                MirNodeInner::TemporalFilter {
+                    column,
+                    comparison,
+                    offset,
+                },
+            ),
+            &[parent],
+        )
+    }
+
     fn make_aggregate_node(
         &mut self,
         query_name: &Relation,
@@ -816,6 +1067,19 @@ impl SqlToMirConverter {
             return Ok(vec![project_coalesce, grouped_node]);
         }
 
+        // Returns the column to aggregate over for the given argument to an aggregate function
+        let over_column = |expr: &Expr| -> ReadySetResult<Column> {
+            Ok(match expr {
+                Expr::Column(col) => Column::from(col.clone()),
+                expr => Column::named(
+                    projected_exprs
+                        .get(expr)
+                        .cloned()
+                        .ok_or_else(|| mk_error!(expr))?,
+                ),
+            })
+        };
+
         let mut out_nodes = Vec::new();
 
         let mknode = |over: Column, t: GroupedNodeType, distinct: bool| {
@@ -942,12 +1206,88 @@ impl SqlToMirConverter {
                 }),
                 false,
             ),
+            Stddev { expr, sample } => mknode(
+                over_column(&expr)?,
+                GroupedNodeType::Aggregation(Aggregation::Stddev { sample }),
+                false,
+            ),
+            Variance { expr, sample } => mknode(
+                over_column(&expr)?,
+                GroupedNodeType::Aggregation(Aggregation::Variance { sample }),
+                false,
+            ),
+            BoolAnd(expr) => mknode(
+                over_column(&expr)?,
+                GroupedNodeType::Aggregation(Aggregation::BoolAnd),
+                false,
+            ),
+            BoolOr(expr) => mknode(
+                over_column(&expr)?,
+                GroupedNodeType::Aggregation(Aggregation::BoolOr),
+                false,
+            ),
+            BitAnd(expr) => mknode(
+                over_column(&expr)?,
+                GroupedNodeType::Aggregation(Aggregation::BitAnd),
+                false,
+            ),
+            BitOr(expr) => mknode(
+                over_column(&expr)?,
+                GroupedNodeType::Aggregation(Aggregation::BitOr),
+                false,
+            ),
+            BitXor(expr) => mknode(
+                over_column(&expr)?,
+                GroupedNodeType::Aggregation(Aggregation::BitXor),
+                false,
+            ),
             _ => {
                 internal!("not an aggregate: {:?}", Sensitive(&function));
             }
         })
     }
 
+    /// Returns a list of nodes computing the sorted array of distinct values of `expr` for each
+    /// group, which is used as the partial state for re-aggregating DISTINCT aggregates
+    /// post-lookup.
+    #[allow(clippy::too_many_arguments)]
+    fn make_distinct_values_node(
+        &mut self,
+        query_name: &Relation,
+        name: Relation,
+        func_col: Column,
+        expr: &Expr,
+        group_cols: Vec<Column>,
+        parent: NodeIndex,
+        projected_exprs: &HashMap<Expr, SqlIdentifier>,
+    ) -> ReadySetResult<Vec<NodeIndex>> {
+        let over = match expr {
+            Expr::Column(col) => Column::from(col.clone()),
+            _ => Column::named(projected_exprs.get(expr).cloned().ok_or_else(|| {
+                internal_err!("projected_exprs does not contain {:?}", Sensitive(expr))
+            })?),
+        };
+
+        let mut dist_cols = vec![over.clone()];
+        dist_cols.extend(group_cols.clone());
+        let distinct = self.make_distinct_node(
+            query_name,
+            format!("{}_d0", name.display_unquoted()).into(),
+            parent,
+            dist_cols,
+        );
+        let grouped = self.make_grouped_node(
+            query_name,
+            name,
+            func_col,
+            (distinct, over),
+            group_cols,
+            GroupedNodeType::Aggregation(Aggregation::ArrayAgg),
+        );
+
+        Ok(vec![distinct, grouped])
+    }
+
     fn make_grouped_node(
         &mut self,
         query_name: &Relation,
@@ -1043,7 +1383,13 @@ impl SqlToMirConverter {
 
         let inner = match kind {
             JoinKind::Inner => MirNodeInner::Join { on, project },
-            JoinKind::Left => MirNodeInner::LeftJoin { on, project },
+            JoinKind::Left => MirNodeInner::LeftJoin {
+                on,
+                project,
+                residual: None,
+                with_nulls: false,
+            },
+            JoinKind::Full => MirNodeInner::FullJoin { on, project },
             JoinKind::DependentInner => MirNodeInner::DependentJoin { on, project },
             JoinKind::DependentLeft => MirNodeInner::DependentLeftJoin { on, project },
         };
@@ -1121,6 +1467,88 @@ impl SqlToMirConverter {
         ))
     }
 
+    /// Make the nodes for the subquery of an `EXISTS` expr, to be joined against `parent`.
+    ///
+    /// Returns a pair of a projection of `parent` with an additional `__exists_join_key` column
+    /// (always `0`), and a node which emits a single row with a `__count_grp` column (always `0`)
+    /// and an `__exists_count` column if and only if the subquery returns any rows. Joining the
+    /// two on `__exists_join_key = __count_grp` gives a semi-join against the subquery.
+    fn make_exists_subquery_nodes(
+        &mut self,
+        query_name: &Relation,
+        name: &Relation,
+        parent: NodeIndex,
+        subquery: &SelectStatement,
+    ) -> ReadySetResult<(NodeIndex, NodeIndex)> {
+        let query_graph = to_query_graph(normalize_exists_subquery(subquery.clone())?)?;
+        let subquery_leaf = self.named_query_to_mir(
+            query_name,
+            &query_graph,
+            &HashMap::new(),
+            LeafBehavior::Anonymous,
+        )?;
+
+        // -> π[lit: 0, lit: 0]
+        let group_proj = self.make_project_node(
+            query_name,
+            format!("{}_prj_hlpr", name.display_unquoted()).into(),
+            subquery_leaf,
+            vec![
+                ProjectExpr::Expr {
+                    alias: "__count_val".into(),
+                    expr: Expr::Literal(0u32.into()),
+                },
+                ProjectExpr::Expr {
+                    alias: "__count_grp".into(),
+                    expr: Expr::Literal(0u32.into()),
+                },
+            ],
+        );
+        // -> [0, 0] for each row
+
+        // -> |0| γ[1]
+        let exists_count_col = Column::named("__exists_count");
+        let exists_count_node = self.make_grouped_node(
+            query_name,
+            format!("{}_count", name.display_unquoted()).into(),
+            exists_count_col,
+            (group_proj, Column::named("__count_val")),
+            vec![Column::named("__count_grp")],
+            GroupedNodeType::Aggregation(Aggregation::Count),
+        );
+        // -> [0, <count>] for each row
+
+        // -> σ[c1 > 0]
+        let gt_0_filter = self.make_filter_node(
+            query_name,
+            format!("{}_count_gt_0", name.display_unquoted()).into(),
+            exists_count_node,
+            Expr::BinaryOp {
+                lhs: Box::new(Expr::Column("__exists_count".into())),
+                op: BinaryOperator::Greater,
+                rhs: Box::new(Expr::Literal(Literal::Integer(0))),
+            },
+        );
+
+        // left -> π[...left, lit: 0]
+        let parent_columns = self.mir_graph.columns(parent);
+        let left_literal_join_key_proj = self.make_project_node(
+            query_name,
+            format!("{}_join_key", name.display_unquoted()).into(),
+            parent,
+            parent_columns
+                .into_iter()
+                .map(ProjectExpr::Column)
+                .chain(iter::once(ProjectExpr::Expr {
+                    alias: "__exists_join_key".into(),
+                    expr: Expr::Literal(0u32.into()),
+                }))
+                .collect(),
+        );
+
+        Ok((left_literal_join_key_proj, gt_0_filter))
+    }
+
     /// Project out a column representing an `IN (subquery)` expr.
     ///
     /// Internally, these are compiled via a LEFT JOIN with a DISTINCT and a marker column on the
@@ -1247,6 +1675,63 @@ impl SqlToMirConverter {
         ))
     }
 
+    /// Project out a column representing an `EXISTS (subquery)` expr.
+    ///
+    /// Internally, these are compiled via a LEFT JOIN against the nodes built by
+    /// [`Self::make_exists_subquery_nodes`], followed by an `IS NOT NULL` expr on the count column
+    /// from the right-hand side projected out for the result
+    fn project_exists_subquery(
+        &mut self,
+        query_name: &Relation,
+        parent: NodeIndex,
+        name: &str,
+        subquery: &SelectStatement,
+        negated: bool,
+    ) -> ReadySetResult<NodeIndex> {
+        let parent_columns = self.columns(parent);
+        let label = self.generate_label(&format!("{name}_exists").into());
+        let (left, right) =
+            self.make_exists_subquery_nodes(query_name, &label, parent, subquery)?;
+
+        let join = self.make_join_node(
+            query_name,
+            self.generate_label(&format!("{name}_join").into()),
+            &[JoinPredicate {
+                left: "__exists_join_key".into(),
+                right: "__count_grp".into(),
+            }],
+            left,
+            right,
+            if is_correlated(subquery) {
+                JoinKind::DependentLeft
+            } else {
+                JoinKind::Left
+            },
+        )?;
+
+        Ok(self.make_project_node(
+            query_name,
+            self.generate_label(&format!("project_{name}").into()),
+            join,
+            parent_columns
+                .into_iter()
+                .map(ProjectExpr::Column)
+                .chain(iter::once(ProjectExpr::Expr {
+                    expr: Expr::BinaryOp {
+                        lhs: Box::new(Expr::Column("__exists_count".into())),
+                        op: if negated {
+                            BinaryOperator::Is
+                        } else {
+                            BinaryOperator::IsNot
+                        },
+                        rhs: Box::new(Expr::Literal(Literal::Null)),
+                    },
+                    alias: name.into(),
+                }))
+                .collect(),
+        ))
+    }
+
     fn make_project_node(
         &mut self,
         query_name: &Relation,
@@ -1377,6 +1862,135 @@ impl SqlToMirConverter {
         Ok(nodes)
     }
 
+    /// Make a window node (preceded by a projection of any non-column expressions it refers to)
+    /// which computes the given window function call and emits its result in a column named
+    /// `output_name`.
+    fn make_window_node(
+        &mut self,
+        query_name: &Relation,
+        name: SqlIdentifier,
+        mut parent: NodeIndex,
+        function: &FunctionExpr,
+        output_name: SqlIdentifier,
+    ) -> ReadySetResult<Vec<NodeIndex>> {
+        let FunctionExpr::Window {
+            function,
+            partition_by,
+            order_by,
+        } = function
+        else {
+            internal!("not a window function: {:?}", function)
+        };
+
+        let (window_function, over) = match function.as_ref() {
+            FunctionExpr::Call { name, arguments } => {
+                let window_function = match name.to_ascii_lowercase().as_str() {
+                    "row_number" => WindowFunction::RowNumber,
+                    "rank" => WindowFunction::Rank,
+                    "dense_rank" => WindowFunction::DenseRank,
+                    _ => unsupported!("Unsupported window function: {}", name),
+                };
+                if !arguments.is_empty() {
+                    invalid_query!("{}() does not take any arguments", name);
+                }
+                (window_function, None)
+            }
+            FunctionExpr::CountStar => (WindowFunction::Count, None),
+            FunctionExpr::Count { distinct: true, .. }
+            | FunctionExpr::Sum { distinct: true, .. }
+            | FunctionExpr::Avg { distinct: true, .. } => {
+                unsupported!("DISTINCT is not supported in window functions")
+            }
+            FunctionExpr::Count { expr, .. } => (WindowFunction::Count, Some(expr.as_ref())),
+            FunctionExpr::Sum { expr, .. } => (WindowFunction::Sum, Some(expr.as_ref())),
+            FunctionExpr::Avg { expr, .. } => (WindowFunction::Avg, Some(expr.as_ref())),
+            FunctionExpr::Max(expr) => (WindowFunction::Max, Some(expr.as_ref())),
+            FunctionExpr::Min(expr) => (WindowFunction::Min, Some(expr.as_ref())),
+            _ => unsupported!(
+                "Unsupported window function: {}",
+                // FIXME(REA-2168): Use correct dialect.
+                function.display(nom_sql::Dialect::MySQL)
+            ),
+        };
+
+        // Gather a list of expressions we need to evaluate before the window node
+        let mut exprs_to_project = vec![];
+        let mut column_for_expr = |expr: &Expr| match expr {
+            Expr::Column(col) => Column::from(col),
+            expr => {
+                // FIXME(REA-2168): Use correct dialect.
+                let col = Column::named(expr.display(nom_sql::Dialect::MySQL).to_string());
+                if self
+                    .mir_graph
+                    .column_id_for_column(parent, &col)
+                    .err()
+                    .iter()
+                    .any(|err| matches!(err, ReadySetError::NonExistentColumn { .. }))
+                    && !exprs_to_project.contains(expr)
+                {
+                    // Only project the expression if we haven't already
+                    exprs_to_project.push(expr.clone());
+                }
+                col
+            }
+        };
+
+        let over = over.map(&mut column_for_expr);
+        let partition_by = partition_by.iter().map(&mut column_for_expr).collect();
+        let order = if order_by.is_empty() {
+            None
+        } else {
+            Some(
+                order_by
+                    .iter()
+                    .map(|(expr, ot)| (column_for_expr(expr), *ot))
+                    .collect(),
+            )
+        };
+
+        let mut nodes = vec![];
+
+        // If the window function refers to non-column expressions, add an extra node to project
+        // those first
+        if !exprs_to_project.is_empty() {
+            let parent_columns = self.mir_graph.columns(parent);
+            let project_node = self.make_project_node(
+                query_name,
+                format!("{}_proj", name).into(),
+                parent,
+                parent_columns
+                    .into_iter()
+                    .map(ProjectExpr::Column)
+                    .chain(exprs_to_project.into_iter().map(|expr| {
+                        // FIXME(REA-2168): Use correct dialect.
+                        let alias = expr.display(nom_sql::Dialect::MySQL).to_string().into();
+                        ProjectExpr::Expr { alias, expr }
+                    }))
+                    .collect(),
+            );
+            nodes.push(project_node);
+            parent = project_node;
+        }
+
+        let window_node = self.add_query_node(
+            query_name.clone(),
+            MirNode::new(
+                name.into(),
+                MirNodeInner::Window {
+                    function: window_function,
+                    over,
+                    partition_by,
+                    order,
+                    output_column: Column::named(output_name),
+                },
+            ),
+            &[parent],
+        );
+        nodes.push(window_node);
+
+        Ok(nodes)
+    }
+
     fn make_predicate_nodes(
         &mut self,
         query_name: &Relation,
@@ -1452,71 +2066,8 @@ impl SqlToMirConverter {
                     }
                 );
 
-                let query_graph = to_query_graph((**subquery).clone())?;
-                let subquery_leaf = self.named_query_to_mir(
-                    query_name,
-                    &query_graph,
-                    &HashMap::new(),
-                    LeafBehavior::Anonymous,
-                )?;
-
-                // -> π[lit: 0, lit: 0]
-                let group_proj = self.make_project_node(
-                    query_name,
-                    format!("{}_prj_hlpr", name.display_unquoted()).into(),
-                    subquery_leaf,
-                    vec![
-                        ProjectExpr::Expr {
-                            alias: "__count_val".into(),
-                            expr: Expr::Literal(0u32.into()),
-                        },
-                        ProjectExpr::Expr {
-                            alias: "__count_grp".into(),
-                            expr: Expr::Literal(0u32.into()),
-                        },
-                    ],
-                );
-                // -> [0, 0] for each row
-
-                // -> |0| γ[1]
-                let exists_count_col = Column::named("__exists_count");
-                let exists_count_node = self.make_grouped_node(
-                    query_name,
-                    format!("{}_count", name.display_unquoted()).into(),
-                    exists_count_col,
-                    (group_proj, Column::named("__count_val")),
-                    vec![Column::named("__count_grp")],
-                    GroupedNodeType::Aggregation(Aggregation::Count),
-                );
-                // -> [0, <count>] for each row
-
-                // -> σ[c1 > 0]
-                let gt_0_filter = self.make_filter_node(
-                    query_name,
-                    format!("{}_count_gt_0", name.display_unquoted()).into(),
-                    exists_count_node,
-                    Expr::BinaryOp {
-                        lhs: Box::new(Expr::Column("__exists_count".into())),
-                        op: BinaryOperator::Greater,
-                        rhs: Box::new(Expr::Literal(Literal::Integer(0))),
-                    },
-                );
-
-                // left -> π[...left, lit: 0]
-                let parent_columns = self.mir_graph.columns(parent);
-                let left_literal_join_key_proj = self.make_project_node(
-                    query_name,
-                    format!("{}_join_key", name.display_unquoted()).into(),
-                    parent,
-                    parent_columns
-                        .into_iter()
-                        .map(ProjectExpr::Column)
-                        .chain(iter::once(ProjectExpr::Expr {
-                            alias: "__exists_join_key".into(),
-                            expr: Expr::Literal(0u32.into()),
-                        }))
-                        .collect(),
-                );
+                let (left_literal_join_key_proj, gt_0_filter) =
+                    self.make_exists_subquery_nodes(query_name, &name, parent, subquery)?;
 
                 let join_preds = [JoinPredicate {
                     left: "__exists_join_key".into(),
@@ -1672,17 +2223,25 @@ impl SqlToMirConverter {
                 }
             }
             Expr::NestedSelect(_) => unsupported!("Nested selects not supported in filters"),
-            _ => self.make_filter_node(
-                query_name,
-                format!(
+            _ => {
+                let name = format!(
                     "{}_f{}",
                     name.display_unquoted(),
                     self.mir_graph.node_count()
                 )
-                .into(),
-                parent,
-                ce.clone(),
-            ),
+                .into();
+                match temporal_comparison(ce)? {
+                    Some((column, comparison, offset)) => self.make_temporal_filter_node(
+                        query_name,
+                        name,
+                        parent,
+                        column.into(),
+                        comparison,
+                        offset,
+                    ),
+                    None => self.make_filter_node(query_name, name, parent, ce.clone()),
+                }
+            }
         };
 
         Ok(leaf)
@@ -1993,7 +2552,9 @@ impl SqlToMirConverter {
                 prev_node = subquery_leaf;
             }
 
-            // 8. Add function and grouped nodes
+            // 8. Add function and grouped nodes. If the leaf will re-aggregate the results for
+            // multiple keys post-lookup, this includes the partial state needed to do so.
+            let post_lookup = leaf_behavior.should_make_leaf() && self.config.allow_post_lookup;
             let mut func_nodes: Vec<NodeIndex> = make_grouped(
                 self,
                 query_name,
@@ -2002,6 +2563,7 @@ impl SqlToMirConverter {
                 &node_for_rel,
                 &mut prev_node,
                 &expressions_above_grouped,
+                post_lookup,
             )?;
 
             // 9. Add predicate nodes for HAVING after GROUP BY nodes
@@ -2018,32 +2580,15 @@ impl SqlToMirConverter {
                 prev_node = subquery_leaf;
             }
 
-            // 10. Get the final node
-            let mut final_node = prev_node;
-
-            if let Some(Pagination {
-                order,
-                limit,
-                offset,
-            }) = query_graph.pagination.as_ref()
+            // 10. Add window nodes for any window functions, which are computed over the result of
+            // grouping and filtering but before pagination
+            if !query_graph.window_functions.is_empty()
+                && matches!(view_key.index_type, IndexType::BTreeMap)
             {
-                let make_topk = offset.is_none();
-                // view key will have the offset parameter if it exists. We must filter it out
-                // of the group by, because the column originates at this node
-                let group_by = view_key
-                    .columns
-                    .iter()
-                    .filter_map(|(col, _)| {
-                        if col.name != *PAGE_NUMBER_COL {
-                            Some(col.clone())
-                        } else {
-                            None
-                        }
-                    })
-                    .collect();
-
-                // Order by expression projections and either a topk or paginate node
-                let paginate_nodes = self.make_paginate_node(
+                unsupported!("Window functions are not supported in queries with range parameters");
+            }
+            for (function, output_name) in &query_graph.window_functions {
+                let window_nodes = self.make_window_node(
                     query_name,
                     format!(
                         "q_{:x}_n{}",
@@ -2051,18 +2596,14 @@ impl SqlToMirConverter {
                         self.mir_graph.node_count()
                     )
                     .into(),
-                    final_node,
-                    group_by,
-                    order,
-                    *limit,
-                    make_topk,
+                    prev_node,
+                    function,
+                    output_name.clone(),
                 )?;
-                func_nodes.extend(paginate_nodes.clone());
-                final_node = *paginate_nodes.last().unwrap();
+                func_nodes.extend(window_nodes.iter().copied());
+                prev_node = *window_nodes.last().unwrap();
             }
 
-            // 10. Generate leaf views that expose the query result
-
             // We may already have added some of the expression and literal columns
             let (_, mut already_computed): (Vec<_>, Vec<_>) = value_columns_needed_for_predicates(
                 &query_graph.columns,
@@ -2071,7 +2612,9 @@ impl SqlToMirConverter {
             .into_iter()
             .unzip();
 
-            // Project out any columns that need special handling
+            // Project out any columns that need special handling. These are joins against
+            // subqueries which never change the number of rows, so they go below pagination to keep
+            // the TopK or Paginate node directly beneath the leaf
             for oc in &query_graph.columns {
                 if expressions_above_grouped
                     .values()
@@ -2093,9 +2636,9 @@ impl SqlToMirConverter {
                         },
                 }) = oc
                 {
-                    final_node = self.project_in_subquery(
+                    prev_node = self.project_in_subquery(
                         query_name,
-                        final_node,
+                        prev_node,
                         name,
                         lhs,
                         (**subquery).clone(),
@@ -2103,8 +2646,74 @@ impl SqlToMirConverter {
                     )?;
                     already_computed.push(oc.clone());
                 }
+
+                if let OutputColumn::Expr(ExprColumn {
+                    name,
+                    table: None,
+                    expression:
+                        expr @ (Expr::Exists(subquery)
+                        | Expr::UnaryOp {
+                            op: UnaryOperator::Not,
+                            rhs: box Expr::Exists(subquery),
+                        }),
+                }) = oc
+                {
+                    prev_node = self.project_exists_subquery(
+                        query_name,
+                        prev_node,
+                        name,
+                        subquery,
+                        matches!(expr, Expr::UnaryOp { .. }),
+                    )?;
+                    already_computed.push(oc.clone());
+                }
             }
 
+            // 11. Get the final node
+            let mut final_node = prev_node;
+
+            if let Some(Pagination {
+                order,
+                limit,
+                offset,
+            }) = query_graph.pagination.as_ref()
+            {
+                let make_topk = offset.is_none();
+                // view key will have the offset parameter if it exists. We must filter it out
+                // of the group by, because the column originates at this node
+                let group_by = view_key
+                    .columns
+                    .iter()
+                    .filter_map(|(col, _)| {
+                        if col.name != *PAGE_NUMBER_COL {
+                            Some(col.clone())
+                        } else {
+                            None
+                        }
+                    })
+                    .collect();
+
+                // Order by expression projections and either a topk or paginate node
+                let paginate_nodes = self.make_paginate_node(
+                    query_name,
+                    format!(
+                        "q_{:x}_n{}",
+                        query_graph.signature().hash,
+                        self.mir_graph.node_count()
+                    )
+                    .into(),
+                    final_node,
+                    group_by,
+                    order,
+                    *limit,
+                    make_topk,
+                )?;
+                func_nodes.extend(paginate_nodes.clone());
+                final_node = *paginate_nodes.last().unwrap();
+            }
+
+            // 12. Generate leaf views that expose the query result
+
             let mut emit = query_graph
                 .columns
                 .iter()
@@ -2146,6 +2755,14 @@ impl SqlToMirConverter {
                 }
             }
 
+            if post_lookup {
+                emit.extend(
+                    post_lookup_partial_state_columns(query_graph)
+                        .into_iter()
+                        .map(|name| ProjectExpr::Column(Column::named(name))),
+                );
+            }
+
             final_node = self.make_project_node(
                 query_name,
                 if leaf_behavior.should_make_leaf() {
@@ -2225,6 +2842,15 @@ impl SqlToMirConverter {
                         project_order.push(ProjectExpr::Column(col));
                     }
                 }
+                if post_lookup {
+                    // The partial state for post-lookup aggregates goes after all the returned
+                    // columns, so it's not returned to the client
+                    project_order.extend(
+                        post_lookup_partial_state_columns(query_graph)
+                            .into_iter()
+                            .map(|name| ProjectExpr::Column(Column::named(name))),
+                    );
+                }
 
                 // Add another project node that will return the required columns in the right order
                 let leaf_project_reorder_node = self.make_project_node(
@@ -2246,28 +2872,14 @@ impl SqlToMirConverter {
                     // (?, ?)`, the aggregate does need to be executed as a
                     // post-lookup. Adding a post-lookup is necessary for `where in` for correctly
                     // aggregating results, but a mild perf impediment for aggregates with a simple
-                    // equality (we'll run an aggregation on a single row). However, we've lost the
-                    // "did this come from a `where in` information" way above, as it's rewritten in
-                    // the adapter. Hence, to avoid that penalty on all users,
+                    // equality (we'll run an aggregation on a single row, and compute the partial
+                    // state for aggregates such as AVG in the dataflow graph). However, we've lost
+                    // the "did this come from a `where in` information" way above, as it's
+                    // rewritten in the adapter. Hence, to avoid that penalty on all users,
                     // only add the post-lookup to users who have opted in to
                     // using post-lookups.
-                    if self.config.allow_post_lookup {
-                        match post_lookup_aggregates(query_graph, query_name) {
-                            Ok(aggs) => aggs,
-                            // This part is a hack. When we get an ReadySetError::Unsupported,
-                            // that is because the aggregate was a AVG, COUNT(DISTINCT..), or
-                            // SUM(DISTINCT..). We can only support those (currently!) when the
-                            // query contained an equality clause, and
-                            // not a `where in` clause (that was
-                            // rewritten as an equality).  As mentioned above, we don't know which
-                            // one the original query had, thus this
-                            // code opts to preserve the functionality
-                            // of the simple equality. Once again, this only applies if the user
-                            // opted in to using "experimental"
-                            // post-lookups.
-                            Err(ReadySetError::Unsupported(..)) => None,
-                            Err(e) => return Err(e),
-                        }
+                    if post_lookup {
+                        post_lookup_aggregates(query_graph, query_name)?
                     } else {
                         None
                     }
@@ -2301,6 +2913,7 @@ impl SqlToMirConverter {
                             returned_cols: Some(returned_cols),
                             default_row: query_graph.default_row.clone(),
                             aggregates: post_lookup_aggregates,
+                            left_join_filter: None,
                         },
                     ),
                     &[leaf_project_reorder_node],
@@ -2326,6 +2939,9 @@ impl SqlToMirConverter {
                         ViewPlaceholder::PageNumber {
                             offset_placeholder, ..
                         } => unsupported_placeholders.push(offset_placeholder as u32),
+                        ViewPlaceholder::LeftJoinFilter(idx) => {
+                            unsupported_placeholders.push(idx as u32)
+                        }
                     }
                 }
 
@@ -6,9 +6,9 @@ use ::mir::visualize::GraphViz;
 use ::mir::DfNodeIndex;
 use ::serde::{Deserialize, Serialize};
 use nom_sql::{
-    CompoundSelectOperator, CompoundSelectStatement, CreateTableBody, CreateTableOption,
-    DialectDisplay, FieldDefinitionExpr, NonReplicatedRelation, NotReplicatedReason, Relation,
-    SelectSpecification, SelectStatement, SqlIdentifier, SqlType, TableExpr,
+    CacheOptions, CompoundSelectStatement, CreateTableBody, CreateTableOption, DialectDisplay,
+    FieldDefinitionExpr, NonReplicatedRelation, NotReplicatedReason, Relation, SelectSpecification,
+    SelectStatement, SqlIdentifier, SqlType, TableExpr,
 };
 use petgraph::graph::NodeIndex;
 use readyset_client::query::QueryId;
@@ -390,7 +390,14 @@ impl SqlIncorporator {
                     self.add_view(stmt.name, definition, schema_search_path.clone())?;
                 }
                 Change::CreateCache(cc) => {
-                    self.add_query(cc.name, *cc.statement, cc.always, &schema_search_path, mig)?;
+                    self.add_query(
+                        cc.name,
+                        *cc.statement,
+                        cc.always,
+                        cc.options,
+                        &schema_search_path,
+                        mig,
+                    )?;
                 }
                 Change::AlterTable(_) => {
                     // The only ALTER TABLE changes that can end up here (currently) are ones that
@@ -633,6 +640,7 @@ impl SqlIncorporator {
         name: Option<Relation>,
         mut stmt: SelectStatement,
         always: bool,
+        options: CacheOptions,
         schema_search_path: &[SqlIdentifier],
         mig: &mut Migration<'_>,
     ) -> ReadySetResult<Relation> {
@@ -697,6 +705,7 @@ impl SqlIncorporator {
             name: name.clone(),
             statement: stmt,
             always,
+            options,
             query_id,
         })?;
         self.registry
@@ -989,16 +998,19 @@ impl SqlIncorporator {
         mig: &mut Migration<'_>,
     ) -> ReadySetResult<MirNodeIndex> {
         let mut subqueries = Vec::with_capacity(query.selects.len());
-        for (_, stmt) in &mut query.selects {
+        for (op, stmt) in &mut query.selects {
             let mut tables = invalidating_tables.is_some().then(Vec::new);
-            subqueries.push(self.select_query_to_mir(
-                query_name.clone(),
-                stmt,
-                search_path,
-                tables.as_mut(),
-                LeafBehavior::Anonymous,
-                mig,
-            )?);
+            subqueries.push((
+                op.clone(),
+                self.select_query_to_mir(
+                    query_name.clone(),
+                    stmt,
+                    search_path,
+                    tables.as_mut(),
+                    LeafBehavior::Anonymous,
+                    mig,
+                )?,
+            ));
             if let Some(ts) = tables {
                 if let Some(its) = invalidating_tables.as_mut() {
                     its.extend(ts);
@@ -1009,7 +1021,6 @@ impl SqlIncorporator {
         self.mir_converter.compound_query_to_mir(
             &query_name,
             subqueries,
-            CompoundSelectOperator::Union,
             &query.order,
             &query.limit_clause,
             leaf_behavior,
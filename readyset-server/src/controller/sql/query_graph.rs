@@ -7,8 +7,9 @@ use std::vec::Vec;
 use std::{iter, mem};
 
 use common::{DfValue, IndexType};
+use nom_sql::analysis::visit::{walk_function_expr, Visitor};
 use nom_sql::analysis::visit_mut::{walk_expr, VisitorMut};
-use nom_sql::analysis::ReferredColumns;
+use nom_sql::analysis::{contains_aggregate, ReferredColumns};
 use nom_sql::{
     BinaryOperator, Column, DialectDisplay, Expr, FieldDefinitionExpr, FieldReference,
     FunctionExpr, InValue, ItemPlaceholder, JoinConstraint, JoinOperator, JoinRightSide,
@@ -230,6 +231,9 @@ pub enum QueryGraphEdge {
         /// Parameters mentioned in the ON clause of the join
         params: Vec<Parameter>,
     },
+    FullJoin {
+        on: Vec<JoinPredicate>,
+    },
 }
 
 #[derive(Clone, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
@@ -268,6 +272,10 @@ pub struct QueryGraph {
     /// If a single aggregate is projected as multiple aliases, only one will appear in this map,
     /// but both will appear in `self.columns` as [`OutputColumn::Data`] referencing that alias
     pub aggregates: HashMap<FunctionExpr, SqlIdentifier>,
+    /// Window functions in the query, represented as a list of the window function call (always a
+    /// [`FunctionExpr::Window`]) and the alias for the column containing its result, in the order
+    /// they appear in the query
+    pub window_functions: Vec<(FunctionExpr, SqlIdentifier)>,
     /// Set of expressions that appear in the GROUP BY clause
     pub group_by: HashSet<Expr>,
     /// Final set of projected columns in this query; may include literals in addition to the
@@ -476,6 +484,7 @@ impl Hash for QueryGraph {
         aggregates.hash(state);
 
         // these fields are Vecs, so already ordered
+        self.window_functions.hash(state);
         self.columns.hash(state);
         self.fields.hash(state);
         self.default_row.hash(state);
@@ -638,7 +647,16 @@ fn classify_conditionals(
                     table: Some(table), ..
                 }) = &**lhs
                 {
-                    local.entry(table.clone()).or_default().push(ce.clone());
+                    if rhs
+                        .referred_columns()
+                        .any(|col| col.table.as_ref().is_some_and(|t| t != table))
+                    {
+                        // comparisons between columns in different tables (such as inequality
+                        // join conditions) are global predicates
+                        global.push(ce.clone());
+                    } else {
+                        local.entry(table.clone()).or_default().push(ce.clone());
+                    }
                 } else {
                     // comparisons between computed columns and literals are global
                     // predicates
@@ -696,6 +714,7 @@ fn classify_conditionals(
         | Expr::Column(_)
         | Expr::NestedSelect(_)
         | Expr::Cast { .. }
+        | Expr::Interval { .. }
         | Expr::Array(_)
         | Expr::Row { .. }
         | Expr::Variable(_) => global.push(ce.clone()),
@@ -847,6 +866,40 @@ fn table_expr_name(table_expr: &TableExpr) -> ReadySetResult<Relation> {
     }
 }
 
+/// Returns true if the given expression contains a window function call anywhere within it,
+/// excluding subqueries
+fn contains_window_function(expr: &Expr) -> bool {
+    #[derive(Default)]
+    struct WindowFunctionFinder {
+        found: bool,
+    }
+
+    impl<'ast> Visitor<'ast> for WindowFunctionFinder {
+        type Error = std::convert::Infallible;
+
+        fn visit_function_expr(
+            &mut self,
+            function_expr: &'ast FunctionExpr,
+        ) -> Result<(), Self::Error> {
+            if matches!(function_expr, FunctionExpr::Window { .. }) {
+                self.found = true;
+                Ok(())
+            } else {
+                walk_function_expr(self, function_expr)
+            }
+        }
+
+        fn visit_select_statement(&mut self, _: &'ast SelectStatement) -> Result<(), Self::Error> {
+            // Don't walk into subqueries
+            Ok(())
+        }
+    }
+
+    let mut finder = WindowFunctionFinder::default();
+    let Ok(()) = finder.visit_expr(expr);
+    finder.found
+}
+
 fn default_row_for_select(st: &SelectStatement) -> Option<Vec<DfValue>> {
     // If this is an aggregated query AND it does not contain a GROUP BY clause,
     // set default values based on the aggregation (or lack thereof) on each
@@ -875,7 +928,35 @@ fn default_row_for_select(st: &SelectStatement) -> Option<Vec<DfValue>> {
 }
 
 #[allow(clippy::cognitive_complexity)]
-pub fn to_query_graph(stmt: SelectStatement) -> ReadySetResult<QueryGraph> {
+pub fn to_query_graph(mut stmt: SelectStatement) -> ReadySetResult<QueryGraph> {
+    // `a RIGHT JOIN b` is the same as `b LEFT JOIN a`, so we can turn right joins into left joins
+    // by swapping the two tables - but only if the left-hand side of the join is a single table,
+    // which is the case for a right join which is the first join in a query with only one table in
+    // the FROM clause. Anywhere else, the left-hand side is the result of the joins before it,
+    // which a left join can't have on its right-hand side.
+    for (i, jc) in stmt.join.iter_mut().enumerate() {
+        if !matches!(
+            jc.operator,
+            JoinOperator::RightJoin | JoinOperator::RightOuterJoin
+        ) {
+            continue;
+        }
+
+        if i != 0 || stmt.tables.len() != 1 {
+            unsupported!(
+                "RIGHT JOIN is only supported as the first join in a query with a single table in \
+                 the FROM clause; rewrite it as a LEFT JOIN with the joined tables swapped"
+            );
+        }
+
+        let JoinRightSide::Table(right) = &mut jc.right else {
+            unsupported!("RIGHT JOIN against multiple tables not supported");
+        };
+        #[allow(clippy::indexing_slicing)] // checked stmt.tables.len() above
+        mem::swap(&mut stmt.tables[0], right);
+        jc.operator = JoinOperator::LeftJoin;
+    }
+
     // a handy closure for making new relation nodes
     let new_node = |rel: Relation,
                     preds: Vec<Expr>,
@@ -987,7 +1068,15 @@ pub fn to_query_graph(stmt: SelectStatement) -> ReadySetResult<QueryGraph> {
                     inner_join_rels.insert(rel);
                 }
             }
-            JoinRightSide::Tables(_) => unsupported!("JoinRightSide::Tables not yet implemented"),
+            JoinRightSide::Tables(table_exprs) => {
+                if !jc.operator.is_inner_join() {
+                    unsupported!("Only inner joins against multiple tables are supported");
+                }
+                for table_expr in table_exprs {
+                    let rel = add_table_expr(table_expr)?;
+                    inner_join_rels.insert(rel);
+                }
+            }
         };
     }
 
@@ -1011,7 +1100,25 @@ pub fn to_query_graph(stmt: SelectStatement) -> ReadySetResult<QueryGraph> {
     for jc in stmt.join {
         let rhs_relation = match jc.right {
             JoinRightSide::Table(te) => table_expr_name(&te)?,
-            JoinRightSide::Tables(_) => unsupported!("JoinRightSide::Tables not yet implemented"),
+            JoinRightSide::Tables(_) => {
+                // An inner join against a list of tables is the same as adding those tables to the
+                // FROM clause, with the join condition as part of the WHERE clause - so we don't
+                // add any edges for them here, and leave inferring the join keys to the same
+                // optimization that handles implicit joins in the FROM clause
+                match jc.constraint {
+                    JoinConstraint::On(cond) => classify_conditionals(
+                        &cond,
+                        &mut local_predicates,
+                        &mut global_predicates,
+                        &mut query_parameters,
+                    )?,
+                    JoinConstraint::Using(_) => {
+                        unsupported!("USING is not supported in joins against multiple tables")
+                    }
+                    JoinConstraint::Empty => {}
+                }
+                continue;
+            }
         };
         // will be defined by join constraint
         let left_table;
@@ -1019,55 +1126,58 @@ pub fn to_query_graph(stmt: SelectStatement) -> ReadySetResult<QueryGraph> {
 
         let (on, extra_preds) = match jc.constraint {
             JoinConstraint::On(cond) => {
-                use nom_sql::analysis::ReferredTables;
-
-                // find all distinct tables mentioned in the condition
-                // conditions for now.
-                let mut tables_mentioned: Vec<Relation> =
-                    cond.referred_tables().into_iter().collect();
+                let other_table_mentioned = cond
+                    .referred_columns()
+                    .filter_map(|col| col.table.as_ref())
+                    .find(|table| **table != rhs_relation)
+                    .cloned();
 
                 let mut join_preds = vec![];
                 let mut extra_preds = vec![];
                 collect_join_predicates(cond, &mut join_preds, &mut extra_preds);
 
-                if tables_mentioned.len() == 2 {
-                    // tables can appear in any order in the join predicate, but
-                    // we cannot just rely on that order, since it may lead us to
-                    // flip LEFT JOINs by accident (yes, this happened)
-                    #[allow(clippy::indexing_slicing)] // len() == 2
-                    if tables_mentioned[1] != rhs_relation {
-                        // tables are in the wrong order in join predicate, swap
-                        tables_mentioned.swap(0, 1);
-                        invariant_eq!(tables_mentioned[1], rhs_relation);
-                    }
-                    left_table = tables_mentioned.remove(0);
-                    right_table = tables_mentioned.remove(0);
-                } else if tables_mentioned.len() == 1 {
-                    // just one table mentioned --> this is a self-join
-                    left_table = tables_mentioned.remove(0);
-                    right_table = left_table.clone();
-                } else {
-                    unsupported!("more than 2 tables mentioned in join condition!");
-                };
-
-                for pred in join_preds.iter_mut() {
-                    // the condition tree might specify tables in opposite order to
-                    // their join order in the query; if so, flip them
-                    // TODO(malte): this only deals with simple, flat join
-                    // conditions for now.
-                    if *pred.left.table.as_ref().ok_or_else(|| no_table_for_col())? == right_table
-                        && *pred
-                            .right
-                            .table
-                            .as_ref()
-                            .ok_or_else(|| no_table_for_col())?
-                            == left_table
-                    {
+                // Equality predicates are only usable as join keys if they compare a column in the
+                // table being joined to a column in a table that came before it. The left table of
+                // the join is taken from the first such predicate, or failing that from the first
+                // other table mentioned in the join condition (as in a join on only inequalities).
+                right_table = rhs_relation;
+                left_table = join_preds
+                    .iter()
+                    .find_map(
+                        |pred| match (pred.left.table.as_ref(), pred.right.table.as_ref()) {
+                            (Some(l), Some(r)) if *r == right_table && *l != right_table => {
+                                Some(l.clone())
+                            }
+                            (Some(l), Some(r)) if *l == right_table && *r != right_table => {
+                                Some(r.clone())
+                            }
+                            _ => None,
+                        },
+                    )
+                    .or(other_table_mentioned)
+                    .unwrap_or_else(|| prev_table.clone());
+
+                let mut on = vec![];
+                for mut pred in join_preds {
+                    let left = pred.left.table.as_ref().ok_or_else(no_table_for_col)?;
+                    let right = pred.right.table.as_ref().ok_or_else(no_table_for_col)?;
+                    if *left == right_table && *right == left_table {
+                        // the condition tree might specify tables in opposite order to their join
+                        // order in the query; if so, flip them
                         mem::swap(&mut pred.left, &mut pred.right);
+                    } else if !(*left == left_table && *right == right_table) {
+                        // Not a join key for this join, so evaluate it like any other condition
+                        extra_preds.push(Expr::BinaryOp {
+                            lhs: Box::new(Expr::Column(pred.left)),
+                            op: BinaryOperator::Equal,
+                            rhs: Box::new(Expr::Column(pred.right)),
+                        });
+                        continue;
                     }
+                    on.push(pred);
                 }
 
-                (join_preds, extra_preds)
+                (on, extra_preds)
             }
             JoinConstraint::Using(cols) => {
                 invariant_eq!(cols.len(), 1);
@@ -1132,7 +1242,15 @@ pub fn to_query_graph(stmt: SelectStatement) -> ReadySetResult<QueryGraph> {
                         params,
                     }
                 }
-                JoinOperator::Join | JoinOperator::InnerJoin => {
+                JoinOperator::FullJoin | JoinOperator::FullOuterJoin => {
+                    if !extra_preds.is_empty() {
+                        unsupported!(
+                            "Only equality join conditions are supported in FULL OUTER JOIN"
+                        );
+                    }
+                    QueryGraphEdge::FullJoin { on }
+                }
+                JoinOperator::Join | JoinOperator::InnerJoin | JoinOperator::CrossJoin => {
                     for pred in &extra_preds {
                         classify_conditionals(
                             pred,
@@ -1207,6 +1325,7 @@ pub fn to_query_graph(stmt: SelectStatement) -> ReadySetResult<QueryGraph> {
         vec![]
     };
 
+    let mut window_functions: Vec<(FunctionExpr, SqlIdentifier)> = vec![];
     let mut columns = Vec::with_capacity(stmt.fields.len());
     for field in stmt.fields.iter() {
         match field {
@@ -1230,6 +1349,56 @@ pub fn to_query_graph(stmt: SelectStatement) -> ReadySetResult<QueryGraph> {
                             column: c.clone(),
                         });
                     }
+                    Expr::Call(function @ FunctionExpr::Window { .. }) => {
+                        if function.arguments().any(contains_window_function) {
+                            unsupported!("Nested window functions are not supported");
+                        }
+
+                        // Any aggregates referenced by the window function are computed before the
+                        // window function itself, so replace them with references to the aggregate
+                        // columns
+                        let mut function = function.clone();
+                        if let FunctionExpr::Window {
+                            function: window_function,
+                            partition_by,
+                            order_by,
+                        } = &mut function
+                        {
+                            if is_aggregate(window_function)
+                                && window_function.arguments().any(contains_aggregate)
+                            {
+                                unsupported!(
+                                    "Aggregates as arguments to aggregate window functions are \
+                                     not supported"
+                                );
+                            }
+                            for expr in partition_by
+                                .iter_mut()
+                                .chain(order_by.iter_mut().map(|(expr, _)| expr))
+                            {
+                                aggregates.extend(map_aggregates(expr));
+                            }
+                        }
+
+                        let window_name =
+                            match window_functions.iter().find(|(f, _)| *f == function) {
+                                Some((_, window_name)) => window_name.clone(),
+                                None => {
+                                    window_functions.push((function, name.clone()));
+                                    name.clone()
+                                }
+                            };
+                        // Like aggregates, window functions end up in qg.columns as
+                        // OutputColumn::Data since by the time we're projecting the result set
+                        // columns they'll have already been computed by a window node
+                        columns.push(OutputColumn::Data {
+                            alias: alias.clone().unwrap_or(name),
+                            column: Column {
+                                name: window_name,
+                                table: None,
+                            },
+                        })
+                    }
                     Expr::Call(function) if is_aggregate(function) => {
                         let agg_name = aggregates
                             .entry(function.clone())
@@ -1250,6 +1419,12 @@ pub fn to_query_graph(stmt: SelectStatement) -> ReadySetResult<QueryGraph> {
                             },
                         })
                     }
+                    _ if contains_window_function(expr) => {
+                        unsupported!(
+                            "Window functions are only supported at the top level of the SELECT \
+                             list"
+                        )
+                    }
                     _ => {
                         let mut expr = expr.clone();
                         let aggs = map_aggregates(&mut expr);
@@ -1441,6 +1616,7 @@ pub fn to_query_graph(stmt: SelectStatement) -> ReadySetResult<QueryGraph> {
         relations,
         edges,
         aggregates,
+        window_functions,
         group_by,
         columns,
         fields: stmt.fields.clone(),
@@ -1661,6 +1837,73 @@ mod tests {
         );
     }
 
+    #[test]
+    fn window_function_over_aggregate() {
+        let qg = make_query_graph(
+            "SELECT t.a, rank() OVER (ORDER BY sum(t.c) DESC) AS r FROM t GROUP BY t.a",
+        );
+
+        assert_eq!(
+            qg.columns,
+            vec![
+                OutputColumn::Data {
+                    alias: "a".into(),
+                    column: Column::from("t.a")
+                },
+                OutputColumn::Data {
+                    alias: "r".into(),
+                    column: Column {
+                        name: "r".into(),
+                        table: None
+                    }
+                }
+            ]
+        );
+
+        assert_eq!(
+            qg.aggregates,
+            HashMap::from([(
+                FunctionExpr::Sum {
+                    expr: Box::new(Expr::Column("t.c".into())),
+                    distinct: false,
+                },
+                "sum(`t`.`c`)".into()
+            )])
+        );
+
+        assert_eq!(
+            qg.window_functions,
+            vec![(
+                FunctionExpr::Window {
+                    function: Box::new(FunctionExpr::Call {
+                        name: "rank".into(),
+                        arguments: vec![]
+                    }),
+                    partition_by: vec![],
+                    order_by: vec![(
+                        Expr::Column(Column {
+                            name: "sum(`t`.`c`)".into(),
+                            table: None
+                        }),
+                        OrderType::OrderDescending
+                    )]
+                },
+                "r".into()
+            )]
+        );
+    }
+
+    #[test]
+    fn nested_window_function() {
+        let query = parse_select_statement(
+            Dialect::MySQL,
+            "SELECT row_number() OVER (ORDER BY t.a) + 1 FROM t",
+        )
+        .unwrap();
+        let err = to_query_graph(query).unwrap_err();
+        assert!(err.caused_by_unsupported());
+    }
+
     #[test]
     fn constant_filter() {
         let qg = make_query_graph("SELECT x FROM t WHERE x = $1 AND 1");
@@ -1731,10 +1974,205 @@ mod tests {
                 assert_eq!(*global_preds, vec![]);
                 assert_eq!(*params, vec![]);
             }
-            QueryGraphEdge::Join { .. } => panic!("Expected left join, got {join:?}"),
+            _ => panic!("Expected left join, got {join:?}"),
         }
     }
 
+    #[test]
+    fn inequality_in_join_condition() {
+        let qg = make_query_graph("SELECT t1.x FROM t1 JOIN t2 ON t1.x = t2.x AND t1.ts >= t2.ts");
+        assert_eq!(
+            *qg.edges.get(&("t1".into(), "t2".into())).unwrap(),
+            QueryGraphEdge::Join {
+                on: vec![JoinPredicate {
+                    left: Column::from("t1.x"),
+                    right: Column::from("t2.x")
+                }]
+            }
+        );
+        assert_eq!(
+            qg.global_predicates,
+            vec![Expr::BinaryOp {
+                lhs: Box::new(Expr::Column("t1.ts".into())),
+                op: BinaryOperator::GreaterOrEqual,
+                rhs: Box::new(Expr::Column("t2.ts".into()))
+            }]
+        );
+        assert!(qg
+            .relations
+            .get(&"t1".into())
+            .unwrap()
+            .predicates
+            .is_empty());
+    }
+
+    #[test]
+    fn join_condition_mentioning_three_tables() {
+        let qg = make_query_graph(
+            "SELECT t1.x FROM t1 JOIN t2 ON t1.x = t2.x JOIN t3 ON t2.y = t3.y AND t3.ts < t1.ts",
+        );
+        assert_eq!(
+            *qg.edges.get(&("t2".into(), "t3".into())).unwrap(),
+            QueryGraphEdge::Join {
+                on: vec![JoinPredicate {
+                    left: Column::from("t2.y"),
+                    right: Column::from("t3.y")
+                }]
+            }
+        );
+        assert_eq!(
+            qg.global_predicates,
+            vec![Expr::BinaryOp {
+                lhs: Box::new(Expr::Column("t3.ts".into())),
+                op: BinaryOperator::Less,
+                rhs: Box::new(Expr::Column("t1.ts".into()))
+            }]
+        );
+    }
+
+    #[test]
+    fn inequality_in_left_join() {
+        let qg =
+            make_query_graph("SELECT t1.x FROM t1 LEFT JOIN t2 ON t1.x = t2.x AND t1.ts >= t2.ts");
+
+        let join = qg.edges.get(&("t1".into(), "t2".into())).unwrap();
+        match join {
+            QueryGraphEdge::LeftJoin {
+                on,
+                left_local_preds,
+                right_local_preds,
+                global_preds,
+                ..
+            } => {
+                assert_eq!(
+                    *on,
+                    vec![JoinPredicate {
+                        left: Column::from("t1.x"),
+                        right: Column::from("t2.x")
+                    }]
+                );
+                assert_eq!(*left_local_preds, vec![]);
+                assert_eq!(*right_local_preds, vec![]);
+                assert_eq!(
+                    *global_preds,
+                    vec![Expr::BinaryOp {
+                        lhs: Box::new(Expr::Column("t1.ts".into())),
+                        op: BinaryOperator::GreaterOrEqual,
+                        rhs: Box::new(Expr::Column("t2.ts".into()))
+                    }]
+                );
+            }
+            _ => panic!("Expected left join, got {join:?}"),
+        }
+    }
+
+    #[test]
+    fn right_join() {
+        let qg = make_query_graph("SELECT t1.x, t2.y FROM t1 RIGHT JOIN t2 ON t1.x = t2.x");
+        let join = qg.edges.get(&("t2".into(), "t1".into())).unwrap();
+        match join {
+            QueryGraphEdge::LeftJoin { on, .. } => assert_eq!(
+                *on,
+                vec![JoinPredicate {
+                    left: Column::from("t2.x"),
+                    right: Column::from("t1.x")
+                }]
+            ),
+            _ => panic!("Expected left join, got {join:?}"),
+        }
+    }
+
+    #[test]
+    fn right_join_after_other_join() {
+        let query = parse_select_statement(
+            Dialect::MySQL,
+            "SELECT t1.x FROM t1 JOIN t2 ON t1.x = t2.x RIGHT JOIN t3 ON t2.y = t3.y",
+        )
+        .unwrap();
+        let err = to_query_graph(query).unwrap_err();
+        assert!(
+            err.to_string().contains("rewrite it as a LEFT JOIN"),
+            "{err}"
+        );
+
+        let query = parse_select_statement(
+            Dialect::MySQL,
+            "SELECT t1.x FROM t1, t2 RIGHT JOIN t3 ON t2.y = t3.y",
+        )
+        .unwrap();
+        to_query_graph(query).unwrap_err();
+    }
+
+    #[test]
+    fn cross_join() {
+        let qg = make_query_graph("SELECT t1.x, t2.y FROM t1 CROSS JOIN t2");
+        assert_eq!(
+            *qg.edges.get(&("t1".into(), "t2".into())).unwrap(),
+            QueryGraphEdge::Join { on: vec![] }
+        );
+    }
+
+    #[test]
+    fn full_join() {
+        let qg = make_query_graph("SELECT t1.x, t2.y FROM t1 FULL OUTER JOIN t2 ON t1.x = t2.x");
+        assert_eq!(
+            *qg.edges.get(&("t1".into(), "t2".into())).unwrap(),
+            QueryGraphEdge::FullJoin {
+                on: vec![JoinPredicate {
+                    left: Column::from("t1.x"),
+                    right: Column::from("t2.x")
+                }]
+            }
+        );
+    }
+
+    #[test]
+    fn full_join_with_inequality() {
+        let query = parse_select_statement(
+            Dialect::MySQL,
+            "SELECT t1.x FROM t1 FULL JOIN t2 ON t1.x = t2.x AND t1.ts >= t2.ts",
+        )
+        .unwrap();
+        to_query_graph(query).unwrap_err();
+    }
+
+    #[test]
+    fn join_against_multiple_tables() {
+        let qg = make_query_graph(
+            "SELECT t1.x FROM t1 JOIN (t2, t3) ON t1.x = t2.x AND t2.y = t3.y WHERE t3.z = 1",
+        );
+        assert_eq!(
+            qg.relations.keys().cloned().collect::<HashSet<_>>(),
+            HashSet::from(["t1".into(), "t2".into(), "t3".into()])
+        );
+        assert!(qg.edges.is_empty());
+        assert_eq_unordered!(
+            qg.global_predicates,
+            vec![
+                Expr::BinaryOp {
+                    lhs: Box::new(Expr::Column("t1.x".into())),
+                    op: BinaryOperator::Equal,
+                    rhs: Box::new(Expr::Column("t2.x".into())),
+                },
+                Expr::BinaryOp {
+                    lhs: Box::new(Expr::Column("t2.y".into())),
+                    op: BinaryOperator::Equal,
+                    rhs: Box::new(Expr::Column("t3.y".into())),
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn left_join_against_multiple_tables() {
+        let query = parse_select_statement(
+            Dialect::MySQL,
+            "SELECT t1.x FROM t1 LEFT JOIN (t2, t3) ON t1.x = t2.x AND t2.y = t3.y",
+        )
+        .unwrap();
+        to_query_graph(query).unwrap_err();
+    }
+
     mod view_key {
         use super::*;
 
@@ -1,11 +1,11 @@
 use std::{fmt, str};
 
-use nom_sql::{Relation, SelectStatement};
+use nom_sql::{CacheOptions, Relation, SelectStatement};
 use petgraph::graph::NodeIndex;
 use readyset_client::recipe::changelist::ChangeList;
 use readyset_client::ViewCreateRequest;
 use readyset_data::Dialect;
-use readyset_errors::ReadySetResult;
+use readyset_errors::{ReadySetError, ReadySetResult};
 use readyset_sql_passes::adapter_rewrites::AdapterRewriteParams;
 use readyset_util::hash::hash;
 use serde::{Deserialize, Serialize};
@@ -145,6 +145,68 @@ impl Recipe {
         self.inc.registry.resolve_alias(alias)
     }
 
+    /// Returns the [`CacheOptions`] of the cache with the given name, alias or original name, or
+    /// `None` if there is no such cache
+    pub(in crate::controller) fn cache_options(&self, alias: &Relation) -> Option<&CacheOptions> {
+        match self.inc.registry.get(self.current_name(alias))? {
+            RecipeExpr::Cache { options, .. } => Some(options),
+            _ => None,
+        }
+    }
+
+    /// Sets each of the given [`CacheOptions`] on the cache with the given name or alias, leaving
+    /// any options not given unchanged, and returns the previous options
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReadySetError::ViewNotFound`] if there is no cache with the given name or alias
+    pub(in crate::controller) fn set_cache_options(
+        &mut self,
+        alias: &Relation,
+        options: CacheOptions,
+    ) -> ReadySetResult<CacheOptions> {
+        self.inc
+            .registry
+            .set_cache_options(alias, options)
+            .ok_or_else(|| ReadySetError::ViewNotFound(alias.display_unquoted().to_string()))
+    }
+
+    /// Sets whether the cache with the given name or alias should always be served from ReadySet,
+    /// even inside of transactions, and returns the previous value
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReadySetError::ViewNotFound`] if there is no cache with the given name or alias
+    pub(in crate::controller) fn set_cache_always(
+        &mut self,
+        alias: &Relation,
+        always: bool,
+    ) -> ReadySetResult<bool> {
+        self.inc
+            .registry
+            .set_cache_always(alias, always)
+            .ok_or_else(|| ReadySetError::ViewNotFound(alias.display_unquoted().to_string()))
+    }
+
+    /// Renames the cache with the given name or alias to `new_name`, without changing the names of
+    /// its dataflow nodes
+    pub(in crate::controller) fn rename_cache(
+        &mut self,
+        alias: &Relation,
+        new_name: Relation,
+    ) -> ReadySetResult<()> {
+        self.inc.registry.rename_cache(alias, new_name)
+    }
+
+    /// Returns the current name of the cache with the given original name, which is only different
+    /// from its original name if the cache has been renamed
+    pub(in crate::controller) fn current_name<'a>(
+        &'a self,
+        original_name: &'a Relation,
+    ) -> &'a Relation {
+        self.inc.registry.current_name(original_name)
+    }
+
     /// Returns a set of all *original names* for all caches in the recipe (not including aliases)
     pub(in crate::controller) fn cache_names(&self) -> impl Iterator<Item = &Relation> + '_ {
         self.inc.registry.cache_names()
@@ -1,10 +1,11 @@
 use std::collections::hash_map::Entry;
 use std::collections::{HashMap, HashSet};
+use std::mem;
 
 use nom_sql::analysis::visit::{self, Visitor};
 use nom_sql::{
-    CreateTableBody, CreateTableStatement, CreateViewStatement, ItemPlaceholder, Literal, Relation,
-    SelectSpecification, SelectStatement, SqlType,
+    CacheOptions, CreateTableBody, CreateTableStatement, CreateViewStatement, ItemPlaceholder,
+    Literal, Relation, SelectSpecification, SelectStatement, SqlType,
 };
 use readyset_client::query::QueryId;
 use readyset_client::recipe::changelist::PostgresTableMetadata;
@@ -38,6 +39,7 @@ pub(crate) enum RecipeExpr {
         name: Relation,
         statement: SelectStatement,
         always: bool,
+        options: CacheOptions,
         query_id: QueryId,
     },
 }
@@ -364,6 +366,18 @@ pub(super) struct ExprRegistry {
     /// Queries that can reuse the view for a different [`RecipeExpr::Cache`], specified by
     /// [`ExprId`]
     reused_caches: HashMap<Relation, Vec1<MatchedCache>>,
+
+    /// Map from the original name of each cache that has been renamed, to its current name.
+    ///
+    /// The dataflow nodes for a cache are always named after the cache's original name, so that
+    /// name stays reserved while the cache exists, but stops being an alias for it once it's
+    /// renamed.
+    ///
+    /// # Invariants
+    /// - Each key here *must* be the name of a [`RecipeExpr::Cache`] in `expressions`.
+    /// - Each value here *must* be an alias for that same [`RecipeExpr::Cache`].
+    #[serde(default)]
+    renamed_caches: HashMap<Relation, Relation>,
 }
 
 impl ExprRegistry {
@@ -428,7 +442,8 @@ impl ExprRegistry {
         self.expressions.get(query_id)
     }
 
-    /// Returns the name of the given expression if it is present in the recipe.
+    /// Returns the name of the given expression if it is present in the recipe, which for caches
+    /// that have been renamed is their current name.
     pub(super) fn expression_name<E>(&self, expression: E) -> Option<Relation>
     where
         E: Into<ExprId>,
@@ -436,9 +451,8 @@ impl ExprRegistry {
         self.expressions
             .get(&expression.into())
             .map(|exp| match exp {
-                RecipeExpr::View { name, .. }
-                | RecipeExpr::Cache { name, .. }
-                | RecipeExpr::Table { name, .. } => name.clone(),
+                RecipeExpr::Cache { name, .. } => self.current_name(name).clone(),
+                RecipeExpr::View { name, .. } | RecipeExpr::Table { name, .. } => name.clone(),
             })
     }
 
@@ -451,6 +465,86 @@ impl ExprRegistry {
             .map(|query_id| self.expressions[query_id].name())
     }
 
+    /// Sets each of the given [`CacheOptions`] on the cache with the given name (or alias),
+    /// leaving any options not given unchanged. Returns the previous options, or `None` if there is
+    /// no such cache.
+    pub(super) fn set_cache_options(
+        &mut self,
+        name_or_alias: &Relation,
+        new_options: CacheOptions,
+    ) -> Option<CacheOptions> {
+        let query_id = self.aliases.get(name_or_alias)?;
+        match self.expressions.get_mut(query_id)? {
+            RecipeExpr::Cache { options, .. } => {
+                let previous = options.clone();
+                options.update(new_options);
+                Some(previous)
+            }
+            _ => None,
+        }
+    }
+
+    /// Sets whether the cache with the given name (or alias) should always be served from
+    /// ReadySet, even inside of transactions. Returns the previous value, or `None` if there is no
+    /// such cache.
+    pub(super) fn set_cache_always(
+        &mut self,
+        name_or_alias: &Relation,
+        new_always: bool,
+    ) -> Option<bool> {
+        let query_id = self.aliases.get(name_or_alias)?;
+        match self.expressions.get_mut(query_id)? {
+            RecipeExpr::Cache { always, .. } => Some(mem::replace(always, new_always)),
+            _ => None,
+        }
+    }
+
+    /// Renames the cache with the given name (or alias) to `new_name`.
+    ///
+    /// The name the cache is renamed from stops being an alias for it. The cache's original name
+    /// continues to name its dataflow nodes, so no other expression can take that name until the
+    /// cache is dropped.
+    ///
+    /// # Errors
+    ///
+    /// - [`ReadySetError::ViewNotFound`] if there is no cache with the given name or alias
+    /// - [`ReadySetError::RecipeInvariantViolated`] if `new_name` is already the name of a
+    ///   different expression, or the original name of a different renamed cache
+    pub(super) fn rename_cache(
+        &mut self,
+        name_or_alias: &Relation,
+        new_name: Relation,
+    ) -> ReadySetResult<()> {
+        let not_found =
+            || ReadySetError::ViewNotFound(name_or_alias.display_unquoted().to_string());
+        let query_id = *self.aliases.get(name_or_alias).ok_or_else(not_found)?;
+        let original_name = match self.expressions.get(&query_id) {
+            Some(RecipeExpr::Cache { name, .. }) => name.clone(),
+            _ => return Err(not_found()),
+        };
+
+        self.assign_alias(new_name.clone(), query_id)?;
+        if *name_or_alias != new_name {
+            self.aliases.remove(name_or_alias);
+        }
+        if new_name == original_name {
+            self.renamed_caches.remove(&original_name);
+        } else {
+            self.renamed_caches.insert(original_name, new_name);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the current name of the cache with the given original name, which is different from
+    /// its original name if the cache has been renamed. Names which aren't the original name of a
+    /// renamed cache are returned unchanged.
+    pub(super) fn current_name<'a>(&'a self, original_name: &'a Relation) -> &'a Relation {
+        self.renamed_caches
+            .get(original_name)
+            .unwrap_or(original_name)
+    }
+
     /// Returns an iterator over all *original names* for all caches in the recipe (not including
     /// aliases)
     pub(super) fn cache_names(&self) -> impl Iterator<Item = &Relation> + '_ {
@@ -463,8 +557,15 @@ impl ExprRegistry {
     /// Removes the [`RecipeExpr`] associated with the given name (or alias), if
     /// it exists, and all the [`RecipeExpr`]s that depend on it.
     /// Returns the removed [`RecipeExpr`] if it was present, or `None` otherwise.
+    ///
+    /// Since expressions are removed from the recipe by their original name, the original name of
+    /// a renamed cache is accepted here too. Callers removing a cache by a user-provided name must
+    /// resolve it with [`ExprRegistry::resolve_alias`] first.
     pub(super) fn remove_expression(&mut self, name_or_alias: &Relation) -> Option<RecipeExpr> {
-        let query_id = *self.aliases.get(name_or_alias)?;
+        let query_id = *self
+            .aliases
+            .get(name_or_alias)
+            .or_else(|| self.aliases.get(self.renamed_caches.get(name_or_alias)?))?;
 
         // Remove all aliases for this query
         let expr_aliases = self
@@ -473,10 +574,15 @@ impl ExprRegistry {
             .filter(|&(_, &v)| v == query_id)
             .map(|(k, _)| k.clone())
             .collect::<Vec<_>>();
-        let expr_aliases = expr_aliases
+        let mut expr_aliases = expr_aliases
             .iter()
             .map(|k| self.aliases.remove_entry(k).unwrap().0)
             .collect::<HashSet<_>>();
+        // A renamed cache's original name is no longer an alias for it, but still names the cache
+        // in `skeletons` and `reused_caches`
+        if let Some(RecipeExpr::Cache { name, .. }) = self.expressions.get(&query_id) {
+            expr_aliases.insert(name.clone());
+        }
 
         // Remove any queries that reuse this query's cache, or the query itself if we are removing
         // a query that reuses a cache.
@@ -516,6 +622,9 @@ impl ExprRegistry {
         for deps in self.custom_type_dependencies.values_mut() {
             deps.remove(&query_id);
         }
+        let aliases = &self.aliases;
+        self.renamed_caches
+            .retain(|_, current_name| aliases.contains_key(current_name));
 
         // If we have only removed a reused cache, there is nothing else to clean up because the
         // expression does not exist in the graph.
@@ -624,6 +733,15 @@ impl ExprRegistry {
     }
 
     fn assign_alias(&mut self, alias: Relation, expr_id: ExprId) -> ReadySetResult<()> {
+        if let Some(current_name) = self.renamed_caches.get(&alias) {
+            if self.aliases.get(current_name) != Some(&expr_id) {
+                return Err(ReadySetError::RecipeInvariantViolated(format!(
+                    "Name {} is still used internally by cache {}, which was renamed from it",
+                    alias.display_unquoted(),
+                    current_name.display_unquoted()
+                )));
+            }
+        }
         match self.aliases.entry(alias.clone()) {
             Entry::Occupied(e) => {
                 if *e.get() != expr_id {
@@ -691,7 +809,7 @@ impl TryFrom<CreateViewStatement> for RecipeExpr {
 
 #[cfg(test)]
 mod tests {
-    use nom_sql::Dialect;
+    use nom_sql::{Dialect, EvictionPriority};
 
     use super::*;
 
@@ -701,6 +819,7 @@ mod tests {
         RecipeExpr::Cache {
             name: name.into(),
             always: false,
+            options: Default::default(),
             query_id: QueryId::from_select(&statement, &[]),
             statement,
         }
@@ -975,6 +1094,145 @@ mod tests {
             assert!(registry.get(&"test_query_alias".into()).is_none())
         }
 
+        #[test]
+        fn set_cache_options() {
+            let mut registry = setup();
+            let options = CacheOptions {
+                memory_limit: Some(1 << 20),
+                ..Default::default()
+            };
+
+            let previous = registry
+                .set_cache_options(&"test_query_alias".into(), options.clone())
+                .unwrap();
+            assert_eq!(previous, CacheOptions::default());
+            match registry.get(&"test_query".into()).unwrap() {
+                RecipeExpr::Cache {
+                    options: new_options,
+                    ..
+                } => assert_eq!(*new_options, options),
+                _ => panic!("Expected a cache"),
+            }
+
+            // Options which aren't given are left unchanged
+            registry
+                .set_cache_options(
+                    &"test_query".into(),
+                    CacheOptions {
+                        eviction_priority: Some(EvictionPriority::High),
+                        ..Default::default()
+                    },
+                )
+                .unwrap();
+            match registry.get(&"test_query".into()).unwrap() {
+                RecipeExpr::Cache {
+                    options: new_options,
+                    ..
+                } => {
+                    assert_eq!(new_options.memory_limit, Some(1 << 20));
+                    assert_eq!(new_options.eviction_priority, Some(EvictionPriority::High));
+                }
+                _ => panic!("Expected a cache"),
+            }
+
+            assert!(registry
+                .set_cache_options(&"test_view".into(), options)
+                .is_none());
+        }
+
+        #[test]
+        fn set_cache_always() {
+            let mut registry = setup();
+            assert_eq!(
+                registry.set_cache_always(&"test_query_alias".into(), true),
+                Some(false)
+            );
+            match registry.get(&"test_query".into()).unwrap() {
+                RecipeExpr::Cache { always, .. } => assert!(*always),
+                _ => panic!("Expected a cache"),
+            }
+            assert!(registry
+                .set_cache_always(&"test_view".into(), true)
+                .is_none());
+        }
+
+        #[test]
+        fn rename_cache() {
+            let mut registry = setup();
+            let stmt = parse_select_statement(Dialect::MySQL, "SELECT * FROM test_table").unwrap();
+
+            registry
+                .rename_cache(&"test_query".into(), "renamed".into())
+                .unwrap();
+            assert_eq!(registry.expression_name(&stmt), Some("renamed".into()));
+            // The original name still names the cache's dataflow nodes, but is no longer an alias
+            // for it
+            assert_eq!(
+                registry.resolve_alias(&"renamed".into()),
+                Some(&"test_query".into())
+            );
+            assert_eq!(
+                registry.current_name(&"test_query".into()),
+                &Relation::from("renamed")
+            );
+            assert!(registry.get(&"test_query".into()).is_none());
+            assert!(registry.resolve_alias(&"test_query".into()).is_none());
+
+            // The original name stays reserved for the cache's dataflow nodes
+            let err = registry
+                .add_query(recipe_expr_cache(
+                    "test_query",
+                    "SELECT col1 FROM test_table;",
+                ))
+                .unwrap_err();
+            assert!(err.to_string().contains("renamed"), "{err}");
+
+            // Renaming again removes the intermediate name
+            registry
+                .rename_cache(&"renamed".into(), "renamed_again".into())
+                .unwrap();
+            assert!(registry.get(&"renamed".into()).is_none());
+            assert_eq!(
+                registry.expression_name(&stmt),
+                Some("renamed_again".into())
+            );
+
+            // Can't rename to the name of another expression, or rename something that isn't a
+            // cache
+            registry
+                .rename_cache(&"renamed_again".into(), "test_view".into())
+                .unwrap_err();
+            registry
+                .rename_cache(&"test_view".into(), "new_view".into())
+                .unwrap_err();
+
+            // Renaming back to the original name makes it an alias again
+            registry
+                .rename_cache(&"renamed_again".into(), "test_query".into())
+                .unwrap();
+            assert!(registry.renamed_caches.is_empty());
+            assert!(registry.get(&"renamed_again".into()).is_none());
+            registry
+                .rename_cache(&"test_query".into(), "renamed_again".into())
+                .unwrap();
+
+            registry.remove_expression(&"renamed_again".into()).unwrap();
+            assert!(registry.renamed_caches.is_empty());
+        }
+
+        #[test]
+        fn remove_renamed_cache_by_original_name() {
+            let mut registry = setup();
+            registry
+                .rename_cache(&"test_query".into(), "renamed".into())
+                .unwrap();
+
+            // Dropping the table drops the renamed cache along with it
+            registry.remove_expression(&"test_table".into()).unwrap();
+            assert!(registry.get(&"renamed".into()).is_none());
+            assert!(registry.renamed_caches.is_empty());
+        }
+
         #[test]
         fn remove_view() {
             let mut registry = setup();
@@ -1125,6 +1383,7 @@ mod tests {
                     query_id: QueryId::from_select(&statement, &[]),
                     statement: statement.clone(),
                     always: false,
+                    options: Default::default(),
                 })
                 .unwrap());
 
@@ -12,12 +12,13 @@
 //! This module provides the structures to store the state of the ReadySet dataflow graph, and
 //! to manipulate it in a thread-safe way.
 
-use std::cell;
 use std::collections::{BTreeMap, HashMap, HashSet};
 use std::net::SocketAddr;
 use std::ops::Deref;
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Instant;
+use std::{cell, fs};
 
 use array2::Array2;
 use common::{IndexPair, Tag};
@@ -31,15 +32,17 @@ use failpoint_macros::set_failpoint;
 use futures::stream::{self, FuturesUnordered, StreamExt, TryStreamExt};
 use futures::{FutureExt, TryFutureExt, TryStream};
 use metrics::{gauge, histogram};
-use nom_sql::{NonReplicatedRelation, Relation, SqlIdentifier};
-use petgraph::visit::{Bfs, IntoNodeReferences};
+use nom_sql::{CacheOptions, NonReplicatedRelation, Relation, SqlIdentifier};
+use petgraph::visit::{Bfs, IntoNodeReferences, Reversed};
 use petgraph::Direction;
 use rand::Rng;
 use readyset_client::builders::{
     ReaderHandleBuilder, ReusedReaderHandleBuilder, TableBuilder, ViewBuilder,
 };
 use readyset_client::consensus::{Authority, AuthorityControl};
-use readyset_client::debug::info::{GraphInfo, MaterializationInfo, NodeSize};
+use readyset_client::debug::info::{
+    GraphInfo, MaterializationInfo, NodeMaterializedSize, NodeSize,
+};
 use readyset_client::debug::stats::{DomainStats, GraphStats, NodeStats};
 #[cfg(feature = "failure_injection")]
 use readyset_client::failpoints;
@@ -86,6 +89,20 @@ pub(in crate::controller) use self::graphviz::Graphviz;
 /// for replication offsets)
 const CONCURRENT_REQUESTS: usize = 16;
 
+/// Name of the file, in a directory created by [`DfState::checkpoint_base_tables`], containing the
+/// dataflow state that the base tables in that directory were checkpointed with
+pub(crate) const CHECKPOINT_STATE_FILE: &str = "dataflow_state";
+
+/// Returns the path that [`CHECKPOINT_STATE_FILE`] is restored to along with the base tables in a
+/// checkpoint, for a server storing its base tables according to `persistence`
+pub(super) fn restored_checkpoint_state_path(persistence: &PersistenceParameters) -> PathBuf {
+    persistence
+        .storage_dir
+        .clone()
+        .unwrap_or_else(|| ".".into())
+        .join(CHECKPOINT_STATE_FILE)
+}
+
 /// This structure holds all the dataflow state.
 /// It's meant to be handled exclusively by the [`DfStateHandle`], which is the structure
 /// that guarantees thread-safe access to it.
@@ -275,9 +292,11 @@ impl DfState {
 
                     // Alias should always resolve to an id and id should always resolve to an
                     // expression. However, this mapping will not catch bugs that break this
-                    // assumption
-                    let name = self.recipe.resolve_alias(&name)?;
-                    let query = self.recipe.expression_by_alias(name)?;
+                    // assumption. Readers are named after their cache's original name, which is
+                    // no longer an alias once the cache has been renamed.
+                    let query = self
+                        .recipe
+                        .expression_by_alias(self.recipe.current_name(&name))?;
 
                     // Only return ingredients created from "CREATE CACHE"
                     match query {
@@ -285,11 +304,13 @@ impl DfState {
                             name,
                             statement,
                             always,
+                            options,
                             query_id,
                         } => Some(CacheExpr {
-                            name,
+                            name: self.recipe.current_name(&name).clone(),
                             statement,
                             always,
+                            options,
                             query_id,
                         }),
                         _ => None,
@@ -301,6 +322,49 @@ impl DfState {
             .collect()
     }
 
+    /// Returns the [`CacheOptions`] of the cache for each reader in the graph, keyed by the name
+    /// of the reader
+    fn reader_cache_options(&self) -> HashMap<Relation, CacheOptions> {
+        self.ingredients
+            .externals(petgraph::EdgeDirection::Outgoing)
+            .filter_map(|n| {
+                #[allow(clippy::indexing_slicing)] // just came from self.ingredients
+                let node = &self.ingredients[n];
+                if !node.is_reader() {
+                    return None;
+                }
+                let options = self.recipe.cache_options(node.name())?;
+                Some((node.name().clone(), options.clone()))
+            })
+            .collect()
+    }
+
+    /// Returns the approximate size of the materialized state of the reader for each cache,
+    /// keyed by the name of the cache
+    pub(super) async fn cache_memory_usage(
+        &self,
+    ) -> ReadySetResult<HashMap<Relation, NodeMaterializedSize>> {
+        let node_sizes = self.node_sizes().await?;
+        Ok(self
+            .ingredients
+            .externals(petgraph::EdgeDirection::Outgoing)
+            .filter_map(|n| {
+                #[allow(clippy::indexing_slicing)] // just came from self.ingredients
+                let node = &self.ingredients[n];
+                if !node.is_reader() {
+                    return None;
+                }
+                let name = self
+                    .recipe
+                    .resolve_alias(self.recipe.current_name(node.name()))?;
+                Some((
+                    self.recipe.current_name(name).clone(),
+                    node_sizes.get(&n)?.bytes,
+                ))
+            })
+            .collect())
+    }
+
     pub(super) fn view_names(
         &self,
         queries: Vec<ViewCreateRequest>,
@@ -427,6 +491,16 @@ impl DfState {
             })
             .collect::<ReadySetResult<Vec<_>>>()?;
 
+        let max_staleness = self
+            .recipe
+            .cache_options(name)
+            .and_then(|options| options.max_staleness);
+        let base_tables = if max_staleness.is_some() {
+            self.base_tables_for(reader_node)
+        } else {
+            vec![]
+        };
+
         Ok(Some(ReaderHandleBuilder {
             name: name.clone(),
             node: reader_node,
@@ -435,9 +509,25 @@ impl DfState {
             replica_shard_addrs: Array2::from_rows(replicas),
             key_mapping,
             view_request_timeout: self.domain_config.view_request_timeout,
+            max_staleness,
+            base_tables,
         }))
     }
 
+    /// Returns the local addresses of all the base tables that the given node reads from
+    fn base_tables_for(&self, node: NodeIndex) -> Vec<LocalNodeIndex> {
+        let mut base_tables = vec![];
+        let mut bfs = Bfs::new(Reversed(&self.ingredients), node);
+        while let Some(ancestor) = bfs.next(Reversed(&self.ingredients)) {
+            #[allow(clippy::indexing_slicing)] // just came from self.ingredients
+            let ancestor = &self.ingredients[ancestor];
+            if ancestor.is_base() {
+                base_tables.push(ancestor.local_addr());
+            }
+        }
+        base_tables
+    }
+
     /// Obtain a `ViewBuilder` that can be sent to a client and then used to query a given
     /// (already maintained) reader node called `name`.
     pub(super) fn view_builder(
@@ -902,11 +992,68 @@ impl DfState {
     /// See [the documentation for PersistentState](::readyset_dataflow::state::persistent_state)
     /// for more information about replication offsets.
     pub(super) async fn replication_offsets(&self) -> ReadySetResult<ReplicationOffsets> {
+        self.query_base_table_offsets(DomainRequest::RequestReplicationOffsets)
+            .await
+    }
+
+    /// Creates a consistent RocksDB checkpoint of every base table in the system in `dir`, which
+    /// is interpreted relative to the filesystem of the worker running each base table. Returns
+    /// the replication offsets of each checkpoint, along with the replication offset for the
+    /// schema stored in the controller.
+    ///
+    /// The dataflow state itself (including the recipe and the schema replication offset) is
+    /// written to [`CHECKPOINT_STATE_FILE`] in `dir` on the controller's filesystem, so that a
+    /// server restored from the checkpoint can resume replication without snapshotting again.
+    ///
+    /// See [`PersistentState::create_checkpoint`] for more information.
+    ///
+    /// [`PersistentState::create_checkpoint`]: readyset_dataflow::PersistentState::create_checkpoint
+    pub(super) async fn checkpoint_base_tables(
+        &self,
+        dir: PathBuf,
+    ) -> ReadySetResult<ReplicationOffsets> {
+        let offsets = self
+            .query_base_table_offsets(DomainRequest::CheckpointBaseTables { dir: dir.clone() })
+            .await?;
+
+        // Save the same subset of the state we'd save to the authority
+        let mut state = self.clone();
+        state.touch_up();
+        fs::create_dir_all(&dir)?;
+        fs::write(dir.join(CHECKPOINT_STATE_FILE), rmp_serde::to_vec(&state)?)?;
+
+        Ok(offsets)
+    }
+
+    /// Load the dataflow state saved by [`checkpoint_base_tables`] in a checkpoint that was
+    /// restored into the storage directory of `persistence`, if any.
+    ///
+    /// The returned state will create its base tables with `persistence`, so that they open the
+    /// restored databases.
+    ///
+    /// [`checkpoint_base_tables`]: Self::checkpoint_base_tables
+    pub(super) fn restored_from_checkpoint(
+        persistence: &PersistenceParameters,
+    ) -> ReadySetResult<Option<Self>> {
+        let path = restored_checkpoint_state_path(persistence);
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let mut state: Self = rmp_serde::from_slice(&fs::read(&path)?)?;
+        state.persistence = persistence.clone();
+        Ok(Some(state))
+    }
+
+    /// Sends the given `request` to all domains with base tables, and collects the replication
+    /// offsets for each base table returned by those domains
+    async fn query_base_table_offsets(
+        &self,
+        request: DomainRequest,
+    ) -> ReadySetResult<ReplicationOffsets> {
         let domains = self.domains_with_base_tables().await?;
         self.query_domains::<_, NodeMap<BaseTableState<Option<ReplicationOffset>>>>(
-            domains
-                .into_iter()
-                .map(|domain| (domain, DomainRequest::RequestReplicationOffsets)),
+            domains.into_iter().map(|domain| (domain, request.clone())),
         )
         .try_fold(
             ReplicationOffsets::with_schema_offset(self.schema_replication_offset.clone()),
@@ -1624,6 +1771,52 @@ impl DfState {
         Ok(1)
     }
 
+    /// Sets each of the given [`CacheOptions`] on the cache with the given name, and informs all
+    /// workers about the cache's new memory limit and eviction priority.
+    pub(super) async fn set_cache_options(
+        &mut self,
+        name: &Relation,
+        options: CacheOptions,
+    ) -> ReadySetResult<()> {
+        self.recipe.set_cache_options(name, options)?;
+        self.send_cache_options_to_workers().await;
+        Ok(())
+    }
+
+    /// Renames the cache with the given name to `new_name`, keeping all of its materialized state
+    pub(super) fn rename_cache(
+        &mut self,
+        name: &Relation,
+        new_name: Relation,
+    ) -> ReadySetResult<()> {
+        self.recipe.rename_cache(name, new_name)
+    }
+
+    /// Sets whether the cache with the given name should always be served from ReadySet, even
+    /// inside of transactions
+    pub(super) fn set_cache_always(&mut self, name: &Relation, always: bool) -> ReadySetResult<()> {
+        self.recipe.set_cache_always(name, always)?;
+        Ok(())
+    }
+
+    /// Sends the [`CacheOptions`] for every reader in the graph to all workers, which use them to
+    /// enforce per-cache memory limits and eviction priorities.
+    pub(super) async fn send_cache_options_to_workers(&self) {
+        let cache_options = self.reader_cache_options();
+        for (address, w) in self.workers.iter() {
+            if let Err(error) = w
+                .rpc::<()>(WorkerRequestKind::SetCacheOptions(cache_options.clone()))
+                .await
+            {
+                error!(
+                    %address,
+                    %error,
+                    "Could not send cache options to worker",
+                );
+            }
+        }
+    }
+
     pub(super) async fn remove_all_queries(&mut self) -> ReadySetResult<()> {
         let changes = self
             .recipe
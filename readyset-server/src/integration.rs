@@ -27,11 +27,12 @@ use dataflow::{
 use futures::{join, StreamExt};
 use itertools::Itertools;
 use nom_sql::{
-    parse_create_table, parse_create_view, parse_query, parse_select_statement, OrderType,
-    Relation, SqlQuery,
+    parse_create_table, parse_create_view, parse_query, parse_select_statement, CacheOptions,
+    OrderType, Relation, SqlQuery,
 };
 use readyset_client::consensus::{Authority, LocalAuthority, LocalAuthorityStore};
 use readyset_client::consistency::Timestamp;
+use readyset_client::debug::info::NodeMaterializedSize;
 use readyset_client::internal::LocalNodeIndex;
 use readyset_client::recipe::changelist::{Change, ChangeList, CreateCache};
 use readyset_client::{KeyComparison, Modification, SchemaType, ViewPlaceholder, ViewQuery};
@@ -39,6 +40,8 @@ use readyset_data::{Bound, DfType, DfValue, Dialect, IntoBoundedRange};
 use readyset_errors::ReadySetError::{self, RpcFailed, SelectQueryCreationFailed};
 use readyset_util::eventually;
 use readyset_util::shutdown::ShutdownSender;
+use replication_offset::mysql::MySqlPosition;
+use replication_offset::ReplicationOffset;
 use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
 use rusty_fork::rusty_fork_test;
@@ -186,14 +189,281 @@ async fn test_timestamp_propagation_simple() {
         cq.raw_lookup(ViewQuery::from((
             vec![KeyComparison::Equal(vec1![id.clone()])],
             false,
-            // The timestamp at the reader node { 0: 4 }, does not
+            // The timestamp at the reader node { 0: 1 }, does not
             // satisfy this timestamp.
-            Some(timestamp(vec![(1, 4)]))
+            Some(timestamp(vec![(0, 4)]))
         )))
         .await,
         Err(ReadySetError::ReaderMissingKey)
     ));
 
+    // Entries for base tables the reader doesn't read from can never be satisfied by the reader,
+    // and so are ignored.
+    let res = cq
+        .raw_lookup(ViewQuery::from((
+            vec![KeyComparison::Equal(vec1![id.clone()])],
+            false,
+            Some(timestamp(vec![(0, 1), (1, 4)])),
+        )))
+        .await
+        .unwrap()
+        .into_vec();
+    assert_eq!(res, vec![vec![id.clone(), value.clone()]]);
+
+    shutdown_tx.shutdown().await;
+}
+
+// Tests that a blocking read with a timestamp the reader can't yet satisfy waits until the
+// reader's timestamp catches up, rather than returning stale results.
+#[tokio::test(flavor = "multi_thread")]
+async fn test_timestamp_blocking_read_waits() {
+    let (mut g, shutdown_tx) = start_simple_unsharded("test_timestamp_blocking_read_waits").await;
+
+    let a = g
+        .migrate(|mig| {
+            let a = mig.add_base(
+                "a",
+                make_columns(&["a", "b"]),
+                Base::new().with_primary_key([0]),
+            );
+            mig.maintain_anonymous(a, &Index::hash_map(vec![0]));
+            a
+        })
+        .await;
+
+    let mut cq = g.view("a").await.unwrap().into_reader_handle().unwrap();
+    let mut muta = g.table_by_index(a).await.unwrap();
+
+    muta.insert(vec![DfValue::Int(1), DfValue::Int(2)])
+        .await
+        .unwrap();
+    muta.update_timestamp(timestamp(vec![(0, 1)]))
+        .await
+        .unwrap();
+
+    let read = tokio::spawn(async move {
+        cq.raw_lookup(ViewQuery::from((
+            vec![KeyComparison::Equal(vec1![DfValue::Int(1)])],
+            true,
+            Some(timestamp(vec![(0, 2)])),
+        )))
+        .await
+        .unwrap()
+        .into_vec()
+    });
+
+    sleep().await;
+    assert!(!read.is_finished());
+
+    muta.update(
+        vec![DfValue::Int(1)],
+        vec![(1, Modification::Set(3.into()))],
+    )
+    .await
+    .unwrap();
+    muta.update_timestamp(timestamp(vec![(0, 2)]))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        read.await.unwrap(),
+        vec![vec![DfValue::Int(1), DfValue::Int(3)]]
+    );
+
+    shutdown_tx.shutdown().await;
+}
+
+// Tests that views for caches created with a max_staleness can build timestamps over all the base
+// tables they read from, which reads from a reader that is too far behind fail to satisfy.
+#[tokio::test(flavor = "multi_thread")]
+async fn view_with_max_staleness() {
+    let (mut g, shutdown_tx) = start_simple_unsharded("view_with_max_staleness").await;
+    let sql = "
+        CREATE TABLE t1 (id int, PRIMARY KEY(id));
+        CREATE TABLE t2 (id int, t1_id int, PRIMARY KEY(id));
+        CREATE TABLE t3 (id int, PRIMARY KEY(id));
+        CREATE CACHE q WITH (max_staleness = '5s') FROM
+            SELECT t2.id FROM t1 JOIN t2 ON t1.id = t2.t1_id WHERE t1.id = ?;
+        CREATE CACHE unbounded FROM SELECT id FROM t3 WHERE id = ?;
+    ";
+    g.extend_recipe(ChangeList::from_str(sql, Dialect::DEFAULT_MYSQL).unwrap())
+        .await
+        .unwrap();
+
+    assert_eq!(g.view("unbounded").await.unwrap().max_staleness(), None);
+
+    let mut q = g.view("q").await.unwrap().into_reader_handle().unwrap();
+    assert_eq!(q.max_staleness(), Some(Duration::from_secs(5)));
+
+    let mut t1 = g.table("t1").await.unwrap();
+    let mut t2 = g.table("t2").await.unwrap();
+    // t3 isn't read by q
+    assert_eq!(
+        q.timestamp_at(2).map,
+        HashMap::from([(t1.node, 2), (t2.node, 2)])
+    );
+
+    t1.insert(vec![DfValue::from(1)]).await.unwrap();
+    t2.insert(vec![DfValue::from(1), DfValue::from(1)])
+        .await
+        .unwrap();
+    for table in [&mut t1, &mut t2] {
+        let node = table.node;
+        table
+            .update_timestamp(Timestamp {
+                map: HashMap::from([(node, 1)]),
+            })
+            .await
+            .unwrap();
+    }
+
+    // Materialize the key first, so that the only miss below is due to the timestamp
+    let res = q
+        .lookup(&[DfValue::from(1)], true)
+        .await
+        .unwrap()
+        .into_vec();
+    assert_eq!(res, vec![vec![DfValue::from(1)]]);
+
+    let ticket = q.timestamp_at(1);
+    let res = q
+        .lookup_ryw(&[DfValue::from(1)], false, Some(ticket))
+        .await
+        .unwrap()
+        .into_vec();
+    assert_eq!(res, vec![vec![DfValue::from(1)]]);
+
+    let ticket = q.timestamp_at(2);
+    assert!(matches!(
+        q.lookup_ryw(&[DfValue::from(1)], false, Some(ticket)).await,
+        Err(ReadySetError::ReaderMissingKey)
+    ));
+
+    shutdown_tx.shutdown().await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn cache_memory_limit() {
+    // Check memory usage often, with a limit for the whole process that's never reached
+    let (mut g, shutdown_tx) = build(
+        "cache_memory_limit",
+        None,
+        Some((usize::MAX, Duration::from_millis(10))),
+    )
+    .await;
+    let sql = "
+        CREATE TABLE t (id int, val text, PRIMARY KEY(id));
+        CREATE CACHE limited WITH (memory_limit = '4KB') FROM SELECT val FROM t WHERE id = ?;
+        CREATE CACHE unlimited FROM SELECT id, val FROM t WHERE id = ?;
+    ";
+    g.extend_recipe(ChangeList::from_str(sql, Dialect::DEFAULT_MYSQL).unwrap())
+        .await
+        .unwrap();
+
+    let mut t = g.table("t").await.unwrap();
+    t.insert_many((0i32..200).map(|id| vec![DfValue::from(id), DfValue::from("a".repeat(100))]))
+        .await
+        .unwrap();
+
+    for name in ["limited", "unlimited"] {
+        let mut view = g.view(name).await.unwrap().into_reader_handle().unwrap();
+        for id in 0i32..200 {
+            view.lookup(&[DfValue::from(id)], true).await.unwrap();
+        }
+    }
+
+    let limit = NodeMaterializedSize(4096);
+    eventually!(run_test: {
+        g.cache_memory_usage().await.unwrap()
+    }, then_assert: |usage| {
+        assert!(usage[&Relation::from("limited")] <= limit);
+        assert!(usage[&Relation::from("unlimited")] > limit);
+    });
+
+    // Limits can be changed without recreating the cache
+    g.set_cache_options(
+        &"unlimited".into(),
+        &CacheOptions {
+            memory_limit: Some(4096),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+    eventually!(run_test: {
+        g.cache_memory_usage().await.unwrap()
+    }, then_assert: |usage| {
+        assert!(usage[&Relation::from("unlimited")] <= limit);
+    });
+
+    shutdown_tx.shutdown().await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn cache_memory_limit_spills_to_disk() {
+    readyset_tracing::init_test_logging();
+    let mut builder = Builder::for_tests();
+    builder.set_persistence(get_persistence_params("cache_memory_limit_spills_to_disk"));
+    builder.set_spill_partial_state(true);
+    builder.set_aggressively_update_state_sizes(true);
+    builder.set_memory_limit(usize::MAX, Duration::from_millis(10));
+    let (mut g, shutdown_tx) = builder.start_local().await.unwrap();
+
+    let sql = "
+        CREATE TABLE t (id int, val text, PRIMARY KEY(id));
+        CREATE CACHE limited WITH (memory_limit = '4KB') FROM SELECT val FROM t WHERE id = ?;
+    ";
+    g.extend_recipe(ChangeList::from_str(sql, Dialect::DEFAULT_MYSQL).unwrap())
+        .await
+        .unwrap();
+
+    // Few enough keys that tracking all of them on disk fits in the limit, but with rows too big
+    // for more than one of them to fit in memory
+    let mut t = g.table("t").await.unwrap();
+    t.insert_many((0i32..20).map(|id| vec![DfValue::from(id), DfValue::from("a".repeat(4096))]))
+        .await
+        .unwrap();
+
+    let mut view = g
+        .view("limited")
+        .await
+        .unwrap()
+        .into_reader_handle()
+        .unwrap();
+    for id in 0i32..20 {
+        view.lookup(&[DfValue::from(id)], true).await.unwrap();
+    }
+
+    let limit = NodeMaterializedSize(4096);
+    eventually!(run_test: {
+        g.cache_memory_usage().await.unwrap()
+    }, then_assert: |usage| {
+        assert!(usage[&Relation::from("limited")] <= limit);
+    });
+
+    // Keys demoted to disk are still served without an upquery
+    for id in 0i32..20 {
+        let res = view
+            .lookup(&[DfValue::from(id)], false)
+            .await
+            .unwrap()
+            .into_vec();
+        assert_eq!(res, vec![vec![DfValue::from("a".repeat(4096))]]);
+    }
+
+    // And writes to them are still applied
+    t.update(
+        vec![DfValue::from(0)],
+        vec![(1, Modification::Set("b".into()))],
+    )
+    .await
+    .unwrap();
+    eventually!(run_test: {
+        view.lookup(&[DfValue::from(0)], false).await.unwrap().into_vec()
+    }, then_assert: |res| {
+        assert_eq!(res, vec![vec![DfValue::from("b")]]);
+    });
+
     shutdown_tx.shutdown().await;
 }
 
@@ -1377,6 +1647,93 @@ async fn it_recovers_persisted_bases() {
     shutdown_tx.shutdown().await;
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn it_restores_base_tables_from_checkpoint() {
+    let dir = tempfile::tempdir().unwrap();
+    let checkpoint_dir = dir.path().join("checkpoint");
+    let persistence_params = |storage_dir: &str| {
+        PersistenceParameters::new(
+            DurabilityMode::Permanent,
+            Some("it_restores_base_tables_from_checkpoint".into()),
+            1,
+            Some(dir.path().join(storage_dir)),
+            0,
+        )
+    };
+    let sql = "
+        CREATE TABLE Car (id int, price int, PRIMARY KEY(id));
+        CREATE CACHE CarPrice FROM SELECT price FROM Car WHERE id = ?;
+    ";
+    let schema_offset: ReplicationOffset =
+        MySqlPosition::from_file_name_and_position("binlog.000001".into(), 4)
+            .unwrap()
+            .into();
+
+    {
+        let mut g = Builder::for_tests();
+        g.set_persistence(persistence_params("original"));
+        let (mut g, shutdown_tx) = g.start_local().await.unwrap();
+        g.backend_ready().await;
+        g.extend_recipe(ChangeList::from_str(sql, Dialect::DEFAULT_MYSQL).unwrap())
+            .await
+            .unwrap();
+
+        let mut mutator = g.table("Car").await.unwrap();
+        for i in 1..10 {
+            mutator
+                .insert(vec![i.into(), (i * 10).into()])
+                .await
+                .unwrap();
+        }
+        sleep().await;
+        g.set_schema_replication_offset(Some(&schema_offset))
+            .await
+            .unwrap();
+
+        let offsets = g
+            .checkpoint_base_tables(checkpoint_dir.clone())
+            .await
+            .unwrap();
+        assert_eq!(offsets.tables.len(), 1);
+        assert_eq!(offsets.schema, Some(schema_offset.clone()));
+
+        // Writes after the checkpoint shouldn't be restored
+        mutator.insert(vec![10.into(), 100.into()]).await.unwrap();
+        shutdown_tx.shutdown().await;
+    }
+
+    crate::restore_checkpoint(&checkpoint_dir, &dir.path().join("restored")).unwrap();
+
+    // The recipe and the schema replication offset are restored along with the base tables
+    let mut g = Builder::for_tests();
+    g.set_persistence(persistence_params("restored"));
+    let (mut g, shutdown_tx) = g.start_local().await.unwrap();
+    g.backend_ready().await;
+
+    let offsets = g.replication_offsets().await.unwrap();
+    assert_eq!(offsets.schema, Some(schema_offset));
+
+    let mut getter = g
+        .view("CarPrice")
+        .await
+        .unwrap()
+        .into_reader_handle()
+        .unwrap();
+    for i in 1..10 {
+        let result = getter.lookup(&[i.into()], true).await.unwrap().into_vec();
+        assert_eq!(result, vec![vec![DfValue::from(i * 10)]]);
+    }
+    let result = getter.lookup(&[10.into()], true).await.unwrap().into_vec();
+    assert!(result.is_empty());
+
+    shutdown_tx.shutdown().await;
+
+    // Restoring the same checkpoint again, as happens if the server restarts with the same
+    // options, does nothing, but restoring a different one fails
+    crate::restore_checkpoint(&checkpoint_dir, &dir.path().join("restored")).unwrap();
+    crate::restore_checkpoint(&dir.path().join("other"), &dir.path().join("restored")).unwrap_err();
+}
+
 // TODO(ENG-860): Flaky test.
 #[tokio::test(flavor = "multi_thread")]
 async fn it_recovers_persisted_bases_with_volume_id() {
@@ -1539,7 +1896,7 @@ async fn mutator_churn() {
             mig.maintain_anonymous_with_reader_processing(
                 vc,
                 &Index::hash_map(vec![0]),
-                ReaderProcessing::new(None, None, Some(vec![0, 1]), None, None).unwrap(),
+                ReaderProcessing::new(None, None, Some(vec![0, 1]), None, None, None).unwrap(),
             );
             vote
         })
@@ -2703,7 +3060,7 @@ async fn cascading_replays_with_sharding() {
             mig.maintain_anonymous_with_reader_processing(
                 end,
                 &Index::hash_map(vec![0]),
-                ReaderProcessing::new(None, None, Some(vec![0, 1]), None, None).unwrap(),
+                ReaderProcessing::new(None, None, Some(vec![0, 1]), None, None, None).unwrap(),
             );
             (j, end)
         })
@@ -2832,7 +3189,7 @@ async fn full_aggregation_with_bogokey() {
             mig.maintain_anonymous_with_reader_processing(
                 agg,
                 &Index::hash_map(vec![0]),
-                ReaderProcessing::new(None, None, Some(vec![0, 1]), None, None).unwrap(),
+                ReaderProcessing::new(None, None, Some(vec![0, 1]), None, None, None).unwrap(),
             );
             agg
         })
@@ -3434,7 +3791,7 @@ async fn live_writes() {
             mig.maintain_anonymous_with_reader_processing(
                 vc,
                 &Index::hash_map(vec![0]),
-                ReaderProcessing::new(None, None, Some(vec![0, 1]), None, None).unwrap(),
+                ReaderProcessing::new(None, None, Some(vec![0, 1]), None, None, None).unwrap(),
             );
             (vote, vc)
         })
@@ -3477,7 +3834,7 @@ async fn live_writes() {
             mig.maintain_anonymous_with_reader_processing(
                 vc2,
                 &Index::hash_map(vec![0]),
-                ReaderProcessing::new(None, None, Some(vec![0, 1]), None, None).unwrap(),
+                ReaderProcessing::new(None, None, Some(vec![0, 1]), None, None, None).unwrap(),
             );
             vc2
         })
@@ -3675,7 +4032,8 @@ async fn finkelstein1982_queries() {
                         .unwrap();
                 }
                 SqlQuery::Select(stmt) => {
-                    inc.add_query(None, stmt, false, &[], mig).unwrap();
+                    inc.add_query(None, stmt, false, Default::default(), &[], mig)
+                        .unwrap();
                 }
                 _ => panic!("unexpected query type"),
             }
@@ -4067,6 +4425,254 @@ async fn union_all_basic() {
     shutdown_tx.shutdown().await;
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn intersect_and_except() {
+    use itertools::sorted;
+
+    let (mut g, shutdown_tx) = start_simple_unsharded("intersect_and_except").await;
+    g.extend_recipe(
+        ChangeList::from_str(
+            "CREATE TABLE twos (id INTEGER PRIMARY KEY);
+         CREATE TABLE threes (id INTEGER PRIMARY KEY);
+         CREATE VIEW twos_intersect_threes AS (SELECT id FROM twos) INTERSECT (SELECT id FROM threes);
+         CREATE VIEW twos_except_threes AS (SELECT id FROM twos) EXCEPT (SELECT id FROM threes);
+         CREATE CACHE intersected FROM SELECT id FROM twos_intersect_threes;
+         CREATE CACHE excepted FROM SELECT id FROM twos_except_threes;",
+            Dialect::DEFAULT_MYSQL,
+        )
+        .unwrap(),
+    )
+    .await
+    .unwrap();
+
+    let mut twos = g.table("twos").await.unwrap();
+    twos.insert_many((0..10).filter(|i: &i32| i % 2 == 0).map(|i| vec![i.into()]))
+        .await
+        .unwrap();
+
+    let mut threes = g.table("threes").await.unwrap();
+    threes
+        .insert_many((0..10).filter(|i: &i32| i % 3 == 0).map(|i| vec![i.into()]))
+        .await
+        .unwrap();
+
+    sleep().await;
+
+    let mut intersected = g
+        .view("intersected")
+        .await
+        .unwrap()
+        .into_reader_handle()
+        .unwrap();
+    let mut excepted = g
+        .view("excepted")
+        .await
+        .unwrap()
+        .into_reader_handle()
+        .unwrap();
+
+    macro_rules! ids {
+        ($view: expr) => {
+            sorted(
+                $view
+                    .lookup(&[0.into()], true)
+                    .await
+                    .unwrap()
+                    .into_vec()
+                    .iter()
+                    .map(|r| get_col!($view, r, "id", i32)),
+            )
+            .collect::<Vec<i32>>()
+        };
+    }
+
+    assert_eq!(ids!(intersected), vec![0, 6]);
+    assert_eq!(ids!(excepted), vec![2, 4, 8]);
+
+    // Deleting a row from the right side should remove it from the intersection, and add it back
+    // to the difference
+    threes.delete(vec![6.into()]).await.unwrap();
+    sleep().await;
+
+    assert_eq!(ids!(intersected), vec![0]);
+    assert_eq!(ids!(excepted), vec![2, 4, 6, 8]);
+
+    shutdown_tx.shutdown().await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn outer_joins() {
+    use itertools::sorted;
+
+    let (mut g, shutdown_tx) = start_simple_unsharded("outer_joins").await;
+    g.extend_recipe(
+        ChangeList::from_str(
+            "CREATE TABLE l (id INTEGER PRIMARY KEY);
+         CREATE TABLE r (id INTEGER PRIMARY KEY);
+         CREATE CACHE right_joined FROM SELECT l.id AS lid, r.id AS rid FROM l RIGHT JOIN r ON l.id = r.id;
+         CREATE CACHE cross_joined FROM SELECT l.id AS lid, r.id AS rid FROM l CROSS JOIN r;
+         CREATE CACHE full_joined FROM SELECT l.id AS lid, r.id AS rid FROM l FULL OUTER JOIN r ON l.id = r.id;",
+            Dialect::DEFAULT_MYSQL,
+        )
+        .unwrap(),
+    )
+    .await
+    .unwrap();
+
+    let mut l = g.table("l").await.unwrap();
+    l.insert_many(vec![vec![1.into()], vec![2.into()]])
+        .await
+        .unwrap();
+    let mut r = g.table("r").await.unwrap();
+    r.insert_many(vec![vec![2.into()], vec![3.into()]])
+        .await
+        .unwrap();
+
+    sleep().await;
+
+    let mut right_joined = g
+        .view("right_joined")
+        .await
+        .unwrap()
+        .into_reader_handle()
+        .unwrap();
+    let mut cross_joined = g
+        .view("cross_joined")
+        .await
+        .unwrap()
+        .into_reader_handle()
+        .unwrap();
+    let mut full_joined = g
+        .view("full_joined")
+        .await
+        .unwrap()
+        .into_reader_handle()
+        .unwrap();
+
+    macro_rules! rows {
+        ($view: expr) => {
+            sorted(
+                $view
+                    .lookup(&[0.into()], true)
+                    .await
+                    .unwrap()
+                    .into_vec()
+                    .iter()
+                    .map(|r| {
+                        (
+                            get_col!($view, r, "lid").clone(),
+                            get_col!($view, r, "rid").clone(),
+                        )
+                    }),
+            )
+            .collect::<Vec<(DfValue, DfValue)>>()
+        };
+    }
+
+    assert_eq!(
+        rows!(right_joined),
+        vec![(DfValue::None, 3.into()), (2.into(), 2.into())]
+    );
+    assert_eq!(
+        rows!(cross_joined),
+        vec![
+            (1.into(), 2.into()),
+            (1.into(), 3.into()),
+            (2.into(), 2.into()),
+            (2.into(), 3.into())
+        ]
+    );
+    assert_eq!(
+        rows!(full_joined),
+        vec![
+            (DfValue::None, 3.into()),
+            (1.into(), DfValue::None),
+            (2.into(), 2.into())
+        ]
+    );
+
+    // Adding a match for a row on either side should replace its NULL-padded row
+    l.insert(vec![3.into()]).await.unwrap();
+    r.delete(vec![2.into()]).await.unwrap();
+    sleep().await;
+
+    assert_eq!(
+        rows!(full_joined),
+        vec![
+            (1.into(), DfValue::None),
+            (2.into(), DfValue::None),
+            (3.into(), 3.into())
+        ]
+    );
+
+    shutdown_tx.shutdown().await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn join_against_multiple_tables() {
+    let (mut g, shutdown_tx) = start_simple_unsharded("join_against_multiple_tables").await;
+    g.extend_recipe(
+        ChangeList::from_str(
+            "CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT);
+         CREATE TABLE posts (id INTEGER PRIMARY KEY, author_id INTEGER, topic_id INTEGER);
+         CREATE TABLE topics (id INTEGER PRIMARY KEY, title TEXT);
+         CREATE CACHE post_details FROM
+         SELECT posts.id, users.name, topics.title
+         FROM posts JOIN (users, topics)
+         ON posts.author_id = users.id AND posts.topic_id = topics.id
+         WHERE posts.id = ?;",
+            Dialect::DEFAULT_MYSQL,
+        )
+        .unwrap(),
+    )
+    .await
+    .unwrap();
+
+    let mut users = g.table("users").await.unwrap();
+    users
+        .insert_many(vec![
+            vec![1.into(), "alice".into()],
+            vec![2.into(), "bob".into()],
+        ])
+        .await
+        .unwrap();
+    let mut topics = g.table("topics").await.unwrap();
+    topics
+        .insert_many(vec![
+            vec![1.into(), "rust".into()],
+            vec![2.into(), "sql".into()],
+        ])
+        .await
+        .unwrap();
+    let mut posts = g.table("posts").await.unwrap();
+    posts
+        .insert_many(vec![
+            vec![1.into(), 1.into(), 2.into()],
+            vec![2.into(), 2.into(), 1.into()],
+        ])
+        .await
+        .unwrap();
+
+    sleep().await;
+
+    let mut post_details = g
+        .view("post_details")
+        .await
+        .unwrap()
+        .into_reader_handle()
+        .unwrap();
+    let res = post_details
+        .lookup(&[1.into()], true)
+        .await
+        .unwrap()
+        .into_vec();
+    assert_eq!(res.len(), 1);
+    assert_eq!(get_col!(post_details, res[0], "name", String), "alice");
+    assert_eq!(get_col!(post_details, res[0], "title", String), "sql");
+
+    shutdown_tx.shutdown().await;
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn between() {
     let (mut g, shutdown_tx) = start_simple_unsharded("between_query").await;
@@ -5201,6 +5807,7 @@ async fn post_read_ilike() {
                     None,
                     None,
                     None,
+                    None,
                 )
                 .unwrap(),
             );
@@ -8839,6 +9446,7 @@ async fn multiple_simultaneous_migrations() {
                         .unwrap()
                 ),
                 always: false,
+                options: Default::default(),
             }),
             Dialect::DEFAULT_MYSQL
         )),
@@ -8850,6 +9458,7 @@ async fn multiple_simultaneous_migrations() {
                         .unwrap()
                 ),
                 always: false,
+                options: Default::default(),
             }),
             Dialect::DEFAULT_MYSQL
         ))
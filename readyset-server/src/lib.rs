@@ -434,15 +434,16 @@ pub mod manual {
     pub use crate::controller::migrate::Migration;
 }
 
+use std::fs;
 use std::net::{IpAddr, ToSocketAddrs};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 
 use anyhow::anyhow;
 use clap::Args;
-use dataflow::DomainConfig;
+use dataflow::{DomainConfig, PersistentState};
 use serde::{Deserialize, Serialize};
-use tracing::warn;
+use tracing::{info, warn};
 
 /// Configuration for a running ReadySet cluster
 // WARNING: if you change this structure or any of the structures used in its fields, make sure to
@@ -504,6 +505,8 @@ impl Default for Config {
                 table_request_timeout: Duration::from_millis(1800000),
                 eviction_kind: dataflow::EvictionKind::Random,
                 verbose_metrics: false,
+                spill_partial_state: false,
+                time_zone: None,
             },
             persistence: Default::default(),
             min_workers: 1,
@@ -520,6 +523,12 @@ impl Default for Config {
     }
 }
 
+/// Parse the given string as the name of a time zone in the IANA time zone database, such as
+/// `America/New_York`
+fn parse_time_zone(time_zone: &str) -> Result<chrono_tz::Tz, String> {
+    time_zone.parse().map_err(|e| format!("{e}"))
+}
+
 /// Parse and normalize the given string as an [`IpAddr`]
 pub fn resolve_addr(addr: &str) -> anyhow::Result<IpAddr> {
     Ok([addr, ":0"]
@@ -555,8 +564,8 @@ pub struct WorkerOptions {
     #[arg(long, short = 'm', default_value = "0", env = "READYSET_MEMORY_LIMIT")]
     pub memory_limit: usize,
 
-    /// Frequency at which to check the process heap allocation against the memory limit (in
-    /// seconds)
+    /// Frequency at which to check the process heap allocation against the memory limit, and the
+    /// state size of each cache against its own memory limit (in seconds)
     #[arg(
         long = "memory-check-every",
         default_value = "1",
@@ -569,6 +578,18 @@ pub struct WorkerOptions {
     #[arg(long = "eviction-policy", default_value_t = dataflow::EvictionKind::LRU, hide = true)]
     pub eviction_kind: dataflow::EvictionKind,
 
+    /// When freeing memory from partially materialized nodes, including the readers of caches,
+    /// spill keys to disk rather than evicting them entirely. Spilled keys can still be read
+    /// without an upquery, so this trades disk space for a smaller working set in memory.
+    #[arg(long, env = "SPILL_PARTIAL_STATE")]
+    pub spill_partial_state: bool,
+
+    /// The time zone of the upstream database, such as `America/New_York`, which should match its
+    /// `time_zone` (MySQL) or `TimeZone` (PostgreSQL) setting. Cached queries which compare
+    /// against `NOW()` evaluate it in this time zone. Defaults to the time zone ReadySet runs in.
+    #[arg(long, env = "UPSTREAM_TIME_ZONE", value_parser = parse_time_zone)]
+    pub upstream_time_zone: Option<chrono_tz::Tz>,
+
     /// Disable partial
     #[arg(long = "nopartial", hide = true)]
     pub no_partial: bool,
@@ -636,6 +657,14 @@ pub struct WorkerOptions {
     #[arg(long, env = "DB_DIR", conflicts_with = "storage_dir", hide = true)]
     db_dir: Option<PathBuf>,
 
+    /// Directory containing a checkpoint of base tables, created by the `checkpoint_base_tables`
+    /// controller request, to restore into the storage directory on startup. Replication will
+    /// resume from the offsets the base tables were checkpointed at, rather than snapshotting
+    /// those tables again. Once the checkpoint has been restored, it won't be restored again if
+    /// the server restarts with the same checkpoint directory.
+    #[arg(long, env = "RESTORE_FROM_CHECKPOINT")]
+    pub restore_from_checkpoint: Option<PathBuf>,
+
     #[command(flatten)]
     pub domain_replication_options: ReplicationOptions,
 
@@ -686,6 +715,96 @@ impl WorkerOptions {
 
         None
     }
+
+    /// If [`restore_from_checkpoint`](Self::restore_from_checkpoint) is set, restore the
+    /// checkpoint in that directory into `deployment_dir`. See [`restore_checkpoint`].
+    pub fn restore_checkpoint(&self, deployment_dir: &Path) -> anyhow::Result<()> {
+        match &self.restore_from_checkpoint {
+            Some(checkpoint_dir) => restore_checkpoint(checkpoint_dir, deployment_dir),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Name of the file, in a deployment directory that a checkpoint has been restored into, recording
+/// the directory of the checkpoint that was restored
+const RESTORED_CHECKPOINT_FILE: &str = "restored_checkpoint";
+
+/// Restore the base tables in the checkpoint in `checkpoint_dir`, created by the
+/// `checkpoint_base_tables` controller request, into `deployment_dir`, which must not already
+/// contain any of them.
+///
+/// The dataflow state saved with the checkpoint is restored alongside the base tables, and will be
+/// used by the next controller to start with that storage directory if there's no controller state
+/// in the authority yet. That controller will resume replication from the restored replication
+/// offsets, rather than snapshotting again.
+///
+/// Once the checkpoint has been restored, the directory it was restored from is recorded in
+/// `deployment_dir`, and restoring the same checkpoint again (for example when a server started
+/// with `--restore-from-checkpoint` restarts) does nothing.
+pub fn restore_checkpoint(checkpoint_dir: &Path, deployment_dir: &Path) -> anyhow::Result<()> {
+    let checkpoint = checkpoint_dir
+        .canonicalize()
+        .unwrap_or_else(|_| checkpoint_dir.to_path_buf());
+    let marker_file = deployment_dir.join(RESTORED_CHECKPOINT_FILE);
+    if marker_file.exists() {
+        let restored = PathBuf::from(fs::read_to_string(&marker_file)?);
+        if restored != checkpoint {
+            return Err(anyhow!(
+                "Failed to restore checkpoint from {}: {} has already been restored into {}",
+                checkpoint_dir.display(),
+                restored.display(),
+                deployment_dir.display()
+            ));
+        }
+        info!(
+            checkpoint = %checkpoint_dir.display(),
+            "Checkpoint has already been restored, not restoring it again"
+        );
+        return Ok(());
+    }
+
+    let state_file = deployment_dir.join(controller::CHECKPOINT_STATE_FILE);
+    if state_file.exists() {
+        return Err(anyhow!(
+            "Failed to restore checkpoint from {}: {} already exists",
+            checkpoint_dir.display(),
+            state_file.display()
+        ));
+    }
+
+    fs::create_dir_all(deployment_dir)?;
+    let restored =
+        PersistentState::restore_checkpoints(checkpoint_dir, deployment_dir).map_err(|e| {
+            anyhow!(
+                "Failed to restore checkpoint from {}: {e}",
+                checkpoint_dir.display()
+            )
+        })?;
+
+    let checkpoint_state_file = checkpoint_dir.join(controller::CHECKPOINT_STATE_FILE);
+    if checkpoint_state_file.exists() {
+        fs::copy(&checkpoint_state_file, &state_file)?;
+    } else {
+        warn!(
+            checkpoint = %checkpoint_dir.display(),
+            "Checkpoint contains no dataflow state, so replication will snapshot again"
+        );
+    }
+
+    // Write the marker last (and atomically), so that it only exists once everything else has been
+    // restored
+    let tmp_marker_file = marker_file.with_extension("tmp");
+    fs::write(&tmp_marker_file, checkpoint.to_string_lossy().as_bytes())?;
+    fs::rename(&tmp_marker_file, &marker_file)?;
+
+    info!(
+        checkpoint = %checkpoint_dir.display(),
+        num_tables = restored.len(),
+        "Restored base tables from checkpoint"
+    );
+
+    Ok(())
 }
 
 // TODO(justin): Change VolumeId type when we know this fixed size.
@@ -222,6 +222,7 @@ fn main() -> anyhow::Result<()> {
         .storage_dir()
         .unwrap_or_else(|| PathBuf::from("."))
         .join(&opts.deployment);
+    opts.worker_options.restore_checkpoint(&deployment_dir)?;
 
     let authority = opts.authority.clone();
     let authority_addr = match authority {
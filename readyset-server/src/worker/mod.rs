@@ -1,13 +1,13 @@
 use std::cmp;
 use std::collections::hash_map::Entry::{Occupied, Vacant};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::future::Future;
 use std::net::{IpAddr, SocketAddr};
 use std::pin::Pin;
-use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::task::{ready, Context, Poll};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use dataflow::payload::EvictRequest;
 use dataflow::{ChannelCoordinator, DomainBuilder, DomainRequest, Packet, Readers};
@@ -17,9 +17,10 @@ use futures_util::future::TryFutureExt;
 use futures_util::sink::SinkExt;
 use futures_util::stream::StreamExt;
 use metrics::{counter, gauge, histogram};
+use nom_sql::{CacheOptions, EvictionPriority, Relation};
 use pin_project::pin_project;
 use readyset_alloc::StdThreadBuildWrapper;
-use readyset_client::internal::ReplicaAddress;
+use readyset_client::internal::{LocalNodeIndex, ReplicaAddress};
 use readyset_client::metrics::recorded;
 use readyset_client::ReadySetHandle;
 use readyset_errors::{internal_err, ReadySetError, ReadySetResult};
@@ -96,6 +97,10 @@ pub enum WorkerRequestKind {
         /// The limit in bytes
         limit: Option<usize>,
     },
+
+    /// Set the [`CacheOptions`] used to enforce per-cache memory limits and eviction priorities,
+    /// keyed by the name of the reader for each cache.
+    SetCacheOptions(HashMap<Relation, CacheOptions>),
 }
 
 /// A request to a running ReadySet worker, containing a request kind and a completion channel.
@@ -206,8 +211,19 @@ pub struct Worker {
     election_state: Option<WorkerElectionState>,
     /// A timer for doing evictions.
     evict_interval: Option<Interval>,
+    /// Whether there's anything for `evict_interval` to enforce: a memory limit for the process
+    /// or for any cache, or a `ttl` for any cache. If not, the timer isn't polled.
+    check_evictions: bool,
     /// A memory limit for state, in bytes.
     memory_limit: Option<usize>,
+    /// The options for each cache, including per-cache memory limits and eviction priorities,
+    /// keyed by the name of the cache's reader.
+    cache_options: Arc<HashMap<Relation, CacheOptions>>,
+    /// The time the worker started, which the expiry of caches with a `ttl` is measured from.
+    started_at: Instant,
+    /// The number of milliseconds after `started_at` up to which caches with a `ttl` have been
+    /// expired.
+    expired_until: Arc<AtomicU64>,
     /// Channel through which worker requests are received.
     rx: Receiver<WorkerRequest>,
     /// Channel coordinator (used by domains to figure out where other domains are).
@@ -245,7 +261,11 @@ impl Worker {
             election_state: None,
             // this initial duration doesn't matter; it gets set upon worker registration
             evict_interval: memory_check_frequency.map(|f| tokio::time::interval(f)),
+            check_evictions: memory_limit.is_some(),
             memory_limit,
+            cache_options: Default::default(),
+            started_at: Instant::now(),
+            expired_until: Default::default(),
             rx: worker_rx,
             coord: Arc::new(Default::default()),
             domain_bind: listen_addr,
@@ -260,9 +280,26 @@ impl Worker {
         })
     }
 
+    /// Only check memory usage and expire caches periodically if there's anything to enforce. See
+    /// [`needs_eviction_check`]
+    fn update_check_evictions(&mut self) {
+        let check_evictions = needs_eviction_check(self.memory_limit, &self.cache_options);
+        if check_evictions && !self.check_evictions {
+            // Don't fire all the ticks missed while we weren't checking at once
+            if let Some(evict_interval) = &mut self.evict_interval {
+                evict_interval.reset();
+            }
+        }
+        self.check_evictions = check_evictions;
+    }
+
     fn process_eviction(&mut self) {
         tokio::spawn(do_eviction(
             self.memory_limit,
+            Arc::clone(&self.cache_options),
+            self.started_at,
+            Arc::clone(&self.expired_until),
+            Arc::clone(&self.readers),
             self.coord.clone(),
             self.memory,
             Arc::clone(&self.state_sizes),
@@ -466,6 +503,12 @@ impl Worker {
             WorkerRequestKind::SetMemoryLimit { period, limit } => {
                 self.evict_interval = period.map(tokio::time::interval);
                 self.memory_limit = limit;
+                self.update_check_evictions();
+                Ok(None)
+            }
+            WorkerRequestKind::SetCacheOptions(cache_options) => {
+                self.cache_options = Arc::new(cache_options);
+                self.update_check_evictions();
                 Ok(None)
             }
         }
@@ -495,9 +538,10 @@ impl Worker {
     /// This function returns if the worker request sender is dropped.
     pub async fn run(mut self) {
         loop {
-            let ei = &mut self.evict_interval;
+            let check_evictions = self.check_evictions;
+            let ei = self.evict_interval.as_mut().filter(|_| check_evictions);
             let eviction = async {
-                if let Some(ref mut ei) = ei {
+                if let Some(ei) = ei {
                     ei.tick().await
                 } else {
                     futures_util::future::pending().await
@@ -556,8 +600,33 @@ impl Worker {
     }
 }
 
+/// Returns true if memory usage needs to be checked periodically, because there's a memory limit
+/// for the process or for any cache, or any cache has a `ttl`
+fn needs_eviction_check(
+    memory_limit: Option<usize>,
+    cache_options: &HashMap<Relation, CacheOptions>,
+) -> bool {
+    memory_limit.is_some()
+        || cache_options
+            .values()
+            .any(|options| options.memory_limit.is_some() || options.ttl.is_some())
+}
+
+/// The size of the state of a single partially materialized reader running on this worker
+struct ReaderSize {
+    /// The name of the reader, which is also the name of the cache it belongs to
+    name: Relation,
+    /// The address of the domain replica that the reader is in
+    domain: ReplicaAddress,
+    /// The index of the reader within its domain
+    node: LocalNodeIndex,
+    /// The approximate size of the reader's state, in bytes
+    size: usize,
+}
+
 /// Calculate the total memory used by the process (by querying [`jemalloc_ctl`]), then perform an
-/// eviction if that's over the configured `memory_limit`.
+/// eviction if that's over the configured `memory_limit`, or if the readers for any cache are over
+/// the memory limit in that cache's [`CacheOptions`].
 ///
 /// There is a *significant* proportional discrepancy - about 8x - between the memory size reported
 /// by individual node states and the actual number of bytes allocated by the application - rather
@@ -566,9 +635,24 @@ impl Worker {
 /// evict, but use the state sizes of individual nodes to decide *where* to evict. This is
 /// imperfect, and should likely be improved in the future, but is a good way to avoid running fully
 /// out of memory and getting OOM-killed before we ever realise it's time to evict.
-#[allow(clippy::type_complexity)]
+///
+/// Per-cache memory limits, on the other hand, are compared against the state sizes of the
+/// readers for that cache, since that's the only measure of memory usage we have per cache. When
+/// evicting to get back under the global memory limit, we evict from the domains with the lowest
+/// [`EvictionPriority`] that have any state at all first, and only evict from domains with a higher
+/// priority whatever those domains can't free on their own. Domains that don't contain a reader for
+/// a cache have [`EvictionPriority::Normal`], and domains containing readers for multiple caches
+/// have the highest of those caches' priorities.
+///
+/// Finally, the readers for every cache with a `ttl` are emptied each time that ttl elapses, as
+/// measured from `started_at`. See [`expired_readers`].
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
 async fn do_eviction(
     memory_limit: Option<usize>,
+    cache_options: Arc<HashMap<Relation, CacheOptions>>,
+    started_at: Instant,
+    expired_until: Arc<AtomicU64>,
+    readers: Readers,
     coord: Arc<ChannelCoordinator>,
     memory_tracker: MemoryTracker,
     state_sizes: Arc<Mutex<HashMap<ReplicaAddress, Arc<AtomicUsize>>>>,
@@ -588,124 +672,296 @@ async fn do_eviction(
 
     let used: usize = memory_tracker.allocated_bytes()?;
     gauge!(recorded::EVICTION_WORKER_HEAP_ALLOCATED_BYTES, used as f64);
+
+    let reader_sizes = {
+        #[allow(clippy::unwrap_used)] // lock poisoning is unrecoverable
+        let readers = readers.lock().unwrap();
+        readers
+            .iter()
+            .filter_map(|(addr, handle)| {
+                let (domain, node) = handle.writer()?;
+                Some(ReaderSize {
+                    name: addr.name.clone(),
+                    domain,
+                    node,
+                    size: handle.state_size(),
+                })
+            })
+            .collect::<Vec<_>>()
+    };
+
+    // add current state sizes (could be out of date, as packet sent below is not
+    // necessarily received immediately)
+    let domain_sizes = if memory_limit.is_some_and(|limit| used >= limit) {
+        let state_sizes = state_sizes.lock().await;
+        state_sizes
+            .iter()
+            .map(|(replica_addr, size_atom)| {
+                let size = size_atom.load(Ordering::Acquire);
+                span.in_scope(|| trace!("domain {} state size is {} bytes", replica_addr, size));
+                (*replica_addr, size)
+            })
+            .collect::<Vec<_>>()
+    } else {
+        vec![]
+    };
+
+    let evictions = span.in_scope(|| {
+        plan_evictions(
+            used,
+            memory_limit,
+            &cache_options,
+            &reader_sizes,
+            domain_sizes,
+        )
+    });
+
+    let now = started_at.elapsed().as_millis() as u64;
+    let since = expired_until.swap(now, Ordering::Relaxed);
+    let expirations = expired_readers(since, now, &cache_options, &reader_sizes);
+
+    if evictions.is_empty() && expirations.is_empty() && memory_limit.is_none() {
+        return Ok(());
+    }
+
+    let requests = evictions
+        .into_iter()
+        .map(|(target, node, evict)| {
+            (
+                target,
+                EvictRequest::Bytes {
+                    node,
+                    num_bytes: evict,
+                },
+            )
+        })
+        .chain(
+            expirations
+                .into_iter()
+                .map(|(target, node)| (target, EvictRequest::Reader { node })),
+        );
+
+    let mut domain_senders = HashMap::new();
+    for (target, request) in requests {
+        counter!(recorded::EVICTION_WORKER_EVICTIONS_REQUESTED, 1);
+
+        let tx = match domain_senders.entry(target) {
+            Occupied(entry) => entry.into_mut(),
+            Vacant(entry) => entry.insert(tokio::task::block_in_place(|| {
+                coord.builder_for(&target)?.build_async().map_err(|e| {
+                    internal_err!(
+                        "an error occurred while trying to create a domain connection: '{}'",
+                        e
+                    )
+                })
+            })?),
+        };
+        let r = tx.send(Packet::Evict(request)).await;
+
+        if let Err(e) = r {
+            // probably exiting?
+            span.in_scope(|| {
+                warn!(
+                    "failed to evict from {}: {}",
+                    target.domain_index.index(),
+                    e
+                )
+            });
+            // remove sender so we don't try to use it again
+            domain_senders.remove(&target);
+        }
+    }
+
+    histogram!(
+        recorded::EVICTION_WORKER_EVICTION_TIME,
+        start.elapsed().as_micros() as f64,
+    );
+
+    Ok(())
+}
+
+/// Decides how many bytes to evict from each domain (and optionally from a specific node within
+/// it), given the number of bytes allocated by the process (`used`), the readers running on this
+/// worker, and the reported state size of each domain. See [`do_eviction`] for the policy.
+///
+/// The state sizes of the domains, `sizes`, only need to be provided if `used` is over
+/// `memory_limit`.
+fn plan_evictions(
+    used: usize,
+    memory_limit: Option<usize>,
+    cache_options: &HashMap<Relation, CacheOptions>,
+    reader_sizes: &[ReaderSize],
+    sizes: Vec<(ReplicaAddress, usize)>,
+) -> Vec<(ReplicaAddress, Option<LocalNodeIndex>, usize)> {
+    // The number of bytes we're going to ask each domain (or a specific node in it) to evict
+    let mut evictions: Vec<(ReplicaAddress, Option<LocalNodeIndex>, usize)> = vec![];
+
+    // First, bring the readers for each cache with a memory limit back under that limit, evicting
+    // from each shard of the reader in proportion to its size.
+    let mut cache_sizes: HashMap<&Relation, usize> = HashMap::new();
+    for reader in reader_sizes {
+        *cache_sizes.entry(&reader.name).or_default() += reader.size;
+    }
+    for reader in reader_sizes {
+        let Some(limit) = cache_options
+            .get(&reader.name)
+            .and_then(|options| options.memory_limit)
+        else {
+            continue;
+        };
+        let limit = limit as usize;
+        let total = cache_sizes.get(&reader.name).copied().unwrap_or_default();
+        if total <= limit || reader.size == 0 {
+            continue;
+        }
+
+        let evict = ((reader.size as f64 / total as f64) * (total - limit) as f64).ceil() as usize;
+        debug!(
+            cache = %reader.name.display_unquoted(),
+            size = total,
+            limit,
+            "cache state size exceeds its memory limit; evicting from domain {}",
+            reader.domain,
+        );
+        evictions.push((reader.domain, Some(reader.node), evict));
+    }
+
     // Are we over the limit?
-    match memory_limit {
-        None => Ok(()),
-        Some(limit) => {
-            if used >= limit {
-                // we are! time to evict.
-                // add current state sizes (could be out of date, as packet sent below is not
-                // necessarily received immediately)
-                let (mut sizes, total_reported) = {
-                    let state_sizes = state_sizes.lock().await;
-                    let mut total_reported = 0;
-                    let sizes = state_sizes
-                        .iter()
-                        .map(|(replica_addr, size_atom)| {
-                            let size = size_atom.load(Ordering::Acquire);
-                            span.in_scope(|| {
-                                trace!("domain {} state size is {} bytes", replica_addr, size)
-                            });
-                            total_reported += size;
-                            (*replica_addr, size)
-                        })
-                        .collect::<Vec<_>>();
-                    (sizes, total_reported)
-                };
+    if let Some(limit) = memory_limit.filter(|limit| used >= *limit) {
+        // we are! time to evict.
+        let total_reported: usize = sizes.iter().map(|(_, size)| size).sum();
+
+        // state sizes are under actual memory usage, but roughly proportional to actual
+        // memory usage - let's figure out proportionally how much *reported* memory we
+        // should evict
+        let actual_over = used - limit;
+        let mut proportional_over =
+            ((total_reported as f64 / used as f64) * actual_over as f64).round() as usize;
+        // anything we're already evicting to enforce per-cache limits counts towards that
+        proportional_over =
+            proportional_over.saturating_sub(evictions.iter().map(|(_, _, evict)| evict).sum());
+
+        // evict from the domains with the lowest eviction priority that have any state first, and
+        // only move on to domains with a higher priority if those can't free enough. A domain
+        // containing readers for several caches takes the highest of their priorities, so that a
+        // high priority cache isn't evicted from just because it shares a domain with a low
+        // priority one.
+        let mut priorities: HashMap<ReplicaAddress, EvictionPriority> = HashMap::new();
+        for reader in reader_sizes {
+            let priority = cache_options
+                .get(&reader.name)
+                .and_then(|options| options.eviction_priority)
+                .unwrap_or_default();
+            priorities
+                .entry(reader.domain)
+                .and_modify(|p| *p = cmp::max(*p, priority))
+                .or_insert(priority);
+        }
+        let mut classes: BTreeMap<EvictionPriority, Vec<(ReplicaAddress, usize)>> = BTreeMap::new();
+        for (domain, size) in sizes.into_iter().filter(|&(_, s)| s > 0) {
+            let priority = priorities.get(&domain).copied().unwrap_or_default();
+            classes.entry(priority).or_default().push((domain, size));
+        }
 
-                // state sizes are under actual memory usage, but roughly proportional to actual
-                // memory usage - let's figure out proportionally how much *reported* memory we
-                // should evict
-                let actual_over = used - limit;
-                let mut proportional_over =
-                    ((total_reported as f64 / used as f64) * actual_over as f64).round() as usize;
-
-                // here's how we're going to proceed.
-                // we don't want to _empty_ any views if we can avoid it.
-                // and we also need to be aware that evicting something from one place may cause a
-                // number of downstream evictions.
-
-                // we want to spread the eviction impact across multiple nodes where possible,
-                // so we distribute how much we're over the limit across the 3 largest nodes.
-                // -1* so we sort in descending order
-                // TODO: be smarter than 3 here
-                sizes.sort_unstable_by_key(|&(_, s)| -(s as i64));
-                sizes.truncate(3);
-
-                // don't evict from tiny things (< 10% of max)
-                if let Some(too_small_i) = sizes.iter().position(|&(_, s)| s < sizes[0].1 / 10) {
-                    // everything beyond this is smaller, so also too small
-                    sizes.truncate(too_small_i);
-                }
+        let num_classes = classes.len();
+        for (class, mut sizes) in classes.into_values().enumerate() {
+            if proportional_over == 0 {
+                break;
+            }
+            let last_class = class + 1 == num_classes;
+
+            // here's how we're going to proceed.
+            // we don't want to _empty_ any views if we can avoid it.
+            // and we also need to be aware that evicting something from one place may cause a
+            // number of downstream evictions.
+
+            // we want to spread the eviction impact across multiple nodes where possible,
+            // so we distribute how much we're over the limit across the 3 largest nodes.
+            // -1* so we sort in descending order
+            // TODO: be smarter than 3 here
+            sizes.sort_unstable_by_key(|&(_, s)| -(s as i64));
+            sizes.truncate(3);
+
+            // don't evict from tiny things (< 10% of max)
+            if let Some(too_small_i) = sizes.iter().position(|&(_, s)| s < sizes[0].1 / 10) {
+                // everything beyond this is smaller, so also too small
+                sizes.truncate(too_small_i);
+            }
 
-                // starting with the smallest of the n domains
-                let mut n = sizes.len();
-                let mut domain_senders = HashMap::new();
-                for &(target, size) in sizes.iter().rev() {
-                    // TODO: should this be evenly divided, or weighted by the size of the domains?
-                    let share = (proportional_over + n - 1) / n;
-                    // we're only willing to evict at most half the state in each domain
-                    // unless this is the only domain left to evict from
-                    let evict = if n > 1 {
-                        cmp::min(size / 2, share)
-                    } else {
-                        assert_eq!(share, proportional_over);
+            // starting with the smallest of the n domains
+            let mut n = sizes.len();
+            for &(target, size) in sizes.iter().rev() {
+                // TODO: should this be evenly divided, or weighted by the size of the domains?
+                let share = (proportional_over + n - 1) / n;
+                // we're only willing to evict at most half the state in each domain
+                // unless this is the only domain left to evict from
+                let evict = if n > 1 {
+                    cmp::min(size / 2, share)
+                } else {
+                    assert_eq!(share, proportional_over);
+                    // if there are domains with a higher priority, whatever this one can't free
+                    // is carried over to them
+                    if last_class {
                         share
-                    };
-                    proportional_over -= evict;
-                    n -= 1;
-
-                    span.in_scope(|| {
-                        debug!(
-                            "memory footprint ({} bytes) exceeds limit ({} bytes); evicting from largest domain {}",
-                            used,
-                            limit,
-                            target.domain_index,
-                        )
-                    });
-
-                    counter!(recorded::EVICTION_WORKER_EVICTIONS_REQUESTED, 1);
-
-                    let tx = match domain_senders.entry(target) {
-                        Occupied(entry) => entry.into_mut(),
-                        Vacant(entry) => entry.insert(tokio::task::block_in_place(|| {
-                            coord.builder_for(&target)?.build_async().map_err(|e| {
-                                internal_err!(
-                                    "an error occurred while trying to create a domain connection: '{}'",
-                                    e
-                                )
-                            })
-                        })?),
-                    };
-                    let r = tx
-                        .send(Packet::Evict(EvictRequest::Bytes {
-                            node: None,
-                            num_bytes: evict,
-                        }))
-                        .await;
-
-                    if let Err(e) = r {
-                        // probably exiting?
-                        span.in_scope(|| {
-                            warn!(
-                                "failed to evict from {}: {}",
-                                target.domain_index.index(),
-                                e
-                            )
-                        });
-                        // remove sender so we don't try to use it again
-                        domain_senders.remove(&target);
+                    } else {
+                        cmp::min(size, share)
                     }
+                };
+                proportional_over -= evict;
+                n -= 1;
+
+                if evict == 0 {
+                    continue;
                 }
-            }
-            histogram!(
-                recorded::EVICTION_WORKER_EVICTION_TIME,
-                start.elapsed().as_micros() as f64,
-            );
 
-            Ok(())
+                debug!(
+                    "memory footprint ({} bytes) exceeds limit ({} bytes); evicting from largest domain {}",
+                    used,
+                    limit,
+                    target.domain_index,
+                );
+                evictions.push((target, None, evict));
+            }
         }
     }
+
+    evictions
+}
+
+/// Returns the reader nodes to empty because the `ttl` of their cache has elapsed at least once
+/// between `since` and `until`, which are both milliseconds since the worker started.
+///
+/// Every cache expires at each multiple of its ttl, rather than once its ttl has elapsed since
+/// each individual key was filled, so that no per-key state needs to be kept. Readers that are
+/// already empty are skipped.
+fn expired_readers(
+    since: u64,
+    until: u64,
+    cache_options: &HashMap<Relation, CacheOptions>,
+    reader_sizes: &[ReaderSize],
+) -> Vec<(ReplicaAddress, LocalNodeIndex)> {
+    reader_sizes
+        .iter()
+        .filter(|reader| reader.size > 0)
+        .filter(|reader| {
+            let Some(ttl) = cache_options
+                .get(&reader.name)
+                .and_then(|options| options.ttl)
+            else {
+                return false;
+            };
+            let ttl = (ttl.as_millis() as u64).max(1);
+            until / ttl > since / ttl
+        })
+        .map(|reader| {
+            debug!(
+                cache = %reader.name.display_unquoted(),
+                "cache ttl elapsed; expiring reader in domain {}",
+                reader.domain,
+            );
+            (reader.domain, reader.node)
+        })
+        .collect()
 }
 
 impl Drop for Worker {
@@ -749,3 +1005,162 @@ impl Drop for Worker {
             .expect("This thread shouldn't panic");
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn replica(domain_index: usize) -> ReplicaAddress {
+        ReplicaAddress {
+            domain_index: domain_index.into(),
+            shard: 0,
+            replica: 0,
+        }
+    }
+
+    fn reader(name: &str, domain_index: usize, size: usize) -> ReaderSize {
+        ReaderSize {
+            name: name.into(),
+            domain: replica(domain_index),
+            node: LocalNodeIndex::make(domain_index as u32),
+            size,
+        }
+    }
+
+    fn options(
+        memory_limit: Option<u64>,
+        eviction_priority: Option<EvictionPriority>,
+    ) -> CacheOptions {
+        CacheOptions {
+            memory_limit,
+            eviction_priority,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn per_cache_memory_limit() {
+        let cache_options = HashMap::from([
+            ("limited".into(), options(Some(200), None)),
+            ("unlimited".into(), options(None, None)),
+        ]);
+        let readers = [
+            reader("limited", 0, 300),
+            reader("limited", 1, 100),
+            reader("unlimited", 2, 10_000),
+        ];
+
+        let evictions = plan_evictions(0, None, &cache_options, &readers, vec![]);
+        // The 200 bytes the cache is over its limit are evicted from each of its readers in
+        // proportion to their sizes
+        assert_eq!(
+            evictions,
+            vec![
+                (replica(0), Some(LocalNodeIndex::make(0)), 150),
+                (replica(1), Some(LocalNodeIndex::make(1)), 50),
+            ]
+        );
+    }
+
+    #[test]
+    fn eviction_check_only_needed_with_limits_or_ttls() {
+        let mut cache_options = HashMap::from([("q".into(), options(None, None))]);
+        assert!(!needs_eviction_check(None, &cache_options));
+        assert!(needs_eviction_check(Some(1024), &cache_options));
+
+        cache_options.insert("limited".into(), options(Some(1024), None));
+        assert!(needs_eviction_check(None, &cache_options));
+
+        cache_options.remove("limited");
+        cache_options.insert(
+            "expiring".into(),
+            CacheOptions {
+                ttl: Some(Duration::from_secs(10)),
+                ..Default::default()
+            },
+        );
+        assert!(needs_eviction_check(None, &cache_options));
+    }
+
+    #[test]
+    fn ttl_expires_readers_at_multiples_of_ttl() {
+        let cache_options = HashMap::from([
+            (
+                "expiring".into(),
+                CacheOptions {
+                    ttl: Some(Duration::from_secs(10)),
+                    ..Default::default()
+                },
+            ),
+            ("forever".into(), options(None, None)),
+        ]);
+        let readers = [
+            reader("expiring", 0, 100),
+            reader("expiring", 1, 0),
+            reader("forever", 2, 100),
+        ];
+
+        assert!(expired_readers(0, 9_999, &cache_options, &readers).is_empty());
+        // Only the non-empty reader for the expiring cache is emptied
+        assert_eq!(
+            expired_readers(9_000, 10_000, &cache_options, &readers),
+            vec![(replica(0), LocalNodeIndex::make(0))]
+        );
+        assert!(expired_readers(10_000, 19_000, &cache_options, &readers).is_empty());
+        assert_eq!(
+            expired_readers(19_000, 45_000, &cache_options, &readers),
+            vec![(replica(0), LocalNodeIndex::make(0))]
+        );
+    }
+
+    #[test]
+    fn global_limit_evicts_lowest_priority_first() {
+        let cache_options = HashMap::from([
+            ("low".into(), options(None, Some(EvictionPriority::Low))),
+            ("high".into(), options(None, Some(EvictionPriority::High))),
+        ]);
+        let readers = [reader("low", 0, 100), reader("high", 1, 100)];
+        let sizes = vec![(replica(0), 100), (replica(1), 100), (replica(2), 800)];
+
+        // Even though domain 2 (which has no readers, so normal priority) is the largest, only the
+        // low priority domain is evicted from
+        let evictions = plan_evictions(1000, Some(900), &cache_options, &readers, sizes);
+        assert_eq!(evictions, vec![(replica(0), None, 100)]);
+    }
+
+    #[test]
+    fn domain_priority_is_highest_of_its_caches() {
+        let cache_options = HashMap::from([
+            ("low".into(), options(None, Some(EvictionPriority::Low))),
+            ("high".into(), options(None, Some(EvictionPriority::High))),
+        ]);
+        // Domain 0 contains readers for both a high and a low priority cache
+        let readers = [
+            reader("high", 0, 200),
+            reader("low", 0, 300),
+            reader("normal", 1, 500),
+        ];
+        let sizes = vec![(replica(0), 500), (replica(1), 500)];
+
+        let evictions = plan_evictions(1000, Some(900), &cache_options, &readers, sizes);
+        assert_eq!(evictions, vec![(replica(1), None, 100)]);
+    }
+
+    #[test]
+    fn global_limit_carries_overage_to_higher_priorities() {
+        let cache_options = HashMap::from([
+            ("low".into(), options(None, Some(EvictionPriority::Low))),
+            ("high".into(), options(None, Some(EvictionPriority::High))),
+        ]);
+        let readers = [reader("low", 0, 100), reader("high", 1, 100)];
+        let sizes = vec![(replica(0), 100), (replica(1), 100), (replica(2), 800)];
+
+        // The low priority domain can only free 100 of the 500 bytes we're over by, so the rest is
+        // evicted from the normal priority domain, and nothing from the high priority one
+        let evictions = plan_evictions(1000, Some(500), &cache_options, &readers, sizes);
+        assert_eq!(
+            evictions,
+            vec![(replica(0), None, 100), (replica(2), None, 400)]
+        );
+    }
+}
@@ -441,19 +441,27 @@ pub(crate) async fn listen(
 /// Verifies that the timestamp in the reader node associated with the read handle, `reader`,
 /// has a greater timestamp than `timestamp`. A greater reader timestamp indicates the writes
 /// in the node include all of the writes associated with `timestamp`.
+///
+/// Once a reader has received a timestamp it has an entry for every base table it reads from, so
+/// entries in `timestamp` for base tables the reader has no entry for are writes the reader can
+/// never observe, and are ignored.
 fn has_sufficient_timestamp(reader: &SingleReadHandle, timestamp: &Option<Timestamp>) -> bool {
-    if timestamp.is_none() {
-        return true;
-    }
-
-    let dataflow_timestamp = reader.timestamp();
-    if dataflow_timestamp.is_none() {
-        return false;
-    }
-
-    dataflow_timestamp
-        .unwrap()
-        .satisfies(timestamp.as_ref().unwrap())
+    let timestamp = match timestamp {
+        Some(timestamp) if !timestamp.map.is_empty() => timestamp,
+        _ => return true,
+    };
+
+    let dataflow_timestamp = match reader.timestamp() {
+        Some(dataflow_timestamp) if !dataflow_timestamp.map.is_empty() => dataflow_timestamp,
+        _ => return false,
+    };
+
+    timestamp.map.iter().all(|(table, t)| {
+        dataflow_timestamp
+            .map
+            .get(table)
+            .map_or(true, |reader_t| reader_t >= t)
+    })
 }
 
 /// Issues a blocking read against a reader. This can be repeatedly polled via `check` for
@@ -464,7 +464,7 @@ impl Replica {
                 Some(_) = refresh_sizes.next() => domain.update_state_sizes(),
 
                 // Wait for a possible sleep
-                _ = tokio::time::sleep(domain.next_poll_duration().unwrap_or_else(|| Duration::from_secs(3600))) => domain.handle_timeout()?,
+                _ = tokio::time::sleep(domain.next_poll_duration().unwrap_or_else(|| Duration::from_secs(3600))) => domain.handle_timeout(out)?,
             }
 
             // Check if the previous batch of send packets is done, and issue a new batch if needed
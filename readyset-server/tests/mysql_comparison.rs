@@ -331,7 +331,8 @@ async fn check_query(
                         // These types are PostgreSQL specific
                         | DfValue::BitVector(_)
                         | DfValue::PassThrough(_)
-                        | DfValue::Array(_) => {
+                        | DfValue::Array(_)
+                        | DfValue::Interval(_) => {
                             unimplemented!()
                         }
                     })
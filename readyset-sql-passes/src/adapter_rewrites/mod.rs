@@ -10,8 +10,8 @@ pub use autoparameterize::auto_parameterize_query;
 use itertools::{Either, Itertools};
 use nom_sql::analysis::visit_mut::{self, VisitorMut};
 use nom_sql::{
-    BinaryOperator, DialectDisplay, Expr, InValue, ItemPlaceholder, LimitClause, Literal,
-    SelectStatement,
+    BinaryOperator, DialectDisplay, Expr, FieldDefinitionExpr, FunctionExpr, InValue,
+    ItemPlaceholder, LimitClause, Literal, SelectStatement,
 };
 use readyset_data::{DfType, DfValue};
 use readyset_errors::{
@@ -360,10 +360,26 @@ fn collapse_where_in(query: &mut SelectStatement) -> ReadySetResult<Vec<Rewritte
         if !res.is_empty() && query.distinct {
             unsupported!("DISTINCT with parameterized IN is not supported");
         }
+
+        // Each key in a parameterized IN is looked up separately, but window functions need to be
+        // computed over the rows for all of the keys together
+        if !res.is_empty() && query.fields.iter().any(contains_window_function) {
+            unsupported!("Window functions with parameterized IN are not supported");
+        }
     }
     Ok(res)
 }
 
+/// Returns true if the given field contains a window function
+fn contains_window_function(field: &FieldDefinitionExpr) -> bool {
+    match field {
+        FieldDefinitionExpr::Expr { expr, .. } => iter::once(expr)
+            .chain(expr.recursive_subexpressions())
+            .any(|expr| matches!(expr, Expr::Call(FunctionExpr::Window { .. }))),
+        FieldDefinitionExpr::All | FieldDefinitionExpr::AllInTable(_) => false,
+    }
+}
+
 /// Given a slice of parameters provided by the user and the list of [`RewrittenIn`] returned by
 /// [`collapse_where_in`] on a query, construct a vector of lookup keys for executing that query
 fn explode_params<'param, 'a, T>(
@@ -823,6 +839,14 @@ mod tests {
                 parse_select_statement_mysql("SELECT * FROM t WHERE x = ? AND y = ?")
             );
         }
+
+        #[test]
+        fn window_functions_with_where_in() {
+            let mut q = parse_select_statement_mysql(
+                "SELECT x, row_number() OVER (ORDER BY y) FROM t WHERE x IN (?, ?)",
+            );
+            collapse_where_in(&mut q).unwrap_err();
+        }
     }
 
     mod explode_params {
@@ -198,6 +198,7 @@ impl<'ast> VisitorMut<'ast> for AnonymizeVisitor<'_> {
             nom_sql::ShowStatement::Events
             | nom_sql::ShowStatement::CachedQueries(..)
             | nom_sql::ShowStatement::ProxiedQueries(..)
+            | nom_sql::ShowStatement::CacheRecommendations(..)
             | nom_sql::ShowStatement::ReadySetStatus
             | nom_sql::ShowStatement::ReadySetStatusAdapter
             | nom_sql::ShowStatement::ReadySetMigrationStatus(..)
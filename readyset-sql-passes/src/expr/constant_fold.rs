@@ -2,7 +2,7 @@ use dataflow_expression::{Dialect, Expr as DataflowExpr, LowerContext};
 use nom_sql::analysis::visit_mut::{self, VisitorMut};
 use nom_sql::{Column, Expr, Literal, Relation};
 use readyset_data::{DfType, DfValue};
-use readyset_errors::{internal, ReadySetResult};
+use readyset_errors::{internal, unsupported, ReadySetResult};
 
 /// Statically evaluate the given expression, returning a literal value representing the result.
 ///
@@ -22,6 +22,11 @@ fn const_eval(expr: &Expr, dialect: Dialect) -> ReadySetResult<Literal> {
     }
 
     let dataflow_expr = DataflowExpr::lower(expr.clone(), dialect, &ConstEvalLowerContext)?;
+    if *dataflow_expr.ty() == DfType::Interval {
+        // Intervals would be folded to string literals, which don't mean the same thing in
+        // date/time arithmetic
+        unsupported!("Can't fold intervals to literals");
+    }
     let res = dataflow_expr.eval::<DfValue>(&[])?;
     res.try_into()
 }
@@ -107,5 +112,6 @@ mod tests {
         if_null_builtin("ifnull(1, 1)", "1");
         within_larger_expression("t.x + 4 + 5", "t.x + 9");
         doc_example("x = ifnull(y, 1 + (4 * 5))", "x = ifnull(y, 21)");
+        interval_not_folded("t.x >= now() - interval (1 + 1) day", "t.x >= now() - interval 2 day");
     }
 }
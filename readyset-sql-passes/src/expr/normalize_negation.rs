@@ -32,6 +32,14 @@ fn negate_expr(expr: &mut Expr) -> bool {
                 BinaryOperator::NotLike => BinaryOperator::Like,
                 BinaryOperator::ILike => BinaryOperator::NotILike,
                 BinaryOperator::NotILike => BinaryOperator::ILike,
+                BinaryOperator::Regexp => BinaryOperator::NotRegexp,
+                BinaryOperator::NotRegexp => BinaryOperator::Regexp,
+                BinaryOperator::SimilarTo => BinaryOperator::NotSimilarTo,
+                BinaryOperator::NotSimilarTo => BinaryOperator::SimilarTo,
+                BinaryOperator::Tilde => BinaryOperator::ExclamationTilde,
+                BinaryOperator::ExclamationTilde => BinaryOperator::Tilde,
+                BinaryOperator::TildeAsterisk => BinaryOperator::ExclamationTildeAsterisk,
+                BinaryOperator::ExclamationTildeAsterisk => BinaryOperator::TildeAsterisk,
                 BinaryOperator::Is => BinaryOperator::IsNot,
                 BinaryOperator::IsNot => BinaryOperator::Is,
                 BinaryOperator::Add
@@ -39,6 +47,7 @@ fn negate_expr(expr: &mut Expr) -> bool {
                 | BinaryOperator::HashSubtract
                 | BinaryOperator::Multiply
                 | BinaryOperator::Divide
+                | BinaryOperator::Modulo
                 | BinaryOperator::DoublePipe
                 | BinaryOperator::Arrow1
                 | BinaryOperator::Arrow2
@@ -47,7 +47,8 @@ pub use crate::star_expansion::StarExpansion;
 pub use crate::strip_literals::{SelectStatementSkeleton, StripLiterals};
 pub use crate::strip_post_filters::StripPostFilters;
 pub use crate::util::{
-    is_correlated, is_logical_op, is_predicate, map_aggregates, outermost_table_exprs, LogicalOp,
+    is_correlated, is_logical_op, is_predicate, map_aggregates, now_offset, outermost_table_exprs,
+    LogicalOp,
 };
 
 /// Context provided to all query rewriting passes.
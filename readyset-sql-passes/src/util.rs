@@ -5,9 +5,11 @@ use itertools::Either;
 use nom_sql::analysis::is_aggregate;
 use nom_sql::{
     BinaryOperator, Column, CommonTableExpr, DialectDisplay, Expr, FieldDefinitionExpr,
-    FunctionExpr, InValue, JoinClause, JoinRightSide, Relation, SelectStatement, SqlIdentifier,
-    TableExpr, TableExprInner,
+    FunctionExpr, InValue, JoinClause, JoinRightSide, Literal, Relation, SelectStatement,
+    SqlIdentifier, SqlType, TableExpr, TableExprInner,
 };
+use readyset_data::Interval;
+use readyset_errors::{invalid_query_err, ReadySetResult};
 
 pub(crate) fn join_clause_tables(join: &JoinClause) -> impl Iterator<Item = &TableExpr> {
     match &join.right {
@@ -115,7 +117,9 @@ pub fn map_aggregates(expr: &mut Expr) -> Vec<(FunctionExpr, SqlIdentifier)> {
             ret.append(&mut map_aggregates(lhs));
             ret.append(&mut map_aggregates(rhs));
         }
-        Expr::UnaryOp { rhs: expr, .. } | Expr::Cast { expr, .. } => {
+        Expr::UnaryOp { rhs: expr, .. }
+        | Expr::Cast { expr, .. }
+        | Expr::Interval { value: expr, .. } => {
             ret.append(&mut map_aggregates(expr));
         }
         Expr::Exists(_) => {}
@@ -156,6 +160,14 @@ pub fn is_predicate(op: &BinaryOperator) -> bool {
         Like | NotLike
             | ILike
             | NotILike
+            | Regexp
+            | NotRegexp
+            | SimilarTo
+            | NotSimilarTo
+            | Tilde
+            | TildeAsterisk
+            | ExclamationTilde
+            | ExclamationTildeAsterisk
             | Equal
             | NotEqual
             | Greater
@@ -176,6 +188,62 @@ pub fn is_logical_op(op: &BinaryOperator) -> bool {
     matches!(op, And | Or)
 }
 
+/// If `expr` is the current time (`NOW()` or `CURRENT_TIMESTAMP()`), optionally plus or minus a
+/// constant interval, returns that interval.
+pub fn now_offset(expr: &Expr) -> ReadySetResult<Option<Interval>> {
+    match expr {
+        Expr::Call(FunctionExpr::Call { name, arguments })
+            if (name.eq_ignore_ascii_case("now")
+                || name.eq_ignore_ascii_case("current_timestamp"))
+                && arguments.iter().all(|arg| matches!(arg, Expr::Literal(_))) =>
+        {
+            Ok(Some(Interval::default()))
+        }
+        Expr::BinaryOp {
+            lhs,
+            op: op @ (BinaryOperator::Add | BinaryOperator::Subtract),
+            rhs,
+        } => {
+            let (Some(now), Some(interval)) = (now_offset(lhs)?, interval_literal(rhs)) else {
+                return Ok(None);
+            };
+            let interval = if *op == BinaryOperator::Subtract {
+                interval.checked_neg()
+            } else {
+                Some(interval)
+            };
+            interval
+                .and_then(|interval| now.checked_add(&interval))
+                .map(Some)
+                .ok_or_else(|| invalid_query_err!("Interval out of range"))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Returns the value of `expr` if it is a constant interval, either in the MySQL (`INTERVAL 30
+/// DAY`) or the PostgreSQL (`INTERVAL '30 days'`) syntax.
+fn interval_literal(expr: &Expr) -> Option<Interval> {
+    match expr {
+        Expr::Interval {
+            value: box Expr::Literal(value),
+            unit,
+        } => {
+            let value = match value {
+                Literal::String(s) => s.clone(),
+                value => value.display(nom_sql::Dialect::MySQL).to_string(),
+            };
+            Interval::from_mysql_unit(&value, *unit)
+        }
+        Expr::Cast {
+            expr: box Expr::Literal(Literal::String(s)),
+            ty: SqlType::Interval { fields: None, .. },
+            ..
+        } => s.parse().ok(),
+        _ => None,
+    }
+}
+
 /// Boolean-valued logical operators
 pub enum LogicalOp {
     And,
@@ -235,4 +303,61 @@ mod tests {
             assert!(is_correlated(&query));
         }
     }
+
+    mod now_offset {
+        use nom_sql::{parse_expr, Dialect};
+
+        use super::*;
+
+        fn now_offset_of(dialect: Dialect, expr: &str) -> Option<Interval> {
+            now_offset(&parse_expr(dialect, expr).unwrap()).unwrap()
+        }
+
+        fn interval(s: &str) -> Interval {
+            s.parse().unwrap()
+        }
+
+        #[test]
+        fn now() {
+            assert_eq!(
+                now_offset_of(Dialect::MySQL, "NOW()"),
+                Some(Interval::default())
+            );
+            assert_eq!(
+                now_offset_of(Dialect::MySQL, "current_timestamp(6)"),
+                Some(Interval::default())
+            );
+        }
+
+        #[test]
+        fn mysql_intervals() {
+            assert_eq!(
+                now_offset_of(Dialect::MySQL, "NOW() - INTERVAL 30 DAY"),
+                Some(interval("-30 days"))
+            );
+            assert_eq!(
+                now_offset_of(
+                    Dialect::MySQL,
+                    "NOW() - INTERVAL 1 DAY + INTERVAL '1:30' HOUR_MINUTE"
+                ),
+                Some(interval("-1 day 01:30:00"))
+            );
+        }
+
+        #[test]
+        fn postgres_intervals() {
+            assert_eq!(
+                now_offset_of(Dialect::PostgreSQL, "now() + interval '2 hours'"),
+                Some(interval("2 hours"))
+            );
+        }
+
+        #[test]
+        fn not_now() {
+            assert_eq!(now_offset_of(Dialect::MySQL, "t.x"), None);
+            assert_eq!(now_offset_of(Dialect::MySQL, "t.x + INTERVAL 1 DAY"), None);
+            assert_eq!(now_offset_of(Dialect::MySQL, "NOW() + 1"), None);
+            assert_eq!(now_offset_of(Dialect::MySQL, "NOW(t.x)"), None);
+        }
+    }
 }
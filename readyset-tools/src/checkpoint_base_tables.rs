@@ -0,0 +1,66 @@
+//! Creates a consistent RocksDB checkpoint of all the base tables in a running ReadySet
+//! deployment, and prints the replication offset each table was checkpointed at.
+//!
+//! The checkpoint is created in the given directory on the filesystem of the server(s) running
+//! the base tables, and the controller's dataflow state (including the schema replication offset)
+//! is saved in the same directory on the filesystem of the controller. A new server can be started
+//! from the checkpoint by passing the same directory to `--restore-from-checkpoint`.
+//!
+//! # Example
+//!
+//! ```bash
+//! cargo run --bin checkpoint_base_tables -- --deployment readyset /var/lib/readyset/checkpoint
+//! ```
+#![warn(clippy::panic)]
+
+use std::path::PathBuf;
+
+use clap::Parser;
+use readyset_client::consensus::AuthorityType;
+use readyset_client::ReadySetHandle;
+
+#[derive(Parser)]
+#[command(name = "checkpoint_base_tables")]
+struct CheckpointBaseTables {
+    #[arg(short, long, env("AUTHORITY_ADDRESS"), default_value("127.0.0.1:8500"))]
+    authority_address: String,
+
+    #[arg(long, env("AUTHORITY"), default_value("consul"), value_parser = ["consul"])]
+    authority: AuthorityType,
+
+    #[arg(short, long, env("DEPLOYMENT"))]
+    deployment: String,
+
+    /// The directory to create the checkpoint in. Must not already contain a checkpoint.
+    dir: PathBuf,
+}
+
+impl CheckpointBaseTables {
+    pub async fn run(self) -> anyhow::Result<()> {
+        let authority = self
+            .authority
+            .to_authority(&self.authority_address, &self.deployment);
+
+        let mut handle: ReadySetHandle = ReadySetHandle::new(authority).await;
+        handle.ready().await?;
+
+        let offsets = handle.checkpoint_base_tables(self.dir.clone()).await?;
+        println!("Created checkpoint in {}", self.dir.display());
+        if let Some(schema) = &offsets.schema {
+            println!("schema: {schema}");
+        }
+        for (table, offset) in &offsets.tables {
+            match offset {
+                Some(offset) => println!("{}: {offset}", table.display_unquoted()),
+                None => println!("{}: <no replication offset>", table.display_unquoted()),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    CheckpointBaseTables::parse().run().await
+}
@@ -315,6 +315,10 @@ pub struct Options {
     #[command(flatten)]
     pub tracing: readyset_tracing::Options,
 
+    /// readyset-mysql-specific options
+    #[command(flatten)]
+    pub mysql_options: mysql::Options,
+
     /// readyset-psql-specific options
     #[command(flatten)]
     pub psql_options: psql::Options,
@@ -1024,6 +1028,12 @@ where
 
         // Run a readyset-server instance within this adapter.
         let internal_server_handle = if deployment_mode.has_reader_nodes() {
+            if deployment_mode.is_standalone() {
+                options
+                    .server_worker_options
+                    .restore_checkpoint(&deployment_dir)?;
+            }
+
             let authority = options.authority.clone();
             let deployment = options.deployment.clone();
             let mut builder = readyset_server::Builder::from_worker_options(
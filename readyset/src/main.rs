@@ -14,6 +14,8 @@ fn main() -> anyhow::Result<()> {
             default_address: SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 3307),
             connection_handler: MySqlHandler {
                 enable_statement_logging: options.tracing.statement_logging,
+                tls_acceptor: options.psql_options.tls_acceptor()?,
+                require_tls: options.mysql_options.mysql_require_tls,
             },
             database_type: DatabaseType::MySQL,
             parse_dialect: nom_sql::Dialect::MySQL,
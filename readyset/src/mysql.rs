@@ -1,15 +1,36 @@
+use std::sync::Arc;
+
+use clap::Parser;
 use mysql_srv::MySqlIntermediary;
 use readyset_adapter::upstream_database::LazyUpstream;
 use readyset_mysql::{MySqlQueryHandler, MySqlUpstream};
 use tokio::net::TcpStream;
+use tokio_native_tls::TlsAcceptor;
 use tracing::{error, instrument};
 
 use crate::ConnectionHandler;
 
-#[derive(Clone, Copy)]
+// readyset-mysql specific options
+#[derive(Clone, Debug, Parser)]
+pub struct Options {
+    /// Reject MySQL clients which don't connect over TLS, rather than accepting them over a
+    /// plaintext connection.
+    ///
+    /// Requires an identity file, since ReadySet can only accept TLS connections if one is
+    /// specified.
+    #[arg(long, env = "MYSQL_REQUIRE_TLS", requires = "readyset_identity_file")]
+    pub mysql_require_tls: bool,
+}
+
+#[derive(Clone)]
 pub struct MySqlHandler {
     /// Whether to log statements received by the client
     pub enable_statement_logging: bool,
+    /// Optional struct to accept a TLS handshake and return a `TlsConnection`.
+    pub tls_acceptor: Option<Arc<TlsAcceptor>>,
+    /// Whether to reject clients which don't connect over TLS. Only has an effect if
+    /// `tls_acceptor` is set.
+    pub require_tls: bool,
 }
 
 impl ConnectionHandler for MySqlHandler {
@@ -22,16 +43,26 @@ impl ConnectionHandler for MySqlHandler {
         stream: TcpStream,
         backend: readyset_adapter::Backend<LazyUpstream<MySqlUpstream>, MySqlQueryHandler>,
     ) {
-        if let Err(e) = MySqlIntermediary::run_on_tcp(
-            readyset_mysql::Backend {
-                noria: backend,
-                enable_statement_logging: self.enable_statement_logging,
-            },
-            stream,
-            self.enable_statement_logging,
-        )
-        .await
-        {
+        let backend = readyset_mysql::Backend {
+            noria: backend,
+            enable_statement_logging: self.enable_statement_logging,
+        };
+        let res = match &self.tls_acceptor {
+            Some(tls_acceptor) => {
+                MySqlIntermediary::run_on_tls_tcp(
+                    backend,
+                    stream,
+                    self.enable_statement_logging,
+                    tls_acceptor.clone(),
+                    self.require_tls,
+                )
+                .await
+            }
+            None => {
+                MySqlIntermediary::run_on_tcp(backend, stream, self.enable_statement_logging).await
+            }
+        };
+        if let Err(e) = res {
             error!(err = %e, "connection lost");
         }
     }
@@ -74,14 +74,23 @@ fn load_pkcs12_identity(options: &Options) -> ReadySetResult<Option<native_tls::
     )?))
 }
 
-impl PsqlHandler {
-    pub fn new(config: Config) -> ReadySetResult<PsqlHandler> {
-        let tls_acceptor = match load_pkcs12_identity(&config.options)? {
+impl Options {
+    /// Build a `TlsAcceptor` from the identity file in these options, if one was specified.
+    ///
+    /// The same acceptor is used for TLS connections from both PostgreSQL and MySQL clients.
+    pub fn tls_acceptor(&self) -> ReadySetResult<Option<Arc<TlsAcceptor>>> {
+        Ok(match load_pkcs12_identity(self)? {
             Some(identity) => Some(Arc::new(TlsAcceptor::from(native_tls::TlsAcceptor::new(
                 identity,
             )?))),
             None => None,
-        };
+        })
+    }
+}
+
+impl PsqlHandler {
+    pub fn new(config: Config) -> ReadySetResult<PsqlHandler> {
+        let tls_acceptor = config.options.tls_acceptor()?;
 
         Ok(PsqlHandler {
             enable_statement_logging: config.enable_statement_logging,
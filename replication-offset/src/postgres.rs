@@ -46,6 +46,14 @@ impl PostgresPosition {
         }
     }
 
+    /// Constructs a [`PostgresPosition`] for a point in the WAL at which we aren't in the middle of
+    /// any transaction, such as the end of the WAL reported by a keepalive message once every
+    /// transaction before it has been applied. The returned position is after every transaction
+    /// that committed before `lsn`, and before every transaction that commits at or after `lsn`.
+    pub fn idle_at(lsn: Lsn) -> Self {
+        Self::commit_start(CommitLsn(lsn.0))
+    }
+
     /// Consumes `self`, constructing a new [`PostgresPosition`] with `self`'s [`CommitLsn`] and the
     /// given [`Lsn`].
     pub fn with_lsn(self, lsn: impl Into<Lsn>) -> Self {
@@ -54,6 +62,18 @@ impl PostgresPosition {
             lsn: lsn.into(),
         }
     }
+
+    /// Returns the LSN up to which every transaction is known to have been applied once
+    /// replication has reached `self`.
+    ///
+    /// In the middle of a transaction this is the LSN of the COMMIT that ends the transaction,
+    /// since every transaction that committed before it has already been applied, and once the
+    /// COMMIT itself has been applied this is the end LSN of the COMMIT. The watermarks of
+    /// successive positions never decrease, and unlike a [`PostgresPosition`] they fit in a single
+    /// integer, which lets them be used as read-your-writes timestamps.
+    pub fn watermark(&self) -> u64 {
+        self.commit_lsn.0.max(self.lsn.0) as u64
+    }
 }
 
 impl From<PostgresPosition> for ReplicationOffset {
@@ -179,6 +199,30 @@ mod tests {
         assert!(pos1 > pos2);
     }
 
+    #[test]
+    fn test_postgres_position_watermark() {
+        let begin = PostgresPosition::commit_start(CommitLsn(100));
+        let row = begin.with_lsn(50);
+        let commit = begin.with_lsn(120);
+        let next_row = PostgresPosition::commit_start(CommitLsn(200)).with_lsn(130);
+
+        assert_eq!(begin.watermark(), 100);
+        assert_eq!(row.watermark(), 100);
+        assert_eq!(commit.watermark(), 120);
+        assert_eq!(next_row.watermark(), 200);
+    }
+
+    #[test]
+    fn test_postgres_position_idle_at() {
+        let commit = PostgresPosition::commit_start(CommitLsn(100)).with_lsn(120);
+        let idle = PostgresPosition::idle_at(Lsn(150));
+        let next_row = PostgresPosition::commit_start(CommitLsn(150)).with_lsn(140);
+
+        assert!(commit < idle);
+        assert!(idle < next_row);
+        assert_eq!(idle.watermark(), 150);
+    }
+
     #[test]
     fn test_commit_lsn_round_trip() {
         assert_eq!(
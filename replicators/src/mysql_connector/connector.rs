@@ -28,6 +28,10 @@ use crate::noria_adapter::{Connector, ReplicationAction};
 const CHECKSUM_QUERY: &str = "SET @source_binlog_checksum='CRC32'";
 const DEFAULT_SERVER_ID: u32 = u32::MAX - 55;
 const MAX_POSITION_TIME: u64 = 10;
+/// How long the server waits without sending any binlog events before it sends a heartbeat, in
+/// nanoseconds. We use heartbeats to report the position of transactions we would otherwise only
+/// report after the next commit, such as transactions that don't write to any table we replicate.
+const HEARTBEAT_PERIOD_NS: u64 = 1_000_000_000;
 
 /// A connector that connects to a MySQL server and starts reading binlogs from a given position.
 ///
@@ -61,6 +65,9 @@ pub(crate) struct MySqlBinlogConnector {
     /// Timestamp of the last reported position. This is use to ensure we keep the distance
     /// between min/max position as short as possible.
     last_reported_pos_ts: std::time::Instant,
+    /// The GTID we last reported a position with, used to report positions on heartbeats only if
+    /// there were transactions since then
+    last_reported_gtid: Option<u64>,
 }
 
 impl MySqlBinlogConnector {
@@ -75,6 +82,13 @@ impl MySqlBinlogConnector {
     /// but others use CRC32 🤷‍♂️
     async fn register_as_replica(&mut self) -> mysql::Result<()> {
         self.connection.query_drop(CHECKSUM_QUERY).await?;
+        // Servers before MySQL 8.0.26 (and MariaDB) only read the variable by its old name
+        self.connection
+            .query_drop(format!(
+                "SET @source_heartbeat_period={HEARTBEAT_PERIOD_NS}, \
+                 @master_heartbeat_period={HEARTBEAT_PERIOD_NS}"
+            ))
+            .await?;
 
         let cmd = mysql_common::packets::ComRegisterSlave::new(self.server_id());
         self.connection.write_command(&cmd).await?;
@@ -139,6 +153,7 @@ impl MySqlBinlogConnector {
             enable_statement_logging,
             last_reported_pos_ts: std::time::Instant::now()
                 - std::time::Duration::from_secs(MAX_POSITION_TIME),
+            last_reported_gtid: None,
         };
 
         connector.register_as_replica().await?;
@@ -218,7 +233,7 @@ impl MySqlBinlogConnector {
             )))
         })?;
 
-        Ok(ReplicationAction::LogPosition)
+        Ok(self.log_position())
     }
 
     /// Process a single binlog WRITE_ROWS_EVENT.
@@ -447,7 +462,7 @@ impl MySqlBinlogConnector {
         use nom_sql::{parse_query, Dialect, SqlQuery};
         match parse_query(Dialect::MySQL, q_event.query()) {
             Ok(SqlQuery::Commit(_)) if self.report_position_elapsed() || is_last => {
-                Ok(ReplicationAction::LogPosition)
+                Ok(self.log_position())
             }
             Ok(SqlQuery::Truncate(truncate)) if truncate.tables.len() == 1 => {
                 // MySQL only allows one table in the statement, or we would be in trouble.
@@ -610,12 +625,21 @@ impl MySqlBinlogConnector {
                     schema: None,
                     name: SqlIdentifier::from(""),
                 },
-                ReplicationAction::LogPosition,
+                self.log_position(),
             );
         }
         Ok(hash_actions.into_values().collect())
     }
 
+    /// Returns a [`ReplicationAction::LogPosition`] reporting every transaction up to the current
+    /// one as replicated
+    fn log_position(&mut self) -> ReplicationAction {
+        self.last_reported_gtid = self.current_gtid;
+        ReplicationAction::LogPosition {
+            txid: self.current_gtid,
+        }
+    }
+
     /// Check whatever we need to report the current position
     /// If last_reported_pos_ts has elapsed, update it with the current timestamp.
     ///
@@ -644,14 +668,21 @@ impl MySqlBinlogConnector {
         loop {
             let binlog_event = self.next_event().await?;
 
-            if u64::from(binlog_event.header().log_pos()) < self.next_position.position
-                && self.next_position.position + u64::from(binlog_event.header().event_size())
-                    > u64::from(u32::MAX)
-            {
-                self.next_position.position =
-                    u64::from(u32::MAX) + 1 + u64::from(binlog_event.header().log_pos());
-            } else {
-                self.next_position.position = u64::from(binlog_event.header().log_pos());
+            // Heartbeats aren't part of the binlog, so they don't move our position in it
+            let is_heartbeat = matches!(
+                binlog_event.header().event_type(),
+                Ok(EventType::HEARTBEAT_EVENT)
+            );
+            if !is_heartbeat {
+                if u64::from(binlog_event.header().log_pos()) < self.next_position.position
+                    && self.next_position.position + u64::from(binlog_event.header().event_size())
+                        > u64::from(u32::MAX)
+                {
+                    self.next_position.position =
+                        u64::from(u32::MAX) + 1 + u64::from(binlog_event.header().log_pos());
+                } else {
+                    self.next_position.position = u64::from(binlog_event.header().log_pos());
+                }
             }
 
             let is_last = match until {
@@ -752,7 +783,7 @@ impl MySqlBinlogConnector {
                     // Generated for a commit of a transaction that modifies one or more tables of
                     // an XA-capable storage engine (InnoDB).
                     if self.report_position_elapsed() || is_last {
-                        return Ok((vec![ReplicationAction::LogPosition], &self.next_position));
+                        return Ok((vec![self.log_position()], &self.next_position));
                     }
                     continue;
                 }
@@ -766,6 +797,15 @@ impl MySqlBinlogConnector {
                 EventType::DELETE_ROWS_EVENT_V1 => unimplemented!(), /* The V1 event numbers are */
                 // used from 5.1.16 until
                 // mysql-5.6.
+                EventType::HEARTBEAT_EVENT => {
+                    // The server only sends heartbeats once it has sent us every transaction it
+                    // has committed, so if there were transactions since we last reported our
+                    // position (which didn't write to any table we replicate, or we'd have
+                    // reported them), report that they've been replicated.
+                    if self.current_gtid != self.last_reported_gtid {
+                        return Ok((vec![self.log_position()], &self.next_position));
+                    }
+                }
                 EventType::GTID_EVENT => {
                     // GTID stands for Global Transaction Identifier It is composed of two parts:
                     // SID for Source Identifier, and GNO for Group Number. The basic idea is to
@@ -838,7 +878,7 @@ impl MySqlBinlogConnector {
             // We didn't get an actionable event, but we still need to check that we haven't reached
             // the until limit
             if is_last {
-                return Ok((vec![ReplicationAction::LogPosition], &self.next_position));
+                return Ok((vec![self.log_position()], &self.next_position));
             }
         }
     }
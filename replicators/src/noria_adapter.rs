@@ -51,13 +51,23 @@ pub(crate) enum ReplicationAction {
         /// table write operation within a transaction should be assigned
         /// the same transaction id. These id's should be monotonically
         /// increasing across transactions.
+        ///
+        /// This is propagated through the dataflow graph as the table's timestamp, and must match
+        /// the values the adapter's timestamp client derives from the writes it proxies upstream:
+        /// the GTID sequence number for MySQL, and the watermark of the replication offset for
+        /// PostgreSQL.
         txid: Option<u64>,
     },
     DdlChange {
         schema: String,
         changes: Vec<Change>,
     },
-    LogPosition,
+    LogPosition {
+        /// The transaction id up to which every transaction on the upstream has been replicated,
+        /// if known. This includes transactions which didn't write to any table we replicate, so
+        /// the timestamps of all tables are advanced to it.
+        txid: Option<u64>,
+    },
 }
 
 #[async_trait]
@@ -160,6 +170,15 @@ pub struct NoriaAdapter {
     table_filter: TableFilter,
     /// If the connector can partially resnapshot a database
     supports_resnapshot: bool,
+    /// The highest transaction id we have propagated as a timestamp to any base table, or that a
+    /// reported log position has told us the upstream is at. Every transaction up to this one has
+    /// been applied, so when we report a log position we advance
+    /// the timestamps of all tables to it, which lets read-your-writes lookups against readers
+    /// over tables that weren't written to make progress.
+    last_txid: Option<u64>,
+    /// The last transaction id we have propagated as a timestamp to each base table, used to only
+    /// advance the timestamps of tables which are behind `last_txid` when we report a log position
+    table_txids: HashMap<Relation, u64>,
 }
 
 impl NoriaAdapter {
@@ -463,6 +482,8 @@ impl NoriaAdapter {
             warned_missing_tables: HashSet::new(),
             table_filter,
             supports_resnapshot: true,
+            last_txid: None,
+            table_txids: HashMap::new(),
             dialect: Dialect::DEFAULT_MYSQL,
         };
 
@@ -742,6 +763,8 @@ impl NoriaAdapter {
             warned_missing_tables: HashSet::new(),
             table_filter,
             supports_resnapshot: true,
+            last_txid: None,
+            table_txids: HashMap::new(),
             dialect: Dialect::DEFAULT_POSTGRESQL,
         };
 
@@ -817,7 +840,7 @@ impl NoriaAdapter {
             if let Some(pos) = self.replication_offsets.max_offset()?.cloned() {
                 // Forward all positions to the maximum position (the one prior to this statement)
                 // to avoid needless replay later
-                self.handle_log_position(&pos).await?;
+                self.handle_log_position(&pos, None).await?;
             }
             return Err(ReadySetError::ResnapshotNeeded);
         }
@@ -896,8 +919,14 @@ impl NoriaAdapter {
         Ok(())
     }
 
-    /// Update the log position of the schema and the tables
-    async fn handle_log_position(&mut self, pos: &ReplicationOffset) -> ReadySetResult<()> {
+    /// Update the log position of the schema and the tables, and advance the timestamps of the
+    /// tables to `txid`, the transaction id up to which the upstream has been replicated (if
+    /// known)
+    async fn handle_log_position(
+        &mut self,
+        pos: &ReplicationOffset,
+        txid: Option<u64>,
+    ) -> ReadySetResult<()> {
         // Update the log position for the schema
         debug!(%pos, "Setting schema replication offset");
         self.noria.set_schema_replication_offset(Some(pos)).await?;
@@ -921,6 +950,30 @@ impl NoriaAdapter {
             }
         }
 
+        // Every transaction up to the reported one has been applied to all tables, not just the
+        // ones it wrote to, and that includes transactions which didn't write to any table we
+        // replicate (or to any table at all). Only tables whose timestamp is behind that
+        // transaction need updating, so once every table has caught up, position reports without
+        // any new transactions don't send anything.
+        self.last_txid = self.last_txid.max(txid);
+        if let Some(tx) = self.last_txid {
+            let tables = self
+                .replication_offsets
+                .tables
+                .keys()
+                .filter(|table| self.table_txids.get(*table).map_or(true, |t| *t < tx))
+                .cloned()
+                .collect::<Vec<_>>();
+            for table in tables {
+                if let Some(mutator) = self.mutator_for_table(&table).await? {
+                    let mut timestamp = Timestamp::default();
+                    timestamp.map.insert(mutator.node, tx);
+                    mutator.update_timestamp(timestamp).await?;
+                    self.table_txids.insert(table, tx);
+                }
+            }
+        }
+
         self.replication_offsets.advance_offset(pos.clone())?;
 
         Ok(())
@@ -965,6 +1018,8 @@ impl NoriaAdapter {
             let mut timestamp = Timestamp::default();
             timestamp.map.insert(table_mutator.node, tx);
             table_mutator.update_timestamp(timestamp).await?;
+            self.last_txid = self.last_txid.max(Some(tx));
+            self.table_txids.insert(table.clone(), tx);
         }
 
         self.replication_offsets
@@ -989,7 +1044,7 @@ impl NoriaAdapter {
         let mut actionables: Vec<ReplicationAction> = Vec::new();
         for action in actions {
             match action {
-                ReplicationAction::DdlChange { .. } | ReplicationAction::LogPosition => {
+                ReplicationAction::DdlChange { .. } | ReplicationAction::LogPosition { .. } => {
                     match &self.replication_offsets.schema {
                         Some(cur) if pos <= *cur => {
                             if !catchup {
@@ -1058,7 +1113,9 @@ impl NoriaAdapter {
                     self.handle_table_actions(table, actions, txid, &pos)
                         .await?
                 }
-                ReplicationAction::LogPosition => self.handle_log_position(&pos).await?,
+                ReplicationAction::LogPosition { txid } => {
+                    self.handle_log_position(&pos, txid).await?
+                }
             }
         }
         Ok(())
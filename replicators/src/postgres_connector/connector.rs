@@ -615,7 +615,12 @@ impl Connector for PostgresWalConnector {
             if actions.is_empty()
                 && matches!(until, Some(until) if &ReplicationOffset::from(cur_pos) >= until)
             {
-                return Ok((vec![ReplicationAction::LogPosition], cur_pos.into()));
+                return Ok((
+                    vec![ReplicationAction::LogPosition {
+                        txid: Some(cur_pos.watermark()),
+                    }],
+                    cur_pos.into(),
+                ));
             }
 
             // Get the next buffered event or error, or read a new event from the WAL stream.
@@ -633,7 +638,7 @@ impl Connector for PostgresWalConnector {
                         vec![ReplicationAction::TableAction {
                             table: cur_table,
                             actions,
-                            txid: None,
+                            txid: Some(cur_pos.watermark()),
                         }],
                         cur_pos.into(),
                     ));
@@ -678,7 +683,7 @@ impl Connector for PostgresWalConnector {
                         vec![ReplicationAction::TableAction {
                             table: cur_table,
                             actions,
-                            txid: None,
+                            txid: Some(cur_pos.watermark()),
                         }],
                         cur_pos.into(),
                     ));
@@ -726,7 +731,7 @@ impl Connector for PostgresWalConnector {
                                         name: name.into(),
                                     },
                                     actions,
-                                    txid: None,
+                                    txid: Some(cur_pos.with_lsn(*lsn).watermark()),
                                 }],
                                 cur_pos.with_lsn(*lsn).into(),
                             ));
@@ -750,7 +755,7 @@ impl Connector for PostgresWalConnector {
                             vec![ReplicationAction::TableAction {
                                 table: cur_table,
                                 actions,
-                                txid: None,
+                                txid: Some(cur_pos.watermark()),
                             }],
                             cur_pos.into(),
                         ));
@@ -770,7 +775,7 @@ impl Connector for PostgresWalConnector {
                             vec![ReplicationAction::TableAction {
                                 table: cur_table,
                                 actions,
-                                txid: None,
+                                txid: Some(cur_pos.watermark()),
                             }],
                             cur_pos.into(),
                         ));
@@ -800,18 +805,33 @@ impl Connector for PostgresWalConnector {
                             vec![ReplicationAction::TableAction {
                                 table: cur_table,
                                 actions,
-                                txid: None,
+                                txid: Some(cur_pos.watermark()),
                             }],
                             cur_pos.into(),
                         ));
                     }
                 }
-                WalEvent::WantsKeepaliveResponse { end } => {
+                WalEvent::Keepalive { end } => {
                     if !self.in_transaction && actions.is_empty() {
                         // If the last event we applied to our base tables was a COMMIT and we have
                         // no buffered actions, we can safely report the "end LSN" given to us in
                         // the keepalive request as our current position.
                         self.send_standby_status_update(end).await?;
+                        self.time_last_position_reported = Instant::now();
+
+                        // Every transaction that committed before `end` has been sent to us, even
+                        // the ones we never see because they didn't write to any published table
+                        // (or were in another database), so report `end` as our position to let
+                        // the timestamps of our tables catch up with the upstream.
+                        let position = PostgresPosition::idle_at(end);
+                        if position > cur_pos {
+                            return Ok((
+                                vec![ReplicationAction::LogPosition {
+                                    txid: Some(position.watermark()),
+                                }],
+                                position.into(),
+                            ));
+                        }
                     } else {
                         // If we have buffered actions, we have to report the position of the *last*
                         // event we applied, since we haven't yet applied the events associated with
@@ -856,12 +876,17 @@ impl Connector for PostgresWalConnector {
                             vec![ReplicationAction::TableAction {
                                 table: cur_table,
                                 actions,
-                                txid: None,
+                                txid: Some(position.watermark()),
                             }],
                             position.into(),
                         ));
                     } else {
-                        return Ok((vec![ReplicationAction::LogPosition], position.into()));
+                        return Ok((
+                            vec![ReplicationAction::LogPosition {
+                                txid: Some(position.watermark()),
+                            }],
+                            position.into(),
+                        ));
                     }
                 }
                 WalEvent::Insert { tuple, lsn, .. } => {
@@ -59,6 +59,7 @@ pub enum TableErrorKind {
     TimestampTzParseError,
     DateParseError,
     TimeParseError(mysql_time::ConvertError),
+    IntervalParseError,
     NumericParseError(NumericParseErrorKind),
     BitVectorParseError(String),
     ArrayParseError,
@@ -5,7 +5,7 @@ use std::sync::Arc;
 use bit_vec::BitVec;
 use mysql_time::MySqlTime;
 use postgres_types::Kind;
-use readyset_data::{Array, Collation, DfType, DfValue, Dialect};
+use readyset_data::{Array, Collation, DfType, DfValue, Dialect, Interval};
 use readyset_errors::ReadySetError;
 use replication_offset::postgres::{CommitLsn, Lsn};
 use rust_decimal::prelude::FromStr;
@@ -38,7 +38,7 @@ pub struct WalReader {
 
 #[derive(Debug)]
 pub(crate) enum WalEvent {
-    WantsKeepaliveResponse {
+    Keepalive {
         end: Lsn,
     },
     Begin {
@@ -102,7 +102,7 @@ impl WalEvent {
             | Self::UpdateByKey { lsn, .. }
             | Self::Truncate { lsn, .. }
             | Self::DdlEvent { lsn, .. } => Some(*lsn),
-            Self::Begin { .. } | Self::Commit { .. } | Self::WantsKeepaliveResponse { .. } => None,
+            Self::Begin { .. } | Self::Commit { .. } | Self::Keepalive { .. } => None,
         }
     }
 }
@@ -159,8 +159,12 @@ impl WalReader {
             };
 
             let (lsn, record) = match data {
-                WalData::Keepalive { reply: 1, end, .. } => {
-                    return Ok(WalEvent::WantsKeepaliveResponse { end });
+                // We respond to every keepalive, not just the ones that ask for a reply: the server
+                // only sends keepalives while it's waiting for new WAL if we've acknowledged the
+                // last one, and those are how we learn that the WAL has moved past transactions
+                // that weren't sent to us.
+                WalData::Keepalive { end, .. } => {
+                    return Ok(WalEvent::Keepalive { end });
                 }
                 WalData::XLogData { start, data, .. } => (start, data),
                 msg => {
@@ -568,6 +572,7 @@ impl wal::TupleData {
                                     PGType::JSONB => DfType::Jsonb,
                                     PGType::DATE => DfType::Date,
                                     PGType::TIME => DfType::Time { subsecond_digits },
+                                    PGType::INTERVAL => DfType::Interval,
                                     PGType::NUMERIC => DfType::DEFAULT_NUMERIC,
                                     PGType::BYTEA => DfType::Blob,
                                     PGType::MACADDR => DfType::MacAddr,
@@ -773,6 +778,15 @@ impl wal::TupleData {
 
                                     DfValue::Time(result?)
                                 }
+                                PGType::INTERVAL => {
+                                    DfValue::from(str.parse::<Interval>().map_err(|_| {
+                                        WalError::TableError {
+                                            kind: TableErrorKind::IntervalParseError,
+                                            schema: relation.schema_name_lossy(),
+                                            table: relation.relation_name_lossy(),
+                                        }
+                                    })?)
+                                }
                                 PGType::BIT | PGType::VARBIT => {
                                     let mut bits = BitVec::with_capacity(str.len());
                                     for c in str.chars() {
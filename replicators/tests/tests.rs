@@ -297,27 +297,24 @@ impl TestHandle {
     async fn start_noria_with_builder(
         url: String,
         config: Option<Config>,
-        builder: Builder,
+        mut builder: Builder,
     ) -> ReadySetResult<(TestHandle, ShutdownSender)> {
-        let authority_store = Arc::new(LocalAuthorityStore::new());
-        let authority = Arc::new(Authority::from(LocalAuthority::new_with_store(
-            authority_store,
-        )));
-        TestHandle::start_with_authority(url, authority, config, builder).await
+        let persistence = readyset_server::PersistenceParameters {
+            mode: readyset_server::DurabilityMode::DeleteOnExit,
+            ..Default::default()
+        };
+        builder.set_persistence(persistence);
+        TestHandle::start_with_authority(url, new_authority(), config, builder, true).await
     }
 
     async fn start_with_authority(
         url: String,
         authority: Arc<Authority>,
         config: Option<Config>,
-        mut builder: Builder,
+        builder: Builder,
+        server_startup: bool,
     ) -> ReadySetResult<(TestHandle, ShutdownSender)> {
         readyset_tracing::init_test_logging();
-        let persistence = readyset_server::PersistenceParameters {
-            mode: readyset_server::DurabilityMode::DeleteOnExit,
-            ..Default::default()
-        };
-        builder.set_persistence(persistence);
         let telemetry_sender = builder.telemetry.clone();
         let (noria, shutdown_tx) = builder.start(Arc::clone(&authority)).await.unwrap();
 
@@ -336,7 +333,9 @@ impl TestHandle {
             notification_channel: None,
         };
 
-        handle.start_repl(config, telemetry_sender, true).await?;
+        handle
+            .start_repl(config, telemetry_sender, server_startup)
+            .await?;
 
         Ok((handle, shutdown_tx))
     }
@@ -449,6 +448,7 @@ impl TestHandle {
                                 .unwrap(),
                         ),
                         always: false,
+                        options: Default::default(),
                     }),
                 ],
                 self.dialect,
@@ -580,6 +580,13 @@ async fn replication_test_inner(url: &str) {
     shutdown_tx.shutdown().await;
 }
 
+fn new_authority() -> Arc<Authority> {
+    let authority_store = Arc::new(LocalAuthorityStore::new());
+    Arc::new(Authority::from(LocalAuthority::new_with_store(
+        authority_store,
+    )))
+}
+
 fn pgsql_url() -> String {
     format!(
         "postgresql://postgres:noria@{}:{}/noria",
@@ -2060,6 +2067,7 @@ async fn postgresql_ddl_replicate_drop_view_internal(url: &str) {
             .unwrap(),
         ),
         always: false,
+        options: Default::default(),
     });
     ctx.noria
         .extend_recipe(ChangeList::from_change(
@@ -2141,6 +2149,7 @@ async fn postgresql_ddl_replicate_create_view_internal(url: &str) {
                     .unwrap()
                 ),
                 always: true,
+                options: Default::default(),
             }),
             Dialect::DEFAULT_POSTGRESQL
         ))
@@ -2215,6 +2224,110 @@ async fn snapshot_telemetry_inner(url: &String) {
     shutdown_tx.shutdown().await;
 }
 
+#[tokio::test(flavor = "multi_thread")]
+#[serial_test::serial]
+#[slow]
+async fn restore_from_checkpoint_mysql() {
+    restore_from_checkpoint_inner(&mysql_url()).await
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[serial_test::serial]
+#[slow]
+async fn restore_from_checkpoint_postgresql() {
+    restore_from_checkpoint_inner(&pgsql_url()).await
+}
+
+/// Tests that a server restored from a checkpoint of its base tables catches up on the changes
+/// made upstream since the checkpoint from the restored replication offsets, without snapshotting
+async fn restore_from_checkpoint_inner(url: &str) {
+    readyset_tracing::init_test_logging();
+    let dir = tempfile::tempdir().unwrap();
+    let checkpoint_dir = dir.path().join("checkpoint");
+    let builder = |storage_dir: &str| {
+        let mut builder = Builder::for_tests();
+        builder.set_persistence(readyset_server::PersistenceParameters::new(
+            readyset_server::DurabilityMode::Permanent,
+            Some("restore_from_checkpoint".into()),
+            1,
+            Some(dir.path().join(storage_dir)),
+            0,
+        ));
+        builder
+    };
+
+    let mut client = DbConnection::connect(url).await.unwrap();
+    client.query(CREATE_SCHEMA).await.unwrap();
+    client.query(POPULATE_SCHEMA).await.unwrap();
+
+    let (mut ctx, shutdown_tx) = TestHandle::start_with_authority(
+        url.to_string(),
+        new_authority(),
+        None,
+        builder("original"),
+        true,
+    )
+    .await
+    .unwrap();
+    ctx.notification_channel
+        .as_mut()
+        .unwrap()
+        .snapshot_completed()
+        .await
+        .unwrap();
+    ctx.check_results("noria_view", "Snapshot", SNAPSHOT_RESULT)
+        .await
+        .unwrap();
+
+    ctx.stop_repl().await;
+    let offsets = ctx
+        .controller()
+        .await
+        .checkpoint_base_tables(checkpoint_dir.clone())
+        .await
+        .unwrap();
+    assert!(offsets.schema.is_some());
+    assert!(offsets.max_offset().unwrap().is_some());
+    ctx.stop().await;
+    shutdown_tx.shutdown().await;
+
+    for (_, test_query, _) in TESTS {
+        client.query(test_query).await.unwrap();
+    }
+    client.query(DISCONNECT_QUERY).await.unwrap();
+
+    readyset_server::restore_checkpoint(&checkpoint_dir, &dir.path().join("restored")).unwrap();
+
+    let (sender, mut reporter) = TelemetryInitializer::test_init();
+    let mut builder = builder("restored");
+    builder.set_telemetry_sender(sender);
+    // A controller restored from a checkpoint doesn't tell the replicator it's starting up, so
+    // that it doesn't resnapshot
+    let (mut ctx, shutdown_tx) =
+        TestHandle::start_with_authority(url.to_string(), new_authority(), None, builder, false)
+            .await
+            .unwrap();
+    ctx.notification_channel
+        .as_mut()
+        .unwrap()
+        .snapshot_completed()
+        .await
+        .unwrap();
+    ctx.check_results("noria_view", "Restored", RECONNECT_RESULT)
+        .await
+        .unwrap();
+
+    reporter.run_timeout(Duration::from_millis(20)).await;
+    assert!(reporter
+        .check_event(TelemetryEvent::SnapshotComplete)
+        .await
+        .is_empty());
+
+    client.stop().await;
+    ctx.stop().await;
+    shutdown_tx.shutdown().await;
+}
+
 #[tokio::test(flavor = "multi_thread")]
 #[serial_test::serial]
 #[slow]
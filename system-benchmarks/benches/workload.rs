@@ -675,6 +675,8 @@ fn start_adapter(args: SystemBenchArgs) -> anyhow::Result<()> {
                 ),
                 connection_handler: MySqlHandler {
                     enable_statement_logging: false,
+                    tls_acceptor: None,
+                    require_tls: false,
                 },
                 database_type: DatabaseType::MySQL,
                 parse_dialect: nom_sql::Dialect::MySQL,
@@ -1,14 +1,46 @@
+use std::str::FromStr;
+
 use anyhow::{anyhow, Error};
 use readyset_client::consistency::Timestamp;
 use readyset_client::internal::LocalNodeIndex;
+use replication_offset::postgres::{CommitLsn, PostgresPosition};
 
 /// Unique identifier for a client write discernible at both the
-/// debezium connector and the noria client.
-// TODO(andrew): Currently only MySQL is supported by the client.
-// https://app.clubhouse.io/readysettech/story/368
+/// replicator and the noria client.
 pub enum WriteId {
     // MySQL global transaction identifier in form: <server-id>:<gtid>
     MySqlGtid(String),
+    // PostgreSQL WAL position after the write committed, in form: <hi>/<lo>, as returned by
+    // `pg_current_wal_lsn()`
+    PostgresLsn(String),
+}
+
+impl WriteId {
+    /// Returns the value the replicator propagates through the dataflow graph once it has applied
+    /// this write (or every write up to this position in the replication log).
+    pub fn timestamp_value(&self) -> Result<u64, Error> {
+        Ok(match self {
+            WriteId::MySqlGtid(id) => {
+                // Expecting form: <server-id>:<id>
+                let mut gtid_tokens = id.split(':');
+                let _server_id = gtid_tokens.next();
+                // TODO(andrew): Proper error handling.
+                // https://app.clubhouse.io/readysettech/story/366
+                let txid = gtid_tokens.next().ok_or_else(|| {
+                    anyhow!("GTID Parising Failure: GTID does not have a valid sequence number")
+                })?;
+                txid.parse()
+                    .map_err(|_| anyhow!("GTID Parse Failure: GTID sequence number not a number"))?
+            }
+            WriteId::PostgresLsn(lsn) => {
+                // The WAL position after our COMMIT is at or past the end of the COMMIT, so once
+                // the replicator has applied everything up to it our write has been applied too
+                let lsn =
+                    CommitLsn::from_str(lsn).map_err(|e| anyhow!("LSN Parse Failure: {e}"))?;
+                PostgresPosition::commit_end(lsn).watermark()
+            }
+        })
+    }
 }
 
 /// A key identifying the objects we are maintaining read-your-write
@@ -22,10 +54,14 @@ pub enum WriteKey {
 }
 
 /// The timestamp client provides users the ability to synchronize
-/// read-your-write state at the noria client and debezium connector.
+/// read-your-write state at the noria client and the replicator.
+///
+/// The timestamps it produces use the same values the replicator propagates through the dataflow
+/// graph alongside the writes it applies to base tables: the GTID sequence number of the
+/// transaction for MySQL, and the [watermark](PostgresPosition::watermark) of the replication
+/// offset for PostgreSQL. A reader whose timestamp satisfies a timestamp returned by
+/// [`append_write`](TimestampClient::append_write) has therefore applied that write.
 // TODO(justin): Integrate with server compnoent of timestamp service.
-// TODO(andrew): TimestampClient only works with MySQL.
-// https://app.clubhouse.io/readysettech/story/368
 #[allow(dead_code)]
 #[derive(Clone, Default)]
 pub struct TimestampClient {}
@@ -33,24 +69,10 @@ pub struct TimestampClient {}
 impl TimestampClient {
     /// Sends a set of write keys, `keys` for a single write, identifier by `write_id` to
     /// the timestamp server. Returns the updated timestamp for the write.
-    // TODO(andrew): Currently only parsing MySql GTID's is supported.
     pub fn append_write(&self, write_id: WriteId, keys: Vec<WriteKey>) -> Result<Timestamp, Error> {
         // TODO: implement to interact with true service
         // https://app.clubhouse.io/readysettech/story/331
-        let timestamp_val: u64 = match write_id {
-            WriteId::MySqlGtid(id) => {
-                // Expecting form: <server-id>:<id>
-                let mut gtid_tokens = id.split(':');
-                let _server_id = gtid_tokens.next();
-                // TODO(andrew): Proper error handling.
-                // https://app.clubhouse.io/readysettech/story/366
-                let txid = gtid_tokens.next().ok_or_else(|| {
-                    anyhow!("GTID Parising Failure: GTID does not have a valid sequence number")
-                })?;
-                txid.parse()
-                    .map_err(|_| anyhow!("GTID Parse Failure: GTID sequence number not a number"))?
-            }
-        };
+        let timestamp_val = write_id.timestamp_value()?;
 
         let mut timestamp = Timestamp::default();
         for key in keys {
@@ -73,3 +95,39 @@ impl TimestampClient {
         Timestamp::default()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn append_write_mysql_gtid() {
+        let table = LocalNodeIndex::make(1);
+        let timestamp = TimestampClient::default()
+            .append_write(
+                WriteId::MySqlGtid("3E11FA47-71CA-11E1-9E33-C80AA9429562:23".into()),
+                vec![WriteKey::TableIndex(table)],
+            )
+            .unwrap();
+        assert_eq!(timestamp.map.get(&table), Some(&23));
+    }
+
+    #[test]
+    fn append_write_postgres_lsn() {
+        let table = LocalNodeIndex::make(1);
+        let timestamp = TimestampClient::default()
+            .append_write(
+                WriteId::PostgresLsn("16/17DD38B8".into()),
+                vec![WriteKey::TableIndex(table)],
+            )
+            .unwrap();
+        assert_eq!(timestamp.map.get(&table), Some(&94889654456));
+    }
+
+    #[test]
+    fn append_write_invalid_lsn() {
+        assert!(TimestampClient::default()
+            .append_write(WriteId::PostgresLsn("nope".into()), vec![])
+            .is_err());
+    }
+}